@@ -0,0 +1,10 @@
+//! Response cache CLI arguments
+
+use clap::Subcommand;
+
+/// Cache subcommands for the on-disk response cache (`--cache`)
+#[derive(Subcommand, Debug)]
+pub enum CacheAction {
+    /// Remove all cached responses
+    Clear,
+}
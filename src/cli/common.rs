@@ -1,18 +1,28 @@
 //! Common CLI types shared across commands
 
 use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
 
 /// Output format options
-#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum OutputFormat {
     /// ASCII table (default)
     Table,
     /// Comma-separated values
     Csv,
+    /// Tab-separated values (tabs and line breaks in values are escaped)
+    Tsv,
     /// JSON array
     Json,
     /// YAML format
     Yaml,
+    /// Newline-delimited JSON (one object per line, for streaming pipelines)
+    Ndjson,
+    /// Go-style template rendered once per row (requires --template)
+    Template,
+    /// GitHub-flavored markdown table with the same columns as `table`
+    Markdown,
 }
 
 impl std::fmt::Display for OutputFormat {
@@ -20,8 +30,12 @@ impl std::fmt::Display for OutputFormat {
         match self {
             OutputFormat::Table => write!(f, "table"),
             OutputFormat::Csv => write!(f, "csv"),
+            OutputFormat::Tsv => write!(f, "tsv"),
             OutputFormat::Json => write!(f, "json"),
             OutputFormat::Yaml => write!(f, "yaml"),
+            OutputFormat::Ndjson => write!(f, "ndjson"),
+            OutputFormat::Template => write!(f, "template"),
+            OutputFormat::Markdown => write!(f, "markdown"),
         }
     }
 }
@@ -34,7 +48,11 @@ mod tests {
     fn test_output_format_display() {
         assert_eq!(OutputFormat::Table.to_string(), "table");
         assert_eq!(OutputFormat::Csv.to_string(), "csv");
+        assert_eq!(OutputFormat::Tsv.to_string(), "tsv");
+        assert_eq!(OutputFormat::Markdown.to_string(), "markdown");
         assert_eq!(OutputFormat::Json.to_string(), "json");
         assert_eq!(OutputFormat::Yaml.to_string(), "yaml");
+        assert_eq!(OutputFormat::Ndjson.to_string(), "ndjson");
+        assert_eq!(OutputFormat::Template.to_string(), "template");
     }
 }
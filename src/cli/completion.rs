@@ -0,0 +1,162 @@
+//! Shell completion script generation
+
+use clap::{CommandFactory, Parser, ValueEnum};
+use clap_complete::Shell;
+
+use super::Cli;
+
+/// Arguments for the hidden 'completion' command
+#[derive(Parser, Debug)]
+pub struct CompletionArgs {
+    /// Shell to generate the completion script for
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+/// Resource kinds offered by dynamic completion
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompleteResource {
+    /// Organization names
+    Org,
+    /// Workspace names in --org (or the context's organization)
+    #[value(alias = "workspace")]
+    Ws,
+}
+
+/// Arguments for the hidden '__complete' command
+#[derive(Parser, Debug)]
+pub struct CompleteArgs {
+    /// Kind of name to complete
+    #[arg(value_enum)]
+    pub resource: CompleteResource,
+
+    /// Partial name typed so far
+    #[arg(default_value = "")]
+    pub partial: String,
+
+    /// Organization to search workspaces in
+    #[arg(short, long)]
+    pub org: Option<String>,
+}
+
+/// Completion script for `shell`
+///
+/// Covers every subcommand, visible alias and flag known to clap. Bash and zsh
+/// scripts also complete `--org` and `--ws` values with live names from
+/// `hcpctl __complete`.
+pub fn completion_script(shell: Shell) -> String {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    let mut out = Vec::new();
+    clap_complete::generate(shell, &mut command, name.clone(), &mut out);
+    let script = String::from_utf8_lossy(&out).into_owned();
+    match shell {
+        Shell::Bash => script + &BASH_LIVE_NAMES.replace("{bin}", &name),
+        Shell::Zsh => zsh_with_live_names(&script, &name),
+        _ => script,
+    }
+}
+
+/// Bash wrapper answering `--org`/`--ws` values before the clap-generated function
+const BASH_LIVE_NAMES: &str = r#"
+_{bin}_live_names() {
+    local cur="${COMP_WORDS[COMP_CWORD]}" prev="${COMP_WORDS[COMP_CWORD-1]}" org="" i
+    if [[ "$prev" == "=" ]]; then
+        prev="${COMP_WORDS[COMP_CWORD-2]}"
+    fi
+    case "$prev" in
+        --org)
+            COMPREPLY=($(compgen -W "$({bin} __complete org "$cur" 2>/dev/null)" -- "$cur"))
+            ;;
+        --ws)
+            for ((i = 1; i < COMP_CWORD - 1; i++)); do
+                if [[ "${COMP_WORDS[i]}" == "--org" && "${COMP_WORDS[i+1]}" != "=" ]]; then
+                    org="${COMP_WORDS[i+1]}"
+                elif [[ "${COMP_WORDS[i]}" == "--org" ]]; then
+                    org="${COMP_WORDS[i+2]}"
+                fi
+            done
+            COMPREPLY=($(compgen -W "$({bin} __complete ws ${org:+--org "$org"} "$cur" 2>/dev/null)" -- "$cur"))
+            ;;
+        *)
+            _{bin} "$@"
+            ;;
+    esac
+}
+
+complete -F _{bin}_live_names -o bashdefault -o default {bin}
+"#;
+
+/// Zsh helpers completing organization and workspace names via `__complete`
+const ZSH_LIVE_NAMES: &str = r#"(( $+functions[_{bin}_orgs] )) ||
+_{bin}_orgs() {
+    local -a names
+    names=(${(f)"$({bin} __complete org "$PREFIX" 2>/dev/null)"})
+    compadd -a names
+}
+(( $+functions[_{bin}_workspaces] )) ||
+_{bin}_workspaces() {
+    local -a names org
+    local i
+    for ((i = 2; i < CURRENT; i++)); do
+        case ${words[i]} in
+            --org) org=(--org "${words[i+1]}") ;;
+            --org=*) org=(--org "${words[i]#--org=}") ;;
+        esac
+    done
+    names=(${(f)"$({bin} __complete ws $org "$PREFIX" 2>/dev/null)"})
+    compadd -a names
+}
+
+"#;
+
+/// Point the zsh `--org`/`--ws` value specs at the live-name helpers
+fn zsh_with_live_names(script: &str, name: &str) -> String {
+    let script = script
+        .replace(":ORG:_default'", &format!(":ORG:_{}_orgs'", name))
+        .replace(
+            ":WORKSPACE:_default'",
+            &format!(":WORKSPACE:_{}_workspaces'", name),
+        )
+        .replace(":WS:_default'", &format!(":WS:_{}_workspaces'", name));
+    let helpers = ZSH_LIVE_NAMES.replace("{bin}", name);
+    // The helpers must be defined before the script's trailing dispatch block
+    match script.rfind("\nif [ \"$funcstack[1]\"") {
+        Some(at) => format!("{}\n{}{}", &script[..at], helpers, &script[at + 1..]),
+        None => script + &helpers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bash_completion_includes_aliases() {
+        let script = completion_script(Shell::Bash);
+        assert!(script.contains("hcpctl"));
+        assert!(script.contains("workspace"));
+        assert!(script.contains("--org"));
+    }
+
+    #[test]
+    fn test_bash_completion_calls_complete_for_names() {
+        let script = completion_script(Shell::Bash);
+        assert!(script.contains("hcpctl __complete org \"$cur\""));
+        assert!(script.contains("hcpctl __complete ws ${org:+--org \"$org\"}"));
+        assert!(script
+            .trim_end()
+            .ends_with("complete -F _hcpctl_live_names -o bashdefault -o default hcpctl"));
+    }
+
+    #[test]
+    fn test_zsh_completion_uses_live_name_helpers() {
+        let script = completion_script(Shell::Zsh);
+        assert!(!script.contains(":ORG:_default'"));
+        assert!(script.contains(":ORG:_hcpctl_orgs'"));
+        assert!(script.contains(":WORKSPACE:_hcpctl_workspaces'"));
+        let helpers = script.find("_hcpctl_orgs() {").unwrap();
+        let dispatch = script.rfind("if [ \"$funcstack[1]\"").unwrap();
+        assert!(helpers < dispatch);
+    }
+}
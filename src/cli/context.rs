@@ -1,7 +1,11 @@
 //! Config management CLI arguments (kubectl-style)
 
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand};
 
+use super::{ColorChoice, OutputFormat};
+
 /// Config subcommands for managing connection contexts
 #[derive(Subcommand, Debug)]
 pub enum ConfigAction {
@@ -17,16 +21,26 @@ pub enum ConfigAction {
     #[command(name = "get-contexts")]
     GetContexts,
 
-    /// Display the current-context
-    #[command(name = "current-context")]
+    /// Display the active context (--context, HCPCTL_CONTEXT or current-context)
+    #[command(name = "current-context", visible_alias = "current")]
     CurrentContext,
 
+    /// Rename a context, keeping it active if it was
+    #[command(name = "rename-context", visible_alias = "rename")]
+    RenameContext(RenameContextArgs),
+
     /// Delete the specified context from the config file
     #[command(name = "delete-context")]
     DeleteContext(DeleteContextArgs),
 
     /// Display config file contents
     View,
+
+    /// Write all contexts to a portable file (tokens redacted by default)
+    Export(ExportContextsArgs),
+
+    /// Merge contexts from a file written by 'config export'
+    Import(ImportContextsArgs),
 }
 
 /// Arguments for 'config set-context' subcommand
@@ -47,6 +61,12 @@ pub struct SetContextArgs {
     /// Default organization
     #[arg(long)]
     pub org: Option<String>,
+    /// Default output format for commands run in this context (an explicit -o wins)
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub default_output: Option<OutputFormat>,
+    /// Default color policy for this context (an explicit --color/--no-color wins)
+    #[arg(long, value_enum, value_name = "WHEN")]
+    pub default_color: Option<ColorChoice>,
 }
 
 /// Arguments for 'config use-context' subcommand
@@ -56,6 +76,39 @@ pub struct UseContextArgs {
     pub name: String,
 }
 
+/// Arguments for 'config export' subcommand
+#[derive(Parser, Debug)]
+#[command(after_help = "EXAMPLES:\n  \
+        - hcpctl config export --file contexts.json\n  \
+        - hcpctl config export --include-tokens > contexts.json   # keep secret!")]
+pub struct ExportContextsArgs {
+    /// File to write (default: stdout)
+    #[arg(long)]
+    pub file: Option<PathBuf>,
+    /// Include API tokens in the export instead of redacting them
+    #[arg(long, default_value_t = false)]
+    pub include_tokens: bool,
+}
+
+/// Arguments for 'config import' subcommand
+#[derive(Parser, Debug)]
+pub struct ImportContextsArgs {
+    /// File written by 'hcpctl config export'
+    pub file: PathBuf,
+    /// Replace existing contexts with the same name (default: skip them)
+    #[arg(long, default_value_t = false)]
+    pub overwrite: bool,
+}
+
+/// Arguments for 'config rename-context' subcommand
+#[derive(Parser, Debug)]
+pub struct RenameContextArgs {
+    /// Existing context name
+    pub old: String,
+    /// New context name
+    pub new: String,
+}
+
 /// Arguments for 'config delete-context' subcommand
 #[derive(Parser, Debug)]
 pub struct DeleteContextArgs {
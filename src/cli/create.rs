@@ -0,0 +1,46 @@
+//! Create command resource definitions and arguments
+
+use clap::{Parser, Subcommand};
+
+use super::enums::VcsProvider;
+
+/// Resource types for the 'create' command
+#[derive(Subcommand, Debug)]
+pub enum CreateResource {
+    /// Create an OAuth client (VCS connection)
+    #[command(
+        visible_alias = "oauth-client",
+        visible_alias = "oauth-clients",
+        visible_alias = "oauthclient",
+        visible_alias = "oauthclients"
+    )]
+    Oc(CreateOcArgs),
+}
+
+/// Arguments for 'create oc' subcommand
+#[derive(Parser, Debug)]
+pub struct CreateOcArgs {
+    /// Organization name
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// VCS service provider
+    #[arg(long, value_enum)]
+    pub service_provider: VcsProvider,
+
+    /// VCS API URL (defaults to the public endpoint for hosted providers)
+    #[arg(long)]
+    pub api_url: Option<String>,
+
+    /// VCS web URL (defaults to the public endpoint for hosted providers)
+    #[arg(long)]
+    pub http_url: Option<String>,
+
+    /// Personal access token used by the connection (never echoed)
+    #[arg(long, env = "HCPCTL_OAUTH_TOKEN_STRING", hide_env_values = true)]
+    pub oauth_token_string: String,
+
+    /// Display name for the OAuth client
+    #[arg(long)]
+    pub name: Option<String>,
+}
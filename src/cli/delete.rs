@@ -13,6 +13,15 @@ pub enum DeleteResource {
     )]
     OrgMember(DeleteOrgMemberArgs),
 
+    /// Delete an OAuth client (VCS connection)
+    #[command(
+        visible_alias = "oauth-client",
+        visible_alias = "oauth-clients",
+        visible_alias = "oauthclient",
+        visible_alias = "oauthclients"
+    )]
+    Oc(DeleteOcArgs),
+
     /// Delete tag bindings from a workspace or project
     #[command(visible_alias = "tags")]
     Tag {
@@ -39,3 +48,22 @@ pub struct DeleteOrgMemberArgs {
     #[arg(short = 'y', long, default_value_t = false)]
     pub yes: bool,
 }
+
+/// Arguments for 'delete oc' subcommand
+#[derive(Parser, Debug)]
+pub struct DeleteOcArgs {
+    /// OAuth client name or ID (oc-xxx)
+    pub name: String,
+
+    /// Organization name (required when argument is a name)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// List workspaces connected through this OAuth client before confirming
+    #[arg(long, default_value_t = false)]
+    pub show_workspaces: bool,
+
+    /// Skip confirmation prompt
+    #[arg(short = 'y', long, default_value_t = false)]
+    pub yes: bool,
+}
@@ -57,4 +57,10 @@ pub struct DownloadConfigArgs {
     /// Output file path (default: configuration-{cv_id}.tar.gz)
     #[arg(long)]
     pub output: Option<PathBuf>,
+
+    /// Directory to save the archive in (created if missing; default: current directory)
+    ///
+    /// A relative --output path is placed inside this directory.
+    #[arg(long, verbatim_doc_comment)]
+    pub output_dir: Option<PathBuf>,
 }
@@ -1,6 +1,49 @@
 //! Sort and subresource enums for CLI commands
 
 use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// When to color output (`--color`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorChoice {
+    /// Color when writing to a terminal, not in --batch mode and NO_COLOR is unset
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
+impl std::fmt::Display for ColorChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorChoice::Auto => write!(f, "auto"),
+            ColorChoice::Always => write!(f, "always"),
+            ColorChoice::Never => write!(f, "never"),
+        }
+    }
+}
+
+/// Graph syntax for the 'graph' command (`--format`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum GraphFormat {
+    /// Graphviz DOT (render with e.g. `dot -Tsvg`)
+    #[default]
+    Dot,
+    /// Mermaid flowchart (renders in GitHub/GitLab Markdown)
+    Mermaid,
+}
+
+impl std::fmt::Display for GraphFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphFormat::Dot => write!(f, "dot"),
+            GraphFormat::Mermaid => write!(f, "mermaid"),
+        }
+    }
+}
 
 /// Sort field options for projects
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -25,7 +68,10 @@ impl std::fmt::Display for PrjSortField {
 pub enum WsSortField {
     /// Sort by workspace name (default)
     Name,
-    /// Sort by resource count
+    /// Sort by organization name
+    Org,
+    /// Sort by resource count (alias: size)
+    #[value(alias = "size")]
     Resources,
     /// Sort by last update time
     UpdatedAt,
@@ -39,6 +85,7 @@ impl std::fmt::Display for WsSortField {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             WsSortField::Name => write!(f, "name"),
+            WsSortField::Org => write!(f, "org"),
             WsSortField::Resources => write!(f, "resources"),
             WsSortField::UpdatedAt => write!(f, "updated-at"),
             WsSortField::TfVersion => write!(f, "tf-version"),
@@ -70,6 +117,28 @@ impl std::fmt::Display for RunSortField {
     }
 }
 
+/// Run fields that can be counted with --count-by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RunCountField {
+    /// Run status
+    Status,
+    /// Run source (tfe-api, tfe-ui, terraform, ...)
+    Source,
+    /// Workspace ID
+    #[value(name = "ws-id")]
+    WsId,
+}
+
+impl std::fmt::Display for RunCountField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunCountField::Status => write!(f, "status"),
+            RunCountField::Source => write!(f, "source"),
+            RunCountField::WsId => write!(f, "ws-id"),
+        }
+    }
+}
+
 /// Sort field options for team-project access
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum TeamAccessSortField {
@@ -91,6 +160,24 @@ impl std::fmt::Display for TeamAccessSortField {
     }
 }
 
+/// Workspace variable categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum VarCategory {
+    /// Terraform input variables
+    Terraform,
+    /// Environment variables
+    Env,
+}
+
+impl std::fmt::Display for VarCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VarCategory::Terraform => write!(f, "terraform"),
+            VarCategory::Env => write!(f, "env"),
+        }
+    }
+}
+
 /// Run subresources that can be fetched
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum RunSubresource {
@@ -100,6 +187,101 @@ pub enum RunSubresource {
     Plan,
     /// Apply details with log access
     Apply,
+    /// Configuration version the run was created from (configuration-version)
+    Config,
+    /// State version(s) produced by the run's apply (apply -> state-versions)
+    State,
+    /// Cost estimate: prior/proposed monthly cost and delta (cost-estimate)
+    CostEstimate,
+    /// Sentinel/OPA policy results per policy (policy-checks)
+    PolicyChecks,
+}
+
+/// VCS service providers supported by 'create oc' (token-based connections)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum VcsProvider {
+    /// GitHub.com
+    Github,
+    /// GitHub Enterprise (self-hosted)
+    GithubEnterprise,
+    /// GitLab.com
+    GitlabHosted,
+    /// GitLab Community Edition (self-hosted)
+    GitlabCommunityEdition,
+    /// GitLab Enterprise Edition (self-hosted)
+    GitlabEnterpriseEdition,
+    /// Azure DevOps Services
+    AdoServices,
+    /// Azure DevOps Server (self-hosted)
+    AdoServer,
+}
+
+impl VcsProvider {
+    /// Value of the `service-provider` API attribute
+    pub fn api_value(&self) -> &'static str {
+        match self {
+            VcsProvider::Github => "github",
+            VcsProvider::GithubEnterprise => "github_enterprise",
+            VcsProvider::GitlabHosted => "gitlab_hosted",
+            VcsProvider::GitlabCommunityEdition => "gitlab_community_edition",
+            VcsProvider::GitlabEnterpriseEdition => "gitlab_enterprise_edition",
+            VcsProvider::AdoServices => "ado_services",
+            VcsProvider::AdoServer => "ado_server",
+        }
+    }
+
+    /// Public (api-url, http-url) for hosted providers; `None` for self-hosted ones
+    pub fn default_urls(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            VcsProvider::Github => Some(("https://api.github.com", "https://github.com")),
+            VcsProvider::GitlabHosted => Some(("https://gitlab.com/api/v4", "https://gitlab.com")),
+            VcsProvider::AdoServices => Some(("https://dev.azure.com", "https://dev.azure.com")),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for VcsProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VcsProvider::Github => write!(f, "github"),
+            VcsProvider::GithubEnterprise => write!(f, "github-enterprise"),
+            VcsProvider::GitlabHosted => write!(f, "gitlab-hosted"),
+            VcsProvider::GitlabCommunityEdition => write!(f, "gitlab-community-edition"),
+            VcsProvider::GitlabEnterpriseEdition => write!(f, "gitlab-enterprise-edition"),
+            VcsProvider::AdoServices => write!(f, "ado-services"),
+            VcsProvider::AdoServer => write!(f, "ado-server"),
+        }
+    }
+}
+
+/// Workspace fields that can be counted with --count-by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum WsCountField {
+    /// Execution mode (remote, local, agent)
+    #[value(alias = "execution_mode")]
+    ExecutionMode,
+    /// Terraform version
+    #[value(alias = "tf_version")]
+    TfVersion,
+    /// Locked state
+    Locked,
+    /// Project (name with --with-prj, otherwise ID)
+    Project,
+    /// Organization
+    Org,
+}
+
+impl std::fmt::Display for WsCountField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WsCountField::ExecutionMode => write!(f, "execution-mode"),
+            WsCountField::TfVersion => write!(f, "tf-version"),
+            WsCountField::Locked => write!(f, "locked"),
+            WsCountField::Project => write!(f, "project"),
+            WsCountField::Org => write!(f, "org"),
+        }
+    }
 }
 
 /// Workspace subresources that can be fetched
@@ -128,6 +310,13 @@ mod tests {
         assert_eq!(WsSortField::PendingRuns.to_string(), "pending-runs");
     }
 
+    #[test]
+    fn test_ws_count_field_display() {
+        assert_eq!(WsCountField::ExecutionMode.to_string(), "execution-mode");
+        assert_eq!(WsCountField::TfVersion.to_string(), "tf-version");
+        assert_eq!(WsCountField::Project.to_string(), "project");
+    }
+
     #[test]
     fn test_prj_sort_field_display() {
         assert_eq!(PrjSortField::Name.to_string(), "name");
@@ -1,9 +1,14 @@
 //! Get command resource definitions and arguments
 
+use std::path::PathBuf;
+
 use clap::{builder::ArgPredicate, Parser, Subcommand};
 
 use super::common::OutputFormat;
-use super::enums::{PrjSortField, RunSortField, RunSubresource, WsSortField, WsSubresource};
+use super::enums::{
+    PrjSortField, RunCountField, RunSortField, RunSubresource, VarCategory, WsCountField,
+    WsSortField, WsSubresource,
+};
 
 /// Resource types for the 'get' command
 #[derive(Subcommand, Debug)]
@@ -37,7 +42,7 @@ pub enum GetResource {
     )]
     Oc(OcArgs),
 
-    /// Get runs (active runs by default - non_final states)
+    /// Get runs (active runs by default - non_final states; --final/--all for completed)
     #[command(visible_alias = "runs")]
     Run(RunArgs),
 
@@ -45,11 +50,13 @@ pub enum GetResource {
     #[command(visible_alias = "teams")]
     Team(TeamArgs),
 
-    /// Get organization members
+    /// Get organization members (email, invite status, team count)
     #[command(
         visible_alias = "org-members",
         visible_alias = "orgmember",
-        visible_alias = "orgmembers"
+        visible_alias = "orgmembers",
+        visible_alias = "member",
+        visible_alias = "members"
     )]
     OrgMember(OrgMemberArgs),
 
@@ -65,6 +72,78 @@ pub enum GetResource {
     /// Get tags (org-level, workspace, or project)
     #[command(visible_alias = "tags")]
     Tag(super::tag::GetTagArgs),
+
+    /// Get workspace variables (Terraform and environment)
+    #[command(
+        visible_alias = "vars",
+        visible_alias = "variable",
+        visible_alias = "variables"
+    )]
+    Var(VarArgs),
+
+    /// Get variable sets in an organization or applied to a project
+    #[command(
+        visible_alias = "varsets",
+        visible_alias = "variable-set",
+        visible_alias = "variable-sets"
+    )]
+    Varset(VarsetArgs),
+
+    /// Get Sentinel/OPA policy sets in an organization
+    #[command(
+        visible_alias = "policy-sets",
+        visible_alias = "policyset",
+        visible_alias = "ps"
+    )]
+    PolicySet(PolicySetArgs),
+
+    /// Get workspace notification configurations (destinations and triggers)
+    #[command(
+        visible_alias = "notification-configs",
+        visible_alias = "notification-configuration",
+        visible_alias = "notification-configurations",
+        visible_alias = "nc"
+    )]
+    NotificationConfig(NotificationConfigArgs),
+
+    /// Get run triggers: source workspaces whose applies queue runs in a workspace
+    #[command(
+        visible_alias = "run-triggers",
+        visible_alias = "runtrigger",
+        visible_alias = "runtriggers",
+        visible_alias = "rt"
+    )]
+    RunTrigger(RunTriggerArgs),
+
+    /// Get modules of the organization's private module registry
+    #[command(
+        visible_alias = "registry-modules",
+        visible_alias = "module",
+        visible_alias = "modules",
+        visible_alias = "rm"
+    )]
+    RegistryModule(RegistryModuleArgs),
+
+    /// Get health assessment results (drift) of workspaces in an organization
+    #[command(
+        visible_alias = "assessments",
+        visible_alias = "drift",
+        visible_alias = "asmt"
+    )]
+    Assessment(AssessmentArgs),
+
+    /// Get state versions of a workspace, or the outputs of one state version
+    #[command(
+        visible_alias = "states",
+        visible_alias = "state-version",
+        visible_alias = "state-versions",
+        visible_alias = "sv"
+    )]
+    State(StateArgs),
+
+    /// Get Terraform outputs from a workspace's current state version
+    #[command(visible_alias = "outputs")]
+    Output(OutputArgs),
 }
 
 /// Arguments for 'get org' subcommand
@@ -82,6 +161,219 @@ pub struct OrgArgs {
     pub output: OutputFormat,
 }
 
+/// Arguments for 'get var' subcommand
+#[derive(Parser, Debug)]
+pub struct VarArgs {
+    /// Variable key (if specified, shows only that variable)
+    pub name: Option<String>,
+
+    /// Workspace name or ID (ws-xxx)
+    #[arg(long = "ws", value_name = "WORKSPACE")]
+    pub workspace: String,
+
+    /// Organization name (needed to look up a workspace by name; falls back to context)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Only show variables of this category
+    #[arg(long, value_enum)]
+    pub category: Option<VarCategory>,
+
+    /// Output format (sensitive values are shown as *** in table/CSV and omitted from JSON/YAML)
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+}
+
+/// Arguments for 'get varset' subcommand
+#[derive(Parser, Debug)]
+pub struct VarsetArgs {
+    /// Variable set name or ID (varset-xxx); shows attached workspaces and projects
+    pub name: Option<String>,
+
+    /// Organization name (required)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Only list variable sets applied to this project (name or ID)
+    #[arg(long, conflicts_with = "name")]
+    pub prj: Option<String>,
+
+    /// Filter variable sets by name (substring match)
+    #[arg(short, long)]
+    pub filter: Option<String>,
+
+    /// Output format (defaults to yaml when a variable set name or ID is given)
+    #[arg(
+        short = 'o',
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Table,
+        default_value_if("name", ArgPredicate::IsPresent, "yaml")
+    )]
+    pub output: OutputFormat,
+}
+
+/// Arguments for 'get policy-set' subcommand
+#[derive(Parser, Debug)]
+pub struct PolicySetArgs {
+    /// Policy set name or ID (polset-xxx); shows attached workspaces and projects
+    pub name: Option<String>,
+
+    /// Organization name (required)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Filter policy sets by name (substring match)
+    #[arg(short, long)]
+    pub filter: Option<String>,
+
+    /// Output format (defaults to yaml when a policy set name or ID is given)
+    #[arg(
+        short = 'o',
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Table,
+        default_value_if("name", ArgPredicate::IsPresent, "yaml")
+    )]
+    pub output: OutputFormat,
+}
+
+/// Arguments for 'get notification-config' subcommand
+#[derive(Parser, Debug)]
+pub struct NotificationConfigArgs {
+    /// Notification configuration ID (nc-xxx); shows all triggers and the redacted URL
+    #[arg(required_unless_present = "workspace")]
+    pub id: Option<String>,
+
+    /// Workspace name or ID (ws-xxx) whose notification configurations to list
+    #[arg(long = "ws", value_name = "WORKSPACE", conflicts_with = "id")]
+    pub workspace: Option<String>,
+
+    /// Organization name (needed to look up a workspace by name; falls back to context)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Output format (defaults to yaml when a notification configuration ID is given)
+    #[arg(
+        short = 'o',
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Table,
+        default_value_if("id", ArgPredicate::IsPresent, "yaml")
+    )]
+    pub output: OutputFormat,
+}
+
+/// Arguments for 'get run-trigger' subcommand
+#[derive(Parser, Debug)]
+pub struct RunTriggerArgs {
+    /// Workspace name or ID (ws-xxx) whose source workspaces to list
+    #[arg(long = "ws", value_name = "WORKSPACE")]
+    pub workspace: String,
+
+    /// Organization name (needed to look up a workspace by name; falls back to context)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Output format (JSON/YAML emit a source → target edge list)
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+}
+
+/// Arguments for 'get registry-module' subcommand
+#[derive(Parser, Debug)]
+pub struct RegistryModuleArgs {
+    /// Module name; shows all published versions
+    pub name: Option<String>,
+
+    /// Organization name (required)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Filter modules by name (substring match)
+    #[arg(short, long, conflicts_with = "name")]
+    pub filter: Option<String>,
+
+    /// Only modules for this provider (e.g. aws); picks the module when a
+    /// name exists for several providers
+    #[arg(long)]
+    pub provider: Option<String>,
+
+    /// Output format (defaults to yaml when a module name is given)
+    #[arg(
+        short = 'o',
+        long,
+        value_enum,
+        default_value_t = OutputFormat::Table,
+        default_value_if("name", ArgPredicate::IsPresent, "yaml")
+    )]
+    pub output: OutputFormat,
+}
+
+/// Arguments for 'get assessment' subcommand
+#[derive(Parser, Debug)]
+pub struct AssessmentArgs {
+    /// Organization name (required)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Filter workspaces by name (substring match)
+    #[arg(short, long)]
+    pub filter: Option<String>,
+
+    /// Output format
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+}
+
+/// Arguments for 'get state' subcommand
+#[derive(Parser, Debug)]
+pub struct StateArgs {
+    /// State version ID (sv-xxx); shows the Terraform outputs it recorded
+    #[arg(required_unless_present = "workspace")]
+    pub id: Option<String>,
+
+    /// Workspace name or ID (ws-xxx) whose state versions to list
+    #[arg(long = "ws", value_name = "WORKSPACE", conflicts_with = "id")]
+    pub workspace: Option<String>,
+
+    /// Organization name (needed to look up a workspace by name; falls back to context)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Fetch all state versions (default: 24 most recent)
+    #[arg(long, conflicts_with = "id")]
+    pub all: bool,
+
+    /// Output format (sensitive output values are shown as *** in table/CSV and omitted
+    /// from JSON/YAML)
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+}
+
+/// Arguments for 'get output' subcommand
+#[derive(Parser, Debug)]
+pub struct OutputArgs {
+    /// Output name (if specified, shows only that output)
+    pub name: Option<String>,
+
+    /// Workspace name or ID (ws-xxx)
+    #[arg(long = "ws", value_name = "WORKSPACE")]
+    pub workspace: String,
+
+    /// Organization name (needed to look up a workspace by name; falls back to context)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Include sensitive values in JSON/YAML output (always *** in table/CSV)
+    #[arg(long, default_value_t = false)]
+    pub show_sensitive: bool,
+
+    /// Output format (sensitive values are omitted from JSON/YAML unless --show-sensitive)
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+}
+
 /// Arguments for 'get team' subcommand
 #[derive(Parser, Debug)]
 pub struct TeamArgs {
@@ -96,6 +388,14 @@ pub struct TeamArgs {
     #[arg(short, long)]
     pub filter: Option<String>,
 
+    /// List the team's members (usernames and emails) instead of the team
+    #[arg(long, default_value_t = false, requires = "name")]
+    pub members: bool,
+
+    /// Add a MEMBERS column with member usernames to the team list
+    #[arg(long, default_value_t = false, conflicts_with = "name")]
+    pub with_members: bool,
+
     /// Output format
     #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
     pub output: OutputFormat,
@@ -142,9 +442,9 @@ pub struct PrjArgs {
     #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
     pub output: OutputFormat,
 
-    /// Sort results by field
-    #[arg(short, long, value_enum, default_value_t = PrjSortField::Name)]
-    pub sort: PrjSortField,
+    /// Sort results by field; comma-separated fields are applied in priority order
+    #[arg(short, long, value_enum, value_delimiter = ',', default_value = "name")]
+    pub sort: Vec<PrjSortField>,
 
     /// Reverse sort order (descending)
     #[arg(short = 'r', long, default_value_t = false)]
@@ -154,7 +454,7 @@ pub struct PrjArgs {
     #[arg(long, default_value_t = false)]
     pub no_group_org: bool,
 
-    /// Include workspace information (count, names, IDs)
+    /// Include workspace information (count, names, IDs; nested objects in JSON/YAML)
     #[arg(long, default_value_t = false)]
     pub with_ws: bool,
 
@@ -185,10 +485,17 @@ pub struct WsArgs {
     #[arg(short, long)]
     pub prj: Option<String>,
 
-    /// Filter workspaces by name (substring match)
+    /// Filter workspaces by name (substring match). Sent to the API as
+    /// search[name] so only matching pages are fetched
     #[arg(short, long)]
     pub filter: Option<String>,
 
+    /// Filter by lifecycle state (e.g. active, archived). Requires a TFE/HCP
+    /// Terraform version that reports the workspace `state` attribute; ignored
+    /// with a warning otherwise
+    #[arg(long, value_name = "STATE")]
+    pub state: Option<String>,
+
     /// Output format (defaults to yaml when --subresource is used)
     #[arg(
         short = 'o',
@@ -199,9 +506,10 @@ pub struct WsArgs {
     )]
     pub output: OutputFormat,
 
-    /// Sort results by field
-    #[arg(short, long, value_enum, default_value_t = WsSortField::Name)]
-    pub sort: WsSortField,
+    /// Sort results by field; comma-separated fields are applied in priority order
+    /// (e.g. org,resources)
+    #[arg(short, long, value_enum, value_delimiter = ',', default_value = "name")]
+    pub sort: Vec<WsSortField>,
 
     /// Reverse sort order (descending)
     #[arg(short = 'r', long, default_value_t = false)]
@@ -223,10 +531,56 @@ pub struct WsArgs {
     #[arg(long, default_value_t = false)]
     pub resources_summary: bool,
 
+    /// Instead of listing workspaces, count them per distinct value of a field
+    /// (JSON/YAML: object of value → count)
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FIELD",
+        conflicts_with = "resources_summary"
+    )]
+    pub count_by: Option<WsCountField>,
+
     /// Show billable RUM count column (fetches current-state-version per workspace)
     #[arg(long, default_value_t = false)]
     pub billable: bool,
 
+    /// Add a project name column next to the project ID, for flat CSV/JSON exports
+    /// (fetches the project list once per organization)
+    #[arg(long, default_value_t = false)]
+    pub with_prj: bool,
+
+    /// Compare the listing against a saved `get ws -o json` export and report
+    /// added, removed and changed workspaces (matched by ID)
+    #[arg(
+        long,
+        value_name = "BASELINE",
+        conflicts_with_all = ["name", "count_by", "resources_summary", "has_pending_runs"]
+    )]
+    pub diff: Option<PathBuf>,
+
+    /// Show workspace settings columns (auto-apply, queue-all-runs, speculative,
+    /// global-remote-state, allow-destroy-plan)
+    #[arg(long, default_value_t = false)]
+    pub wide: bool,
+
+    /// Comma-separated columns to show in table/CSV output, in order
+    /// (e.g. name,id,resources,updated-at). JSON/YAML are unaffected.
+    /// Columns: org, project-id, project-name, name, id, resources, billable,
+    /// execution-mode, locked, tf-version, updated-at, vcs-repo,
+    /// working-directory, pending-runs (empty when not VCS-backed)
+    #[arg(long, value_name = "COLS")]
+    pub columns: Option<String>,
+
+    /// Flatten JSON output into one object per workspace with dotted keys
+    /// (e.g. attributes.resource-count), for BI tools expecting flat records
+    #[arg(long, default_value_t = false)]
+    pub flatten: bool,
+
+    /// Format resource counts with thousands separators (e.g. 12,345) in table output
+    #[arg(long, default_value_t = false)]
+    pub human: bool,
+
     /// List recent runs with phase durations for this workspace
     #[arg(long)]
     pub runs: bool,
@@ -271,6 +625,10 @@ pub struct OcArgs {
     #[arg(short, long)]
     pub filter: Option<String>,
 
+    /// Fetch each client's OAuth tokens (ID, service-provider user, created-at)
+    #[arg(long, default_value_t = false)]
+    pub with_tokens: bool,
+
     /// Output format
     #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
     pub output: OutputFormat,
@@ -278,42 +636,107 @@ pub struct OcArgs {
 
 /// Arguments for 'get run' subcommand
 ///
-/// Lists only active (non-final) runs. Use --status to filter by specific statuses.
+/// Lists active (non-final) runs by default; --final and --all widen the status
+/// group. Use --status to filter by specific statuses within it.
 #[derive(Parser, Debug, Clone)]
 #[command(
-    after_help = "NOTE: This command shows only active (non-final) runs.\n\
-                        Use --status to filter by specific non-final statuses (e.g. planning,applying).\n\
-                        Completed runs (applied, errored, canceled) are not shown."
+    after_help = "NOTE: By default this command shows only active (non-final) runs.\n\
+                        Use --final for completed runs (applied, errored, canceled, ...) or --all for both.\n\
+                        --status filters within the selected group (e.g. planning,applying; with --all\n\
+                        or --final also applied,errored)."
 )]
 pub struct RunArgs {
     /// Run ID (if specified, shows details for that run)
     pub name: Option<String>,
 
+    /// More run IDs; with --subresource plan|apply --get-log their logs are
+    /// fetched concurrently and printed in order under a run ID header
+    #[arg(value_name = "RUN_ID", requires = "get_log")]
+    pub more_run_ids: Vec<String>,
+
     /// Organization name (lists runs across org workspaces)
     #[arg(long = "org", conflicts_with = "ws")]
     pub org: Option<String>,
 
-    /// Workspace ID (lists runs for specific workspace, must start with ws-)
+    /// Workspace ID (lists runs for specific workspace, must start with ws-).
+    /// With --current, a workspace name is also accepted
     #[arg(long = "ws", conflicts_with = "org")]
     pub ws: Option<String>,
 
+    /// Print only the workspace's current run status and ID (requires --ws).
+    /// Exits non-zero unless the run finished successfully
+    #[arg(
+        long,
+        default_value_t = false,
+        requires = "ws",
+        conflicts_with = "name"
+    )]
+    pub current: bool,
+
+    /// With --current, block until the current run reaches a final state
+    #[arg(long, default_value_t = false, requires = "current")]
+    pub wait: bool,
+
+    /// With --wait, give up and exit non-zero if the run is not final after this many seconds
+    #[arg(long, value_name = "SECONDS", requires = "wait")]
+    pub wait_timeout: Option<u64>,
+
     /// Filter by workspace names (comma-separated, only with --org)
     #[arg(long = "workspace-names", requires = "org")]
     pub workspace_names: Option<String>,
 
-    /// Filter by specific non-final run statuses (comma-separated).
-    /// Valid values: pending, fetching, queuing, plan_queued, planning, planned,
+    /// Filter by specific run statuses (comma-separated).
+    /// Non-final: pending, fetching, queuing, plan_queued, planning, planned,
     /// cost_estimating, cost_estimated, policy_checking, policy_override,
     /// policy_soft_failed, policy_checked, confirmed, post_plan_running,
-    /// post_plan_completed, applying, apply_queued
+    /// post_plan_completed, applying, apply_queued.
+    /// Final (with --final or --all): applied, discarded, errored, canceled,
+    /// force_canceled, planned_and_finished, planned_and_saved
     #[arg(long)]
     pub status: Option<String>,
 
+    /// Show completed runs (final statuses) instead of active ones
+    #[arg(
+        long = "final",
+        default_value_t = false,
+        conflicts_with_all = ["all", "current"]
+    )]
+    pub final_only: bool,
+
+    /// Show runs in any status, active and completed
+    #[arg(long, default_value_t = false, conflicts_with = "current")]
+    pub all: bool,
+
+    /// Only show runs awaiting apply confirmation
+    #[arg(long, default_value_t = false)]
+    pub confirmable: bool,
+
+    /// Instead of listing runs, count them per distinct value of a field
+    /// (JSON/YAML: object of value → count)
+    #[arg(long, value_enum, value_name = "FIELD", conflicts_with = "name")]
+    pub count_by: Option<RunCountField>,
+
+    /// Only show runs created after the given run (cursor for incremental polling)
+    #[arg(long, value_name = "RUN_ID", conflicts_with = "name")]
+    pub newer_than: Option<String>,
+
+    /// Re-fetch and redraw the listing every SECONDS (default: 5) until Ctrl+C.
+    /// Not available with --batch or --output-file
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        num_args = 0..=1,
+        default_missing_value = "5",
+        conflicts_with_all = ["name", "current"]
+    )]
+    pub watch: Option<u64>,
+
     /// Output format
     #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
     pub output: OutputFormat,
 
-    /// Fetch a related subresource (events, plan, apply). Requires run ID.
+    /// Fetch a subresource (events, plan, apply, config, state, cost-estimate, policy-checks).
+    /// Requires run ID.
     #[arg(long, value_enum, requires = "name")]
     pub subresource: Option<RunSubresource>,
 
@@ -329,9 +752,16 @@ pub struct RunArgs {
     #[arg(long, default_value_t = false)]
     pub raw: bool,
 
-    /// Sort results by field (default: created-at, newest first)
-    #[arg(short, long, value_enum, default_value_t = RunSortField::CreatedAt)]
-    pub sort: RunSortField,
+    /// Sort results by field (default: created-at, newest first); comma-separated
+    /// fields are applied in priority order
+    #[arg(
+        short,
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "created-at"
+    )]
+    pub sort: Vec<RunSortField>,
 
     /// Reverse sort order
     #[arg(short = 'r', long, default_value_t = false)]
@@ -0,0 +1,17 @@
+//! Graph command arguments
+
+use clap::Parser;
+
+use super::enums::GraphFormat;
+
+/// Arguments for 'graph' command
+#[derive(Parser, Debug)]
+pub struct GraphArgs {
+    /// Organization name (required)
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Graph syntax to emit
+    #[arg(long, value_enum, default_value_t = GraphFormat::Dot)]
+    pub format: GraphFormat,
+}
@@ -0,0 +1,29 @@
+//! Lock/unlock command arguments
+
+use clap::Parser;
+
+/// Arguments for 'lock' command
+#[derive(Parser, Debug)]
+pub struct LockArgs {
+    /// Workspace name or ID (ws-xxx)
+    pub workspace: String,
+
+    /// Organization name (searches all organizations if omitted)
+    #[arg(short = 'O', long)]
+    pub org: Option<String>,
+
+    /// Reason for the lock, shown to other users while it is held
+    #[arg(long)]
+    pub reason: Option<String>,
+}
+
+/// Arguments for 'unlock' command
+#[derive(Parser, Debug)]
+pub struct UnlockArgs {
+    /// Workspace name or ID (ws-xxx)
+    pub workspace: String,
+
+    /// Organization name (searches all organizations if omitted)
+    #[arg(short = 'O', long)]
+    pub org: Option<String>,
+}
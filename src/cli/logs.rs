@@ -22,7 +22,7 @@ pub struct LogsArgs {
     pub apply: bool,
 
     /// Follow log output in real-time until completion (like tail -f)
-    #[arg(short = 'f', long, default_value_t = false)]
+    #[arg(short = 'f', long, visible_alias = "tail", default_value_t = false)]
     pub follow: bool,
 
     /// Output raw log without parsing (default: extract @message from JSON lines)
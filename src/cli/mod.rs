@@ -7,36 +7,58 @@
 //! - hcpctl purge state <ws-id>      - purge all resources from workspace state
 //! - hcpctl download config <ws>     - download workspace configuration
 
+mod cache;
 mod common;
+mod completion;
 mod context;
+mod create;
 mod delete;
 mod download;
 mod enums;
 mod get;
+mod graph;
 mod invite;
+mod lock;
 mod logs;
 mod purge;
+mod run;
 mod set;
 mod tag;
 mod team_access;
 mod watch;
+mod whoami;
 
-use clap::{Parser, Subcommand};
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Parser, Subcommand};
 
-use crate::config::defaults;
+use crate::config::{api, cache as cache_config, defaults};
 
 // Re-export all types for public API
+pub use cache::CacheAction;
 pub use common::OutputFormat;
-pub use context::{ConfigAction, DeleteContextArgs, SetContextArgs, UseContextArgs};
-pub use delete::{DeleteOrgMemberArgs, DeleteResource};
+pub use completion::{completion_script, CompleteArgs, CompleteResource, CompletionArgs};
+pub use context::{
+    ConfigAction, DeleteContextArgs, ExportContextsArgs, ImportContextsArgs, RenameContextArgs,
+    SetContextArgs, UseContextArgs,
+};
+pub use create::{CreateOcArgs, CreateResource};
+pub use delete::{DeleteOcArgs, DeleteOrgMemberArgs, DeleteResource};
 pub use download::{DownloadConfigArgs, DownloadResource};
 pub use enums::{
-    PrjSortField, RunSortField, RunSubresource, TeamAccessSortField, WsSortField, WsSubresource,
+    ColorChoice, GraphFormat, PrjSortField, RunCountField, RunSortField, RunSubresource,
+    TeamAccessSortField, VarCategory, VcsProvider, WsCountField, WsSortField, WsSubresource,
+};
+pub use get::{
+    AssessmentArgs, GetResource, NotificationConfigArgs, OcArgs, OrgArgs, OrgMemberArgs,
+    OutputArgs, PolicySetArgs, PrjArgs, RegistryModuleArgs, RunArgs, RunTriggerArgs, StateArgs,
+    TeamArgs, VarArgs, VarsetArgs, WsArgs,
 };
-pub use get::{GetResource, OcArgs, OrgArgs, OrgMemberArgs, PrjArgs, RunArgs, TeamArgs, WsArgs};
+pub use graph::GraphArgs;
 pub use invite::InviteArgs;
+pub use lock::{LockArgs, UnlockArgs};
 pub use logs::LogsArgs;
 pub use purge::{PurgeResource, PurgeRunArgs, PurgeStateArgs};
+pub use run::{ApplyRunArgs, OverrideRunArgs, RunAction};
 pub use set::{SetResource, SetWsArgs};
 pub use tag::{
     classify_tags, parse_tags, DeleteTagPrjArgs, DeleteTagResource, DeleteTagWsArgs, GetTagArgs,
@@ -44,6 +66,7 @@ pub use tag::{
 };
 pub use team_access::TeamAccessArgs;
 pub use watch::{WatchResource, WatchWsArgs};
+pub use whoami::WhoamiArgs;
 
 const AFTER_LONG_HELP: &str = r#"HOST RESOLUTION:
 
@@ -76,7 +99,7 @@ CONTEXT:
   Resolution (first match wins):
 
     - Host:  -H flag → TFE_HOSTNAME env → context → credentials file
-    - Token: -t flag → HCP_TOKEN/TFC_TOKEN/TFE_TOKEN env → context → credentials file
+    - Token: -t flag → HCP_TOKEN/TFC_TOKEN/TFE_TOKEN env → context → credentials file (see whoami)
     - Org:   --org flag → context
 
 EXAMPLES:
@@ -102,6 +125,22 @@ pub struct Cli {
     #[arg(short = 'c', long, global = true)]
     pub context: Option<String>,
 
+    /// Use a named context for this invocation only; unlike --context it fails
+    /// when the context does not exist. --host/--token still win field by field
+    #[arg(
+        short = 'P',
+        long,
+        global = true,
+        value_name = "NAME",
+        conflicts_with = "context"
+    )]
+    pub profile: Option<String>,
+
+    /// Ignore the context's default org and query every organization the token
+    /// can see (cannot be combined with --org)
+    #[arg(long, global = true, default_value_t = false)]
+    pub all_orgs: bool,
+
     /// TFE/HCP host URL (falls back to TFE_HOSTNAME env var or credentials file)
     #[arg(short = 'H', long, global = true)]
     pub host: Option<String>,
@@ -110,18 +149,245 @@ pub struct Cli {
     #[arg(short = 't', long, global = true)]
     pub token: Option<String>,
 
+    /// PEM bundle of extra CA certificates to trust, for TFE installs with a
+    /// private or self-signed CA (falls back to TFE_CACERT env var)
+    #[arg(long, global = true, value_name = "PATH", env = api::CA_CERT_ENV_VAR)]
+    pub ca_cert: Option<std::path::PathBuf>,
+
+    /// PEM client certificate for mutual TLS (requires --client-key)
+    #[arg(long, global = true, value_name = "PATH", requires = "client_key")]
+    pub client_cert: Option<std::path::PathBuf>,
+
+    /// PEM private key for --client-cert
+    #[arg(long, global = true, value_name = "PATH", requires = "client_cert")]
+    pub client_key: Option<std::path::PathBuf>,
+
+    /// Proxy for all requests, e.g. http://proxy:3128 or socks5://proxy:1080
+    /// (overrides HTTPS_PROXY/HTTP_PROXY; NO_PROXY still applies)
+    #[arg(long, global = true, value_name = "URL")]
+    pub proxy: Option<String>,
+
+    /// API version path segment, e.g. v2 for /api/v2 (falls back to TFE_API_VERSION env var)
+    #[arg(long, global = true, env = api::VERSION_ENV_VAR, default_value = api::DEFAULT_VERSION)]
+    pub api_version: String,
+
     /// Log level (error, warn, info, debug, trace)
     #[arg(short, long, global = true, default_value = defaults::LOG_LEVEL)]
     pub log_level: String,
 
+    /// Print each API request (method, redacted URL, status, elapsed time) to stderr
+    #[arg(long, global = true, default_value_t = false)]
+    pub debug_http: bool,
+
     /// Batch mode - no interactive prompts, no spinners
     #[arg(short, long, global = true, default_value_t = false)]
     pub batch: bool,
 
+    /// Quiet mode - print only data: no spinners, "Total" footers or notices
+    /// (prompts still appear, unlike --batch)
+    #[arg(short, long, global = true, default_value_t = false)]
+    pub quiet: bool,
+
+    /// Seconds to wait at interactive prompts before applying the default answer
+    /// (the ambiguous workspace picker fails with the candidate list instead)
+    #[arg(long, global = true, value_name = "SECONDS")]
+    pub prompt_timeout: Option<u64>,
+
     /// Omit header row in table/CSV output
     #[arg(long, global = true, default_value_t = false)]
     pub no_header: bool,
 
+    /// Show at most N rows of a listing (applied after sorting); a
+    /// "Showing N of M" note goes to stderr unless --no-header is set
+    #[arg(short = 'n', long, global = true, value_name = "N")]
+    pub limit: Option<usize>,
+
+    /// Fetch only page N (1-based) of listings instead of all pages; the
+    /// pagination meta (page, total pages, total count) goes to stderr
+    #[arg(
+        long,
+        global = true,
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with = "limit"
+    )]
+    pub page: Option<u32>,
+
+    /// Items per page with --page (1-100, default 100); alone, fetches the first page
+    #[arg(
+        long,
+        global = true,
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(1..=100),
+        conflicts_with = "limit"
+    )]
+    pub page_size: Option<u32>,
+
+    /// Show raw ISO timestamps in tables instead of relative ages (e.g. "3d 4h");
+    /// JSON/YAML/CSV always carry the full timestamp
+    #[arg(long, global = true, default_value_t = false)]
+    pub absolute_time: bool,
+
+    /// Print only resource IDs, one per line (list commands)
+    #[arg(long, global = true, default_value_t = false)]
+    pub id_only: bool,
+
+    /// Write results to PATH instead of stdout (parent directories are created;
+    /// the byte count goes to stderr). Prompts and progress stay on the terminal
+    #[arg(long, global = true, value_name = "PATH")]
+    pub output_file: Option<std::path::PathBuf>,
+
+    /// Cache GET responses by ETag and revalidate with If-None-Match (reduces load when polling).
+    /// With --cache, every cached entry is revalidated instead of trusted until the TTL expires
+    #[arg(long, global = true, default_value_t = false)]
+    pub conditional_requests: bool,
+
+    /// Cache GET responses on disk (~/.hcpctl/cache) and reuse them across invocations.
+    /// Entries are keyed by host and token; mutating commands (create, delete,
+    /// set, purge, invite, tag changes) and polling commands (--wait, --watch,
+    /// watch ws, logs --follow) always bypass the cache
+    #[arg(long, global = true, env = cache_config::ENV_VAR, default_value_t = false)]
+    pub cache: bool,
+
+    /// Seconds a cached response stays valid (with --cache)
+    #[arg(long, global = true, value_name = "SECS", default_value_t = cache_config::DEFAULT_TTL_SECS)]
+    pub cache_ttl: u64,
+
+    /// Bypass the on-disk response cache (overrides --cache and HCPCTL_CACHE)
+    #[arg(long, global = true, default_value_t = false)]
+    pub no_cache: bool,
+
+    /// Wait and retry (with backoff) when TFE answers 503 during maintenance
+    #[arg(long = "retry-on-503", global = true, default_value_t = false)]
+    pub retry_on_503: bool,
+
+    /// Maximum retries when TFE answers 429 Too Many Requests, honoring Retry-After
+    /// or backing off exponentially (0 disables)
+    #[arg(long, global = true, value_name = "N", default_value_t = api::RATE_LIMIT_MAX_RETRIES)]
+    pub max_retries: u32,
+
+    /// Maximum concurrent API requests when fetching across organizations,
+    /// pages or projects (minimum 1)
+    #[arg(
+        long,
+        global = true,
+        value_name = "N",
+        default_value_t = api::MAX_CONCURRENT_PAGE_REQUESTS
+    )]
+    pub concurrency: usize,
+
+    /// Only retry idempotent requests (GET, HEAD, PUT, DELETE). Set to false to
+    /// also retry POST/PATCH, which risks e.g. duplicate runs
+    #[arg(
+        long,
+        global = true,
+        default_value_t = true,
+        action = clap::ArgAction::Set,
+        value_name = "BOOL"
+    )]
+    pub retry_idempotent_only: bool,
+
+    /// When to color output: auto (terminal only, not with --batch or NO_COLOR),
+    /// always (even when piped) or never
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        value_name = "WHEN",
+        default_value_t = ColorChoice::Auto
+    )]
+    pub color: ColorChoice,
+
+    /// Disable colored output (same as --color never)
+    #[arg(long, global = true, default_value_t = false)]
+    pub no_color: bool,
+
+    /// Truncate workspace/project names longer than N characters in table output
+    /// (full names are kept in CSV/JSON/YAML)
+    #[arg(long, global = true, value_name = "N")]
+    pub max_name_width: Option<usize>,
+
+    /// Wrap table cells to fit the terminal width
+    #[arg(
+        long,
+        global = true,
+        default_value_t = false,
+        overrides_with = "no_wrap"
+    )]
+    pub wrap: bool,
+
+    /// Never wrap table cells; long rows overflow the terminal (default)
+    #[arg(long, global = true, default_value_t = false, overrides_with = "wrap")]
+    pub no_wrap: bool,
+
+    /// Sort object keys alphabetically in JSON output (stable diffs between exports)
+    #[arg(long, global = true, default_value_t = false)]
+    pub sort_keys: bool,
+
+    /// Print only the part of JSON output (-o json) matched by a JSONPath expression,
+    /// e.g. '$[*].name' or "$[?(@.status == 'planning')].id". Fails if nothing matches
+    #[arg(long, global = true, value_name = "EXPR")]
+    pub jsonpath: Option<String>,
+
+    /// Go-style template rendered once per row with -o template, e.g. '{{.name}}'
+    ///
+    /// Supports {{.field}}, {{.a.b}}, {{"\n"}}, {{range .list}}...{{end}} and
+    /// {{if .field}}...{{else}}...{{end}}. Each row is printed on its own line.
+    ///
+    /// Fields per resource:
+    ///   ws           org, workspace_id, workspace_name, project_id, project_name,
+    ///                resources, billable, execution_mode, locked, terraform_version,
+    ///                updated_at, vcs_repo, working_directory, pending_runs, settings
+    ///   prj          org, id, name, description, workspace_count, workspaces
+    ///   org          id, name, email, external_id, created_at, saml_enabled,
+    ///                default_project_id, oauth_token_ids
+    ///   run          run_id, workspace_id, status, source, message, has_changes,
+    ///                is_destroy, plan_only, trigger_reason, created_at
+    ///   team         id, name, users_count, visibility
+    ///   oc           org, id, name, service_provider, http_url, created_at,
+    ///                organization_scoped, oauth_token_ids
+    ///   org-member   id, organization, email, status, created_at, teams,
+    ///                team_count, attributes
+    ///   team-access  id, team_id, team_name, project_id, project_name, access
+    ///   tag          key, value, created_at (ws/prj); name, instance_count (org)
+    ///   var          id, key, value, category, hcl, sensitive, description
+    ///   varset       id, name, description, global, priority, workspace_count,
+    ///                project_count, var_count
+    ///   policy-set   id, name, description, kind, global, workspace_count,
+    ///                project_count, policy_count, overridable
+    ///   registry-module id, name, namespace, provider, registry_name,
+    ///                latest_version, status, updated_at; versions (single module)
+    ///   assessment   workspace_id, workspace_name, status, drifted, succeeded,
+    ///                error_msg, assessed_at, result_id
+    ///   state        id, serial, status, created_at, size, resources,
+    ///                delta_resources, terraform_version, run_id, vcs_commit_sha;
+    ///                name, type, sensitive, value (outputs of sv-xxx)
+    ///   output       name, type, sensitive, value
+    ///
+    /// Single-resource lookups (e.g. get run run-xxx) render the API object:
+    /// id, type, attributes.<name>, relationships.<name>
+    #[arg(long, global = true, value_name = "TEMPLATE", verbatim_doc_comment)]
+    pub template: Option<String>,
+
+    /// Fail the command if any organization errors during multi-org fetches
+    /// (default: show partial results and report failed organizations)
+    #[arg(long, global = true, default_value_t = false)]
+    pub strict: bool,
+
+    /// Don't show the update-available notice for this invocation
+    /// (the background version check still runs)
+    #[arg(long, global = true, default_value_t = false)]
+    pub no_update_notice: bool,
+
+    /// Skip the background update check entirely (no network call; also
+    /// HCPCTL_NO_UPDATE_CHECK=1, and implied by --batch)
+    #[arg(long, global = true, default_value_t = false)]
+    pub no_update_check: bool,
+
+    /// Print the API calls the command would make (method and path) without executing them
+    #[arg(long, global = true, default_value_t = false)]
+    pub explain: bool,
+
     /// Generate Markdown documentation for all commands (hidden)
     #[arg(long, hide = true)]
     pub markdown_help: bool,
@@ -135,6 +401,12 @@ pub enum Command {
         resource: GetResource,
     },
 
+    /// Create resources (OAuth clients)
+    Create {
+        #[command(subcommand)]
+        resource: CreateResource,
+    },
+
     /// Delete resources
     Delete {
         #[command(subcommand)]
@@ -166,15 +438,38 @@ pub enum Command {
     #[command(visible_alias = "log", verbatim_doc_comment)]
     Logs(LogsArgs),
 
+    /// Act on a single run (apply a run awaiting confirmation)
+    Run {
+        #[command(subcommand)]
+        action: RunAction,
+    },
+
     /// Watch resources for changes
     Watch {
         #[command(subcommand)]
         resource: WatchResource,
     },
 
+    /// Print workspace dependencies (run triggers) as a Graphviz DOT or Mermaid graph
+    ///
+    /// Nodes are workspaces linked by at least one run trigger; an edge points
+    /// from the source workspace to the workspace whose runs it triggers.
+    /// e.g. hcpctl graph --org my-org | dot -Tsvg > deps.svg
+    #[command(verbatim_doc_comment)]
+    Graph(GraphArgs),
+
     /// Invite a user to an organization
     Invite(InviteArgs),
 
+    /// Lock a workspace so no runs can be applied (optionally with --reason)
+    Lock(LockArgs),
+
+    /// Unlock a workspace locked by you
+    Unlock(UnlockArgs),
+
+    /// Show the authenticated user, the resolved host and where the token came from
+    Whoami(WhoamiArgs),
+
     /// Set resource properties (assign workspace to project, etc.)
     Set {
         #[command(subcommand)]
@@ -187,8 +482,172 @@ pub enum Command {
         action: ConfigAction,
     },
 
+    /// Manage the on-disk response cache (see --cache)
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+
     /// Update hcpctl to the latest version
     Update,
+
+    /// Print a shell completion script (bash, zsh, fish, powershell)
+    ///
+    /// e.g. hcpctl completion bash > /etc/bash_completion.d/hcpctl
+    #[command(hide = true, verbatim_doc_comment)]
+    Completion(CompletionArgs),
+
+    /// Print org or workspace names matching a prefix, one per line (hidden)
+    ///
+    /// Called by shell completion scripts, e.g. hcpctl __complete ws --org my-org app
+    /// Prints nothing on any error (no credentials, timeout) so completion never blocks.
+    #[command(name = "__complete", hide = true, verbatim_doc_comment)]
+    Complete(CompleteArgs),
+}
+
+impl Command {
+    /// Output format selected with `-o`, for commands that print resources
+    pub fn output_format(&self) -> Option<&OutputFormat> {
+        match self {
+            Command::Get { resource } => Some(match resource {
+                GetResource::Org(args) => &args.output,
+                GetResource::Prj(args) => &args.output,
+                GetResource::Ws(args) => &args.output,
+                GetResource::Oc(args) => &args.output,
+                GetResource::Run(args) => &args.output,
+                GetResource::Team(args) => &args.output,
+                GetResource::OrgMember(args) => &args.output,
+                GetResource::TeamAccess(args) => &args.output,
+                GetResource::Tag(args) => &args.output,
+                GetResource::Var(args) => &args.output,
+                GetResource::State(args) => &args.output,
+                GetResource::Output(args) => &args.output,
+                GetResource::Varset(args) => &args.output,
+                GetResource::PolicySet(args) => &args.output,
+                GetResource::NotificationConfig(args) => &args.output,
+                GetResource::RunTrigger(args) => &args.output,
+                GetResource::Assessment(args) => &args.output,
+                GetResource::RegistryModule(args) => &args.output,
+            }),
+            Command::Invite(args) => Some(&args.output),
+            Command::Whoami(args) => Some(&args.output),
+            _ => None,
+        }
+    }
+
+    /// Mutable counterpart of [`Command::output_format`], used to apply
+    /// context defaults
+    pub fn output_format_mut(&mut self) -> Option<&mut OutputFormat> {
+        match self {
+            Command::Get { resource } => Some(match resource {
+                GetResource::Org(args) => &mut args.output,
+                GetResource::Prj(args) => &mut args.output,
+                GetResource::Ws(args) => &mut args.output,
+                GetResource::Oc(args) => &mut args.output,
+                GetResource::Run(args) => &mut args.output,
+                GetResource::Team(args) => &mut args.output,
+                GetResource::OrgMember(args) => &mut args.output,
+                GetResource::TeamAccess(args) => &mut args.output,
+                GetResource::Tag(args) => &mut args.output,
+                GetResource::Var(args) => &mut args.output,
+                GetResource::State(args) => &mut args.output,
+                GetResource::Output(args) => &mut args.output,
+                GetResource::Varset(args) => &mut args.output,
+                GetResource::PolicySet(args) => &mut args.output,
+                GetResource::NotificationConfig(args) => &mut args.output,
+                GetResource::RunTrigger(args) => &mut args.output,
+                GetResource::Assessment(args) => &mut args.output,
+                GetResource::RegistryModule(args) => &mut args.output,
+            }),
+            Command::Invite(args) => Some(&mut args.output),
+            Command::Whoami(args) => Some(&mut args.output),
+            _ => None,
+        }
+    }
+
+    /// Whether the command changes server-side state (tags, purge, create, ...)
+    ///
+    /// Such commands never read from the on-disk response cache, so decisions
+    /// are always made on live data.
+    pub fn is_mutating(&self) -> bool {
+        matches!(
+            self,
+            Command::Create { .. }
+                | Command::Delete { .. }
+                | Command::Purge { .. }
+                | Command::Invite(_)
+                | Command::Lock(_)
+                | Command::Unlock(_)
+                | Command::Run { .. }
+                | Command::Set { .. }
+        )
+    }
+
+    /// Whether the command polls the API until something changes
+    /// (`get run --current --wait`, `get run --watch`, `watch ws`, `logs --follow`)
+    ///
+    /// Such commands never use the on-disk response cache; a cached body would
+    /// be served for the whole TTL and the change would never be seen.
+    pub fn is_polling(&self) -> bool {
+        match self {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => args.wait || args.watch.is_some(),
+            Command::Watch { .. } => true,
+            Command::Logs(args) => args.follow,
+            _ => false,
+        }
+    }
+
+    /// Whether the command streams to the terminal as it goes
+    /// (`watch ws`, `logs --follow`, `get run --tail-log`, `purge` previews and prompts)
+    ///
+    /// `--output-file` cannot capture such output, so it is rejected for them.
+    pub fn streams_to_terminal(&self) -> bool {
+        match self {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => args.tail_log,
+            Command::Watch { .. } | Command::Purge { .. } => true,
+            Command::Logs(args) => args.follow,
+            _ => false,
+        }
+    }
+}
+
+impl Cli {
+    /// TTL of the on-disk response cache, or `None` when it must not be used
+    ///
+    /// The cache needs `--cache` (or `HCPCTL_CACHE`) without `--no-cache`, and is
+    /// never used by mutating or polling commands.
+    pub fn disk_cache_ttl(&self) -> Option<std::time::Duration> {
+        let applies = self.cache
+            && !self.no_cache
+            && !self.command.is_mutating()
+            && !self.command.is_polling();
+        applies.then(|| std::time::Duration::from_secs(self.cache_ttl))
+    }
+
+    /// Page of the rendered listing requested with `--page`/`--page-size`
+    ///
+    /// Only the top-level listing is paged; lookups and enrichment fetches
+    /// always read every page.
+    pub fn page_request(&self) -> Option<crate::hcp::PageRequest> {
+        crate::hcp::PageRequest::from_flags(self.page, self.page_size)
+    }
+}
+
+/// Whether argument `id` was given on the command line or through its env
+/// var at any subcommand level, rather than falling back to its default
+pub fn arg_given(matches: &ArgMatches, id: &str) -> bool {
+    let here = matches.ids().any(|i| i.as_str() == id)
+        && matches!(
+            matches.value_source(id),
+            Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+        );
+    here || matches
+        .subcommand()
+        .is_some_and(|(_, sub)| arg_given(sub, id))
 }
 
 #[cfg(test)]
@@ -201,6 +660,7 @@ mod tests {
         assert_eq!(OutputFormat::Csv.to_string(), "csv");
         assert_eq!(OutputFormat::Json.to_string(), "json");
         assert_eq!(OutputFormat::Yaml.to_string(), "yaml");
+        assert_eq!(OutputFormat::Ndjson.to_string(), "ndjson");
     }
 
     #[test]
@@ -460,323 +920,1497 @@ mod tests {
     }
 
     #[test]
-    fn test_output_format_json() {
-        let cli = Cli::parse_from(["hcp", "get", "org", "-o", "json"]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Org(args),
-            } => {
-                assert_eq!(args.output, OutputFormat::Json);
-            }
-            _ => panic!("Expected Get Org command"),
-        }
+    fn test_api_version_default() {
+        let cli = Cli::parse_from(["hcp", "get", "org"]);
+        assert_eq!(cli.api_version, "v2");
     }
 
-    // === Get run tests ===
-
     #[test]
-    fn test_get_run_single() {
-        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123"]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert_eq!(args.name, Some("run-abc123".to_string()));
-                assert!(args.org.is_none());
-                assert!(args.ws.is_none());
-            }
-            _ => panic!("Expected Get Run command"),
-        }
+    fn test_api_version_option() {
+        let cli = Cli::parse_from(["hcp", "--api-version", "v3", "get", "org"]);
+        assert_eq!(cli.api_version, "v3");
     }
 
     #[test]
-    fn test_get_run_with_org() {
-        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "my-org"]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert!(args.name.is_none());
-                assert_eq!(args.org, Some("my-org".to_string()));
-                assert!(args.ws.is_none());
-            }
-            _ => panic!("Expected Get Run command"),
-        }
-    }
+    fn test_conditional_requests_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "org"]);
+        assert!(!cli.conditional_requests);
 
-    #[test]
-    fn test_get_run_with_ws() {
-        let cli = Cli::parse_from(["hcp", "get", "run", "--ws", "ws-abc123"]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert!(args.name.is_none());
-                assert!(args.org.is_none());
-                assert_eq!(args.ws, Some("ws-abc123".to_string()));
-            }
-            _ => panic!("Expected Get Run command"),
-        }
+        let cli = Cli::parse_from(["hcp", "--conditional-requests", "watch", "ws", "my-ws"]);
+        assert!(cli.conditional_requests);
     }
 
     #[test]
-    fn test_get_run_with_subresource() {
-        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123", "--subresource", "events"]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert_eq!(args.name, Some("run-abc123".to_string()));
-                assert_eq!(args.subresource, Some(RunSubresource::Events));
-            }
-            _ => panic!("Expected Get Run command"),
-        }
+    fn test_retry_on_503_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "org"]);
+        assert!(!cli.retry_on_503);
+
+        let cli = Cli::parse_from(["hcp", "get", "org", "--retry-on-503"]);
+        assert!(cli.retry_on_503);
     }
 
     #[test]
-    fn test_get_run_with_subresource_plan() {
-        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123", "--subresource", "plan"]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert_eq!(args.name, Some("run-abc123".to_string()));
-                assert_eq!(args.subresource, Some(RunSubresource::Plan));
-                assert!(!args.get_log);
-                assert!(!args.tail_log);
-            }
-            _ => panic!("Expected Get Run command"),
-        }
+    fn test_retry_idempotent_only_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "org"]);
+        assert!(cli.retry_idempotent_only);
+
+        let cli = Cli::parse_from(["hcp", "get", "org", "--retry-idempotent-only=false"]);
+        assert!(!cli.retry_idempotent_only);
     }
 
     #[test]
-    fn test_get_run_with_subresource_apply() {
-        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123", "--subresource", "apply"]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert_eq!(args.name, Some("run-abc123".to_string()));
-                assert_eq!(args.subresource, Some(RunSubresource::Apply));
-            }
-            _ => panic!("Expected Get Run command"),
-        }
+    fn test_table_layout_flags() {
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert_eq!(cli.max_name_width, None);
+        assert!(!cli.wrap);
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--max-name-width", "20", "--wrap"]);
+        assert_eq!(cli.max_name_width, Some(20));
+        assert!(cli.wrap);
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--wrap", "--no-wrap"]);
+        assert!(!cli.wrap);
     }
 
     #[test]
-    fn test_get_run_with_get_log() {
+    fn test_template_output() {
         let cli = Cli::parse_from([
             "hcp",
             "get",
             "run",
-            "run-abc123",
-            "--subresource",
-            "plan",
-            "--get-log",
+            "--org",
+            "my-org",
+            "-o",
+            "template",
+            "--template",
+            "{{.run_id}} {{.status}}",
         ]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert_eq!(args.subresource, Some(RunSubresource::Plan));
-                assert!(args.get_log);
-                assert!(!args.tail_log);
-            }
-            _ => panic!("Expected Get Run command"),
-        }
+        assert_eq!(cli.command.output_format(), Some(&OutputFormat::Template));
+        assert_eq!(cli.template.as_deref(), Some("{{.run_id}} {{.status}}"));
+
+        let cli = Cli::parse_from(["hcp", "update"]);
+        assert_eq!(cli.command.output_format(), None);
     }
 
     #[test]
-    fn test_get_run_with_tail_log() {
-        let cli = Cli::parse_from([
-            "hcp",
-            "get",
-            "run",
-            "run-abc123",
-            "--subresource",
-            "apply",
-            "--tail-log",
-        ]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert_eq!(args.subresource, Some(RunSubresource::Apply));
-                assert!(!args.get_log);
-                assert!(args.tail_log);
-                assert!(!args.raw);
-            }
-            _ => panic!("Expected Get Run command"),
-        }
+    fn test_jsonpath_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert!(cli.jsonpath.is_none());
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "-o", "json", "--jsonpath", "$[*].name"]);
+        assert_eq!(cli.jsonpath.as_deref(), Some("$[*].name"));
     }
 
     #[test]
-    fn test_get_run_with_raw_log() {
-        let cli = Cli::parse_from([
-            "hcp",
-            "get",
-            "run",
-            "run-abc123",
-            "--subresource",
-            "plan",
-            "--get-log",
-            "--raw",
-        ]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert!(args.get_log);
-                assert!(args.raw);
-            }
-            _ => panic!("Expected Get Run command"),
-        }
+    fn test_sort_keys_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert!(!cli.sort_keys);
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "-o", "json", "--sort-keys"]);
+        assert!(cli.sort_keys);
     }
 
     #[test]
-    fn test_get_run_with_status_filter() {
-        let cli = Cli::parse_from([
-            "hcp",
-            "get",
-            "run",
-            "--org",
-            "my-org",
-            "--status",
-            "planning,applying",
-        ]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
-            } => {
-                assert_eq!(args.status, Some("planning,applying".to_string()));
+    fn test_page_flags() {
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert_eq!((cli.page, cli.page_size), (None, None));
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--page", "3", "--page-size", "20"]);
+        assert_eq!((cli.page, cli.page_size), (Some(3), Some(20)));
+
+        assert!(Cli::try_parse_from(["hcp", "get", "ws", "--page", "0"]).is_err());
+        assert!(Cli::try_parse_from(["hcp", "get", "ws", "--page-size", "101"]).is_err());
+        assert!(Cli::try_parse_from(["hcp", "get", "ws", "--page", "2", "-n", "5"]).is_err());
+        assert!(Cli::try_parse_from(["hcp", "get", "ws", "--page-size", "5", "-n", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_quiet_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert!(!cli.quiet);
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "-q"]);
+        assert!(cli.quiet);
+        assert!(!cli.batch);
+
+        let cli = Cli::parse_from(["hcp", "--quiet", "get", "org"]);
+        assert!(cli.quiet);
+    }
+
+    #[test]
+    fn test_debug_http_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "org"]);
+        assert!(!cli.debug_http);
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--debug-http"]);
+        assert!(cli.debug_http);
+    }
+
+    #[test]
+    fn test_no_update_check_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "org"]);
+        assert!(!cli.no_update_check);
+
+        let cli = Cli::parse_from(["hcp", "--no-update-check", "get", "org"]);
+        assert!(cli.no_update_check);
+    }
+
+    #[test]
+    fn test_no_update_notice_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "org"]);
+        assert!(!cli.no_update_notice);
+
+        let cli = Cli::parse_from(["hcp", "get", "org", "--no-update-notice"]);
+        assert!(cli.no_update_notice);
+    }
+
+    #[test]
+    fn test_explain_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert!(!cli.explain);
+
+        let cli = Cli::parse_from(["hcp", "delete", "oc", "oc-123", "--explain"]);
+        assert!(cli.explain);
+    }
+
+    #[test]
+    fn test_max_retries_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "run"]);
+        assert_eq!(cli.max_retries, 5);
+
+        let cli = Cli::parse_from(["hcp", "get", "run", "--max-retries", "0"]);
+        assert_eq!(cli.max_retries, 0);
+    }
+
+    #[test]
+    fn test_concurrency_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert_eq!(cli.concurrency, api::MAX_CONCURRENT_PAGE_REQUESTS);
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--concurrency", "3"]);
+        assert_eq!(cli.concurrency, 3);
+    }
+
+    #[test]
+    fn test_tls_flags() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "ws",
+            "--ca-cert",
+            "/etc/tfe/ca.pem",
+            "--client-cert",
+            "/etc/tfe/client.pem",
+            "--client-key",
+            "/etc/tfe/client.key",
+        ]);
+        assert_eq!(
+            cli.ca_cert.as_deref(),
+            Some(std::path::Path::new("/etc/tfe/ca.pem"))
+        );
+        assert_eq!(
+            cli.client_cert.as_deref(),
+            Some(std::path::Path::new("/etc/tfe/client.pem"))
+        );
+        assert_eq!(
+            cli.client_key.as_deref(),
+            Some(std::path::Path::new("/etc/tfe/client.key"))
+        );
+    }
+
+    #[test]
+    fn test_proxy_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert!(cli.proxy.is_none());
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--proxy", "http://proxy.corp:3128"]);
+        assert_eq!(cli.proxy.as_deref(), Some("http://proxy.corp:3128"));
+    }
+
+    #[test]
+    fn test_client_cert_requires_key() {
+        let result = Cli::try_parse_from(["hcp", "get", "ws", "--client-cert", "c.pem"]);
+        assert!(result.is_err());
+        let result = Cli::try_parse_from(["hcp", "get", "ws", "--client-key", "k.pem"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_mutating_commands_bypass_cache() {
+        let cli = Cli::parse_from(["hcp", "--cache", "get", "ws"]);
+        assert!(!cli.command.is_mutating());
+        let cli = Cli::parse_from(["hcp", "--cache", "set", "tag", "ws", "my-ws", "env=prod"]);
+        assert!(cli.command.is_mutating());
+        let cli = Cli::parse_from(["hcp", "--cache", "purge", "state", "ws-abc123"]);
+        assert!(cli.command.is_mutating());
+    }
+
+    #[test]
+    fn test_polling_commands_bypass_cache() {
+        let ttl = |args: &[&str]| Cli::parse_from(args).disk_cache_ttl();
+
+        assert_eq!(
+            ttl(&["hcp", "--cache", "get", "run", "--ws", "my-ws", "--current"]),
+            Some(std::time::Duration::from_secs(300))
+        );
+        assert!(ttl(&[
+            "hcp",
+            "--cache",
+            "get",
+            "run",
+            "--ws",
+            "my-ws",
+            "--current",
+            "--wait"
+        ])
+        .is_none());
+        assert!(ttl(&["hcp", "--cache", "get", "run", "--org", "o", "--watch"]).is_none());
+        assert!(ttl(&["hcp", "--cache", "watch", "ws", "my-ws"]).is_none());
+        assert!(ttl(&["hcp", "--cache", "logs", "run-abc123", "-f"]).is_none());
+        assert!(ttl(&["hcp", "--cache", "logs", "run-abc123"]).is_some());
+        assert!(ttl(&["hcp", "--cache", "--no-cache", "get", "ws"]).is_none());
+        assert!(ttl(&["hcp", "get", "ws"]).is_none());
+    }
+
+    #[test]
+    fn test_streams_to_terminal() {
+        let streams = |args: &[&str]| Cli::parse_from(args).command.streams_to_terminal();
+
+        assert!(streams(&["hcp", "watch", "ws", "my-ws"]));
+        assert!(streams(&["hcp", "logs", "run-abc123", "-f"]));
+        assert!(streams(&["hcp", "purge", "state", "ws-abc123"]));
+        assert!(streams(&[
+            "hcp",
+            "get",
+            "run",
+            "run-abc123",
+            "--subresource",
+            "plan",
+            "--tail-log"
+        ]));
+        assert!(!streams(&["hcp", "logs", "run-abc123"]));
+        assert!(!streams(&["hcp", "get", "run", "run-abc123"]));
+        assert!(!streams(&["hcp", "get", "ws"]));
+    }
+
+    #[test]
+    fn test_cache_flags() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--cache"]);
+        assert!(cli.cache);
+        assert!(!cli.no_cache);
+        assert_eq!(cli.cache_ttl, 300);
+
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "ws",
+            "--cache",
+            "--cache-ttl",
+            "60",
+            "--no-cache",
+        ]);
+        assert_eq!(cli.cache_ttl, 60);
+        assert!(cli.no_cache);
+    }
+
+    #[test]
+    fn test_cache_clear_command() {
+        let cli = Cli::parse_from(["hcp", "cache", "clear"]);
+        assert!(matches!(
+            cli.command,
+            Command::Cache {
+                action: CacheAction::Clear
+            }
+        ));
+    }
+
+    #[test]
+    fn test_strict_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert!(!cli.strict);
+
+        let cli = Cli::parse_from(["hcp", "--strict", "get", "ws"]);
+        assert!(cli.strict);
+    }
+
+    #[test]
+    fn test_prompt_timeout_default() {
+        let cli = Cli::parse_from(["hcp", "get", "org"]);
+        assert_eq!(cli.prompt_timeout, None);
+    }
+
+    #[test]
+    fn test_prompt_timeout_option() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--prompt-timeout", "30"]);
+        assert_eq!(cli.prompt_timeout, Some(30));
+    }
+
+    #[test]
+    fn test_id_only_option() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--id-only"]);
+        assert!(cli.id_only);
+
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert!(!cli.id_only);
+    }
+
+    #[test]
+    fn test_output_format_json() {
+        let cli = Cli::parse_from(["hcp", "get", "org", "-o", "json"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Org(args),
+            } => {
+                assert_eq!(args.output, OutputFormat::Json);
+            }
+            _ => panic!("Expected Get Org command"),
+        }
+    }
+
+    #[test]
+    fn test_output_format_ndjson() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "my-org", "-o", "ndjson"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.output, OutputFormat::Ndjson);
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_output_format_markdown() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "-o", "markdown"]);
+        assert_eq!(cli.command.output_format(), Some(&OutputFormat::Markdown));
+    }
+
+    #[test]
+    fn test_output_format_tsv() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "-o", "tsv"]);
+        assert_eq!(cli.command.output_format(), Some(&OutputFormat::Tsv));
+    }
+
+    // === Get run tests ===
+
+    #[test]
+    fn test_get_run_single() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.name, Some("run-abc123".to_string()));
+                assert!(args.org.is_none());
+                assert!(args.ws.is_none());
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_org() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "my-org"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert!(args.name.is_none());
+                assert_eq!(args.org, Some("my-org".to_string()));
+                assert!(args.ws.is_none());
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_status_group_flags() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "o", "--final"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert!(args.final_only);
+                assert!(!args.all);
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "o", "--all"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => assert!(args.all),
+            _ => panic!("Expected Get Run command"),
+        }
+        assert!(Cli::try_parse_from(["hcp", "get", "run", "--final", "--all"]).is_err());
+    }
+
+    #[test]
+    fn test_completion_command() {
+        let cli = Cli::parse_from(["hcp", "completion", "zsh"]);
+        match cli.command {
+            Command::Completion(args) => assert_eq!(args.shell, clap_complete::Shell::Zsh),
+            _ => panic!("Expected Completion command"),
+        }
+        assert!(Cli::try_parse_from(["hcp", "completion", "tcsh"]).is_err());
+    }
+
+    #[test]
+    fn test_complete_command() {
+        let cli = Cli::parse_from(["hcp", "__complete", "ws", "--org", "my-org", "ap"]);
+        match cli.command {
+            Command::Complete(args) => {
+                assert_eq!(args.resource, CompleteResource::Ws);
+                assert_eq!(args.partial, "ap");
+                assert_eq!(args.org.as_deref(), Some("my-org"));
+            }
+            _ => panic!("Expected Complete command"),
+        }
+        let cli = Cli::parse_from(["hcp", "__complete", "org"]);
+        match cli.command {
+            Command::Complete(args) => assert_eq!(args.partial, ""),
+            _ => panic!("Expected Complete command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_ws() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--ws", "ws-abc123"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert!(args.name.is_none());
+                assert!(args.org.is_none());
+                assert_eq!(args.ws, Some("ws-abc123".to_string()));
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_subresource() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123", "--subresource", "events"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.name, Some("run-abc123".to_string()));
+                assert_eq!(args.subresource, Some(RunSubresource::Events));
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_subresource_plan() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123", "--subresource", "plan"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.name, Some("run-abc123".to_string()));
+                assert_eq!(args.subresource, Some(RunSubresource::Plan));
+                assert!(!args.get_log);
+                assert!(!args.tail_log);
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_subresource_apply() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123", "--subresource", "apply"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.name, Some("run-abc123".to_string()));
+                assert_eq!(args.subresource, Some(RunSubresource::Apply));
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_subresource_config() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123", "--subresource", "config"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.name, Some("run-abc123".to_string()));
+                assert_eq!(args.subresource, Some(RunSubresource::Config));
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_subresource_state() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "run-abc123", "--subresource", "state"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.subresource, Some(RunSubresource::State));
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_subresource_cost_estimate() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-abc123",
+            "--subresource",
+            "cost-estimate",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.subresource, Some(RunSubresource::CostEstimate));
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_subresource_policy_checks() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-abc123",
+            "--subresource",
+            "policy-checks",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.subresource, Some(RunSubresource::PolicyChecks));
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_get_log() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-abc123",
+            "--subresource",
+            "plan",
+            "--get-log",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.subresource, Some(RunSubresource::Plan));
+                assert!(args.get_log);
+                assert!(!args.tail_log);
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_several_ids_requires_get_log() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-a",
+            "run-b",
+            "run-c",
+            "--subresource",
+            "plan",
+            "--get-log",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.name.as_deref(), Some("run-a"));
+                assert_eq!(args.more_run_ids, vec!["run-b", "run-c"]);
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+
+        assert!(Cli::try_parse_from(["hcp", "get", "run", "run-a", "run-b"]).is_err());
+    }
+
+    #[test]
+    fn test_get_run_with_tail_log() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-abc123",
+            "--subresource",
+            "apply",
+            "--tail-log",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.subresource, Some(RunSubresource::Apply));
+                assert!(!args.get_log);
+                assert!(args.tail_log);
+                assert!(!args.raw);
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_raw_log() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-abc123",
+            "--subresource",
+            "plan",
+            "--get-log",
+            "--raw",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert!(args.get_log);
+                assert!(args.raw);
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_with_status_filter() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "--org",
+            "my-org",
+            "--status",
+            "planning,applying",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.status, Some("planning,applying".to_string()));
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_sort_options() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "my-org", "-s", "ws-id"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert_eq!(args.sort, vec![RunSortField::WsId]);
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_ws_sort_size_alias_and_human() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--sort", "size", "--human"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Ws(args),
+            } => {
+                assert_eq!(args.sort, vec![WsSortField::Resources]);
+                assert!(args.human);
+            }
+            _ => panic!("Expected Get Ws command"),
+        }
+    }
+
+    #[test]
+    fn test_get_ws_with_prj_flag() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "ws",
+            "--org",
+            "acme",
+            "-o",
+            "csv",
+            "--with-prj",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Ws(args),
+            } => assert!(args.with_prj),
+            _ => panic!("Expected Get Ws command"),
+        }
+    }
+
+    #[test]
+    fn test_get_ws_count_by() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--count-by", "execution_mode"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Ws(args),
+            } => assert_eq!(args.count_by, Some(WsCountField::ExecutionMode)),
+            _ => panic!("Expected Get Ws command"),
+        }
+        assert!(Cli::try_parse_from([
+            "hcp",
+            "get",
+            "ws",
+            "--count-by",
+            "locked",
+            "--resources-summary"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_var() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "var",
+            "--ws",
+            "ws-abc123",
+            "--category",
+            "env",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Var(args),
+            } => {
+                assert_eq!(args.workspace, "ws-abc123");
+                assert_eq!(args.category, Some(VarCategory::Env));
+                assert!(args.name.is_none());
+            }
+            _ => panic!("Expected Get Var command"),
+        }
+
+        let cli = Cli::parse_from([
+            "hcp", "get", "vars", "region", "--ws", "net", "--org", "acme",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Var(args),
+            } => {
+                assert_eq!(args.name.as_deref(), Some("region"));
+                assert_eq!(args.org.as_deref(), Some("acme"));
+            }
+            _ => panic!("Expected Get Var command"),
+        }
+
+        // --ws is required
+        assert!(Cli::try_parse_from(["hcp", "get", "var"]).is_err());
+    }
+
+    #[test]
+    fn test_get_state() {
+        let cli = Cli::parse_from(["hcp", "get", "state", "--ws", "ws-abc123", "--all"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::State(args),
+            } => {
+                assert_eq!(args.workspace.as_deref(), Some("ws-abc123"));
+                assert!(args.all);
+                assert!(args.id.is_none());
+            }
+            _ => panic!("Expected Get State command"),
+        }
+
+        let cli = Cli::parse_from(["hcp", "get", "sv", "sv-123", "-o", "json"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::State(args),
+            } => {
+                assert_eq!(args.id.as_deref(), Some("sv-123"));
+                assert!(matches!(args.output, OutputFormat::Json));
+            }
+            _ => panic!("Expected Get State command"),
+        }
+
+        // Either a state version ID or --ws is required, not both
+        assert!(Cli::try_parse_from(["hcp", "get", "state"]).is_err());
+        assert!(Cli::try_parse_from(["hcp", "get", "state", "sv-1", "--ws", "app"]).is_err());
+    }
+
+    #[test]
+    fn test_get_run_trigger() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run-trigger",
+            "--ws",
+            "ws-abc123",
+            "-o",
+            "json",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::RunTrigger(args),
+            } => {
+                assert_eq!(args.workspace, "ws-abc123");
+                assert!(args.org.is_none());
+                assert!(matches!(args.output, OutputFormat::Json));
+            }
+            _ => panic!("Expected Get RunTrigger command"),
+        }
+
+        // --ws is required
+        assert!(Cli::try_parse_from(["hcp", "get", "rt"]).is_err());
+    }
+
+    #[test]
+    fn test_get_notification_config() {
+        let cli = Cli::parse_from(["hcp", "get", "nc", "--ws", "app", "--org", "acme"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::NotificationConfig(args),
+            } => {
+                assert_eq!(args.workspace.as_deref(), Some("app"));
+                assert!(args.id.is_none());
+                assert!(matches!(args.output, OutputFormat::Table));
+            }
+            _ => panic!("Expected Get NotificationConfig command"),
+        }
+
+        // A single configuration defaults to yaml
+        let cli = Cli::parse_from(["hcp", "get", "notification-config", "nc-123"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::NotificationConfig(args),
+            } => {
+                assert_eq!(args.id.as_deref(), Some("nc-123"));
+                assert!(matches!(args.output, OutputFormat::Yaml));
+            }
+            _ => panic!("Expected Get NotificationConfig command"),
+        }
+
+        // Either an ID or --ws is required, not both
+        assert!(Cli::try_parse_from(["hcp", "get", "nc"]).is_err());
+        assert!(Cli::try_parse_from(["hcp", "get", "nc", "nc-1", "--ws", "app"]).is_err());
+    }
+
+    #[test]
+    fn test_get_output() {
+        let cli = Cli::parse_from([
+            "hcp", "get", "outputs", "vpc_id", "--ws", "app", "--org", "acme",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Output(args),
+            } => {
+                assert_eq!(args.name.as_deref(), Some("vpc_id"));
+                assert_eq!(args.workspace, "app");
+                assert_eq!(args.org.as_deref(), Some("acme"));
+                assert!(!args.show_sensitive);
+            }
+            _ => panic!("Expected Get Output command"),
+        }
+
+        let cli = Cli::parse_from(["hcp", "get", "output", "--ws", "ws-1", "--show-sensitive"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Output(args),
+            } => assert!(args.show_sensitive),
+            _ => panic!("Expected Get Output command"),
+        }
+
+        assert!(Cli::try_parse_from(["hcp", "get", "output"]).is_err());
+    }
+
+    #[test]
+    fn test_get_varset() {
+        let cli = Cli::parse_from(["hcp", "get", "varset", "--org", "acme"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Varset(args),
+            } => {
+                assert_eq!(args.org.as_deref(), Some("acme"));
+                assert!(matches!(args.output, OutputFormat::Table));
+            }
+            _ => panic!("Expected Get Varset command"),
+        }
+
+        // A single variable set defaults to YAML
+        let cli = Cli::parse_from(["hcp", "get", "varsets", "aws-creds", "--org", "acme"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Varset(args),
+            } => {
+                assert_eq!(args.name.as_deref(), Some("aws-creds"));
+                assert!(matches!(args.output, OutputFormat::Yaml));
+            }
+            _ => panic!("Expected Get Varset command"),
+        }
+
+        assert!(Cli::try_parse_from(["hcp", "get", "varset", "x", "--prj", "p"]).is_err());
+    }
+
+    #[test]
+    fn test_get_assessment() {
+        let cli = Cli::parse_from(["hcp", "get", "assessment", "--org", "acme", "-f", "prod"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Assessment(args),
+            } => {
+                assert_eq!(args.org.as_deref(), Some("acme"));
+                assert_eq!(args.filter.as_deref(), Some("prod"));
+                assert!(matches!(args.output, OutputFormat::Table));
+            }
+            _ => panic!("Expected Get Assessment command"),
+        }
+
+        let cli = Cli::parse_from(["hcp", "get", "drift", "-o", "json"]);
+        assert!(matches!(
+            cli.command.output_format(),
+            Some(OutputFormat::Json)
+        ));
+    }
+
+    #[test]
+    fn test_get_registry_module() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "registry-module",
+            "--org",
+            "acme",
+            "-f",
+            "vpc",
+            "--provider",
+            "aws",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::RegistryModule(args),
+            } => {
+                assert_eq!(args.org.as_deref(), Some("acme"));
+                assert_eq!(args.filter.as_deref(), Some("vpc"));
+                assert_eq!(args.provider.as_deref(), Some("aws"));
+                assert!(args.name.is_none());
+                assert!(matches!(args.output, OutputFormat::Table));
+            }
+            _ => panic!("Expected Get RegistryModule command"),
+        }
+
+        // A single module defaults to YAML
+        let cli = Cli::parse_from(["hcp", "get", "module", "vpc"]);
+        assert!(matches!(
+            cli.command.output_format(),
+            Some(OutputFormat::Yaml)
+        ));
+
+        // Name and filter are mutually exclusive
+        assert!(Cli::try_parse_from(["hcp", "get", "rm", "vpc", "-f", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_get_policy_set() {
+        let cli = Cli::parse_from(["hcp", "get", "policy-set", "--org", "acme", "-f", "cis"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::PolicySet(args),
+            } => {
+                assert_eq!(args.org.as_deref(), Some("acme"));
+                assert_eq!(args.filter.as_deref(), Some("cis"));
+                assert!(matches!(args.output, OutputFormat::Table));
+            }
+            _ => panic!("Expected Get PolicySet command"),
+        }
+
+        // A single policy set defaults to YAML
+        let cli = Cli::parse_from(["hcp", "get", "ps", "polset-abc"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::PolicySet(args),
+            } => {
+                assert_eq!(args.name.as_deref(), Some("polset-abc"));
+                assert!(matches!(args.output, OutputFormat::Yaml));
+            }
+            _ => panic!("Expected Get PolicySet command"),
+        }
+    }
+
+    #[test]
+    fn test_get_ws_columns() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--columns", "name,id,updated-at"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Ws(args),
+            } => assert_eq!(args.columns.as_deref(), Some("name,id,updated-at")),
+            _ => panic!("Expected Get Ws command"),
+        }
+    }
+
+    #[test]
+    fn test_get_ws_diff() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--diff", "baseline.json"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Ws(args),
+            } => assert_eq!(
+                args.diff.as_deref(),
+                Some(std::path::Path::new("baseline.json"))
+            ),
+            _ => panic!("Expected Get Ws command"),
+        }
+        assert!(Cli::try_parse_from(["hcp", "get", "ws", "my-ws", "--diff", "b.json"]).is_err());
+        assert!(Cli::try_parse_from([
+            "hcp",
+            "get",
+            "ws",
+            "--diff",
+            "b.json",
+            "--count-by",
+            "locked"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_ws_multi_key_sort() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--sort", "org,resources"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Ws(args),
+            } => {
+                assert_eq!(args.sort, vec![WsSortField::Org, WsSortField::Resources]);
+            }
+            _ => panic!("Expected Get Ws command"),
+        }
+
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Ws(args),
+            } => assert_eq!(args.sort, vec![WsSortField::Name]),
+            _ => panic!("Expected Get Ws command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_confirmable_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "my-org", "--confirmable"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert!(args.confirmable);
             }
             _ => panic!("Expected Get Run command"),
         }
     }
 
     #[test]
-    fn test_get_run_sort_options() {
-        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "my-org", "-s", "ws-id"]);
+    fn test_get_run_current_wait_flags() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--ws", "my-ws", "--current", "--wait"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => {
+                assert!(args.current);
+                assert!(args.wait);
+                assert_eq!(args.ws.as_deref(), Some("my-ws"));
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_newer_than() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "o", "--newer-than", "run-abc"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => assert_eq!(args.newer_than.as_deref(), Some("run-abc")),
+            _ => panic!("Expected Get Run command"),
+        }
+        assert!(
+            Cli::try_parse_from(["hcp", "get", "run", "run-x", "--newer-than", "run-y"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_get_run_count_by() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "o", "--count-by", "ws-id"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => assert_eq!(args.count_by, Some(RunCountField::WsId)),
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_member_alias() {
+        let cli = Cli::parse_from([
+            "hcp", "get", "member", "--org", "my-org", "--status", "invited", "-f", "@corp",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::OrgMember(args),
+            } => {
+                assert_eq!(args.org.as_deref(), Some("my-org"));
+                assert_eq!(args.status.as_deref(), Some("invited"));
+                assert_eq!(args.filter.as_deref(), Some("@corp"));
+            }
+            _ => panic!("Expected Get OrgMember command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_watch() {
+        let parse_watch = |extra: &[&str]| {
+            let mut argv = vec!["hcp", "get", "run", "--org", "o"];
+            argv.extend_from_slice(extra);
+            match Cli::parse_from(argv).command {
+                Command::Get {
+                    resource: GetResource::Run(args),
+                } => args.watch,
+                _ => panic!("Expected Get Run command"),
+            }
+        };
+        assert_eq!(parse_watch(&[]), None);
+        assert_eq!(parse_watch(&["--watch"]), Some(5));
+        assert_eq!(parse_watch(&["--watch", "10"]), Some(10));
+        assert!(Cli::try_parse_from(["hcp", "get", "run", "run-x", "--watch"]).is_err());
+    }
+
+    #[test]
+    fn test_get_run_current_requires_ws() {
+        assert!(Cli::try_parse_from(["hcp", "get", "run", "--current"]).is_err());
+        assert!(Cli::try_parse_from(["hcp", "get", "run", "--ws", "ws-1", "--wait"]).is_err());
+    }
+
+    #[test]
+    fn test_get_run_yes_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "my-org", "-y"]);
         match cli.command {
             Command::Get {
                 resource: GetResource::Run(args),
             } => {
-                assert_eq!(args.sort, RunSortField::WsId);
+                assert!(args.yes);
+            }
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_get_run_alias() {
+        let cli = Cli::parse_from(["hcp", "get", "runs"]);
+        assert!(matches!(
+            cli.command,
+            Command::Get {
+                resource: GetResource::Run(_)
+            }
+        ));
+    }
+
+    #[test]
+    fn test_run_sort_field_display() {
+        assert_eq!(RunSortField::CreatedAt.to_string(), "created-at");
+        assert_eq!(RunSortField::Status.to_string(), "status");
+        assert_eq!(RunSortField::WsId.to_string(), "ws-id");
+    }
+
+    #[test]
+    fn test_logs_command_with_run_id() {
+        let cli = Cli::parse_from(["hcp", "logs", "run-abc123"]);
+        match cli.command {
+            Command::Logs(args) => {
+                assert_eq!(args.target, "run-abc123");
+                assert!(!args.apply);
+                assert!(!args.follow);
+                assert!(!args.raw);
+                assert!(args.org.is_none());
+            }
+            _ => panic!("Expected Logs command"),
+        }
+    }
+
+    #[test]
+    fn test_logs_command_with_workspace_name() {
+        let cli = Cli::parse_from(["hcp", "logs", "my-workspace", "-O", "my-org"]);
+        match cli.command {
+            Command::Logs(args) => {
+                assert_eq!(args.target, "my-workspace");
+                assert_eq!(args.org, Some("my-org".to_string()));
+            }
+            _ => panic!("Expected Logs command"),
+        }
+    }
+
+    #[test]
+    fn test_logs_command_with_apply_flag() {
+        let cli = Cli::parse_from(["hcp", "logs", "run-abc123", "--apply"]);
+        match cli.command {
+            Command::Logs(args) => {
+                assert!(args.apply);
+            }
+            _ => panic!("Expected Logs command"),
+        }
+    }
+
+    #[test]
+    fn test_logs_command_with_follow_flag() {
+        let cli = Cli::parse_from(["hcp", "logs", "run-abc123", "-f"]);
+        match cli.command {
+            Command::Logs(args) => {
+                assert!(args.follow);
+            }
+            _ => panic!("Expected Logs command"),
+        }
+
+        let cli = Cli::parse_from(["hcp", "logs", "run-abc123", "--apply", "--tail"]);
+        match cli.command {
+            Command::Logs(args) => {
+                assert!(args.follow);
+                assert!(args.apply);
+            }
+            _ => panic!("Expected Logs command"),
+        }
+    }
+
+    #[test]
+    fn test_logs_command_with_raw_flag() {
+        let cli = Cli::parse_from(["hcp", "logs", "run-abc123", "--raw"]);
+        match cli.command {
+            Command::Logs(args) => {
+                assert!(args.raw);
+            }
+            _ => panic!("Expected Logs command"),
+        }
+    }
+
+    #[test]
+    fn test_logs_command_all_options() {
+        let cli = Cli::parse_from(["hcp", "logs", "my-ws", "-O", "my-org", "-a", "-f", "--raw"]);
+        match cli.command {
+            Command::Logs(args) => {
+                assert_eq!(args.target, "my-ws");
+                assert_eq!(args.org, Some("my-org".to_string()));
+                assert!(args.apply);
+                assert!(args.follow);
+                assert!(args.raw);
+            }
+            _ => panic!("Expected Logs command"),
+        }
+    }
+
+    #[test]
+    fn test_run_apply_command() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "run",
+            "apply",
+            "run-abc123",
+            "--comment",
+            "LGTM",
+            "-y",
+        ]);
+        match &cli.command {
+            Command::Run {
+                action: RunAction::Apply(args),
+            } => {
+                assert_eq!(args.run_id.as_deref(), Some("run-abc123"));
+                assert_eq!(args.comment, Some("LGTM".to_string()));
+                assert!(args.yes);
+            }
+            _ => panic!("Expected Run Apply command"),
+        }
+        assert!(cli.command.is_mutating());
+
+        let cli = Cli::parse_from(["hcp", "run", "confirm", "run-abc123"]);
+        match cli.command {
+            Command::Run {
+                action: RunAction::Apply(args),
+            } => assert!(!args.yes && args.comment.is_none()),
+            _ => panic!("Expected Run Apply command"),
+        }
+    }
+
+    #[test]
+    fn test_run_apply_batch_filters() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "run",
+            "apply",
+            "--org",
+            "my-org",
+            "--status",
+            "planned",
+            "--dry-run",
+        ]);
+        match cli.command {
+            Command::Run {
+                action: RunAction::Apply(args),
+            } => {
+                assert!(args.run_id.is_none());
+                assert_eq!(args.org.as_deref(), Some("my-org"));
+                assert_eq!(args.status.as_deref(), Some("planned"));
+                assert!(args.dry_run);
             }
-            _ => panic!("Expected Get Run command"),
+            _ => panic!("Expected Run Apply command"),
         }
+        assert!(Cli::try_parse_from(["hcp", "run", "apply", "run-x", "--ws", "app"]).is_err());
     }
 
     #[test]
-    fn test_get_run_yes_flag() {
-        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "my-org", "-y"]);
-        match cli.command {
-            Command::Get {
-                resource: GetResource::Run(args),
+    fn test_run_override_command() {
+        let cli = Cli::parse_from(["hcp", "run", "override", "polchk-abc123", "-y"]);
+        match &cli.command {
+            Command::Run {
+                action: RunAction::Override(args),
             } => {
+                assert_eq!(args.target, "polchk-abc123");
                 assert!(args.yes);
             }
-            _ => panic!("Expected Get Run command"),
+            _ => panic!("Expected Run Override command"),
         }
+        assert!(cli.command.is_mutating());
+        assert!(Cli::try_parse_from(["hcp", "run", "override"]).is_err());
     }
 
     #[test]
-    fn test_get_run_alias() {
-        let cli = Cli::parse_from(["hcp", "get", "runs"]);
-        assert!(matches!(
-            cli.command,
-            Command::Get {
-                resource: GetResource::Run(_)
-            }
-        ));
+    fn test_limit_is_global() {
+        assert_eq!(Cli::parse_from(["hcp", "get", "ws"]).limit, None);
+        assert_eq!(
+            Cli::parse_from(["hcp", "get", "run", "--org", "o", "-n", "10"]).limit,
+            Some(10)
+        );
+        assert_eq!(
+            Cli::parse_from(["hcp", "--limit", "5", "get", "team"]).limit,
+            Some(5)
+        );
+        assert!(Cli::try_parse_from(["hcp", "get", "ws", "-n", "many"]).is_err());
     }
 
     #[test]
-    fn test_run_sort_field_display() {
-        assert_eq!(RunSortField::CreatedAt.to_string(), "created-at");
-        assert_eq!(RunSortField::Status.to_string(), "status");
-        assert_eq!(RunSortField::WsId.to_string(), "ws-id");
+    fn test_absolute_time_is_global() {
+        assert!(!Cli::parse_from(["hcp", "get", "ws"]).absolute_time);
+        assert!(Cli::parse_from(["hcp", "get", "ws", "--absolute-time"]).absolute_time);
     }
 
     #[test]
-    fn test_logs_command_with_run_id() {
-        let cli = Cli::parse_from(["hcp", "logs", "run-abc123"]);
-        match cli.command {
-            Command::Logs(args) => {
-                assert_eq!(args.target, "run-abc123");
-                assert!(!args.apply);
-                assert!(!args.follow);
-                assert!(!args.raw);
-                assert!(args.org.is_none());
-            }
-            _ => panic!("Expected Logs command"),
-        }
+    fn test_no_color_is_global() {
+        assert!(!Cli::parse_from(["hcp", "get", "run"]).no_color);
+        assert!(Cli::parse_from(["hcp", "get", "run", "--no-color"]).no_color);
     }
 
     #[test]
-    fn test_logs_command_with_workspace_name() {
-        let cli = Cli::parse_from(["hcp", "logs", "my-workspace", "-O", "my-org"]);
-        match cli.command {
-            Command::Logs(args) => {
-                assert_eq!(args.target, "my-workspace");
-                assert_eq!(args.org, Some("my-org".to_string()));
-            }
-            _ => panic!("Expected Logs command"),
-        }
+    fn test_color_flag() {
+        assert_eq!(
+            Cli::parse_from(["hcp", "get", "run"]).color,
+            ColorChoice::Auto
+        );
+        let cli = Cli::parse_from(["hcp", "--color", "always", "get", "run"]);
+        assert_eq!(cli.color, ColorChoice::Always);
+        let cli = Cli::parse_from(["hcp", "get", "run", "--color=never"]);
+        assert_eq!(cli.color, ColorChoice::Never);
+        assert!(Cli::try_parse_from(["hcp", "get", "run", "--color", "sometimes"]).is_err());
     }
 
     #[test]
-    fn test_logs_command_with_apply_flag() {
-        let cli = Cli::parse_from(["hcp", "logs", "run-abc123", "--apply"]);
-        match cli.command {
-            Command::Logs(args) => {
-                assert!(args.apply);
-            }
-            _ => panic!("Expected Logs command"),
-        }
+    fn test_logs_alias() {
+        let cli = Cli::parse_from(["hcp", "log", "run-abc123"]);
+        assert!(matches!(cli.command, Command::Logs(_)));
     }
 
     #[test]
-    fn test_logs_command_with_follow_flag() {
-        let cli = Cli::parse_from(["hcp", "logs", "run-abc123", "-f"]);
+    fn test_graph_command() {
+        let cli = Cli::parse_from(["hcp", "graph", "--org", "my-org"]);
         match cli.command {
-            Command::Logs(args) => {
-                assert!(args.follow);
+            Command::Graph(args) => {
+                assert_eq!(args.org.as_deref(), Some("my-org"));
+                assert_eq!(args.format, GraphFormat::Dot);
             }
-            _ => panic!("Expected Logs command"),
+            _ => panic!("Expected Graph command"),
         }
+
+        let cli = Cli::parse_from(["hcp", "graph", "--org", "my-org", "--format", "mermaid"]);
+        assert!(matches!(
+            cli.command,
+            Command::Graph(GraphArgs {
+                format: GraphFormat::Mermaid,
+                ..
+            })
+        ));
+        assert!(Cli::try_parse_from(["hcp", "graph", "--format", "svg"]).is_err());
     }
 
     #[test]
-    fn test_logs_command_with_raw_flag() {
-        let cli = Cli::parse_from(["hcp", "logs", "run-abc123", "--raw"]);
-        match cli.command {
-            Command::Logs(args) => {
-                assert!(args.raw);
-            }
-            _ => panic!("Expected Logs command"),
-        }
+    fn test_whoami_command() {
+        let cli = Cli::parse_from(["hcp", "whoami"]);
+        assert!(matches!(cli.command, Command::Whoami(_)));
+        assert!(matches!(
+            cli.command.output_format(),
+            Some(OutputFormat::Table)
+        ));
+        assert!(!cli.command.is_mutating());
+
+        let cli = Cli::parse_from(["hcp", "whoami", "-o", "json"]);
+        assert!(matches!(
+            cli.command.output_format(),
+            Some(OutputFormat::Json)
+        ));
     }
 
     #[test]
-    fn test_logs_command_all_options() {
-        let cli = Cli::parse_from(["hcp", "logs", "my-ws", "-O", "my-org", "-a", "-f", "--raw"]);
+    fn test_lock_command_with_reason() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "lock",
+            "my-ws",
+            "-O",
+            "my-org",
+            "--reason",
+            "change freeze",
+        ]);
         match cli.command {
-            Command::Logs(args) => {
-                assert_eq!(args.target, "my-ws");
+            Command::Lock(args) => {
+                assert_eq!(args.workspace, "my-ws");
                 assert_eq!(args.org, Some("my-org".to_string()));
-                assert!(args.apply);
-                assert!(args.follow);
-                assert!(args.raw);
+                assert_eq!(args.reason, Some("change freeze".to_string()));
             }
-            _ => panic!("Expected Logs command"),
+            _ => panic!("Expected Lock command"),
         }
+        assert!(Cli::parse_from(["hcp", "lock", "ws-abc"])
+            .command
+            .is_mutating());
     }
 
     #[test]
-    fn test_logs_alias() {
-        let cli = Cli::parse_from(["hcp", "log", "run-abc123"]);
-        assert!(matches!(cli.command, Command::Logs(_)));
+    fn test_unlock_command_rejects_reason() {
+        let cli = Cli::parse_from(["hcp", "unlock", "ws-abc"]);
+        match &cli.command {
+            Command::Unlock(args) => {
+                assert_eq!(args.workspace, "ws-abc");
+                assert!(args.org.is_none());
+            }
+            _ => panic!("Expected Unlock command"),
+        }
+        assert!(cli.command.is_mutating());
+        assert!(Cli::try_parse_from(["hcp", "unlock", "ws-abc", "--reason", "x"]).is_err());
     }
 
     #[test]
@@ -835,7 +2469,7 @@ mod tests {
             Command::Set {
                 resource: SetResource::Ws(args),
             } => {
-                assert_eq!(args.workspace, "ws-abc123");
+                assert_eq!(args.workspace.as_deref(), Some("ws-abc123"));
                 assert_eq!(args.project, Some("prj-xyz789".to_string()));
                 assert!(args.terraform_version.is_none());
                 assert!(args.org.is_none());
@@ -861,7 +2495,7 @@ mod tests {
             Command::Set {
                 resource: SetResource::Ws(args),
             } => {
-                assert_eq!(args.workspace, "my-workspace");
+                assert_eq!(args.workspace.as_deref(), Some("my-workspace"));
                 assert_eq!(args.project, Some("my-project".to_string()));
                 assert_eq!(args.org, Some("my-org".to_string()));
                 assert!(!args.yes);
@@ -985,7 +2619,7 @@ mod tests {
             Command::Set {
                 resource: SetResource::Ws(args),
             } => {
-                assert_eq!(args.workspace, "ws-abc123");
+                assert_eq!(args.workspace.as_deref(), Some("ws-abc123"));
                 assert!(args.project.is_none());
                 assert_eq!(args.terraform_version, Some("1.7.0".to_string()));
             }
@@ -1006,6 +2640,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_set_ws_settings_toggles() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "set",
+            "ws",
+            "ws-abc123",
+            "--auto-apply",
+            "true",
+            "--speculative",
+            "false",
+        ]);
+        match cli.command {
+            Command::Set {
+                resource: SetResource::Ws(args),
+            } => {
+                assert_eq!(args.auto_apply, Some(true));
+                assert_eq!(args.speculative_enabled, Some(false));
+                assert_eq!(args.queue_all_runs, None);
+                assert_eq!(args.allow_destroy_plan, None);
+
+                let settings = args.requested_settings();
+                assert_eq!(
+                    settings.api_attributes(),
+                    vec![("auto-apply", true), ("speculative-enabled", false)]
+                );
+            }
+            _ => panic!("Expected Set Ws command"),
+        }
+    }
+
+    #[test]
+    fn test_set_ws_settings_toggle_invalid_value() {
+        let result = Cli::try_parse_from(["hcp", "set", "ws", "ws-abc123", "--auto-apply", "yes"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_set_ws_both_flags() {
         let cli = Cli::parse_from([
@@ -1022,7 +2693,7 @@ mod tests {
             Command::Set {
                 resource: SetResource::Ws(args),
             } => {
-                assert_eq!(args.workspace, "ws-abc123");
+                assert_eq!(args.workspace.as_deref(), Some("ws-abc123"));
                 assert_eq!(args.project, Some("prj-xyz789".to_string()));
                 assert_eq!(args.terraform_version, Some("1.9.0".to_string()));
             }
@@ -1046,7 +2717,7 @@ mod tests {
             Command::Set {
                 resource: SetResource::Ws(args),
             } => {
-                assert_eq!(args.workspace, "my-workspace");
+                assert_eq!(args.workspace.as_deref(), Some("my-workspace"));
                 assert!(args.project.is_none());
                 assert_eq!(args.terraform_version, Some("1.6.0".to_string()));
                 assert_eq!(args.org, Some("my-org".to_string()));
@@ -1056,6 +2727,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_set_ws_bulk_terraform_version() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "set",
+            "ws",
+            "--org",
+            "my-org",
+            "-f",
+            "prod",
+            "--tf-version",
+            "1.9.5",
+            "--dry-run",
+        ]);
+        match cli.command {
+            Command::Set {
+                resource: SetResource::Ws(args),
+            } => {
+                assert!(args.workspace.is_none());
+                assert_eq!(args.filter.as_deref(), Some("prod"));
+                assert_eq!(args.terraform_version.as_deref(), Some("1.9.5"));
+                assert!(args.dry_run);
+            }
+            _ => panic!("Expected Set Ws command"),
+        }
+    }
+
+    #[test]
+    fn test_set_ws_bulk_rejects_invalid_combinations() {
+        // Workspace or --filter is required
+        assert!(Cli::try_parse_from(["hcp", "set", "ws", "--tf-version", "1.9.5"]).is_err());
+        // --filter only supports --terraform-version
+        assert!(Cli::try_parse_from(["hcp", "set", "ws", "-f", "prod", "--prj", "p"]).is_err());
+        assert!(Cli::try_parse_from([
+            "hcp",
+            "set",
+            "ws",
+            "-f",
+            "prod",
+            "--tf-version",
+            "1.9.5",
+            "--auto-apply",
+            "true"
+        ])
+        .is_err());
+        // Workspace and --filter are mutually exclusive
+        assert!(Cli::try_parse_from([
+            "hcp",
+            "set",
+            "ws",
+            "app",
+            "-f",
+            "prod",
+            "--tf-version",
+            "1"
+        ])
+        .is_err());
+        // --dry-run requires --filter
+        assert!(
+            Cli::try_parse_from(["hcp", "set", "ws", "app", "--tf-version", "1", "--dry-run"])
+                .is_err()
+        );
+    }
+
     // === Set tag tests ===
 
     #[test]
@@ -1323,6 +3058,74 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_create_oc_args() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "create",
+            "oc",
+            "--org",
+            "my-org",
+            "--service-provider",
+            "github-enterprise",
+            "--api-url",
+            "https://ghe.example.com/api/v3",
+            "--http-url",
+            "https://ghe.example.com",
+            "--oauth-token-string",
+            "secret",
+        ]);
+        match cli.command {
+            Command::Create {
+                resource: CreateResource::Oc(args),
+            } => {
+                assert_eq!(args.org, Some("my-org".to_string()));
+                assert_eq!(args.service_provider, VcsProvider::GithubEnterprise);
+                assert_eq!(args.oauth_token_string, "secret");
+                assert!(args.name.is_none());
+            }
+            _ => panic!("Expected Create Oc command"),
+        }
+    }
+
+    #[test]
+    fn test_vcs_provider_values() {
+        assert_eq!(VcsProvider::Github.to_string(), "github");
+        assert_eq!(
+            VcsProvider::GithubEnterprise.api_value(),
+            "github_enterprise"
+        );
+        assert!(VcsProvider::Github.default_urls().is_some());
+        assert!(VcsProvider::GitlabEnterpriseEdition
+            .default_urls()
+            .is_none());
+    }
+
+    #[test]
+    fn test_delete_oc_args() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "delete",
+            "oauth-client",
+            "GitHub",
+            "--org",
+            "my-org",
+            "--show-workspaces",
+            "-y",
+        ]);
+        match cli.command {
+            Command::Delete {
+                resource: DeleteResource::Oc(args),
+            } => {
+                assert_eq!(args.name, "GitHub");
+                assert_eq!(args.org, Some("my-org".to_string()));
+                assert!(args.show_workspaces);
+                assert!(args.yes);
+            }
+            _ => panic!("Expected Delete Oc command"),
+        }
+    }
+
     // === Delete tag tests ===
 
     #[test]
@@ -1423,6 +3226,51 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // === Get team tests ===
+
+    #[test]
+    fn test_get_team_members() {
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "team",
+            "my-team",
+            "--org",
+            "my-org",
+            "--members",
+        ]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Team(args),
+            } => {
+                assert_eq!(args.name, Some("my-team".to_string()));
+                assert!(args.members);
+                assert!(!args.with_members);
+            }
+            _ => panic!("Expected Get Team command"),
+        }
+    }
+
+    #[test]
+    fn test_get_team_members_requires_name() {
+        let result = Cli::try_parse_from(["hcp", "get", "team", "--org", "my-org", "--members"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_team_with_members_conflicts_with_name() {
+        let cli = Cli::parse_from(["hcp", "get", "team", "--org", "my-org", "--with-members"]);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Team(args),
+            } => assert!(args.with_members),
+            _ => panic!("Expected Get Team command"),
+        }
+
+        let result = Cli::try_parse_from(["hcp", "get", "team", "devs", "--with-members"]);
+        assert!(result.is_err());
+    }
+
     // === Get team-access tests ===
 
     #[test]
@@ -1607,6 +3455,48 @@ mod tests {
 
     // === Config tests (kubectl-style) ===
 
+    #[test]
+    fn test_config_export_import() {
+        let cli = Cli::parse_from(["hcp", "config", "export", "--file", "ctx.json"]);
+        match cli.command {
+            Command::Config {
+                action: ConfigAction::Export(args),
+            } => {
+                assert_eq!(args.file, Some(std::path::PathBuf::from("ctx.json")));
+                assert!(!args.include_tokens);
+            }
+            _ => panic!("Expected Config Export command"),
+        }
+        let cli = Cli::parse_from(["hcp", "config", "import", "ctx.json", "--overwrite"]);
+        match cli.command {
+            Command::Config {
+                action: ConfigAction::Import(args),
+            } => assert!(args.overwrite),
+            _ => panic!("Expected Config Import command"),
+        }
+    }
+
+    #[test]
+    fn test_config_rename_and_current_aliases() {
+        let cli = Cli::parse_from(["hcp", "config", "rename", "old", "new"]);
+        match cli.command {
+            Command::Config {
+                action: ConfigAction::RenameContext(args),
+            } => {
+                assert_eq!(args.old, "old");
+                assert_eq!(args.new, "new");
+            }
+            _ => panic!("Expected Config RenameContext command"),
+        }
+        let cli = Cli::parse_from(["hcp", "config", "current"]);
+        assert!(matches!(
+            cli.command,
+            Command::Config {
+                action: ConfigAction::CurrentContext
+            }
+        ));
+    }
+
     #[test]
     fn test_config_get_contexts() {
         let cli = Cli::parse_from(["hcp", "config", "get-contexts"]);
@@ -1728,6 +3618,39 @@ mod tests {
         assert_eq!(cli.context, Some("prod".to_string()));
     }
 
+    #[test]
+    fn test_global_profile_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "org", "-P", "prod"]);
+        assert_eq!(cli.profile, Some("prod".to_string()));
+        let cli = Cli::parse_from(["hcp", "--profile", "dev", "get", "org"]);
+        assert_eq!(cli.profile, Some("dev".to_string()));
+    }
+
+    #[test]
+    fn test_global_all_orgs_flag() {
+        let cli = Cli::parse_from(["hcp", "get", "ws", "--all-orgs"]);
+        assert!(cli.all_orgs);
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        assert!(!cli.all_orgs);
+    }
+
+    #[test]
+    fn test_arg_given_distinguishes_defaults() {
+        use clap::CommandFactory;
+        let matches = Cli::command().get_matches_from(["hcp", "get", "ws", "--org", "acme"]);
+        assert!(arg_given(&matches, "org"));
+        assert!(!arg_given(&matches, "output"));
+        let matches = Cli::command().get_matches_from(["hcp", "get", "ws", "-o", "table"]);
+        assert!(arg_given(&matches, "output"));
+        assert!(!arg_given(&matches, "org"));
+    }
+
+    #[test]
+    fn test_profile_conflicts_with_context() {
+        let result = Cli::try_parse_from(["hcp", "-P", "prod", "-c", "dev", "get", "org"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_config_set_context_requires_name() {
         let result = Cli::try_parse_from(["hcp", "config", "set-context"]);
@@ -15,7 +15,7 @@ pub enum PurgeResource {
     ///
     ///   1. Fetches workspace info and validates it exists
     ///   2. Fetches current state version metadata
-    ///   3. Displays warning and requires confirmation (type workspace ID)
+    ///   3. Displays warning and requires confirmation (type workspace name)
     ///   4. LOCKS the workspace to prevent concurrent modifications
     ///   5. Downloads the current Terraform state file
     ///   6. Creates a new empty state (preserving lineage, incrementing serial)
@@ -24,7 +24,9 @@ pub enum PurgeResource {
     ///
     /// SAFETY:
     ///
-    ///   - Requires interactive confirmation by default (--batch is ignored)
+    ///   - Use --dry-run to preview the current state version without changes
+    ///   - Requires typing the workspace name to confirm; --batch without
+    ///     --yes aborts instead of prompting
     ///   - Requires exact workspace ID (ws-xxx), NOT workspace name
     ///   - Workspace is locked during the entire operation
     ///   - If upload fails, workspace is still unlocked
@@ -55,7 +57,7 @@ pub enum PurgeResource {
     ///   1. Resolves workspace by name or ID (auto-discovers organization)
     ///   2. Fetches all pending runs and current run
     ///   3. Displays summary table with run details
-    ///   4. Requires user confirmation
+    ///   4. Requires typing the workspace name to confirm (or --yes)
     ///   5. Processes runs: pending first (newest→oldest), then current run
     ///   6. Uses appropriate action (cancel/discard) based on run state
     ///
@@ -73,6 +75,7 @@ pub enum PurgeResource {
     /// NOTES:
     ///
     ///   - Use --dry-run to preview without making changes
+    ///   - --batch without --yes aborts instead of prompting
     ///   - Workspace name can be used (auto-discovers organization)
     ///   - Workspace ID (ws-xxx) can also be used directly
     #[command(verbatim_doc_comment, visible_alias = "runs")]
@@ -92,6 +95,14 @@ pub struct PurgeStateArgs {
     /// Batch mode - no interactive prompts, no spinners
     #[arg(long)]
     pub my_resume_is_updated: bool,
+
+    /// Skip the typed workspace-name confirmation
+    #[arg(short = 'y', long, default_value_t = false)]
+    pub yes: bool,
+
+    /// Preview the current state version and what would be removed without making changes
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 /// Arguments for 'purge run' subcommand
@@ -112,6 +123,10 @@ pub struct PurgeRunArgs {
     /// Preview what would be canceled without making changes
     #[arg(long)]
     pub dry_run: bool,
+
+    /// Skip the typed workspace-name confirmation
+    #[arg(short = 'y', long, default_value_t = false)]
+    pub yes: bool,
 }
 
 #[cfg(test)]
@@ -146,9 +161,22 @@ mod tests {
         match cli.resource {
             PurgeResource::State(args) => {
                 assert_eq!(args.workspace_id, "ws-abc123");
+                assert!(!args.dry_run);
             }
             _ => panic!("Expected State variant"),
         }
+
+        let cli = TestCli::parse_from(["test", "state", "ws-abc123", "--dry-run"]);
+        match cli.resource {
+            PurgeResource::State(args) => assert!(args.dry_run),
+            _ => panic!("Expected State variant"),
+        }
+
+        let cli = TestCli::parse_from(["test", "state", "ws-abc123", "-y"]);
+        match cli.resource {
+            PurgeResource::State(args) => assert!(args.yes),
+            _ => panic!("Expected State variant"),
+        }
     }
 
     #[test]
@@ -211,6 +239,7 @@ mod tests {
                 assert_eq!(args.workspace, "my-workspace");
                 assert_eq!(args.org, Some("my-org".to_string()));
                 assert!(args.dry_run);
+                assert!(!args.yes);
             }
             _ => panic!("Expected Run variant"),
         }
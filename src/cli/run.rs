@@ -0,0 +1,75 @@
+//! Run command actions and arguments
+
+use clap::{Parser, Subcommand};
+
+/// Actions for the 'run' command
+#[derive(Subcommand, Debug)]
+pub enum RunAction {
+    /// Apply planned runs that are waiting for confirmation
+    ///
+    /// Shows the runs and asks for confirmation before applying them.
+    /// Only runs TFE reports as confirmable (e.g. status "planned"
+    /// or "policy_checked") are applied.
+    ///
+    /// Target can be:
+    ///   run-xxx     a single run
+    ///   --ws NAME   every confirmable run in a workspace
+    ///   --org ORG   every confirmable run in an organization
+    ///
+    /// Runs are applied one by one; the first failure stops the batch.
+    #[command(verbatim_doc_comment, visible_alias = "confirm")]
+    Apply(ApplyRunArgs),
+
+    /// Override a soft-failed policy check so the run can continue
+    ///
+    /// Target can be:
+    ///   polchk-xxx  a policy check
+    ///   run-xxx     a run; its single overridable policy check is used
+    ///
+    /// Fails if the run has no overridable policy check, or several.
+    #[command(verbatim_doc_comment)]
+    Override(OverrideRunArgs),
+}
+
+/// Arguments for 'run apply' subcommand
+#[derive(Parser, Debug)]
+pub struct ApplyRunArgs {
+    /// Run ID (run-xxx) to apply; omit to apply runs matching --ws/--org/--status
+    #[arg(conflicts_with_all = ["org", "ws", "status"])]
+    pub run_id: Option<String>,
+
+    /// Organization to apply runs in
+    #[arg(long = "org")]
+    pub org: Option<String>,
+
+    /// Workspace name or ID (ws-xxx) to apply runs in
+    #[arg(long = "ws")]
+    pub ws: Option<String>,
+
+    /// Only runs with these statuses (comma-separated, e.g. planned,policy_checked)
+    #[arg(long)]
+    pub status: Option<String>,
+
+    /// Comment recorded with each confirmation
+    #[arg(long)]
+    pub comment: Option<String>,
+
+    /// Preview which runs would be applied without making changes
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Skip confirmation prompt
+    #[arg(short = 'y', long, default_value_t = false)]
+    pub yes: bool,
+}
+
+/// Arguments for 'run override' subcommand
+#[derive(Parser, Debug)]
+pub struct OverrideRunArgs {
+    /// Policy check ID (polchk-xxx) or run ID (run-xxx)
+    pub target: String,
+
+    /// Skip confirmation prompt
+    #[arg(short = 'y', long, default_value_t = false)]
+    pub yes: bool,
+}
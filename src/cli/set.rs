@@ -2,6 +2,8 @@
 
 use clap::{Parser, Subcommand};
 
+use crate::hcp::WorkspaceSettings;
+
 /// Resource types for the 'set' command
 #[derive(Subcommand, Debug)]
 pub enum SetResource {
@@ -18,11 +20,39 @@ pub enum SetResource {
 }
 
 /// Arguments for 'set ws' subcommand
+///
+/// With `--filter`, every matching workspace in the organization gets the new
+/// Terraform version (other settings are single-workspace only).
 #[derive(Parser, Debug)]
-#[command(group = clap::ArgGroup::new("settings").required(true).multiple(true).args(["project", "terraform_version"]))]
+#[command(group = clap::ArgGroup::new("settings").required(true).multiple(true).args([
+    "project",
+    "terraform_version",
+    "auto_apply",
+    "queue_all_runs",
+    "speculative_enabled",
+    "allow_destroy_plan",
+]))]
 pub struct SetWsArgs {
     /// Workspace name or ID (ws-xxx)
-    pub workspace: String,
+    #[arg(required_unless_present = "filter")]
+    pub workspace: Option<String>,
+
+    /// Update every workspace whose name contains this substring (requires --org
+    /// and --terraform-version)
+    #[arg(
+        short,
+        long,
+        requires = "terraform_version",
+        conflicts_with_all = [
+            "workspace",
+            "project",
+            "auto_apply",
+            "queue_all_runs",
+            "speculative_enabled",
+            "allow_destroy_plan",
+        ]
+    )]
+    pub filter: Option<String>,
 
     /// Target project name or ID (prj-xxx)
     #[arg(long = "prj", short = 'p')]
@@ -32,11 +62,48 @@ pub struct SetWsArgs {
     #[arg(long = "terraform-version", visible_alias = "tf-version")]
     pub terraform_version: Option<String>,
 
+    /// Automatically apply successful plans (true/false)
+    #[arg(long, value_name = "BOOL")]
+    pub auto_apply: Option<bool>,
+
+    /// Queue runs immediately instead of waiting for a VCS webhook (true/false)
+    #[arg(long, value_name = "BOOL")]
+    pub queue_all_runs: Option<bool>,
+
+    /// Allow speculative plans on pull requests (true/false)
+    #[arg(
+        long = "speculative-enabled",
+        visible_alias = "speculative",
+        value_name = "BOOL"
+    )]
+    pub speculative_enabled: Option<bool>,
+
+    /// Allow destroy plans to be queued (true/false)
+    #[arg(long, value_name = "BOOL")]
+    pub allow_destroy_plan: Option<bool>,
+
     /// Organization name (auto-discovered when using workspace ID)
     #[arg(long = "org")]
     pub org: Option<String>,
 
+    /// Preview which workspaces --filter would update without making changes
+    #[arg(long, conflicts_with = "workspace")]
+    pub dry_run: bool,
+
     /// Skip confirmation prompt
     #[arg(short = 'y', long, default_value_t = false)]
     pub yes: bool,
 }
+
+impl SetWsArgs {
+    /// Boolean settings requested on the command line (None = leave unchanged)
+    pub fn requested_settings(&self) -> WorkspaceSettings {
+        WorkspaceSettings {
+            auto_apply: self.auto_apply,
+            queue_all_runs: self.queue_all_runs,
+            speculative_enabled: self.speculative_enabled,
+            global_remote_state: None,
+            allow_destroy_plan: self.allow_destroy_plan,
+        }
+    }
+}
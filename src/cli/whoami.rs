@@ -0,0 +1,13 @@
+//! Whoami command arguments
+
+use clap::Parser;
+
+use super::common::OutputFormat;
+
+/// Arguments for 'whoami' command
+#[derive(Parser, Debug)]
+pub struct WhoamiArgs {
+    /// Output format
+    #[arg(short = 'o', long, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+}
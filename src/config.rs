@@ -1,7 +1,22 @@
 /// Configuration constants for TFE API
 pub mod api {
-    /// Base path for TFE API v2
-    pub const BASE_PATH: &str = "/api/v2";
+    /// Root path for the TFE API (the version segment is appended)
+    pub const ROOT_PATH: &str = "/api";
+
+    /// Default API version segment (yields `/api/v2`)
+    pub const DEFAULT_VERSION: &str = "v2";
+
+    /// Environment variable for overriding the API version
+    pub const VERSION_ENV_VAR: &str = "TFE_API_VERSION";
+
+    /// Environment variable with a PEM CA bundle to trust (fallback for --ca-cert)
+    pub const CA_CERT_ENV_VAR: &str = "TFE_CACERT";
+
+    /// JSON:API media type, sent as Content-Type on every API request
+    pub const MEDIA_TYPE: &str = "application/vnd.api+json";
+
+    /// Account of the authenticated token
+    pub const ACCOUNT_DETAILS: &str = "account/details";
 
     /// Organizations endpoint
     pub const ORGANIZATIONS: &str = "organizations";
@@ -15,6 +30,12 @@ pub mod api {
     /// Runs endpoint
     pub const RUNS: &str = "runs";
 
+    /// Cost estimates endpoint
+    pub const COST_ESTIMATES: &str = "cost-estimates";
+
+    /// Policy checks endpoint
+    pub const POLICY_CHECKS: &str = "policy-checks";
+
     /// State versions endpoint
     pub const STATE_VERSIONS: &str = "state-versions";
 
@@ -24,6 +45,27 @@ pub mod api {
     /// Team projects (team-project access bindings) endpoint
     pub const TEAM_PROJECTS: &str = "team-projects";
 
+    /// Workspace variables endpoint (nested under a workspace)
+    pub const VARS: &str = "vars";
+
+    /// Variable sets endpoint
+    pub const VARSETS: &str = "varsets";
+
+    /// Policy sets endpoint
+    pub const POLICY_SETS: &str = "policy-sets";
+
+    /// Private module registry endpoint (nested under an organization)
+    pub const REGISTRY_MODULES: &str = "registry-modules";
+
+    /// Notification configurations endpoint
+    pub const NOTIFICATION_CONFIGURATIONS: &str = "notification-configurations";
+
+    /// Current assessment result of a workspace (nested under a workspace)
+    pub const CURRENT_ASSESSMENT_RESULT: &str = "current-assessment-result";
+
+    /// Run triggers endpoint (nested under a workspace)
+    pub const RUN_TRIGGERS: &str = "run-triggers";
+
     /// Default page size for API requests
     pub const DEFAULT_PAGE_SIZE: u32 = 100;
 
@@ -31,6 +73,30 @@ pub mod api {
     /// Keep reasonable to avoid overwhelming TFE and hitting rate limits
     pub const MAX_CONCURRENT_PAGE_REQUESTS: usize = 10;
 
+    /// Maximum retries for 503 (maintenance mode) responses with --retry-on-503
+    pub const MAINTENANCE_MAX_RETRIES: u32 = 5;
+
+    /// Base delay in seconds for 503 retry backoff (doubled on each attempt)
+    pub const MAINTENANCE_BACKOFF_BASE_SECS: u64 = 2;
+
+    /// Upper bound in seconds for a single 503 retry delay
+    pub const MAINTENANCE_MAX_DELAY_SECS: u64 = 120;
+
+    /// Default maximum retries for 429 (rate limited) responses (`--max-retries`)
+    pub const RATE_LIMIT_MAX_RETRIES: u32 = 5;
+
+    /// Base delay in milliseconds for 429 retry backoff (doubled on each attempt)
+    pub const RATE_LIMIT_BACKOFF_BASE_MS: u64 = 1000;
+
+    /// Upper bound in seconds for a single 429 retry delay
+    pub const RATE_LIMIT_MAX_DELAY_SECS: u64 = 60;
+
+    /// Times a dropped file download is resumed before giving up
+    pub const DOWNLOAD_MAX_RETRIES: u32 = 3;
+
+    /// Base delay in milliseconds before resuming a download (doubled on each attempt)
+    pub const DOWNLOAD_RETRY_BASE_MS: u64 = 500;
+
     /// Threshold for large result set warning (requires confirmation)
     /// Fetching more than this many items triggers a DoS warning
     pub const LARGE_RESULT_THRESHOLD: u32 = 1000;
@@ -68,6 +134,29 @@ pub mod context {
     pub const DIR_NAME: &str = ".hcpctl";
     /// Config file name
     pub const FILE_NAME: &str = "config.json";
+    /// Schema version written by `config export` and accepted by `config import`
+    pub const EXPORT_VERSION: u32 = 1;
+}
+
+/// Configuration for the on-disk response cache (`--cache`)
+pub mod cache {
+    /// Cache directory name (under the hcpctl config directory)
+    pub const DIR_NAME: &str = "cache";
+    /// Default time-to-live for cached responses, in seconds
+    pub const DEFAULT_TTL_SECS: u64 = 300;
+    /// Environment variable enabling the cache
+    pub const ENV_VAR: &str = "HCPCTL_CACHE";
+}
+
+/// Configuration for dynamic shell completion (`hcpctl __complete`)
+pub mod complete {
+    use std::time::Duration;
+
+    /// Maximum number of candidates printed
+    pub const MAX_RESULTS: usize = 50;
+
+    /// Overall time budget; completion prints nothing rather than block the prompt
+    pub const TIMEOUT: Duration = Duration::from_secs(2);
 }
 
 /// Configuration for update checker
@@ -83,6 +172,15 @@ pub mod update {
     /// Timeout for GitHub API request
     pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
 
+    /// How long the update notice stays hidden after being shown once
+    pub const NOTICE_SNOOZE: Duration = Duration::from_secs(7 * 24 * 60 * 60); // 7 days
+
+    /// Environment variable overriding the notice snooze period (hours, 0 = never snooze)
+    pub const NOTICE_SNOOZE_ENV_VAR: &str = "HCPCTL_UPDATE_NOTICE_SNOOZE_HOURS";
+
+    /// Environment variable disabling the background update check (e.g. `1`)
+    pub const NO_CHECK_ENV_VAR: &str = "HCPCTL_NO_UPDATE_CHECK";
+
     /// Install script URLs
     pub mod install {
         /// Unix install script
@@ -100,8 +198,20 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_api_base_path_format() {
-        assert!(api::BASE_PATH.starts_with('/'));
+    fn test_api_root_path_format() {
+        assert!(api::ROOT_PATH.starts_with('/'));
+        assert!(!api::ROOT_PATH.ends_with('/'));
+    }
+
+    #[test]
+    fn test_api_media_type() {
+        assert_eq!(api::MEDIA_TYPE, "application/vnd.api+json");
+    }
+
+    #[test]
+    fn test_api_default_version() {
+        assert_eq!(api::DEFAULT_VERSION, "v2");
+        assert_eq!(api::VERSION_ENV_VAR, "TFE_API_VERSION");
     }
 
     #[test]
@@ -123,6 +233,8 @@ mod tests {
         assert_eq!(api::PROJECTS, "projects");
         assert_eq!(api::WORKSPACES, "workspaces");
         assert_eq!(api::RUNS, "runs");
+        assert_eq!(api::COST_ESTIMATES, "cost-estimates");
+        assert_eq!(api::POLICY_CHECKS, "policy-checks");
         assert_eq!(api::TEAMS, "teams");
         assert_eq!(api::TEAM_PROJECTS, "team-projects");
     }
@@ -1,23 +1,35 @@
 //! Context command handlers
 
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, ContentArrangement, Table};
+use std::io::Write;
+use std::path::Path;
 
-use crate::cli::{ConfigAction, SetContextArgs};
+use crate::cli::{ConfigAction, ExportContextsArgs, ImportContextsArgs, SetContextArgs};
+use crate::config::context as context_config;
 use crate::error::TfeError;
 
-use super::models::Context;
+use super::models::{Context, ContextExport};
+use super::resolve::resolve_active_context_source;
 use super::store::ContextStore;
 
 /// Dispatch context subcommands
-pub fn run_context_command(action: &ConfigAction) -> Result<(), Box<dyn std::error::Error>> {
+///
+/// `cli_context` is the global `--context` flag, honored by `current-context`.
+pub fn run_context_command(
+    action: &ConfigAction,
+    cli_context: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
     let store = ContextStore::new();
     match action {
         ConfigAction::GetContexts => run_context_list(&store),
         ConfigAction::SetContext(args) => run_context_set(&store, args),
         ConfigAction::UseContext(args) => run_context_use(&store, &args.name),
         ConfigAction::DeleteContext(args) => run_context_delete(&store, &args.name),
-        ConfigAction::CurrentContext => run_context_show(&store),
+        ConfigAction::CurrentContext => run_context_show(&store, cli_context),
+        ConfigAction::RenameContext(args) => run_context_rename(&store, &args.old, &args.new),
         ConfigAction::View => run_config_view(&store),
+        ConfigAction::Export(args) => run_context_export(&store, args),
+        ConfigAction::Import(args) => run_context_import(&store, args),
     }
 }
 
@@ -63,21 +75,29 @@ fn run_context_list(store: &ContextStore) -> Result<(), Box<dyn std::error::Erro
     Ok(())
 }
 
-/// Show the current context details
-fn run_context_show(store: &ContextStore) -> Result<(), Box<dyn std::error::Error>> {
+/// Show the active context details
+///
+/// Resolves like every other command: --context, then HCPCTL_CONTEXT, then
+/// the config file's current-context.
+fn run_context_show(
+    store: &ContextStore,
+    cli_context: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
     let config = store.load()?;
 
-    let current_name = config.current_context.as_ref().ok_or_else(|| {
-        TfeError::Config(
-            "No current context set. Use 'hcpctl config use-context <name>' to set one."
-                .to_string(),
-        )
-    })?;
+    let (current_name, source) =
+        resolve_active_context_source(cli_context, store).ok_or_else(|| {
+            TfeError::Config(
+                "No current context set. Use 'hcpctl config use-context <name>' to set one."
+                    .to_string(),
+            )
+        })?;
 
-    let ctx = config.contexts.get(current_name).ok_or_else(|| {
+    let ctx = config.contexts.get(&current_name).ok_or_else(|| {
         TfeError::Config(format!(
-            "Current context '{}' not found in config. Available: {}",
+            "Current context '{}' (from {}) not found in config. Available: {}",
             current_name,
+            source,
             config
                 .contexts
                 .keys()
@@ -87,10 +107,16 @@ fn run_context_show(store: &ContextStore) -> Result<(), Box<dyn std::error::Erro
         ))
     })?;
 
-    println!("Current context: {}", current_name);
+    println!("Current context: {} (from {})", current_name, source);
     println!("  Host:  {}", ctx.host);
     println!("  Token: {}", mask_token(ctx.token.as_deref()));
     println!("  Org:   {}", ctx.org.as_deref().unwrap_or("<not set>"));
+    if let Some(format) = ctx.output_format {
+        println!("  Output: {}", format);
+    }
+    if let Some(color) = ctx.color {
+        println!("  Color: {}", color);
+    }
 
     Ok(())
 }
@@ -113,6 +139,12 @@ fn run_context_set(
         if args.org.is_some() {
             existing.org = args.org.clone();
         }
+        if args.default_output.is_some() {
+            existing.output_format = args.default_output;
+        }
+        if args.default_color.is_some() {
+            existing.color = args.default_color;
+        }
         store.save(&config)?;
         println!("✓ Updated context '{}'", args.name);
     } else {
@@ -129,6 +161,8 @@ fn run_context_set(
             host: host.clone(),
             token: args.token.clone(),
             org: args.org.clone(),
+            output_format: args.default_output,
+            color: args.default_color,
         };
 
         config.contexts.insert(args.name.clone(), ctx);
@@ -170,6 +204,42 @@ fn run_context_use(store: &ContextStore, name: &str) -> Result<(), Box<dyn std::
     Ok(())
 }
 
+/// Rename a context, moving the current-context pointer along with it
+fn run_context_rename(
+    store: &ContextStore,
+    old: &str,
+    new: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut config = store.load()?;
+
+    if config.contexts.contains_key(new) {
+        return Err(TfeError::Config(format!("Context '{}' already exists", new)).into());
+    }
+    let Some(ctx) = config.contexts.remove(old) else {
+        return Err(TfeError::Config(format!(
+            "Context '{}' not found. Available contexts: {}",
+            old,
+            config
+                .contexts
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+        .into());
+    };
+    config.contexts.insert(new.to_string(), ctx);
+
+    if config.current_context.as_deref() == Some(old) {
+        config.current_context = Some(new.to_string());
+    }
+
+    store.save(&config)?;
+    println!("✓ Renamed context '{}' to '{}'", old, new);
+
+    Ok(())
+}
+
 /// Delete a named context
 fn run_context_delete(store: &ContextStore, name: &str) -> Result<(), Box<dyn std::error::Error>> {
     let mut config = store.load()?;
@@ -208,6 +278,137 @@ fn run_config_view(store: &ContextStore) -> Result<(), Box<dyn std::error::Error
     Ok(())
 }
 
+/// Export all contexts, redacting tokens unless --include-tokens
+fn run_context_export(
+    store: &ContextStore,
+    args: &ExportContextsArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = store.load()?;
+    let export = ContextExport {
+        version: context_config::EXPORT_VERSION,
+        contexts: config
+            .contexts
+            .into_iter()
+            .map(|(name, mut ctx)| {
+                if !args.include_tokens {
+                    ctx.token = None;
+                }
+                (name, ctx)
+            })
+            .collect(),
+    };
+    let json = serde_json::to_string_pretty(&export)
+        .map_err(|e| TfeError::Config(format!("Failed to serialize contexts: {}", e)))?;
+
+    let Some(path) = &args.file else {
+        println!("{}", json);
+        return Ok(());
+    };
+    write_export(path, format!("{}\n", json).as_bytes(), args.include_tokens)
+        .map_err(|e| TfeError::Config(format!("Failed to write {}: {}", path.display(), e)))?;
+    let redacted = if args.include_tokens {
+        "tokens included"
+    } else {
+        "tokens redacted"
+    };
+    println!(
+        "✓ Exported {} context(s) to {} ({})",
+        export.contexts.len(),
+        path.display(),
+        redacted
+    );
+    Ok(())
+}
+
+/// Write an export file; with tokens it is owner-only (0600 on Unix)
+///
+/// The mode is set on creation and an existing file is narrowed through the
+/// open handle, both before any content is written.
+fn write_export(path: &Path, content: &[u8], private: bool) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    if private {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+    }
+    let mut file = options.open(path)?;
+    if private {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        }
+    }
+    file.write_all(content)
+}
+
+/// Merge contexts from an export file into the config
+///
+/// Existing names are skipped unless --overwrite. An overwritten context keeps
+/// its stored token when the export was redacted.
+fn run_context_import(
+    store: &ContextStore,
+    args: &ImportContextsArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let content = std::fs::read_to_string(&args.file)
+        .map_err(|e| TfeError::Config(format!("Failed to read {}: {}", args.file.display(), e)))?;
+    let export = parse_export(&content)
+        .map_err(|e| TfeError::Config(format!("{}: {}", args.file.display(), e)))?;
+
+    let mut config = store.load()?;
+    let (mut added, mut replaced, mut skipped) = (0, 0, Vec::new());
+    for (name, mut ctx) in export.contexts {
+        match config.contexts.get(&name) {
+            Some(_) if !args.overwrite => skipped.push(name),
+            Some(existing) => {
+                if ctx.token.is_none() {
+                    ctx.token = existing.token.clone();
+                }
+                config.contexts.insert(name, ctx);
+                replaced += 1;
+            }
+            None => {
+                config.contexts.insert(name, ctx);
+                added += 1;
+            }
+        }
+    }
+    store.save(&config)?;
+
+    println!(
+        "✓ Imported contexts: {} added, {} replaced",
+        added, replaced
+    );
+    if !skipped.is_empty() {
+        println!(
+            "  Skipped existing: {} (use --overwrite to replace)",
+            skipped.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Parse an export file, rejecting unknown schema versions
+fn parse_export(content: &str) -> Result<ContextExport, String> {
+    let value: serde_json::Value =
+        serde_json::from_str(content).map_err(|e| format!("not valid JSON: {}", e))?;
+    match value.get("version").and_then(|v| v.as_u64()) {
+        Some(v) if v == u64::from(context_config::EXPORT_VERSION) => {}
+        Some(v) => {
+            return Err(format!(
+                "unsupported context export version {} (expected {})",
+                v,
+                context_config::EXPORT_VERSION
+            ))
+        }
+        None => return Err("not a context export (missing \"version\")".to_string()),
+    }
+    serde_json::from_value(value).map_err(|e| format!("invalid context export: {}", e))
+}
+
 /// Mask a token for display — show last 4 chars or "<not set>"
 fn mask_token(token: Option<&str>) -> String {
     match token {
@@ -220,6 +421,7 @@ fn mask_token(token: Option<&str>) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::{ColorChoice, OutputFormat};
     use crate::context::models::{Context, ContextConfig};
     use tempfile::TempDir;
 
@@ -256,6 +458,8 @@ mod tests {
             host: None,
             token: None,
             org: None,
+            default_output: None,
+            default_color: None,
         };
         let result = run_context_set(&store, &args);
         assert!(result.is_err());
@@ -272,6 +476,8 @@ mod tests {
             host: Some("app.terraform.io".to_string()),
             token: Some("my-token".to_string()),
             org: Some("my-org".to_string()),
+            default_output: None,
+            default_color: None,
         };
         run_context_set(&store, &args).unwrap();
 
@@ -295,6 +501,8 @@ mod tests {
             host: Some("old-host.com".to_string()),
             token: Some("old-token".to_string()),
             org: Some("old-org".to_string()),
+            default_output: None,
+            default_color: None,
         };
         run_context_set(&store, &args).unwrap();
 
@@ -304,6 +512,8 @@ mod tests {
             host: None,
             token: None,
             org: Some("new-org".to_string()),
+            default_output: None,
+            default_color: None,
         };
         run_context_set(&store, &args).unwrap();
 
@@ -313,6 +523,37 @@ mod tests {
         assert_eq!(config.contexts["prod"].org, Some("new-org".to_string())); // updated
     }
 
+    #[test]
+    fn test_context_set_output_preferences() {
+        let dir = TempDir::new().unwrap();
+        let store = test_store(&dir);
+
+        let args = SetContextArgs {
+            name: "prod".to_string(),
+            host: Some("app.terraform.io".to_string()),
+            token: None,
+            org: None,
+            default_output: Some(OutputFormat::Yaml),
+            default_color: None,
+        };
+        run_context_set(&store, &args).unwrap();
+
+        // Updating color keeps the stored output format
+        let args = SetContextArgs {
+            name: "prod".to_string(),
+            host: None,
+            token: None,
+            org: None,
+            default_output: None,
+            default_color: Some(ColorChoice::Never),
+        };
+        run_context_set(&store, &args).unwrap();
+
+        let ctx = &store.load().unwrap().contexts["prod"];
+        assert_eq!(ctx.output_format, Some(OutputFormat::Yaml));
+        assert_eq!(ctx.color, Some(ColorChoice::Never));
+    }
+
     #[test]
     fn test_context_use_sets_current() {
         let dir = TempDir::new().unwrap();
@@ -326,6 +567,8 @@ mod tests {
                 host: "prod.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         config.contexts.insert(
@@ -334,6 +577,8 @@ mod tests {
                 host: "dev.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         store.save(&config).unwrap();
@@ -369,6 +614,8 @@ mod tests {
                 host: "prod.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         store.save(&config).unwrap();
@@ -403,6 +650,8 @@ mod tests {
                 host: "prod.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         config.contexts.insert(
@@ -411,6 +660,8 @@ mod tests {
                 host: "dev.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         store.save(&config).unwrap();
@@ -422,6 +673,165 @@ mod tests {
         assert_eq!(config.contexts.len(), 1);
     }
 
+    #[test]
+    fn test_context_rename_moves_current_pointer() {
+        let dir = TempDir::new().unwrap();
+        let store = store_with(
+            &dir,
+            &[("prod", "prod.com", Some("t")), ("dev", "dev.com", None)],
+        );
+        run_context_use(&store, "prod").unwrap();
+
+        run_context_rename(&store, "prod", "production").unwrap();
+
+        let config = store.load().unwrap();
+        assert!(!config.contexts.contains_key("prod"));
+        assert_eq!(config.contexts["production"].host, "prod.com");
+        assert_eq!(config.contexts["production"].token.as_deref(), Some("t"));
+        assert_eq!(config.current_context.as_deref(), Some("production"));
+    }
+
+    #[test]
+    fn test_context_rename_errors() {
+        let dir = TempDir::new().unwrap();
+        let store = store_with(
+            &dir,
+            &[("prod", "prod.com", None), ("dev", "dev.com", None)],
+        );
+
+        let err = run_context_rename(&store, "missing", "x")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'missing' not found"), "{}", err);
+        let err = run_context_rename(&store, "prod", "dev")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'dev' already exists"), "{}", err);
+        assert_eq!(store.load().unwrap().contexts.len(), 2);
+    }
+
+    #[test]
+    fn test_context_show_prefers_cli_flag() {
+        let dir = TempDir::new().unwrap();
+        let store = store_with(&dir, &[("prod", "prod.com", None)]);
+        assert!(run_context_show(&store, Some("prod")).is_ok());
+        let err = run_context_show(&store, Some("nope"))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("'nope' (from --context flag) not found"),
+            "{}",
+            err
+        );
+    }
+
+    fn store_with(dir: &TempDir, contexts: &[(&str, &str, Option<&str>)]) -> ContextStore {
+        let store = test_store(dir);
+        let mut config = ContextConfig::default();
+        for (name, host, token) in contexts {
+            config.contexts.insert(
+                name.to_string(),
+                Context {
+                    host: host.to_string(),
+                    token: token.map(str::to_string),
+                    org: None,
+                    output_format: None,
+                    color: None,
+                },
+            );
+        }
+        store.save(&config).unwrap();
+        store
+    }
+
+    fn export_to(store: &ContextStore, dir: &TempDir, include_tokens: bool) -> String {
+        let path = dir.path().join("export.json");
+        let args = ExportContextsArgs {
+            file: Some(path.clone()),
+            include_tokens,
+        };
+        run_context_export(store, &args).unwrap();
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn test_context_export_redacts_tokens_by_default() {
+        let dir = TempDir::new().unwrap();
+        let store = store_with(&dir, &[("prod", "prod.com", Some("secret-token"))]);
+
+        let json = export_to(&store, &dir, false);
+        assert!(!json.contains("secret-token"), "{}", json);
+        let export = parse_export(&json).unwrap();
+        assert_eq!(export.version, context_config::EXPORT_VERSION);
+        assert_eq!(export.contexts["prod"].host, "prod.com");
+
+        let json = export_to(&store, &dir, true);
+        assert!(json.contains("secret-token"), "{}", json);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_context_export_with_tokens_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = TempDir::new().unwrap();
+        let store = store_with(&dir, &[("prod", "prod.com", Some("secret-token"))]);
+        let path = dir.path().join("export.json");
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        export_to(&store, &dir, true);
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_context_import_skips_or_overwrites_collisions() {
+        let src_dir = TempDir::new().unwrap();
+        let src = store_with(
+            &src_dir,
+            &[("prod", "new-prod.com", None), ("dev", "dev.com", None)],
+        );
+        export_to(&src, &src_dir, false);
+        let file = src_dir.path().join("export.json");
+
+        let dir = TempDir::new().unwrap();
+        let store = store_with(&dir, &[("prod", "old-prod.com", Some("kept-token"))]);
+
+        let args = ImportContextsArgs {
+            file: file.clone(),
+            overwrite: false,
+        };
+        run_context_import(&store, &args).unwrap();
+        let config = store.load().unwrap();
+        assert_eq!(config.contexts["prod"].host, "old-prod.com");
+        assert_eq!(config.contexts["dev"].host, "dev.com");
+
+        let args = ImportContextsArgs {
+            file,
+            overwrite: true,
+        };
+        run_context_import(&store, &args).unwrap();
+        let config = store.load().unwrap();
+        assert_eq!(config.contexts["prod"].host, "new-prod.com");
+        // A redacted export does not wipe the stored token
+        assert_eq!(config.contexts["prod"].token.as_deref(), Some("kept-token"));
+    }
+
+    #[test]
+    fn test_parse_export_validates_version() {
+        let err = parse_export(r#"{"version": 99, "contexts": {}}"#).unwrap_err();
+        assert!(
+            err.contains("unsupported context export version 99"),
+            "{}",
+            err
+        );
+        let err = parse_export(r#"{"contexts": {}}"#).unwrap_err();
+        assert!(err.contains("missing"), "{}", err);
+        assert!(parse_export("not json").is_err());
+        assert!(parse_export(r#"{"version": 1, "contexts": {}}"#).is_ok());
+    }
+
     #[test]
     fn test_context_set_second_does_not_auto_set_current() {
         let dir = TempDir::new().unwrap();
@@ -433,6 +843,8 @@ mod tests {
             host: Some("first.com".to_string()),
             token: None,
             org: None,
+            default_output: None,
+            default_color: None,
         };
         run_context_set(&store, &args).unwrap();
         assert_eq!(
@@ -446,6 +858,8 @@ mod tests {
             host: Some("second.com".to_string()),
             token: None,
             org: None,
+            default_output: None,
+            default_color: None,
         };
         run_context_set(&store, &args).unwrap();
         assert_eq!(
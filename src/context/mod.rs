@@ -9,6 +9,6 @@ mod resolve;
 mod store;
 
 pub use commands::run_context_command;
-pub use models::{Context, ContextConfig};
-pub use resolve::resolve_active_context;
+pub use models::{Context, ContextConfig, ContextExport};
+pub use resolve::{apply_context_defaults, resolve_active_context, resolve_profile};
 pub use store::ContextStore;
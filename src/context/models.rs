@@ -3,6 +3,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
+use crate::cli::{ColorChoice, OutputFormat};
+
 /// Top-level context configuration
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct ContextConfig {
@@ -25,6 +27,25 @@ pub struct Context {
     /// Default organization
     #[serde(skip_serializing_if = "Option::is_none")]
     pub org: Option<String>,
+    /// Default `-o` format when the flag is not given
+    #[serde(
+        rename = "output-format",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub output_format: Option<OutputFormat>,
+    /// Default `--color` policy when neither `--color` nor `--no-color` is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<ColorChoice>,
+}
+
+/// Portable set of contexts written by `config export`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContextExport {
+    /// Schema version, checked on import
+    pub version: u32,
+    /// Exported contexts (tokens omitted unless explicitly included)
+    pub contexts: BTreeMap<String, Context>,
 }
 
 #[cfg(test)]
@@ -50,6 +71,8 @@ mod tests {
                 host: "app.terraform.io".to_string(),
                 token: Some("secret-token".to_string()),
                 org: Some("my-org".to_string()),
+                output_format: None,
+                color: None,
             },
         );
         config.contexts.insert(
@@ -58,6 +81,8 @@ mod tests {
                 host: "tfe-dev.corp.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
 
@@ -96,6 +121,8 @@ mod tests {
                 host: "example.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         let json = serde_json::to_string(&config).unwrap();
@@ -112,6 +139,8 @@ mod tests {
                 host: "z.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         config.contexts.insert(
@@ -120,6 +149,8 @@ mod tests {
                 host: "a.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         config.contexts.insert(
@@ -128,6 +159,8 @@ mod tests {
                 host: "m.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
 
@@ -157,10 +190,29 @@ mod tests {
             host: "example.com".to_string(),
             token: Some("tok".to_string()),
             org: Some("org".to_string()),
+            output_format: None,
+            color: None,
         };
         let cloned = ctx.clone();
         assert_eq!(cloned.host, ctx.host);
         assert_eq!(cloned.token, ctx.token);
         assert_eq!(cloned.org, ctx.org);
     }
+
+    #[test]
+    fn test_output_preferences_serde() {
+        let ctx: Context =
+            serde_json::from_str(r#"{"host": "h", "output-format": "json", "color": "never"}"#)
+                .unwrap();
+        assert_eq!(ctx.output_format, Some(OutputFormat::Json));
+        assert_eq!(ctx.color, Some(ColorChoice::Never));
+
+        let json = serde_json::to_string(&ctx).unwrap();
+        assert!(json.contains(r#""output-format":"json""#), "{}", json);
+
+        // Older config files without the fields still load
+        let ctx: Context = serde_json::from_str(r#"{"host": "h"}"#).unwrap();
+        assert!(ctx.output_format.is_none() && ctx.color.is_none());
+        assert!(!serde_json::to_string(&ctx).unwrap().contains("color"));
+    }
 }
@@ -1,7 +1,11 @@
 //! Context resolution from multiple sources
 
+use clap::ArgMatches;
 use log::debug;
 
+use crate::cli::{arg_given, Cli};
+use crate::error::TfeError;
+
 use crate::config::context as context_config;
 
 use super::models::Context;
@@ -12,10 +16,19 @@ use super::store::ContextStore;
 /// 2. HCPCTL_CONTEXT env var
 /// 3. current-context from config file
 pub fn resolve_active_context_name(cli_context: Option<&str>) -> Option<String> {
+    resolve_active_context_source(cli_context, &ContextStore::new()).map(|(name, _)| name)
+}
+
+/// Like [`resolve_active_context_name`], also naming the source that won
+/// ("--context flag", the env var name, or "config file")
+pub(crate) fn resolve_active_context_source(
+    cli_context: Option<&str>,
+    store: &ContextStore,
+) -> Option<(String, &'static str)> {
     // 1. CLI flag
     if let Some(name) = cli_context {
         debug!("Using context from CLI flag: {}", name);
-        return Some(name.to_string());
+        return Some((name.to_string(), "--context flag"));
     }
 
     // 2. Environment variable
@@ -26,16 +39,15 @@ pub fn resolve_active_context_name(cli_context: Option<&str>) -> Option<String>
                 context_config::ENV_VAR,
                 name
             );
-            return Some(name);
+            return Some((name, context_config::ENV_VAR));
         }
     }
 
     // 3. Config file current-context
-    let store = ContextStore::new();
     if let Ok(config) = store.load() {
         if let Some(name) = config.current_context {
             debug!("Using context from config file: {}", name);
-            return Some(name);
+            return Some((name, "config file"));
         }
     }
 
@@ -67,9 +79,50 @@ pub fn resolve_active_context(cli_context: Option<&str>) -> Option<Context> {
     }
 }
 
+/// Load the context named by `--profile`, failing if it does not exist
+pub fn resolve_profile(name: &str) -> Result<Context, TfeError> {
+    load_named_context(name, &ContextStore::new())
+}
+
+fn load_named_context(name: &str, store: &ContextStore) -> Result<Context, TfeError> {
+    let mut config = store.load()?;
+    config.contexts.remove(name).ok_or_else(|| {
+        TfeError::Config(format!(
+            "Profile '{}' not found. Available contexts: {}",
+            name,
+            config
+                .contexts
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    })
+}
+
+/// Apply the context's output format and color preference to `cli`
+///
+/// Precedence: explicit flag > context default > built-in default. `matches`
+/// tells an explicit `-o table` apart from the default one.
+pub fn apply_context_defaults(cli: &mut Cli, matches: &ArgMatches, ctx: &Context) {
+    if let Some(format) = ctx.output_format {
+        if !arg_given(matches, "output") {
+            if let Some(output) = cli.command.output_format_mut() {
+                *output = format;
+            }
+        }
+    }
+    if let Some(color) = ctx.color {
+        if !arg_given(matches, "color") && !arg_given(matches, "no_color") {
+            cli.color = color;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cli::{ColorChoice, OutputFormat};
 
     #[test]
     fn test_cli_flag_wins() {
@@ -93,4 +146,72 @@ mod tests {
         let result = resolve_active_context(Some("nonexistent-context-xyz"));
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_named_context_found_and_missing() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let store = ContextStore::with_path(dir.path().join("config.json"));
+        let mut config = crate::context::ContextConfig::default();
+        config
+            .contexts
+            .insert("prod".to_string(), ctx_with_prefs(None, None));
+        store.save(&config).unwrap();
+
+        let ctx = load_named_context("prod", &store).unwrap();
+        assert_eq!(ctx.host, "app.terraform.io");
+
+        let err = load_named_context("stage", &store).unwrap_err().to_string();
+        assert!(err.contains("Profile 'stage' not found"), "{}", err);
+        assert!(err.contains("Available contexts: prod"), "{}", err);
+    }
+
+    fn ctx_with_prefs(output: Option<OutputFormat>, color: Option<ColorChoice>) -> Context {
+        Context {
+            host: "app.terraform.io".to_string(),
+            token: None,
+            org: None,
+            output_format: output,
+            color,
+        }
+    }
+
+    fn parse_with_context(args: &[&str], ctx: &Context) -> Cli {
+        use clap::{CommandFactory, FromArgMatches};
+        let matches = Cli::command().try_get_matches_from(args).unwrap();
+        let mut cli = Cli::from_arg_matches(&matches).unwrap();
+        apply_context_defaults(&mut cli, &matches, ctx);
+        cli
+    }
+
+    #[test]
+    fn test_context_output_format_applies_without_flag() {
+        let ctx = ctx_with_prefs(Some(OutputFormat::Json), None);
+        let cli = parse_with_context(&["hcp", "get", "ws"], &ctx);
+        assert_eq!(cli.command.output_format(), Some(&OutputFormat::Json));
+    }
+
+    #[test]
+    fn test_explicit_output_flag_beats_context() {
+        let ctx = ctx_with_prefs(Some(OutputFormat::Json), None);
+        let cli = parse_with_context(&["hcp", "get", "ws", "-o", "table"], &ctx);
+        assert_eq!(cli.command.output_format(), Some(&OutputFormat::Table));
+    }
+
+    #[test]
+    fn test_context_color_applies_without_flag() {
+        let ctx = ctx_with_prefs(None, Some(ColorChoice::Always));
+        let cli = parse_with_context(&["hcp", "get", "ws"], &ctx);
+        assert_eq!(cli.color, ColorChoice::Always);
+        assert_eq!(cli.command.output_format(), Some(&OutputFormat::Table));
+    }
+
+    #[test]
+    fn test_explicit_color_flags_beat_context() {
+        let ctx = ctx_with_prefs(None, Some(ColorChoice::Always));
+        let cli = parse_with_context(&["hcp", "get", "ws", "--color", "auto"], &ctx);
+        assert_eq!(cli.color, ColorChoice::Auto);
+        let cli = parse_with_context(&["hcp", "--no-color", "get", "ws"], &ctx);
+        assert_eq!(cli.color, ColorChoice::Auto);
+        assert!(cli.no_color);
+    }
 }
@@ -169,6 +169,8 @@ mod tests {
                 host: "app.terraform.io".to_string(),
                 token: Some("my-token".to_string()),
                 org: Some("my-org".to_string()),
+                output_format: None,
+                color: None,
             },
         );
 
@@ -194,6 +196,8 @@ mod tests {
                 host: "first.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         store.save(&config1).unwrap();
@@ -205,6 +209,8 @@ mod tests {
                 host: "second.com".to_string(),
                 token: None,
                 org: None,
+                output_format: None,
+                color: None,
             },
         );
         store.save(&config2).unwrap();
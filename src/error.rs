@@ -1,12 +1,22 @@
+use serde::Deserialize;
 use std::fmt;
 
+use crate::hcp::redact;
+
 /// Custom error type for TFE operations
 #[derive(Debug)]
 pub enum TfeError {
     /// HTTP request failed
     Http(reqwest::Error),
     /// API returned an error response
-    Api { status: u16, message: String },
+    ///
+    /// `details` holds the JSON:API `errors` array when the body had one, so
+    /// callers can match on e.g. a validation error's `source.pointer`.
+    Api {
+        status: u16,
+        message: String,
+        details: Vec<ApiErrorDetail>,
+    },
     /// Token not found in any source
     TokenNotFound(String),
     /// Host not found in any source
@@ -21,13 +31,34 @@ pub enum TfeError {
     Io { message: String },
     /// User cancelled the operation (e.g., declined large result set warning)
     UserCancelled,
+    /// Server returned 503, typically while TFE is being upgraded
+    Maintenance { retry_after: Option<u64> },
+    /// Custom CA or client certificate could not be loaded
+    Tls(String),
+    /// Server kept answering 429 after all retries were used
+    RateLimited {
+        retry_after: Option<u64>,
+        attempts: u32,
+    },
 }
 
 impl fmt::Display for TfeError {
+    /// Renders the error with registered tokens and `Bearer` credentials scrubbed
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = String::new();
+        self.write_unredacted(&mut text)?;
+        f.write_str(&redact::scrub(&text))
+    }
+}
+
+impl TfeError {
+    /// Write the message as-is; callers must scrub it before it is shown
+    fn write_unredacted(&self, f: &mut impl fmt::Write) -> fmt::Result {
         match self {
             TfeError::Http(e) => write!(f, "HTTP request failed: {}", e),
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 write!(f, "API error (status {}): {}", status, message)
             }
             TfeError::TokenNotFound(msg) => write!(f, "{}", msg),
@@ -37,7 +68,171 @@ impl fmt::Display for TfeError {
             TfeError::Config(msg) => write!(f, "Configuration error: {}", msg),
             TfeError::Io { message } => write!(f, "IO error: {}", message),
             TfeError::UserCancelled => write!(f, "Operation cancelled by user"),
+            TfeError::Tls(msg) => write!(f, "TLS configuration error: {}", msg),
+            TfeError::Maintenance { retry_after } => {
+                write!(f, "TFE appears to be in maintenance mode; retry later")?;
+                if let Some(secs) = retry_after {
+                    write!(f, " (server suggests retrying in {}s)", secs)?;
+                }
+                write!(f, ". Use --retry-on-503 to wait and retry automatically")
+            }
+            TfeError::RateLimited {
+                retry_after,
+                attempts,
+            } => {
+                write!(
+                    f,
+                    "TFE rate limit exceeded (429 Too Many Requests) after {} attempt(s)",
+                    attempts
+                )?;
+                if let Some(secs) = retry_after {
+                    write!(f, "; server suggests retrying in {}s", secs)?;
+                }
+                write!(f, ". Use --max-retries to retry more often")
+            }
+        }
+    }
+
+    /// API error for a failed response, with `body` parsed for JSON:API errors
+    ///
+    /// The message is `context` followed by the rendered error details, or by
+    /// the raw body when it holds no JSON:API errors.
+    /// Secrets echoed back in the body are scrubbed from the message.
+    pub fn api_response(status: u16, context: impl Into<String>, body: &str) -> Self {
+        let body = &redact::scrub(body);
+        let details = ApiErrorDetail::parse_all(body);
+        let mut message = context.into();
+        let text = ApiErrorDetail::render(&details, body);
+        if !text.is_empty() {
+            message.push_str(": ");
+            message.push_str(&text);
+        }
+        TfeError::Api {
+            status,
+            message,
+            details,
+        }
+    }
+
+    /// Like [`TfeError::api_response`] but never echoes a non-JSON:API body
+    ///
+    /// For generic fetch paths where the body may be an HTML error page.
+    pub(crate) fn api_with_details(status: u16, context: impl Into<String>, body: &str) -> Self {
+        let details = ApiErrorDetail::parse_all(body);
+        if details.is_empty() {
+            TfeError::Api {
+                status,
+                message: context.into(),
+                details,
+            }
+        } else {
+            Self::api_response(status, context, body)
+        }
+    }
+
+    /// JSON:API error details of an `Api` error (empty for other variants)
+    pub fn api_details(&self) -> &[ApiErrorDetail] {
+        match self {
+            TfeError::Api { details, .. } => details,
+            _ => &[],
+        }
+    }
+}
+
+/// One entry of a JSON:API `errors` array
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct ApiErrorDetail {
+    /// HTTP status as reported in the error object (a string in JSON:API)
+    pub status: Option<String>,
+    /// Short summary, e.g. "invalid attribute"
+    pub title: Option<String>,
+    /// Human-readable explanation
+    pub detail: Option<String>,
+    /// JSON pointer to the offending request field, e.g. `/data/attributes/name`
+    pub pointer: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ApiErrorBody {
+    errors: Vec<RawApiError>,
+}
+
+#[derive(Deserialize)]
+struct RawApiError {
+    status: Option<serde_json::Value>,
+    title: Option<String>,
+    detail: Option<String>,
+    source: Option<RawErrorSource>,
+}
+
+#[derive(Deserialize)]
+struct RawErrorSource {
+    pointer: Option<String>,
+}
+
+impl ApiErrorDetail {
+    /// Parse the `errors` array of a JSON:API error body
+    ///
+    /// Returns an empty list when the body is not JSON or has no `errors`.
+    /// Plain-string entries (used by some older TFE endpoints) become `detail`.
+    pub fn parse_all(body: &str) -> Vec<ApiErrorDetail> {
+        if let Ok(parsed) = serde_json::from_str::<ApiErrorBody>(body) {
+            return parsed
+                .errors
+                .into_iter()
+                .map(|e| ApiErrorDetail {
+                    status: e.status.map(|s| match s {
+                        serde_json::Value::String(s) => s,
+                        other => other.to_string(),
+                    }),
+                    title: e.title,
+                    detail: e.detail,
+                    pointer: e.source.and_then(|s| s.pointer),
+                })
+                .collect();
+        }
+        #[derive(Deserialize)]
+        struct StringErrors {
+            errors: Vec<String>,
+        }
+        serde_json::from_str::<StringErrors>(body)
+            .map(|parsed| {
+                parsed
+                    .errors
+                    .into_iter()
+                    .map(|detail| ApiErrorDetail {
+                        detail: Some(detail),
+                        ..Default::default()
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Render parsed details joined with "; ", or the trimmed raw body if none
+    pub fn render(details: &[ApiErrorDetail], body: &str) -> String {
+        if details.is_empty() {
+            return body.trim().to_string();
         }
+        details
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+impl fmt::Display for ApiErrorDetail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.title, &self.detail) {
+            (Some(title), Some(detail)) if title != detail => write!(f, "{}: {}", title, detail)?,
+            (Some(text), _) | (None, Some(text)) => write!(f, "{}", text)?,
+            (None, None) => write!(f, "unknown error")?,
+        }
+        if let Some(pointer) = &self.pointer {
+            write!(f, " (at {})", pointer)?;
+        }
+        Ok(())
     }
 }
 
@@ -51,8 +246,13 @@ impl std::error::Error for TfeError {
 }
 
 impl From<reqwest::Error> for TfeError {
+    /// Keeps the failed request's URL, with its secrets redacted
     fn from(err: reqwest::Error) -> Self {
-        TfeError::Http(err)
+        let redacted = err.url().map(redact::redacted_url);
+        match redacted {
+            Some(url) => TfeError::Http(err.with_url(url)),
+            None => TfeError::Http(err),
+        }
     }
 }
 
@@ -81,6 +281,28 @@ pub type Result<T> = std::result::Result<T, TfeError>;
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_api_response_scrubs_secrets() {
+        redact::register_secret("error-test.atlasv1.c2VjcmV0");
+        let err = TfeError::api_response(
+            400,
+            "Failed to create OAuth client",
+            "invalid oauth-token-string error-test.atlasv1.c2VjcmV0",
+        );
+        assert!(!err.to_string().contains("c2VjcmV0"));
+        assert!(!format!("{:?}", err).contains("c2VjcmV0"));
+        assert!(err
+            .to_string()
+            .contains("invalid oauth-token-string REDACTED"));
+
+        // Messages built elsewhere are scrubbed when rendered
+        let err = TfeError::Config("Bearer abc.def rejected".to_string());
+        assert_eq!(
+            err.to_string(),
+            "Configuration error: Bearer REDACTED rejected"
+        );
+    }
+
     #[test]
     fn test_error_display() {
         let err = TfeError::TokenNotFound("test host".to_string());
@@ -92,6 +314,7 @@ mod tests {
         let err = TfeError::Api {
             status: 404,
             message: "Not found".to_string(),
+            details: Vec::new(),
         };
         assert!(err.to_string().contains("404"));
         assert!(err.to_string().contains("Not found"));
@@ -157,6 +380,7 @@ mod tests {
         let err = TfeError::Api {
             status: 500,
             message: "Server error".to_string(),
+            details: Vec::new(),
         };
         assert!(err.source().is_none());
     }
@@ -175,4 +399,87 @@ mod tests {
         let err = TfeError::UserCancelled;
         assert!(err.to_string().contains("cancelled"));
     }
+
+    #[test]
+    fn test_maintenance_display() {
+        let err = TfeError::Maintenance { retry_after: None };
+        assert!(err.to_string().contains("maintenance mode"));
+        assert!(!err.to_string().contains("server suggests"));
+
+        let err = TfeError::Maintenance {
+            retry_after: Some(120),
+        };
+        assert!(err.to_string().contains("retrying in 120s"));
+        assert!(err.to_string().contains("--retry-on-503"));
+    }
+
+    #[test]
+    fn test_rate_limited_display() {
+        let err = TfeError::RateLimited {
+            retry_after: Some(30),
+            attempts: 6,
+        };
+        assert!(err.to_string().contains("429"));
+        assert!(err.to_string().contains("after 6 attempt(s)"));
+        assert!(err.to_string().contains("retrying in 30s"));
+        assert!(err.to_string().contains("--max-retries"));
+    }
+
+    #[test]
+    fn test_api_response_parses_multiple_errors() {
+        let body = r#"{"errors": [
+            {"status": "422", "title": "invalid attribute", "detail": "Name has already been taken",
+             "source": {"pointer": "/data/attributes/name"}},
+            {"status": 422, "title": "invalid attribute", "detail": "Terraform version is invalid",
+             "source": {"pointer": "/data/attributes/terraform-version"}}
+        ]}"#;
+        let err = TfeError::api_response(422, "Failed to create workspace 'app'", body);
+        let details = err.api_details();
+        assert_eq!(details.len(), 2);
+        assert_eq!(details[0].status.as_deref(), Some("422"));
+        assert_eq!(details[1].status.as_deref(), Some("422"));
+        assert_eq!(details[0].pointer.as_deref(), Some("/data/attributes/name"));
+        assert_eq!(
+            details[1].detail.as_deref(),
+            Some("Terraform version is invalid")
+        );
+        assert_eq!(
+            err.to_string(),
+            "API error (status 422): Failed to create workspace 'app': \
+             invalid attribute: Name has already been taken (at /data/attributes/name); \
+             invalid attribute: Terraform version is invalid (at /data/attributes/terraform-version)"
+        );
+    }
+
+    #[test]
+    fn test_api_response_permission_error_without_pointer() {
+        let body = r#"{"errors": [{"status": "403", "title": "forbidden"}]}"#;
+        let err = TfeError::api_response(403, "Failed to lock workspace 'ws-1'", body);
+        assert!(matches!(
+            err.api_details(),
+            [ApiErrorDetail { title: Some(t), pointer: None, .. }] if t == "forbidden"
+        ));
+        assert!(err.to_string().ends_with("'ws-1': forbidden"));
+    }
+
+    #[test]
+    fn test_api_response_string_errors() {
+        let err = TfeError::api_response(404, "ctx", r#"{"errors": ["not found"]}"#);
+        assert_eq!(err.api_details()[0].detail.as_deref(), Some("not found"));
+        assert!(err.to_string().ends_with("ctx: not found"));
+    }
+
+    #[test]
+    fn test_api_response_falls_back_to_raw_body() {
+        let body = "<html>Bad Gateway</html>\n";
+        let err = TfeError::api_response(502, "Failed to fetch runs", body);
+        assert!(err.api_details().is_empty());
+        assert!(err
+            .to_string()
+            .ends_with("Failed to fetch runs: <html>Bad Gateway</html>"));
+
+        let err = TfeError::api_response(500, "Failed to fetch runs", "");
+        assert!(err.to_string().ends_with("Failed to fetch runs"));
+        assert!(TfeError::UserCancelled.api_details().is_empty());
+    }
 }
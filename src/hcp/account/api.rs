@@ -0,0 +1,73 @@
+//! Account API operations
+
+use crate::config::api;
+use crate::error::Result;
+use crate::hcp::TfeClient;
+
+use super::models::Account;
+
+impl TfeClient {
+    /// Get the account the API token authenticates as
+    ///
+    /// Returns `None` when the API has no account for the token (404), which
+    /// is the case for organization tokens.
+    pub async fn get_account_details(&self) -> Result<Option<Account>> {
+        let path = format!("/{}", api::ACCOUNT_DETAILS);
+        Ok(self
+            .fetch_resource_by_path::<Account>(&path, "account details")
+            .await?
+            .map(|(account, _)| account))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TfeError;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_account_details() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/account/details"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "id": "user-abc",
+                    "type": "users",
+                    "attributes": {"username": "alice", "email": "alice@example.com"}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let account = client.get_account_details().await.unwrap().unwrap();
+        assert_eq!(account.id, "user-abc");
+        assert_eq!(account.attributes.username, "alice");
+    }
+
+    #[tokio::test]
+    async fn test_get_account_details_not_found_and_unauthorized() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/account/details"))
+            .respond_with(ResponseTemplate::new(404))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/account/details"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&mock_server)
+            .await;
+
+        assert!(client.get_account_details().await.unwrap().is_none());
+        let err = client.get_account_details().await.unwrap_err();
+        assert!(matches!(err, TfeError::Api { status: 401, .. }));
+    }
+}
@@ -0,0 +1,101 @@
+//! Account command handlers
+
+use crate::error::TfeError;
+use crate::hcp::explain::{call, print_explain, PlannedCall};
+use crate::hcp::{TfeClient, TokenSource};
+use crate::output::output_whoami;
+use crate::ui::{create_spinner, finish_spinner};
+use crate::Cli;
+
+use super::models::Whoami;
+
+/// Run the whoami command
+///
+/// Shows the account behind the API token together with the resolved host
+/// and the source the token was taken from. `token_source` is only `None`
+/// under `--explain`, where no token is resolved.
+pub async fn run_whoami_command(
+    client: &TfeClient,
+    cli: &Cli,
+    token_source: Option<&TokenSource>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if cli.explain {
+        print_explain(&plan_whoami());
+        return Ok(());
+    }
+    let token_source = token_source.ok_or("No API token resolved")?;
+
+    let host = client.host();
+    let spinner = create_spinner(
+        &format!("Fetching account details from {}...", host),
+        cli.batch,
+    );
+    let result = client.get_account_details().await;
+    finish_spinner(spinner);
+
+    let account = match result {
+        Ok(Some(account)) => account,
+        Ok(None) => {
+            return Err(format!(
+                "{} has no user account for the token from the {} \
+                 (organization tokens are not tied to a user)",
+                host, token_source
+            )
+            .into())
+        }
+        Err(TfeError::Api { status: 401, .. }) => {
+            return Err(unauthorized_message(host, token_source).into())
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let whoami = Whoami {
+        user_id: account.id,
+        username: account.attributes.username,
+        email: account.attributes.email,
+        service_account: account.attributes.is_service_account,
+        host: host.to_string(),
+        token_source: token_source.to_string(),
+        org: client.effective_org(None),
+    };
+    output_whoami(&whoami, cli);
+    Ok(())
+}
+
+/// API calls made by `whoami`
+fn plan_whoami() -> Vec<PlannedCall> {
+    vec![call("GET", "/account/details", "authenticated user")]
+}
+
+/// Error for a token the host rejects, naming where the token came from
+fn unauthorized_message(host: &str, token_source: &TokenSource) -> String {
+    format!(
+        "Authentication failed (401 Unauthorized): {} rejected the token from the {}.\n\
+         The token may be expired, revoked or issued for another host; \
+         run 'terraform login {}' or pass a valid --token",
+        host, token_source, host
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_whoami() {
+        let calls = plan_whoami();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].path, "/account/details");
+    }
+
+    #[test]
+    fn test_unauthorized_message_names_source() {
+        let msg = unauthorized_message(
+            "app.terraform.io",
+            &TokenSource::EnvVar("TFE_TOKEN".to_string()),
+        );
+        assert!(msg.contains("401 Unauthorized"));
+        assert!(msg.contains("TFE_TOKEN environment variable"));
+        assert!(msg.contains("terraform login app.terraform.io"));
+    }
+}
@@ -0,0 +1,8 @@
+//! Account module - the user (or team service account) behind the API token
+
+mod api;
+mod commands;
+mod models;
+
+pub use commands::run_whoami_command;
+pub use models::{Account, AccountAttributes, Whoami};
@@ -0,0 +1,93 @@
+//! Account data models
+
+use serde::{Deserialize, Serialize};
+
+use crate::hcp::traits::TfeResource;
+
+/// Authenticated user from `GET /account/details`
+#[derive(Deserialize, Debug, Clone)]
+pub struct Account {
+    pub id: String,
+    pub attributes: AccountAttributes,
+}
+
+/// Account attributes from TFE API
+#[derive(Deserialize, Debug, Clone)]
+pub struct AccountAttributes {
+    pub username: String,
+    pub email: Option<String>,
+    /// Team tokens authenticate as a service account (`api-team_...`)
+    #[serde(rename = "is-service-account", default)]
+    pub is_service_account: bool,
+}
+
+impl TfeResource for Account {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.attributes.username
+    }
+}
+
+/// Identity report printed by `whoami`
+#[derive(Serialize, Debug, Clone)]
+pub struct Whoami {
+    pub user_id: String,
+    pub username: String,
+    pub email: Option<String>,
+    pub service_account: bool,
+    pub host: String,
+    pub token_source: String,
+    /// Default organization (`--org`-less commands) from the active context
+    pub org: Option<String>,
+}
+
+impl TfeResource for Whoami {
+    fn id(&self) -> &str {
+        &self.user_id
+    }
+
+    fn name(&self) -> &str {
+        &self.username
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_account_deserialize() {
+        let account: Account = serde_json::from_value(serde_json::json!({
+            "id": "user-V3R563qtJNcExAkN",
+            "type": "users",
+            "attributes": {
+                "username": "admin",
+                "email": "admin@example.com",
+                "is-service-account": false,
+                "two-factor": {"enabled": false, "verified": false}
+            }
+        }))
+        .unwrap();
+        assert_eq!(account.id(), "user-V3R563qtJNcExAkN");
+        assert_eq!(account.name(), "admin");
+        assert_eq!(
+            account.attributes.email.as_deref(),
+            Some("admin@example.com")
+        );
+        assert!(!account.attributes.is_service_account);
+    }
+
+    #[test]
+    fn test_service_account_without_email() {
+        let account: Account = serde_json::from_value(serde_json::json!({
+            "id": "user-team",
+            "attributes": {"username": "api-team_123", "is-service-account": true}
+        }))
+        .unwrap();
+        assert!(account.attributes.is_service_account);
+        assert!(account.attributes.email.is_none());
+    }
+}
@@ -0,0 +1,86 @@
+//! Assessment result API operations
+
+use crate::config::api;
+use crate::error::Result;
+use crate::hcp::TfeClient;
+
+use super::models::AssessmentResult;
+
+impl TfeClient {
+    /// Get the current assessment result of a workspace
+    ///
+    /// Returns `None` when the workspace has no assessment result yet.
+    pub async fn get_current_assessment_result(
+        &self,
+        workspace_id: &str,
+    ) -> Result<Option<AssessmentResult>> {
+        let path = format!(
+            "/{}/{}/{}",
+            api::WORKSPACES,
+            workspace_id,
+            api::CURRENT_ASSESSMENT_RESULT
+        );
+        let label = format!("assessment result for workspace '{}'", workspace_id);
+        Ok(self
+            .fetch_resource_by_path::<AssessmentResult>(&path, &label)
+            .await?
+            .map(|(result, _raw)| result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hcp::assessments::AssessmentStatus;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_current_assessment_result() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc/current-assessment-result"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "id": "asmtres-1",
+                    "type": "assessment-results",
+                    "attributes": {
+                        "drifted": true,
+                        "succeeded": true,
+                        "created-at": "2024-05-01T12:00:00Z"
+                    }
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let result = client
+            .get_current_assessment_result("ws-abc")
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.id, "asmtres-1");
+        assert_eq!(result.status(), AssessmentStatus::Drifted);
+    }
+
+    #[tokio::test]
+    async fn test_get_current_assessment_result_none_yet() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-new/current-assessment-result"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        assert!(client
+            .get_current_assessment_result("ws-new")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,252 @@
+//! Assessment command handlers
+
+use futures::stream::{self, StreamExt};
+use log::debug;
+
+use crate::cli::AssessmentArgs;
+use crate::hcp::explain::{call, print_explain, PlannedCall};
+use crate::hcp::traits::TfeResource;
+use crate::hcp::workspaces::WorkspaceFilter;
+use crate::hcp::TfeClient;
+use crate::output::{apply_limit, output_assessments};
+use crate::ui::{create_spinner, finish_spinner};
+use crate::{Cli, Command, GetResource};
+
+use super::models::{AssessmentStatus, WorkspaceAssessment};
+
+/// Run the assessment report command
+///
+/// Fetches the current assessment result of every workspace with health
+/// assessments enabled (concurrently, bounded by `--concurrency`).
+pub async fn run_assessment_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::Assessment(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let effective_org = client.effective_org(args.org.as_ref());
+    let org = effective_org
+        .as_ref()
+        .ok_or("Organization is required (--org)")?;
+    if cli.explain {
+        print_explain(&plan_get_assessment(args, org));
+        return Ok(());
+    }
+
+    let mut filter = WorkspaceFilter::new().org(org);
+    if let Some(name) = &args.filter {
+        filter = filter.name_contains(name);
+    }
+    let spinner = create_spinner(&format!("Fetching workspaces for '{}'...", org), cli.batch);
+    let result = client
+        .with_progress(spinner.as_ref())
+        .list_workspaces(&filter)
+        .await;
+    finish_spinner(spinner);
+    let mut workspaces = result?;
+    workspaces.retain(|ws| ws.attributes.assessments_enabled == Some(true));
+    debug!(
+        "{} workspaces in '{}' have health assessments enabled",
+        workspaces.len(),
+        org
+    );
+
+    if workspaces.is_empty() {
+        if args.filter.is_some() {
+            eprintln!("No workspaces with health assessments enabled found matching filter");
+        } else {
+            eprintln!(
+                "No workspaces with health assessments enabled in organization '{}'",
+                org
+            );
+        }
+        return Ok(());
+    }
+
+    let spinner = create_spinner(
+        &format!(
+            "Fetching assessment results for {} workspaces in '{}'...",
+            workspaces.len(),
+            org
+        ),
+        cli.batch,
+    );
+    let results: Vec<_> = stream::iter(workspaces.iter())
+        .map(|ws| client.get_current_assessment_result(&ws.id))
+        .buffered(client.concurrency())
+        .collect()
+        .await;
+    finish_spinner(spinner);
+
+    let mut assessments = Vec::with_capacity(workspaces.len());
+    for (ws, result) in workspaces.iter().zip(results) {
+        let (result, fetch_error) = match result {
+            Ok(result) => (result, None),
+            Err(e) => {
+                debug!("Failed to fetch assessment for '{}': {}", ws.name(), e);
+                (None, Some(e.to_string()))
+            }
+        };
+        assessments.push(WorkspaceAssessment {
+            workspace_id: ws.id.clone(),
+            workspace_name: ws.name().to_string(),
+            result,
+            fetch_error,
+        });
+    }
+    report_unavailable(&assessments, cli.strict)?;
+
+    sort_assessments(&mut assessments);
+    apply_limit(&mut assessments, cli);
+    output_assessments(&assessments, cli);
+    Ok(())
+}
+
+/// Warn about workspaces whose result could not be fetched; fail under `--strict`
+fn report_unavailable(assessments: &[WorkspaceAssessment], strict: bool) -> Result<(), String> {
+    let failures = assessments
+        .iter()
+        .filter(|a| a.fetch_error.is_some())
+        .count();
+    if failures == 0 {
+        return Ok(());
+    }
+    if strict {
+        return Err(format!(
+            "Could not fetch assessment results for {} of {} workspace(s)",
+            failures,
+            assessments.len()
+        ));
+    }
+    eprintln!(
+        "Warning: could not fetch assessment results for {} of {} workspace(s); shown as 'unavailable'",
+        failures,
+        assessments.len()
+    );
+    Ok(())
+}
+
+/// Order drifted workspaces first, then errored, unavailable, ok and not yet assessed
+fn sort_assessments(assessments: &mut [WorkspaceAssessment]) {
+    let rank = |status: AssessmentStatus| match status {
+        AssessmentStatus::Drifted => 0,
+        AssessmentStatus::Errored => 1,
+        AssessmentStatus::Unavailable => 2,
+        AssessmentStatus::Ok => 3,
+        AssessmentStatus::None => 4,
+    };
+    assessments.sort_by(|a, b| {
+        rank(a.status())
+            .cmp(&rank(b.status()))
+            .then_with(|| a.workspace_name.cmp(&b.workspace_name))
+    });
+}
+
+/// API calls made by `get assessment` in `org`
+fn plan_get_assessment(args: &AssessmentArgs, org: &str) -> Vec<PlannedCall> {
+    let list_path = match &args.filter {
+        Some(f) => format!(
+            "/organizations/{}/workspaces?search[name]={}",
+            org,
+            urlencoding::encode(f)
+        ),
+        None => format!("/organizations/{}/workspaces", org),
+    };
+    vec![
+        call("GET", list_path, "paginated"),
+        call(
+            "GET",
+            "/workspaces/:ws_id/current-assessment-result",
+            "per workspace with assessments enabled",
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hcp::assessments::AssessmentResult;
+
+    fn assessment(name: &str, drifted: Option<bool>) -> WorkspaceAssessment {
+        WorkspaceAssessment {
+            workspace_id: format!("ws-{}", name),
+            workspace_name: name.to_string(),
+            result: drifted.map(|drifted| {
+                serde_json::from_value::<AssessmentResult>(serde_json::json!({
+                    "id": format!("asmtres-{}", name),
+                    "attributes": {"drifted": drifted, "succeeded": true}
+                }))
+                .unwrap()
+            }),
+            fetch_error: None,
+        }
+    }
+
+    #[test]
+    fn test_sort_assessments_drifted_first() {
+        let mut assessments = vec![
+            assessment("new", None),
+            assessment("b-ok", Some(false)),
+            assessment("z-drift", Some(true)),
+            assessment("a-ok", Some(false)),
+            assessment("a-drift", Some(true)),
+        ];
+        sort_assessments(&mut assessments);
+        let names: Vec<_> = assessments.iter().map(|a| a.name()).collect();
+        assert_eq!(names, ["a-drift", "z-drift", "a-ok", "b-ok", "new"]);
+    }
+
+    #[test]
+    fn test_sort_assessments_unavailable_after_errored() {
+        let mut assessments = vec![
+            assessment("ok", Some(false)),
+            WorkspaceAssessment {
+                fetch_error: Some("HTTP 500".to_string()),
+                ..assessment("broken", None)
+            },
+            assessment("drift", Some(true)),
+        ];
+        sort_assessments(&mut assessments);
+        let names: Vec<_> = assessments.iter().map(|a| a.name()).collect();
+        assert_eq!(names, ["drift", "broken", "ok"]);
+    }
+
+    #[test]
+    fn test_report_unavailable() {
+        let mut assessments = vec![assessment("ok", Some(false))];
+        assert!(report_unavailable(&assessments, true).is_ok());
+
+        assessments.push(WorkspaceAssessment {
+            fetch_error: Some("HTTP 500".to_string()),
+            ..assessment("broken", None)
+        });
+        assert!(report_unavailable(&assessments, false).is_ok());
+        let err = report_unavailable(&assessments, true).unwrap_err();
+        assert!(err.contains("1 of 2 workspace(s)"), "{}", err);
+    }
+
+    #[test]
+    fn test_plan_get_assessment() {
+        let cli = <Cli as clap::Parser>::parse_from(["hcp", "get", "assessment", "-f", "prod"]);
+        let Command::Get {
+            resource: GetResource::Assessment(args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        let calls = plan_get_assessment(args, "acme");
+        assert_eq!(
+            calls[0].path,
+            "/organizations/acme/workspaces?search[name]=prod"
+        );
+        assert_eq!(
+            calls[1].path,
+            "/workspaces/:ws_id/current-assessment-result"
+        );
+    }
+}
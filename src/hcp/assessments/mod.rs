@@ -0,0 +1,10 @@
+//! Assessments module - org-wide drift/health assessment report
+
+mod api;
+mod commands;
+mod models;
+
+pub use commands::run_assessment_command;
+pub use models::{
+    AssessmentResult, AssessmentResultAttributes, AssessmentStatus, WorkspaceAssessment,
+};
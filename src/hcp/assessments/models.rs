@@ -0,0 +1,164 @@
+//! Assessment result data models
+
+use serde::{Deserialize, Serialize};
+
+use crate::hcp::traits::TfeResource;
+
+/// Health assessment result from TFE API (current-assessment-result)
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct AssessmentResult {
+    pub id: String,
+    pub attributes: AssessmentResultAttributes,
+}
+
+/// Assessment result attributes from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct AssessmentResultAttributes {
+    /// Whether real infrastructure differs from the state
+    pub drifted: Option<bool>,
+    /// Whether the assessment run completed
+    pub succeeded: Option<bool>,
+    #[serde(rename = "error-msg")]
+    pub error_msg: Option<String>,
+    #[serde(rename = "created-at")]
+    pub created_at: Option<String>,
+}
+
+/// Summarized outcome of a workspace's latest assessment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssessmentStatus {
+    Drifted,
+    Ok,
+    Errored,
+    /// Assessments are enabled but no result exists yet
+    None,
+    /// The current result could not be fetched
+    Unavailable,
+}
+
+impl AssessmentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssessmentStatus::Drifted => "drifted",
+            AssessmentStatus::Ok => "ok",
+            AssessmentStatus::Errored => "errored",
+            AssessmentStatus::None => "none",
+            AssessmentStatus::Unavailable => "unavailable",
+        }
+    }
+}
+
+impl AssessmentResult {
+    /// Outcome of the assessment; a failed run is "errored" whatever its drift flag
+    pub fn status(&self) -> AssessmentStatus {
+        let attrs = &self.attributes;
+        if attrs.succeeded == Some(false) || attrs.error_msg.is_some() {
+            AssessmentStatus::Errored
+        } else if attrs.drifted == Some(true) {
+            AssessmentStatus::Drifted
+        } else {
+            AssessmentStatus::Ok
+        }
+    }
+
+    /// When the assessment ran (empty if not reported)
+    pub fn created_at(&self) -> &str {
+        self.attributes.created_at.as_deref().unwrap_or("")
+    }
+}
+
+/// Latest assessment of one workspace with health assessments enabled
+#[derive(Debug, Clone)]
+pub struct WorkspaceAssessment {
+    pub workspace_id: String,
+    pub workspace_name: String,
+    /// `None` until the first assessment has completed
+    pub result: Option<AssessmentResult>,
+    /// Why the current result could not be fetched, if it could not
+    pub fetch_error: Option<String>,
+}
+
+impl WorkspaceAssessment {
+    pub fn status(&self) -> AssessmentStatus {
+        if self.fetch_error.is_some() {
+            return AssessmentStatus::Unavailable;
+        }
+        self.result
+            .as_ref()
+            .map_or(AssessmentStatus::None, AssessmentResult::status)
+    }
+
+    /// The fetch error, or the assessment's own error message
+    pub fn error_msg(&self) -> Option<&str> {
+        self.fetch_error.as_deref().or_else(|| {
+            self.result
+                .as_ref()
+                .and_then(|r| r.attributes.error_msg.as_deref())
+        })
+    }
+}
+
+impl TfeResource for WorkspaceAssessment {
+    fn id(&self) -> &str {
+        &self.workspace_id
+    }
+
+    fn name(&self) -> &str {
+        &self.workspace_name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(attributes: serde_json::Value) -> AssessmentResult {
+        serde_json::from_value(serde_json::json!({
+            "id": "asmtres-1",
+            "type": "assessment-results",
+            "attributes": attributes
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_assessment_result() {
+        let r = result(serde_json::json!({
+            "drifted": true,
+            "succeeded": true,
+            "error-msg": null,
+            "created-at": "2024-05-01T12:00:00Z"
+        }));
+        assert_eq!(r.id, "asmtres-1");
+        assert_eq!(r.created_at(), "2024-05-01T12:00:00Z");
+        assert_eq!(r.status(), AssessmentStatus::Drifted);
+    }
+
+    #[test]
+    fn test_status() {
+        let ok = result(serde_json::json!({"drifted": false, "succeeded": true}));
+        assert_eq!(ok.status(), AssessmentStatus::Ok);
+
+        let failed = result(serde_json::json!({"drifted": true, "succeeded": false}));
+        assert_eq!(failed.status(), AssessmentStatus::Errored);
+
+        let error_msg = result(serde_json::json!({"error-msg": "plan failed"}));
+        assert_eq!(error_msg.status(), AssessmentStatus::Errored);
+
+        let pending = WorkspaceAssessment {
+            workspace_id: "ws-1".to_string(),
+            workspace_name: "app".to_string(),
+            result: None,
+            fetch_error: None,
+        };
+        assert_eq!(pending.status(), AssessmentStatus::None);
+        assert_eq!(pending.status().as_str(), "none");
+
+        let unavailable = WorkspaceAssessment {
+            fetch_error: Some("HTTP 500".to_string()),
+            ..pending
+        };
+        assert_eq!(unavailable.status(), AssessmentStatus::Unavailable);
+        assert_eq!(unavailable.error_msg(), Some("HTTP 500"));
+    }
+}
@@ -1,13 +1,23 @@
 //! TFE HTTP client for API interactions
 
 use futures::stream::{self, StreamExt};
-use log::debug;
-use reqwest::Client;
+use indicatif::ProgressBar;
+use log::{debug, warn};
+use reqwest::header::{
+    HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, ETAG, IF_NONE_MATCH, RETRY_AFTER,
+};
+use reqwest::{Client, StatusCode};
 use serde::de::DeserializeOwned;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use crate::config::api;
-use crate::error::{Result, TfeError};
+use crate::error::{ApiErrorDetail, Result, TfeError};
+use crate::hcp::disk_cache::{default_cache_dir, DiskCache};
+use crate::hcp::etag_cache::{CachedResponse, ETagCache};
+use crate::hcp::proxy::apply_proxy;
+use crate::hcp::redact::{self, RedactedUrl};
+use crate::hcp::tls::TlsConfig;
 use crate::hcp::traits::PaginatedResponse;
 
 /// Pagination info returned from first page fetch
@@ -19,7 +29,69 @@ pub struct PaginationInfo {
     pub total_pages: u32,
 }
 
+/// Format one `--debug-http` line: `[http] GET <url> -> 200 OK (12ms)`
+fn http_debug_line(
+    method: &reqwest::Method,
+    url: &str,
+    outcome: &str,
+    elapsed: Duration,
+) -> String {
+    format!(
+        "[http] {} {} -> {} ({}ms)",
+        method,
+        url,
+        outcome,
+        elapsed.as_millis()
+    )
+}
+
+/// One page requested with `--page`/`--page-size` instead of all pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    /// Page number (1-based)
+    pub number: u32,
+    /// Items per page
+    pub size: u32,
+}
+
+impl PageRequest {
+    /// Build from the `--page`/`--page-size` flags; `None` when neither is given
+    pub fn from_flags(page: Option<u32>, page_size: Option<u32>) -> Option<Self> {
+        if page.is_none() && page_size.is_none() {
+            return None;
+        }
+        Some(Self {
+            number: page.unwrap_or(1),
+            size: page_size.unwrap_or(api::DEFAULT_PAGE_SIZE),
+        })
+    }
+
+    /// Pagination summary for stderr, e.g. "Page 2 of 5 (page size 20, 93 total)"
+    ///
+    /// Totals are omitted where the API doesn't report them (org run listings).
+    pub fn summary(&self, total_pages: Option<u32>, total_count: Option<u32>) -> String {
+        let mut summary = format!("Page {}", self.number);
+        if let Some(pages) = total_pages {
+            summary.push_str(&format!(" of {}", pages));
+        }
+        summary.push_str(&format!(" (page size {}", self.size));
+        if let Some(count) = total_count {
+            summary.push_str(&format!(", {} total", count));
+        }
+        summary.push(')');
+        summary
+    }
+
+    /// Print the pagination summary on stderr
+    pub fn report(&self, total_pages: Option<u32>, total_count: Option<u32>) {
+        eprintln!("{}", self.summary(total_pages, total_count));
+    }
+}
+
 /// TFE API client
+///
+/// Cloning is cheap: the HTTP connection pool and response caches are shared.
+#[derive(Clone)]
 pub struct TfeClient {
     client: Client,
     token: String,
@@ -28,14 +100,47 @@ pub struct TfeClient {
     base_url_override: Option<String>,
     /// Batch mode - disables interactive prompts
     batch_mode: bool,
+    /// Seconds to wait at interactive prompts before applying the default answer
+    prompt_timeout: Option<u64>,
+    /// Quiet mode - suppresses the `--page` summary on stderr
+    quiet: bool,
     /// Default organization from active context
     context_org: Option<String>,
+    /// API version path segment (e.g. "v2")
+    api_version: String,
+    /// ETag cache for conditional GET requests (None when disabled)
+    etag_cache: Option<Arc<ETagCache>>,
+    /// On-disk cache for GET responses (None unless `--cache` is given)
+    disk_cache: Option<Arc<DiskCache>>,
+    /// Retry 503 (maintenance mode) responses with backoff
+    retry_on_503: bool,
+    /// Restrict automatic retries to idempotent methods (GET/HEAD/PUT/DELETE)
+    retry_idempotent_only: bool,
+    /// Maximum retries for 429 (rate limited) responses
+    max_retries: u32,
+    /// Maximum in-flight requests when fanning out across orgs or pages
+    concurrency: usize,
+    /// Print each request line with status and timing to stderr (`--debug-http`)
+    debug_http: bool,
+    /// Spinner that paginated fetches report their pages to (see [`Self::with_progress`])
+    progress: Option<ProgressBar>,
 }
 
 impl TfeClient {
     /// Create a new TFE client with optimized connection settings
-    pub fn new(token: String, host: String) -> Self {
-        let client = Client::builder()
+    ///
+    /// `tls` adds a custom CA bundle and/or client certificate; unreadable or
+    /// invalid certificate files are reported as [`TfeError::Tls`]. `proxy`
+    /// overrides the `HTTPS_PROXY`/`HTTP_PROXY` environment variables; a proxy
+    /// setup the HTTP client rejects fails with [`TfeError::Config`] instead of
+    /// silently connecting directly.
+    pub fn new(
+        token: String,
+        host: String,
+        tls: Option<&TlsConfig>,
+        proxy: Option<&str>,
+    ) -> Result<Self> {
+        let builder = Client::builder()
             // Connection pool settings - reuse connections
             .pool_max_idle_per_host(20)
             .pool_idle_timeout(Duration::from_secs(90))
@@ -43,24 +148,45 @@ impl TfeClient {
             .tcp_keepalive(Duration::from_secs(60))
             // Timeouts
             .connect_timeout(Duration::from_secs(10))
-            .timeout(Duration::from_secs(30))
-            .build()
-            .unwrap_or_else(|_| Client::new());
+            .timeout(Duration::from_secs(30));
+        let builder = apply_proxy(builder, proxy)?;
+        let client = match tls.filter(|t| !t.is_empty()) {
+            Some(tls) => tls
+                .apply(builder)?
+                .build()
+                .map_err(|e| TfeError::Tls(e.to_string()))?,
+            None => builder
+                .build()
+                .map_err(|e| TfeError::Config(format!("Cannot create HTTP client: {}", e)))?,
+        };
 
-        Self {
+        redact::register_secret(&token);
+        Ok(Self {
             client,
             token,
             host,
             base_url_override: None,
             batch_mode: false,
+            prompt_timeout: None,
+            quiet: false,
             context_org: None,
-        }
+            api_version: api::DEFAULT_VERSION.to_string(),
+            etag_cache: None,
+            disk_cache: None,
+            retry_on_503: false,
+            retry_idempotent_only: true,
+            max_retries: api::RATE_LIMIT_MAX_RETRIES,
+            concurrency: api::MAX_CONCURRENT_PAGE_REQUESTS,
+            debug_http: false,
+            progress: None,
+        })
     }
 
     /// Create a client with custom base URL (for testing with mock servers)
     #[cfg(test)]
     pub fn with_base_url(token: String, host: String, base_url: String) -> Self {
         let client = Client::builder().build().unwrap_or_else(|_| Client::new());
+        redact::register_secret(&token);
 
         Self {
             client,
@@ -68,10 +194,26 @@ impl TfeClient {
             host,
             base_url_override: Some(base_url),
             batch_mode: false,
+            prompt_timeout: None,
+            quiet: false,
             context_org: None,
+            api_version: api::DEFAULT_VERSION.to_string(),
+            etag_cache: None,
+            disk_cache: None,
+            retry_on_503: false,
+            retry_idempotent_only: true,
+            max_retries: api::RATE_LIMIT_MAX_RETRIES,
+            concurrency: api::MAX_CONCURRENT_PAGE_REQUESTS,
+            debug_http: false,
+            progress: None,
         }
     }
 
+    /// Print method, redacted URL, status and elapsed time of every request to stderr
+    pub fn set_debug_http(&mut self, enabled: bool) {
+        self.debug_http = enabled;
+    }
+
     /// Set batch mode (disables interactive prompts for large result sets)
     pub fn set_batch_mode(&mut self, batch: bool) {
         self.batch_mode = batch;
@@ -82,11 +224,130 @@ impl TfeClient {
         self.batch_mode
     }
 
+    /// Set quiet mode (suppresses informational notices on stderr)
+    pub fn set_quiet(&mut self, quiet: bool) {
+        self.quiet = quiet;
+    }
+
+    /// Check if quiet mode is enabled
+    pub fn is_quiet(&self) -> bool {
+        self.quiet
+    }
+
+    /// Set the timeout applied to interactive prompts (None waits indefinitely)
+    pub fn set_prompt_timeout(&mut self, secs: Option<u64>) {
+        self.prompt_timeout = secs;
+    }
+
+    /// Get the timeout applied to interactive prompts
+    pub fn prompt_timeout(&self) -> Option<u64> {
+        self.prompt_timeout
+    }
+
+    /// Enable or disable conditional requests (ETag / If-None-Match caching)
+    pub fn set_conditional_requests(&mut self, enabled: bool) {
+        self.etag_cache = enabled.then(Arc::default);
+    }
+
+    /// Check if conditional requests are enabled
+    pub fn conditional_requests(&self) -> bool {
+        self.etag_cache.is_some()
+    }
+
+    /// Enable the on-disk response cache for GET requests with the given TTL
+    pub fn enable_disk_cache(&mut self, ttl: Duration) {
+        self.disk_cache = Some(Arc::new(DiskCache::new(
+            default_cache_dir(),
+            ttl,
+            &self.token,
+        )));
+    }
+
+    /// Enable the on-disk response cache in a specific directory
+    #[cfg(test)]
+    pub(crate) fn enable_disk_cache_in(&mut self, dir: std::path::PathBuf, ttl: Duration) {
+        self.disk_cache = Some(Arc::new(DiskCache::new(dir, ttl, &self.token)));
+    }
+
+    /// Check if the on-disk response cache is enabled
+    pub fn disk_cache_enabled(&self) -> bool {
+        self.disk_cache.is_some()
+    }
+
+    /// Enable or disable retrying 503 (maintenance mode) responses
+    pub fn set_retry_on_503(&mut self, enabled: bool) {
+        self.retry_on_503 = enabled;
+    }
+
+    /// Restrict automatic retries to idempotent methods (default: true)
+    ///
+    /// When disabled, POST and PATCH requests are retried as well, which may
+    /// e.g. create duplicate runs if the first attempt reached the server.
+    pub fn set_retry_idempotent_only(&mut self, enabled: bool) {
+        self.retry_idempotent_only = enabled;
+    }
+
+    /// Set the maximum number of retries for 429 (rate limited) responses (0 disables)
+    pub fn set_max_retries(&mut self, max_retries: u32) {
+        self.max_retries = max_retries;
+    }
+
+    /// Set the maximum number of concurrent requests (at least 1)
+    pub fn set_concurrency(&mut self, concurrency: usize) {
+        self.concurrency = concurrency.max(1);
+    }
+
+    /// Maximum number of concurrent requests for fan-out operations
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// A client whose paginated fetches advance `progress`
+    ///
+    /// Once a fetch knows it spans more than one page, the spinner turns into
+    /// a page progress bar; concurrent fetches through the same handle (e.g.
+    /// one per organization) add up. `None` (batch/quiet mode) reports nothing.
+    pub fn with_progress(&self, progress: Option<&ProgressBar>) -> Self {
+        Self {
+            progress: progress.cloned(),
+            ..self.clone()
+        }
+    }
+
+    fn add_progress_pages(&self, pages: u64) {
+        if let Some(bar) = &self.progress {
+            crate::ui::add_pages(bar, pages);
+        }
+    }
+
+    fn page_fetched(&self) {
+        if let Some(bar) = &self.progress {
+            bar.inc(1);
+        }
+    }
+
     /// Set the default organization from active context
     pub fn set_context_org(&mut self, org: Option<String>) {
         self.context_org = org;
     }
 
+    /// Set the API version path segment (accepts "v2", "2" or "/v2")
+    pub fn set_api_version(&mut self, version: &str) {
+        let version = version.trim().trim_matches('/');
+        self.api_version = if version.is_empty() {
+            api::DEFAULT_VERSION.to_string()
+        } else if version.starts_with('v') {
+            version.to_string()
+        } else {
+            format!("v{}", version)
+        };
+    }
+
+    /// Get the API version path segment
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
     /// Resolve org: explicit CLI value wins, then context default
     pub fn effective_org(&self, explicit: Option<&String>) -> Option<String> {
         explicit.cloned().or_else(|| self.context_org.clone())
@@ -98,9 +359,10 @@ impl TfeClient {
             return url.clone();
         }
         format!(
-            "https://{}/{}",
+            "https://{}/{}/{}",
             self.host,
-            api::BASE_PATH.trim_start_matches('/')
+            api::ROOT_PATH.trim_start_matches('/'),
+            self.api_version
         )
     }
 
@@ -110,10 +372,21 @@ impl TfeClient {
     }
 
     /// Add standard headers to a request builder
+    ///
+    /// Content-Type is set up front so `RequestBuilder::json` keeps the JSON:API
+    /// media type instead of falling back to plain `application/json`.
+    /// The Authorization header is marked sensitive so request `Debug` output
+    /// (e.g. `--log-level trace`) never shows the token.
     fn with_headers(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
-        builder
-            .header("Authorization", format!("Bearer {}", self.token))
-            .header("Content-Type", "application/vnd.api+json")
+        let builder = builder.header(CONTENT_TYPE, api::MEDIA_TYPE);
+        match HeaderValue::from_str(&format!("Bearer {}", self.token)) {
+            Ok(mut auth) => {
+                auth.set_sensitive(true);
+                builder.header(AUTHORIZATION, auth)
+            }
+            // Invalid header characters: let reqwest report the error on send
+            Err(_) => builder.header(AUTHORIZATION, format!("Bearer {}", self.token)),
+        }
     }
 
     /// Create a GET request builder with standard headers
@@ -121,6 +394,314 @@ impl TfeClient {
         self.with_headers(self.client.get(url))
     }
 
+    /// Send a GET request, serving it from the on-disk cache when `--cache` is enabled
+    ///
+    /// Fresh cached entries are returned as `200` responses without touching the
+    /// network. Expired entries with an `ETag` are revalidated with
+    /// `If-None-Match` and reused on `304`; with `--conditional-requests` every
+    /// entry is revalidated, so data is never older than the server's. Successful
+    /// JSON responses are stored with their `ETag` for later invocations.
+    pub(crate) async fn send_get(&self, url: &str) -> Result<reqwest::Response> {
+        let Some(disk_cache) = &self.disk_cache else {
+            return self.send_get_uncached(url).await;
+        };
+        let mut cached_headers = HeaderMap::new();
+        cached_headers.insert(CONTENT_TYPE, api::MEDIA_TYPE.parse().expect("valid header"));
+
+        if self.etag_cache.is_none() {
+            if let Some(body) = disk_cache.get(url) {
+                debug!("Disk cache hit for: {}", url);
+                return Ok(Self::build_response(StatusCode::OK, cached_headers, body));
+            }
+        }
+
+        let stale = disk_cache.get_revalidatable(url);
+        let mut request = self.get(url);
+        if let Some((etag, _)) = &stale {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let response = self.send(request).await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some((etag, body)) = stale {
+                debug!("Not modified, reusing disk cache entry for: {}", url);
+                disk_cache.store(url, &body, Some(&etag));
+                return Ok(Self::build_response(StatusCode::OK, cached_headers, body));
+            }
+            return Ok(response);
+        }
+        if response.status() != StatusCode::OK {
+            return Ok(response);
+        }
+        let headers = response.headers().clone();
+        let etag = headers.get(ETAG).and_then(|v| v.to_str().ok());
+        let body = response.bytes().await?.to_vec();
+        disk_cache.store(url, &body, etag);
+        Ok(Self::build_response(StatusCode::OK, headers, body))
+    }
+
+    /// Send a GET request, revalidating cached responses when conditional requests are enabled
+    ///
+    /// A `304 Not Modified` answer is turned into a `200` response carrying the
+    /// cached body, so callers handle cached and fresh responses the same way.
+    async fn send_get_uncached(&self, url: &str) -> Result<reqwest::Response> {
+        let Some(cache) = &self.etag_cache else {
+            return self.send(self.get(url)).await;
+        };
+
+        let mut request = self.get(url);
+        if let Some(etag) = cache.etag_for(url) {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let response = self.send(request).await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(cached) = cache.get(url) {
+                debug!("Not modified, using cached response for: {}", url);
+                return Ok(Self::build_response(
+                    StatusCode::OK,
+                    cached.headers,
+                    cached.body,
+                ));
+            }
+            return Ok(response);
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from);
+        let Some(etag) = etag.filter(|_| response.status().is_success()) else {
+            return Ok(response);
+        };
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?.to_vec();
+        cache.store(
+            url,
+            CachedResponse {
+                etag,
+                headers: headers.clone(),
+                body: body.clone(),
+            },
+        );
+        Ok(Self::build_response(status, headers, body))
+    }
+
+    /// Send a request, handling 429 (rate limited), 503 (maintenance mode) and 406 responses centrally
+    ///
+    /// A 429 is retried up to `--max-retries` times, waiting for the server's
+    /// `Retry-After` or an exponential backoff with jitter; the request was not
+    /// processed, so every method is safe to repeat. A 503 becomes `TfeError::Maintenance`. With `--retry-on-503` the request
+    /// is retried with exponential backoff, honoring the server's `Retry-After`.
+    /// Only idempotent methods (GET, HEAD, PUT, DELETE) are retried unless
+    /// `--retry-idempotent-only=false` is given. A 406 means the server refused
+    /// the JSON:API media type and is reported as such rather than as a generic failure.
+    /// A successful mutation clears the on-disk response cache.
+    pub(crate) async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let is_mutation = Self::request_method(&request)
+            .is_some_and(|m| !matches!(m, reqwest::Method::GET | reqwest::Method::HEAD));
+        let response = self.send_with_retry(request).await?;
+        if is_mutation && response.status().is_success() {
+            if let Some(disk_cache) = &self.disk_cache {
+                disk_cache.clear();
+            }
+        }
+        Ok(response)
+    }
+
+    /// Send a request, retrying 429 and (when enabled) 503 responses (see [`Self::send`])
+    async fn send_with_retry(
+        &self,
+        mut request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+        let mut rate_limit_attempt = 0;
+        loop {
+            let clone = request.try_clone();
+            let next = clone
+                .as_ref()
+                .and_then(|c| c.try_clone())
+                .filter(|next| self.may_retry(next));
+            let response = self.execute(request).await?;
+            if response.status() == StatusCode::NOT_ACCEPTABLE {
+                return Err(Self::not_acceptable_error(response).await);
+            }
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                let retry_after = Self::retry_after_secs(response.headers());
+                match clone {
+                    Some(clone) if rate_limit_attempt < self.max_retries => {
+                        let delay = Self::rate_limit_delay(rate_limit_attempt, retry_after);
+                        debug!(
+                            "Rate limited (429), retrying in {}ms ({}/{})",
+                            delay.as_millis(),
+                            rate_limit_attempt + 1,
+                            self.max_retries
+                        );
+                        tokio::time::sleep(delay).await;
+                        rate_limit_attempt += 1;
+                        request = clone;
+                        continue;
+                    }
+                    _ => {
+                        return Err(TfeError::RateLimited {
+                            retry_after,
+                            attempts: rate_limit_attempt + 1,
+                        })
+                    }
+                }
+            }
+            if response.status() != StatusCode::SERVICE_UNAVAILABLE {
+                return Ok(response);
+            }
+
+            let retry_after = Self::retry_after_secs(response.headers());
+            match next {
+                Some(next) if self.retry_on_503 && attempt < api::MAINTENANCE_MAX_RETRIES => {
+                    let delay = Self::maintenance_delay(attempt, retry_after);
+                    warn!(
+                        "TFE appears to be in maintenance mode (503), retrying in {}s ({}/{})",
+                        delay,
+                        attempt + 1,
+                        api::MAINTENANCE_MAX_RETRIES
+                    );
+                    tokio::time::sleep(Duration::from_secs(delay)).await;
+                    attempt += 1;
+                    request = next;
+                }
+                _ => return Err(TfeError::Maintenance { retry_after }),
+            }
+        }
+    }
+
+    /// Send a single request, printing it with `--debug-http`
+    ///
+    /// Every attempt (including retries) is printed as one line; the URL is
+    /// redacted and the token, which only travels in a header, never appears.
+    async fn execute(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        if !self.debug_http {
+            return Ok(request.send().await?);
+        }
+        let (client, request) = request.build_split();
+        let request = request?;
+        let method = request.method().clone();
+        let url = RedactedUrl(request.url()).to_string();
+        let started = Instant::now();
+        let result = client.execute(request).await.map_err(TfeError::from);
+        let outcome = match &result {
+            Ok(response) => response.status().to_string(),
+            Err(e) => format!("failed: {}", e),
+        };
+        eprintln!(
+            "{}",
+            http_debug_line(&method, &url, &outcome, started.elapsed())
+        );
+        result
+    }
+
+    /// Build the error for a 406 Not Acceptable response, including the API detail if any
+    async fn not_acceptable_error(response: reqwest::Response) -> TfeError {
+        let body = response.text().await.unwrap_or_default();
+        let details = ApiErrorDetail::parse_all(&body);
+        let detail = details
+            .first()
+            .and_then(|d| d.detail.as_deref().or(d.title.as_deref()));
+        let mut message = format!(
+            "TFE rejected the request media type '{}' (406 Not Acceptable). \
+             Check that --host points at a TFE/HCP Terraform API and that --api-version is supported",
+            api::MEDIA_TYPE
+        );
+        if let Some(detail) = detail {
+            message.push_str(&format!(": {}", detail));
+        }
+        TfeError::Api {
+            status: 406,
+            message,
+            details,
+        }
+    }
+
+    /// Check whether a request may be retried automatically
+    fn may_retry(&self, request: &reqwest::RequestBuilder) -> bool {
+        if !self.retry_idempotent_only {
+            return true;
+        }
+        let Some(method) = Self::request_method(request) else {
+            return false;
+        };
+        let idempotent = Self::is_idempotent(&method);
+        if !idempotent {
+            debug!("Not retrying non-idempotent {} request", method);
+        }
+        idempotent
+    }
+
+    /// HTTP method of a request builder (None if the request can't be built)
+    fn request_method(request: &reqwest::RequestBuilder) -> Option<reqwest::Method> {
+        request
+            .try_clone()
+            .and_then(|r| r.build().ok())
+            .map(|r| r.method().clone())
+    }
+
+    /// Methods that are safe to repeat without side effects piling up
+    fn is_idempotent(method: &reqwest::Method) -> bool {
+        matches!(
+            *method,
+            reqwest::Method::GET
+                | reqwest::Method::HEAD
+                | reqwest::Method::PUT
+                | reqwest::Method::DELETE
+        )
+    }
+
+    /// Parse a `Retry-After` header given in seconds
+    fn retry_after_secs(headers: &HeaderMap) -> Option<u64> {
+        headers
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+    }
+
+    /// Delay before the next 503 retry: server hint, else exponential backoff
+    fn maintenance_delay(attempt: u32, retry_after: Option<u64>) -> u64 {
+        retry_after
+            .unwrap_or_else(|| api::MAINTENANCE_BACKOFF_BASE_SECS << attempt.min(16))
+            .min(api::MAINTENANCE_MAX_DELAY_SECS)
+    }
+
+    /// Delay before the next 429 retry: server hint, else exponential backoff with jitter
+    ///
+    /// Jitter adds up to half the backoff so parallel page fetches that were
+    /// throttled together don't all come back at the same instant.
+    fn rate_limit_delay(attempt: u32, retry_after: Option<u64>) -> Duration {
+        let max = Duration::from_secs(api::RATE_LIMIT_MAX_DELAY_SECS);
+        if let Some(secs) = retry_after {
+            return Duration::from_secs(secs).min(max);
+        }
+        let backoff_ms = api::RATE_LIMIT_BACKOFF_BASE_MS.saturating_mul(1 << attempt.min(16));
+        let jitter_ms = Self::jitter_seed() % (backoff_ms / 2 + 1);
+        Duration::from_millis(backoff_ms + jitter_ms).min(max)
+    }
+
+    /// Cheap pseudo-random value for backoff jitter (no need for a RNG dependency)
+    fn jitter_seed() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| u64::from(d.subsec_nanos()))
+            .unwrap_or(0)
+    }
+
+    /// Rebuild a response from a status, headers and an already-read body
+    fn build_response(status: StatusCode, headers: HeaderMap, body: Vec<u8>) -> reqwest::Response {
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        *response.headers_mut() = headers;
+        reqwest::Response::from(response)
+    }
+
     /// Create a POST request builder with standard headers
     #[allow(dead_code)]
     pub(crate) fn post(&self, url: &str) -> reqwest::RequestBuilder {
@@ -150,10 +731,13 @@ impl TfeClient {
         T: serde::de::DeserializeOwned,
     {
         if !response.status().is_success() {
-            return Err(TfeError::Api {
-                status: response.status().as_u16(),
-                message: format!("Failed to fetch {}", error_context),
-            });
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TfeError::api_with_details(
+                status,
+                format!("Failed to fetch {}", error_context),
+                &body,
+            ));
         }
         Ok(response.json().await?)
     }
@@ -183,6 +767,30 @@ impl TfeClient {
             .await
     }
 
+    /// Fetch the listing the command renders: only `page` when given
+    /// (`--page`/`--page-size`), otherwise all pages
+    ///
+    /// Enrichment and lookup fetches use [`Self::fetch_all_pages`] so that a
+    /// requested page never truncates them.
+    pub async fn fetch_pages<T, R>(
+        &self,
+        path: &str,
+        error_context: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<T>>
+    where
+        T: Send,
+        R: DeserializeOwned + PaginatedResponse<T> + Send,
+    {
+        match page {
+            Some(page) => {
+                self.fetch_requested_page::<T, R>(path, page, error_context)
+                    .await
+            }
+            None => self.fetch_all_pages::<T, R>(path, error_context).await,
+        }
+    }
+
     /// Prefetch pagination info without fetching all data
     ///
     /// This method fetches only the first page to get pagination metadata.
@@ -214,7 +822,7 @@ impl TfeClient {
 
         debug!("Prefetching pagination info from: {}", first_page_url);
 
-        let response = self.get(&first_page_url).send().await?;
+        let response = self.send_get(&first_page_url).await?;
 
         let first_resp: R = self.parse_api_response(response, error_context).await?;
 
@@ -252,7 +860,7 @@ impl TfeClient {
         let url = format!("{}{}", self.base_url(), path);
         debug!("Fetching {} from: {}", resource_label, url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -261,14 +869,19 @@ impl TfeClient {
                     serde_json::from_value(raw["data"].clone()).map_err(|e| TfeError::Api {
                         status: 200,
                         message: format!("Failed to parse {}: {}", resource_label, e),
+                        details: Vec::new(),
                     })?;
                 Ok(Some((item, raw)))
             }
             404 => Ok(None),
-            status => Err(TfeError::Api {
-                status,
-                message: format!("Failed to fetch {}", resource_label),
-            }),
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                Err(TfeError::api_with_details(
+                    status,
+                    format!("Failed to fetch {}", resource_label),
+                    &body,
+                ))
+            }
         }
     }
 
@@ -296,7 +909,7 @@ impl TfeClient {
 
         debug!("Fetching page 1 from: {}", first_page_url);
 
-        let response = self.get(&first_page_url).send().await?;
+        let response = self.send_get(&first_page_url).await?;
 
         let first_resp: R = self.parse_api_response(response, error_context).await?;
         let meta = first_resp.meta().cloned();
@@ -312,6 +925,8 @@ impl TfeClient {
         };
 
         debug!("Page 1/{}, total items: {}", total_pages, total_count);
+        self.add_progress_pages(u64::from(total_pages.max(1)));
+        self.page_fetched();
 
         // If only one page, we're done
         if total_pages <= 1 {
@@ -324,7 +939,7 @@ impl TfeClient {
         debug!(
             "Fetching {} remaining pages in parallel (max {} concurrent)",
             remaining_pages.len(),
-            api::MAX_CONCURRENT_PAGE_REQUESTS
+            self.concurrency
         );
 
         // Create futures for all remaining pages
@@ -342,7 +957,7 @@ impl TfeClient {
 
         // Execute with concurrency limit
         let results: Vec<Result<(u32, Vec<T>)>> = stream::iter(page_futures)
-            .buffer_unordered(api::MAX_CONCURRENT_PAGE_REQUESTS)
+            .buffer_unordered(self.concurrency)
             .collect()
             .await;
 
@@ -371,6 +986,40 @@ impl TfeClient {
         Ok(all_items)
     }
 
+    /// Fetch only the page requested with `--page`/`--page-size`, reporting
+    /// the pagination meta on stderr
+    async fn fetch_requested_page<T, R>(
+        &self,
+        path: &str,
+        page: PageRequest,
+        error_context: &str,
+    ) -> Result<Vec<T>>
+    where
+        R: DeserializeOwned + PaginatedResponse<T>,
+    {
+        let separator = if path.contains('?') { "&" } else { "?" };
+        let url = format!(
+            "{}{}{}page[size]={}&page[number]={}",
+            self.base_url(),
+            path,
+            separator,
+            page.size,
+            page.number
+        );
+        debug!("Fetching requested page {} from: {}", page.number, url);
+
+        let response = self.send_get(&url).await?;
+        let resp: R = self.parse_api_response(response, error_context).await?;
+        let pagination = resp.meta().and_then(|m| m.pagination.as_ref());
+        if !self.quiet {
+            page.report(
+                pagination.map(|p| p.total_pages),
+                pagination.map(|p| p.total_count),
+            );
+        }
+        Ok(resp.into_data())
+    }
+
     /// Fetch a single page (helper for parallel pagination)
     async fn fetch_single_page<T, R>(
         &self,
@@ -383,11 +1032,12 @@ impl TfeClient {
     {
         debug!("Fetching page {} from: {}", page_num, url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         let page_context = format!("{} (page {})", error_context, page_num);
         let resp: R = self.parse_api_response(response, &page_context).await?;
         let items = resp.into_data();
+        self.page_fetched();
 
         debug!("Page {} returned {} items", page_num, items.len());
         Ok((page_num, items))
@@ -415,21 +1065,48 @@ mod tests {
 
     #[test]
     fn test_base_url() {
-        let client = TfeClient::new("token".to_string(), "example.com".to_string());
+        let client =
+            TfeClient::new("token".to_string(), "example.com".to_string(), None, None).unwrap();
         assert_eq!(client.base_url(), "https://example.com/api/v2");
     }
 
     #[test]
     fn test_client_creation() {
-        let client = TfeClient::new("my-token".to_string(), "tfe.example.com".to_string());
+        let client = TfeClient::new(
+            "my-token".to_string(),
+            "tfe.example.com".to_string(),
+            None,
+            None,
+        )
+        .unwrap();
         assert_eq!(client.host, "tfe.example.com");
         assert_eq!(client.token, "my-token");
         assert!(!client.batch_mode); // Default is false
     }
 
+    #[test]
+    fn test_effective_org_precedence() {
+        let mut client =
+            TfeClient::new("token".to_string(), "example.com".to_string(), None, None).unwrap();
+        let explicit = "cli-org".to_string();
+        assert_eq!(client.effective_org(None), None);
+
+        client.set_context_org(Some("ctx-org".to_string()));
+        assert_eq!(client.effective_org(None), Some("ctx-org".to_string()));
+        assert_eq!(
+            client.effective_org(Some(&explicit)),
+            Some("cli-org".to_string())
+        );
+
+        // --all-orgs clears the context org
+        client.set_context_org(None);
+        assert_eq!(client.effective_org(None), None);
+    }
+
     #[test]
     fn test_batch_mode() {
-        let mut client = TfeClient::new("token".to_string(), "example.com".to_string());
+        let mut client =
+            TfeClient::new("token".to_string(), "example.com".to_string(), None, None).unwrap();
         assert!(!client.is_batch_mode());
 
         client.set_batch_mode(true);
@@ -439,22 +1116,142 @@ mod tests {
         assert!(!client.is_batch_mode());
     }
 
+    #[test]
+    fn test_conditional_requests_toggle() {
+        let mut client =
+            TfeClient::new("token".to_string(), "example.com".to_string(), None, None).unwrap();
+        assert!(!client.conditional_requests());
+
+        client.set_conditional_requests(true);
+        assert!(client.conditional_requests());
+
+        client.set_conditional_requests(false);
+        assert!(!client.conditional_requests());
+    }
+
+    #[test]
+    fn test_maintenance_delay() {
+        assert_eq!(TfeClient::maintenance_delay(0, None), 2);
+        assert_eq!(TfeClient::maintenance_delay(2, None), 8);
+        assert_eq!(TfeClient::maintenance_delay(10, None), 120);
+        assert_eq!(TfeClient::maintenance_delay(3, Some(5)), 5);
+        assert_eq!(TfeClient::maintenance_delay(0, Some(3600)), 120);
+    }
+
+    #[test]
+    fn test_rate_limit_delay() {
+        assert_eq!(
+            TfeClient::rate_limit_delay(0, Some(7)),
+            Duration::from_secs(7)
+        );
+        assert_eq!(
+            TfeClient::rate_limit_delay(0, Some(3600)),
+            Duration::from_secs(api::RATE_LIMIT_MAX_DELAY_SECS)
+        );
+        for attempt in 0..4 {
+            let base = Duration::from_millis(api::RATE_LIMIT_BACKOFF_BASE_MS << attempt);
+            let delay = TfeClient::rate_limit_delay(attempt, None);
+            assert!(delay >= base && delay <= base + base / 2, "{:?}", delay);
+        }
+        assert_eq!(
+            TfeClient::rate_limit_delay(20, None),
+            Duration::from_secs(api::RATE_LIMIT_MAX_DELAY_SECS)
+        );
+    }
+
+    #[test]
+    fn test_retry_after_secs() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(TfeClient::retry_after_secs(&headers), None);
+
+        headers.insert(RETRY_AFTER, "30".parse().unwrap());
+        assert_eq!(TfeClient::retry_after_secs(&headers), Some(30));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(TfeClient::retry_after_secs(&headers), None);
+    }
+
+    #[test]
+    fn test_quiet() {
+        let mut client = TfeClient::test_client("http://localhost");
+        assert!(!client.is_quiet());
+
+        client.set_quiet(true);
+        assert!(client.is_quiet());
+    }
+
+    #[test]
+    fn test_prompt_timeout() {
+        let mut client =
+            TfeClient::new("token".to_string(), "example.com".to_string(), None, None).unwrap();
+        assert_eq!(client.prompt_timeout(), None);
+
+        client.set_prompt_timeout(Some(30));
+        assert_eq!(client.prompt_timeout(), Some(30));
+    }
+
+    #[test]
+    fn test_api_version_default() {
+        let client =
+            TfeClient::new("token".to_string(), "example.com".to_string(), None, None).unwrap();
+        assert_eq!(client.api_version(), "v2");
+    }
+
+    #[test]
+    fn test_api_version_override() {
+        let mut client =
+            TfeClient::new("token".to_string(), "example.com".to_string(), None, None).unwrap();
+        client.set_api_version("v3");
+        assert_eq!(client.base_url(), "https://example.com/api/v3");
+    }
+
+    #[test]
+    fn test_api_version_normalization() {
+        let mut client =
+            TfeClient::new("token".to_string(), "example.com".to_string(), None, None).unwrap();
+
+        client.set_api_version("2");
+        assert_eq!(client.api_version(), "v2");
+
+        client.set_api_version("/v3/");
+        assert_eq!(client.api_version(), "v3");
+
+        client.set_api_version("  ");
+        assert_eq!(client.api_version(), "v2");
+    }
+
     #[test]
     fn test_host_getter() {
-        let client = TfeClient::new("token".to_string(), "custom.terraform.io".to_string());
+        let client = TfeClient::new(
+            "token".to_string(),
+            "custom.terraform.io".to_string(),
+            None,
+            None,
+        )
+        .unwrap();
         assert_eq!(client.host(), "custom.terraform.io");
     }
 
     #[test]
     fn test_base_url_with_app_terraform_io() {
-        let client = TfeClient::new("token".to_string(), "app.terraform.io".to_string());
+        let client = TfeClient::new(
+            "token".to_string(),
+            "app.terraform.io".to_string(),
+            None,
+            None,
+        )
+        .unwrap();
         assert_eq!(client.base_url(), "https://app.terraform.io/api/v2");
     }
 
     #[test]
     fn test_base_url_strips_leading_slash() {
-        // Ensure base_url works correctly regardless of BASE_PATH format
-        let client = TfeClient::new("token".to_string(), "test.com".to_string());
+        // Ensure base_url works correctly regardless of ROOT_PATH format
+        let client =
+            TfeClient::new("token".to_string(), "test.com".to_string(), None, None).unwrap();
         let url = client.base_url();
         assert!(!url.contains("//api")); // No double slashes
         assert!(url.starts_with("https://"));
@@ -551,43 +1348,103 @@ mod pagination_tests {
     }
 
     #[tokio::test]
-    async fn test_fetch_all_pages_multiple_pages_parallel() {
+    async fn test_fetch_pages_requested_page_only() {
         let mock_server = MockServer::start().await;
         let client = TfeClient::test_client(&mock_server.uri());
+        let page = PageRequest::from_flags(Some(3), Some(2));
 
-        // Page 1
         Mock::given(method("GET"))
             .and(path("/test-items"))
-            .and(query_param("page[number]", "1"))
+            .and(query_param("page[number]", "3"))
+            .and(query_param("page[size]", "2"))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": [
-                    test_item_json("item-1", "Item 1"),
-                    test_item_json("item-2", "Item 2")
-                ],
+                "data": [test_item_json("item-5", "Item 5")],
                 "meta": {
-                    "pagination": {
-                        "current-page": 1,
-                        "total-pages": 3,
-                        "total-count": 6
-                    }
+                    "pagination": {"current-page": 3, "total-pages": 3, "total-count": 5}
                 }
             })))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
-        // Page 2
-        Mock::given(method("GET"))
-            .and(path("/test-items"))
-            .and(query_param("page[number]", "2"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "data": [
-                    test_item_json("item-3", "Item 3"),
-                    test_item_json("item-4", "Item 4")
-                ],
-                "meta": {
-                    "pagination": {
-                        "current-page": 2,
-                        "total-pages": 3,
+        let items = client
+            .fetch_pages::<TestItem, TestItemsResponse>("/test-items", "test items", page)
+            .await
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id, "item-5");
+    }
+
+    #[test]
+    fn test_page_request_from_flags() {
+        assert_eq!(PageRequest::from_flags(None, None), None);
+        assert_eq!(
+            PageRequest::from_flags(Some(2), None),
+            Some(PageRequest {
+                number: 2,
+                size: api::DEFAULT_PAGE_SIZE
+            })
+        );
+        assert_eq!(
+            PageRequest::from_flags(None, Some(10)),
+            Some(PageRequest {
+                number: 1,
+                size: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_page_request_summary() {
+        let page = PageRequest {
+            number: 2,
+            size: 20,
+        };
+        assert_eq!(
+            page.summary(Some(5), Some(93)),
+            "Page 2 of 5 (page size 20, 93 total)"
+        );
+        assert_eq!(page.summary(None, None), "Page 2 (page size 20)");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_pages_multiple_pages_parallel() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        // Page 1
+        Mock::given(method("GET"))
+            .and(path("/test-items"))
+            .and(query_param("page[number]", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    test_item_json("item-1", "Item 1"),
+                    test_item_json("item-2", "Item 2")
+                ],
+                "meta": {
+                    "pagination": {
+                        "current-page": 1,
+                        "total-pages": 3,
+                        "total-count": 6
+                    }
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        // Page 2
+        Mock::given(method("GET"))
+            .and(path("/test-items"))
+            .and(query_param("page[number]", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    test_item_json("item-3", "Item 3"),
+                    test_item_json("item-4", "Item 4")
+                ],
+                "meta": {
+                    "pagination": {
+                        "current-page": 2,
+                        "total-pages": 3,
                         "total-count": 6
                     }
                 }
@@ -615,7 +1472,9 @@ mod pagination_tests {
             .mount(&mock_server)
             .await;
 
+        let bar = ProgressBar::hidden();
         let result = client
+            .with_progress(Some(&bar))
             .fetch_all_pages::<TestItem, TestItemsResponse>("/test-items", "test items")
             .await;
 
@@ -623,6 +1482,10 @@ mod pagination_tests {
         let items = result.unwrap();
         assert_eq!(items.len(), 6);
 
+        // Every page is reported to the client's progress handle
+        assert_eq!(bar.length(), Some(3));
+        assert_eq!(bar.position(), 3);
+
         // Verify order is maintained (page 1, then page 2, then page 3)
         assert_eq!(items[0].id, "item-1");
         assert_eq!(items[1].id, "item-2");
@@ -682,6 +1545,29 @@ mod pagination_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_fetch_all_pages_api_error_details() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/test-items"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "errors": [{"status": "404", "title": "not found"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let err = client
+            .fetch_all_pages::<TestItem, TestItemsResponse>("/test-items", "test items")
+            .await
+            .unwrap_err();
+
+        assert_eq!(err.api_details().len(), 1);
+        assert_eq!(err.api_details()[0].title.as_deref(), Some("not found"));
+        assert!(err.to_string().ends_with(": not found"), "{}", err);
+    }
+
     #[tokio::test]
     async fn test_fetch_all_pages_api_error_on_subsequent_page() {
         let mock_server = MockServer::start().await;
@@ -718,7 +1604,9 @@ mod pagination_tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 500);
                 assert!(message.contains("page 2"));
             }
@@ -794,3 +1682,652 @@ mod pagination_tests {
         assert!(result.unwrap().is_empty());
     }
 }
+
+#[cfg(test)]
+mod conditional_request_tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn workspace_body(name: &str) -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "id": "ws-abc123",
+                "type": "workspaces",
+                "attributes": { "name": name }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_not_modified_returns_cached_payload() {
+        let mock_server = MockServer::start().await;
+        let mut client = TfeClient::test_client(&mock_server.uri());
+        client.set_conditional_requests(true);
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc123"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc123"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(workspace_body("cached-ws")),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let first = client
+            .fetch_resource_by_path::<serde_json::Value>("/workspaces/ws-abc123", "workspace")
+            .await
+            .unwrap()
+            .unwrap();
+        let second = client
+            .fetch_resource_by_path::<serde_json::Value>("/workspaces/ws-abc123", "workspace")
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(first.1, second.1);
+        assert_eq!(second.0["attributes"]["name"], "cached-ws");
+    }
+
+    #[tokio::test]
+    async fn test_disabled_does_not_send_if_none_match() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc123"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .with_priority(1)
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc123"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(workspace_body("fresh-ws")),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        for _ in 0..2 {
+            let (ws, _raw) = client
+                .fetch_resource_by_path::<serde_json::Value>("/workspaces/ws-abc123", "workspace")
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(ws["attributes"]["name"], "fresh-ws");
+        }
+    }
+}
+
+#[cfg(test)]
+mod maintenance_tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_503_returns_maintenance_error() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(
+                ResponseTemplate::new(503)
+                    .insert_header("Retry-After", "120")
+                    .set_body_string("TFE is down for maintenance"),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/organizations", mock_server.uri());
+        let result = client.send_get(&url).await;
+
+        match result {
+            Err(TfeError::Maintenance { retry_after }) => assert_eq!(retry_after, Some(120)),
+            other => panic!(
+                "Expected maintenance error, got {:?}",
+                other.map(|r| r.status())
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_503_retried_when_enabled() {
+        let mock_server = MockServer::start().await;
+        let mut client = TfeClient::test_client(&mock_server.uri());
+        client.set_retry_on_503(true);
+
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"data": []})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/organizations", mock_server.uri());
+        let response = client.send_get(&url).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_503_post_not_retried_by_default() {
+        let mock_server = MockServer::start().await;
+        let mut client = TfeClient::test_client(&mock_server.uri());
+        client.set_retry_on_503(true);
+
+        Mock::given(method("POST"))
+            .and(path("/runs"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/runs", mock_server.uri());
+        let result = client.send(client.post(&url).body("{}")).await;
+
+        assert!(matches!(result, Err(TfeError::Maintenance { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_503_post_retried_when_idempotent_only_disabled() {
+        let mock_server = MockServer::start().await;
+        let mut client = TfeClient::test_client(&mock_server.uri());
+        client.set_retry_on_503(true);
+        client.set_retry_idempotent_only(false);
+
+        Mock::given(method("POST"))
+            .and(path("/runs"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/runs"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/runs", mock_server.uri());
+        let response = client.send(client.post(&url).body("{}")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_json_body_keeps_jsonapi_content_type() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("PATCH"))
+            .and(path("/workspaces/ws-1"))
+            .and(header("content-type", "application/vnd.api+json"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/workspaces/ws-1", mock_server.uri());
+        let body = serde_json::json!({"data": {"type": "workspaces"}});
+        let response = client.send(client.patch(&url).json(&body)).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_token_never_in_rendered_errors() {
+        let token = "leak-check.atlasv1.Zm9vYmFyYmF6";
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::with_base_url(
+            token.to_string(),
+            "mock.terraform.io".to_string(),
+            mock_server.uri(),
+        );
+
+        // A misbehaving gateway echoing the request headers in its error detail
+        let detail = format!("Authorization: Bearer {} rejected", token);
+        Mock::given(method("GET"))
+            .and(path("/api/v2/organizations"))
+            .and(header(
+                "Authorization",
+                format!("Bearer {}", token).as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "errors": [{"status": "401", "detail": detail}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/api/v2/organizations", mock_server.uri());
+        let response = client.send_get(&url).await.unwrap();
+        let err = client
+            .parse_api_response::<serde_json::Value>(response, "organizations")
+            .await
+            .unwrap_err();
+        let rendered = format!("{} {:?}", err, err);
+        assert!(!rendered.contains(token), "token leaked: {}", rendered);
+        assert!(rendered.contains("Bearer REDACTED"));
+
+        // Request debug output hides the Authorization header
+        let request = client.get(&url).build().unwrap();
+        assert!(!format!("{:?}", request).contains(token));
+
+        // Transport errors keep the URL but redact its secrets
+        let err = client
+            .send(client.get("http://127.0.0.1:1/x?token=abc123&page=1"))
+            .await
+            .unwrap_err();
+        let rendered = format!("{} {:?}", err, err);
+        assert!(!rendered.contains("abc123"), "secret leaked: {}", rendered);
+        assert!(rendered.contains("page=1"));
+    }
+
+    #[tokio::test]
+    async fn test_406_returns_clear_error() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("POST"))
+            .and(path("/runs"))
+            .respond_with(ResponseTemplate::new(406).set_body_json(serde_json::json!({
+                "errors": [{"status": "406", "title": "not acceptable", "detail": "bad media type"}]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/runs", mock_server.uri());
+        let err = client
+            .send(client.post(&url).json(&serde_json::json!({})))
+            .await
+            .unwrap_err();
+
+        match err {
+            TfeError::Api {
+                status, message, ..
+            } => {
+                assert_eq!(status, 406);
+                assert!(message.contains("application/vnd.api+json"));
+                assert!(message.contains("bad media type"));
+            }
+            other => panic!("Expected API error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_http_debug_line() {
+        let line = http_debug_line(
+            &reqwest::Method::GET,
+            "https://tfe.example.com/api/v2/ping",
+            "200 OK",
+            Duration::from_millis(42),
+        );
+        assert_eq!(
+            line,
+            "[http] GET https://tfe.example.com/api/v2/ping -> 200 OK (42ms)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_debug_http_keeps_response_and_errors() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v2/ping"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+
+        let mut client = TfeClient::test_client(&mock_server.uri());
+        client.set_debug_http(true);
+        let url = format!("{}/api/v2/ping", mock_server.uri());
+        let response = client.send(client.get(&url)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        // Connection failures still surface as errors
+        let result = client.send(client.get("http://127.0.0.1:1/ping")).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_is_idempotent() {
+        assert!(TfeClient::is_idempotent(&reqwest::Method::GET));
+        assert!(TfeClient::is_idempotent(&reqwest::Method::HEAD));
+        assert!(TfeClient::is_idempotent(&reqwest::Method::PUT));
+        assert!(TfeClient::is_idempotent(&reqwest::Method::DELETE));
+        assert!(!TfeClient::is_idempotent(&reqwest::Method::POST));
+        assert!(!TfeClient::is_idempotent(&reqwest::Method::PATCH));
+    }
+}
+
+#[cfg(test)]
+mod disk_cache_tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn cached_client(uri: &str, dir: &std::path::Path) -> TfeClient {
+        let mut client = TfeClient::test_client(uri);
+        client.disk_cache = Some(Arc::new(DiskCache::new(
+            dir.to_path_buf(),
+            Duration::from_secs(60),
+            "test-token",
+        )));
+        client
+    }
+
+    #[tokio::test]
+    async fn test_second_get_served_from_disk() {
+        let mock_server = MockServer::start().await;
+        let dir = tempfile::tempdir().unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "id": "ws-abc123", "attributes": { "name": "cached" } }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // Separate clients mimic separate invocations sharing the cache directory
+        for _ in 0..2 {
+            let client = cached_client(&mock_server.uri(), dir.path());
+            let (ws, _raw) = client
+                .fetch_resource_by_path::<serde_json::Value>("/workspaces/ws-abc123", "workspace")
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(ws["attributes"]["name"], "cached");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mutation_clears_disk_cache() {
+        let mock_server = MockServer::start().await;
+        let dir = tempfile::tempdir().unwrap();
+        let client = cached_client(&mock_server.uri(), dir.path());
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "id": "ws-abc123" }
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/workspaces/ws-abc123"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/workspaces/ws-abc123", mock_server.uri());
+        client.send_get(&url).await.unwrap();
+        client.send(client.patch(&url)).await.unwrap();
+        client.send_get(&url).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_expired_entry_revalidated_with_etag() {
+        let mock_server = MockServer::start().await;
+        let dir = tempfile::tempdir().unwrap();
+        let mut client = cached_client(&mock_server.uri(), dir.path());
+        client.disk_cache = Some(Arc::new(DiskCache::new(
+            dir.path().to_path_buf(),
+            Duration::ZERO,
+            "test-token",
+        )));
+
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(serde_json::json!({"data": [{"id": "org-a"}]})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/organizations", mock_server.uri());
+        for _ in 0..2 {
+            let response = client.send_get(&url).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body: serde_json::Value = response.json().await.unwrap();
+            assert_eq!(body["data"][0]["id"], "org-a");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fresh_entry_with_etag_served_from_disk() {
+        let mock_server = MockServer::start().await;
+        let dir = tempfile::tempdir().unwrap();
+        let client = cached_client(&mock_server.uri(), dir.path());
+
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(serde_json::json!({"data": [{"id": "org-a"}]})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // Within the TTL the ETag is not revalidated; no request is made
+        let url = format!("{}/organizations", mock_server.uri());
+        for _ in 0..3 {
+            let body: serde_json::Value =
+                client.send_get(&url).await.unwrap().json().await.unwrap();
+            assert_eq!(body["data"][0]["id"], "org-a");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_conditional_requests_revalidate_fresh_entries() {
+        let mock_server = MockServer::start().await;
+        let dir = tempfile::tempdir().unwrap();
+        let mut client = cached_client(&mock_server.uri(), dir.path());
+        client.set_conditional_requests(true);
+
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v2\"")
+                    .set_body_json(serde_json::json!({"data": [{"id": "changed"}]})),
+            )
+            .expect(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(serde_json::json!({"data": [{"id": "original"}]})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/organizations", mock_server.uri());
+        client.send_get(&url).await.unwrap();
+        let body: serde_json::Value = client.send_get(&url).await.unwrap().json().await.unwrap();
+        assert_eq!(body["data"][0]["id"], "changed");
+    }
+
+    #[tokio::test]
+    async fn test_error_responses_not_cached() {
+        let mock_server = MockServer::start().await;
+        let dir = tempfile::tempdir().unwrap();
+        let client = cached_client(&mock_server.uri(), dir.path());
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({})))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/workspaces/ws-missing", mock_server.uri());
+        for _ in 0..2 {
+            let response = client.send_get(&url).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+    }
+}
+
+#[cfg(test)]
+mod rate_limit_tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_429_retried_until_success() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/runs"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"data": []})))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/organizations/my-org/runs", mock_server.uri());
+        let response = client.send_get(&url).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_429_error_after_max_retries() {
+        let mock_server = MockServer::start().await;
+        let mut client = TfeClient::test_client(&mock_server.uri());
+        client.set_max_retries(2);
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/runs"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/organizations/my-org/runs", mock_server.uri());
+        let result = client.send_get(&url).await;
+
+        match result {
+            Err(TfeError::RateLimited {
+                retry_after,
+                attempts,
+            }) => {
+                assert_eq!(retry_after, Some(0));
+                assert_eq!(attempts, 3);
+            }
+            other => panic!(
+                "Expected rate limited error, got {:?}",
+                other.map(|r| r.status())
+            ),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_429_post_retried() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("POST"))
+            .and(path("/runs"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/runs"))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/runs", mock_server.uri());
+        let response = client.send(client.post(&url).body("{}")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_429_not_retried_when_disabled() {
+        let mock_server = MockServer::start().await;
+        let mut client = TfeClient::test_client(&mock_server.uri());
+        client.set_max_retries(0);
+
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(ResponseTemplate::new(429))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let url = format!("{}/organizations", mock_server.uri());
+        let result = client.send_get(&url).await;
+
+        assert!(matches!(
+            result,
+            Err(TfeError::RateLimited { attempts: 1, .. })
+        ));
+    }
+}
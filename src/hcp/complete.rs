@@ -0,0 +1,104 @@
+//! Dynamic shell completion of organization and workspace names
+//!
+//! `hcpctl __complete ws --org my-org <partial>` prints matching names one per
+//! line. It always runs non-interactively within a short time budget and
+//! prints nothing on failure, so a shell completion function calling it never
+//! hangs or shows errors at the prompt.
+
+use log::debug;
+
+use crate::cli::{Cli, CompleteArgs, CompleteResource};
+use crate::config::complete;
+use crate::context::resolve_active_context;
+use crate::error::Result;
+use crate::hcp::{HostResolver, TfeClient, TlsConfig, TokenResolver};
+
+/// Print completion candidates; errors and timeouts print nothing
+pub async fn run_complete_command(cli: &Cli, args: &CompleteArgs) {
+    match tokio::time::timeout(complete::TIMEOUT, complete_names(cli, args)).await {
+        Ok(Ok(names)) => {
+            for name in names {
+                println!("{}", name);
+            }
+        }
+        Ok(Err(e)) => debug!("Completion failed: {}", e),
+        Err(_) => debug!("Completion timed out"),
+    }
+}
+
+async fn complete_names(cli: &Cli, args: &CompleteArgs) -> Result<Vec<String>> {
+    let client = completion_client(cli).await?;
+    let names = match args.resource {
+        CompleteResource::Org => client.get_organizations().await?,
+        CompleteResource::Ws => {
+            let Some(org) = client.effective_org(args.org.as_ref()) else {
+                return Ok(Vec::new());
+            };
+            client
+                .search_workspace_names(&org, &args.partial, complete::MAX_RESULTS)
+                .await?
+        }
+    };
+    Ok(matching_names(names, &args.partial))
+}
+
+/// Client built from the usual host/token sources without ever prompting
+async fn completion_client(cli: &Cli) -> Result<TfeClient> {
+    let context = resolve_active_context(cli.profile.as_deref().or(cli.context.as_deref()));
+    let host = HostResolver::resolve(
+        cli.host.as_deref(),
+        context.as_ref().map(|c| c.host.as_str()),
+        true,
+        None,
+    )
+    .await?;
+    let token = TokenResolver::new(&host).resolve(
+        cli.token.as_deref(),
+        context.as_ref().and_then(|c| c.token.as_deref()),
+    )?;
+    let tls = TlsConfig {
+        ca_cert: cli.ca_cert.clone(),
+        client_cert: cli.client_cert.clone(),
+        client_key: cli.client_key.clone(),
+    };
+    let mut client = TfeClient::new(token.into_value(), host, Some(&tls), cli.proxy.as_deref())?;
+    client.set_batch_mode(true);
+    client.set_max_retries(0);
+    client.set_api_version(&cli.api_version);
+    client.set_context_org(context.and_then(|c| c.org));
+    Ok(client)
+}
+
+/// Names starting with `partial`, sorted and capped at [`complete::MAX_RESULTS`]
+fn matching_names(mut names: Vec<String>, partial: &str) -> Vec<String> {
+    names.retain(|n| n.starts_with(partial));
+    names.sort();
+    names.dedup();
+    names.truncate(complete::MAX_RESULTS);
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_names_prefix_sorted_capped() {
+        let names = vec![
+            "app-b".into(),
+            "other".into(),
+            "app-a".into(),
+            "app-a".into(),
+        ];
+        assert_eq!(matching_names(names, "app"), vec!["app-a", "app-b"]);
+
+        // Fuzzy server-side search matches that do not start with the prefix are dropped
+        assert_eq!(
+            matching_names(vec!["app-web".into(), "my-app".into()], "app"),
+            vec!["app-web"]
+        );
+
+        let many: Vec<String> = (0..100).map(|i| format!("ws-{:03}", i)).collect();
+        assert_eq!(matching_names(many, "").len(), complete::MAX_RESULTS);
+    }
+}
@@ -1,7 +1,9 @@
 //! Configuration versions API operations
 
-use log::debug;
-use std::path::Path;
+use log::{debug, warn};
+use reqwest::header::{CONTENT_RANGE, RANGE};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 use tokio::fs::File;
 use tokio::io::AsyncWriteExt;
 
@@ -43,7 +45,7 @@ impl TfeClient {
         let url = format!("{}/configuration-versions/{}", self.base_url(), cv_id);
         debug!("Fetching configuration version: {}", url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -53,22 +55,26 @@ impl TfeClient {
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Configuration version '{}' not found", cv_id),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!(
-                        "Failed to fetch configuration version '{}': {}",
-                        cv_id, body
-                    ),
-                })
+                    format!("Failed to fetch configuration version '{}'", cv_id),
+                    &body,
+                ))
             }
         }
     }
 
     /// Download configuration files as tar.gz
     ///
+    /// The archive is streamed into `<output_path>.part` and renamed into place
+    /// once complete, so an interrupted download never leaves a truncated file
+    /// at `output_path`. A dropped connection is resumed with an HTTP Range
+    /// request; if the server ignores the range the download starts over.
+    ///
     /// # Arguments
     /// * `cv_id` - The configuration version ID
     /// * `output_path` - Path where to save the tar.gz file
@@ -83,31 +89,137 @@ impl TfeClient {
         );
         debug!("Downloading configuration from: {}", url);
 
-        // The /download endpoint returns 302 redirect to actual file
-        // reqwest follows redirects by default
-        let response = self.get(&url).send().await?;
+        let part_path = part_path(output_path);
+        let result = self.download_to_part(&url, cv_id, &part_path).await;
+        let size = match result {
+            Ok(size) => size,
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&part_path).await;
+                return Err(e);
+            }
+        };
 
-        match response.status().as_u16() {
-            200 => {
-                let bytes = response.bytes().await?;
-                let size = bytes.len() as u64;
+        tokio::fs::rename(&part_path, output_path)
+            .await
+            .map_err(|e| TfeError::Io {
+                message: format!(
+                    "Failed to move '{}' to '{}': {}",
+                    part_path.display(),
+                    output_path.display(),
+                    e
+                ),
+            })?;
 
-                // Write to file
-                let mut file = File::create(output_path).await.map_err(|e| TfeError::Io {
-                    message: format!("Failed to create file '{}': {}", output_path.display(), e),
-                })?;
+        debug!("Downloaded {} bytes to {}", size, output_path.display());
+        Ok(size)
+    }
 
-                file.write_all(&bytes).await.map_err(|e| TfeError::Io {
-                    message: format!("Failed to write to '{}': {}", output_path.display(), e),
-                })?;
+    /// Download `url` into `part_path`, resuming after dropped connections
+    async fn download_to_part(&self, url: &str, cv_id: &str, part_path: &Path) -> Result<u64> {
+        let mut file = create_file(part_path).await?;
+        let mut written: u64 = 0;
+        let mut expected: Option<u64> = None;
+        let mut attempt = 0;
+
+        loop {
+            // The /download endpoint returns 302 redirect to actual file;
+            // reqwest follows redirects by default and keeps the Range header
+            let mut request = self.get(url);
+            if written > 0 {
+                request = request.header(RANGE, format!("bytes={}-", written));
+            }
 
-                // Ensure all data is flushed to disk
-                file.flush().await.map_err(|e| TfeError::Io {
-                    message: format!("Failed to flush file '{}': {}", output_path.display(), e),
-                })?;
+            let outcome = match self.send(request).await {
+                Ok(response) => {
+                    self.start_body(response, cv_id, part_path, &mut file, &mut written)
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            let error = match outcome {
+                Ok((response, total)) => {
+                    expected = total.or(expected);
+                    match stream_body(response, part_path, &mut file, &mut written).await {
+                        Ok(()) => break,
+                        Err(e) => e,
+                    }
+                }
+                Err(e) => e,
+            };
 
-                debug!("Downloaded {} bytes to {}", size, output_path.display());
-                Ok(size)
+            // Only transport errors are worth resuming; API and IO errors are final
+            if !matches!(error, TfeError::Http(_)) || attempt >= api::DOWNLOAD_MAX_RETRIES {
+                return Err(error);
+            }
+            attempt += 1;
+            let delay = api::DOWNLOAD_RETRY_BASE_MS * 2u64.pow(attempt - 1);
+            warn!(
+                "Download of configuration '{}' interrupted after {} bytes ({}), resuming in {}ms ({}/{})",
+                cv_id,
+                written,
+                error,
+                delay,
+                attempt,
+                api::DOWNLOAD_MAX_RETRIES
+            );
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+        }
+
+        file.flush().await.map_err(|e| TfeError::Io {
+            message: format!("Failed to flush file '{}': {}", part_path.display(), e),
+        })?;
+
+        if let Some(expected) = expected.filter(|&e| e != written) {
+            return Err(TfeError::Io {
+                message: format!(
+                    "Download of configuration '{}' is truncated: received {} of {} bytes",
+                    cv_id, written, expected
+                ),
+            });
+        }
+        Ok(written)
+    }
+
+    /// Check the status of a (possibly ranged) download response
+    ///
+    /// Returns the response with the total size it announces. A full `200`
+    /// answer to a ranged request restarts the part file from scratch.
+    async fn start_body(
+        &self,
+        response: reqwest::Response,
+        cv_id: &str,
+        part_path: &Path,
+        file: &mut File,
+        written: &mut u64,
+    ) -> Result<(reqwest::Response, Option<u64>)> {
+        match response.status().as_u16() {
+            200 => {
+                if *written > 0 {
+                    debug!("Server ignored Range request, restarting download");
+                    *file = create_file(part_path).await?;
+                    *written = 0;
+                }
+                let total = response.content_length();
+                Ok((response, total))
+            }
+            206 => {
+                let range = response
+                    .headers()
+                    .get(CONTENT_RANGE)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(parse_content_range);
+                match range {
+                    Some((start, total)) if start == *written => Ok((response, total)),
+                    _ => Err(TfeError::Api {
+                        status: 206,
+                        message: format!(
+                            "Cannot resume download of configuration '{}': unexpected Content-Range",
+                            cv_id
+                        ),
+                        details: Vec::new(),
+                    }),
+                }
             }
             204 => Err(TfeError::Api {
                 status: 204,
@@ -115,17 +227,20 @@ impl TfeClient {
                     "Configuration version '{}' has no downloadable content (empty or not uploaded)",
                     cv_id
                 ),
+                details: Vec::new(),
             }),
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Configuration version '{}' not found", cv_id),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!("Failed to download configuration '{}': {}", cv_id, body),
-                })
+                    format!("Failed to download configuration '{}'", cv_id),
+                    &body,
+                ))
             }
         }
     }
@@ -145,6 +260,42 @@ impl TfeClient {
     }
 }
 
+/// Temporary path the download is streamed into, next to the destination
+fn part_path(output_path: &Path) -> PathBuf {
+    let mut name = output_path.as_os_str().to_os_string();
+    name.push(".part");
+    PathBuf::from(name)
+}
+
+async fn create_file(path: &Path) -> Result<File> {
+    File::create(path).await.map_err(|e| TfeError::Io {
+        message: format!("Failed to create file '{}': {}", path.display(), e),
+    })
+}
+
+/// Append the response body to `file`, counting bytes into `written`
+async fn stream_body(
+    mut response: reqwest::Response,
+    path: &Path,
+    file: &mut File,
+    written: &mut u64,
+) -> Result<()> {
+    while let Some(chunk) = response.chunk().await? {
+        file.write_all(&chunk).await.map_err(|e| TfeError::Io {
+            message: format!("Failed to write to '{}': {}", path.display(), e),
+        })?;
+        *written += chunk.len() as u64;
+    }
+    Ok(())
+}
+
+/// Parse `bytes <start>-<end>/<total>` into the start offset and total size
+fn parse_content_range(value: &str) -> Option<(u64, Option<u64>)> {
+    let (range, total) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let start = range.split_once('-')?.0.trim().parse().ok()?;
+    Some((start, total.trim().parse().ok()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -329,6 +480,119 @@ mod tests {
         assert_eq!(downloaded, tar_content);
     }
 
+    /// Serve one canned raw HTTP response per connection, recording requests
+    ///
+    /// wiremock always sends complete bodies, so dropped connections need a
+    /// plain TCP server.
+    async fn raw_server(responses: Vec<Vec<u8>>) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+        use tokio::io::AsyncReadExt;
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let uri = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for response in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+                requests.push(String::from_utf8_lossy(&request).to_lowercase());
+                socket.write_all(&response).await.unwrap();
+                socket.shutdown().await.unwrap();
+            }
+            requests
+        });
+        (uri, handle)
+    }
+
+    fn raw_response(head: &str, body: &[u8]) -> Vec<u8> {
+        let mut response = format!("{}\r\nConnection: close\r\n\r\n", head).into_bytes();
+        response.extend_from_slice(body);
+        response
+    }
+
+    #[tokio::test]
+    async fn test_download_configuration_resumes_with_range() {
+        let content = b"0123456789abcdefghij";
+        let (uri, server) = raw_server(vec![
+            raw_response("HTTP/1.1 200 OK\r\nContent-Length: 20", &content[..8]),
+            raw_response(
+                "HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 8-19/20\r\nContent-Length: 12",
+                &content[8..],
+            ),
+        ])
+        .await;
+
+        let client = TfeClient::test_client(&uri);
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output_path = temp_dir.path().join("config.tar.gz");
+
+        let size = client
+            .download_configuration("cv-abc123", &output_path)
+            .await
+            .unwrap();
+
+        assert_eq!(size, 20);
+        assert_eq!(std::fs::read(&output_path).unwrap(), content);
+        assert!(!part_path(&output_path).exists());
+        let requests = server.await.unwrap();
+        assert!(!requests[0].contains("range:"));
+        assert!(requests[1].contains("range: bytes=8-"));
+    }
+
+    #[tokio::test]
+    async fn test_download_configuration_restarts_when_range_ignored() {
+        let content = b"0123456789";
+        let (uri, _server) = raw_server(vec![
+            raw_response("HTTP/1.1 200 OK\r\nContent-Length: 10", &content[..4]),
+            raw_response("HTTP/1.1 200 OK\r\nContent-Length: 10", content),
+        ])
+        .await;
+
+        let client = TfeClient::test_client(&uri);
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output_path = temp_dir.path().join("config.tar.gz");
+
+        let size = client
+            .download_configuration("cv-abc123", &output_path)
+            .await
+            .unwrap();
+
+        assert_eq!(size, 10);
+        assert_eq!(std::fs::read(&output_path).unwrap(), content);
+    }
+
+    #[tokio::test]
+    async fn test_download_configuration_gives_up_and_cleans_up() {
+        let truncated = raw_response("HTTP/1.1 200 OK\r\nContent-Length: 10", b"0123");
+        let attempts = api::DOWNLOAD_MAX_RETRIES as usize + 1;
+        let (uri, _server) = raw_server(vec![truncated; attempts]).await;
+
+        let client = TfeClient::test_client(&uri);
+        let temp_dir = tempfile::tempdir().unwrap();
+        let output_path = temp_dir.path().join("config.tar.gz");
+
+        let err = client
+            .download_configuration("cv-abc123", &output_path)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, TfeError::Http(_)), "{}", err);
+        assert!(!output_path.exists());
+        assert!(!part_path(&output_path).exists());
+    }
+
+    #[test]
+    fn test_parse_content_range() {
+        assert_eq!(parse_content_range("bytes 8-19/20"), Some((8, Some(20))));
+        assert_eq!(parse_content_range("bytes 0-9/*"), Some((0, None)));
+        assert_eq!(parse_content_range("items 0-9/10"), None);
+    }
+
     #[tokio::test]
     async fn test_download_configuration_no_content() {
         let mock_server = MockServer::start().await;
@@ -4,8 +4,9 @@ use std::path::PathBuf;
 
 use log::debug;
 
-use crate::cli::{Cli, Command, DownloadResource};
+use crate::cli::{Cli, Command, DownloadConfigArgs, DownloadResource};
 use crate::error::TfeError;
+use crate::hcp::explain::{call, print_explain, workspace_lookup, PlannedCall};
 use crate::hcp::workspaces::resolve_workspace;
 use crate::hcp::TfeClient;
 use crate::ui::{create_spinner, finish_spinner, finish_spinner_with_message};
@@ -22,10 +23,21 @@ pub async fn run_download_config_command(
         unreachable!()
     };
 
-    // Resolve workspace
     let effective_org = client.effective_org(args.org.as_ref());
-    let resolved =
-        resolve_workspace(client, &args.workspace, effective_org.as_deref(), cli.batch).await?;
+    if cli.explain {
+        print_explain(&plan_download_config(args, effective_org.as_ref()));
+        return Ok(());
+    }
+
+    // Resolve workspace
+    let resolved = resolve_workspace(
+        client,
+        &args.workspace,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
     let workspace_id = &resolved.workspace.id;
 
     debug!(
@@ -50,6 +62,7 @@ pub async fn run_download_config_command(
                     "Configuration version '{}' is not downloadable (status: {})",
                     cv_id, cv.attributes.status
                 ),
+                details: Vec::new(),
             }));
         }
         cv
@@ -65,16 +78,19 @@ pub async fn run_download_config_command(
                     "No downloadable configuration version found for workspace '{}'",
                     resolved.workspace.attributes.name
                 ),
+                details: Vec::new(),
             })?;
         finish_spinner(sp);
         cv
     };
 
     // Determine output path
-    let output_path = args
-        .output
-        .clone()
-        .unwrap_or_else(|| PathBuf::from(format!("configuration-{}.tar.gz", cv.id)));
+    let output_path = resolve_output_path(args.output.as_ref(), args.output_dir.as_ref(), &cv.id);
+    if let Some(dir) = &args.output_dir {
+        std::fs::create_dir_all(dir).map_err(|e| TfeError::Io {
+            message: format!("Failed to create directory '{}': {}", dir.display(), e),
+        })?;
+    }
 
     // Download configuration
     let sp = create_spinner(
@@ -109,6 +125,45 @@ pub async fn run_download_config_command(
     Ok(())
 }
 
+/// Output file for the archive: --output (inside --output-dir when relative)
+/// or configuration-{cv_id}.tar.gz
+fn resolve_output_path(
+    output: Option<&PathBuf>,
+    output_dir: Option<&PathBuf>,
+    cv_id: &str,
+) -> PathBuf {
+    let file = output
+        .cloned()
+        .unwrap_or_else(|| PathBuf::from(format!("configuration-{}.tar.gz", cv_id)));
+    match output_dir {
+        Some(dir) => dir.join(file),
+        None => file,
+    }
+}
+
+/// API calls made by `download config` for the effective organization
+fn plan_download_config(args: &DownloadConfigArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    let mut calls = workspace_lookup(&args.workspace, org);
+    calls.push(match &args.cv_id {
+        Some(cv_id) => call(
+            "GET",
+            format!("/configuration-versions/{}", cv_id),
+            "check it is downloadable",
+        ),
+        None => call(
+            "GET",
+            "/workspaces/:ws_id/configuration-versions",
+            "find latest configuration version",
+        ),
+    });
+    calls.push(call(
+        "GET",
+        "/configuration-versions/:cv_id/download",
+        "download tarball",
+    ));
+    calls
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,4 +180,26 @@ mod tests {
         let custom = PathBuf::from("/tmp/my-config.tar.gz");
         assert_eq!(custom.to_str().unwrap(), "/tmp/my-config.tar.gz");
     }
+
+    #[test]
+    fn test_resolve_output_path() {
+        let dir = PathBuf::from("/tmp/configs");
+        assert_eq!(
+            resolve_output_path(None, None, "cv-1"),
+            PathBuf::from("configuration-cv-1.tar.gz")
+        );
+        assert_eq!(
+            resolve_output_path(None, Some(&dir), "cv-1"),
+            PathBuf::from("/tmp/configs/configuration-cv-1.tar.gz")
+        );
+        assert_eq!(
+            resolve_output_path(Some(&PathBuf::from("app.tgz")), Some(&dir), "cv-1"),
+            PathBuf::from("/tmp/configs/app.tgz")
+        );
+        // An absolute --output wins over --output-dir
+        assert_eq!(
+            resolve_output_path(Some(&PathBuf::from("/abs/app.tgz")), Some(&dir), "cv-1"),
+            PathBuf::from("/abs/app.tgz")
+        );
+    }
 }
@@ -3,7 +3,9 @@
 use log::debug;
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::fmt;
 use std::fs;
+use std::path::PathBuf;
 
 use crate::config::credentials;
 use crate::error::{Result, TfeError};
@@ -20,6 +22,62 @@ struct TfeCredential {
     token: String,
 }
 
+/// Where the API token was found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenSource {
+    /// `--token` argument
+    CliArg,
+    /// Environment variable (e.g. `HCP_TOKEN`)
+    EnvVar(String),
+    /// Token stored in the active context
+    Context,
+    /// Terraform credentials file (`terraform login`)
+    CredentialsFile(PathBuf),
+}
+
+impl fmt::Display for TokenSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenSource::CliArg => write!(f, "--token argument"),
+            TokenSource::EnvVar(name) => write!(f, "{} environment variable", name),
+            TokenSource::Context => write!(f, "active context"),
+            TokenSource::CredentialsFile(path) => {
+                write!(f, "credentials file {}", path.display())
+            }
+        }
+    }
+}
+
+/// A resolved API token and the source that provided it
+///
+/// `Debug` output shows the source only, never the token.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ResolvedToken {
+    pub value: String,
+    pub source: TokenSource,
+}
+
+impl fmt::Debug for ResolvedToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResolvedToken")
+            .field("value", &crate::hcp::redact::REDACTED)
+            .field("source", &self.source)
+            .finish()
+    }
+}
+
+impl ResolvedToken {
+    /// The raw token string
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Take the raw token string (e.g. to build a client)
+    pub fn into_value(self) -> String {
+        self.value
+    }
+}
+
 /// Token resolution with fallback logic
 pub struct TokenResolver {
     host: String,
@@ -38,25 +96,57 @@ impl TokenResolver {
     /// 2. Environment variables (HCP_TOKEN, TFC_TOKEN, TFE_TOKEN - in order)
     /// 3. Active context token
     /// 4. Credentials file (~/.terraform.d/credentials.tfrc.json)
-    pub fn resolve(&self, cli_token: Option<&str>, context_token: Option<&str>) -> Result<String> {
+    ///
+    /// The returned [`ResolvedToken`] records which source won.
+    pub fn resolve(
+        &self,
+        cli_token: Option<&str>,
+        context_token: Option<&str>,
+    ) -> Result<ResolvedToken> {
+        self.resolve_with(
+            cli_token,
+            context_token,
+            |name| std::env::var(name).ok(),
+            Self::get_credentials_path(),
+        )
+    }
+
+    /// [`Self::resolve`] with the environment lookup and credentials file
+    /// location injected (for tests)
+    fn resolve_with(
+        &self,
+        cli_token: Option<&str>,
+        context_token: Option<&str>,
+        env: impl Fn(&str) -> Option<String>,
+        credentials_path: Option<PathBuf>,
+    ) -> Result<ResolvedToken> {
         // 1. CLI argument takes precedence
         if let Some(token) = cli_token {
             debug!("Using token from CLI argument");
-            return Ok(token.to_string());
+            return Ok(ResolvedToken {
+                value: token.to_string(),
+                source: TokenSource::CliArg,
+            });
         }
 
         // 2. Environment variables (try in order)
         for env_var in credentials::TOKEN_ENV_VARS {
-            if let Ok(token) = std::env::var(env_var) {
+            if let Some(token) = env(env_var) {
                 debug!("Using token from {} environment variable", env_var);
-                return Ok(token);
+                return Ok(ResolvedToken {
+                    value: token,
+                    source: TokenSource::EnvVar(env_var.to_string()),
+                });
             }
         }
 
         // 3. Context token
         if let Some(token) = context_token {
             debug!("Using token from active context");
-            return Ok(token.to_string());
+            return Ok(ResolvedToken {
+                value: token.to_string(),
+                source: TokenSource::Context,
+            });
         }
 
         // 4. Credentials file
@@ -64,12 +154,15 @@ impl TokenResolver {
             "No token found in environment variables {:?} or context, trying credentials file",
             credentials::TOKEN_ENV_VARS
         );
-        self.read_from_credentials_file()
+        self.read_from_credentials_file(credentials_path)
     }
 
     /// Read token from Terraform credentials file
-    fn read_from_credentials_file(&self) -> Result<String> {
-        let credentials_path = Self::get_credentials_path()
+    fn read_from_credentials_file(
+        &self,
+        credentials_path: Option<PathBuf>,
+    ) -> Result<ResolvedToken> {
+        let credentials_path = credentials_path
             .ok_or_else(|| TfeError::TokenNotFound(self.token_not_found_message(None)))?;
 
         debug!(
@@ -103,7 +196,10 @@ impl TokenResolver {
                     credentials_path.display(),
                     self.host
                 );
-                cred.token.clone()
+                ResolvedToken {
+                    value: cred.token.clone(),
+                    source: TokenSource::CredentialsFile(credentials_path.clone()),
+                }
             })
             .ok_or_else(|| {
                 TfeError::TokenNotFound(self.token_not_found_message(Some(&credentials_path)))
@@ -151,12 +247,114 @@ impl TokenResolver {
 mod tests {
     use super::*;
 
+    /// Environment lookup backed by a fixed list of variables
+    fn env_of(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: Vec<(String, String)> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone())
+    }
+
+    /// Credentials file in a temp dir holding a token for `host`
+    fn credentials_file(dir: &tempfile::TempDir, host: &str, token: &str) -> PathBuf {
+        let path = dir.path().join("credentials.tfrc.json");
+        let json = serde_json::json!({"credentials": {host: {"token": token}}});
+        fs::write(&path, json.to_string()).unwrap();
+        path
+    }
+
     #[test]
     fn test_resolver_cli_token_takes_precedence() {
         let resolver = TokenResolver::new("test.example.com");
         let result = resolver.resolve(Some("cli-token-123"), None);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "cli-token-123");
+        assert_eq!(result.unwrap().as_str(), "cli-token-123");
+    }
+
+    #[test]
+    fn test_resolve_cli_arg_beats_every_other_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let resolver = TokenResolver::new("tfe.example.com");
+        let resolved = resolver
+            .resolve_with(
+                Some("cli-token"),
+                Some("ctx-token"),
+                env_of(&[("HCP_TOKEN", "env-token")]),
+                Some(credentials_file(&dir, "tfe.example.com", "file-token")),
+            )
+            .unwrap();
+        assert_eq!(resolved.as_str(), "cli-token");
+        assert_eq!(resolved.source, TokenSource::CliArg);
+        assert!(!format!("{:?}", resolved).contains("cli-token"));
+    }
+
+    #[test]
+    fn test_resolve_env_var_in_order_before_context() {
+        let resolver = TokenResolver::new("tfe.example.com");
+        let resolved = resolver
+            .resolve_with(
+                None,
+                Some("ctx-token"),
+                env_of(&[("TFE_TOKEN", "tfe-token"), ("TFC_TOKEN", "tfc-token")]),
+                None,
+            )
+            .unwrap();
+        assert_eq!(resolved.as_str(), "tfc-token");
+        assert_eq!(
+            resolved.source,
+            TokenSource::EnvVar("TFC_TOKEN".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_context_before_credentials_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let resolver = TokenResolver::new("tfe.example.com");
+        let resolved = resolver
+            .resolve_with(
+                None,
+                Some("ctx-token"),
+                env_of(&[]),
+                Some(credentials_file(&dir, "tfe.example.com", "file-token")),
+            )
+            .unwrap();
+        assert_eq!(resolved.as_str(), "ctx-token");
+        assert_eq!(resolved.source, TokenSource::Context);
+    }
+
+    #[test]
+    fn test_resolve_credentials_file_for_host() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = credentials_file(&dir, "tfe.example.com", "file-token");
+        let resolver = TokenResolver::new("tfe.example.com");
+        let resolved = resolver
+            .resolve_with(None, None, env_of(&[]), Some(path.clone()))
+            .unwrap();
+        assert_eq!(resolved.source, TokenSource::CredentialsFile(path.clone()));
+        assert_eq!(resolved.into_value(), "file-token");
+
+        // No entry for this host: nothing found
+        let other = TokenResolver::new("other.example.com");
+        let err = other
+            .resolve_with(None, None, env_of(&[]), Some(path))
+            .unwrap_err();
+        assert!(matches!(err, TfeError::TokenNotFound(_)));
+    }
+
+    #[test]
+    fn test_token_source_display() {
+        assert_eq!(TokenSource::CliArg.to_string(), "--token argument");
+        assert_eq!(
+            TokenSource::EnvVar("TFE_TOKEN".to_string()).to_string(),
+            "TFE_TOKEN environment variable"
+        );
+        assert_eq!(TokenSource::Context.to_string(), "active context");
+        assert_eq!(
+            TokenSource::CredentialsFile(PathBuf::from("/home/u/.terraform.d/creds.json"))
+                .to_string(),
+            "credentials file /home/u/.terraform.d/creds.json"
+        );
     }
 
     #[test]
@@ -0,0 +1,294 @@
+//! Opt-in on-disk cache for GET responses (`--cache`)
+//!
+//! Each cached response is one JSON file under `~/.hcpctl/cache/`, named by a
+//! SHA-256 digest of the API token and the full request URL, so different
+//! tokens never share entries and the token itself is never written to disk.
+//! Files are created readable by the owner only, since they hold
+//! authenticated API responses. Entries older than the TTL are not served
+//! directly, but their `ETag` is kept so the next request can be revalidated
+//! with `If-None-Match` and a `304` reuses the stored body. Any successful
+//! mutation clears the whole cache, since a single change can affect many list
+//! endpoints.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use log::debug;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::cli::CacheAction;
+use crate::config::{cache as cache_config, context as context_config};
+
+/// One cached GET response
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    stored_at: u64, // Unix timestamp
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    etag: Option<String>,
+    body: serde_json::Value,
+}
+
+/// Disk-backed response cache scoped to one API token
+#[derive(Debug)]
+pub(crate) struct DiskCache {
+    dir: PathBuf,
+    ttl: Duration,
+    token_hash: String,
+}
+
+impl DiskCache {
+    /// Create a cache in `dir` for responses fetched with `token`
+    pub fn new(dir: PathBuf, ttl: Duration, token: &str) -> Self {
+        Self {
+            dir,
+            ttl,
+            token_hash: sha256_hex(token.as_bytes()),
+        }
+    }
+
+    /// File holding the entry for a URL
+    fn entry_path(&self, url: &str) -> PathBuf {
+        let key = format!("{}\n{}", self.token_hash, url);
+        self.dir
+            .join(format!("{}.json", sha256_hex(key.as_bytes())))
+    }
+
+    /// Entry for a URL regardless of age
+    fn load(&self, url: &str) -> Option<CacheEntry> {
+        let content = fs::read_to_string(self.entry_path(url)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        (entry.url == url).then_some(entry)
+    }
+
+    /// Cached body for a URL, if present and not expired
+    pub fn get(&self, url: &str) -> Option<Vec<u8>> {
+        let entry = self.load(url)?;
+        let age = unix_now().saturating_sub(entry.stored_at);
+        if age >= self.ttl.as_secs() {
+            debug!("Disk cache entry expired ({}s old): {}", age, url);
+            return None;
+        }
+        serde_json::to_vec(&entry.body).ok()
+    }
+
+    /// ETag and body of an entry (expired or not) that can be revalidated
+    pub fn get_revalidatable(&self, url: &str) -> Option<(String, Vec<u8>)> {
+        let entry = self.load(url)?;
+        let etag = entry.etag?;
+        Some((etag, serde_json::to_vec(&entry.body).ok()?))
+    }
+
+    /// Store a response body with its ETag; non-JSON bodies (e.g. downloads)
+    /// are skipped. Storing again after a `304` restarts the TTL.
+    pub fn store(&self, url: &str, body: &[u8], etag: Option<&str>) {
+        let Ok(body) = serde_json::from_slice::<serde_json::Value>(body) else {
+            return;
+        };
+        let entry = CacheEntry {
+            url: url.to_string(),
+            stored_at: unix_now(),
+            etag: etag.map(String::from),
+            body,
+        };
+        if fs::create_dir_all(&self.dir).is_err() {
+            return;
+        }
+        if let Ok(content) = serde_json::to_string(&entry) {
+            let _ = write_private(&self.entry_path(url), content.as_bytes());
+        }
+    }
+
+    /// Drop all cached entries (after a mutation)
+    pub fn clear(&self) {
+        let removed = clear_cache_dir(&self.dir);
+        debug!("Cleared {} disk cache entries after mutation", removed);
+    }
+}
+
+/// Lowercase hex SHA-256 digest (stable across toolchains, unlike `DefaultHasher`)
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Write `content` to `path`, creating the file with mode 0600 on Unix
+///
+/// An existing file is removed first so a wider mode left by an older version
+/// is never reused.
+fn write_private(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let _ = fs::remove_file(path);
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(content)
+}
+
+/// Current Unix timestamp in seconds
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Default cache directory (`~/.hcpctl/cache`)
+pub(crate) fn default_cache_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(context_config::DIR_NAME)
+        .join(cache_config::DIR_NAME)
+}
+
+/// Remove all cache entries in `dir`, returning how many were removed
+fn clear_cache_dir(dir: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .filter(|p| fs::remove_file(p).is_ok())
+        .count()
+}
+
+/// Run a `cache` subcommand
+pub fn run_cache_command(action: &CacheAction) -> Result<(), Box<dyn std::error::Error>> {
+    match action {
+        CacheAction::Clear => {
+            let dir = default_cache_dir();
+            let removed = clear_cache_dir(&dir);
+            println!(
+                "Removed {} cached response(s) from {}",
+                removed,
+                dir.display()
+            );
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_in(dir: &Path, ttl_secs: u64, token: &str) -> DiskCache {
+        DiskCache::new(dir.to_path_buf(), Duration::from_secs(ttl_secs), token)
+    }
+
+    #[test]
+    fn test_store_and_get() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache_in(dir.path(), 60, "token");
+        cache.store("https://tfe/api/v2/organizations", br#"{"data":[]}"#, None);
+
+        let body = cache.get("https://tfe/api/v2/organizations").unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            serde_json::json!({"data": []})
+        );
+        assert!(cache.get("https://tfe/api/v2/projects").is_none());
+    }
+
+    #[test]
+    fn test_expired_entry_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache_in(dir.path(), 0, "token");
+        cache.store("https://tfe/a", b"{}", None);
+        assert!(cache.get("https://tfe/a").is_none());
+    }
+
+    #[test]
+    fn test_entries_scoped_by_token() {
+        let dir = tempfile::tempdir().unwrap();
+        cache_in(dir.path(), 60, "token-a").store("https://tfe/a", b"{}", None);
+        assert!(cache_in(dir.path(), 60, "token-b")
+            .get("https://tfe/a")
+            .is_none());
+        assert!(cache_in(dir.path(), 60, "token-a")
+            .get("https://tfe/a")
+            .is_some());
+    }
+
+    #[test]
+    fn test_token_not_written_to_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        cache_in(dir.path(), 60, "secret-token-value").store("https://tfe/a", b"{}", None);
+        for entry in fs::read_dir(dir.path()).unwrap() {
+            let content = fs::read_to_string(entry.unwrap().path()).unwrap();
+            assert!(!content.contains("secret-token-value"));
+        }
+    }
+
+    #[test]
+    fn test_entry_name_is_stable_digest() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache_in(dir.path(), 60, "token");
+        let name = cache.entry_path("https://tfe/a");
+        let name = name.file_name().unwrap().to_str().unwrap();
+        let expected = sha256_hex(format!("{}\nhttps://tfe/a", sha256_hex(b"token")).as_bytes());
+        assert_eq!(name, format!("{}.json", expected));
+        assert_eq!(expected.len(), 64);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_entries_written_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache_in(dir.path(), 60, "token");
+        let path = cache.entry_path("https://tfe/a");
+        fs::write(&path, "{}").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+
+        cache.store("https://tfe/a", b"{}", None);
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(cache.get("https://tfe/a").is_some());
+    }
+
+    #[test]
+    fn test_non_json_body_not_stored() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache_in(dir.path(), 60, "token");
+        cache.store("https://tfe/download", &[0x1f, 0x8b, 0x08], None);
+        assert!(cache.get("https://tfe/download").is_none());
+    }
+
+    #[test]
+    fn test_expired_entry_revalidatable_by_etag() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache_in(dir.path(), 0, "token");
+        cache.store("https://tfe/a", br#"{"v":1}"#, Some("\"e1\""));
+        cache.store("https://tfe/b", b"{}", None);
+
+        assert!(cache.get("https://tfe/a").is_none());
+        let (etag, body) = cache.get_revalidatable("https://tfe/a").unwrap();
+        assert_eq!(etag, "\"e1\"");
+        assert_eq!(body, br#"{"v":1}"#);
+        assert!(cache.get_revalidatable("https://tfe/b").is_none());
+    }
+
+    #[test]
+    fn test_clear_removes_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache_in(dir.path(), 60, "token");
+        cache.store("https://tfe/a", b"{}", None);
+        cache.store("https://tfe/b", b"[]", None);
+
+        assert_eq!(clear_cache_dir(dir.path()), 2);
+        assert!(cache.get("https://tfe/a").is_none());
+        assert_eq!(clear_cache_dir(&dir.path().join("missing")), 0);
+    }
+}
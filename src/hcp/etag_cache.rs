@@ -0,0 +1,90 @@
+//! In-memory ETag cache for conditional GET requests
+//!
+//! Stores the ETag and body of successful GET responses per URL so repeated
+//! requests (e.g. while polling) can be revalidated with `If-None-Match`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use reqwest::header::HeaderMap;
+
+/// Cached response for a single URL
+#[derive(Debug, Clone)]
+pub(crate) struct CachedResponse {
+    pub etag: String,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// Thread-safe ETag cache keyed by request URL
+#[derive(Debug, Default)]
+pub(crate) struct ETagCache {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+}
+
+impl ETagCache {
+    /// Get the cached ETag for a URL, if any
+    pub fn etag_for(&self, url: &str) -> Option<String> {
+        self.entries
+            .lock()
+            .ok()?
+            .get(url)
+            .map(|entry| entry.etag.clone())
+    }
+
+    /// Get the cached response for a URL, if any
+    pub fn get(&self, url: &str) -> Option<CachedResponse> {
+        self.entries.lock().ok()?.get(url).cloned()
+    }
+
+    /// Store (or replace) the cached response for a URL
+    pub fn store(&self, url: &str, response: CachedResponse) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(url.to_string(), response);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(etag: &str, body: &str) -> CachedResponse {
+        CachedResponse {
+            etag: etag.to_string(),
+            headers: HeaderMap::new(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_empty_cache() {
+        let cache = ETagCache::default();
+        assert!(cache.etag_for("https://example.com/a").is_none());
+        assert!(cache.get("https://example.com/a").is_none());
+    }
+
+    #[test]
+    fn test_store_and_get() {
+        let cache = ETagCache::default();
+        cache.store("https://example.com/a", entry("\"v1\"", "{}"));
+
+        assert_eq!(
+            cache.etag_for("https://example.com/a"),
+            Some("\"v1\"".to_string())
+        );
+        assert_eq!(cache.get("https://example.com/a").unwrap().body, b"{}");
+        assert!(cache.etag_for("https://example.com/b").is_none());
+    }
+
+    #[test]
+    fn test_store_replaces_entry() {
+        let cache = ETagCache::default();
+        cache.store("https://example.com/a", entry("\"v1\"", "old"));
+        cache.store("https://example.com/a", entry("\"v2\"", "new"));
+
+        let cached = cache.get("https://example.com/a").unwrap();
+        assert_eq!(cached.etag, "\"v2\"");
+        assert_eq!(cached.body, b"new");
+    }
+}
@@ -0,0 +1,153 @@
+//! `--explain`: describe the API calls a command would make, without executing them
+//!
+//! Each command handler builds its plan next to the code making the calls, once
+//! the effective organization (`--org`, context org, `--all-orgs`) is known, and
+//! prints it with [`print_explain`] instead of running.
+//!
+//! Plans are path templates (`:org`, `:ws_id`, ...) filled in with whatever the
+//! command line already pins down. Per-item calls (one per workspace, run, ...)
+//! are listed once and marked as such, since their count is only known at runtime.
+
+/// A single planned API call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedCall {
+    pub method: &'static str,
+    pub path: String,
+    pub note: String,
+}
+
+pub(crate) fn call(
+    method: &'static str,
+    path: impl Into<String>,
+    note: impl Into<String>,
+) -> PlannedCall {
+    PlannedCall {
+        method,
+        path: path.into(),
+        note: note.into(),
+    }
+}
+
+/// Organization value for a path, or a placeholder
+pub(crate) fn org_path(org: Option<&String>) -> String {
+    org.cloned().unwrap_or_else(|| ":org".to_string())
+}
+
+/// Calls needed to determine which organizations a command covers
+pub(crate) fn org_scope(org: Option<&String>) -> Vec<PlannedCall> {
+    match org {
+        Some(_) => vec![],
+        None => vec![call(
+            "GET",
+            "/organizations",
+            "list organizations; each is then queried in parallel",
+        )],
+    }
+}
+
+/// Calls listing an organization collection (e.g. `teams`), across all
+/// organizations when none is given
+pub(crate) fn org_listing(org: Option<&String>, collection: &str) -> Vec<PlannedCall> {
+    let mut calls = org_scope(org);
+    calls.push(call(
+        "GET",
+        format!("/organizations/{}/{}", org_path(org), collection),
+        "paginated",
+    ));
+    calls
+}
+
+/// Calls needed to resolve a project given by name or ID in `org`
+pub(crate) fn project_lookup(target: &str, org: &str) -> Vec<PlannedCall> {
+    if target.starts_with("prj-") {
+        return vec![call("GET", format!("/projects/{}", target), "project")];
+    }
+    vec![
+        call(
+            "GET",
+            format!("/organizations/{}/projects", org),
+            "find project by name",
+        ),
+        call("GET", "/projects/:prj_id", "project"),
+    ]
+}
+
+/// Calls needed to resolve a workspace given by name or ID
+pub(crate) fn workspace_lookup(target: &str, org: Option<&String>) -> Vec<PlannedCall> {
+    if target.starts_with("ws-") {
+        vec![call("GET", format!("/workspaces/{}", target), "workspace")]
+    } else {
+        let mut calls = org_scope(org);
+        calls.push(call(
+            "GET",
+            format!("/organizations/{}/workspaces/{}", org_path(org), target),
+            "look up workspace by name",
+        ));
+        calls
+    }
+}
+
+/// Print the call plan for a command instead of executing it
+///
+/// The plan goes to the output sink, so `--output-file` captures it.
+pub fn print_explain(calls: &[PlannedCall]) {
+    crate::output::print_text(&render_explain(calls));
+}
+
+/// Render the call plan as printed by [`print_explain`]
+fn render_explain(calls: &[PlannedCall]) -> String {
+    if calls.is_empty() {
+        return "This command makes no TFE API calls.\n".to_string();
+    }
+
+    let mut text = String::from("Planned API calls (not executed):\n");
+    let width = calls.iter().map(|c| c.path.len()).max().unwrap_or(0);
+    for c in calls {
+        text.push_str(&format!(
+            "  {:<6} {:<width$}  # {}\n",
+            c.method,
+            c.path,
+            c.note,
+            width = width
+        ));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_org_scope_only_without_org() {
+        assert!(org_scope(Some(&"acme".to_string())).is_empty());
+        assert_eq!(org_scope(None)[0].path, "/organizations");
+    }
+
+    #[test]
+    fn test_workspace_lookup_by_name_uses_org() {
+        let org = "acme".to_string();
+        let calls = workspace_lookup("app", Some(&org));
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].path, "/organizations/acme/workspaces/app");
+
+        let calls = workspace_lookup("ws-abc", None);
+        assert_eq!(calls[0].path, "/workspaces/ws-abc");
+    }
+
+    #[test]
+    fn test_render_explain() {
+        assert_eq!(
+            render_explain(&[]),
+            "This command makes no TFE API calls.\n"
+        );
+        let calls = [
+            call("GET", "/organizations", "list"),
+            call("GET", "/runs/:id", "run"),
+        ];
+        assert_eq!(
+            render_explain(&calls),
+            "Planned API calls (not executed):\n  GET    /organizations  # list\n  GET    /runs/:id       # run\n"
+        );
+    }
+}
@@ -2,9 +2,7 @@
 //!
 //! These utilities support fetching data across multiple organizations in parallel.
 
-use futures::future::join_all;
-use futures::stream::{FuturesUnordered, StreamExt};
-use indicatif::ProgressBar;
+use futures::stream::{self, FuturesUnordered, StreamExt};
 use std::future::Future;
 
 use crate::TfeError;
@@ -13,49 +11,123 @@ use crate::TfeError;
 ///
 /// The `fetcher` function is called for each organization and should return
 /// either the fetched data or an error tuple containing the org name and error.
+/// At most `concurrency` organizations are in flight at once (`--concurrency`);
+/// results are returned in the order of `organizations`.
 pub async fn fetch_from_organizations<T, F, Fut>(
     organizations: Vec<String>,
+    concurrency: usize,
     fetcher: F,
 ) -> Vec<Result<T, (String, TfeError)>>
 where
     F: Fn(String) -> Fut,
     Fut: Future<Output = Result<T, (String, TfeError)>>,
 {
-    let futures = organizations.into_iter().map(fetcher);
-    join_all(futures).await
+    let fetcher = &fetcher;
+    let mut results: Vec<_> = stream::iter(
+        organizations
+            .into_iter()
+            .enumerate()
+            .map(|(i, org)| async move { (i, fetcher(org).await) }),
+    )
+    .buffer_unordered(concurrency.max(1))
+    .collect()
+    .await;
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Per-organization failures collected during a multi-org fan-out
+///
+/// Failed organizations don't abort the fan-out; successful data is still
+/// returned and the failures are reported once at the end (or, with
+/// `--strict`, turned into a command failure).
+#[derive(Debug, Default)]
+pub struct OrgErrors {
+    /// Number of organizations queried
+    total: usize,
+    /// (org name, error message) for each failed organization
+    failures: Vec<(String, String)>,
+}
+
+impl OrgErrors {
+    /// Create an empty report for a fan-out across `total` organizations
+    pub fn new(total: usize) -> Self {
+        Self {
+            total,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Record a failure for an organization
+    pub fn push(&mut self, org: String, error: impl std::fmt::Display) {
+        self.failures.push((org, error.to_string()));
+    }
+
+    /// Check if any organization failed
+    pub fn has_errors(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    /// Failed organizations with their error messages
+    pub fn failures(&self) -> &[(String, String)] {
+        &self.failures
+    }
+
+    /// Consolidated report, or None if every organization succeeded
+    pub fn report(&self, resource_name: &str) -> Option<String> {
+        if !self.has_errors() {
+            return None;
+        }
+        let mut msg = format!(
+            "{} of {} organization(s) failed while fetching {}:",
+            self.failures.len(),
+            self.total,
+            resource_name
+        );
+        for (org, error) in &self.failures {
+            msg.push_str(&format!("\n  - {}: {}", org, error));
+        }
+        Some(msg)
+    }
+
+    /// Print the consolidated report to stderr (no-op without errors)
+    pub fn print_report(&self, resource_name: &str) {
+        if let Some(report) = self.report(resource_name) {
+            eprintln!("\n{}\nResults above are partial.", report);
+        }
+    }
+
+    /// Fail with the consolidated report when `strict` is set and any org failed
+    pub fn check_strict(&self, strict: bool, resource_name: &str) -> Result<(), String> {
+        match self.report(resource_name) {
+            Some(report) if strict => Err(format!("{}\nFailing because --strict is set", report)),
+            _ => Ok(()),
+        }
+    }
 }
 
 /// Collect results from parallel organization fetches
 ///
-/// Returns a tuple of (successes, had_errors). Errors are printed to stderr,
-/// respecting spinner suspension if a spinner is active.
+/// Returns the successful results together with the per-org failures, so
+/// callers can print a single consolidated report once output is done.
 pub fn collect_org_results<T>(
     results: Vec<Result<T, (String, TfeError)>>,
-    spinner: &Option<ProgressBar>,
     resource_name: &str,
-) -> (Vec<T>, bool) {
+) -> (Vec<T>, OrgErrors) {
     let mut successes = Vec::new();
-    let mut had_errors = false;
+    let mut errors = OrgErrors::new(results.len());
 
     for result in results {
         match result {
             Ok(data) => successes.push(data),
             Err((org, e)) => {
-                had_errors = true;
-                let msg = format!(
-                    "Error fetching {} for org '{}':\n  {}\n",
-                    resource_name, org, e
-                );
-                if let Some(ref s) = spinner {
-                    s.suspend(|| eprintln!("{}", msg));
-                } else {
-                    eprintln!("{}", msg);
-                }
+                log::debug!("Error fetching {} for org '{}': {}", resource_name, org, e);
+                errors.push(org, e);
             }
         }
     }
 
-    (successes, had_errors)
+    (successes, errors)
 }
 
 /// Log completion status to info log
@@ -154,9 +226,9 @@ mod tests {
     #[test]
     fn test_collect_org_results_all_success() {
         let results: Vec<Result<i32, (String, TfeError)>> = vec![Ok(1), Ok(2), Ok(3)];
-        let (successes, had_errors) = collect_org_results(results, &None, "items");
+        let (successes, errors) = collect_org_results(results, "items");
         assert_eq!(successes, vec![1, 2, 3]);
-        assert!(!had_errors);
+        assert!(!errors.has_errors());
     }
 
     #[test]
@@ -166,17 +238,19 @@ mod tests {
             Err(("org1".to_string(), TfeError::Config("test".to_string()))),
             Ok(3),
         ];
-        let (successes, had_errors) = collect_org_results(results, &None, "items");
+        let (successes, errors) = collect_org_results(results, "items");
         assert_eq!(successes, vec![1, 3]);
-        assert!(had_errors);
+        assert!(errors.has_errors());
+        assert_eq!(errors.failures().len(), 1);
+        assert_eq!(errors.failures()[0].0, "org1");
     }
 
     #[test]
     fn test_collect_org_results_empty() {
         let results: Vec<Result<i32, (String, TfeError)>> = vec![];
-        let (successes, had_errors) = collect_org_results(results, &None, "workspaces");
+        let (successes, errors) = collect_org_results(results, "workspaces");
         assert!(successes.is_empty());
-        assert!(!had_errors);
+        assert!(!errors.has_errors());
     }
 
     #[test]
@@ -185,9 +259,10 @@ mod tests {
             Err(("org1".to_string(), TfeError::Config("error1".to_string()))),
             Err(("org2".to_string(), TfeError::Config("error2".to_string()))),
         ];
-        let (successes, had_errors) = collect_org_results(results, &None, "projects");
+        let (successes, errors) = collect_org_results(results, "projects");
         assert!(successes.is_empty());
-        assert!(had_errors);
+        assert!(errors.has_errors());
+        assert_eq!(errors.failures().len(), 2);
     }
 
     #[test]
@@ -197,17 +272,44 @@ mod tests {
             Ok(("org1".to_string(), vec![1, 2])),
             Ok(("org2".to_string(), vec![3, 4, 5])),
         ];
-        let (successes, had_errors) = collect_org_results(results, &None, "data");
+        let (successes, errors) = collect_org_results(results, "data");
         assert_eq!(successes.len(), 2);
         assert_eq!(successes[0].1.len(), 2);
         assert_eq!(successes[1].1.len(), 3);
-        assert!(!had_errors);
+        assert!(!errors.has_errors());
+    }
+
+    #[test]
+    fn test_org_errors_report() {
+        let mut errors = OrgErrors::new(5);
+        assert!(errors.report("workspaces").is_none());
+
+        errors.push("org-a".to_string(), "API error (status 500)");
+        errors.push("org-b".to_string(), "timeout");
+
+        let report = errors.report("workspaces").unwrap();
+        assert!(report.starts_with("2 of 5 organization(s) failed while fetching workspaces:"));
+        assert!(report.contains("  - org-a: API error (status 500)"));
+        assert!(report.contains("  - org-b: timeout"));
+    }
+
+    #[test]
+    fn test_org_errors_check_strict() {
+        let mut errors = OrgErrors::new(2);
+        assert!(errors.check_strict(true, "projects").is_ok());
+
+        errors.push("org-a".to_string(), "boom");
+        assert!(errors.check_strict(false, "projects").is_ok());
+
+        let err = errors.check_strict(true, "projects").unwrap_err();
+        assert!(err.contains("org-a: boom"));
+        assert!(err.contains("--strict"));
     }
 
     #[tokio::test]
     async fn test_fetch_from_organizations() {
         let orgs = vec!["org1".to_string(), "org2".to_string()];
-        let results = fetch_from_organizations(orgs, |org| async move {
+        let results = fetch_from_organizations(orgs, 2, |org| async move {
             Ok::<_, (String, TfeError)>(format!("result-{}", org))
         })
         .await;
@@ -222,7 +324,7 @@ mod tests {
     #[tokio::test]
     async fn test_fetch_from_organizations_with_error() {
         let orgs = vec!["org1".to_string(), "fail".to_string()];
-        let results = fetch_from_organizations(orgs, |org| async move {
+        let results = fetch_from_organizations(orgs, 2, |org| async move {
             if org == "fail" {
                 Err((org, TfeError::Config("simulated error".to_string())))
             } else {
@@ -236,12 +338,41 @@ mod tests {
         assert!(results[1].is_err());
     }
 
+    #[tokio::test]
+    async fn test_fetch_from_organizations_bounded_and_ordered() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let orgs: Vec<String> = (0..8).map(|i| format!("org{}", i)).collect();
+        let results = fetch_from_organizations(orgs, 3, |org| {
+            let (in_flight, peak) = (&in_flight, &peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok::<_, (String, TfeError)>(org)
+            }
+        })
+        .await;
+
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        let names: Vec<_> = results.into_iter().map(|r| r.unwrap()).collect();
+        let expected: Vec<_> = (0..8).map(|i| format!("org{}", i)).collect();
+        assert_eq!(names, expected);
+    }
+
     #[tokio::test]
     async fn test_fetch_from_organizations_empty() {
         let orgs: Vec<String> = vec![];
         let results =
-            fetch_from_organizations(orgs, |org| async move { Ok::<_, (String, TfeError)>(org) })
-                .await;
+            fetch_from_organizations(
+                orgs,
+                2,
+                |org| async move { Ok::<_, (String, TfeError)>(org) },
+            )
+            .await;
 
         assert!(results.is_empty());
     }
@@ -1,6 +1,5 @@
 //! Host resolution from multiple sources
 
-use dialoguer::{theme::ColorfulTheme, Select};
 use log::debug;
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -8,6 +7,7 @@ use std::fs;
 
 use crate::config::{credentials, host as host_config};
 use crate::error::{Result, TfeError};
+use crate::ui::select_with_timeout;
 
 /// Credentials file structure (shared with TokenResolver)
 #[derive(Deserialize, Debug)]
@@ -32,10 +32,12 @@ impl HostResolver {
     /// * `cli_host` - Host from CLI argument (--host)
     /// * `context_host` - Host from active context
     /// * `batch_mode` - If true, error on multiple hosts instead of interactive selection
-    pub fn resolve(
+    /// * `prompt_timeout` - Seconds to wait for a selection before using the first host
+    pub async fn resolve(
         cli_host: Option<&str>,
         context_host: Option<&str>,
         batch_mode: bool,
+        prompt_timeout: Option<u64>,
     ) -> Result<String> {
         // 1. CLI argument takes precedence
         if let Some(host) = cli_host {
@@ -64,11 +66,14 @@ impl HostResolver {
             "No host in CLI, {} or context, trying credentials file",
             host_config::ENV_VAR
         );
-        Self::resolve_from_credentials_file(batch_mode)
+        Self::resolve_from_credentials_file(batch_mode, prompt_timeout).await
     }
 
     /// Read available hosts from Terraform credentials file
-    fn resolve_from_credentials_file(batch_mode: bool) -> Result<String> {
+    async fn resolve_from_credentials_file(
+        batch_mode: bool,
+        prompt_timeout: Option<u64>,
+    ) -> Result<String> {
         let credentials_path = Self::get_credentials_path()
             .ok_or_else(|| TfeError::HostNotFound(Self::host_not_found_message(None, None)))?;
 
@@ -119,27 +124,30 @@ impl HostResolver {
                         Some(&hosts),
                     )))
                 } else {
-                    Self::interactive_host_selection(&hosts, &credentials_path)
+                    Self::interactive_host_selection(hosts, &credentials_path, prompt_timeout).await
                 }
             }
         }
     }
 
     /// Prompt user to select a host interactively
-    fn interactive_host_selection(
-        hosts: &[String],
+    async fn interactive_host_selection(
+        mut hosts: Vec<String>,
         credentials_path: &std::path::Path,
+        prompt_timeout: Option<u64>,
     ) -> Result<String> {
         eprintln!("\nMultiple hosts found in {}:", credentials_path.display());
 
-        let selection = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt("Select a host")
-            .items(hosts)
-            .default(0)
-            .interact()
+        let selection = select_with_timeout("Select a host", &hosts, 0, prompt_timeout)
+            .await
             .map_err(|e| TfeError::HostNotFound(format!("Failed to select host: {}", e)))?;
 
-        let host = hosts[selection].clone();
+        let Some(selection) = selection else {
+            let host = hosts.swap_remove(0);
+            eprintln!("Using default host {}", host);
+            return Ok(host);
+        };
+        let host = hosts.swap_remove(selection);
         debug!("User selected host: {}", host);
         Ok(host)
     }
@@ -193,16 +201,16 @@ impl HostResolver {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_cli_host_takes_precedence() {
-        let result = HostResolver::resolve(Some("my-custom-host.com"), None, false);
+    #[tokio::test]
+    async fn test_cli_host_takes_precedence() {
+        let result = HostResolver::resolve(Some("my-custom-host.com"), None, false, None).await;
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "my-custom-host.com");
     }
 
-    #[test]
-    fn test_cli_host_takes_precedence_batch() {
-        let result = HostResolver::resolve(Some("my-custom-host.com"), None, true);
+    #[tokio::test]
+    async fn test_cli_host_takes_precedence_batch() {
+        let result = HostResolver::resolve(Some("my-custom-host.com"), None, true, None).await;
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "my-custom-host.com");
     }
@@ -9,6 +9,7 @@
 use log::debug;
 
 use crate::cli::LogsArgs;
+use crate::hcp::explain::{call, print_explain, workspace_lookup, PlannedCall};
 use crate::hcp::runs::{fetch_and_print_log, tail_log};
 use crate::hcp::workspaces::{extract_current_run_id, resolve_workspace};
 use crate::hcp::TfeClient;
@@ -37,6 +38,12 @@ pub async fn run_logs_command(
     cli: &Cli,
     args: &LogsArgs,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    if cli.explain {
+        let effective_org = client.effective_org(args.org.as_ref());
+        print_explain(&plan_logs(args, effective_org.as_ref()));
+        return Ok(());
+    }
+
     // Resolve run ID from target
     let run_id = resolve_run_id(client, cli, args).await?;
 
@@ -65,13 +72,50 @@ async fn resolve_run_id(
         LogTarget::Workspace(target) => {
             // Use shared workspace resolver
             let effective_org = client.effective_org(args.org.as_ref());
-            let resolved =
-                resolve_workspace(client, &target, effective_org.as_deref(), cli.batch).await?;
+            let resolved = resolve_workspace(
+                client,
+                &target,
+                effective_org.as_deref(),
+                cli.batch,
+                cli.strict,
+            )
+            .await?;
             extract_current_run_id(&resolved.raw)
         }
     }
 }
 
+/// API calls made by `logs` for the effective organization
+///
+/// A workspace target yields its current run from the workspace's
+/// `current-run` relationship, so no extra call is needed for it.
+fn plan_logs(args: &LogsArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    let (mut calls, run) = match parse_target(&args.target) {
+        LogTarget::Run(run_id) => (vec![], run_id),
+        LogTarget::Workspace(target) => (
+            workspace_lookup(&target, org),
+            ":current_run_id".to_string(),
+        ),
+    };
+    let phase = if args.apply { "apply" } else { "plan" };
+    let polled = if args.follow {
+        ", polled until finished (--follow)"
+    } else {
+        ""
+    };
+    calls.push(call(
+        "GET",
+        format!("/runs/{}/{}", run, phase),
+        format!("{}{}", phase, polled),
+    ));
+    calls.push(call(
+        "GET",
+        ":log-read-url",
+        format!("log archive{}", polled),
+    ));
+    calls
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,4 +203,34 @@ mod tests {
     }
 
     // Note: resolve_workspace tests moved to workspace_resolver module tests
+
+    fn plan(argv: &[&str], org: Option<&str>) -> Vec<PlannedCall> {
+        let cli = <Cli as clap::Parser>::parse_from(argv);
+        let crate::Command::Logs(args) = &cli.command else {
+            unreachable!()
+        };
+        plan_logs(args, org.map(str::to_string).as_ref())
+    }
+
+    #[test]
+    fn test_plan_logs_uses_current_run_relationship() {
+        let calls = plan(&["hcp", "logs", "app"], Some("acme"));
+        let paths: Vec<_> = calls.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/organizations/acme/workspaces/app",
+                "/runs/:current_run_id/plan",
+                ":log-read-url"
+            ]
+        );
+        assert!(!calls.iter().any(|c| c.path.ends_with("/current-run")));
+    }
+
+    #[test]
+    fn test_plan_logs_for_run_id() {
+        let calls = plan(&["hcp", "logs", "run-abc", "--apply"], None);
+        assert_eq!(calls[0].path, "/runs/run-abc/apply");
+        assert_eq!(calls.len(), 2);
+    }
 }
@@ -2,51 +2,90 @@
 //!
 //! This module provides functionality to interact with Terraform Enterprise API.
 
+pub mod account;
+pub mod assessments;
 mod client;
+mod complete;
 pub mod configuration_versions;
 mod credentials;
+mod disk_cache;
+mod etag_cache;
+mod explain;
 pub mod helpers;
 mod host;
 pub mod logs;
+pub mod notification_configs;
 pub mod oauth_clients;
 pub mod org_memberships;
 pub mod organizations;
+pub mod policy_sets;
 pub mod projects;
+mod proxy;
+pub mod redact;
+pub mod registry_modules;
+pub mod run_triggers;
 pub mod runs;
 pub mod state;
 pub mod tags;
 pub mod team_projects;
 pub mod teams;
+mod tls;
 pub mod traits;
+pub mod variable_sets;
+pub mod variables;
 pub mod watch;
 pub mod workspaces;
 
 use serde::Deserialize;
 
-pub use client::{PaginationInfo, TfeClient};
+pub use account::{run_whoami_command, Account, AccountAttributes, Whoami};
+pub use assessments::{
+    run_assessment_command, AssessmentResult, AssessmentStatus, WorkspaceAssessment,
+};
+pub use client::{PageRequest, PaginationInfo, TfeClient};
+pub use complete::run_complete_command;
 pub use configuration_versions::run_download_config_command;
-pub use credentials::TokenResolver;
+pub use credentials::{ResolvedToken, TokenResolver, TokenSource};
+pub use disk_cache::run_cache_command;
+pub use explain::{print_explain, PlannedCall};
 pub use helpers::{
     aggregate_pagination_info, collect_org_results, fetch_from_organizations, log_completion,
-    AggregatedPaginationInfo,
+    AggregatedPaginationInfo, OrgErrors,
 };
 pub use host::HostResolver;
 pub use logs::run_logs_command;
-pub use oauth_clients::{run_oc_command, OAuthClient, OAuthClientAttributes, OAuthToken};
+pub use notification_configs::{
+    run_notification_config_command, NotificationConfig, NotificationConfigAttributes,
+};
+pub use oauth_clients::{
+    run_create_oc_command, run_delete_oc_command, run_oc_command, OAuthClient,
+    OAuthClientAttributes, OAuthToken,
+};
 pub use org_memberships::{
     run_delete_org_member_command, run_invite_command, run_org_member_command,
     OrganizationMembership, OrganizationMembershipAttributes,
 };
 pub use organizations::{
     resolve_organizations, run_org_command, Organization, OrganizationAttributes,
-    OrganizationWithTokens,
+    OrganizationSettings, OrganizationWithTokens,
 };
+pub use policy_sets::{run_policy_set_command, PolicySet, PolicySetAttributes};
 pub use projects::{
     resolve_project, run_prj_command, Project, ProjectAttributes, ProjectWorkspaces,
     ResolvedProject,
 };
-pub use runs::{run_purge_run_command, run_runs_command, Run, RunAttributes};
-pub use state::run_purge_state_command;
+pub use proxy::apply_proxy;
+pub use registry_modules::{
+    run_registry_module_command, ModuleVersionStatus, RegistryModule, RegistryModuleAttributes,
+};
+pub use run_triggers::{
+    run_graph_command, run_run_trigger_command, RunTrigger, RunTriggerAttributes,
+};
+pub use runs::{
+    run_apply_run_command, run_override_run_command, run_purge_run_command, run_runs_command, Run,
+    RunAttributes,
+};
+pub use state::{run_get_output_command, run_get_state_command, run_purge_state_command};
 pub use tags::{
     run_delete_tag_command, run_get_tag_command, run_set_tag_command, OrgTag, OrgTagAttributes,
     TagBinding, TagBindingAttributes, TagBindingsResponse, TagTarget, TagTargetKind,
@@ -55,12 +94,16 @@ pub use team_projects::{
     run_team_access_command, EnrichedTeamProjectAccess, TeamProjectAccess,
     TeamProjectAccessAttributes,
 };
-pub use teams::{run_team_command, Team, TeamAttributes};
+pub use teams::{run_team_command, Team, TeamAttributes, TeamMember};
+pub use tls::TlsConfig;
 pub use traits::{PaginatedResponse, TfeResource};
+pub use variable_sets::{run_varset_command, VariableSet, VariableSetAttributes};
+pub use variables::{run_var_command, Variable, VariableAttributes};
 pub use watch::run_watch_ws_command;
 pub use workspaces::{
-    extract_current_run_id, resolve_workspace, run_set_ws_command, run_ws_command,
-    ResolvedWorkspace, Workspace, WorkspaceAttributes, WorkspaceTarget,
+    extract_current_run_id, resolve_workspace, run_lock_command, run_set_ws_command,
+    run_unlock_command, run_ws_command, ResolvedWorkspace, Workspace, WorkspaceAttributes,
+    WorkspaceFilter, WorkspaceSettings, WorkspaceTarget,
 };
 
 /// Pagination metadata from TFE API (shared across resources)
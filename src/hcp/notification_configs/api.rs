@@ -0,0 +1,116 @@
+//! Notification configuration API operations
+
+use crate::config::api;
+use crate::error::Result;
+use crate::hcp::traits::ApiListResponse;
+use crate::hcp::{PageRequest, TfeClient};
+
+use super::models::NotificationConfig;
+
+impl TfeClient {
+    /// Get all notification configurations of a workspace (with pagination)
+    pub async fn get_notification_configs(
+        &self,
+        workspace_id: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<NotificationConfig>> {
+        let path = format!(
+            "/{}/{}/{}",
+            api::WORKSPACES,
+            workspace_id,
+            api::NOTIFICATION_CONFIGURATIONS
+        );
+        let error_context = format!(
+            "notification configurations for workspace '{}'",
+            workspace_id
+        );
+
+        self.fetch_pages::<NotificationConfig, ApiListResponse<NotificationConfig>>(
+            &path,
+            &error_context,
+            page,
+        )
+        .await
+    }
+
+    /// Get a notification configuration by ID
+    pub async fn get_notification_config(
+        &self,
+        config_id: &str,
+    ) -> Result<Option<(NotificationConfig, serde_json::Value)>> {
+        let path = format!("/{}/{}", api::NOTIFICATION_CONFIGURATIONS, config_id);
+        self.fetch_resource_by_path::<NotificationConfig>(
+            &path,
+            &format!("notification configuration '{}'", config_id),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn config_json(id: &str, name: &str, destination: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "type": "notification-configurations",
+            "attributes": {
+                "name": name,
+                "destination-type": destination,
+                "enabled": true,
+                "triggers": ["run:errored"]
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_notification_configs() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc/notification-configurations"))
+            .and(query_param("page[number]", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    config_json("nc-1", "slack-alerts", "slack"),
+                    config_json("nc-2", "oncall", "email")
+                ],
+                "meta": {"pagination": {
+                    "current-page": 1, "page-size": 100, "total-pages": 1, "total-count": 2
+                }}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let configs = client
+            .get_notification_configs("ws-abc", None)
+            .await
+            .unwrap();
+
+        assert_eq!(configs.len(), 2);
+        assert_eq!(configs[0].name(), "slack-alerts");
+        assert_eq!(configs[1].destination_type(), "email");
+    }
+
+    #[tokio::test]
+    async fn test_get_notification_config_not_found() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/notification-configurations/nc-missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        assert!(client
+            .get_notification_config("nc-missing")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
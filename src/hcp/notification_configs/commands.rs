@@ -0,0 +1,116 @@
+//! Notification configuration command handlers
+
+use log::debug;
+
+use crate::hcp::explain::{call, print_explain, workspace_lookup};
+use crate::hcp::traits::TfeResource;
+use crate::hcp::workspaces::resolve_workspace;
+use crate::hcp::TfeClient;
+use crate::output::{apply_limit, output_notification_configs};
+use crate::ui::{create_spinner, finish_spinner};
+use crate::{Cli, Command, GetResource};
+
+/// Run the notification configuration list/get command
+///
+/// Lists a workspace's notification configurations, or a single one (with
+/// its full trigger list and redacted URL) when an ID is given.
+pub async fn run_notification_config_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::NotificationConfig(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if let Some(nc_id) = &args.id {
+        if !nc_id.starts_with("nc-") {
+            return Err(format!(
+                "Invalid notification configuration ID '{}'. Use an ID starting with 'nc-', or \
+                 --ws to list a workspace's notification configurations.",
+                nc_id
+            )
+            .into());
+        }
+        if cli.explain {
+            print_explain(&[call(
+                "GET",
+                format!("/notification-configurations/{}", nc_id),
+                "notification configuration",
+            )]);
+            return Ok(());
+        }
+
+        let spinner = create_spinner(
+            &format!("Fetching notification configuration '{}'...", nc_id),
+            cli.batch,
+        );
+        let result = client.get_notification_config(nc_id).await;
+        finish_spinner(spinner);
+        return match result? {
+            Some((config, _raw)) => {
+                output_notification_configs(&[config], cli);
+                Ok(())
+            }
+            None => Err(format!("Notification configuration '{}' not found", nc_id).into()),
+        };
+    }
+
+    let Some(target) = &args.workspace else {
+        unreachable!("clap requires --ws when no notification configuration ID is given")
+    };
+    let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        let mut calls = workspace_lookup(target, effective_org.as_ref());
+        calls.push(call(
+            "GET",
+            "/workspaces/:ws_id/notification-configurations",
+            "paginated",
+        ));
+        print_explain(&calls);
+        return Ok(());
+    }
+    let resolved = resolve_workspace(
+        client,
+        target,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
+    let ws_name = resolved.workspace.name().to_string();
+
+    let spinner = create_spinner(
+        &format!(
+            "Fetching notification configurations for workspace '{}'...",
+            ws_name
+        ),
+        cli.batch,
+    );
+    let result = client
+        .with_progress(spinner.as_ref())
+        .get_notification_configs(&resolved.workspace.id, cli.page_request())
+        .await;
+    finish_spinner(spinner);
+    let mut configs = result?;
+    debug!(
+        "Fetched {} notification configurations for workspace '{}'",
+        configs.len(),
+        ws_name
+    );
+
+    if configs.is_empty() {
+        eprintln!(
+            "No notification configurations found for workspace '{}'",
+            ws_name
+        );
+        return Ok(());
+    }
+
+    configs.sort_by(|a, b| a.name().cmp(b.name()));
+    apply_limit(&mut configs, cli);
+    output_notification_configs(&configs, cli);
+    Ok(())
+}
@@ -0,0 +1,8 @@
+//! Notification configurations module - list workspace notification settings
+
+mod api;
+mod commands;
+mod models;
+
+pub use commands::run_notification_config_command;
+pub use models::{NotificationConfig, NotificationConfigAttributes};
@@ -0,0 +1,144 @@
+//! Notification configuration data models
+
+use serde::{Deserialize, Serialize};
+
+use crate::hcp::traits::TfeResource;
+
+/// Notification configuration from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct NotificationConfig {
+    pub id: String,
+    pub attributes: NotificationConfigAttributes,
+}
+
+/// Notification configuration attributes from TFE API
+///
+/// `url` may embed a secret (Slack/Teams webhooks), so output only ever shows
+/// [`NotificationConfig::redacted_url`].
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct NotificationConfigAttributes {
+    pub name: String,
+    /// Destination: "email", "slack", "microsoft-teams" or "generic" (webhook)
+    #[serde(rename = "destination-type")]
+    pub destination_type: String,
+    #[serde(default)]
+    pub enabled: bool,
+    /// Events that fire the notification (e.g. "run:errored", "assessment:drifted")
+    #[serde(default)]
+    pub triggers: Vec<String>,
+    pub url: Option<String>,
+    #[serde(rename = "email-addresses", default)]
+    pub email_addresses: Vec<String>,
+}
+
+impl NotificationConfig {
+    /// Get notification configuration name
+    pub fn name(&self) -> &str {
+        &self.attributes.name
+    }
+
+    /// Get destination type
+    pub fn destination_type(&self) -> &str {
+        &self.attributes.destination_type
+    }
+
+    /// Destination URL with everything after the host masked
+    ///
+    /// Returns `None` when no URL is configured (email destinations).
+    pub fn redacted_url(&self) -> Option<String> {
+        let url = self.attributes.url.as_deref().filter(|u| !u.is_empty())?;
+        let redacted = match reqwest::Url::parse(url) {
+            Ok(parsed) => match parsed.host_str() {
+                Some(host) if parsed.path() == "/" && parsed.query().is_none() => {
+                    format!("{}://{}", parsed.scheme(), host)
+                }
+                Some(host) => format!("{}://{}/***", parsed.scheme(), host),
+                None => "***".to_string(),
+            },
+            Err(_) => "***".to_string(),
+        };
+        Some(redacted)
+    }
+}
+
+impl TfeResource for NotificationConfig {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.attributes.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_url(url: Option<&str>) -> NotificationConfig {
+        serde_json::from_value(serde_json::json!({
+            "id": "nc-abc",
+            "attributes": {
+                "name": "alerts",
+                "destination-type": "slack",
+                "url": url
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_notification_config() {
+        let json = r#"{
+            "id": "nc-AeUQ2zfKZzW9TiGZ",
+            "type": "notification-configurations",
+            "attributes": {
+                "enabled": true,
+                "name": "Webhook server test",
+                "url": "https://httpstat.us/200",
+                "destination-type": "generic",
+                "token": null,
+                "triggers": ["run:applying", "run:completed"],
+                "delivery-responses": [],
+                "created-at": "2018-09-27T12:14:52.209Z",
+                "updated-at": "2018-09-27T12:14:52.209Z"
+            }
+        }"#;
+
+        let nc: NotificationConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(nc.id, "nc-AeUQ2zfKZzW9TiGZ");
+        assert_eq!(nc.name(), "Webhook server test");
+        assert_eq!(nc.destination_type(), "generic");
+        assert!(nc.attributes.enabled);
+        assert_eq!(nc.attributes.triggers, ["run:applying", "run:completed"]);
+        assert!(nc.attributes.email_addresses.is_empty());
+    }
+
+    #[test]
+    fn test_redacted_url_masks_path_and_query() {
+        let nc = config_with_url(Some("https://hooks.slack.com/services/T0/B0/secret"));
+        assert_eq!(
+            nc.redacted_url().as_deref(),
+            Some("https://hooks.slack.com/***")
+        );
+
+        let nc = config_with_url(Some("https://example.com/?token=secret"));
+        assert_eq!(
+            nc.redacted_url().as_deref(),
+            Some("https://example.com/***")
+        );
+
+        let nc = config_with_url(Some("https://example.com"));
+        assert_eq!(nc.redacted_url().as_deref(), Some("https://example.com"));
+    }
+
+    #[test]
+    fn test_redacted_url_without_or_with_invalid_url() {
+        assert!(config_with_url(None).redacted_url().is_none());
+        assert!(config_with_url(Some("")).redacted_url().is_none());
+        assert_eq!(
+            config_with_url(Some("not a url")).redacted_url().as_deref(),
+            Some("***")
+        );
+    }
+}
@@ -1,21 +1,30 @@
 //! OAuth Client API operations
 
+use std::collections::HashMap;
+
+use futures::stream::{self, StreamExt};
 use log::debug;
 
 use crate::config::api;
 use crate::error::{Result, TfeError};
-use crate::hcp::TfeClient;
+use crate::hcp::{PageRequest, TfeClient};
 
-use super::models::OAuthClient;
+use super::models::{
+    CreateOAuthClientRequest, OAuthClient, OAuthClientResponse, OAuthToken, VcsWorkspace,
+};
 use crate::hcp::traits::ApiListResponse;
 
 impl TfeClient {
     /// Get all OAuth clients for an organization (with pagination)
-    pub async fn get_oauth_clients(&self, org: &str) -> Result<Vec<OAuthClient>> {
+    pub async fn get_oauth_clients(
+        &self,
+        org: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<OAuthClient>> {
         let path = format!("/{}/{}/oauth-clients", api::ORGANIZATIONS, org);
         let error_context = format!("OAuth clients for organization '{}'", org);
 
-        self.fetch_all_pages::<OAuthClient, ApiListResponse<OAuthClient>>(&path, &error_context)
+        self.fetch_pages::<OAuthClient, ApiListResponse<OAuthClient>>(&path, &error_context, page)
             .await
     }
 
@@ -31,7 +40,148 @@ impl TfeClient {
             .ok_or_else(|| TfeError::Api {
                 status: 404,
                 message: format!("OAuth client '{}' not found", client_id),
+                details: Vec::new(),
+            })
+    }
+
+    /// Create an OAuth client in an organization
+    pub async fn create_oauth_client(
+        &self,
+        org: &str,
+        request: &CreateOAuthClientRequest,
+    ) -> Result<OAuthClient> {
+        let url = format!(
+            "{}/{}/{}/oauth-clients",
+            self.base_url(),
+            api::ORGANIZATIONS,
+            org
+        );
+
+        debug!(
+            "Creating {} OAuth client in organization {}",
+            request.data.attributes.service_provider, org
+        );
+
+        let response = self.send(self.post(&url).json(request)).await?;
+
+        match response.status().as_u16() {
+            200 | 201 => {
+                let created: OAuthClientResponse = response.json().await?;
+                debug!("Created OAuth client {}", created.data.id);
+                Ok(created.data)
+            }
+            404 => Err(TfeError::Api {
+                status: 404,
+                message: format!("Organization '{}' not found", org),
+                details: Vec::new(),
+            }),
+            422 => {
+                let body = response.text().await.unwrap_or_default();
+                Err(TfeError::api_response(
+                    422,
+                    "Cannot create OAuth client",
+                    &body,
+                ))
+            }
+            status => Err(TfeError::Api {
+                status,
+                message: format!("Failed to create OAuth client in '{}'", org),
+                details: Vec::new(),
+            }),
+        }
+    }
+
+    /// Delete an OAuth client by ID
+    ///
+    /// The API rejects the deletion (409/422) while the client is still in use.
+    pub async fn delete_oauth_client(&self, client_id: &str) -> Result<()> {
+        let url = format!("{}/oauth-clients/{}", self.base_url(), client_id);
+
+        debug!("Deleting OAuth client: {}", client_id);
+
+        let response = self.send(self.delete(&url)).await?;
+
+        match response.status().as_u16() {
+            200 | 204 => {
+                debug!("Successfully deleted OAuth client {}", client_id);
+                Ok(())
+            }
+            404 => Err(TfeError::Api {
+                status: 404,
+                message: format!("OAuth client '{}' not found", client_id),
+                details: Vec::new(),
+            }),
+            status @ (409 | 422) => {
+                let body = response.text().await.unwrap_or_default();
+                Err(TfeError::api_response(
+                    status,
+                    format!("Cannot delete OAuth client '{}'", client_id),
+                    &body,
+                ))
+            }
+            status => Err(TfeError::Api {
+                status,
+                message: format!("Failed to delete OAuth client '{}'", client_id),
+                details: Vec::new(),
+            }),
+        }
+    }
+
+    /// Get workspaces in an organization whose VCS connection uses one of the given OAuth tokens
+    pub async fn get_oauth_client_workspaces(
+        &self,
+        org: &str,
+        token_ids: &[&str],
+    ) -> Result<Vec<VcsWorkspace>> {
+        let path = format!("/{}/{}/workspaces", api::ORGANIZATIONS, org);
+        let error_context = format!("workspaces for organization '{}'", org);
+
+        let workspaces = self
+            .fetch_all_pages::<VcsWorkspace, ApiListResponse<VcsWorkspace>>(&path, &error_context)
+            .await?;
+        Ok(workspaces
+            .into_iter()
+            .filter(|ws| ws.uses_any_token(token_ids))
+            .collect())
+    }
+
+    /// Get the OAuth tokens of a single OAuth client (with pagination)
+    pub async fn get_oauth_client_tokens(&self, client_id: &str) -> Result<Vec<OAuthToken>> {
+        let path = format!("/oauth-clients/{}/oauth-tokens", client_id);
+        let error_context = format!("OAuth tokens for OAuth client '{}'", client_id);
+
+        self.fetch_all_pages::<OAuthToken, ApiListResponse<OAuthToken>>(&path, &error_context)
+            .await
+    }
+
+    /// Fetch OAuth tokens for several clients concurrently, keyed by client ID
+    ///
+    /// Uses `buffer_unordered` bounded by `--concurrency`.
+    /// Clients whose tokens fail to load are skipped with a debug warning.
+    pub async fn fetch_oauth_client_tokens<'a, I>(
+        &self,
+        clients: I,
+    ) -> HashMap<String, Vec<OAuthToken>>
+    where
+        I: IntoIterator<Item = &'a OAuthClient>,
+    {
+        stream::iter(clients)
+            .map(|oc| async move {
+                match self.get_oauth_client_tokens(&oc.id).await {
+                    Ok(tokens) => Some((oc.id.clone(), tokens)),
+                    Err(e) => {
+                        debug!(
+                            "Error fetching OAuth tokens for '{}': {}, skipping",
+                            oc.id, e
+                        );
+                        None
+                    }
+                }
             })
+            .buffer_unordered(self.concurrency())
+            .filter_map(|entry| async move { entry })
+            .collect()
+            .await
     }
 
     /// Get OAuth tokens for an organization (from the oauth-tokens link)
@@ -43,7 +193,7 @@ impl TfeClient {
 
         debug!("Fetching OAuth tokens from: {}", url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         if !response.status().is_success() {
             // Return empty vec instead of error for orgs without oauth tokens
@@ -102,7 +252,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_oauth_clients("my-org").await;
+        let result = client.get_oauth_clients("my-org", None).await;
 
         assert!(result.is_ok());
         let clients = result.unwrap();
@@ -123,7 +273,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_oauth_clients("my-org").await;
+        let result = client.get_oauth_clients("my-org", None).await;
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
@@ -140,7 +290,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_oauth_clients("my-org").await;
+        let result = client.get_oauth_clients("my-org", None).await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -172,6 +322,185 @@ mod tests {
         assert_eq!(oauth_client.name(), "My GitHub");
     }
 
+    #[tokio::test]
+    async fn test_create_oauth_client_success() {
+        use wiremock::matchers::body_partial_json;
+
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("POST"))
+            .and(path("/organizations/my-org/oauth-clients"))
+            .and(body_partial_json(serde_json::json!({
+                "data": {
+                    "type": "oauth-clients",
+                    "attributes": {
+                        "service-provider": "github",
+                        "api-url": "https://api.github.com",
+                        "http-url": "https://github.com",
+                        "oauth-token-string": "ghp_secret"
+                    }
+                }
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "data": oauth_client_json("oc-new", "GitHub")
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let request = CreateOAuthClientRequest::new(
+            "github",
+            "https://api.github.com",
+            "https://github.com",
+            "ghp_secret",
+            Some("GitHub"),
+        );
+        let created = client
+            .create_oauth_client("my-org", &request)
+            .await
+            .unwrap();
+
+        assert_eq!(created.id, "oc-new");
+    }
+
+    #[tokio::test]
+    async fn test_create_oauth_client_validation_error() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("POST"))
+            .and(path("/organizations/my-org/oauth-clients"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "errors": [{
+                    "status": "422",
+                    "title": "invalid attribute",
+                    "detail": "Oauth token string is invalid",
+                    "source": {"pointer": "/data/attributes/oauth-token-string"}
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let request = CreateOAuthClientRequest::new(
+            "github",
+            "https://api.github.com",
+            "https://github.com",
+            "bad",
+            None,
+        );
+        let err = client
+            .create_oauth_client("my-org", &request)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("token string is invalid"));
+        match err {
+            TfeError::Api {
+                status, details, ..
+            } => {
+                assert_eq!(status, 422);
+                assert_eq!(details.len(), 1);
+                assert_eq!(
+                    details[0].pointer.as_deref(),
+                    Some("/data/attributes/oauth-token-string")
+                );
+            }
+            _ => panic!("Expected TfeError::Api"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_oauth_client_success() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("DELETE"))
+            .and(path("/oauth-clients/oc-abc123"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let result = client.delete_oauth_client("oc-abc123").await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_oauth_client_in_use_rejected() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        let response_body = serde_json::json!({
+            "errors": [{
+                "status": "422",
+                "detail": "OAuth client is used by 3 workspaces"
+            }]
+        });
+
+        Mock::given(method("DELETE"))
+            .and(path("/oauth-clients/oc-abc123"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(&response_body))
+            .mount(&mock_server)
+            .await;
+
+        let result = client.delete_oauth_client("oc-abc123").await;
+
+        match result.unwrap_err() {
+            TfeError::Api {
+                status, message, ..
+            } => {
+                assert_eq!(status, 422);
+                assert!(message.contains("used by 3 workspaces"));
+            }
+            _ => panic!("Expected TfeError::Api"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_oauth_client_not_found() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("DELETE"))
+            .and(path("/oauth-clients/oc-missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let err = client.delete_oauth_client("oc-missing").await.unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[tokio::test]
+    async fn test_get_oauth_client_workspaces_filters_by_token() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        let response_body = serde_json::json!({
+            "data": [
+                {"id": "ws-1", "attributes": {"name": "uses-it", "vcs-repo": {"oauth-token-id": "ot-1"}}},
+                {"id": "ws-2", "attributes": {"name": "other-vcs", "vcs-repo": {"oauth-token-id": "ot-9"}}},
+                {"id": "ws-3", "attributes": {"name": "no-vcs", "vcs-repo": null}}
+            ]
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/workspaces"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+            .mount(&mock_server)
+            .await;
+
+        let workspaces = client
+            .get_oauth_client_workspaces("my-org", &["ot-1"])
+            .await
+            .unwrap();
+
+        assert_eq!(workspaces.len(), 1);
+        assert_eq!(workspaces[0].id, "ws-1");
+    }
+
     #[tokio::test]
     async fn test_get_oauth_tokens_for_org_success() {
         let mock_server = MockServer::start().await;
@@ -218,4 +547,41 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
     }
+
+    #[tokio::test]
+    async fn test_fetch_oauth_client_tokens() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/oauth-clients/oc-1/oauth-tokens"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{
+                    "id": "ot-1",
+                    "type": "oauth-tokens",
+                    "attributes": {
+                        "created-at": "2025-01-01T00:00:00Z",
+                        "service-provider-user": "octocat"
+                    }
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/oauth-clients/oc-2/oauth-tokens"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let clients: Vec<OAuthClient> = serde_json::from_value(serde_json::json!([
+            oauth_client_json("oc-1", "first"),
+            oauth_client_json("oc-2", "second")
+        ]))
+        .unwrap();
+        let tokens = client.fetch_oauth_client_tokens(&clients).await;
+
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens["oc-1"][0].id, "ot-1");
+        assert_eq!(tokens["oc-1"][0].service_provider_user(), "octocat");
+    }
 }
@@ -1,16 +1,24 @@
 //! OAuth Client command handlers
 
+use std::collections::HashMap;
+
 use log::debug;
 
-use crate::cli::{Cli, Command, GetResource, OutputFormat};
-use crate::hcp::helpers::{collect_org_results, fetch_from_organizations, log_completion};
+use crate::cli::{
+    Cli, Command, CreateResource, DeleteOcArgs, DeleteResource, GetResource, OcArgs, OutputFormat,
+    VcsProvider,
+};
+use crate::hcp::explain::{call, org_listing, org_path, print_explain, PlannedCall};
+use crate::hcp::helpers::{
+    collect_org_results, fetch_from_organizations, log_completion, OrgErrors,
+};
 use crate::hcp::organizations::resolve_organizations;
 use crate::hcp::traits::TfeResource;
 use crate::hcp::TfeClient;
-use crate::output::{output_oauth_clients, output_raw};
-use crate::ui::{create_spinner, finish_spinner, finish_spinner_with_status};
+use crate::output::{apply_limit_grouped, output_oauth_clients, output_raw};
+use crate::ui::{confirm_action, create_spinner, finish_spinner, finish_spinner_with_status};
 
-use super::models::OAuthClient;
+use super::models::{CreateOAuthClientRequest, OAuthClient, OAuthToken};
 
 /// Run the OAuth client list command
 pub async fn run_oc_command(
@@ -24,14 +32,17 @@ pub async fn run_oc_command(
         unreachable!()
     };
 
+    let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        print_explain(&plan_get_oc(args, effective_org.as_ref()));
+        return Ok(());
+    }
+
     // If NAME is specified, get single OAuth client
     if let Some(name) = &args.name {
-        let effective_org = client.effective_org(args.org.as_ref());
         return get_single_oauth_client(client, cli, name, effective_org.as_ref()).await;
     }
 
-    let effective_org = client.effective_org(args.org.as_ref());
-
     // Otherwise list all OAuth clients
     let organizations = resolve_organizations(client, effective_org.as_ref()).await?;
 
@@ -50,8 +61,11 @@ pub async fn run_oc_command(
     );
 
     // Fetch OAuth clients from all orgs in parallel
-    let results = fetch_from_organizations(organizations, |org| async move {
-        let clients = client.get_oauth_clients(&org).await;
+    let progress_client = &client.with_progress(spinner.as_ref());
+    let results = fetch_from_organizations(organizations, client.concurrency(), |org| async move {
+        let clients = progress_client
+            .get_oauth_clients(&org, cli.page_request())
+            .await;
 
         match clients {
             Ok(ocs) => {
@@ -66,19 +80,41 @@ pub async fn run_oc_command(
     })
     .await;
 
-    let (all_clients, had_errors): (Vec<(String, Vec<OAuthClient>)>, bool) =
-        collect_org_results(results, &spinner, "OAuth clients");
+    let (mut all_clients, org_errors): (Vec<(String, Vec<OAuthClient>)>, OrgErrors) =
+        collect_org_results(results, "OAuth clients");
 
-    finish_spinner_with_status(spinner, &all_clients, had_errors);
+    finish_spinner_with_status(spinner, &all_clients, org_errors.has_errors());
+    org_errors.check_strict(cli.strict, "OAuth clients")?;
 
     if !all_clients.is_empty() {
-        output_oauth_clients(&all_clients, cli);
+        apply_limit_grouped(&mut all_clients, cli);
+        let tokens = fetch_tokens_if_requested(client, cli, args, &all_clients).await;
+        output_oauth_clients(&all_clients, tokens.as_ref(), cli);
     }
 
-    log_completion(had_errors);
+    org_errors.print_report("OAuth clients");
+    log_completion(org_errors.has_errors());
     Ok(())
 }
 
+/// Fetch the OAuth tokens of the listed clients when `--with-tokens` is set
+async fn fetch_tokens_if_requested(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &OcArgs,
+    clients: &[(String, Vec<OAuthClient>)],
+) -> Option<HashMap<String, Vec<OAuthToken>>> {
+    if !args.with_tokens {
+        return None;
+    }
+    let spinner = create_spinner("Fetching OAuth tokens...", cli.batch);
+    let tokens = client
+        .fetch_oauth_client_tokens(clients.iter().flat_map(|(_, ocs)| ocs))
+        .await;
+    finish_spinner(spinner);
+    Some(tokens)
+}
+
 /// Get a single OAuth client by ID
 async fn get_single_oauth_client(
     client: &TfeClient,
@@ -101,8 +137,16 @@ async fn get_single_oauth_client(
             Ok((oauth_client, raw)) => {
                 finish_spinner(spinner);
 
-                // For JSON/YAML, return raw API response
-                if matches!(args.output, OutputFormat::Json | OutputFormat::Yaml) {
+                // For JSON/YAML, return raw API response (it carries no token details)
+                if !args.with_tokens
+                    && matches!(
+                        args.output,
+                        OutputFormat::Json
+                            | OutputFormat::Yaml
+                            | OutputFormat::Ndjson
+                            | OutputFormat::Template
+                    )
+                {
                     output_raw(&raw, &args.output);
                     return Ok(());
                 }
@@ -112,7 +156,8 @@ async fn get_single_oauth_client(
                     .unwrap_or("unknown")
                     .to_string();
                 let all_clients = vec![(org_name, vec![oauth_client])];
-                output_oauth_clients(&all_clients, cli);
+                let tokens = fetch_tokens_if_requested(client, cli, args, &all_clients).await;
+                output_oauth_clients(&all_clients, tokens.as_ref(), cli);
                 return Ok(());
             }
             Err(e) => {
@@ -136,10 +181,11 @@ async fn get_single_oauth_client(
 
     // Search in all organizations IN PARALLEL
     let name_owned = name.to_string();
+    let progress_client = &client.with_progress(spinner.as_ref());
     let found = crate::hcp::helpers::search_first_in_orgs(&organizations, |org| {
         let oc_name = name_owned.clone();
         async move {
-            match client.get_oauth_clients(&org).await {
+            match progress_client.get_oauth_clients(&org, None).await {
                 Ok(clients) => {
                     let found: Vec<_> = clients
                         .into_iter()
@@ -162,7 +208,15 @@ async fn get_single_oauth_client(
 
         // For JSON/YAML with name search, we need to fetch the raw JSON
         // (we only have the model from list, not raw JSON)
-        if matches!(args.output, OutputFormat::Json | OutputFormat::Yaml) {
+        if !args.with_tokens
+            && matches!(
+                args.output,
+                OutputFormat::Json
+                    | OutputFormat::Yaml
+                    | OutputFormat::Ndjson
+                    | OutputFormat::Template
+            )
+        {
             // Fetch the first match by ID to get raw JSON
             if let Ok((_, raw)) = client.get_oauth_client(&found[0].id).await {
                 output_raw(&raw, &args.output);
@@ -171,10 +225,333 @@ async fn get_single_oauth_client(
         }
 
         let all_clients = vec![(org_name, found)];
-        output_oauth_clients(&all_clients, cli);
+        let tokens = fetch_tokens_if_requested(client, cli, args, &all_clients).await;
+        output_oauth_clients(&all_clients, tokens.as_ref(), cli);
         return Ok(());
     }
 
     finish_spinner(spinner);
     Err(crate::hcp::helpers::not_found_in_orgs_error("OAuth client", name, &organizations).into())
 }
+
+/// Run the create OAuth client command
+pub async fn run_create_oc_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Create {
+        resource: CreateResource::Oc(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let org = client
+        .effective_org(args.org.as_ref())
+        .ok_or("--org is required to create an OAuth client")?;
+    if cli.explain {
+        print_explain(&plan_create_oc(&org));
+        return Ok(());
+    }
+
+    let (api_url, http_url) = resolve_vcs_urls(
+        args.service_provider,
+        args.api_url.as_deref(),
+        args.http_url.as_deref(),
+    )?;
+
+    if args.oauth_token_string.trim().is_empty() {
+        return Err("--oauth-token-string must not be empty".into());
+    }
+
+    debug!(
+        "Creating OAuth client: provider={}, api-url={}, http-url={}, token={}",
+        args.service_provider,
+        api_url,
+        http_url,
+        mask_token(&args.oauth_token_string)
+    );
+
+    let request = CreateOAuthClientRequest::new(
+        args.service_provider.api_value(),
+        &api_url,
+        &http_url,
+        &args.oauth_token_string,
+        args.name.as_deref(),
+    );
+
+    let spinner = create_spinner(
+        &format!(
+            "Creating {} OAuth client in '{}'...",
+            args.service_provider, org
+        ),
+        cli.batch,
+    );
+    let result = client.create_oauth_client(&org, &request).await;
+    finish_spinner(spinner);
+    let created = result?;
+
+    println!(
+        "✓ Created OAuth client '{}' ({}) in '{}' (token: {})",
+        created.name(),
+        created.id,
+        org,
+        mask_token(&args.oauth_token_string)
+    );
+
+    Ok(())
+}
+
+/// Resolve api/http URLs for a provider, falling back to the public endpoints
+/// of hosted providers. Self-hosted providers require both URLs.
+fn resolve_vcs_urls(
+    provider: VcsProvider,
+    api_url: Option<&str>,
+    http_url: Option<&str>,
+) -> Result<(String, String), String> {
+    match (api_url, http_url, provider.default_urls()) {
+        (Some(api), Some(http), _) => Ok((api.to_string(), http.to_string())),
+        (api, http, Some((default_api, default_http))) => Ok((
+            api.unwrap_or(default_api).to_string(),
+            http.unwrap_or(default_http).to_string(),
+        )),
+        _ => Err(format!(
+            "--api-url and --http-url are required for service provider '{}'",
+            provider
+        )),
+    }
+}
+
+/// Mask a secret for display, keeping only the last 4 characters
+fn mask_token(token: &str) -> String {
+    let chars: Vec<char> = token.chars().collect();
+    if chars.len() <= 8 {
+        return "****".to_string();
+    }
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("****{}", tail)
+}
+
+/// Run the delete OAuth client command
+pub async fn run_delete_oc_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Delete {
+        resource: DeleteResource::Oc(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        print_explain(&plan_delete_oc(args, effective_org.as_ref()));
+        return Ok(());
+    }
+
+    // Resolve the OAuth client from ID or name
+    let oauth_client = if args.name.starts_with("oc-") {
+        let spinner = create_spinner(
+            &format!("Fetching OAuth client '{}'...", args.name),
+            cli.batch,
+        );
+        let result = client.get_oauth_client(&args.name).await;
+        finish_spinner(spinner);
+        result?.0
+    } else {
+        let org = effective_org
+            .as_ref()
+            .ok_or("OAuth client name requires --org. Use: delete oc NAME --org ORG")?;
+        let spinner = create_spinner(
+            &format!("Looking up OAuth client '{}' in '{}'...", args.name, org),
+            cli.batch,
+        );
+        let result = client
+            .with_progress(spinner.as_ref())
+            .get_oauth_clients(org, None)
+            .await;
+        finish_spinner(spinner);
+        result?
+            .into_iter()
+            .find(|c| c.name() == args.name)
+            .ok_or_else(|| {
+                format!(
+                    "OAuth client '{}' not found in organization '{}'",
+                    args.name, org
+                )
+            })?
+    };
+
+    let org = effective_org
+        .clone()
+        .or_else(|| oauth_client.organization_id().map(String::from));
+
+    println!(
+        "Warning: deleting OAuth client '{}' breaks VCS connections for every workspace using it.",
+        oauth_client.name()
+    );
+
+    if args.show_workspaces {
+        match &org {
+            Some(org) => {
+                let token_ids = oauth_client.oauth_token_ids();
+                let spinner = create_spinner(
+                    &format!("Finding workspaces using '{}'...", oauth_client.name()),
+                    cli.batch,
+                );
+                let result = client
+                    .with_progress(spinner.as_ref())
+                    .get_oauth_client_workspaces(org, &token_ids)
+                    .await;
+                finish_spinner(spinner);
+                let workspaces = result?;
+                if workspaces.is_empty() {
+                    println!("No workspaces in '{}' use this OAuth client.", org);
+                } else {
+                    println!("Affected workspaces ({}):", workspaces.len());
+                    for ws in &workspaces {
+                        println!("  - {} ({})", ws.attributes.name, ws.id);
+                    }
+                }
+            }
+            None => debug!("Cannot determine organization, skipping affected workspaces"),
+        }
+    }
+
+    let prompt = format!(
+        "Delete OAuth client '{}' ({})?",
+        oauth_client.name(),
+        oauth_client.id
+    );
+    if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
+        println!("Cancelled");
+        return Ok(());
+    }
+
+    let spinner = create_spinner(
+        &format!("Deleting OAuth client {}...", oauth_client.id),
+        cli.batch,
+    );
+    let result = client.delete_oauth_client(&oauth_client.id).await;
+    finish_spinner(spinner);
+    result?;
+
+    println!("✓ Deleted OAuth client '{}'", oauth_client.name());
+    Ok(())
+}
+
+/// API calls made by `get oc` for the effective organization
+fn plan_get_oc(args: &OcArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    match &args.name {
+        Some(id) if id.starts_with("oc-") => {
+            vec![call(
+                "GET",
+                format!("/oauth-clients/{}", id),
+                "OAuth client",
+            )]
+        }
+        _ => org_listing(org, "oauth-clients"),
+    }
+}
+
+/// API calls made by `create oc`
+fn plan_create_oc(org: &str) -> Vec<PlannedCall> {
+    vec![call(
+        "POST",
+        format!("/organizations/{}/oauth-clients", org),
+        "create OAuth client",
+    )]
+}
+
+/// API calls made by `delete oc` for the effective organization
+fn plan_delete_oc(args: &DeleteOcArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    let mut calls = if args.name.starts_with("oc-") {
+        vec![call(
+            "GET",
+            format!("/oauth-clients/{}", args.name),
+            "OAuth client",
+        )]
+    } else {
+        vec![call(
+            "GET",
+            format!("/organizations/{}/oauth-clients", org_path(org)),
+            "resolve by name",
+        )]
+    };
+    if args.show_workspaces {
+        calls.push(call(
+            "GET",
+            format!("/organizations/{}/workspaces", org_path(org)),
+            "find affected workspaces",
+        ));
+    }
+    calls.push(call("DELETE", "/oauth-clients/:oc_id", "delete"));
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_vcs_urls_hosted_defaults() {
+        let (api, http) = resolve_vcs_urls(VcsProvider::Github, None, None).unwrap();
+        assert_eq!(api, "https://api.github.com");
+        assert_eq!(http, "https://github.com");
+    }
+
+    #[test]
+    fn test_resolve_vcs_urls_explicit_override() {
+        let (api, http) = resolve_vcs_urls(
+            VcsProvider::GitlabHosted,
+            Some("https://gl.example.com/api/v4"),
+            None,
+        )
+        .unwrap();
+        assert_eq!(api, "https://gl.example.com/api/v4");
+        assert_eq!(http, "https://gitlab.com");
+    }
+
+    #[test]
+    fn test_resolve_vcs_urls_self_hosted_requires_both() {
+        let err = resolve_vcs_urls(VcsProvider::GithubEnterprise, Some("https://ghe/api"), None)
+            .unwrap_err();
+        assert!(err.contains("--api-url and --http-url are required"));
+        assert!(err.contains("github-enterprise"));
+        assert!(resolve_vcs_urls(
+            VcsProvider::AdoServer,
+            Some("https://ado/api"),
+            Some("https://ado")
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_mask_token() {
+        assert_eq!(mask_token("ghp_abcdefghijkl"), "****ijkl");
+        assert_eq!(mask_token("short"), "****");
+        assert!(!mask_token("ghp_abcdefghijkl").contains("ghp_"));
+    }
+
+    #[test]
+    fn test_plan_delete_oc_ends_with_delete() {
+        let cli = <Cli as clap::Parser>::parse_from(["hcp", "delete", "oc", "oc-123"]);
+        let Command::Delete {
+            resource: DeleteResource::Oc(args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        let calls = plan_delete_oc(args, None);
+        let last = calls.last().unwrap();
+        assert_eq!(last.method, "DELETE");
+        assert_eq!(last.path, "/oauth-clients/:oc_id");
+    }
+
+    #[test]
+    fn test_plan_create_oc_uses_resolved_org() {
+        let calls = plan_create_oc("acme");
+        assert_eq!(calls[0].path, "/organizations/acme/oauth-clients");
+    }
+}
@@ -4,5 +4,5 @@ mod api;
 mod commands;
 mod models;
 
-pub use commands::run_oc_command;
+pub use commands::{run_create_oc_command, run_delete_oc_command, run_oc_command};
 pub use models::{OAuthClient, OAuthClientAttributes, OAuthToken};
@@ -1,6 +1,6 @@
 //! OAuth Client data models
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::hcp::traits::TfeResource;
 
@@ -128,6 +128,104 @@ impl OAuthClient {
     }
 }
 
+/// Response wrapper for a single OAuth client
+#[derive(Deserialize, Debug)]
+pub struct OAuthClientResponse {
+    pub data: OAuthClient,
+}
+
+/// Request body for creating an OAuth client
+///
+/// Deliberately not `Debug`: it carries the VCS token.
+#[derive(Serialize)]
+pub struct CreateOAuthClientRequest {
+    pub data: CreateOAuthClientData,
+}
+
+/// Data part of create OAuth client request
+#[derive(Serialize)]
+pub struct CreateOAuthClientData {
+    #[serde(rename = "type")]
+    pub data_type: String,
+    pub attributes: CreateOAuthClientAttributes,
+}
+
+/// Attributes for create OAuth client request
+#[derive(Serialize)]
+pub struct CreateOAuthClientAttributes {
+    #[serde(rename = "service-provider")]
+    pub service_provider: String,
+    #[serde(rename = "api-url")]
+    pub api_url: String,
+    #[serde(rename = "http-url")]
+    pub http_url: String,
+    #[serde(rename = "oauth-token-string")]
+    pub oauth_token_string: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+impl CreateOAuthClientRequest {
+    /// Create a new OAuth client request
+    pub fn new(
+        service_provider: &str,
+        api_url: &str,
+        http_url: &str,
+        oauth_token_string: &str,
+        name: Option<&str>,
+    ) -> Self {
+        Self {
+            data: CreateOAuthClientData {
+                data_type: "oauth-clients".to_string(),
+                attributes: CreateOAuthClientAttributes {
+                    service_provider: service_provider.to_string(),
+                    api_url: api_url.to_string(),
+                    http_url: http_url.to_string(),
+                    oauth_token_string: oauth_token_string.to_string(),
+                    name: name.map(String::from),
+                },
+            },
+        }
+    }
+}
+
+// ============================================================================
+// Workspace VCS view (for finding workspaces connected through an OAuth client)
+// ============================================================================
+
+/// Minimal workspace record carrying only the VCS connection
+#[derive(Deserialize, Debug, Clone)]
+pub struct VcsWorkspace {
+    pub id: String,
+    pub attributes: VcsWorkspaceAttributes,
+}
+
+/// Workspace attributes relevant to VCS connections
+#[derive(Deserialize, Debug, Clone)]
+pub struct VcsWorkspaceAttributes {
+    pub name: String,
+    #[serde(rename = "vcs-repo")]
+    pub vcs_repo: Option<VcsRepo>,
+}
+
+/// VCS repository settings of a workspace
+#[derive(Deserialize, Debug, Clone)]
+pub struct VcsRepo {
+    #[serde(rename = "oauth-token-id")]
+    pub oauth_token_id: Option<String>,
+}
+
+impl VcsWorkspace {
+    /// Check if this workspace is connected through one of the given OAuth tokens
+    pub fn uses_any_token(&self, token_ids: &[&str]) -> bool {
+        self.attributes
+            .vcs_repo
+            .as_ref()
+            .and_then(|r| r.oauth_token_id.as_deref())
+            .is_some_and(|id| token_ids.contains(&id))
+    }
+}
+
 // ============================================================================
 // OAuth Token models (for fetching from organization's oauth-tokens endpoint)
 // ============================================================================
@@ -3,21 +3,26 @@
 use log::debug;
 
 use crate::config::api;
-use crate::error::{Result, TfeError};
-use crate::hcp::TfeClient;
+use crate::error::{ApiErrorDetail, Result, TfeError};
+use crate::hcp::{PageRequest, TfeClient};
 
 use super::models::{InviteUserRequest, OrganizationMembership, OrganizationMembershipResponse};
 use crate::hcp::traits::ApiListResponse;
 
 impl TfeClient {
     /// Get all organization memberships for an organization (with pagination)
-    pub async fn get_org_memberships(&self, org: &str) -> Result<Vec<OrganizationMembership>> {
+    pub async fn get_org_memberships(
+        &self,
+        org: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<OrganizationMembership>> {
         let path = format!("/{}/{}/organization-memberships", api::ORGANIZATIONS, org);
         let error_context = format!("organization memberships for '{}'", org);
 
-        self.fetch_all_pages::<OrganizationMembership, ApiListResponse<OrganizationMembership>>(
+        self.fetch_pages::<OrganizationMembership, ApiListResponse<OrganizationMembership>>(
             &path,
             &error_context,
+            page,
         )
         .await
     }
@@ -38,7 +43,7 @@ impl TfeClient {
 
         debug!("Looking up membership for {} in {}", email, org);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -49,6 +54,7 @@ impl TfeClient {
             status => Err(TfeError::Api {
                 status,
                 message: format!("Failed to lookup membership for '{}' in '{}'", email, org),
+                details: Vec::new(),
             }),
         }
     }
@@ -76,6 +82,7 @@ impl TfeClient {
                     "User '{}' already has membership in '{}' (status: {}, id: {})",
                     email, org, status, membership.id
                 ),
+                details: Vec::new(),
             });
         }
 
@@ -93,7 +100,7 @@ impl TfeClient {
             _ => InviteUserRequest::new(email),
         };
 
-        let response = self.post(&url).json(&request).send().await?;
+        let response = self.send(self.post(&url).json(&request)).await?;
 
         match response.status().as_u16() {
             200 | 201 => {
@@ -107,28 +114,29 @@ impl TfeClient {
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Organization '{}' not found", org),
+                details: Vec::new(),
             }),
             422 => {
                 // Try to parse error message from response
-                let error_body: serde_json::Value =
-                    response.json().await.unwrap_or(serde_json::json!({}));
-                let error_msg = error_body["errors"][0]["detail"]
-                    .as_str()
+                let body = response.text().await.unwrap_or_default();
+                let details = ApiErrorDetail::parse_all(&body);
+                let error_msg = details
+                    .first()
+                    .and_then(|d| d.detail.as_deref())
                     .unwrap_or("Validation error");
                 Err(TfeError::Api {
                     status: 422,
                     message: format!("Cannot invite '{}': {}", email, error_msg),
+                    details,
                 })
             }
             status => {
                 let error_body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!(
-                        "Failed to invite user '{}' to '{}': {}",
-                        email, org, error_body
-                    ),
-                })
+                    format!("Failed to invite user '{}' to '{}'", email, org),
+                    &error_body,
+                ))
             }
         }
     }
@@ -143,7 +151,7 @@ impl TfeClient {
 
         debug!("Deleting organization membership: {}", membership_id);
 
-        let response = self.delete(&url).send().await?;
+        let response = self.send(self.delete(&url)).await?;
 
         match response.status().as_u16() {
             200 | 204 => {
@@ -153,10 +161,12 @@ impl TfeClient {
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Membership '{}' not found", membership_id),
+                details: Vec::new(),
             }),
             status => Err(TfeError::Api {
                 status,
                 message: format!("Failed to delete membership '{}'", membership_id),
+                details: Vec::new(),
             }),
         }
     }
@@ -206,7 +216,7 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let memberships = client.get_org_memberships("my-org").await.unwrap();
+        let memberships = client.get_org_memberships("my-org", None).await.unwrap();
 
         assert_eq!(memberships.len(), 2);
         assert_eq!(memberships[0].email(), "user1@example.com");
@@ -496,7 +506,7 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let memberships = client.get_org_memberships("my-org").await.unwrap();
+        let memberships = client.get_org_memberships("my-org", None).await.unwrap();
 
         assert_eq!(memberships.len(), 2);
         assert_eq!(memberships[0].id, "ou-page1");
@@ -3,9 +3,11 @@
 use log::debug;
 
 use crate::cli::OutputFormat;
-use crate::hcp::helpers::fetch_from_organizations;
+use crate::hcp::explain::{call, org_listing, org_path, print_explain, PlannedCall};
+use crate::hcp::helpers::{collect_org_results, fetch_from_organizations, OrgErrors};
 use crate::hcp::TfeClient;
-use crate::output::org_memberships::output_org_memberships;
+use crate::output::apply_limit;
+use crate::output::org_memberships::{membership_json, output_org_memberships};
 use crate::ui::{confirm_action, create_spinner, finish_spinner};
 use crate::{Cli, Command, DeleteOrgMemberArgs, GetResource, InviteArgs};
 
@@ -22,16 +24,27 @@ pub async fn run_org_member_command(
     };
 
     let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        print_explain(&org_listing(
+            effective_org.as_ref(),
+            "organization-memberships",
+        ));
+        return Ok(());
+    }
 
     // If ID is specified, get single membership or filter by email
     if let Some(id_or_email) = &args.id {
         return get_single_org_member(client, cli, id_or_email, effective_org.as_ref()).await;
     }
 
+    let mut org_errors = OrgErrors::default();
     let memberships = if let Some(org) = &effective_org {
         // Single org
         let spinner = create_spinner(&format!("Fetching members from '{}'...", org), cli.batch);
-        let result = client.get_org_memberships(org).await?;
+        let result = client
+            .with_progress(spinner.as_ref())
+            .get_org_memberships(org, cli.page_request())
+            .await?;
         finish_spinner(spinner);
         result
             .into_iter()
@@ -48,8 +61,12 @@ pub async fn run_org_member_command(
             cli.batch,
         );
 
-        let results = fetch_from_organizations(orgs, |org| async move {
-            match client.get_org_memberships(&org).await {
+        let progress_client = &client.with_progress(spinner.as_ref());
+        let results = fetch_from_organizations(orgs, client.concurrency(), |org| async move {
+            match progress_client
+                .get_org_memberships(&org, cli.page_request())
+                .await
+            {
                 Ok(members) => {
                     let with_org: Vec<_> = members.into_iter().map(|m| (org.clone(), m)).collect();
                     Ok(with_org)
@@ -61,16 +78,14 @@ pub async fn run_org_member_command(
 
         finish_spinner(spinner);
 
-        // Collect all successful results, ignore errors
-        results
-            .into_iter()
-            .filter_map(|r| r.ok())
-            .flatten()
-            .collect()
+        let (batches, errors) = collect_org_results(results, "organization members");
+        errors.check_strict(cli.strict, "organization members")?;
+        org_errors = errors;
+        batches.into_iter().flatten().collect()
     };
 
     // Apply filters
-    let filtered: Vec<_> = memberships
+    let mut filtered: Vec<_> = memberships
         .into_iter()
         .filter(|(_, m)| {
             // Filter by email
@@ -91,8 +106,10 @@ pub async fn run_org_member_command(
         })
         .collect();
 
+    apply_limit(&mut filtered, cli);
     output_org_memberships(&filtered, args, cli.no_header)?;
 
+    org_errors.print_report("organization members");
     Ok(())
 }
 
@@ -123,10 +140,11 @@ async fn get_single_org_member(
     if id_or_email.starts_with("ou-") {
         let target_id = id_or_email.to_string();
 
-        let results = fetch_from_organizations(orgs, |org| {
+        let progress_client = &client.with_progress(spinner.as_ref());
+        let results = fetch_from_organizations(orgs, client.concurrency(), |org| {
             let target = target_id.clone();
             async move {
-                match client.get_org_memberships(&org).await {
+                match progress_client.get_org_memberships(&org, None).await {
                     Ok(members) => {
                         if let Some(m) = members.into_iter().find(|m| m.id == target) {
                             Ok(Some((org, m)))
@@ -155,7 +173,7 @@ async fn get_single_org_member(
     // It's an email - search by email in parallel
     let email = id_or_email.to_string();
 
-    let results = fetch_from_organizations(orgs, |org| {
+    let results = fetch_from_organizations(orgs, client.concurrency(), |org| {
         let email_ref = email.clone();
         async move {
             match client.get_org_membership_by_email(&org, &email_ref).await {
@@ -197,29 +215,11 @@ fn output_single_membership(
     cli: &Cli,
 ) -> std::result::Result<(), Box<dyn std::error::Error>> {
     match args.output {
-        OutputFormat::Json => {
-            let output = serde_json::json!({
-                "id": m.id,
-                "organization": org,
-                "email": m.email(),
-                "status": m.status(),
-                "created_at": m.created_at(),
-                "teams": m.team_ids()
-            });
-            println!("{}", serde_json::to_string_pretty(&output)?);
-        }
-        OutputFormat::Yaml => {
-            let output = serde_json::json!({
-                "id": m.id,
-                "organization": org,
-                "email": m.email(),
-                "status": m.status(),
-                "created_at": m.created_at(),
-                "teams": m.team_ids()
-            });
-            println!("{}", serde_yml::to_string(&output)?);
+        OutputFormat::Json | OutputFormat::Yaml | OutputFormat::Ndjson | OutputFormat::Template => {
+            let raw = serde_json::json!({ "data": membership_json(org, m) });
+            crate::output::output_raw(&raw, &args.output);
         }
-        OutputFormat::Csv | OutputFormat::Table => {
+        OutputFormat::Csv | OutputFormat::Tsv | OutputFormat::Table | OutputFormat::Markdown => {
             let memberships = vec![(org.to_string(), m.clone())];
             output_org_memberships(&memberships, args, cli.no_header)?;
         }
@@ -246,6 +246,10 @@ pub async fn run_delete_org_member_command(
 
     let id_or_email = &args.id;
     let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        print_explain(&plan_delete_org_member(id_or_email, effective_org.as_ref()));
+        return Ok(());
+    }
 
     // Resolve membership ID from argument (can be ou-xxx ID or email)
     let membership_id = if id_or_email.contains('@') {
@@ -289,7 +293,7 @@ pub async fn run_delete_org_member_command(
         format!("Delete membership {}?", membership_id)
     };
 
-    if !confirm_action(&prompt, args.yes || cli.batch)? {
+    if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
         println!("Cancelled");
         return Ok(());
     }
@@ -320,6 +324,10 @@ pub async fn run_invite_command(
     let Command::Invite(args) = &cli.command else {
         unreachable!()
     };
+    if cli.explain {
+        print_explain(&plan_invite(args));
+        return Ok(());
+    }
 
     let org = &args.org;
     let email = &args.email;
@@ -366,3 +374,50 @@ pub async fn run_invite_command(
 
     Ok(())
 }
+
+/// API calls made by `delete org-member` for the effective organization
+fn plan_delete_org_member(id_or_email: &str, org: Option<&String>) -> Vec<PlannedCall> {
+    let mut calls = vec![];
+    if id_or_email.contains('@') {
+        calls.push(call(
+            "GET",
+            format!(
+                "/organizations/{}/organization-memberships?filter[email]={}",
+                org_path(org),
+                id_or_email
+            ),
+            "resolve membership",
+        ));
+    }
+    calls.push(call(
+        "DELETE",
+        "/organization-memberships/:membership_id",
+        "remove member",
+    ));
+    calls
+}
+
+/// API calls made by `invite`
+fn plan_invite(args: &InviteArgs) -> Vec<PlannedCall> {
+    let mut calls = vec![call(
+        "GET",
+        format!(
+            "/organizations/{}/organization-memberships?filter[email]={}",
+            args.org, args.email
+        ),
+        "check for existing membership",
+    )];
+    if args.teams.is_some() {
+        calls.push(call(
+            "GET",
+            format!("/organizations/{}/teams?filter[names]=:team", args.org),
+            "per team name",
+        ));
+    }
+    calls.push(call(
+        "POST",
+        format!("/organizations/{}/organization-memberships", args.org),
+        "invite",
+    ));
+    calls
+}
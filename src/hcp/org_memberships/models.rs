@@ -25,6 +25,9 @@ pub struct OrganizationMembershipAttributes {
     pub status: Option<String>,
     #[serde(rename = "created-at")]
     pub created_at: Option<String>,
+    /// Remaining API attributes, kept so JSON/YAML output carries the raw set
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Organization membership relationships
@@ -96,6 +99,11 @@ impl OrganizationMembership {
             .map(|t| t.data.iter().map(|d| d.id.as_str()).collect())
             .unwrap_or_default()
     }
+
+    /// Number of teams the member belongs to
+    pub fn team_count(&self) -> usize {
+        self.team_ids().len()
+    }
 }
 
 /// Request payload for inviting a user to an organization
@@ -219,6 +227,26 @@ mod tests {
         assert_eq!(membership.status(), "active");
         assert_eq!(membership.user_id(), Some("user-xyz"));
         assert_eq!(membership.team_ids(), vec!["team-1", "team-2"]);
+        assert_eq!(membership.team_count(), 2);
+    }
+
+    #[test]
+    fn test_unmodeled_attributes_round_trip() {
+        let json = serde_json::json!({
+            "id": "ou-abc123",
+            "attributes": {
+                "email": "user@example.com",
+                "status": "invited",
+                "user-email": "user@example.com"
+            }
+        });
+
+        let membership: OrganizationMembership = serde_json::from_value(json).unwrap();
+        let attrs = serde_json::to_value(&membership.attributes).unwrap();
+
+        assert_eq!(attrs["status"], "invited");
+        assert_eq!(attrs["user-email"], "user@example.com");
+        assert_eq!(membership.team_count(), 0);
     }
 
     #[test]
@@ -21,7 +21,7 @@ impl TfeClient {
         let url = format!("{}/{}", self.base_url(), api::ORGANIZATIONS);
         debug!("Fetching organizations from: {}", url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         let orgs_response: ApiListResponse<Organization> =
             self.parse_api_response(response, "organizations").await?;
@@ -43,7 +43,7 @@ impl TfeClient {
         let url = format!("{}/{}/{}", self.base_url(), api::ORGANIZATIONS, name_or_id);
         debug!("Fetching organization by name: {}", url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -52,6 +52,7 @@ impl TfeClient {
                     serde_json::from_value(raw["data"].clone()).map_err(|e| TfeError::Api {
                         status: 200,
                         message: format!("Failed to parse organization: {}", e),
+                        details: Vec::new(),
                     })?;
                 Ok(Some((org, raw)))
             }
@@ -70,6 +71,7 @@ impl TfeClient {
             status => Err(TfeError::Api {
                 status,
                 message: format!("Failed to fetch organization '{}'", name_or_id),
+                details: Vec::new(),
             }),
         }
     }
@@ -85,7 +87,7 @@ impl TfeClient {
         if let Some(org) = orgs.into_iter().find(|o| o.external_id() == external_id) {
             // Fetch full details by name to get raw JSON
             let url = format!("{}/{}/{}", self.base_url(), api::ORGANIZATIONS, org.id);
-            let response = self.get(&url).send().await?;
+            let response = self.send_get(&url).await?;
 
             if response.status().is_success() {
                 let raw: serde_json::Value = response.json().await?;
@@ -93,6 +95,7 @@ impl TfeClient {
                     serde_json::from_value(raw["data"].clone()).map_err(|e| TfeError::Api {
                         status: 200,
                         message: format!("Failed to parse organization: {}", e),
+                        details: Vec::new(),
                     })?;
                 return Ok(Some((org, raw)));
             }
@@ -5,10 +5,11 @@ use log::debug;
 
 use crate::cli::OutputFormat;
 use crate::error::Result;
+use crate::hcp::explain::{call, print_explain, PlannedCall};
 use crate::hcp::oauth_clients::OAuthToken;
 use crate::hcp::traits::TfeResource;
 use crate::hcp::TfeClient;
-use crate::output::{output_organizations, output_raw};
+use crate::output::{apply_limit, output_organizations, output_raw};
 use crate::ui::{create_spinner, finish_spinner};
 use crate::{Cli, Command, GetResource};
 
@@ -55,6 +56,10 @@ pub async fn run_org_command(
     else {
         unreachable!()
     };
+    if cli.explain {
+        print_explain(&plan_get_org(args.name.as_ref()));
+        return Ok(());
+    }
 
     debug!("Fetching organizations");
 
@@ -62,7 +67,10 @@ pub async fn run_org_command(
 
     // If NAME is specified and output is JSON/YAML, use direct API call for raw output
     if let Some(name) = &args.name {
-        if matches!(args.output, OutputFormat::Json | OutputFormat::Yaml) {
+        if matches!(
+            args.output,
+            OutputFormat::Json | OutputFormat::Yaml | OutputFormat::Ndjson | OutputFormat::Template
+        ) {
             // Direct API call - returns raw JSON
             match client.get_organization(name).await? {
                 Some((_org, raw)) => {
@@ -128,7 +136,7 @@ pub async fn run_org_command(
     finish_spinner(spinner);
 
     // Combine organizations with their tokens
-    let orgs_with_tokens: Vec<OrganizationWithTokens> = organizations
+    let mut orgs_with_tokens: Vec<OrganizationWithTokens> = organizations
         .into_iter()
         .map(|org| {
             let tokens = token_map.remove(org.name()).unwrap_or_default();
@@ -139,6 +147,26 @@ pub async fn run_org_command(
         })
         .collect();
 
+    apply_limit(&mut orgs_with_tokens, cli);
     output_organizations(&orgs_with_tokens, cli);
     Ok(())
 }
+
+/// API calls made by `get org`
+fn plan_get_org(name: Option<&String>) -> Vec<PlannedCall> {
+    match name {
+        Some(name) => vec![call(
+            "GET",
+            format!("/organizations/{}", name),
+            "organization",
+        )],
+        None => vec![
+            call("GET", "/organizations", "list organizations"),
+            call(
+                "GET",
+                "/organizations/:org/oauth-tokens",
+                "per organization",
+            ),
+        ],
+    }
+}
@@ -5,4 +5,4 @@ mod commands;
 mod models;
 
 pub use commands::{resolve_organizations, run_org_command, OrganizationWithTokens};
-pub use models::{Organization, OrganizationAttributes};
+pub use models::{Organization, OrganizationAttributes, OrganizationSettings};
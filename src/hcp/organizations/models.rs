@@ -25,6 +25,22 @@ pub struct OrganizationAttributes {
     pub created_at: Option<String>,
     #[serde(rename = "saml-enabled")]
     pub saml_enabled: Option<bool>,
+    /// Org-wide defaults and policies (shown in single-org detail)
+    #[serde(flatten)]
+    pub settings: OrganizationSettings,
+}
+
+/// Organization-wide defaults and policies commonly audited
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct OrganizationSettings {
+    #[serde(rename = "default-execution-mode")]
+    pub default_execution_mode: Option<String>,
+    #[serde(rename = "cost-estimation-enabled")]
+    pub cost_estimation_enabled: Option<bool>,
+    #[serde(rename = "collaborator-auth-policy")]
+    pub collaborator_auth_policy: Option<String>,
+    #[serde(rename = "two-factor-conformant")]
+    pub two_factor_conformant: Option<bool>,
 }
 
 /// Organization relationships from TFE API
@@ -95,6 +111,14 @@ impl Organization {
             .unwrap_or(false)
     }
 
+    /// Get org-wide settings (defaults to all-unset when attributes are missing)
+    pub fn settings(&self) -> OrganizationSettings {
+        self.attributes
+            .as_ref()
+            .map(|a| a.settings.clone())
+            .unwrap_or_default()
+    }
+
     /// Get default project ID from relationships
     pub fn default_project_id(&self) -> Option<&str> {
         self.relationships
@@ -150,6 +174,7 @@ mod tests {
                 external_id: Some("org-123".to_string()),
                 created_at: Some("2025-01-01T00:00:00Z".to_string()),
                 saml_enabled: Some(true),
+                settings: Default::default(),
             }),
             relationships: Some(OrganizationRelationships {
                 default_project: Some(RelationshipData {
@@ -167,6 +192,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_organization_settings_deserialization() {
+        let json = r#"{
+            "id": "my-org",
+            "attributes": {
+                "name": "my-org",
+                "default-execution-mode": "remote",
+                "cost-estimation-enabled": true,
+                "collaborator-auth-policy": "two_factor_mandatory",
+                "two-factor-conformant": false
+            }
+        }"#;
+        let org: Organization = serde_json::from_str(json).unwrap();
+        let settings = org.settings();
+        assert_eq!(settings.default_execution_mode.as_deref(), Some("remote"));
+        assert_eq!(settings.cost_estimation_enabled, Some(true));
+        assert_eq!(
+            settings.collaborator_auth_policy.as_deref(),
+            Some("two_factor_mandatory")
+        );
+        assert_eq!(settings.two_factor_conformant, Some(false));
+    }
+
+    #[test]
+    fn test_organization_settings_missing_attributes() {
+        let org: Organization = serde_json::from_str(r#"{"id": "my-org"}"#).unwrap();
+        assert_eq!(org.settings(), OrganizationSettings::default());
+    }
+
     #[test]
     fn test_organization_name() {
         let org = create_test_org();
@@ -0,0 +1,83 @@
+//! Policy set API operations
+
+use crate::config::api;
+use crate::error::Result;
+use crate::hcp::traits::ApiListResponse;
+use crate::hcp::{PageRequest, TfeClient};
+
+use super::models::PolicySet;
+
+impl TfeClient {
+    /// Get all policy sets of an organization (with pagination)
+    pub async fn get_policy_sets(
+        &self,
+        org: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<PolicySet>> {
+        let path = format!("/{}/{}/{}", api::ORGANIZATIONS, org, api::POLICY_SETS);
+        let error_context = format!("policy sets for organization '{}'", org);
+
+        self.fetch_pages::<PolicySet, ApiListResponse<PolicySet>>(&path, &error_context, page)
+            .await
+    }
+
+    /// Get a policy set by ID, including its workspace and project relationships
+    pub async fn get_policy_set(
+        &self,
+        policy_set_id: &str,
+    ) -> Result<Option<(PolicySet, serde_json::Value)>> {
+        let path = format!("/{}/{}", api::POLICY_SETS, policy_set_id);
+        self.fetch_resource_by_path::<PolicySet>(&path, &format!("policy set '{}'", policy_set_id))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_policy_sets() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/policy-sets"))
+            .and(query_param("page[number]", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"id": "polset-1", "attributes": {"name": "cis", "kind": "opa"}},
+                    {"id": "polset-2", "attributes": {"name": "cost", "kind": "sentinel"}}
+                ],
+                "meta": {"pagination": {"current-page": 1, "total-pages": 1, "total-count": 2}}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let sets = client.get_policy_sets("my-org", None).await.unwrap();
+
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets[0].kind(), "opa");
+        assert_eq!(sets[1].name(), "cost");
+    }
+
+    #[tokio::test]
+    async fn test_get_policy_set_not_found() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/policy-sets/polset-missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        assert!(client
+            .get_policy_set("polset-missing")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
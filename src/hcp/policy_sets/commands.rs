@@ -0,0 +1,144 @@
+//! Policy set command handlers
+
+use log::debug;
+
+use crate::cli::PolicySetArgs;
+use crate::hcp::explain::{call, print_explain, PlannedCall};
+use crate::hcp::TfeClient;
+use crate::output::{apply_limit, output_policy_sets};
+use crate::ui::{create_spinner, finish_spinner};
+use crate::{Cli, Command, GetResource};
+
+use super::models::PolicySet;
+
+/// Run the policy set list/get command
+pub async fn run_policy_set_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::PolicySet(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let effective_org = client.effective_org(args.org.as_ref());
+    let org = effective_org
+        .as_ref()
+        .ok_or("Organization is required (--org)")?;
+    if cli.explain {
+        print_explain(&plan_get_policy_set(args, org));
+        return Ok(());
+    }
+
+    if let Some(name) = &args.name {
+        let set = get_single_policy_set(client, cli, org, name).await?;
+        output_policy_sets(&[set], cli);
+        return Ok(());
+    }
+
+    let spinner = create_spinner(&format!("Fetching policy sets for '{}'...", org), cli.batch);
+    let result = client
+        .with_progress(spinner.as_ref())
+        .get_policy_sets(org, cli.page_request())
+        .await;
+    finish_spinner(spinner);
+    let mut sets = result?;
+
+    if let Some(filter) = &args.filter {
+        let filter_lower = filter.to_lowercase();
+        sets.retain(|ps| ps.name().to_lowercase().contains(&filter_lower));
+        debug!(
+            "Filtered to {} policy sets matching '{}'",
+            sets.len(),
+            filter
+        );
+    }
+
+    if sets.is_empty() {
+        if args.filter.is_some() {
+            eprintln!("No policy sets found matching filter");
+        } else {
+            eprintln!("No policy sets found");
+        }
+        return Ok(());
+    }
+
+    sets.sort_by(|a, b| a.name().cmp(b.name()));
+    apply_limit(&mut sets, cli);
+    output_policy_sets(&sets, cli);
+    Ok(())
+}
+
+/// Fetch one policy set by ID (polset-xxx) or name, with its relationships
+async fn get_single_policy_set(
+    client: &TfeClient,
+    cli: &Cli,
+    org: &str,
+    target: &str,
+) -> Result<PolicySet, Box<dyn std::error::Error>> {
+    let spinner = create_spinner(&format!("Fetching policy set '{}'...", target), cli.batch);
+
+    let set_id = if target.starts_with("polset-") {
+        target.to_string()
+    } else {
+        let sets = client
+            .with_progress(spinner.as_ref())
+            .get_policy_sets(org, None)
+            .await;
+        let found = sets.map(|list| {
+            list.into_iter()
+                .find(|ps| ps.name().eq_ignore_ascii_case(target))
+                .map(|ps| ps.id)
+        });
+        match found {
+            Ok(Some(id)) => id,
+            Ok(None) => {
+                finish_spinner(spinner);
+                return Err(format!(
+                    "Policy set '{}' not found in organization '{}'",
+                    target, org
+                )
+                .into());
+            }
+            Err(e) => {
+                finish_spinner(spinner);
+                return Err(e.into());
+            }
+        }
+    };
+
+    let result = client.get_policy_set(&set_id).await;
+    finish_spinner(spinner);
+    match result? {
+        Some((set, _raw)) => Ok(set),
+        None => Err(format!("Policy set '{}' not found", target).into()),
+    }
+}
+
+/// API calls made by `get policy-set` in `org`
+fn plan_get_policy_set(args: &PolicySetArgs, org: &str) -> Vec<PlannedCall> {
+    match &args.name {
+        Some(id) if id.starts_with("polset-") => {
+            vec![call("GET", format!("/policy-sets/{}", id), "policy set")]
+        }
+        Some(_) => vec![
+            call(
+                "GET",
+                format!("/organizations/{}/policy-sets", org),
+                "find policy set by name",
+            ),
+            call(
+                "GET",
+                "/policy-sets/:polset_id",
+                "policy set with attachments",
+            ),
+        ],
+        None => vec![call(
+            "GET",
+            format!("/organizations/{}/policy-sets", org),
+            "paginated",
+        )],
+    }
+}
@@ -0,0 +1,8 @@
+//! Policy sets module - list Sentinel/OPA policy sets and their attachments
+
+mod api;
+mod commands;
+mod models;
+
+pub use commands::run_policy_set_command;
+pub use models::{PolicySet, PolicySetAttributes};
@@ -0,0 +1,173 @@
+//! Policy set data models
+
+use serde::{Deserialize, Serialize};
+
+use crate::hcp::traits::TfeResource;
+
+/// Policy set from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PolicySet {
+    pub id: String,
+    pub attributes: PolicySetAttributes,
+    pub relationships: Option<PolicySetRelationships>,
+}
+
+/// Policy set attributes from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PolicySetAttributes {
+    pub name: String,
+    pub description: Option<String>,
+    /// Policy framework: "sentinel" or "opa"
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub global: bool,
+    pub overridable: Option<bool>,
+    #[serde(rename = "workspace-count")]
+    pub workspace_count: Option<u32>,
+    #[serde(rename = "project-count")]
+    pub project_count: Option<u32>,
+    #[serde(rename = "policy-count")]
+    pub policy_count: Option<u32>,
+}
+
+/// Policy set relationships (attached workspaces and projects)
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PolicySetRelationships {
+    pub workspaces: Option<PolicySetRelationship>,
+    pub projects: Option<PolicySetRelationship>,
+}
+
+/// To-many relationship of a policy set
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct PolicySetRelationship {
+    #[serde(default)]
+    pub data: Vec<PolicySetRef>,
+}
+
+/// Reference to a workspace or project attached to a policy set
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PolicySetRef {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub ref_type: Option<String>,
+}
+
+impl PolicySet {
+    /// Get policy set name
+    pub fn name(&self) -> &str {
+        &self.attributes.name
+    }
+
+    /// Policy framework, defaulting to "sentinel" (the only kind on older TFE)
+    pub fn kind(&self) -> &str {
+        self.attributes.kind.as_deref().unwrap_or("sentinel")
+    }
+
+    /// Number of workspaces the set is attached to
+    pub fn workspace_count(&self) -> u32 {
+        self.attributes
+            .workspace_count
+            .unwrap_or_else(|| self.workspace_ids().len() as u32)
+    }
+
+    /// Number of projects the set is attached to
+    pub fn project_count(&self) -> u32 {
+        self.attributes
+            .project_count
+            .unwrap_or_else(|| self.project_ids().len() as u32)
+    }
+
+    /// IDs of attached workspaces (from relationships)
+    pub fn workspace_ids(&self) -> Vec<&str> {
+        Self::ref_ids(
+            self.relationships
+                .as_ref()
+                .and_then(|r| r.workspaces.as_ref()),
+        )
+    }
+
+    /// IDs of attached projects (from relationships)
+    pub fn project_ids(&self) -> Vec<&str> {
+        Self::ref_ids(
+            self.relationships
+                .as_ref()
+                .and_then(|r| r.projects.as_ref()),
+        )
+    }
+
+    fn ref_ids(rel: Option<&PolicySetRelationship>) -> Vec<&str> {
+        rel.map(|r| r.data.iter().map(|d| d.id.as_str()).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl TfeResource for PolicySet {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        self.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_policy_set() {
+        let json = r#"{
+            "id": "polset-3yVQZvHzf5j3WRJ1",
+            "type": "policy-sets",
+            "attributes": {
+                "name": "cis-baseline",
+                "description": "CIS checks",
+                "kind": "opa",
+                "global": false,
+                "overridable": true,
+                "workspace-count": 2,
+                "project-count": 1,
+                "policy-count": 7
+            },
+            "relationships": {
+                "workspaces": {
+                    "data": [
+                        {"id": "ws-1", "type": "workspaces"},
+                        {"id": "ws-2", "type": "workspaces"}
+                    ]
+                },
+                "projects": {
+                    "data": [{"id": "prj-1", "type": "projects"}]
+                },
+                "policies": {"data": []}
+            }
+        }"#;
+
+        let set: PolicySet = serde_json::from_str(json).unwrap();
+        assert_eq!(set.id, "polset-3yVQZvHzf5j3WRJ1");
+        assert_eq!(set.name(), "cis-baseline");
+        assert_eq!(set.kind(), "opa");
+        assert_eq!(set.workspace_count(), 2);
+        assert_eq!(set.project_count(), 1);
+        assert_eq!(set.workspace_ids(), vec!["ws-1", "ws-2"]);
+        assert_eq!(set.project_ids(), vec!["prj-1"]);
+    }
+
+    #[test]
+    fn test_defaults_without_kind_and_counts() {
+        let json = r#"{
+            "id": "polset-abc",
+            "attributes": {"name": "legacy", "global": true},
+            "relationships": {
+                "workspaces": {"data": [{"id": "ws-1", "type": "workspaces"}]}
+            }
+        }"#;
+
+        let set: PolicySet = serde_json::from_str(json).unwrap();
+        assert!(set.attributes.global);
+        assert_eq!(set.kind(), "sentinel");
+        assert_eq!(set.workspace_count(), 1);
+        assert_eq!(set.project_count(), 0);
+    }
+}
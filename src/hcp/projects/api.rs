@@ -7,7 +7,7 @@ use crate::config::api;
 use crate::error::Result;
 use crate::hcp::traits::TfeResource;
 use crate::hcp::workspaces::WorkspaceQuery;
-use crate::hcp::TfeClient;
+use crate::hcp::{PageRequest, TfeClient};
 
 use super::models::Project;
 use crate::hcp::traits::ApiListResponse;
@@ -17,7 +17,12 @@ impl TfeClient {
     ///
     /// When `search` is provided, uses API's `q=` parameter for case-insensitive server-side filtering.
     /// This is more efficient than fetching all projects and filtering locally.
-    pub async fn get_projects(&self, org: &str, search: Option<&str>) -> Result<Vec<Project>> {
+    pub async fn get_projects(
+        &self,
+        org: &str,
+        search: Option<&str>,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<Project>> {
         // Build path with optional query param
         let mut path = format!("/{}/{}/{}", api::ORGANIZATIONS, org, api::PROJECTS);
 
@@ -27,7 +32,7 @@ impl TfeClient {
 
         let error_context = format!("projects for organization '{}' (search: {:?})", org, search);
 
-        self.fetch_all_pages::<Project, ApiListResponse<Project>>(&path, &error_context)
+        self.fetch_pages::<Project, ApiListResponse<Project>>(&path, &error_context, page)
             .await
     }
 
@@ -50,7 +55,7 @@ impl TfeClient {
         name: &str,
     ) -> Result<Option<(Project, serde_json::Value)>> {
         debug!("Fetching project by name: {}", name);
-        let projects = self.get_projects(org, None).await?;
+        let projects = self.get_projects(org, None, None).await?;
 
         // Find the project by name
         if let Some(project) = projects.into_iter().find(|p| p.matches(name)) {
@@ -61,6 +66,21 @@ impl TfeClient {
         }
     }
 
+    /// Count the workspaces of one project from the first page's pagination meta
+    ///
+    /// Falls back to listing the project's workspaces when the response
+    /// carries no pagination info.
+    pub async fn count_project_workspaces(&self, org: &str, project_id: &str) -> Result<usize> {
+        let query = WorkspaceQuery {
+            project_id: Some(project_id),
+            ..Default::default()
+        };
+        match self.prefetch_workspaces_pagination_info(org, query).await? {
+            Some(info) => Ok(info.total_count as usize),
+            None => Ok(self.get_workspaces(org, query).await?.len()),
+        }
+    }
+
     /// Count workspaces per project in an organization
     pub async fn count_workspaces_by_project(&self, org: &str) -> Result<HashMap<String, usize>> {
         let workspaces = self.get_workspaces(org, WorkspaceQuery::default()).await?;
@@ -116,7 +136,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_projects("my-org", None).await;
+        let result = client.get_projects("my-org", None, None).await;
 
         assert!(result.is_ok());
         let projects = result.unwrap();
@@ -141,7 +161,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_projects("my-org", Some("prod")).await;
+        let result = client.get_projects("my-org", Some("prod"), None).await;
 
         assert!(result.is_ok());
         let projects = result.unwrap();
@@ -162,7 +182,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_projects("my-org", None).await;
+        let result = client.get_projects("my-org", None, None).await;
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
@@ -179,7 +199,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_projects("my-org", None).await;
+        let result = client.get_projects("my-org", None, None).await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -227,4 +247,24 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().is_none());
     }
+
+    #[tokio::test]
+    async fn test_count_project_workspaces_uses_pagination_meta() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/workspaces"))
+            .and(query_param("filter[project][id]", "prj-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [],
+                "meta": {"pagination": {"current-page": 1, "total-pages": 2, "total-count": 142}}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let count = client.count_project_workspaces("my-org", "prj-1").await;
+        assert_eq!(count.unwrap(), 142);
+    }
 }
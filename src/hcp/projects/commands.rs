@@ -1,19 +1,120 @@
 //! Project command handlers
 
+use std::cmp::Ordering;
+use std::future::Future;
+
+use futures::stream::{self, StreamExt};
+use log::debug;
+
 use crate::cli::OutputFormat;
+use crate::hcp::explain::{call, org_listing, org_path, print_explain, PlannedCall};
 use crate::hcp::helpers::{collect_org_results, fetch_from_organizations, log_completion};
 use crate::hcp::organizations::resolve_organizations;
 use crate::hcp::projects::models::ProjectWorkspaces;
 use crate::hcp::traits::TfeResource;
 use crate::hcp::workspaces::WorkspaceQuery;
 use crate::hcp::TfeClient;
-use crate::output::{output_projects, output_raw};
+use crate::output::{apply_limit, output_projects, output_raw};
 use crate::ui::{create_spinner, finish_spinner, finish_spinner_with_status};
-use crate::{Cli, Command, GetResource, PrjSortField, Project};
+use crate::{Cli, Command, GetResource, PrjArgs, PrjSortField, Project};
 
 /// Project row for output: (org_name, project, workspace_info)
 pub type ProjectRow = (String, Project, ProjectWorkspaces);
 
+/// How much workspace data the `--with-ws*` flags need per project
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WsFetch {
+    /// No workspace info requested
+    None,
+    /// Only the workspace count (one small request per project)
+    Count,
+    /// Full workspace list (names, IDs, nested refs)
+    Full,
+}
+
+impl WsFetch {
+    pub(crate) fn from_args(args: &PrjArgs) -> Self {
+        if args.with_ws_names || args.with_ws_ids || args.with_ws_details {
+            return WsFetch::Full;
+        }
+        if !args.with_ws {
+            return WsFetch::None;
+        }
+        // Tables and CSV only show the count; JSON/YAML nest workspace refs
+        match args.output {
+            OutputFormat::Table
+            | OutputFormat::Markdown
+            | OutputFormat::Csv
+            | OutputFormat::Tsv => WsFetch::Count,
+            _ => WsFetch::Full,
+        }
+    }
+}
+
+/// Fetch the workspace info of one project as far as `mode` requires
+async fn fetch_project_workspaces(
+    client: &TfeClient,
+    org: &str,
+    project_id: &str,
+    mode: WsFetch,
+) -> crate::error::Result<ProjectWorkspaces> {
+    match mode {
+        WsFetch::None => Ok(ProjectWorkspaces::new()),
+        WsFetch::Count => client
+            .count_project_workspaces(org, project_id)
+            .await
+            .map(ProjectWorkspaces::from_count),
+        WsFetch::Full => {
+            let query = WorkspaceQuery {
+                project_id: Some(project_id),
+                ..Default::default()
+            };
+            client
+                .get_workspaces(org, query)
+                .await
+                .map(ProjectWorkspaces::from_workspaces)
+        }
+    }
+}
+
+/// Attach workspace info to each (org, project), at most `concurrency` in flight
+///
+/// A failed fetch leaves that project without workspace info instead of
+/// failing the listing; the number of failures is returned. Rows come back in
+/// completion order (callers sort afterwards).
+async fn enrich_projects<F, Fut>(
+    projects: Vec<(String, Project)>,
+    concurrency: usize,
+    fetch: F,
+) -> (Vec<ProjectRow>, usize)
+where
+    F: Fn(String, String) -> Fut,
+    Fut: Future<Output = crate::error::Result<ProjectWorkspaces>>,
+{
+    let fetch = &fetch;
+    let results: Vec<_> = stream::iter(projects.into_iter().map(|(org, project)| async move {
+        let result = fetch(org.clone(), project.id.clone()).await;
+        (org, project, result)
+    }))
+    .buffer_unordered(concurrency.max(1))
+    .collect()
+    .await;
+
+    let mut failures = 0;
+    let rows = results
+        .into_iter()
+        .map(|(org, project, result)| {
+            let ws_info = result.unwrap_or_else(|e| {
+                debug!("Workspaces of project '{}' unavailable: {}", project.id, e);
+                failures += 1;
+                ProjectWorkspaces::unavailable()
+            });
+            (org, project, ws_info)
+        })
+        .collect();
+    (rows, failures)
+}
+
 /// Run the project list command
 pub async fn run_prj_command(
     client: &TfeClient,
@@ -26,15 +127,18 @@ pub async fn run_prj_command(
         unreachable!()
     };
 
-    // Determine if we need workspace info (any of the flags)
-    let need_ws_info =
-        args.with_ws || args.with_ws_names || args.with_ws_ids || args.with_ws_details;
+    // Determine how much workspace info the flags need
+    let ws_fetch = WsFetch::from_args(args);
 
     let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        print_explain(&plan_get_prj(args, effective_org.as_ref()));
+        return Ok(());
+    }
 
     // If NAME is specified, get single project
     if let Some(name) = &args.name {
-        return get_single_project(client, cli, name, effective_org.as_ref(), need_ws_info).await;
+        return get_single_project(client, cli, name, effective_org.as_ref(), ws_fetch).await;
     }
 
     // Otherwise list all projects
@@ -51,92 +155,111 @@ pub async fn run_prj_command(
     // Fetch projects from all orgs in parallel
     // Note: filter is passed to API for server-side filtering (case-insensitive)
     let filter = args.filter.as_deref();
-    let results = fetch_from_organizations(organizations, |org| async move {
-        if need_ws_info {
-            // Fetch projects and workspaces IN PARALLEL
-            let (projects_result, workspaces_result) = tokio::join!(
-                client.get_projects(&org, filter),
-                client.get_workspaces(&org, WorkspaceQuery::default())
-            );
-
-            match (projects_result, workspaces_result) {
-                (Ok(projects), Ok(workspaces)) => {
-                    // Build workspace info per project
-                    let results: Vec<ProjectRow> = projects
-                        .into_iter()
-                        .map(|project| {
-                            let ws_list: Vec<_> = workspaces
-                                .iter()
-                                .filter(|ws| ws.project_id() == Some(&project.id))
-                                .cloned()
-                                .collect();
-                            (
-                                org.clone(),
-                                project,
-                                ProjectWorkspaces::from_workspaces(ws_list),
-                            )
-                        })
-                        .collect();
-                    Ok(results)
-                }
-                (Err(e), _) => Err((org, e)),
-                (_, Err(e)) => Err((org, e)),
-            }
-        } else {
-            // Just fetch projects, no workspace info
-            match client.get_projects(&org, filter).await {
-                Ok(projects) => {
-                    let results: Vec<ProjectRow> = projects
-                        .into_iter()
-                        .map(|project| (org.clone(), project, ProjectWorkspaces::new()))
-                        .collect();
-                    Ok(results)
-                }
-                Err(e) => Err((org, e)),
-            }
+    let progress_client = &client.with_progress(spinner.as_ref());
+    let results = fetch_from_organizations(organizations, client.concurrency(), |org| async move {
+        match progress_client
+            .get_projects(&org, filter, cli.page_request())
+            .await
+        {
+            Ok(projects) => Ok(projects
+                .into_iter()
+                .map(|project| (org.clone(), project))
+                .collect::<Vec<_>>()),
+            Err(e) => Err((org, e)),
         }
     })
     .await;
 
-    let (project_batches, had_errors) = collect_org_results(results, &spinner, "projects");
-    let mut all_projects: Vec<ProjectRow> = project_batches.into_iter().flatten().collect();
+    let (project_batches, org_errors) = collect_org_results(results, "projects");
+    let projects: Vec<(String, Project)> = project_batches.into_iter().flatten().collect();
 
-    finish_spinner_with_status(spinner, &all_projects, had_errors);
+    finish_spinner_with_status(spinner, &projects, org_errors.has_errors());
+    org_errors.check_strict(cli.strict, "projects")?;
+
+    // Workspace info is fetched per project, bounded across all organizations
+    let mut all_projects: Vec<ProjectRow> = if ws_fetch == WsFetch::None {
+        projects
+            .into_iter()
+            .map(|(org, project)| (org, project, ProjectWorkspaces::new()))
+            .collect()
+    } else {
+        let total = projects.len();
+        let spinner = create_spinner(
+            &format!("Fetching workspaces of {} project(s)...", total),
+            cli.batch,
+        );
+        let (rows, failures) = enrich_projects(
+            projects,
+            client.concurrency(),
+            |org, project_id| async move {
+                fetch_project_workspaces(client, &org, &project_id, ws_fetch).await
+            },
+        )
+        .await;
+        finish_spinner(spinner);
+
+        if failures > 0 {
+            if cli.strict {
+                return Err(format!(
+                    "Could not fetch workspaces for {} of {} project(s)",
+                    failures, total
+                )
+                .into());
+            }
+            eprintln!(
+                "Warning: could not fetch workspaces for {} of {} project(s); shown as '-'",
+                failures, total
+            );
+        }
+        rows
+    };
 
     // Sort projects
     let group_by_org = effective_org.is_none() && !args.no_group_org;
     all_projects.sort_by(|a, b| {
         if group_by_org {
             let org_cmp = a.0.cmp(&b.0);
-            if org_cmp != std::cmp::Ordering::Equal {
+            if org_cmp != Ordering::Equal {
                 return org_cmp;
             }
         }
-        match args.sort {
-            PrjSortField::Name => a.1.name().cmp(b.1.name()),
-            PrjSortField::Workspaces => a.2.count().cmp(&b.2.count()),
-        }
+        compare_projects(a, b, &args.sort)
     });
 
     if args.reverse {
         all_projects.reverse();
     }
 
+    apply_limit(&mut all_projects, cli);
+
     if !all_projects.is_empty() {
         output_projects(&all_projects, cli);
     }
 
-    log_completion(had_errors);
+    org_errors.print_report("projects");
+    log_completion(org_errors.has_errors());
     Ok(())
 }
 
+/// Compare project rows by each sort field in turn, falling through on ties
+fn compare_projects(a: &ProjectRow, b: &ProjectRow, fields: &[PrjSortField]) -> Ordering {
+    fields
+        .iter()
+        .map(|field| match field {
+            PrjSortField::Name => a.1.name().cmp(b.1.name()),
+            PrjSortField::Workspaces => a.2.count().cmp(&b.2.count()),
+        })
+        .find(|ord| ord.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
 /// Get a single project by name or ID
 async fn get_single_project(
     client: &TfeClient,
     cli: &Cli,
     name: &str,
     org: Option<&String>,
-    need_ws_info: bool,
+    ws_fetch: WsFetch,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let Command::Get {
         resource: GetResource::Prj(args),
@@ -154,7 +277,13 @@ async fn get_single_project(
                 finish_spinner(spinner);
 
                 // For JSON/YAML, return raw API response
-                if matches!(args.output, OutputFormat::Json | OutputFormat::Yaml) {
+                if matches!(
+                    args.output,
+                    OutputFormat::Json
+                        | OutputFormat::Yaml
+                        | OutputFormat::Ndjson
+                        | OutputFormat::Template
+                ) {
                     output_raw(&raw, &args.output);
                     return Ok(());
                 }
@@ -166,16 +295,18 @@ async fn get_single_project(
                     .to_string();
 
                 // For ID-based lookup, we can now get workspace info if org is known
-                let ws_info = if need_ws_info && org_name != "unknown" {
-                    let workspaces = client
-                        .get_workspaces(&org_name, WorkspaceQuery::default())
-                        .await
-                        .unwrap_or_default();
-                    let ws_list: Vec<_> = workspaces
-                        .into_iter()
-                        .filter(|ws| ws.project_id() == Some(&project.id))
-                        .collect();
-                    ProjectWorkspaces::from_workspaces(ws_list)
+                let ws_info = if org_name != "unknown" {
+                    match fetch_project_workspaces(client, &org_name, &project.id, ws_fetch).await {
+                        Ok(ws_info) => ws_info,
+                        Err(e) if cli.strict => return Err(e.into()),
+                        Err(e) => {
+                            eprintln!(
+                                "Warning: could not fetch workspaces for project '{}': {}; shown as '-'",
+                                project.id, e
+                            );
+                            ProjectWorkspaces::unavailable()
+                        }
+                    }
                 } else {
                     ProjectWorkspaces::new()
                 };
@@ -224,25 +355,18 @@ async fn get_single_project(
         finish_spinner(spinner);
 
         // For JSON/YAML, return raw API response
-        if matches!(args.output, OutputFormat::Json | OutputFormat::Yaml) {
+        if matches!(
+            args.output,
+            OutputFormat::Json | OutputFormat::Yaml | OutputFormat::Ndjson | OutputFormat::Template
+        ) {
             output_raw(&raw, &args.output);
             return Ok(());
         }
 
         // Get workspace info if requested
-        let ws_info = if need_ws_info {
-            let workspaces = client
-                .get_workspaces(&org_name, WorkspaceQuery::default())
-                .await
-                .unwrap_or_default();
-            let ws_list: Vec<_> = workspaces
-                .into_iter()
-                .filter(|ws| ws.project_id() == Some(&project.id))
-                .collect();
-            ProjectWorkspaces::from_workspaces(ws_list)
-        } else {
-            ProjectWorkspaces::new()
-        };
+        let ws_info = fetch_project_workspaces(client, &org_name, &project.id, ws_fetch)
+            .await
+            .unwrap_or_default();
 
         let all_projects = vec![(org_name, project, ws_info)];
         output_projects(&all_projects, cli);
@@ -252,3 +376,165 @@ async fn get_single_project(
     finish_spinner(spinner);
     Err(crate::hcp::helpers::not_found_in_orgs_error("Project", name, &organizations).into())
 }
+
+/// API calls made by `get prj` for the effective organization
+fn plan_get_prj(args: &PrjArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    let mut calls = org_listing(org, "projects");
+    let ws_path = format!(
+        "/organizations/{}/workspaces?filter[project][id]=:prj_id",
+        org_path(org)
+    );
+    match WsFetch::from_args(args) {
+        WsFetch::None => {}
+        WsFetch::Count => calls.push(call("GET", ws_path, "per project, count only")),
+        WsFetch::Full => calls.push(call("GET", ws_path, "per project, paginated")),
+    }
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::TfeError;
+    use crate::hcp::projects::models::ProjectAttributes;
+    use clap::Parser;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+    use std::time::Duration;
+
+    fn project(id: &str) -> (String, Project) {
+        (
+            "acme".to_string(),
+            Project {
+                id: id.to_string(),
+                project_type: Some("projects".to_string()),
+                attributes: ProjectAttributes {
+                    name: id.to_string(),
+                    description: None,
+                },
+            },
+        )
+    }
+
+    fn ws_fetch(args: &[&str]) -> WsFetch {
+        let cli = Cli::parse_from([&["hcp", "get", "prj"], args].concat());
+        let Command::Get {
+            resource: GetResource::Prj(prj_args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        WsFetch::from_args(prj_args)
+    }
+
+    #[test]
+    fn test_ws_fetch_only_lists_workspaces_when_needed() {
+        assert_eq!(ws_fetch(&[]), WsFetch::None);
+        assert_eq!(ws_fetch(&["--with-ws"]), WsFetch::Count);
+        assert_eq!(ws_fetch(&["--with-ws", "-o", "csv"]), WsFetch::Count);
+        assert_eq!(ws_fetch(&["--with-ws", "-o", "json"]), WsFetch::Full);
+        assert_eq!(ws_fetch(&["--with-ws-names"]), WsFetch::Full);
+        assert_eq!(ws_fetch(&["--with-ws-ids"]), WsFetch::Full);
+        assert_eq!(ws_fetch(&["--with-ws-details"]), WsFetch::Full);
+    }
+
+    #[tokio::test]
+    async fn test_enrich_projects_bounded_and_tolerates_failures() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let projects: Vec<_> = (0..8).map(|i| project(&format!("prj-{}", i))).collect();
+
+        let (rows, failures) = enrich_projects(projects, 3, |_org, project_id| {
+            let (in_flight, peak) = (&in_flight, &peak);
+            async move {
+                let now = in_flight.fetch_add(1, AtomicOrdering::SeqCst) + 1;
+                peak.fetch_max(now, AtomicOrdering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                in_flight.fetch_sub(1, AtomicOrdering::SeqCst);
+                if project_id == "prj-5" {
+                    Err(TfeError::Api {
+                        status: 500,
+                        message: "boom".to_string(),
+                        details: Vec::new(),
+                    })
+                } else {
+                    Ok(ProjectWorkspaces::from_count(2))
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(peak.load(AtomicOrdering::SeqCst), 3);
+        assert_eq!(rows.len(), 8);
+        assert_eq!(failures, 1);
+        let failed = rows.iter().find(|(_, p, _)| p.id == "prj-5").unwrap();
+        assert!(failed.2.failed);
+        assert_eq!(rows.iter().filter(|(_, _, ws)| ws.count() == 2).count(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_page_request_applies_to_project_listing_only() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/projects"))
+            .and(query_param("page[size]", "5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"id": "prj-1", "attributes": {"name": "one"}}],
+                "meta": {"pagination": {"current-page": 1, "total-pages": 1, "total-count": 1}}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/workspaces"))
+            .and(query_param("filter[project][id]", "prj-1"))
+            .and(query_param("page[size]", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [],
+                "meta": {"pagination": {"current-page": 1, "total-pages": 1, "total-count": 7}}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let cli = Cli::parse_from(["hcp", "--page-size", "5", "get", "prj", "--with-ws"]);
+        let client = TfeClient::test_client(&server.uri());
+        let projects = client
+            .get_projects("my-org", None, cli.page_request())
+            .await
+            .unwrap();
+        let ws = fetch_project_workspaces(&client, "my-org", &projects[0].id, WsFetch::Count)
+            .await
+            .unwrap();
+        assert_eq!(ws.count(), 7);
+    }
+
+    fn plan(args: &[&str], org: Option<&str>) -> Vec<PlannedCall> {
+        let cli = Cli::parse_from([&["hcp", "get", "prj"], args].concat());
+        let Command::Get {
+            resource: GetResource::Prj(prj_args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        plan_get_prj(prj_args, org.map(str::to_string).as_ref())
+    }
+
+    #[test]
+    fn test_plan_get_prj_with_ws() {
+        let calls = plan(&[], Some("acme"));
+        assert_eq!(calls.len(), 1);
+        let calls = plan(&["--with-ws"], Some("acme"));
+        assert_eq!(
+            calls[1].path,
+            "/organizations/acme/workspaces?filter[project][id]=:prj_id"
+        );
+        assert_eq!(calls[1].note, "per project, count only");
+        let calls = plan(&["--with-ws-names"], Some("acme"));
+        assert_eq!(calls[1].note, "per project, paginated");
+        let calls = plan(&[], None);
+        assert_eq!(calls[0].path, "/organizations");
+    }
+}
@@ -42,23 +42,44 @@ impl Project {
 pub struct ProjectWorkspaces {
     /// Full workspace objects
     pub workspaces: Vec<crate::hcp::workspaces::Workspace>,
+    /// Workspace count when only the count was fetched (no workspace objects)
+    pub total: Option<usize>,
+    /// The workspaces could not be fetched; neither count nor list is known
+    pub failed: bool,
 }
 
 impl ProjectWorkspaces {
     pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_workspaces(ws_list: Vec<crate::hcp::workspaces::Workspace>) -> Self {
+        Self {
+            workspaces: ws_list,
+            total: None,
+            failed: false,
+        }
+    }
+
+    /// Count-only info (`--with-ws` without names/IDs)
+    pub fn from_count(total: usize) -> Self {
         Self {
             workspaces: Vec::new(),
+            total: Some(total),
+            failed: false,
         }
     }
 
-    pub fn from_workspaces(ws_list: Vec<crate::hcp::workspaces::Workspace>) -> Self {
+    /// Workspaces that could not be fetched (rendered as "-" or null)
+    pub fn unavailable() -> Self {
         Self {
-            workspaces: ws_list,
+            failed: true,
+            ..Self::default()
         }
     }
 
     pub fn count(&self) -> usize {
-        self.workspaces.len()
+        self.total.unwrap_or(self.workspaces.len())
     }
 
     pub fn names(&self) -> Vec<&str> {
@@ -81,7 +102,7 @@ impl ProjectWorkspaces {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.workspaces.is_empty()
+        self.count() == 0
     }
 }
 
@@ -210,6 +231,24 @@ mod tests {
         assert_eq!(pw.count(), 0);
     }
 
+    #[test]
+    fn test_project_workspaces_from_count() {
+        let pw = ProjectWorkspaces::from_count(7);
+        assert_eq!(pw.count(), 7);
+        assert!(!pw.is_empty());
+        assert!(pw.names().is_empty());
+        assert!(ProjectWorkspaces::from_count(0).is_empty());
+    }
+
+    #[test]
+    fn test_project_workspaces_unavailable() {
+        let pw = ProjectWorkspaces::unavailable();
+        assert!(pw.failed);
+        assert_eq!(pw.total, None);
+        assert_eq!(pw.count(), 0);
+        assert!(!ProjectWorkspaces::from_count(0).failed);
+    }
+
     #[test]
     fn test_project_workspaces_from_workspaces() {
         use crate::hcp::workspaces::{Workspace, WorkspaceAttributes};
@@ -219,11 +258,16 @@ mod tests {
                 id: "ws-1".to_string(),
                 attributes: WorkspaceAttributes {
                     name: "workspace-1".to_string(),
+                    vcs_repo: None,
+                    working_directory: None,
+                    assessments_enabled: None,
                     execution_mode: None,
                     resource_count: None,
                     locked: None,
                     terraform_version: None,
                     updated_at: None,
+                    settings: Default::default(),
+                    state: None,
                 },
                 relationships: None,
             },
@@ -231,11 +275,16 @@ mod tests {
                 id: "ws-2".to_string(),
                 attributes: WorkspaceAttributes {
                     name: "workspace-2".to_string(),
+                    vcs_repo: None,
+                    working_directory: None,
+                    assessments_enabled: None,
                     execution_mode: None,
                     resource_count: None,
                     locked: None,
                     terraform_version: None,
                     updated_at: None,
+                    settings: Default::default(),
+                    state: None,
                 },
                 relationships: None,
             },
@@ -255,11 +304,16 @@ mod tests {
                 id: "ws-1".to_string(),
                 attributes: WorkspaceAttributes {
                     name: "alpha".to_string(),
+                    vcs_repo: None,
+                    working_directory: None,
+                    assessments_enabled: None,
                     execution_mode: None,
                     resource_count: None,
                     locked: None,
                     terraform_version: None,
                     updated_at: None,
+                    settings: Default::default(),
+                    state: None,
                 },
                 relationships: None,
             },
@@ -267,11 +321,16 @@ mod tests {
                 id: "ws-2".to_string(),
                 attributes: WorkspaceAttributes {
                     name: "beta".to_string(),
+                    vcs_repo: None,
+                    working_directory: None,
+                    assessments_enabled: None,
                     execution_mode: None,
                     resource_count: None,
                     locked: None,
                     terraform_version: None,
                     updated_at: None,
+                    settings: Default::default(),
+                    state: None,
                 },
                 relationships: None,
             },
@@ -291,11 +350,16 @@ mod tests {
                 id: "ws-abc".to_string(),
                 attributes: WorkspaceAttributes {
                     name: "alpha".to_string(),
+                    vcs_repo: None,
+                    working_directory: None,
+                    assessments_enabled: None,
                     execution_mode: None,
                     resource_count: None,
                     locked: None,
                     terraform_version: None,
                     updated_at: None,
+                    settings: Default::default(),
+                    state: None,
                 },
                 relationships: None,
             },
@@ -303,11 +367,16 @@ mod tests {
                 id: "ws-xyz".to_string(),
                 attributes: WorkspaceAttributes {
                     name: "beta".to_string(),
+                    vcs_repo: None,
+                    working_directory: None,
+                    assessments_enabled: None,
                     execution_mode: None,
                     resource_count: None,
                     locked: None,
                     terraform_version: None,
                     updated_at: None,
+                    settings: Default::default(),
+                    state: None,
                 },
                 relationships: None,
             },
@@ -326,11 +395,16 @@ mod tests {
             id: "ws-123".to_string(),
             attributes: WorkspaceAttributes {
                 name: "my-workspace".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: None,
                 resource_count: None,
                 locked: None,
                 terraform_version: None,
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         }];
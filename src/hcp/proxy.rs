@@ -0,0 +1,105 @@
+//! Proxy settings shared by the TFE client and the update checker
+//!
+//! `--proxy URL` sends all traffic through one proxy. Without it the standard
+//! `HTTPS_PROXY`/`HTTP_PROXY` variables (or their lowercase forms) are used,
+//! and hosts listed in `NO_PROXY` are always reached directly. `socks5://`
+//! and `socks5h://` URLs are accepted as well as `http://`.
+
+use reqwest::{ClientBuilder, NoProxy, Proxy};
+
+use crate::error::{Result, TfeError};
+
+/// Configure `builder` with `proxy` or the proxy environment variables
+pub fn apply_proxy(builder: ClientBuilder, proxy: Option<&str>) -> Result<ClientBuilder> {
+    // Drop reqwest's implicit system proxy so only the rules below apply
+    let mut builder = builder.no_proxy();
+    if let Some(url) = proxy.filter(|u| !u.is_empty()) {
+        return Ok(builder.proxy(build_proxy(Proxy::all(url), url, "--proxy")?));
+    }
+    if let Some((name, url)) = env_proxy(&["HTTPS_PROXY", "https_proxy"]) {
+        builder = builder.proxy(build_proxy(Proxy::https(&url), &url, name)?);
+    }
+    if let Some((name, url)) = env_proxy(&["HTTP_PROXY", "http_proxy"]) {
+        builder = builder.proxy(build_proxy(Proxy::http(&url), &url, name)?);
+    }
+    Ok(builder)
+}
+
+fn build_proxy(proxy: reqwest::Result<Proxy>, url: &str, source: &str) -> Result<Proxy> {
+    proxy
+        .map(|p| p.no_proxy(NoProxy::from_env()))
+        .map_err(|e| TfeError::Config(format!("Invalid proxy URL '{}' ({}): {}", url, source, e)))
+}
+
+/// First non-empty variable among `names`
+fn env_proxy(names: &[&'static str]) -> Option<(&'static str, String)> {
+    names.iter().find_map(|name| {
+        std::env::var(name)
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .map(|v| (*name, v))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_proxy_accepted() {
+        for url in [
+            "http://proxy.corp:3128",
+            "socks5://127.0.0.1:1080",
+            "socks5h://proxy.corp:1080",
+        ] {
+            assert!(
+                apply_proxy(reqwest::Client::builder(), Some(url)).is_ok(),
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_proxy_names_source() {
+        let err = apply_proxy(reqwest::Client::builder(), Some("not a url"))
+            .expect_err("expected invalid proxy error")
+            .to_string();
+        assert!(err.contains("Invalid proxy URL 'not a url'"), "{}", err);
+        assert!(err.contains("--proxy"), "{}", err);
+    }
+
+    #[test]
+    fn test_empty_proxy_falls_back_to_env() {
+        assert!(apply_proxy(reqwest::Client::builder(), Some("")).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_requests_go_through_proxy() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        // A plain HTTP request via an HTTP proxy is sent to the proxy with
+        // the absolute target URL, so the mock answers it in place of the host
+        let proxy = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("via proxy"))
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let client = apply_proxy(reqwest::Client::builder(), Some(&proxy.uri()))
+            .unwrap()
+            .build()
+            .unwrap();
+        let body = client
+            .get("http://tfe.invalid/api/v2/ping")
+            .send()
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+        assert_eq!(body, "via proxy");
+    }
+}
@@ -0,0 +1,116 @@
+//! Registry module API operations
+
+use crate::config::api;
+use crate::error::Result;
+use crate::hcp::traits::ApiListResponse;
+use crate::hcp::{PageRequest, TfeClient};
+
+use super::models::RegistryModule;
+
+impl TfeClient {
+    /// Get all registry modules of an organization (with pagination)
+    pub async fn get_registry_modules(
+        &self,
+        org: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<RegistryModule>> {
+        let path = format!("/{}/{}/{}", api::ORGANIZATIONS, org, api::REGISTRY_MODULES);
+        let error_context = format!("registry modules for organization '{}'", org);
+
+        self.fetch_pages::<RegistryModule, ApiListResponse<RegistryModule>>(
+            &path,
+            &error_context,
+            page,
+        )
+        .await
+    }
+
+    /// Get one registry module with all its published versions
+    pub async fn get_registry_module(
+        &self,
+        org: &str,
+        module: &RegistryModule,
+    ) -> Result<Option<(RegistryModule, serde_json::Value)>> {
+        let path = format!(
+            "/{}/{}/{}/{}/{}/{}/{}",
+            api::ORGANIZATIONS,
+            org,
+            api::REGISTRY_MODULES,
+            module.registry_name(),
+            module.namespace(),
+            module.name(),
+            module.provider()
+        );
+        let label = format!("registry module '{}/{}'", module.name(), module.provider());
+        self.fetch_resource_by_path::<RegistryModule>(&path, &label)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn module_json(name: &str, provider: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": format!("mod-{}-{}", name, provider),
+            "type": "registry-modules",
+            "attributes": {
+                "name": name,
+                "namespace": "my-org",
+                "provider": provider,
+                "registry-name": "private",
+                "version-statuses": [{"version": "1.0.0", "status": "ok"}]
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_registry_modules() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/registry-modules"))
+            .and(query_param("page[number]", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [module_json("vpc", "aws"), module_json("network", "azurerm")],
+                "meta": {"pagination": {"current-page": 1, "total-pages": 1, "total-count": 2}}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let modules = client.get_registry_modules("my-org", None).await.unwrap();
+
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[0].name(), "vpc");
+        assert_eq!(modules[1].provider(), "azurerm");
+    }
+
+    #[tokio::test]
+    async fn test_get_registry_module() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path(
+                "/organizations/my-org/registry-modules/private/my-org/vpc/aws",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": module_json("vpc", "aws")
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let listed: RegistryModule = serde_json::from_value(module_json("vpc", "aws")).unwrap();
+        let (module, _raw) = client
+            .get_registry_module("my-org", &listed)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(module.latest_version(), Some("1.0.0"));
+    }
+}
@@ -0,0 +1,202 @@
+//! Registry module command handlers
+
+use log::debug;
+
+use crate::cli::RegistryModuleArgs;
+use crate::hcp::explain::{call, print_explain, PlannedCall};
+use crate::hcp::TfeClient;
+use crate::output::{apply_limit, output_registry_modules};
+use crate::ui::{create_spinner, finish_spinner};
+use crate::{Cli, Command, GetResource};
+
+use super::models::RegistryModule;
+
+/// Run the registry module list/get command
+pub async fn run_registry_module_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::RegistryModule(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let effective_org = client.effective_org(args.org.as_ref());
+    let org = effective_org
+        .as_ref()
+        .ok_or("Organization is required (--org)")?;
+    if cli.explain {
+        print_explain(&plan_get_registry_module(args, org));
+        return Ok(());
+    }
+
+    let spinner = create_spinner(
+        &format!("Fetching registry modules for '{}'...", org),
+        cli.batch,
+    );
+    let result = client
+        .with_progress(spinner.as_ref())
+        .get_registry_modules(org, cli.page_request())
+        .await;
+    finish_spinner(spinner);
+    let mut modules = result?;
+
+    if let Some(name) = &args.name {
+        let module = find_module(&modules, org, name, args.provider.as_deref())?;
+        let spinner = create_spinner(
+            &format!("Fetching registry module '{}'...", name),
+            cli.batch,
+        );
+        let result = client.get_registry_module(org, module).await;
+        finish_spinner(spinner);
+        let (module, _raw) =
+            result?.ok_or_else(|| format!("Registry module '{}' not found", name))?;
+        output_registry_modules(&[module], cli);
+        return Ok(());
+    }
+
+    if let Some(filter) = &args.filter {
+        let filter_lower = filter.to_lowercase();
+        modules.retain(|m| m.name().to_lowercase().contains(&filter_lower));
+        debug!(
+            "Filtered to {} registry modules matching '{}'",
+            modules.len(),
+            filter
+        );
+    }
+    if let Some(provider) = &args.provider {
+        modules.retain(|m| m.provider().eq_ignore_ascii_case(provider));
+    }
+
+    if modules.is_empty() {
+        if args.filter.is_some() || args.provider.is_some() {
+            eprintln!("No registry modules found matching filter");
+        } else {
+            eprintln!("No registry modules found in organization '{}'", org);
+        }
+        return Ok(());
+    }
+
+    modules.sort_by(|a, b| {
+        a.name()
+            .cmp(b.name())
+            .then_with(|| a.provider().cmp(b.provider()))
+    });
+    apply_limit(&mut modules, cli);
+    output_registry_modules(&modules, cli);
+    Ok(())
+}
+
+/// Find a module by name (and provider, needed when several providers share the name)
+fn find_module<'a>(
+    modules: &'a [RegistryModule],
+    org: &str,
+    name: &str,
+    provider: Option<&str>,
+) -> Result<&'a RegistryModule, String> {
+    let matches: Vec<_> = modules
+        .iter()
+        .filter(|m| m.name().eq_ignore_ascii_case(name))
+        .filter(|m| provider.is_none_or(|p| m.provider().eq_ignore_ascii_case(p)))
+        .collect();
+    match matches.as_slice() {
+        [module] => Ok(module),
+        [] => Err(format!(
+            "Registry module '{}' not found in organization '{}'",
+            name, org
+        )),
+        several => Err(format!(
+            "Registry module '{}' exists for several providers ({}); use --provider",
+            name,
+            several
+                .iter()
+                .map(|m| m.provider())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+/// API calls made by `get registry-module` in `org`
+fn plan_get_registry_module(args: &RegistryModuleArgs, org: &str) -> Vec<PlannedCall> {
+    let mut calls = vec![call(
+        "GET",
+        format!("/organizations/{}/registry-modules", org),
+        "paginated",
+    )];
+    if let Some(name) = &args.name {
+        calls.push(call(
+            "GET",
+            format!(
+                "/organizations/{}/registry-modules/private/:namespace/{}/:provider",
+                org, name
+            ),
+            "module with published versions",
+        ));
+    }
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module(name: &str, provider: &str) -> RegistryModule {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("mod-{}-{}", name, provider),
+            "attributes": {"name": name, "namespace": "my-org", "provider": provider}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_find_module() {
+        let modules = [
+            module("vpc", "aws"),
+            module("vpc", "azurerm"),
+            module("dns", "aws"),
+        ];
+
+        assert_eq!(
+            find_module(&modules, "o", "DNS", None).unwrap().id,
+            "mod-dns-aws"
+        );
+        assert_eq!(
+            find_module(&modules, "o", "vpc", Some("azurerm"))
+                .unwrap()
+                .id,
+            "mod-vpc-azurerm"
+        );
+
+        let err = find_module(&modules, "o", "vpc", None).unwrap_err();
+        assert!(err.contains("aws, azurerm"), "{}", err);
+        assert!(find_module(&modules, "o", "missing", None)
+            .unwrap_err()
+            .contains("not found"));
+    }
+
+    fn plan(argv: &[&str]) -> Vec<PlannedCall> {
+        let cli = <Cli as clap::Parser>::parse_from(argv);
+        let Command::Get {
+            resource: GetResource::RegistryModule(args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        plan_get_registry_module(args, "acme")
+    }
+
+    #[test]
+    fn test_plan_get_registry_module() {
+        let calls = plan(&["hcp", "get", "registry-module"]);
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].path, "/organizations/acme/registry-modules");
+        let calls = plan(&["hcp", "get", "module", "vpc"]);
+        assert_eq!(
+            calls[1].path,
+            "/organizations/acme/registry-modules/private/:namespace/vpc/:provider"
+        );
+    }
+}
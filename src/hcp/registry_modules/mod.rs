@@ -0,0 +1,8 @@
+//! Registry modules module - list modules of the private module registry
+
+mod api;
+mod commands;
+mod models;
+
+pub use commands::run_registry_module_command;
+pub use models::{ModuleVersionStatus, RegistryModule, RegistryModuleAttributes};
@@ -0,0 +1,164 @@
+//! Registry module data models
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use crate::hcp::traits::TfeResource;
+
+/// Private registry module from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RegistryModule {
+    pub id: String,
+    pub attributes: RegistryModuleAttributes,
+}
+
+/// Registry module attributes from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RegistryModuleAttributes {
+    pub name: String,
+    pub namespace: String,
+    /// Terraform provider the module targets (e.g. "aws")
+    pub provider: String,
+    /// "private" or "public"
+    #[serde(rename = "registry-name")]
+    pub registry_name: Option<String>,
+    /// Module status (e.g. "setup_complete")
+    pub status: Option<String>,
+    /// Published versions with their ingress status
+    #[serde(rename = "version-statuses", default)]
+    pub version_statuses: Vec<ModuleVersionStatus>,
+    #[serde(rename = "updated-at")]
+    pub updated_at: Option<String>,
+}
+
+/// One published version of a registry module
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ModuleVersionStatus {
+    pub version: String,
+    /// Ingress status (e.g. "ok", "pending", "reg_ingress_failed")
+    pub status: Option<String>,
+}
+
+impl ModuleVersionStatus {
+    /// Whether the version was ingested and can be used
+    fn is_ok(&self) -> bool {
+        self.status.as_deref().is_none_or(|s| s == "ok")
+    }
+}
+
+impl RegistryModule {
+    /// Get module name
+    pub fn name(&self) -> &str {
+        &self.attributes.name
+    }
+
+    /// Get module namespace (the organization for private modules)
+    pub fn namespace(&self) -> &str {
+        &self.attributes.namespace
+    }
+
+    /// Get module provider
+    pub fn provider(&self) -> &str {
+        &self.attributes.provider
+    }
+
+    /// Registry the module lives in, defaulting to "private"
+    pub fn registry_name(&self) -> &str {
+        self.attributes
+            .registry_name
+            .as_deref()
+            .unwrap_or("private")
+    }
+
+    /// Published versions, newest first (semver order; unparsable versions last)
+    pub fn versions(&self) -> Vec<&ModuleVersionStatus> {
+        let mut versions: Vec<_> = self.attributes.version_statuses.iter().collect();
+        versions.sort_by(|a, b| {
+            let key = |v: &ModuleVersionStatus| Version::parse(&v.version).ok();
+            key(b).cmp(&key(a)).then_with(|| b.version.cmp(&a.version))
+        });
+        versions
+    }
+
+    /// Newest successfully published version
+    pub fn latest_version(&self) -> Option<&str> {
+        self.versions()
+            .into_iter()
+            .find(|v| v.is_ok())
+            .map(|v| v.version.as_str())
+    }
+}
+
+impl TfeResource for RegistryModule {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.attributes.name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module(versions: serde_json::Value) -> RegistryModule {
+        serde_json::from_value(serde_json::json!({
+            "id": "mod-kwt1cBiX2SdDz38w",
+            "type": "registry-modules",
+            "attributes": {
+                "name": "vpc",
+                "namespace": "my-org",
+                "provider": "aws",
+                "registry-name": "private",
+                "status": "setup_complete",
+                "version-statuses": versions,
+                "updated-at": "2024-03-01T10:00:00Z"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_registry_module() {
+        let m = module(serde_json::json!([{"version": "1.0.0", "status": "ok"}]));
+        assert_eq!(m.id, "mod-kwt1cBiX2SdDz38w");
+        assert_eq!(m.name(), "vpc");
+        assert_eq!(m.namespace(), "my-org");
+        assert_eq!(m.provider(), "aws");
+        assert_eq!(m.registry_name(), "private");
+        assert_eq!(m.latest_version(), Some("1.0.0"));
+    }
+
+    #[test]
+    fn test_versions_semver_order() {
+        let m = module(serde_json::json!([
+            {"version": "1.9.0", "status": "ok"},
+            {"version": "1.10.0", "status": "ok"},
+            {"version": "1.2.3", "status": "ok"}
+        ]));
+        let versions: Vec<_> = m.versions().iter().map(|v| v.version.as_str()).collect();
+        assert_eq!(versions, ["1.10.0", "1.9.0", "1.2.3"]);
+    }
+
+    #[test]
+    fn test_latest_version_skips_failed_ingress() {
+        let m = module(serde_json::json!([
+            {"version": "2.0.0", "status": "reg_ingress_failed"},
+            {"version": "1.4.0", "status": "ok"}
+        ]));
+        assert_eq!(m.latest_version(), Some("1.4.0"));
+    }
+
+    #[test]
+    fn test_latest_version_none_without_versions() {
+        let m: RegistryModule = serde_json::from_value(serde_json::json!({
+            "id": "mod-1",
+            "attributes": {"name": "vpc", "namespace": "my-org", "provider": "aws"}
+        }))
+        .unwrap();
+        assert!(m.attributes.version_statuses.is_empty());
+        assert_eq!(m.latest_version(), None);
+    }
+}
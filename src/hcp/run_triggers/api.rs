@@ -0,0 +1,71 @@
+//! Run trigger API operations
+
+use crate::config::api;
+use crate::error::Result;
+use crate::hcp::traits::ApiListResponse;
+use crate::hcp::{PageRequest, TfeClient};
+
+use super::models::RunTrigger;
+
+impl TfeClient {
+    /// Get the inbound run triggers of a workspace (with pagination)
+    ///
+    /// Each trigger names a source workspace whose applies queue runs in
+    /// `workspace_id`.
+    pub async fn get_run_triggers(
+        &self,
+        workspace_id: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<RunTrigger>> {
+        let path = format!(
+            "/{}/{}/{}?filter[run-trigger][type]=inbound",
+            api::WORKSPACES,
+            workspace_id,
+            api::RUN_TRIGGERS
+        );
+        let error_context = format!("run triggers for workspace '{}'", workspace_id);
+
+        self.fetch_pages::<RunTrigger, ApiListResponse<RunTrigger>>(&path, &error_context, page)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_run_triggers_inbound() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc/run-triggers"))
+            .and(query_param("filter[run-trigger][type]", "inbound"))
+            .and(query_param("page[number]", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{
+                    "id": "rt-1",
+                    "type": "run-triggers",
+                    "attributes": {"workspace-name": "app", "sourceable-name": "network"},
+                    "relationships": {
+                        "workspace": {"data": {"id": "ws-abc", "type": "workspaces"}},
+                        "sourceable": {"data": {"id": "ws-net", "type": "workspaces"}}
+                    }
+                }],
+                "meta": {"pagination": {
+                    "current-page": 1, "page-size": 100, "total-pages": 1, "total-count": 1
+                }}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let triggers = client.get_run_triggers("ws-abc", None).await.unwrap();
+
+        assert_eq!(triggers.len(), 1);
+        assert_eq!(triggers[0].sourceable_name(), "network");
+        assert_eq!(triggers[0].sourceable_id(), "ws-net");
+    }
+}
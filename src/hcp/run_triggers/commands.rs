@@ -0,0 +1,76 @@
+//! Run trigger command handlers
+
+use log::debug;
+
+use crate::hcp::explain::{call, print_explain, workspace_lookup};
+use crate::hcp::traits::TfeResource;
+use crate::hcp::workspaces::resolve_workspace;
+use crate::hcp::TfeClient;
+use crate::output::{apply_limit, output_run_triggers};
+use crate::ui::{create_spinner, finish_spinner};
+use crate::{Cli, Command, GetResource};
+
+/// Run the run trigger list command
+///
+/// Lists the source workspaces whose applies queue runs in `--ws`.
+pub async fn run_run_trigger_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::RunTrigger(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        let mut calls = workspace_lookup(&args.workspace, effective_org.as_ref());
+        calls.push(call(
+            "GET",
+            "/workspaces/:ws_id/run-triggers?filter[run-trigger][type]=inbound",
+            "paginated",
+        ));
+        print_explain(&calls);
+        return Ok(());
+    }
+    let resolved = resolve_workspace(
+        client,
+        &args.workspace,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
+    let ws_name = resolved.workspace.name().to_string();
+
+    let spinner = create_spinner(
+        &format!("Fetching run triggers for workspace '{}'...", ws_name),
+        cli.batch,
+    );
+    let result = client
+        .with_progress(spinner.as_ref())
+        .get_run_triggers(&resolved.workspace.id, cli.page_request())
+        .await;
+    finish_spinner(spinner);
+    let mut triggers = result?;
+    debug!(
+        "Fetched {} run triggers for workspace '{}'",
+        triggers.len(),
+        ws_name
+    );
+
+    if triggers.is_empty() {
+        eprintln!(
+            "No run triggers found for workspace '{}' (no source workspaces trigger its runs)",
+            ws_name
+        );
+        return Ok(());
+    }
+
+    triggers.sort_by(|a, b| a.sourceable_name().cmp(b.sourceable_name()));
+    apply_limit(&mut triggers, cli);
+    output_run_triggers(&triggers, cli);
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! Graph command handler (workspace dependencies from run triggers)
+
+use futures::stream::{self, StreamExt};
+use log::debug;
+
+use crate::cli::{Cli, GraphArgs};
+use crate::hcp::explain::{call, print_explain, PlannedCall};
+use crate::hcp::workspaces::WorkspaceFilter;
+use crate::hcp::TfeClient;
+use crate::output::output_graph;
+use crate::ui::{create_spinner, finish_spinner};
+
+use super::models::RunTrigger;
+
+/// Run the graph command
+///
+/// Fetches the inbound run triggers of every workspace in the organization
+/// (concurrently, bounded by `--concurrency`) and prints them as a graph.
+pub async fn run_graph_command(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &GraphArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let effective_org = client.effective_org(args.org.as_ref());
+    let org = effective_org
+        .as_ref()
+        .ok_or("Organization is required (--org)")?;
+    if cli.explain {
+        print_explain(&plan_graph(org));
+        return Ok(());
+    }
+
+    let spinner = create_spinner(&format!("Fetching workspaces for '{}'...", org), cli.batch);
+    let result = client
+        .with_progress(spinner.as_ref())
+        .list_workspaces(&WorkspaceFilter::new().org(org))
+        .await;
+    finish_spinner(spinner);
+    let workspaces = result?;
+
+    let spinner = create_spinner(
+        &format!(
+            "Fetching run triggers for {} workspaces in '{}'...",
+            workspaces.len(),
+            org
+        ),
+        cli.batch,
+    );
+    let progress_client = &client.with_progress(spinner.as_ref());
+    let results: Vec<_> = stream::iter(workspaces.iter())
+        .map(|ws| progress_client.get_run_triggers(&ws.id, None))
+        .buffered(client.concurrency())
+        .collect()
+        .await;
+    finish_spinner(spinner);
+
+    let mut triggers: Vec<RunTrigger> = Vec::new();
+    for result in results {
+        triggers.extend(result?);
+    }
+    debug!(
+        "Found {} run triggers across {} workspaces in '{}'",
+        triggers.len(),
+        workspaces.len(),
+        org
+    );
+
+    if triggers.is_empty() {
+        eprintln!("No run triggers found in organization '{}'", org);
+        return Ok(());
+    }
+
+    output_graph(&triggers, args.format);
+    Ok(())
+}
+
+/// API calls made by `graph` for the effective organization
+fn plan_graph(org: &str) -> Vec<PlannedCall> {
+    vec![
+        call(
+            "GET",
+            format!("/organizations/{}/workspaces", org),
+            "paginated",
+        ),
+        call(
+            "GET",
+            "/workspaces/:ws_id/run-triggers?filter[run-trigger][type]=inbound",
+            "per workspace (concurrent)",
+        ),
+    ]
+}
@@ -0,0 +1,11 @@
+//! Run triggers module - list the source workspaces that trigger a workspace
+//! and graph workspace dependencies across an organization
+
+mod api;
+mod commands;
+mod graph_commands;
+mod models;
+
+pub use commands::run_run_trigger_command;
+pub use graph_commands::run_graph_command;
+pub use models::{RunTrigger, RunTriggerAttributes};
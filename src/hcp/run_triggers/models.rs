@@ -0,0 +1,123 @@
+//! Run trigger data models
+
+use serde::{Deserialize, Serialize};
+
+use crate::hcp::traits::TfeResource;
+
+/// Run trigger from TFE API
+///
+/// A completed apply in the sourceable workspace queues a run in the
+/// (target) workspace.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RunTrigger {
+    pub id: String,
+    pub attributes: RunTriggerAttributes,
+    pub relationships: Option<RunTriggerRelationships>,
+}
+
+/// Run trigger attributes from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RunTriggerAttributes {
+    #[serde(rename = "workspace-name")]
+    pub workspace_name: String,
+    #[serde(rename = "sourceable-name")]
+    pub sourceable_name: String,
+    #[serde(rename = "created-at")]
+    pub created_at: Option<String>,
+}
+
+/// Run trigger relationships (target workspace and source workspace)
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RunTriggerRelationships {
+    pub workspace: Option<RunTriggerRelationship>,
+    pub sourceable: Option<RunTriggerRelationship>,
+}
+
+/// To-one relationship of a run trigger
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RunTriggerRelationship {
+    pub data: Option<RunTriggerRef>,
+}
+
+/// Reference to a workspace linked by a run trigger
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RunTriggerRef {
+    pub id: String,
+}
+
+impl RunTrigger {
+    /// ID of the workspace that gets triggered
+    pub fn workspace_id(&self) -> &str {
+        self.relationships
+            .as_ref()
+            .and_then(|r| r.workspace.as_ref())
+            .and_then(|w| w.data.as_ref())
+            .map(|d| d.id.as_str())
+            .unwrap_or("")
+    }
+
+    /// ID of the source workspace whose applies trigger runs
+    pub fn sourceable_id(&self) -> &str {
+        self.relationships
+            .as_ref()
+            .and_then(|r| r.sourceable.as_ref())
+            .and_then(|s| s.data.as_ref())
+            .map(|d| d.id.as_str())
+            .unwrap_or("")
+    }
+
+    /// Name of the source workspace
+    pub fn sourceable_name(&self) -> &str {
+        &self.attributes.sourceable_name
+    }
+}
+
+impl TfeResource for RunTrigger {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        self.sourceable_name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_run_trigger() {
+        let json = r#"{
+            "id": "rt-3yVQZvHzf5j3WRJ1",
+            "type": "run-triggers",
+            "attributes": {
+                "workspace-name": "workspace-1",
+                "sourceable-name": "workspace-2",
+                "created-at": "2018-09-11T18:21:21.784Z"
+            },
+            "relationships": {
+                "workspace": {"data": {"id": "ws-2HRvNs49EWPjDqT1", "type": "workspaces"}},
+                "sourceable": {"data": {"id": "ws-BUHBEM97xboT8TVz", "type": "workspaces"}}
+            }
+        }"#;
+
+        let rt: RunTrigger = serde_json::from_str(json).unwrap();
+        assert_eq!(rt.id, "rt-3yVQZvHzf5j3WRJ1");
+        assert_eq!(rt.attributes.workspace_name, "workspace-1");
+        assert_eq!(rt.sourceable_name(), "workspace-2");
+        assert_eq!(rt.workspace_id(), "ws-2HRvNs49EWPjDqT1");
+        assert_eq!(rt.sourceable_id(), "ws-BUHBEM97xboT8TVz");
+    }
+
+    #[test]
+    fn test_run_trigger_without_relationships() {
+        let rt: RunTrigger = serde_json::from_value(serde_json::json!({
+            "id": "rt-1",
+            "attributes": {"workspace-name": "a", "sourceable-name": "b"}
+        }))
+        .unwrap();
+        assert_eq!(rt.workspace_id(), "");
+        assert_eq!(rt.sourceable_id(), "");
+    }
+}
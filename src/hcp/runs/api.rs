@@ -23,8 +23,11 @@ impl TfeClient {
         max_results: Option<u32>,
     ) -> Result<Vec<Run>> {
         let mut all_runs = Vec::new();
-        let mut page = query.page.unwrap_or(1);
-        let page_size = query.page_size.unwrap_or(api::DEFAULT_PAGE_SIZE);
+        let requested = query.requested_page;
+        let mut page = requested.map_or(query.page.unwrap_or(1), |p| p.number);
+        let page_size = requested.map_or(query.page_size.unwrap_or(api::DEFAULT_PAGE_SIZE), |p| {
+            p.size
+        });
 
         loop {
             let mut url = format!(
@@ -42,7 +45,7 @@ impl TfeClient {
 
             debug!("Fetching runs page {} from: {}", page, url);
 
-            let response = self.get(&url).send().await?;
+            let response = self.send_get(&url).await?;
 
             let runs_response: RunsResponse = self
                 .parse_api_response(response, &format!("runs for workspace '{}'", workspace_id))
@@ -50,6 +53,22 @@ impl TfeClient {
             let run_count = runs_response.data.len();
             all_runs.extend(runs_response.data);
 
+            // `--page`: report this page only and stop
+            if let Some(requested) = requested {
+                let pagination = runs_response
+                    .meta
+                    .as_ref()
+                    .and_then(|m| m.pagination.as_ref());
+                requested.report(
+                    pagination.and_then(|p| p.total_pages),
+                    pagination.and_then(|p| p.total_count),
+                );
+                if let Some(max) = max_results {
+                    all_runs.truncate(max as usize);
+                }
+                break;
+            }
+
             // Check if we've reached max_results
             if let Some(max) = max_results {
                 if all_runs.len() >= max as usize {
@@ -106,8 +125,11 @@ impl TfeClient {
         max_results: Option<u32>,
     ) -> Result<Vec<Run>> {
         let mut all_runs = Vec::new();
-        let mut page = query.page.unwrap_or(1);
-        let page_size = query.page_size.unwrap_or(api::DEFAULT_PAGE_SIZE);
+        let requested = query.requested_page;
+        let mut page = requested.map_or(query.page.unwrap_or(1), |p| p.number);
+        let page_size = requested.map_or(query.page_size.unwrap_or(api::DEFAULT_PAGE_SIZE), |p| {
+            p.size
+        });
 
         loop {
             let mut url = format!(
@@ -139,7 +161,7 @@ impl TfeClient {
 
             debug!("Fetching runs page {} from: {}", page, url);
 
-            let response = self.get(&url).send().await?;
+            let response = self.send_get(&url).await?;
 
             let runs_response: RunsResponse = self
                 .parse_api_response(response, &format!("runs for organization '{}'", org))
@@ -147,6 +169,22 @@ impl TfeClient {
             let run_count = runs_response.data.len();
             all_runs.extend(runs_response.data);
 
+            // `--page`: report this page only and stop
+            if let Some(requested) = requested {
+                let pagination = runs_response
+                    .meta
+                    .as_ref()
+                    .and_then(|m| m.pagination.as_ref());
+                requested.report(
+                    pagination.and_then(|p| p.total_pages),
+                    pagination.and_then(|p| p.total_count),
+                );
+                if let Some(max) = max_results {
+                    all_runs.truncate(max as usize);
+                }
+                break;
+            }
+
             // Check if we've reached max_results
             if let Some(max) = max_results {
                 if all_runs.len() >= max as usize {
@@ -223,7 +261,7 @@ impl TfeClient {
 
         debug!("Fetching plan for run: {}", url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         let plan_response: super::models::PlanResponse = self
             .parse_api_response(response, &format!("plan for run '{}'", run_id))
@@ -237,7 +275,7 @@ impl TfeClient {
 
         debug!("Fetching apply for run: {}", url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         let apply_response: super::models::ApplyResponse = self
             .parse_api_response(response, &format!("apply for run '{}'", run_id))
@@ -245,12 +283,60 @@ impl TfeClient {
         Ok(apply_response.data)
     }
 
+    /// Get a cost estimate by ID
+    pub async fn get_cost_estimate(&self, ce_id: &str) -> Result<super::models::CostEstimate> {
+        let url = format!("{}/{}/{}", self.base_url(), api::COST_ESTIMATES, ce_id);
+
+        debug!("Fetching cost estimate: {}", url);
+
+        let response = self.send_get(&url).await?;
+
+        let ce_response: super::models::CostEstimateResponse = self
+            .parse_api_response(response, &format!("cost estimate '{}'", ce_id))
+            .await?;
+        Ok(ce_response.data)
+    }
+
+    /// Get the policy checks of a run
+    pub async fn get_run_policy_checks(
+        &self,
+        run_id: &str,
+    ) -> Result<Vec<super::models::PolicyCheck>> {
+        let url = format!("{}/{}/{}/policy-checks", self.base_url(), api::RUNS, run_id);
+
+        debug!("Fetching policy checks for run: {}", url);
+
+        let response = self.send_get(&url).await?;
+
+        let checks_response: super::models::PolicyChecksResponse = self
+            .parse_api_response(response, &format!("policy checks for run '{}'", run_id))
+            .await?;
+        Ok(checks_response.data)
+    }
+
+    /// Get a policy check by ID
+    pub async fn get_policy_check(&self, check_id: &str) -> Result<super::models::PolicyCheck> {
+        let url = format!("{}/{}/{}", self.base_url(), api::POLICY_CHECKS, check_id);
+
+        debug!("Fetching policy check: {}", url);
+
+        let response = self.send_get(&url).await?;
+
+        let check_response: super::models::PolicyCheckResponse = self
+            .parse_api_response(response, &format!("policy check '{}'", check_id))
+            .await?;
+        Ok(check_response.data)
+    }
+
     /// Get log content from a log-read-url
     ///
     /// The log-read-url is a temporary authenticated URL that expires in 1 minute.
     /// This method fetches the raw log content from the archivist.
     pub async fn get_log_content(&self, log_read_url: &str) -> Result<String> {
-        debug!("Fetching log content from: {}", log_read_url);
+        debug!(
+            "Fetching log content from: {}",
+            crate::hcp::redact::redact_url_str(log_read_url)
+        );
 
         // Note: log-read-url is a pre-authenticated URL, so we use a raw client
         let response = reqwest::get(log_read_url).await?;
@@ -259,6 +345,7 @@ impl TfeClient {
             return Err(TfeError::Api {
                 status: response.status().as_u16(),
                 message: "Failed to fetch log content".to_string(),
+                details: Vec::new(),
             });
         }
 
@@ -280,15 +367,44 @@ impl TfeClient {
 
         debug!("Canceling run: {}", run_id);
 
-        let response = self.post(&url).send().await?;
+        let response = self.send(self.post(&url)).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
             let body = response.text().await.unwrap_or_default();
-            return Err(TfeError::Api {
+            return Err(TfeError::api_response(
                 status,
-                message: format!("Failed to cancel run '{}': {}", run_id, body),
-            });
+                format!("Failed to cancel run '{}'", run_id),
+                &body,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Apply a run that is waiting for confirmation
+    ///
+    /// Sends POST /runs/:run_id/actions/apply with an optional comment.
+    /// The run must have is-confirmable: true in its actions.
+    pub async fn confirm_run(&self, run_id: &str, comment: Option<&str>) -> Result<()> {
+        let url = format!("{}/{}/{}/actions/apply", self.base_url(), api::RUNS, run_id);
+
+        debug!("Confirming run: {} (comment={:?})", run_id, comment);
+
+        let mut request = self.post(&url);
+        if let Some(comment) = comment {
+            request = request.json(&serde_json::json!({ "comment": comment }));
+        }
+        let response = self.send(request).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TfeError::api_response(
+                status,
+                format!("Failed to apply run '{}'", run_id),
+                &body,
+            ));
         }
 
         Ok(())
@@ -308,15 +424,45 @@ impl TfeClient {
 
         debug!("Discarding run: {}", run_id);
 
-        let response = self.post(&url).send().await?;
+        let response = self.send(self.post(&url)).await?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
             let body = response.text().await.unwrap_or_default();
-            return Err(TfeError::Api {
+            return Err(TfeError::api_response(
                 status,
-                message: format!("Failed to discard run '{}': {}", run_id, body),
-            });
+                format!("Failed to discard run '{}'", run_id),
+                &body,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Override a soft-failed policy check so the run can continue
+    ///
+    /// Sends POST /policy-checks/:id/actions/override
+    /// The check must have is-overridable: true in its actions.
+    pub async fn override_policy(&self, policy_check_id: &str) -> Result<()> {
+        let url = format!(
+            "{}/{}/{}/actions/override",
+            self.base_url(),
+            api::POLICY_CHECKS,
+            policy_check_id
+        );
+
+        debug!("Overriding policy check: {}", policy_check_id);
+
+        let response = self.send(self.post(&url)).await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TfeError::api_response(
+                status,
+                format!("Failed to override policy check '{}'", policy_check_id),
+                &body,
+            ));
         }
 
         Ok(())
@@ -325,7 +471,7 @@ impl TfeClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{method, path, query_param};
+    use wiremock::matchers::{body_json, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn sample_runs_response() -> serde_json::Value {
@@ -394,6 +540,33 @@ mod tests {
         assert_eq!(runs[0].status(), "planning");
     }
 
+    #[tokio::test]
+    async fn test_get_runs_requested_page_only() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        let mut body = sample_runs_response();
+        body["meta"]["pagination"]["next-page"] = serde_json::json!(5);
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/runs"))
+            .and(query_param("page[number]", "4"))
+            .and(query_param("page[size]", "10"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let query = RunQuery {
+            requested_page: crate::hcp::PageRequest::from_flags(Some(4), Some(10)),
+            ..RunQuery::non_final()
+        };
+        let runs = client
+            .get_runs_for_organization("my-org", query, None)
+            .await
+            .unwrap();
+        assert_eq!(runs.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_get_runs_for_organization_success() {
         let mock_server = MockServer::start().await;
@@ -650,6 +823,33 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_get_cost_estimate_success() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/cost-estimates/ce-abc123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "id": "ce-abc123",
+                    "type": "cost-estimates",
+                    "attributes": {
+                        "status": "finished",
+                        "prior-monthly-cost": "10.0",
+                        "proposed-monthly-cost": "12.5",
+                        "delta-monthly-cost": "2.5"
+                    }
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let ce = client.get_cost_estimate("ce-abc123").await.unwrap();
+        assert_eq!(ce.id, "ce-abc123");
+        assert_eq!(ce.delta_monthly_cost(), Some(2.5));
+    }
+
     #[tokio::test]
     async fn test_get_log_content_success() {
         let mock_server = MockServer::start().await;
@@ -708,6 +908,48 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_confirm_run_sends_comment() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/runs/run-abc123/actions/apply"))
+            .and(body_json(serde_json::json!({"comment": "LGTM"})))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let result = client.confirm_run("run-abc123", Some("LGTM")).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_confirm_run_not_confirmable() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/runs/run-abc123/actions/apply"))
+            .respond_with(
+                ResponseTemplate::new(409).set_body_string(
+                    r#"{"errors":[{"status":"409","title":"conflict","detail":"Run is not confirmable"}]}"#,
+                ),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let err = client
+            .confirm_run("run-abc123", None)
+            .await
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("Run is not confirmable"), "{}", err);
+    }
+
     #[tokio::test]
     async fn test_discard_run_success() {
         let mock_server = MockServer::start().await;
@@ -743,6 +985,38 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_override_policy_success() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("POST"))
+            .and(path("/policy-checks/polchk-abc123/actions/override"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        client.override_policy("polchk-abc123").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_override_policy_not_overridable() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("POST"))
+            .and(path("/policy-checks/polchk-abc123/actions/override"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(serde_json::json!({
+                "errors": [{"status": "409", "title": "transition not allowed"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let err = client.override_policy("polchk-abc123").await.unwrap_err();
+        assert!(err.to_string().contains("polchk-abc123"), "{}", err);
+    }
+
     #[tokio::test]
     async fn test_cancel_run_not_found() {
         let mock_server = MockServer::start().await;
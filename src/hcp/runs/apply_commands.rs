@@ -0,0 +1,432 @@
+//! Run apply (confirm) command handler
+
+use log::debug;
+
+use crate::cli::{ApplyRunArgs, Cli, Command, OutputFormat, RunAction};
+use crate::hcp::explain::{call, org_path, print_explain, workspace_lookup, PlannedCall};
+use crate::hcp::runs::{Run, RunQuery, RunStatus};
+use crate::hcp::traits::TfeResource;
+use crate::hcp::workspaces::resolve_workspace;
+use crate::hcp::TfeClient;
+use crate::output::format_age;
+use crate::ui::{confirm_action, create_spinner, finish_spinner};
+
+/// Run the run apply command (confirm runs awaiting confirmation)
+pub async fn run_apply_run_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Run {
+        action: RunAction::Apply(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+    if cli.explain {
+        print_explain(&plan_apply_run(
+            args,
+            client.effective_org(args.org.as_ref()).as_ref(),
+        ));
+        return Ok(());
+    }
+
+    let (runs, scope) = match &args.run_id {
+        Some(run_id) => (
+            vec![fetch_confirmable_run(client, cli, run_id).await?],
+            None,
+        ),
+        None => {
+            let (runs, scope) = fetch_confirmable_runs(client, cli, args).await?;
+            (runs, Some(scope))
+        }
+    };
+
+    if runs.is_empty() {
+        println!(
+            "\n✓ No runs awaiting confirmation in {}.",
+            scope.unwrap_or_default()
+        );
+        return Ok(());
+    }
+
+    let dry_run_prefix = if args.dry_run { "[DRY-RUN] " } else { "" };
+    println!();
+    println!("{}The following runs will be applied:", dry_run_prefix);
+    println!();
+    output_confirmable_runs_table(&runs);
+    println!();
+
+    let prompt = format!("{}Apply {} run(s)?", dry_run_prefix, runs.len());
+    if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
+        println!("\nAborted.");
+        return Ok(());
+    }
+
+    println!();
+    let mut success_count = 0;
+    let mut error_count = 0;
+    for run in &runs {
+        if args.dry_run {
+            println!("[DRY-RUN] Would apply run: {}", run.id);
+            success_count += 1;
+            continue;
+        }
+        let spinner = create_spinner(&format!("Applying run {}...", run.id), cli.batch);
+        let result = client.confirm_run(&run.id, args.comment.as_deref()).await;
+        finish_spinner(spinner);
+        match result {
+            Ok(()) => {
+                println!("✓ Applied run: {}", run.id);
+                success_count += 1;
+            }
+            Err(e) if runs.len() == 1 => return Err(e.into()),
+            Err(e) => {
+                eprintln!("✗ Failed to apply run {}: {}", run.id, e);
+                error_count += 1;
+                // Stop on first error, like purge run
+                break;
+            }
+        }
+    }
+
+    // Summary (a single run needs none beyond its own line)
+    if runs.len() > 1 || args.dry_run {
+        println!();
+        if args.dry_run {
+            println!("Dry-run complete. No changes were made.");
+        } else if error_count > 0 {
+            println!(
+                "Processed {} runs. {} succeeded, {} failed.",
+                success_count + error_count,
+                success_count,
+                error_count
+            );
+        } else {
+            println!("All {} runs applied successfully.", success_count);
+        }
+    }
+    Ok(())
+}
+
+/// Fetch a single run, failing unless it is awaiting confirmation
+async fn fetch_confirmable_run(
+    client: &TfeClient,
+    cli: &Cli,
+    run_id: &str,
+) -> Result<Run, Box<dyn std::error::Error>> {
+    let spinner = create_spinner(&format!("Fetching run {}...", run_id), cli.batch);
+    let run = client.get_run_by_id(run_id).await;
+    finish_spinner(spinner);
+    let Some((run, _)) = run? else {
+        return Err(format!("Run '{}' not found", run_id).into());
+    };
+
+    debug!(
+        "Run {} status={} confirmable={}",
+        run.id,
+        run.status(),
+        run.is_confirmable()
+    );
+    if !run.is_confirmable() {
+        return Err(format!(
+            "Run '{}' is not awaiting confirmation (status: {})",
+            run.id,
+            run.status()
+        )
+        .into());
+    }
+    Ok(run)
+}
+
+/// Fetch confirmable runs for `--ws` or `--org`, with a label for the scope
+async fn fetch_confirmable_runs(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &ApplyRunArgs,
+) -> Result<(Vec<Run>, String), Box<dyn std::error::Error>> {
+    let query = match &args.status {
+        Some(status_str) => {
+            let statuses: Vec<RunStatus> = status_str
+                .split(',')
+                .map(|s| s.trim().parse())
+                .collect::<Result<_, _>>()
+                .map_err(|e| format!("Invalid status: {}", e))?;
+            RunQuery::with_statuses(statuses)
+        }
+        None => RunQuery::non_final(),
+    };
+
+    let effective_org = client.effective_org(args.org.as_ref());
+    let (runs, scope) = if let Some(ws) = &args.ws {
+        let resolved =
+            resolve_workspace(client, ws, effective_org.as_deref(), cli.batch, cli.strict).await?;
+        let ws_id = &resolved.workspace.id;
+        let spinner = create_spinner(&format!("Fetching runs for {}...", ws_id), cli.batch);
+        let runs = client.get_runs_for_workspace(ws_id, query, None).await;
+        finish_spinner(spinner);
+        let scope = format!("workspace '{}'", resolved.workspace.name());
+        (runs?, scope)
+    } else if let Some(org) = effective_org {
+        let spinner = create_spinner(&format!("Fetching runs in {}...", org), cli.batch);
+        let runs = client.get_runs_for_organization(&org, query, None).await;
+        finish_spinner(spinner);
+        (runs?, format!("organization '{}'", org))
+    } else {
+        return Err("Specify a run ID (run-xxx), --ws or --org".into());
+    };
+
+    let runs = runs.into_iter().filter(|r| r.is_confirmable()).collect();
+    Ok((runs, scope))
+}
+
+/// Display runs about to be applied
+fn output_confirmable_runs_table(runs: &[Run]) {
+    use comfy_table::{presets::UTF8_FULL_CONDENSED, Table};
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec!["Run ID", "Workspace", "Status", "Age", "Message"]);
+
+    for run in runs {
+        let status = if run.is_destroy() {
+            format!("{} (destroy)", run.status())
+        } else {
+            run.status().to_string()
+        };
+        table.add_row(vec![
+            run.id.as_str(),
+            run.workspace_id().unwrap_or(""),
+            &status,
+            &format_age(run.attributes.created_at.as_deref()),
+            run.message(),
+        ]);
+    }
+
+    crate::output::print_table(&table, &OutputFormat::Table);
+}
+
+/// API calls made by `run apply` for the effective organization
+fn plan_apply_run(args: &ApplyRunArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    let mut calls = match (&args.run_id, &args.ws) {
+        (Some(run_id), _) => {
+            vec![call(
+                "GET",
+                format!("/runs/{}", run_id),
+                "check run is confirmable",
+            )]
+        }
+        (None, Some(ws)) => {
+            let mut calls = workspace_lookup(ws, org);
+            calls.push(call("GET", "/workspaces/:ws_id/runs", "confirmable runs"));
+            calls
+        }
+        (None, None) => vec![call(
+            "GET",
+            format!("/organizations/{}/runs", org_path(org)),
+            "confirmable runs",
+        )],
+    };
+    let run = args.run_id.as_deref().unwrap_or(":run_id");
+    let note = if args.run_id.is_some() {
+        "apply"
+    } else {
+        "per confirmable run"
+    };
+    calls.push(call("POST", format!("/runs/{}/actions/apply", run), note));
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn run_json(id: &str, status: &str, confirmable: bool) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "type": "runs",
+            "attributes": {
+                "status": status,
+                "message": "Triggered via API",
+                "actions": {"is-confirmable": confirmable}
+            }
+        })
+    }
+
+    async fn mount_run(server: &MockServer, status: &str, confirmable: bool) {
+        Mock::given(method("GET"))
+            .and(path("/runs/run-abc123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": run_json("run-abc123", status, confirmable)
+            })))
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_org_runs(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    run_json("run-1", "planned", true),
+                    run_json("run-2", "planning", false),
+                    run_json("run-3", "policy_checked", true),
+                ],
+                "meta": {"pagination": {"current-page": 1, "next-page": null, "page-size": 100}}
+            })))
+            .mount(server)
+            .await;
+    }
+
+    async fn apply(server: &MockServer, argv: &[&str]) -> Result<(), String> {
+        let client = TfeClient::test_client(&server.uri());
+        let cli = Cli::parse_from(argv);
+        run_apply_run_command(&client, &cli)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_apply_confirmable_run() {
+        let server = MockServer::start().await;
+        mount_run(&server, "planned", true).await;
+        Mock::given(method("POST"))
+            .and(path("/runs/run-abc123/actions/apply"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        apply(&server, &["hcp", "run", "apply", "run-abc123", "-y"])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_apply_rejects_unconfirmable_run() {
+        let server = MockServer::start().await;
+        mount_run(&server, "applied", false).await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let err = apply(&server, &["hcp", "run", "apply", "run-abc123", "-y"])
+            .await
+            .unwrap_err();
+        assert!(err.contains("not awaiting confirmation"), "{}", err);
+        assert!(err.contains("applied"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_apply_org_applies_only_confirmable_runs() {
+        let server = MockServer::start().await;
+        mount_org_runs(&server).await;
+        for id in ["run-1", "run-3"] {
+            Mock::given(method("POST"))
+                .and(path(format!("/runs/{}/actions/apply", id)))
+                .respond_with(ResponseTemplate::new(202))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        apply(&server, &["hcp", "run", "apply", "--org", "my-org", "-y"])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_apply_org_stops_on_first_error() {
+        let server = MockServer::start().await;
+        mount_org_runs(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/runs/run-1/actions/apply"))
+            .respond_with(ResponseTemplate::new(409))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/runs/run-3/actions/apply"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        apply(&server, &["hcp", "run", "apply", "--org", "my-org", "-y"])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_apply_dry_run_does_not_post() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/runs"))
+            .and(query_param("filter[status]", "planned"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [run_json("run-1", "planned", true)],
+                "meta": {"pagination": {"current-page": 1, "next-page": null, "page-size": 100}}
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        apply(
+            &server,
+            &[
+                "hcp",
+                "run",
+                "apply",
+                "--org",
+                "my-org",
+                "--status",
+                "planned",
+                "--dry-run",
+                "-y",
+            ],
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_apply_requires_target() {
+        let server = MockServer::start().await;
+        let err = apply(&server, &["hcp", "run", "apply", "-y"])
+            .await
+            .unwrap_err();
+        assert!(err.contains("--ws or --org"), "{}", err);
+    }
+
+    fn plan(argv: &[&str], org: Option<&str>) -> Vec<PlannedCall> {
+        let cli = Cli::parse_from(argv);
+        let Command::Run {
+            action: RunAction::Apply(args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        plan_apply_run(args, org.map(str::to_string).as_ref())
+    }
+
+    #[test]
+    fn test_plan_run_apply() {
+        let calls = plan(&["hcp", "run", "apply", "run-abc", "-y"], None);
+        let paths: Vec<_> = calls.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, ["/runs/run-abc", "/runs/run-abc/actions/apply"]);
+        assert_eq!(calls[1].method, "POST");
+
+        let calls = plan(&["hcp", "run", "apply", "--dry-run"], Some("my-org"));
+        assert_eq!(calls[0].path, "/organizations/my-org/runs");
+        assert_eq!(calls[1].path, "/runs/:run_id/actions/apply");
+    }
+}
@@ -5,16 +5,23 @@ use std::io::{self, Write};
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
-use dialoguer::Confirm;
+use futures::future::join_all;
+use futures::stream::{self, StreamExt};
 use tokio::time::sleep;
 
-use crate::cli::{OutputFormat, RunSortField, RunSubresource};
-use crate::hcp::runs::{Run, RunEventsResponse, RunQuery};
+use crate::cli::{OutputFormat, RunArgs, RunCountField, RunSortField, RunSubresource};
+use crate::error::TfeError;
+use crate::hcp::explain::{call, org_path, print_explain, workspace_lookup, PlannedCall};
+use crate::hcp::runs::{PolicyChecksResponse, Run, RunEventsResponse, RunQuery, RunStatus};
 use crate::hcp::traits::TfeResource;
 use crate::hcp::workspaces::{extract_current_run_id, resolve_workspace};
 use crate::hcp::TfeClient;
-use crate::output::{output_apply, output_plan, output_raw, output_run_events, output_runs};
-use crate::ui::{confirm_action, create_spinner, finish_spinner};
+use crate::output::{
+    apply_limit, count_by, format_age, output_apply, output_configuration_version,
+    output_cost_estimate, output_counts, output_current_run, output_plan, output_policy_checks,
+    output_raw, output_run_events, output_runs, output_state_versions, print_ids, print_text,
+};
+use crate::ui::{confirm_typed, confirm_with_timeout, create_spinner, finish_spinner};
 use crate::{Cli, Command, GetResource};
 
 /// Maximum results before requiring user confirmation
@@ -32,8 +39,17 @@ pub async fn run_runs_command(
         unreachable!()
     };
 
+    let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        print_explain(&plan_get_run(args, effective_org.as_ref()));
+        return Ok(());
+    }
+
     // If run ID is specified, get single run
     if let Some(run_id) = &args.name {
+        if !args.more_run_ids.is_empty() {
+            return get_logs_for_runs(client, cli, args, run_id).await;
+        }
         if run_id.starts_with("run-") {
             return get_single_run(client, cli, run_id).await;
         }
@@ -44,7 +60,9 @@ pub async fn run_runs_command(
         .into());
     }
 
-    let effective_org = client.effective_org(args.org.as_ref());
+    if args.current {
+        return get_current_run(client, cli, args).await;
+    }
 
     // Validate that org or ws is provided
     if effective_org.is_none() && args.ws.is_none() {
@@ -56,35 +74,159 @@ pub async fn run_runs_command(
         return Err("--workspace-names can only be used with --org, not --ws".into());
     }
 
+    if args.watch.is_some() && cli.batch {
+        return Err("--watch redraws the terminal and cannot be used with --batch".into());
+    }
+    if args.watch.is_some() && cli.output_file.is_some() {
+        return Err("--watch redraws the terminal and cannot be used with --output-file".into());
+    }
+
+    // Resolve the --newer-than cursor before listing, so a bad reference fails fast
+    let newer_than = match &args.newer_than {
+        Some(run_id) => Some(resolve_cursor(client, run_id).await?),
+        None => None,
+    };
+
     // Build query
     let mut query = build_run_query(args)?;
+    query.requested_page = cli.page_request();
 
     // Add workspace names filter if provided (for org endpoint)
     if let Some(ws_names) = &args.workspace_names {
         query.workspace_names = Some(ws_names.split(',').map(|s| s.trim().to_string()).collect());
     }
 
-    // Fetch runs based on whether we have org or ws
-    let runs = if let Some(ws_id) = &args.ws {
-        fetch_workspace_runs(client, cli, ws_id, query, args.yes).await?
-    } else if let Some(org) = &effective_org {
-        fetch_org_runs(client, cli, org, query, args.yes).await?
+    if let Some(interval) = args.watch {
+        return watch_runs(
+            client,
+            cli,
+            args,
+            effective_org.as_deref(),
+            query,
+            newer_than,
+            interval,
+        )
+        .await;
+    }
+
+    let runs = fetch_runs(client, cli, args, effective_org.as_deref(), query, args.yes).await?;
+    render_runs(cli, args, runs, newer_than.as_ref());
+
+    Ok(())
+}
+
+/// Whether `--limit` is small enough that the newest `CONFIRM_THRESHOLD` runs
+/// suffice, so the "continue fetching all?" prompt is skipped
+fn limit_within_threshold(cli: &Cli) -> bool {
+    cli.limit.is_some_and(|limit| limit <= CONFIRM_THRESHOLD)
+}
+
+/// Fetch runs from the workspace (`--ws`) or organization (`--org`)
+async fn fetch_runs(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &RunArgs,
+    org: Option<&str>,
+    query: RunQuery,
+    auto_confirm: bool,
+) -> Result<Vec<Run>, Box<dyn std::error::Error>> {
+    if let Some(ws_id) = &args.ws {
+        fetch_workspace_runs(client, cli, ws_id, query, auto_confirm).await
+    } else if let Some(org) = org {
+        fetch_org_runs(client, cli, org, query, auto_confirm).await
     } else {
         unreachable!()
-    };
+    }
+}
+
+/// Filter, sort and print one listing of runs
+fn render_runs(cli: &Cli, args: &RunArgs, runs: Vec<Run>, newer_than: Option<&DateTime<Utc>>) {
+    let runs = filter_confirmable(runs, args.confirmable);
 
     if runs.is_empty() {
-        println!("\nNo runs found matching the criteria.");
-        return Ok(());
+        print_text("\nNo runs found matching the criteria.\n");
+        return;
+    }
+
+    // Sort runs, then drop everything at or before the cursor
+    let sorted_runs = sort_runs(runs, &args.sort, args.reverse);
+    let mut sorted_runs = match newer_than {
+        Some(cutoff) => filter_newer_than(sorted_runs, cutoff),
+        None => sorted_runs,
+    };
+
+    if sorted_runs.is_empty() {
+        print_text("\nNo runs newer than the given run.\n");
+        return;
     }
 
-    // Sort runs
-    let sorted_runs = sort_runs(runs, args.sort, args.reverse);
+    if let Some(field) = args.count_by {
+        let counts = count_by(&sorted_runs, |run| match field {
+            RunCountField::Status => run.status().to_string(),
+            RunCountField::Source => run.source().to_string(),
+            RunCountField::WsId => run.workspace_id().unwrap_or("").to_string(),
+        });
+        output_counts(
+            &counts,
+            &field.to_string(),
+            "runs",
+            &args.output,
+            cli.no_header,
+        );
+        return;
+    }
+
+    apply_limit(&mut sorted_runs, cli);
 
     // Output
-    output_runs(&sorted_runs, &args.output, cli.no_header);
+    if cli.id_only {
+        print_ids(&sorted_runs);
+    } else {
+        output_runs(&sorted_runs, &args.output, cli.no_header);
+    }
+}
 
-    Ok(())
+/// Re-fetch and redraw the run listing every `interval` seconds until Ctrl+C
+///
+/// Each frame goes through the same fetch/filter/sort path as a one-shot listing,
+/// fetching everything without the large-result prompt.
+async fn watch_runs(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &RunArgs,
+    org: Option<&str>,
+    query: RunQuery,
+    newer_than: Option<DateTime<Utc>>,
+    interval: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let poll_interval = Duration::from_secs(interval.max(1));
+
+    loop {
+        let frame = async {
+            let runs = fetch_runs(client, cli, args, org, query.clone(), true).await?;
+            // Clear the screen and move the cursor home before redrawing
+            print!("\x1b[2J\x1b[H");
+            println!(
+                "Every {}s: {} in {} (updated {}) - press Ctrl+C to stop",
+                poll_interval.as_secs(),
+                runs_label(args),
+                args.ws.as_deref().or(org).unwrap_or_default(),
+                Utc::now().format("%H:%M:%S")
+            );
+            render_runs(cli, args, runs, newer_than.as_ref());
+            io::stdout().flush()?;
+            sleep(poll_interval).await;
+            Ok::<(), Box<dyn std::error::Error>>(())
+        };
+
+        tokio::select! {
+            result = frame => result?,
+            _ = tokio::signal::ctrl_c() => {
+                println!();
+                return Ok(());
+            }
+        }
+    }
 }
 
 /// Get a single run by ID
@@ -113,7 +255,10 @@ async fn get_single_run(
 
             // For single run, output raw JSON/YAML or table
             match args.output {
-                OutputFormat::Json | OutputFormat::Yaml => {
+                OutputFormat::Json
+                | OutputFormat::Yaml
+                | OutputFormat::Ndjson
+                | OutputFormat::Template => {
                     output_raw(&raw, &args.output);
                 }
                 _ => {
@@ -135,37 +280,61 @@ async fn get_single_run(
 }
 
 /// Build RunQuery from CLI arguments
-/// Always uses non_final status group. --status filters within non_final only.
+///
+/// Uses the non_final status group unless --final or --all is given; --status
+/// values must belong to the selected group.
 fn build_run_query(args: &crate::cli::RunArgs) -> Result<RunQuery, Box<dyn std::error::Error>> {
     use crate::hcp::runs::RunStatus;
 
-    // If explicit statuses provided, validate they are non-final and use them
+    // If explicit statuses provided, validate they fit the status group and use them
     if let Some(status_str) = &args.status {
-        let statuses: Result<Vec<RunStatus>, _> =
-            status_str.split(',').map(|s| s.trim().parse()).collect();
-
-        match statuses {
-            Ok(s) => {
-                // Validate all statuses are non-final
-                for status in &s {
-                    if !status.is_non_final() {
-                        return Err(format!(
-                            "Status '{}' is a final status. Only non-final statuses are allowed.",
-                            status
-                        )
-                        .into());
-                    }
-                }
-                return Ok(RunQuery::with_statuses(s));
+        let statuses: Vec<RunStatus> = status_str
+            .split(',')
+            .map(|s| s.trim().parse())
+            .collect::<Result<_, _>>()
+            .map_err(|e| format!("Invalid status: {}", e))?;
+
+        for status in &statuses {
+            if args.all {
+                continue;
             }
-            Err(e) => {
-                return Err(format!("Invalid status: {}", e).into());
+            if args.final_only && status.is_non_final() {
+                return Err(format!(
+                    "Status '{}' is a non-final status. Only final statuses are allowed with --final.",
+                    status
+                )
+                .into());
+            }
+            if !args.final_only && !status.is_non_final() {
+                return Err(format!(
+                    "Status '{}' is a final status. Only non-final statuses are allowed \
+                     (use --final or --all for completed runs).",
+                    status
+                )
+                .into());
             }
         }
+        return Ok(RunQuery::with_statuses(statuses));
     }
 
-    // Default: all non_final runs
-    Ok(RunQuery::non_final())
+    if args.all {
+        Ok(RunQuery::all())
+    } else if args.final_only {
+        Ok(RunQuery::final_only())
+    } else {
+        Ok(RunQuery::non_final())
+    }
+}
+
+/// Label for the runs a listing covers, e.g. in the --watch header
+fn runs_label(args: &RunArgs) -> &'static str {
+    if args.all {
+        "runs"
+    } else if args.final_only {
+        "completed runs"
+    } else {
+        "active runs"
+    }
 }
 
 /// Fetch runs from a workspace
@@ -189,20 +358,18 @@ async fn fetch_workspace_runs(
     if initial_runs.len() > CONFIRM_THRESHOLD {
         finish_spinner(spinner);
 
-        if !auto_confirm && !cli.batch {
-            let confirm = Confirm::new()
-                .with_prompt(format!(
-                    "Found more than {} runs. Continue fetching all?",
-                    CONFIRM_THRESHOLD
-                ))
-                .default(false)
-                .interact()?;
+        if !auto_confirm && !cli.batch && !limit_within_threshold(cli) {
+            let prompt = format!(
+                "Found more than {} runs. Continue fetching all?",
+                CONFIRM_THRESHOLD
+            );
+            let confirm = confirm_with_timeout(&prompt, false, cli.prompt_timeout).await?;
 
             if !confirm {
                 return Ok(initial_runs.into_iter().take(CONFIRM_THRESHOLD).collect());
             }
-        } else if cli.batch && !auto_confirm {
-            // In batch mode without --yes, limit to threshold
+        } else if !auto_confirm {
+            // In batch mode or with a small --limit, without --yes, limit to threshold
             return Ok(initial_runs.into_iter().take(CONFIRM_THRESHOLD).collect());
         }
 
@@ -238,20 +405,18 @@ async fn fetch_org_runs(
     if initial_runs.len() > CONFIRM_THRESHOLD {
         finish_spinner(spinner);
 
-        if !auto_confirm && !cli.batch {
-            let confirm = Confirm::new()
-                .with_prompt(format!(
-                    "Found more than {} runs. Continue fetching all?",
-                    CONFIRM_THRESHOLD
-                ))
-                .default(false)
-                .interact()?;
+        if !auto_confirm && !cli.batch && !limit_within_threshold(cli) {
+            let prompt = format!(
+                "Found more than {} runs. Continue fetching all?",
+                CONFIRM_THRESHOLD
+            );
+            let confirm = confirm_with_timeout(&prompt, false, cli.prompt_timeout).await?;
 
             if !confirm {
                 return Ok(initial_runs.into_iter().take(CONFIRM_THRESHOLD).collect());
             }
-        } else if cli.batch && !auto_confirm {
-            // In batch mode without --yes, limit to threshold
+        } else if !auto_confirm {
+            // In batch mode or with a small --limit, without --yes, limit to threshold
             return Ok(initial_runs.into_iter().take(CONFIRM_THRESHOLD).collect());
         }
 
@@ -266,20 +431,195 @@ async fn fetch_org_runs(
     Ok(initial_runs)
 }
 
+/// Keep only runs awaiting apply confirmation when `confirmable` is set
+fn filter_confirmable(runs: Vec<Run>, confirmable: bool) -> Vec<Run> {
+    if !confirmable {
+        return runs;
+    }
+    runs.into_iter().filter(|r| r.is_confirmable()).collect()
+}
+
+/// Fetch the created-at timestamp of the run used as a `--newer-than` cursor
+async fn resolve_cursor(
+    client: &TfeClient,
+    run_id: &str,
+) -> Result<DateTime<Utc>, Box<dyn std::error::Error>> {
+    if !run_id.starts_with("run-") {
+        return Err(format!(
+            "Invalid --newer-than run ID '{}'. Run IDs must start with 'run-'",
+            run_id
+        )
+        .into());
+    }
+    let run = match client.get_run_by_id(run_id).await {
+        Ok(Some((run, _))) => run,
+        Ok(None) => return Err(format!("--newer-than run '{}' not found", run_id).into()),
+        Err(e) => {
+            return Err(format!("Failed to fetch --newer-than run '{}': {}", run_id, e).into())
+        }
+    };
+    parse_created_at(&run)
+        .ok_or_else(|| format!("--newer-than run '{}' has no created-at timestamp", run_id).into())
+}
+
+/// Parse a run's created-at timestamp
+fn parse_created_at(run: &Run) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(run.created_at())
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Keep only runs created strictly after `cutoff` (runs without a timestamp are dropped)
+fn filter_newer_than(runs: Vec<Run>, cutoff: &DateTime<Utc>) -> Vec<Run> {
+    runs.into_iter()
+        .filter(|r| parse_created_at(r).is_some_and(|t| t > *cutoff))
+        .collect()
+}
+
+/// Print the current run of a workspace as `<status> <run-id>`
+///
+/// Exit status follows the run: success only when it finished without failure.
+/// With `--wait`, polls until the run reaches a final state first, failing once
+/// `--wait-timeout` elapses.
+async fn get_current_run(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &RunArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+    let target = args.ws.as_deref().expect("--current requires --ws");
+    let effective_org = client.effective_org(None);
+    let resolved = resolve_workspace(
+        client,
+        target,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
+    let run_id = extract_current_run_id(&resolved.raw)?;
+
+    let mut run = fetch_run(client, &run_id).await?;
+    if args.wait {
+        let timeout = args.wait_timeout.map(Duration::from_secs);
+        run = wait_for_final_run(client, run, POLL_INTERVAL, timeout, cli.batch).await?;
+    }
+
+    output_current_run(&run, cli.id_only);
+
+    current_run_outcome(&run)
+}
+
+/// Poll a run every `interval` until it reaches a final state
+///
+/// Fails if the run is still active once `timeout` has elapsed.
+async fn wait_for_final_run(
+    client: &TfeClient,
+    mut run: Run,
+    interval: Duration,
+    timeout: Option<Duration>,
+    batch: bool,
+) -> Result<Run, Box<dyn std::error::Error>> {
+    if is_final_run(&run) {
+        return Ok(run);
+    }
+    let run_id = run.id.clone();
+    let deadline = timeout.map(|t| tokio::time::Instant::now() + t);
+    let spinner = create_spinner(
+        &format!("Waiting for run '{}' ({})...", run_id, run.status()),
+        batch,
+    );
+    while !is_final_run(&run) {
+        if let Some(deadline) = deadline {
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                finish_spinner(spinner);
+                return Err(format!(
+                    "Timed out after {}s waiting for run '{}' (last status: {})",
+                    timeout.unwrap_or_default().as_secs(),
+                    run_id,
+                    run.status()
+                )
+                .into());
+            }
+            sleep(interval.min(deadline - now)).await;
+        } else {
+            sleep(interval).await;
+        }
+        run = fetch_run(client, &run_id).await?;
+        if let Some(s) = &spinner {
+            s.set_message(format!(
+                "Waiting for run '{}' ({})...",
+                run_id,
+                run.status()
+            ));
+        }
+    }
+    finish_spinner(spinner);
+    Ok(run)
+}
+
+/// Fetch a run by ID, treating "not found" as an error
+async fn fetch_run(client: &TfeClient, run_id: &str) -> Result<Run, Box<dyn std::error::Error>> {
+    match client.get_run_by_id(run_id).await? {
+        Some((run, _)) => Ok(run),
+        None => Err(format!("Run '{}' not found", run_id).into()),
+    }
+}
+
+/// Whether a run has reached a final state
+///
+/// Unknown statuses count as final so `--wait` cannot poll forever on a status
+/// this version does not recognize; [`current_run_outcome`] reports them.
+fn is_final_run(run: &Run) -> bool {
+    !run.status()
+        .parse::<RunStatus>()
+        .is_ok_and(|s| s.is_non_final())
+}
+
+/// Map the current run's state to the command result
+fn current_run_outcome(run: &Run) -> Result<(), Box<dyn std::error::Error>> {
+    match run.status().parse::<RunStatus>() {
+        Ok(status) if status.is_successful() => Ok(()),
+        Ok(status) if !status.is_non_final() => {
+            Err(format!("Run '{}' finished with status '{}'", run.id, status).into())
+        }
+        Err(_) => Err(format!(
+            "Run '{}' has unrecognized status '{}'",
+            run.id,
+            run.status()
+        )
+        .into()),
+        Ok(_) => Err(format!(
+            "Run '{}' is still active ({}); use --wait to block until it finishes",
+            run.id,
+            run.status()
+        )
+        .into()),
+    }
+}
+
 /// Sort runs by the specified field
-fn sort_runs(mut runs: Vec<Run>, sort_field: RunSortField, reverse: bool) -> Vec<Run> {
+///
+/// Fields are applied in priority order; later fields only break ties.
+fn sort_runs(mut runs: Vec<Run>, sort_fields: &[RunSortField], reverse: bool) -> Vec<Run> {
     runs.sort_by(|a, b| {
-        let cmp = match sort_field {
-            RunSortField::CreatedAt => {
-                // Default: newest first (reverse chronological)
-                b.created_at().cmp(a.created_at())
-            }
-            RunSortField::Status => a.status().cmp(b.status()),
-            RunSortField::WsId => a
-                .workspace_id()
-                .unwrap_or("")
-                .cmp(b.workspace_id().unwrap_or("")),
-        };
+        let cmp = sort_fields
+            .iter()
+            .map(|field| match field {
+                RunSortField::CreatedAt => {
+                    // Default: newest first (reverse chronological)
+                    b.created_at().cmp(a.created_at())
+                }
+                RunSortField::Status => a.status().cmp(b.status()),
+                RunSortField::WsId => a
+                    .workspace_id()
+                    .unwrap_or("")
+                    .cmp(b.workspace_id().unwrap_or("")),
+            })
+            .find(|ord| ord.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal);
 
         if reverse {
             cmp.reverse()
@@ -316,6 +656,196 @@ async fn fetch_and_output_subresource(
         RunSubresource::Apply => {
             fetch_and_output_apply(client, cli, run_id, args.get_log, args.tail_log).await
         }
+        RunSubresource::Config => fetch_and_output_config(client, cli, run_raw).await,
+        RunSubresource::State => fetch_and_output_state(client, cli, run_id).await,
+        RunSubresource::CostEstimate => fetch_and_output_cost_estimate(client, cli, run_raw).await,
+        RunSubresource::PolicyChecks => fetch_and_output_policy_checks(client, cli, run_raw).await,
+    }
+}
+
+/// Fetch and output the cost estimate of a run
+///
+/// Runs in workspaces without cost estimation have no `cost-estimate`
+/// relationship (or one that no longer resolves); both are reported as
+/// "no cost estimate available" rather than as an API error.
+async fn fetch_and_output_cost_estimate(
+    client: &TfeClient,
+    cli: &Cli,
+    run_raw: &serde_json::Value,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::Run(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let run_id = run_raw["data"]["id"].as_str().unwrap_or_default();
+    let not_available = || {
+        format!(
+            "No cost estimate available for run '{}' (cost estimation is not enabled \
+             for this organization, or the run has not reached cost estimation)",
+            run_id
+        )
+    };
+
+    let ce_id = run_raw["data"]["relationships"]["cost-estimate"]["data"]["id"]
+        .as_str()
+        .ok_or_else(not_available)?;
+
+    let spinner = create_spinner("Fetching cost estimate...", cli.batch);
+    let result = client.get_cost_estimate(ce_id).await;
+    finish_spinner(spinner);
+
+    let ce = match result {
+        Ok(ce) => ce,
+        Err(TfeError::Api { status: 404, .. }) => return Err(not_available().into()),
+        Err(e) => return Err(e.into()),
+    };
+
+    // Create raw JSON for JSON/YAML output
+    let raw_json = serde_json::json!({
+        "data": {
+            "id": ce.id,
+            "type": "cost-estimates",
+            "attributes": {
+                "status": ce.attributes.status,
+                "prior-monthly-cost": ce.attributes.prior_monthly_cost,
+                "proposed-monthly-cost": ce.attributes.proposed_monthly_cost,
+                "delta-monthly-cost": ce.attributes.delta_monthly_cost,
+                "resources-count": ce.attributes.resources_count,
+                "matched-resources-count": ce.attributes.matched_resources_count,
+                "unmatched-resources-count": ce.attributes.unmatched_resources_count,
+                "error-message": ce.attributes.error_message
+            }
+        }
+    });
+    output_cost_estimate(&ce, &args.output, cli.no_header, &raw_json);
+    Ok(())
+}
+
+/// Fetch and output the state version(s) produced by a run's apply
+async fn fetch_and_output_state(
+    client: &TfeClient,
+    cli: &Cli,
+    run_id: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::Run(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let spinner = create_spinner("Fetching state versions produced by run...", cli.batch);
+
+    let apply = match client.get_run_apply(run_id).await {
+        Ok(apply) => apply,
+        Err(e) => {
+            finish_spinner(spinner);
+            return Err(e.into());
+        }
+    };
+
+    let sv_ids = apply.state_version_ids();
+    if sv_ids.is_empty() {
+        finish_spinner(spinner);
+        return Err(format!(
+            "Run '{}' has not produced a state version (apply status: {})",
+            run_id,
+            apply.status()
+        )
+        .into());
+    }
+
+    let results = join_all(sv_ids.iter().map(|id| client.get_state_version(id))).await;
+    finish_spinner(spinner);
+
+    let mut states = results.into_iter().collect::<Result<Vec<_>, _>>()?;
+    states.sort_by_key(|sv| std::cmp::Reverse(sv.attributes.serial));
+
+    let deltas = vec![None; states.len()];
+    output_state_versions(&states, &deltas, &args.output, cli.no_header);
+    Ok(())
+}
+
+/// Fetch and output the configuration version a run was created from
+async fn fetch_and_output_config(
+    client: &TfeClient,
+    cli: &Cli,
+    run_raw: &serde_json::Value,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::Run(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let cv_id = run_raw["data"]["relationships"]["configuration-version"]["data"]["id"]
+        .as_str()
+        .ok_or("No 'configuration-version' relationship found for this run")?;
+
+    let spinner = create_spinner("Fetching configuration version...", cli.batch);
+
+    match client.get_configuration_version(cv_id).await {
+        Ok(cv) => {
+            finish_spinner(spinner);
+
+            // Create raw JSON for JSON/YAML output
+            let raw_json = serde_json::json!({
+                "data": {
+                    "id": cv.id,
+                    "type": "configuration-versions",
+                    "attributes": {
+                        "source": cv.attributes.source,
+                        "status": cv.attributes.status,
+                        "speculative": cv.attributes.speculative,
+                        "provisional": cv.attributes.provisional,
+                        "error-message": cv.attributes.error_message
+                    }
+                }
+            });
+            output_configuration_version(&cv, &args.output, cli.no_header, &raw_json);
+            Ok(())
+        }
+        Err(e) => {
+            finish_spinner(spinner);
+            Err(e.into())
+        }
+    }
+}
+
+/// Fetch and output the policy checks (Sentinel/OPA results) of a run
+async fn fetch_and_output_policy_checks(
+    client: &TfeClient,
+    cli: &Cli,
+    run_raw: &serde_json::Value,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::Run(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let url = run_raw["data"]["relationships"]["policy-checks"]["links"]["related"]
+        .as_str()
+        .ok_or("No 'policy-checks' relationship found for this run")?;
+
+    let spinner = create_spinner("Fetching policy-checks...", cli.batch);
+
+    match client.get_subresource(url).await {
+        Ok(raw) => {
+            finish_spinner(spinner);
+            let response: PolicyChecksResponse = serde_json::from_value(raw.clone())?;
+            output_policy_checks(&response.data, &args.output, cli.no_header, &raw);
+            Ok(())
+        }
+        Err(e) => {
+            finish_spinner(spinner);
+            Err(e.into())
+        }
     }
 }
 
@@ -475,15 +1005,104 @@ async fn output_log(
     let content = client.get_log_content(url).await?;
 
     if raw {
-        print!("{}", content);
+        print_text(&content);
     } else {
-        print_human_readable_log(&content);
+        print_text(&format_log_with_prefix(&content, None, false));
     }
     Ok(())
 }
 
 // Use shared log parsing from log_utils module
-use super::log_utils::print_human_readable_log;
+use super::log_utils::{format_log_with_prefix, print_human_readable_log};
+
+/// Fetch plan or apply logs for several runs concurrently
+///
+/// Requests are bounded by `--concurrency`; logs are printed in
+/// the order the run IDs were given, each under a `==> run-xxx (plan log) <==`
+/// header. A failed run is reported on stderr without hiding the others.
+async fn get_logs_for_runs(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &RunArgs,
+    first_run_id: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let is_apply = match args.subresource {
+        Some(RunSubresource::Plan) => false,
+        Some(RunSubresource::Apply) => true,
+        _ => return Err(
+            "Multiple run IDs are only supported with --subresource plan or apply and --get-log"
+                .into(),
+        ),
+    };
+    let run_ids: Vec<&str> = std::iter::once(first_run_id)
+        .chain(args.more_run_ids.iter().map(String::as_str))
+        .collect();
+    if let Some(bad) = run_ids.iter().find(|id| !id.starts_with("run-")) {
+        return Err(format!("Invalid run ID '{}'. Run IDs must start with 'run-'", bad).into());
+    }
+
+    let phase = if is_apply { "apply" } else { "plan" };
+    let spinner = create_spinner(
+        &format!("Fetching {} logs for {} runs...", phase, run_ids.len()),
+        cli.batch,
+    );
+    let results: Vec<(&str, Result<String, String>)> = stream::iter(run_ids.iter().copied())
+        .map(|run_id| async move { (run_id, fetch_log_content(client, run_id, is_apply).await) })
+        .buffered(client.concurrency())
+        .collect()
+        .await;
+    finish_spinner(spinner);
+
+    let mut failed = 0;
+    for (i, (run_id, result)) in results.iter().enumerate() {
+        match result {
+            Ok(content) => {
+                let separator = if i > 0 { "\n" } else { "" };
+                print_text(&format!(
+                    "{}==> {} ({} log) <==\n{}",
+                    separator,
+                    run_id,
+                    phase,
+                    format_log_with_prefix(content, None, args.raw)
+                ));
+            }
+            Err(e) => {
+                failed += 1;
+                eprintln!("Failed to fetch {} log for {}: {}", phase, run_id, e);
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} of {} logs could not be fetched", failed, results.len()).into());
+    }
+    Ok(())
+}
+
+/// Download the plan or apply log of one run
+async fn fetch_log_content(
+    client: &TfeClient,
+    run_id: &str,
+    is_apply: bool,
+) -> Result<String, String> {
+    let log_url = if is_apply {
+        client
+            .get_run_apply(run_id)
+            .await
+            .map(|a| a.attributes.log_read_url)
+    } else {
+        client
+            .get_run_plan(run_id)
+            .await
+            .map(|p| p.attributes.log_read_url)
+    }
+    .map_err(|e| e.to_string())?;
+    let url = log_url.ok_or("No log-read-url available for this resource")?;
+    client
+        .get_log_content(&url)
+        .await
+        .map_err(|e| e.to_string())
+}
 
 /// Fetch and print log for a run (plan or apply)
 ///
@@ -611,10 +1230,21 @@ pub async fn run_purge_run_command(
         unreachable!()
     };
 
-    // Step 1: Resolve workspace
     let effective_org = client.effective_org(args.org.as_ref());
-    let resolved =
-        resolve_workspace(client, &args.workspace, effective_org.as_deref(), cli.batch).await?;
+    if cli.explain {
+        print_explain(&plan_purge_run(&args.workspace, effective_org.as_ref()));
+        return Ok(());
+    }
+
+    // Step 1: Resolve workspace
+    let resolved = resolve_workspace(
+        client,
+        &args.workspace,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
     let workspace = &resolved.workspace;
     let ws_id = &workspace.id;
     let ws_name = workspace.name();
@@ -690,10 +1320,10 @@ pub async fn run_purge_run_command(
 
     println!();
 
-    // Confirmation prompt (skipped in batch mode)
-    let prompt = format!("{}Do you want to continue?", dry_run_prefix);
-    if !confirm_action(&prompt, cli.batch)? {
-        println!("\nAborted.");
+    // Typed workspace-name confirmation; skipped with --yes and for dry runs,
+    // --batch alone aborts
+    let skip_prompt = args.yes || args.dry_run;
+    if !confirm_typed(ws_name, skip_prompt, cli.batch, cli.prompt_timeout).await? {
         return Ok(());
     }
 
@@ -821,30 +1451,6 @@ fn determine_action(run: &Run) -> Option<RunAction> {
     None
 }
 
-/// Format age from ISO timestamp
-fn format_age(created_at: Option<&str>) -> String {
-    let Some(ts) = created_at else {
-        return "unknown".to_string();
-    };
-
-    let Ok(dt) = ts.parse::<DateTime<Utc>>() else {
-        return "unknown".to_string();
-    };
-
-    let now = Utc::now();
-    let duration = now.signed_duration_since(dt);
-
-    if duration.num_days() > 0 {
-        format!("{}d {}h", duration.num_days(), duration.num_hours() % 24)
-    } else if duration.num_hours() > 0 {
-        format!("{}h {}m", duration.num_hours(), duration.num_minutes() % 60)
-    } else if duration.num_minutes() > 0 {
-        format!("{}m", duration.num_minutes())
-    } else {
-        format!("{}s", duration.num_seconds())
-    }
-}
-
 /// Output pending runs table using comfy_table
 fn output_pending_runs_table(
     runs: &[Run],
@@ -882,54 +1488,151 @@ fn output_pending_runs_table(
         table.add_row(vec![&run.id, &status, &age, action_str, &url]);
     }
 
-    println!("{}", table);
+    crate::output::print_table(&table, &OutputFormat::Table);
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_confirm_threshold() {
-        assert_eq!(CONFIRM_THRESHOLD, 100);
+/// API calls made by `get run` for the effective organization
+fn plan_get_run(args: &RunArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    if let Some(run_id) = &args.name {
+        if !args.more_run_ids.is_empty() {
+            let phase = match args.subresource {
+                Some(RunSubresource::Apply) => "apply",
+                _ => "plan",
+            };
+            return std::iter::once(run_id)
+                .chain(&args.more_run_ids)
+                .flat_map(|id| {
+                    [
+                        call("GET", format!("/runs/{}/{}", id, phase), "concurrent"),
+                        call("GET", ":log-read-url", "log archive"),
+                    ]
+                })
+                .collect();
+        }
+        let mut calls = vec![call("GET", format!("/runs/{}", run_id), "run")];
+        match &args.subresource {
+            Some(RunSubresource::Events) => calls.push(call(
+                "GET",
+                format!("/runs/{}/run-events", run_id),
+                "events",
+            )),
+            Some(RunSubresource::Plan) => {
+                calls.push(call("GET", format!("/runs/{}/plan", run_id), "plan"))
+            }
+            Some(RunSubresource::Apply) => {
+                calls.push(call("GET", format!("/runs/{}/apply", run_id), "apply"))
+            }
+            Some(RunSubresource::Config) => calls.push(call(
+                "GET",
+                "/configuration-versions/:cv_id",
+                "configuration version",
+            )),
+            Some(RunSubresource::CostEstimate) => {
+                calls.push(call("GET", "/cost-estimates/:ce_id", "cost estimate"))
+            }
+            Some(RunSubresource::PolicyChecks) => calls.push(call(
+                "GET",
+                format!("/runs/{}/policy-checks", run_id),
+                "policy checks",
+            )),
+            Some(RunSubresource::State) => {
+                calls.push(call("GET", format!("/runs/{}/apply", run_id), "apply"));
+                calls.push(call(
+                    "GET",
+                    "/state-versions/:sv_id",
+                    "per produced state version",
+                ));
+            }
+            None => {}
+        }
+        if args.get_log || args.tail_log {
+            calls.push(call(
+                "GET",
+                format!("/runs/{}/plan", run_id),
+                "plan (or /apply)",
+            ));
+            calls.push(call("GET", ":log-read-url", "log archive"));
+        }
+        return calls;
     }
 
-    #[test]
-    fn test_format_age_minutes() {
-        // Recent timestamp - should show minutes
-        let now = Utc::now();
-        let five_min_ago = now - chrono::Duration::minutes(5);
-        let ts = five_min_ago.to_rfc3339();
-        let age = format_age(Some(&ts));
-        assert!(age.contains("m") || age.contains("s"));
+    if args.current {
+        let mut calls = workspace_lookup(args.ws.as_deref().unwrap_or(":ws"), org);
+        calls.push(call(
+            "GET",
+            "/runs/:current_run_id",
+            if args.wait {
+                "current run (workspace relationship), polled until final"
+            } else {
+                "current run (workspace relationship)"
+            },
+        ));
+        return calls;
     }
 
-    #[test]
-    fn test_format_age_hours() {
-        let now = Utc::now();
-        let two_hours_ago = now - chrono::Duration::hours(2);
-        let ts = two_hours_ago.to_rfc3339();
-        let age = format_age(Some(&ts));
-        assert!(age.contains("h"));
-    }
+    let mut calls = match &args.newer_than {
+        Some(cursor) => vec![call(
+            "GET",
+            format!("/runs/{}", cursor),
+            "--newer-than cursor",
+        )],
+        None => vec![],
+    };
+    let note = match args.watch {
+        Some(secs) => format!("paginated, repeated every {}s (--watch)", secs),
+        None => "paginated".to_string(),
+    };
+    let filter = if args.all {
+        ""
+    } else if args.final_only {
+        "?filter[status_group]=final"
+    } else {
+        "?filter[status_group]=non_final"
+    };
+    calls.extend(match &args.ws {
+        Some(ws) => vec![call(
+            "GET",
+            format!("/workspaces/{}/runs{}", ws, filter),
+            note,
+        )],
+        None => vec![call(
+            "GET",
+            format!("/organizations/{}/runs{}", org_path(org), filter),
+            note,
+        )],
+    });
+    calls
+}
 
-    #[test]
-    fn test_format_age_days() {
-        let now = Utc::now();
-        let two_days_ago = now - chrono::Duration::days(2);
-        let ts = two_days_ago.to_rfc3339();
-        let age = format_age(Some(&ts));
-        assert!(age.contains("d"));
-    }
+/// API calls made by `purge run` for the effective organization
+fn plan_purge_run(workspace: &str, org: Option<&String>) -> Vec<PlannedCall> {
+    let mut calls = workspace_lookup(workspace, org);
+    calls.push(call(
+        "GET",
+        "/workspaces/:ws_id/runs?filter[status_group]=non_final",
+        "pending/active runs",
+    ));
+    calls.push(call(
+        "GET",
+        "/runs/:current_run_id",
+        "current run (workspace relationship)",
+    ));
+    calls.push(call(
+        "POST",
+        "/runs/:run_id/actions/cancel",
+        "per run (or /actions/discard)",
+    ));
+    calls
+}
 
-    #[test]
-    fn test_format_age_none() {
-        assert_eq!(format_age(None), "unknown");
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
 
     #[test]
-    fn test_format_age_invalid() {
-        assert_eq!(format_age(Some("not-a-date")), "unknown");
+    fn test_confirm_threshold() {
+        assert_eq!(CONFIRM_THRESHOLD, 100);
     }
 
     #[test]
@@ -1013,5 +1716,603 @@ mod tests {
         assert!(determine_action(&run).is_none());
     }
 
+    fn run_with_confirmable(id: &str, confirmable: Option<bool>) -> Run {
+        Run {
+            id: id.to_string(),
+            attributes: crate::hcp::runs::RunAttributes {
+                status: "planned".to_string(),
+                message: None,
+                source: None,
+                created_at: None,
+                has_changes: None,
+                is_destroy: None,
+                plan_only: None,
+                auto_apply: None,
+                trigger_reason: None,
+                actions: Some(crate::hcp::runs::RunActions {
+                    is_cancelable: None,
+                    is_confirmable: confirmable,
+                    is_discardable: None,
+                    is_force_cancelable: None,
+                }),
+                status_timestamps: None,
+            },
+            relationships: None,
+        }
+    }
+
+    #[test]
+    fn test_filter_confirmable_excludes_non_confirmable() {
+        let runs = vec![
+            run_with_confirmable("run-a", Some(true)),
+            run_with_confirmable("run-b", Some(false)),
+            run_with_confirmable("run-c", None),
+        ];
+        let filtered = filter_confirmable(runs, true);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "run-a");
+    }
+
+    #[test]
+    fn test_filter_confirmable_disabled_keeps_all() {
+        let runs = vec![
+            run_with_confirmable("run-a", Some(true)),
+            run_with_confirmable("run-b", Some(false)),
+        ];
+        assert_eq!(filter_confirmable(runs, false).len(), 2);
+    }
+
+    fn run_with_status(status: &str) -> Run {
+        let mut run = run_with_confirmable("run-x", None);
+        run.attributes.status = status.to_string();
+        run
+    }
+
+    #[test]
+    fn test_current_run_outcome_success() {
+        assert!(current_run_outcome(&run_with_status("applied")).is_ok());
+        assert!(current_run_outcome(&run_with_status("planned_and_finished")).is_ok());
+    }
+
+    #[test]
+    fn test_current_run_outcome_failed() {
+        let err = current_run_outcome(&run_with_status("errored")).unwrap_err();
+        assert!(err.to_string().contains("finished with status 'errored'"));
+        assert!(current_run_outcome(&run_with_status("discarded")).is_err());
+    }
+
+    #[test]
+    fn test_current_run_outcome_active() {
+        let err = current_run_outcome(&run_with_status("planning")).unwrap_err();
+        assert!(err.to_string().contains("still active"));
+    }
+
+    #[test]
+    fn test_is_final_run() {
+        assert!(is_final_run(&run_with_status("applied")));
+        assert!(is_final_run(&run_with_status("canceled")));
+        assert!(!is_final_run(&run_with_status("applying")));
+        assert!(is_final_run(&run_with_status("some_new_status")));
+    }
+
+    #[test]
+    fn test_current_run_outcome_unknown_status() {
+        let err = current_run_outcome(&run_with_status("some_new_status")).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unrecognized status 'some_new_status'"));
+    }
+
+    #[test]
+    fn test_sort_runs_two_keys() {
+        let mut a = run_with_status("planning");
+        a.id = "run-a".to_string();
+        a.attributes.created_at = Some("2024-01-01T00:00:00Z".to_string());
+        let mut b = run_with_status("applying");
+        b.id = "run-b".to_string();
+        b.attributes.created_at = Some("2024-01-01T00:00:00Z".to_string());
+        let mut c = run_with_status("applying");
+        c.id = "run-c".to_string();
+        c.attributes.created_at = Some("2024-02-01T00:00:00Z".to_string());
+
+        let sorted = sort_runs(
+            vec![a, b, c],
+            &[RunSortField::Status, RunSortField::CreatedAt],
+            false,
+        );
+        let ids: Vec<_> = sorted.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["run-c", "run-b", "run-a"]);
+    }
+
+    fn run_created_at(id: &str, created_at: Option<&str>) -> Run {
+        let mut run = run_with_confirmable(id, None);
+        run.attributes.created_at = created_at.map(str::to_string);
+        run
+    }
+
+    #[tokio::test]
+    async fn test_watch_rejected_in_batch_mode() {
+        let client = TfeClient::test_client("http://127.0.0.1:1");
+        let cli = Cli::parse_from(["hcp", "--batch", "get", "run", "--org", "o", "--watch"]);
+        let err = run_runs_command(&client, &cli).await.unwrap_err();
+        assert!(err.to_string().contains("--batch"));
+    }
+
+    #[tokio::test]
+    async fn test_watch_rejected_with_output_file() {
+        let client = TfeClient::test_client("http://127.0.0.1:1");
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "--org",
+            "o",
+            "--watch",
+            "--output-file",
+            "runs.txt",
+        ]);
+        let err = run_runs_command(&client, &cli).await.unwrap_err();
+        assert!(err.to_string().contains("--output-file"));
+    }
+
+    #[test]
+    fn test_filter_newer_than_drops_at_or_before_cursor() {
+        let cutoff = DateTime::parse_from_rfc3339("2024-03-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let runs = vec![
+            run_created_at("run-old", Some("2024-03-01T11:59:59Z")),
+            run_created_at("run-same", Some("2024-03-01T12:00:00Z")),
+            run_created_at("run-new", Some("2024-03-01T12:00:01.5Z")),
+            run_created_at("run-offset", Some("2024-03-01T14:30:00+02:00")),
+            run_created_at("run-none", None),
+        ];
+        let ids: Vec<_> = filter_newer_than(runs, &cutoff)
+            .into_iter()
+            .map(|r| r.id)
+            .collect();
+        assert_eq!(ids, ["run-new", "run-offset"]);
+    }
+
+    fn run_args(argv: &[&str]) -> RunArgs {
+        let cli = Cli::parse_from(argv);
+        match cli.command {
+            Command::Get {
+                resource: GetResource::Run(args),
+            } => args,
+            _ => panic!("Expected Get Run command"),
+        }
+    }
+
+    #[test]
+    fn test_build_run_query_status_groups() {
+        let query = build_run_query(&run_args(&["hcp", "get", "run", "--org", "o"])).unwrap();
+        assert_eq!(query.status_group.as_deref(), Some("non_final"));
+
+        let args = run_args(&["hcp", "get", "run", "--org", "o", "--final"]);
+        assert_eq!(
+            build_run_query(&args).unwrap().status_group.as_deref(),
+            Some("final")
+        );
+        assert_eq!(runs_label(&args), "completed runs");
+
+        let args = run_args(&["hcp", "get", "run", "--org", "o", "--all"]);
+        assert!(build_run_query(&args).unwrap().status_group.is_none());
+        assert_eq!(runs_label(&args), "runs");
+    }
+
+    #[test]
+    fn test_build_run_query_validates_status_against_group() {
+        let err = build_run_query(&run_args(&[
+            "hcp", "get", "run", "--org", "o", "--status", "applied",
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("--final or --all"));
+
+        let err = build_run_query(&run_args(&[
+            "hcp", "get", "run", "--org", "o", "--final", "--status", "planning",
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("non-final status"));
+
+        let query = build_run_query(&run_args(&[
+            "hcp",
+            "get",
+            "run",
+            "--org",
+            "o",
+            "--all",
+            "--status",
+            "applied,planning",
+        ]))
+        .unwrap();
+        assert_eq!(
+            query.statuses,
+            Some(vec![RunStatus::Applied, RunStatus::Planning])
+        );
+
+        let err = build_run_query(&run_args(&[
+            "hcp", "get", "run", "--org", "o", "--status", "bogus",
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid status"));
+    }
+
+    #[tokio::test]
+    async fn test_cost_estimate_unavailable_without_relationship() {
+        let client = TfeClient::test_client("http://127.0.0.1:1");
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-a",
+            "--subresource",
+            "cost-estimate",
+        ]);
+        let run_raw = serde_json::json!({
+            "data": {"id": "run-a", "relationships": {"cost-estimate": {"data": null}}}
+        });
+        let err = fetch_and_output_cost_estimate(&client, &cli, &run_raw)
+            .await
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("No cost estimate available for run 'run-a'"));
+    }
+
+    async fn mount_many_org_runs(server: &wiremock::MockServer, count: usize) {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let runs: Vec<_> = (0..count)
+            .map(|i| serde_json::json!({"id": format!("run-{}", i), "attributes": {"status": "x"}}))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/runs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": runs,
+                "meta": {"pagination": {"current-page": 1, "next-page": null, "page-size": 100}}
+            })))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_small_limit_skips_fetch_all_prompt() {
+        let server = wiremock::MockServer::start().await;
+        mount_many_org_runs(&server, CONFIRM_THRESHOLD + 1).await;
+        let client = TfeClient::test_client(&server.uri());
+        let cli = Cli::parse_from(["hcp", "get", "run", "--org", "my-org", "-n", "10"]);
+
+        // Not batch and no --yes: without the limit this would prompt
+        let runs = fetch_org_runs(&client, &cli, "my-org", RunQuery::non_final(), false)
+            .await
+            .unwrap();
+        assert_eq!(runs.len(), CONFIRM_THRESHOLD);
+    }
+
+    #[test]
+    fn test_limit_within_threshold() {
+        let limit = |argv: &[&str]| limit_within_threshold(&Cli::parse_from(argv));
+        assert!(limit(&["hcp", "get", "run", "-n", "100"]));
+        assert!(!limit(&["hcp", "get", "run", "-n", "101"]));
+        assert!(!limit(&["hcp", "get", "run"]));
+    }
+
+    #[tokio::test]
+    async fn test_policy_checks_requires_relationship() {
+        let client = TfeClient::test_client("http://127.0.0.1:1");
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-a",
+            "--subresource",
+            "policy-checks",
+        ]);
+        let run_raw = serde_json::json!({"data": {"id": "run-a", "relationships": {}}});
+        let err = fetch_and_output_policy_checks(&client, &cli, &run_raw)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("'policy-checks'"));
+    }
+
+    #[tokio::test]
+    async fn test_cost_estimate_unavailable_on_not_found() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/cost-estimates/ce-gone"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        let client = TfeClient::test_client(&server.uri());
+        let cli = Cli::parse_from([
+            "hcp",
+            "--batch",
+            "get",
+            "run",
+            "run-a",
+            "--subresource",
+            "cost-estimate",
+        ]);
+        let run_raw = serde_json::json!({
+            "data": {
+                "id": "run-a",
+                "relationships": {"cost-estimate": {"data": {"id": "ce-gone"}}}
+            }
+        });
+        let err = fetch_and_output_cost_estimate(&client, &cli, &run_raw)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("No cost estimate available"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_with_cache_sees_status_change() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let run_json = |status: &str| {
+            serde_json::json!({
+                "data": {"id": "run-a", "type": "runs", "attributes": {"status": status}}
+            })
+        };
+        let server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/runs/run-a"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(run_json("planning")))
+            .up_to_n_times(2)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/runs/run-a"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(run_json("applied")))
+            .mount(&server)
+            .await;
+
+        // Configure the client the way main does for this command line
+        let cli = Cli::parse_from([
+            "hcp",
+            "--cache",
+            "--batch",
+            "get",
+            "run",
+            "--ws",
+            "my-ws",
+            "--current",
+            "--wait",
+        ]);
+        let dir = tempfile::tempdir().unwrap();
+        let mut client = TfeClient::test_client(&server.uri());
+        if let Some(ttl) = cli.disk_cache_ttl() {
+            client.enable_disk_cache_in(dir.path().to_path_buf(), ttl);
+        }
+
+        let run = fetch_run(&client, "run-a").await.unwrap();
+        assert_eq!(run.status(), "planning");
+        let run = wait_for_final_run(&client, run, Duration::from_millis(10), None, true)
+            .await
+            .unwrap();
+        assert_eq!(run.status(), "applied");
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_final_run_times_out() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/runs/run-x"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"id": "run-x", "attributes": {"status": "planning"}}
+            })))
+            .mount(&server)
+            .await;
+        let client = TfeClient::test_client(&server.uri());
+
+        let err = wait_for_final_run(
+            &client,
+            run_with_status("planning"),
+            Duration::from_millis(10),
+            Some(Duration::from_millis(50)),
+            true,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.to_string().contains("Timed out"), "{}", err);
+        assert!(err.to_string().contains("planning"), "{}", err);
+    }
+
+    #[test]
+    fn test_get_run_wait_timeout_requires_wait() {
+        assert!(Cli::try_parse_from([
+            "hcp",
+            "get",
+            "run",
+            "--ws",
+            "ws-1",
+            "--current",
+            "--wait-timeout",
+            "5"
+        ])
+        .is_err());
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "--ws",
+            "ws-1",
+            "--current",
+            "--wait",
+            "--wait-timeout",
+            "5",
+        ]);
+        let Command::Get {
+            resource: GetResource::Run(args),
+        } = cli.command
+        else {
+            panic!("Expected Get Run command");
+        };
+        assert_eq!(args.wait_timeout, Some(5));
+    }
+
+    async fn mount_plan_log(server: &wiremock::MockServer, run_id: &str, log: &str) {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let log_path = format!("/logs/{}", run_id);
+        Mock::given(method("GET"))
+            .and(path(format!("/runs/{}/plan", run_id)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "id": format!("plan-{}", run_id),
+                    "attributes": {
+                        "status": "finished",
+                        "log-read-url": format!("{}{}", server.uri(), log_path)
+                    }
+                }
+            })))
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(log_path))
+            .respond_with(ResponseTemplate::new(200).set_body_string(log))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_fetch_log_content_for_plan() {
+        let server = wiremock::MockServer::start().await;
+        mount_plan_log(&server, "run-a", "Terraform v1.9.0\n").await;
+        let client = TfeClient::test_client(&server.uri());
+
+        let content = fetch_log_content(&client, "run-a", false).await.unwrap();
+        assert_eq!(content, "Terraform v1.9.0\n");
+        assert!(fetch_log_content(&client, "run-missing", false)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_logs_for_runs_reports_failures() {
+        let server = wiremock::MockServer::start().await;
+        mount_plan_log(&server, "run-a", "ok\n").await;
+        let client = TfeClient::test_client(&server.uri());
+        let cli = Cli::parse_from([
+            "hcp",
+            "--batch",
+            "get",
+            "run",
+            "run-a",
+            "run-missing",
+            "--subresource",
+            "plan",
+            "--get-log",
+        ]);
+
+        let err = run_runs_command(&client, &cli).await.unwrap_err();
+        assert_eq!(err.to_string(), "1 of 2 logs could not be fetched");
+    }
+
+    #[tokio::test]
+    async fn test_logs_for_runs_validation() {
+        let client = TfeClient::test_client("http://127.0.0.1:1");
+
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-a",
+            "run-b",
+            "--subresource",
+            "events",
+            "--get-log",
+        ]);
+        let err = run_runs_command(&client, &cli).await.unwrap_err();
+        assert!(err.to_string().contains("--subresource plan or apply"));
+
+        let cli = Cli::parse_from([
+            "hcp",
+            "get",
+            "run",
+            "run-a",
+            "ws-b",
+            "--subresource",
+            "plan",
+            "--get-log",
+        ]);
+        let err = run_runs_command(&client, &cli).await.unwrap_err();
+        assert!(err.to_string().contains("Invalid run ID 'ws-b'"));
+    }
+
     // Note: print_human_readable_log tests moved to log_utils module
+
+    fn plan(argv: &[&str], org: Option<&str>) -> Vec<PlannedCall> {
+        let cli = Cli::parse_from(argv);
+        let Command::Get {
+            resource: GetResource::Run(args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        plan_get_run(args, org.map(str::to_string).as_ref())
+    }
+
+    #[test]
+    fn test_plan_get_run_logs_for_several_runs() {
+        let calls = plan(
+            &[
+                "hcp",
+                "get",
+                "run",
+                "run-a",
+                "run-b",
+                "--subresource",
+                "apply",
+                "--get-log",
+            ],
+            None,
+        );
+        let paths: Vec<_> = calls.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/runs/run-a/apply",
+                ":log-read-url",
+                "/runs/run-b/apply",
+                ":log-read-url"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_get_run_status_group() {
+        let calls = plan(&["hcp", "get", "run", "--org", "o", "--final"], Some("o"));
+        assert_eq!(
+            calls.last().unwrap().path,
+            "/organizations/o/runs?filter[status_group]=final"
+        );
+        let calls = plan(&["hcp", "get", "run", "--ws", "ws-1", "--all"], None);
+        assert_eq!(calls.last().unwrap().path, "/workspaces/ws-1/runs");
+    }
+
+    #[test]
+    fn test_plan_current_run_uses_context_org() {
+        let calls = plan(
+            &["hcp", "get", "run", "--ws", "app", "--current"],
+            Some("ctx"),
+        );
+        let paths: Vec<_> = calls.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            ["/organizations/ctx/workspaces/app", "/runs/:current_run_id"]
+        );
+    }
 }
@@ -57,6 +57,15 @@ pub fn print_human_readable_log(content: &str) {
 /// * `prefix` - Optional prefix to prepend to each line (e.g., run ID)
 /// * `raw` - If true, output raw content; if false, extract @message from JSON
 pub fn print_log_with_prefix(content: &str, prefix: Option<&str>, raw: bool) {
+    print!("{}", format_log_with_prefix(content, prefix, raw));
+    io::stdout().flush().ok();
+}
+
+/// Render log content as printed by [`print_log_with_prefix`]
+///
+/// Each kept line ends with a newline; empty JSON messages are dropped.
+pub fn format_log_with_prefix(content: &str, prefix: Option<&str>, raw: bool) -> String {
+    let mut text = String::new();
     for line in content.lines() {
         let message = if raw {
             line.to_string()
@@ -70,11 +79,11 @@ pub fn print_log_with_prefix(content: &str, prefix: Option<&str>, raw: bool) {
         }
 
         match prefix {
-            Some(p) => println!("[{}] {}", p, message),
-            None => println!("{}", message),
+            Some(p) => text.push_str(&format!("[{}] {}\n", p, message)),
+            None => text.push_str(&format!("{}\n", message)),
         }
     }
-    io::stdout().flush().ok();
+    text
 }
 
 #[cfg(test)]
@@ -142,4 +151,17 @@ Plain text"#;
         // Should not panic, parsed mode
         print_log_with_prefix(content, Some("run-123"), false);
     }
+
+    #[test]
+    fn test_format_log_with_prefix() {
+        let content = "{\"@message\":\"Plan: 1 to add\"}\n{\"type\":\"version\"}\nplain";
+        assert_eq!(
+            format_log_with_prefix(content, Some("run-1"), false),
+            "[run-1] Plan: 1 to add\n[run-1] plain\n"
+        );
+        assert_eq!(
+            format_log_with_prefix(content, None, true),
+            format!("{}\n", content)
+        );
+    }
 }
@@ -1,14 +1,23 @@
 //! Runs module
 
 mod api;
+mod apply_commands;
 mod commands;
 pub mod log_utils;
 mod models;
+mod override_commands;
 
+pub use apply_commands::run_apply_run_command;
 pub use commands::{fetch_and_print_log, run_purge_run_command, run_runs_command, tail_log};
-pub use log_utils::{extract_log_message, print_human_readable_log, print_log_with_prefix};
+pub use log_utils::{
+    extract_log_message, format_log_with_prefix, print_human_readable_log, print_log_with_prefix,
+};
 pub use models::{
-    count_runs_by_workspace, format_duration, Apply, ApplyAttributes, ApplyResponse, Plan,
-    PlanAttributes, PlanResponse, Run, RunActions, RunAttributes, RunEvent, RunEventsResponse,
-    RunPagination, RunPaginationMeta, RunQuery, RunRelationships, RunStatus, RunsResponse,
+    count_runs_by_workspace, format_duration, Apply, ApplyAttributes, ApplyRelationships,
+    ApplyResponse, ApplyStateVersionsRelationship, CostEstimate, CostEstimateAttributes,
+    CostEstimateResponse, Plan, PlanAttributes, PlanResponse, PolicyCheck, PolicyCheckActions,
+    PolicyCheckAttributes, PolicyCheckPermissions, PolicyCheckResponse, PolicyChecksResponse,
+    PolicyResult, Run, RunActions, RunAttributes, RunEvent, RunEventsResponse, RunPagination,
+    RunPaginationMeta, RunQuery, RunRelationships, RunStatus, RunsResponse,
 };
+pub use override_commands::run_override_run_command;
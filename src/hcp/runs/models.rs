@@ -3,7 +3,8 @@
 use serde::Deserialize;
 
 use crate::hcp::traits::TfeResource;
-use crate::hcp::workspaces::RelationshipData;
+use crate::hcp::workspaces::{RelationshipData, RelationshipId};
+use crate::hcp::PageRequest;
 
 /// Individual run statuses for explicit filtering
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -127,10 +128,18 @@ impl RunStatus {
                 | RunStatus::PlannedAndSaved
         )
     }
+
+    /// Check if this is a final status that completed without failure
+    pub fn is_successful(&self) -> bool {
+        matches!(
+            self,
+            RunStatus::Applied | RunStatus::PlannedAndFinished | RunStatus::PlannedAndSaved
+        )
+    }
 }
 
 /// Query options for listing runs
-#[derive(Default, Clone)]
+#[derive(Debug, Default, Clone)]
 pub struct RunQuery {
     /// Filter by status group: "non_final", "final", "discardable"
     pub status_group: Option<String>,
@@ -144,6 +153,8 @@ pub struct RunQuery {
     pub page_size: Option<u32>,
     /// Include all operation types (overrides API default which excludes plan_only)
     pub include_all_operations: bool,
+    /// Fetch only this page and report it on stderr (`--page`/`--page-size`)
+    pub requested_page: Option<PageRequest>,
 }
 
 impl RunQuery {
@@ -155,6 +166,19 @@ impl RunQuery {
         }
     }
 
+    /// Create a query with the final status group (completed runs)
+    pub fn final_only() -> Self {
+        Self {
+            status_group: Some("final".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a query without a status group (runs in any status)
+    pub fn all() -> Self {
+        Self::default()
+    }
+
     /// Create a query filtering only pending runs
     pub fn pending() -> Self {
         Self {
@@ -339,6 +363,144 @@ pub struct RunEventsResponse {
     pub data: Vec<RunEvent>,
 }
 
+/// Policy check from TFE API (GET /runs/:id/policy-checks)
+#[derive(Deserialize, Debug, Clone)]
+pub struct PolicyCheck {
+    pub id: String,
+    pub attributes: PolicyCheckAttributes,
+}
+
+/// Policy check attributes
+#[derive(Deserialize, Debug, Clone)]
+pub struct PolicyCheckAttributes {
+    pub status: String,
+    pub scope: Option<String>,
+    /// Raw result; per-policy outcomes live under `sentinel.data`
+    pub result: Option<serde_json::Value>,
+    pub actions: Option<PolicyCheckActions>,
+    pub permissions: Option<PolicyCheckPermissions>,
+}
+
+/// Policy check actions
+#[derive(Deserialize, Debug, Clone)]
+pub struct PolicyCheckActions {
+    #[serde(rename = "is-overridable")]
+    pub is_overridable: Option<bool>,
+}
+
+/// Policy check permissions of the current token
+#[derive(Deserialize, Debug, Clone)]
+pub struct PolicyCheckPermissions {
+    #[serde(rename = "can-override")]
+    pub can_override: Option<bool>,
+}
+
+/// Outcome of a single policy within a policy check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyResult {
+    pub policy_set: String,
+    pub policy: String,
+    pub enforcement_level: String,
+    /// passed, advisory-failed, soft-failed or hard-failed
+    pub result: &'static str,
+}
+
+impl PolicyResult {
+    /// Whether this is a soft failure (the only kind that can be overridden)
+    pub fn is_soft_failed(&self) -> bool {
+        self.result == "soft-failed"
+    }
+}
+
+impl PolicyCheck {
+    /// Get policy check status (passed, soft_failed, hard_failed, overridden, ...)
+    pub fn status(&self) -> &str {
+        &self.attributes.status
+    }
+
+    /// Whether soft failures of this check can be overridden
+    pub fn is_overridable(&self) -> bool {
+        self.attributes
+            .actions
+            .as_ref()
+            .and_then(|a| a.is_overridable)
+            .unwrap_or(false)
+    }
+
+    /// Whether the check soft-failed and can still be overridden
+    pub fn is_override_pending(&self) -> bool {
+        self.status() == "soft_failed" && self.is_overridable()
+    }
+
+    /// Whether the current token may perform the override
+    pub fn can_override(&self) -> bool {
+        self.attributes
+            .permissions
+            .as_ref()
+            .and_then(|p| p.can_override)
+            .unwrap_or(false)
+    }
+
+    /// Per-policy outcomes, flattened across policy sets
+    ///
+    /// Policies without an explicit `enforcement-level` are classified from
+    /// `allowed-failure` (soft-mandatory when failures are allowed).
+    pub fn policy_results(&self) -> Vec<PolicyResult> {
+        let Some(sets) = self
+            .attributes
+            .result
+            .as_ref()
+            .and_then(|r| r["sentinel"]["data"].as_object())
+        else {
+            return Vec::new();
+        };
+
+        let mut results = Vec::new();
+        for (set_name, set) in sets {
+            let Some(policies) = set["policies"].as_array() else {
+                continue;
+            };
+            for policy in policies {
+                let name = policy["policy"].as_str().unwrap_or("");
+                let name = name.strip_prefix(&format!("{}/", set_name)).unwrap_or(name);
+                let level = match policy["enforcement-level"].as_str() {
+                    Some(level) => level,
+                    None if policy["allowed-failure"].as_bool() == Some(true) => "soft-mandatory",
+                    None => "hard-mandatory",
+                };
+                let result = if policy["result"].as_bool() == Some(true) {
+                    "passed"
+                } else {
+                    match level {
+                        "advisory" => "advisory-failed",
+                        "soft-mandatory" => "soft-failed",
+                        _ => "hard-failed",
+                    }
+                };
+                results.push(PolicyResult {
+                    policy_set: set_name.clone(),
+                    policy: name.to_string(),
+                    enforcement_level: level.to_string(),
+                    result,
+                });
+            }
+        }
+        results
+    }
+}
+
+/// Response wrapper for policy checks
+#[derive(Deserialize, Debug)]
+pub struct PolicyChecksResponse {
+    pub data: Vec<PolicyCheck>,
+}
+
+/// Response wrapper for a single policy check
+#[derive(Deserialize, Debug)]
+pub struct PolicyCheckResponse {
+    pub data: PolicyCheck,
+}
+
 /// Plan data from TFE API (GET /runs/:id/plan)
 #[derive(Deserialize, Debug, Clone)]
 pub struct Plan {
@@ -422,6 +584,21 @@ pub struct PlanResponse {
 pub struct Apply {
     pub id: String,
     pub attributes: ApplyAttributes,
+    #[serde(default)]
+    pub relationships: Option<ApplyRelationships>,
+}
+
+/// Apply relationships from TFE API
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApplyRelationships {
+    #[serde(rename = "state-versions")]
+    pub state_versions: Option<ApplyStateVersionsRelationship>,
+}
+
+/// State versions produced by an apply (contains array of state versions)
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApplyStateVersionsRelationship {
+    pub data: Option<Vec<RelationshipId>>,
 }
 
 /// Apply attributes from TFE API
@@ -468,6 +645,16 @@ impl Apply {
         self.attributes.resource_imports.unwrap_or(0)
     }
 
+    /// Get IDs of the state versions created by this apply
+    pub fn state_version_ids(&self) -> Vec<&str> {
+        self.relationships
+            .as_ref()
+            .and_then(|r| r.state_versions.as_ref())
+            .and_then(|sv| sv.data.as_ref())
+            .map(|data| data.iter().map(|d| d.id.as_str()).collect())
+            .unwrap_or_default()
+    }
+
     /// Get log read URL (temporary, expires in 1 minute)
     pub fn log_read_url(&self) -> Option<&str> {
         self.attributes.log_read_url.as_deref()
@@ -488,6 +675,77 @@ pub struct ApplyResponse {
     pub data: Apply,
 }
 
+/// Cost estimate data from TFE API (GET /cost-estimates/:id)
+#[derive(Deserialize, Debug, Clone)]
+pub struct CostEstimate {
+    pub id: String,
+    pub attributes: CostEstimateAttributes,
+}
+
+/// Cost estimate attributes from TFE API
+///
+/// Monthly costs are decimal strings in USD (e.g. "25.488").
+#[derive(Deserialize, Debug, Clone)]
+pub struct CostEstimateAttributes {
+    pub status: String,
+    #[serde(rename = "prior-monthly-cost")]
+    pub prior_monthly_cost: Option<String>,
+    #[serde(rename = "proposed-monthly-cost")]
+    pub proposed_monthly_cost: Option<String>,
+    #[serde(rename = "delta-monthly-cost")]
+    pub delta_monthly_cost: Option<String>,
+    #[serde(rename = "resources-count")]
+    pub resources_count: Option<i32>,
+    #[serde(rename = "matched-resources-count")]
+    pub matched_resources_count: Option<i32>,
+    #[serde(rename = "unmatched-resources-count")]
+    pub unmatched_resources_count: Option<i32>,
+    #[serde(rename = "error-message")]
+    pub error_message: Option<String>,
+}
+
+impl CostEstimate {
+    /// Get cost estimate status
+    pub fn status(&self) -> &str {
+        &self.attributes.status
+    }
+
+    /// Prior monthly cost in USD, if estimated
+    pub fn prior_monthly_cost(&self) -> Option<f64> {
+        parse_cost(self.attributes.prior_monthly_cost.as_deref())
+    }
+
+    /// Proposed monthly cost in USD, if estimated
+    pub fn proposed_monthly_cost(&self) -> Option<f64> {
+        parse_cost(self.attributes.proposed_monthly_cost.as_deref())
+    }
+
+    /// Monthly cost delta in USD (proposed - prior), if estimated
+    pub fn delta_monthly_cost(&self) -> Option<f64> {
+        parse_cost(self.attributes.delta_monthly_cost.as_deref())
+    }
+
+    /// Get matched resources count
+    pub fn matched_resources_count(&self) -> i32 {
+        self.attributes.matched_resources_count.unwrap_or(0)
+    }
+
+    /// Get total resources count
+    pub fn resources_count(&self) -> i32 {
+        self.attributes.resources_count.unwrap_or(0)
+    }
+}
+
+fn parse_cost(value: Option<&str>) -> Option<f64> {
+    value.and_then(|v| v.trim().parse().ok())
+}
+
+/// Response wrapper for cost estimate
+#[derive(Deserialize, Debug)]
+pub struct CostEstimateResponse {
+    pub data: CostEstimate,
+}
+
 impl TfeResource for Run {
     fn id(&self) -> &str {
         &self.id
@@ -679,6 +937,17 @@ mod tests {
         assert!(query.statuses.is_none());
     }
 
+    #[test]
+    fn test_run_query_final_and_all() {
+        assert_eq!(
+            RunQuery::final_only().status_group.as_deref(),
+            Some("final")
+        );
+        let all = RunQuery::all();
+        assert!(all.status_group.is_none());
+        assert!(all.statuses.is_none());
+    }
+
     #[test]
     fn test_run_query_pending() {
         let query = RunQuery::pending();
@@ -763,6 +1032,15 @@ mod tests {
         assert!(!RunStatus::PlannedAndSaved.is_non_final());
     }
 
+    #[test]
+    fn test_run_status_is_successful() {
+        assert!(RunStatus::Applied.is_successful());
+        assert!(RunStatus::PlannedAndFinished.is_successful());
+        assert!(!RunStatus::Errored.is_successful());
+        assert!(!RunStatus::Discarded.is_successful());
+        assert!(!RunStatus::Applying.is_successful());
+    }
+
     #[test]
     fn test_run_event_deserialization() {
         let event: RunEvent = serde_json::from_value(serde_json::json!({
@@ -1012,6 +1290,27 @@ mod tests {
         assert_eq!(apply.resource_destructions(), 0);
         assert_eq!(apply.resource_imports(), 0);
         assert!(apply.log_read_url().is_none());
+        assert!(apply.state_version_ids().is_empty());
+    }
+
+    #[test]
+    fn test_apply_state_version_ids() {
+        let apply: Apply = serde_json::from_value(serde_json::json!({
+            "id": "apply-xyz789",
+            "type": "applies",
+            "attributes": {"status": "finished"},
+            "relationships": {
+                "state-versions": {
+                    "data": [
+                        {"id": "sv-111", "type": "state-versions"},
+                        {"id": "sv-222", "type": "state-versions"}
+                    ]
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(apply.state_version_ids(), vec!["sv-111", "sv-222"]);
     }
 
     #[test]
@@ -1031,6 +1330,124 @@ mod tests {
         assert_eq!(response.data.status(), "finished");
     }
 
+    #[test]
+    fn test_cost_estimate_deserialization() {
+        let response: CostEstimateResponse = serde_json::from_value(serde_json::json!({
+            "data": {
+                "id": "ce-abc123",
+                "type": "cost-estimates",
+                "attributes": {
+                    "status": "finished",
+                    "prior-monthly-cost": "10.5",
+                    "proposed-monthly-cost": "25.488",
+                    "delta-monthly-cost": "14.988",
+                    "resources-count": 4,
+                    "matched-resources-count": 3,
+                    "unmatched-resources-count": 1,
+                    "error-message": null
+                }
+            }
+        }))
+        .unwrap();
+
+        let ce = response.data;
+        assert_eq!(ce.id, "ce-abc123");
+        assert_eq!(ce.status(), "finished");
+        assert_eq!(ce.prior_monthly_cost(), Some(10.5));
+        assert_eq!(ce.proposed_monthly_cost(), Some(25.488));
+        assert_eq!(ce.delta_monthly_cost(), Some(14.988));
+        assert_eq!(ce.matched_resources_count(), 3);
+        assert_eq!(ce.resources_count(), 4);
+    }
+
+    #[test]
+    fn test_cost_estimate_pending_has_no_costs() {
+        let ce: CostEstimate = serde_json::from_value(serde_json::json!({
+            "id": "ce-abc123",
+            "attributes": {
+                "status": "pending",
+                "prior-monthly-cost": "",
+                "proposed-monthly-cost": null
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(ce.prior_monthly_cost(), None);
+        assert_eq!(ce.proposed_monthly_cost(), None);
+        assert_eq!(ce.delta_monthly_cost(), None);
+        assert_eq!(ce.resources_count(), 0);
+    }
+
+    fn policy_check(result: serde_json::Value) -> PolicyCheck {
+        serde_json::from_value(serde_json::json!({
+            "id": "polchk-abc123",
+            "type": "policy-checks",
+            "attributes": {
+                "status": "soft_failed",
+                "scope": "organization",
+                "result": result,
+                "actions": {"is-overridable": true},
+                "permissions": {"can-override": false}
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_policy_check_results() {
+        let policies = serde_json::json!([
+            {"policy": "baseline/require-tags", "result": true, "allowed-failure": false},
+            {"policy": "baseline/limit-cost", "result": false, "allowed-failure": true},
+            {"policy": "baseline/no-public-s3", "result": false, "allowed-failure": false},
+            {"policy": "baseline/naming", "result": false, "enforcement-level": "advisory"}
+        ]);
+        let check = policy_check(serde_json::json!({
+            "result": false,
+            "sentinel": {
+                "schema-version": "1.0.0",
+                "data": {"baseline": {"can-override": true, "policies": policies}}
+            }
+        }));
+
+        assert_eq!(check.status(), "soft_failed");
+        assert!(check.is_overridable());
+        assert!(check.is_override_pending());
+        assert!(!check.can_override());
+
+        let results = check.policy_results();
+        let summary: Vec<_> = results
+            .iter()
+            .map(|r| (r.policy.as_str(), r.enforcement_level.as_str(), r.result))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("require-tags", "hard-mandatory", "passed"),
+                ("limit-cost", "soft-mandatory", "soft-failed"),
+                ("no-public-s3", "hard-mandatory", "hard-failed"),
+                ("naming", "advisory", "advisory-failed"),
+            ]
+        );
+        assert_eq!(results[0].policy_set, "baseline");
+        assert!(results[1].is_soft_failed());
+        assert!(!results[2].is_soft_failed());
+    }
+
+    #[test]
+    fn test_policy_check_without_sentinel_data() {
+        let check = policy_check(serde_json::json!({"result": false}));
+        assert!(check.policy_results().is_empty());
+
+        let check: PolicyCheck = serde_json::from_value(serde_json::json!({
+            "id": "polchk-x",
+            "attributes": {"status": "queued"}
+        }))
+        .unwrap();
+        assert!(check.policy_results().is_empty());
+        assert!(!check.is_overridable());
+        assert!(!check.is_override_pending());
+    }
+
     #[test]
     fn test_format_duration_none() {
         assert_eq!(format_duration(None), "-");
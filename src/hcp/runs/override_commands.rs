@@ -0,0 +1,336 @@
+//! Run override (policy check override) command handler
+
+use log::debug;
+use std::io::{self, Write};
+
+use crate::cli::{Cli, Command, OutputFormat, RunAction};
+use crate::hcp::explain::{call, print_explain, PlannedCall};
+use crate::hcp::runs::PolicyCheck;
+use crate::hcp::TfeClient;
+use crate::ui::{confirm_action, create_spinner, finish_spinner};
+
+/// Run the run override command (override a soft-failed policy check)
+pub async fn run_override_run_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    override_run_to(client, cli, &mut io::stdout()).await
+}
+
+/// Run override, writing the preview and outcome to `out`
+async fn override_run_to<W: Write>(
+    client: &TfeClient,
+    cli: &Cli,
+    out: &mut W,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Run {
+        action: RunAction::Override(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+    if cli.explain {
+        print_explain(&plan_override_run(&args.target));
+        return Ok(());
+    }
+
+    let check = if args.target.starts_with("run-") {
+        fetch_run_overridable_check(client, cli, &args.target).await?
+    } else {
+        fetch_overridable_check(client, cli, &args.target).await?
+    };
+
+    writeln!(out)?;
+    writeln!(out, "The following policy check will be overridden:")?;
+    writeln!(out)?;
+    write_overridable_check_table(out, &check)?;
+    writeln!(out)?;
+
+    if !check.can_override() {
+        eprintln!("Warning: the current token may lack permission to override this check");
+    }
+
+    let prompt = format!("Override policy check {}?", check.id);
+    if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
+        writeln!(out, "\nAborted.")?;
+        return Ok(());
+    }
+
+    let spinner = create_spinner(
+        &format!("Overriding policy check {}...", check.id),
+        cli.batch,
+    );
+    let result = client.override_policy(&check.id).await;
+    finish_spinner(spinner);
+    result?;
+
+    writeln!(out, "✓ Overridden policy check: {}", check.id)?;
+    Ok(())
+}
+
+/// Fetch a policy check, failing unless it can be overridden
+async fn fetch_overridable_check(
+    client: &TfeClient,
+    cli: &Cli,
+    check_id: &str,
+) -> Result<PolicyCheck, Box<dyn std::error::Error>> {
+    let spinner = create_spinner(&format!("Fetching policy check {}...", check_id), cli.batch);
+    let check = client.get_policy_check(check_id).await;
+    finish_spinner(spinner);
+    let check = check?;
+
+    debug!(
+        "Policy check {} status={} overridable={}",
+        check.id,
+        check.status(),
+        check.is_overridable()
+    );
+    if !check.is_override_pending() {
+        return Err(format!(
+            "Policy check '{}' cannot be overridden (status: {})",
+            check.id,
+            check.status()
+        )
+        .into());
+    }
+    Ok(check)
+}
+
+/// Find the single overridable policy check of a run
+async fn fetch_run_overridable_check(
+    client: &TfeClient,
+    cli: &Cli,
+    run_id: &str,
+) -> Result<PolicyCheck, Box<dyn std::error::Error>> {
+    let spinner = create_spinner(
+        &format!("Fetching policy checks for run {}...", run_id),
+        cli.batch,
+    );
+    let checks = client.get_run_policy_checks(run_id).await;
+    finish_spinner(spinner);
+
+    let mut overridable: Vec<_> = checks?
+        .into_iter()
+        .filter(|c| c.is_override_pending())
+        .collect();
+    match overridable.len() {
+        0 => Err(format!("Run '{}' has no overridable policy check", run_id).into()),
+        1 => Ok(overridable.remove(0)),
+        n => Err(format!(
+            "Run '{}' has {} overridable policy checks ({}); pass a policy check ID instead",
+            run_id,
+            n,
+            overridable
+                .iter()
+                .map(|c| c.id.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+        .into()),
+    }
+}
+
+/// Write the policy check about to be overridden
+fn write_overridable_check_table<W: Write>(writer: &mut W, check: &PolicyCheck) -> io::Result<()> {
+    use comfy_table::{presets::UTF8_FULL_CONDENSED, Table};
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec!["Policy Check ID", "Status", "Soft-Failed Policies"]);
+
+    let soft_failed: Vec<_> = check
+        .policy_results()
+        .into_iter()
+        .filter(|r| r.is_soft_failed())
+        .map(|r| r.policy)
+        .collect();
+    table.add_row(vec![
+        check.id.as_str(),
+        check.status(),
+        &soft_failed.join(", "),
+    ]);
+
+    crate::output::write_table(writer, &table, &OutputFormat::Table)
+}
+
+/// API calls made by `run override`
+fn plan_override_run(target: &str) -> Vec<PlannedCall> {
+    let (lookup, check) = if target.starts_with("run-") {
+        (
+            call(
+                "GET",
+                format!("/runs/{}/policy-checks", target),
+                "find the overridable check",
+            ),
+            ":policy_check_id",
+        )
+    } else {
+        (
+            call(
+                "GET",
+                format!("/policy-checks/{}", target),
+                "check it is overridable",
+            ),
+            target,
+        )
+    };
+    vec![
+        lookup,
+        call(
+            "POST",
+            format!("/policy-checks/{}/actions/override", check),
+            "override",
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn check_json(id: &str, status: &str, overridable: bool) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "type": "policy-checks",
+            "attributes": {
+                "status": status,
+                "actions": {"is-overridable": overridable},
+                "permissions": {"can-override": true}
+            }
+        })
+    }
+
+    async fn mount_run_checks(server: &MockServer, checks: Vec<serde_json::Value>) {
+        Mock::given(method("GET"))
+            .and(path("/runs/run-abc123/policy-checks"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": checks })),
+            )
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_override(server: &MockServer, check_id: &str, expected: u64) {
+        Mock::given(method("POST"))
+            .and(path(format!(
+                "/policy-checks/{}/actions/override",
+                check_id
+            )))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    async fn override_run(server: &MockServer, argv: &[&str]) -> Result<String, String> {
+        let client = TfeClient::test_client(&server.uri());
+        let cli = Cli::parse_from(argv);
+        let mut out = Vec::new();
+        override_run_to(&client, &cli, &mut out)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_override_policy_check_id() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/policy-checks/polchk-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": check_json("polchk-1", "soft_failed", true)
+            })))
+            .mount(&server)
+            .await;
+        mount_override(&server, "polchk-1", 1).await;
+
+        let out = override_run(&server, &["hcp", "run", "override", "polchk-1", "-y"])
+            .await
+            .unwrap();
+        assert!(out.contains("polchk-1"), "{}", out);
+        assert!(out.contains("soft_failed"), "{}", out);
+        assert!(
+            out.contains("✓ Overridden policy check: polchk-1"),
+            "{}",
+            out
+        );
+    }
+
+    #[tokio::test]
+    async fn test_override_rejects_passed_check() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/policy-checks/polchk-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": check_json("polchk-1", "passed", false)
+            })))
+            .mount(&server)
+            .await;
+        mount_override(&server, "polchk-1", 0).await;
+
+        let err = override_run(&server, &["hcp", "run", "override", "polchk-1", "-y"])
+            .await
+            .unwrap_err();
+        assert!(
+            err.contains("cannot be overridden (status: passed)"),
+            "{}",
+            err
+        );
+    }
+
+    #[tokio::test]
+    async fn test_override_resolves_run_to_single_check() {
+        let server = MockServer::start().await;
+        mount_run_checks(
+            &server,
+            vec![
+                check_json("polchk-1", "passed", false),
+                check_json("polchk-2", "soft_failed", true),
+            ],
+        )
+        .await;
+        mount_override(&server, "polchk-2", 1).await;
+
+        override_run(&server, &["hcp", "run", "override", "run-abc123", "-y"])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_override_run_without_overridable_check() {
+        let server = MockServer::start().await;
+        mount_run_checks(&server, vec![check_json("polchk-1", "hard_failed", false)]).await;
+
+        let err = override_run(&server, &["hcp", "run", "override", "run-abc123", "-y"])
+            .await
+            .unwrap_err();
+        assert!(err.contains("has no overridable policy check"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_override_run_with_several_overridable_checks() {
+        let server = MockServer::start().await;
+        mount_run_checks(
+            &server,
+            vec![
+                check_json("polchk-1", "soft_failed", true),
+                check_json("polchk-2", "soft_failed", true),
+            ],
+        )
+        .await;
+        mount_override(&server, "polchk-1", 0).await;
+        mount_override(&server, "polchk-2", 0).await;
+
+        let err = override_run(&server, &["hcp", "run", "override", "run-abc123", "-y"])
+            .await
+            .unwrap_err();
+        assert!(
+            err.contains("2 overridable policy checks (polchk-1, polchk-2)"),
+            "{}",
+            err
+        );
+    }
+}
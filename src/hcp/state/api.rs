@@ -6,11 +6,12 @@ use md5::{Digest, Md5};
 
 use crate::config::api;
 use crate::error::{Result, TfeError};
-use crate::hcp::TfeClient;
+use crate::hcp::{PageRequest, TfeClient};
 
 use super::models::{
     CurrentStateVersionResponse, EmptyTerraformState, StateVersionListItem,
-    StateVersionListResponse, StateVersionRequest, TerraformState,
+    StateVersionListResponse, StateVersionOutput, StateVersionOutputsResponse, StateVersionRequest,
+    StateVersionResponse, TerraformState,
 };
 
 impl TfeClient {
@@ -28,7 +29,7 @@ impl TfeClient {
 
         debug!("Fetching current state version for: {}", workspace_id);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -41,40 +42,44 @@ impl TfeClient {
                     "No state version found for workspace '{}'. The workspace may be empty.",
                     workspace_id
                 ),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!(
-                        "Failed to get state version for '{}': {}",
-                        workspace_id, body
-                    ),
-                })
+                    format!("Failed to get state version for '{}'", workspace_id),
+                    &body,
+                ))
             }
         }
     }
 
     /// Download state file from URL
     pub async fn download_state(&self, download_url: &str) -> Result<TerraformState> {
-        debug!("Downloading state from: {}", download_url);
+        debug!(
+            "Downloading state from: {}",
+            crate::hcp::redact::redact_url_str(download_url)
+        );
 
-        let response = self.get(download_url).send().await?;
+        let response = self.send(self.get(download_url)).await?;
 
         match response.status().as_u16() {
             200 => {
                 let state: TerraformState = response.json().await.map_err(|e| TfeError::Api {
                     status: 200,
                     message: format!("Failed to parse state file: {}", e),
+                    details: Vec::new(),
                 })?;
                 Ok(state)
             }
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!("Failed to download state: {}", body),
-                })
+                    "Failed to download state",
+                    &body,
+                ))
             }
         }
     }
@@ -121,7 +126,7 @@ impl TfeClient {
             &state_base64,
         );
 
-        let response = self.post(&url).json(&request).send().await?;
+        let response = self.send(self.post(&url).json(&request)).await?;
 
         match response.status().as_u16() {
             200 | 201 => {
@@ -131,6 +136,7 @@ impl TfeClient {
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Workspace '{}' not found", workspace_id),
+                details: Vec::new(),
             }),
             409 => Err(TfeError::Api {
                 status: 409,
@@ -138,48 +144,105 @@ impl TfeClient {
                     "State version conflict for '{}'. Another state may have been uploaded.",
                     workspace_id
                 ),
+                details: Vec::new(),
             }),
             422 => {
-                let error_body: serde_json::Value =
-                    response.json().await.unwrap_or(serde_json::json!({}));
-                let error_msg = error_body["errors"][0]["detail"]
-                    .as_str()
-                    .unwrap_or("Validation error");
-                Err(TfeError::Api {
-                    status: 422,
-                    message: format!("Invalid state version: {}", error_msg),
-                })
+                let body = response.text().await.unwrap_or_default();
+                Err(TfeError::api_response(422, "Invalid state version", &body))
             }
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!("Failed to upload state version: {}", body),
-                })
+                    "Failed to upload state version",
+                    &body,
+                ))
             }
         }
     }
+    /// Get a single state version by ID
+    pub async fn get_state_version(&self, sv_id: &str) -> Result<StateVersionListItem> {
+        let url = format!("{}/{}/{}", self.base_url(), api::STATE_VERSIONS, sv_id);
+        debug!("Fetching state version: {}", url);
+
+        let response = self.send_get(&url).await?;
+        let data: StateVersionResponse = self
+            .parse_api_response(response, &format!("state version '{}'", sv_id))
+            .await?;
+        Ok(data.data)
+    }
+
+    /// Get the Terraform outputs recorded in a state version
+    pub async fn get_state_version_outputs(
+        &self,
+        sv_id: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<StateVersionOutput>> {
+        let path = format!("/{}/{}/outputs", api::STATE_VERSIONS, sv_id);
+        self.fetch_pages::<StateVersionOutput, StateVersionOutputsResponse>(
+            &path,
+            &format!("outputs of state version '{}'", sv_id),
+            page,
+        )
+        .await
+    }
+
+    /// Count the state versions of a workspace from the first page's pagination meta
+    ///
+    /// Returns `None` when the API response carries no pagination info.
+    pub async fn count_state_versions(
+        &self,
+        org: &str,
+        workspace_name: &str,
+    ) -> crate::error::Result<Option<u32>> {
+        let path = format!(
+            "/{}?filter[organization][name]={}&filter[workspace][name]={}",
+            api::STATE_VERSIONS,
+            urlencoding::encode(org),
+            urlencoding::encode(workspace_name)
+        );
+        let info = self
+            .prefetch_pagination_info::<StateVersionListItem, StateVersionListResponse>(
+                &path,
+                &format!("state versions for workspace '{}'", workspace_name),
+            )
+            .await?;
+        Ok(info.map(|i| i.total_count))
+    }
+
     /// List state versions for a workspace
     ///
     /// Uses `fetch_all_pages` for full pagination, or a single-page request
-    /// with the specified `page_size` when `all` is false.
+    /// with the specified `page_size` when `all` is false. A requested `page`
+    /// (`--page`/`--page-size`) takes precedence over both.
     pub async fn get_state_versions_for_workspace(
         &self,
         org: &str,
         workspace_name: &str,
         page_size: usize,
         all: bool,
+        page: Option<PageRequest>,
     ) -> crate::error::Result<Vec<StateVersionListItem>> {
-        let path = format!(
-            "/{}?filter[organization][name]={}&filter[workspace][name]={}&page[size]={}",
+        let filter_path = format!(
+            "/{}?filter[organization][name]={}&filter[workspace][name]={}",
             api::STATE_VERSIONS,
             urlencoding::encode(org),
             urlencoding::encode(workspace_name),
-            page_size
         );
+        let path = format!("{}&page[size]={}", filter_path, page_size);
 
         let error_context = format!("state versions for workspace '{}'", workspace_name);
 
+        if page.is_some() {
+            return self
+                .fetch_pages::<StateVersionListItem, StateVersionListResponse>(
+                    &filter_path,
+                    &error_context,
+                    page,
+                )
+                .await;
+        }
+
         if all {
             self.fetch_all_pages::<StateVersionListItem, StateVersionListResponse>(
                 &path,
@@ -190,7 +253,7 @@ impl TfeClient {
             let url = format!("{}{}", self.base_url(), path);
             debug!("Fetching state versions: {}", url);
 
-            let response = self.get(&url).send().await?;
+            let response = self.send_get(&url).await?;
             let data: StateVersionListResponse =
                 self.parse_api_response(response, &error_context).await?;
             Ok(data.data)
@@ -345,4 +408,173 @@ mod tests {
         let err = result.unwrap_err();
         assert!(err.to_string().contains("conflict"));
     }
+
+    #[tokio::test]
+    async fn test_upload_state_version_validation_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/workspaces/ws-123/state-versions"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "errors": [{
+                    "status": "422",
+                    "title": "invalid attribute",
+                    "detail": "Serial must be greater than the current serial",
+                    "source": {"pointer": "/data/attributes/serial"}
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        let empty_state = EmptyTerraformState {
+            version: 4,
+            terraform_version: "1.5.0".to_string(),
+            serial: 11,
+            lineage: "abc-123".to_string(),
+            outputs: serde_json::json!({}),
+            resources: vec![],
+        };
+
+        let err = client
+            .upload_state_version("ws-123", &empty_state)
+            .await
+            .unwrap_err();
+
+        match err {
+            TfeError::Api {
+                status,
+                message,
+                details,
+            } => {
+                assert_eq!(status, 422);
+                assert!(message.contains("Serial must be greater"));
+                assert_eq!(details.len(), 1);
+                assert_eq!(
+                    details[0].pointer.as_deref(),
+                    Some("/data/attributes/serial")
+                );
+            }
+            _ => panic!("Expected TfeError::Api"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_state_version_success() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/state-versions/sv-789"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "id": "sv-789",
+                    "type": "state-versions",
+                    "attributes": {
+                        "serial": 7,
+                        "created-at": "2025-01-01T10:00:00.000Z",
+                        "resources-processed": true,
+                        "resources": [{"count": 2}, {"count": 3}]
+                    }
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let sv = client.get_state_version("sv-789").await.unwrap();
+
+        assert_eq!(sv.id, "sv-789");
+        assert_eq!(sv.attributes.serial, Some(7));
+        assert_eq!(sv.resource_count(), Some(5));
+    }
+
+    #[tokio::test]
+    async fn test_get_state_version_not_found() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/state-versions/sv-missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let result = client.get_state_version("sv-missing").await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_state_version_outputs() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/state-versions/sv-789/outputs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {
+                        "id": "wsout-1",
+                        "type": "state-version-outputs",
+                        "attributes": {
+                            "name": "vpc_id",
+                            "sensitive": false,
+                            "type": "string",
+                            "value": "vpc-123"
+                        }
+                    },
+                    {
+                        "id": "wsout-2",
+                        "type": "state-version-outputs",
+                        "attributes": {
+                            "name": "db_password",
+                            "sensitive": true,
+                            "type": "string",
+                            "value": null
+                        }
+                    }
+                ],
+                "meta": {"pagination": {"current-page": 1, "total-pages": 1, "total-count": 2}}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let outputs = client
+            .get_state_version_outputs("sv-789", None)
+            .await
+            .unwrap();
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].attributes.name, "vpc_id");
+        assert_eq!(outputs[0].attributes.value, serde_json::json!("vpc-123"));
+        assert!(outputs[1].attributes.sensitive);
+        assert!(outputs[1].attributes.value.is_null());
+    }
+
+    #[tokio::test]
+    async fn test_count_state_versions() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/state-versions"))
+            .and(wiremock::matchers::query_param(
+                "filter[workspace][name]",
+                "my-ws",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [],
+                "meta": {"pagination": {"current-page": 1, "total-pages": 3, "total-count": 57}}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let count = client
+            .count_state_versions("my-org", "my-ws")
+            .await
+            .unwrap();
+
+        assert_eq!(count, Some(57));
+    }
 }
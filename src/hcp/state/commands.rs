@@ -1,15 +1,19 @@
-//! State purge command handler
-
-use std::io::{self, Write};
+//! State command handlers: list state versions and purge workspace state
 
 use log::debug;
 
 use crate::error::Result;
-use crate::hcp::state::models::EmptyTerraformState;
-use crate::hcp::workspaces::{parse_workspace_target, WorkspaceTarget};
+use crate::hcp::explain::{call, print_explain, workspace_lookup, PlannedCall};
+use crate::hcp::state::models::{resource_deltas, CurrentStateVersion, EmptyTerraformState};
+use crate::hcp::traits::TfeResource;
+use crate::hcp::workspaces::{parse_workspace_target, resolve_workspace, WorkspaceTarget};
 use crate::hcp::TfeClient;
-use crate::ui::{create_spinner, finish_spinner, finish_spinner_with_message};
-use crate::{Cli, Command, PurgeResource};
+use crate::output::{apply_limit, format_age, output_state_version_outputs, output_state_versions};
+use crate::ui::{confirm_typed, create_spinner, finish_spinner, finish_spinner_with_message};
+use crate::{Cli, Command, GetResource, OutputFormat, PurgeResource};
+
+/// Number of state versions listed without --all
+const RECENT_STATE_VERSIONS: usize = 24;
 
 /// Warning message displayed before purging state
 const PURGE_WARNING: &str = r#"
@@ -32,6 +36,7 @@ const PURGE_WARNING: &str = r#"
 /// Workspace statistics
 struct WorkspaceStats {
     name: String,
+    org: Option<String>,
     resource_count: u32,
     serial: u64,
     resources_processed: bool,
@@ -46,6 +51,7 @@ async fn fetch_workspace_stats(client: &TfeClient, workspace_id: &str) -> Result
         .ok_or_else(|| crate::error::TfeError::Api {
             status: 404,
             message: format!("Workspace '{}' not found", workspace_id),
+            details: Vec::new(),
         })?;
 
     // Get current state version for serial and resources_processed
@@ -53,6 +59,7 @@ async fn fetch_workspace_stats(client: &TfeClient, workspace_id: &str) -> Result
 
     Ok(WorkspaceStats {
         name: workspace.attributes.name.clone(),
+        org: workspace.organization_name().map(String::from),
         resource_count: workspace.resource_count(),
         serial: state_version.data.attributes.serial,
         resources_processed: state_version
@@ -79,6 +86,251 @@ fn print_workspace_stats(stats: &WorkspaceStats, workspace_id: &str, label: &str
     );
 }
 
+/// Show what `purge state` would do, in the purge-run confirmation table style
+fn print_purge_preview(
+    current: &CurrentStateVersion,
+    stats: &WorkspaceStats,
+    workspace_id: &str,
+    version_count: Option<u32>,
+) {
+    println!();
+    println!("[DRY-RUN] Workspace:    {} ({})", stats.name, workspace_id);
+    if let Some(org) = &stats.org {
+        println!("[DRY-RUN] Organization: {}", org);
+    }
+    println!();
+    println!("[DRY-RUN] The following state changes would be made:");
+    println!();
+    crate::output::print_table(
+        &purge_preview_table(current, stats.resource_count),
+        &OutputFormat::Table,
+    );
+    println!();
+    match version_count {
+        Some(count) => println!(
+            "[DRY-RUN] {} existing state versions would be kept (0 removed);              purging only uploads a new empty version.",
+            count
+        ),
+        None => println!(
+            "[DRY-RUN] No state versions would be removed; purging only uploads a new empty              version."
+        ),
+    }
+    println!();
+    println!("Dry-run complete. No changes were made.");
+}
+
+/// Build the preview table: current state version and the empty one that replaces it
+fn purge_preview_table(current: &CurrentStateVersion, resource_count: u32) -> comfy_table::Table {
+    use comfy_table::{presets::UTF8_FULL_CONDENSED, Table};
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec![
+        "State Version",
+        "Serial",
+        "Age",
+        "Resources",
+        "Action",
+    ]);
+    table.add_row(vec![
+        current.id.clone(),
+        current.attributes.serial.to_string(),
+        format_age(current.attributes.created_at.as_deref()),
+        resource_count.to_string(),
+        format!("remove {} resources", resource_count),
+    ]);
+    table.add_row(vec![
+        "(new)".to_string(),
+        (current.attributes.serial + 1).to_string(),
+        "-".to_string(),
+        "0".to_string(),
+        "upload empty state".to_string(),
+    ]);
+    table
+}
+
+/// Run the get state command
+///
+/// Lists a workspace's state versions (serial ascending, with resource deltas),
+/// or the outputs recorded in a single state version when an ID is given.
+pub async fn run_get_state_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::State(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if let Some(sv_id) = &args.id {
+        if !sv_id.starts_with("sv-") {
+            return Err(format!(
+                "Invalid state version ID '{}'. Use an ID starting with 'sv-', or --ws to list \
+                 a workspace's state versions.",
+                sv_id
+            )
+            .into());
+        }
+        if cli.explain {
+            print_explain(&[call(
+                "GET",
+                format!("/state-versions/{}/outputs", sv_id),
+                "paginated",
+            )]);
+            return Ok(());
+        }
+
+        let spinner = create_spinner(
+            &format!("Fetching outputs of state version '{}'...", sv_id),
+            cli.batch,
+        );
+        let outputs = client
+            .with_progress(spinner.as_ref())
+            .get_state_version_outputs(sv_id, cli.page_request())
+            .await;
+        finish_spinner(spinner);
+        let outputs = outputs?;
+
+        if outputs.is_empty() {
+            eprintln!("No outputs recorded in state version '{}'", sv_id);
+            return Ok(());
+        }
+        output_state_version_outputs(&outputs, &args.output, cli.no_header, false);
+        return Ok(());
+    }
+
+    let Some(target) = &args.workspace else {
+        unreachable!("clap requires --ws when no state version ID is given")
+    };
+    let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        let mut calls = workspace_lookup(target, effective_org.as_ref());
+        calls.push(call(
+            "GET",
+            "/state-versions?filter[organization][name]=:org&filter[workspace][name]=:ws",
+            if args.all {
+                "paginated"
+            } else {
+                "24 most recent"
+            },
+        ));
+        print_explain(&calls);
+        return Ok(());
+    }
+    let resolved = resolve_workspace(
+        client,
+        target,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
+    let ws_name = resolved.workspace.name().to_string();
+
+    let spinner = create_spinner(
+        &format!("Fetching state versions for workspace '{}'...", ws_name),
+        cli.batch,
+    );
+    let states = client
+        .with_progress(spinner.as_ref())
+        .get_state_versions_for_workspace(
+            &resolved.org,
+            &ws_name,
+            RECENT_STATE_VERSIONS,
+            args.all,
+            cli.page_request(),
+        )
+        .await;
+    finish_spinner(spinner);
+    let mut states = states?;
+    debug!(
+        "Fetched {} state versions for workspace '{}'",
+        states.len(),
+        ws_name
+    );
+
+    if states.is_empty() {
+        eprintln!("No state versions found for workspace '{}'", ws_name);
+        return Ok(());
+    }
+
+    states.sort_by_key(|s| s.attributes.serial);
+    let mut deltas = resource_deltas(&states);
+    apply_limit(&mut states, cli);
+    deltas.truncate(states.len());
+    output_state_versions(&states, &deltas, &args.output, cli.no_header);
+    Ok(())
+}
+
+/// Run the get output command
+///
+/// Resolves the workspace's current state version and prints its outputs.
+pub async fn run_get_output_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::Output(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        print_explain(&plan_get_output(&args.workspace, effective_org.as_ref()));
+        return Ok(());
+    }
+    let resolved = resolve_workspace(
+        client,
+        &args.workspace,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
+    let ws_name = resolved.workspace.name().to_string();
+
+    let spinner = create_spinner(
+        &format!("Fetching outputs for workspace '{}'...", ws_name),
+        cli.batch,
+    );
+    let outputs = match client
+        .get_current_state_version(&resolved.workspace.id)
+        .await
+    {
+        Ok(current) => {
+            client
+                .with_progress(spinner.as_ref())
+                .get_state_version_outputs(&current.data.id, cli.page_request())
+                .await
+        }
+        Err(e) => Err(e),
+    };
+    finish_spinner(spinner);
+    let outputs: Vec<_> = outputs?
+        .into_iter()
+        .filter(|o| args.name.as_deref().is_none_or(|n| o.attributes.name == n))
+        .collect();
+
+    if outputs.is_empty() {
+        return match &args.name {
+            Some(name) => {
+                Err(format!("Output '{}' not found in workspace '{}'", name, ws_name).into())
+            }
+            None => {
+                eprintln!("No outputs found in workspace '{}'", ws_name);
+                Ok(())
+            }
+        };
+    }
+
+    output_state_version_outputs(&outputs, &args.output, cli.no_header, args.show_sensitive);
+    Ok(())
+}
+
 /// Run the purge state command
 pub async fn run_purge_state_command(
     client: &TfeClient,
@@ -105,6 +357,10 @@ pub async fn run_purge_state_command(
             .into());
         }
     }
+    if cli.explain {
+        print_explain(&plan_purge_state(workspace_id));
+        return Ok(());
+    }
 
     // Fetch and display BEFORE stats
     let spinner = create_spinner(
@@ -133,6 +389,26 @@ pub async fn run_purge_state_command(
         &format!("State version {} retrieved", state_version_id),
     );
 
+    if args.dry_run {
+        let version_count = match &before_stats.org {
+            Some(org) => client
+                .count_state_versions(org, &before_stats.name)
+                .await
+                .unwrap_or_else(|e| {
+                    debug!("Failed to count state versions: {}", e);
+                    None
+                }),
+            None => None,
+        };
+        print_purge_preview(
+            &state_version.data,
+            &before_stats,
+            workspace_id,
+            version_count,
+        );
+        return Ok(());
+    }
+
     let download_url = state_version
         .data
         .attributes
@@ -142,35 +418,29 @@ pub async fn run_purge_state_command(
             "No state download URL available. The workspace may have no state or use remote state storage.",
         )?;
 
-    // Show critical warning and require confirmation
-    // Skipped only with --my-resume-is-updated flag
-    if !args.my_resume_is_updated {
+    // Show critical warning and require the workspace name to be typed
+    // Skipped with --yes (or --my-resume-is-updated); --batch alone aborts
+    let skip_prompt = args.yes || args.my_resume_is_updated;
+    if !skip_prompt {
         println!("{}", PURGE_WARNING);
-
-        print!(
-            "Type the workspace ID '{}' to confirm purge: ",
-            workspace_id
-        );
-        io::stdout().flush()?;
-
-        let mut input = String::new();
-        io::stdin().read_line(&mut input)?;
-        let input = input.trim();
-
-        if input != workspace_id {
-            println!(
-                "\nAborted. Input '{}' does not match '{}'.",
-                input, workspace_id
-            );
-            return Ok(());
-        }
-
+    }
+    if !confirm_typed(
+        &before_stats.name,
+        skip_prompt,
+        cli.batch,
+        cli.prompt_timeout,
+    )
+    .await?
+    {
+        return Ok(());
+    }
+    if !skip_prompt {
         println!();
     }
 
     // Step 1: Lock workspace
     let spinner = create_spinner(&format!("Locking workspace {}...", workspace_id), cli.batch);
-    if let Err(e) = client.lock_workspace(workspace_id).await {
+    if let Err(e) = client.lock_workspace(workspace_id, None).await {
         finish_spinner(spinner);
         return Err(format!("Failed to lock workspace: {}", e).into());
     }
@@ -274,6 +544,42 @@ async fn purge_state_internal(
     Ok(())
 }
 
+/// API calls made by `get output` for the effective organization
+fn plan_get_output(workspace: &str, org: Option<&String>) -> Vec<PlannedCall> {
+    let mut calls = workspace_lookup(workspace, org);
+    calls.push(call(
+        "GET",
+        "/workspaces/:ws_id/current-state-version",
+        "current state version",
+    ));
+    calls.push(call("GET", "/state-versions/:sv_id/outputs", "paginated"));
+    calls
+}
+
+/// API calls made by `purge state`
+fn plan_purge_state(ws: &str) -> Vec<PlannedCall> {
+    vec![
+        call("GET", format!("/workspaces/{}", ws), "workspace"),
+        call(
+            "GET",
+            format!("/workspaces/{}/current-state-version", ws),
+            "current state",
+        ),
+        call("POST", format!("/workspaces/{}/actions/lock", ws), "lock"),
+        call("GET", ":hosted-state-download-url", "download state"),
+        call(
+            "POST",
+            format!("/workspaces/{}/state-versions", ws),
+            "upload emptied state",
+        ),
+        call(
+            "POST",
+            format!("/workspaces/{}/actions/unlock", ws),
+            "unlock",
+        ),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,4 +594,43 @@ mod tests {
         assert!(PURGE_WARNING.contains("NOT destroy"));
         assert!(PURGE_WARNING.contains("orphaned"));
     }
+
+    #[test]
+    fn test_purge_preview_table() {
+        let current: CurrentStateVersion = serde_json::from_value(serde_json::json!({
+            "id": "sv-123",
+            "attributes": {"serial": 41, "created-at": "2024-01-01T00:00:00Z"}
+        }))
+        .unwrap();
+
+        let rendered = purge_preview_table(&current, 7).to_string();
+        assert!(rendered.contains("sv-123"));
+        assert!(rendered.contains("41"));
+        assert!(rendered.contains("remove 7 resources"));
+        assert!(rendered.contains("42"));
+        assert!(rendered.contains("upload empty state"));
+    }
+
+    #[test]
+    fn test_plan_get_output() {
+        let calls = plan_get_output("ws-abc", None);
+        let paths: Vec<_> = calls.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/workspaces/ws-abc",
+                "/workspaces/:ws_id/current-state-version",
+                "/state-versions/:sv_id/outputs"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_purge_state_locks_and_unlocks() {
+        let methods: Vec<_> = plan_purge_state("ws-abc")
+            .iter()
+            .map(|c| c.method)
+            .collect();
+        assert_eq!(methods, ["GET", "GET", "POST", "GET", "POST", "POST"]);
+    }
 }
@@ -4,8 +4,9 @@ mod api;
 mod commands;
 mod models;
 
-pub use commands::run_purge_state_command;
+pub use commands::{run_get_output_command, run_get_state_command, run_purge_state_command};
 pub use models::{
-    CurrentStateVersion, StateVersionListItem, StateVersionListResponse, StateVersionRequest,
+    resource_deltas, CurrentStateVersion, StateVersionListItem, StateVersionListResponse,
+    StateVersionOutput, StateVersionOutputsResponse, StateVersionRequest, StateVersionResponse,
     StateVersionUpload,
 };
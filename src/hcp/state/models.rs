@@ -20,6 +20,9 @@ pub struct CurrentStateVersion {
 pub struct StateVersionAttributes {
     pub serial: u64,
 
+    #[serde(rename = "created-at")]
+    pub created_at: Option<String>,
+
     #[serde(rename = "terraform-version")]
     pub terraform_version: Option<String>,
 
@@ -131,6 +134,12 @@ impl crate::hcp::PaginatedResponse<StateVersionListItem> for StateVersionListRes
     }
 }
 
+/// Response wrapper for a single state version (GET /state-versions/:id)
+#[derive(Deserialize, Debug)]
+pub struct StateVersionResponse {
+    pub data: StateVersionListItem,
+}
+
 /// A single state version item from the list endpoint
 #[derive(Deserialize, Debug)]
 pub struct StateVersionListItem {
@@ -229,10 +238,94 @@ impl StateVersionListItem {
     }
 }
 
+/// Resource count change of each state version against the previous one
+///
+/// `states` must be sorted by serial ascending; the first entry has no delta.
+pub fn resource_deltas(states: &[StateVersionListItem]) -> Vec<Option<i64>> {
+    states
+        .iter()
+        .enumerate()
+        .map(|(i, state)| {
+            let prev = states.get(i.checked_sub(1)?)?.resource_count()?;
+            Some(state.resource_count()? as i64 - prev as i64)
+        })
+        .collect()
+}
+
+/// Response wrapper for state version outputs (GET /state-versions/:id/outputs)
+#[derive(Deserialize, Debug)]
+pub struct StateVersionOutputsResponse {
+    pub data: Vec<StateVersionOutput>,
+    #[serde(default)]
+    pub meta: Option<crate::hcp::PaginationMeta>,
+}
+
+impl crate::hcp::PaginatedResponse<StateVersionOutput> for StateVersionOutputsResponse {
+    fn into_data(self) -> Vec<StateVersionOutput> {
+        self.data
+    }
+
+    fn meta(&self) -> Option<&crate::hcp::PaginationMeta> {
+        self.meta.as_ref()
+    }
+}
+
+/// A Terraform output recorded in a state version
+#[derive(Deserialize, Debug)]
+pub struct StateVersionOutput {
+    pub id: String,
+    pub attributes: StateVersionOutputAttributes,
+}
+
+/// Attributes for a state version output
+#[derive(Deserialize, Debug)]
+pub struct StateVersionOutputAttributes {
+    pub name: String,
+
+    #[serde(default)]
+    pub sensitive: bool,
+
+    /// Terraform type name (string, number, bool, array, object)
+    #[serde(rename = "type")]
+    pub output_type: Option<String>,
+
+    /// Output value; null for sensitive outputs unless the token may read them
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn state_with_resources(serial: u64, count: Option<u64>) -> StateVersionListItem {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("sv-{}", serial),
+            "attributes": {
+                "serial": serial,
+                "resources-processed": count.is_some(),
+                "resources": [{"count": count.unwrap_or(0)}]
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resource_deltas() {
+        let states = vec![
+            state_with_resources(1, Some(10)),
+            state_with_resources(2, Some(12)),
+            state_with_resources(3, None),
+            state_with_resources(4, Some(7)),
+            state_with_resources(5, Some(5)),
+        ];
+        assert_eq!(
+            resource_deltas(&states),
+            vec![None, Some(2), None, None, Some(-2)]
+        );
+        assert!(resource_deltas(&[]).is_empty());
+    }
+
     #[test]
     fn test_state_version_list_item_resource_count() {
         let item = StateVersionListItem {
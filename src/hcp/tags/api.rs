@@ -4,7 +4,7 @@ use log::debug;
 
 use crate::config::api;
 use crate::error::{Result, TfeError};
-use crate::hcp::TfeClient;
+use crate::hcp::{PageRequest, TfeClient};
 
 use super::models::{OrgTag, TagBinding, TagBindingsResponse};
 use crate::hcp::traits::ApiListResponse;
@@ -41,7 +41,7 @@ impl TfeClient {
 
         debug!("Fetching tag bindings from: {}", url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -58,16 +58,15 @@ impl TfeClient {
                     },
                     target.display_name
                 ),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!(
-                        "Failed to fetch tag bindings for '{}': {}",
-                        target.display_name, body
-                    ),
-                })
+                    format!("Failed to fetch tag bindings for '{}'", target.display_name),
+                    &body,
+                ))
             }
         }
     }
@@ -112,7 +111,7 @@ impl TfeClient {
 
         let body = serde_json::json!({ "data": data });
 
-        let response = self.patch(&url).json(&body).send().await?;
+        let response = self.send(self.patch(&url).json(&body)).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -129,20 +128,23 @@ impl TfeClient {
                     },
                     target.display_name
                 ),
+                details: Vec::new(),
             }),
             422 => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
-                    status: 422,
-                    message: format!("Cannot set tags on '{}': {}", target.display_name, body),
-                })
+                Err(TfeError::api_response(
+                    422,
+                    format!("Cannot set tags on '{}'", target.display_name),
+                    &body,
+                ))
             }
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!("Failed to set tags on '{}': {}", target.display_name, body),
-                })
+                    format!("Failed to set tags on '{}'", target.display_name),
+                    &body,
+                ))
             }
         }
     }
@@ -176,6 +178,7 @@ impl TfeClient {
                     target.display_name,
                     missing_keys.join(", ")
                 ),
+                details: Vec::new(),
             });
         }
 
@@ -224,7 +227,7 @@ impl TfeClient {
             }
         });
 
-        let response = self.patch(&url).json(&body).send().await?;
+        let response = self.send(self.patch(&url).json(&body)).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -241,16 +244,15 @@ impl TfeClient {
                     },
                     target.display_name
                 ),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!(
-                        "Failed to remove tags from '{}': {}",
-                        target.display_name, body
-                    ),
-                })
+                    format!("Failed to remove tags from '{}'", target.display_name),
+                    &body,
+                ))
             }
         }
     }
@@ -266,7 +268,7 @@ impl TfeClient {
 
         debug!("Fetching workspace flat string tags from: {}", url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -276,16 +278,15 @@ impl TfeClient {
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Workspace '{}' not found", workspace_id),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!(
-                        "Failed to fetch tags for workspace '{}': {}",
-                        workspace_id, body
-                    ),
-                })
+                    format!("Failed to fetch tags for workspace '{}'", workspace_id),
+                    &body,
+                ))
             }
         }
     }
@@ -295,6 +296,7 @@ impl TfeClient {
         &self,
         org: &str,
         search: Option<&str>,
+        page: Option<PageRequest>,
     ) -> crate::error::Result<Vec<OrgTag>> {
         let mut path = format!("/{}/{}/tags", api::ORGANIZATIONS, org);
         if let Some(q) = search {
@@ -302,7 +304,7 @@ impl TfeClient {
         }
         let error_context = format!("tags for organization '{}'", org);
 
-        self.fetch_all_pages::<OrgTag, ApiListResponse<OrgTag>>(&path, &error_context)
+        self.fetch_pages::<OrgTag, ApiListResponse<OrgTag>>(&path, &error_context, page)
             .await
     }
 
@@ -336,23 +338,22 @@ impl TfeClient {
 
         let body = serde_json::json!({ "data": data });
 
-        let response = self.post(&url).json(&body).send().await?;
+        let response = self.send(self.post(&url).json(&body)).await?;
 
         match response.status().as_u16() {
             204 => Ok(()),
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Workspace '{}' not found", workspace_id),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!(
-                        "Failed to add tags to workspace '{}': {}",
-                        workspace_id, body
-                    ),
-                })
+                    format!("Failed to add tags to workspace '{}'", workspace_id),
+                    &body,
+                ))
             }
         }
     }
@@ -391,23 +392,22 @@ impl TfeClient {
 
         let body = serde_json::json!({ "data": data });
 
-        let response = self.delete(&url).json(&body).send().await?;
+        let response = self.send(self.delete(&url).json(&body)).await?;
 
         match response.status().as_u16() {
             204 => Ok(()),
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Workspace '{}' not found", workspace_id),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!(
-                        "Failed to remove tags from workspace '{}': {}",
-                        workspace_id, body
-                    ),
-                })
+                    format!("Failed to remove tags from workspace '{}'", workspace_id),
+                    &body,
+                ))
             }
         }
     }
@@ -540,7 +540,9 @@ mod tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 404);
                 assert!(message.contains("missing-ws"));
             }
@@ -673,7 +675,9 @@ mod tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 422);
                 assert!(message.contains("my-workspace"));
             }
@@ -765,7 +769,9 @@ mod tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 404);
                 assert!(message.contains("nonexistent"));
             }
@@ -817,7 +823,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_org_tags("my-org", None).await;
+        let result = client.get_org_tags("my-org", None, None).await;
         assert!(result.is_ok());
         let tags = result.unwrap();
         assert_eq!(tags.len(), 2);
@@ -847,7 +853,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_org_tags("my-org", None).await;
+        let result = client.get_org_tags("my-org", None, None).await;
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
     }
@@ -865,7 +871,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.get_org_tags("my-org", Some("env")).await;
+        let result = client.get_org_tags("my-org", Some("env"), None).await;
         assert!(result.is_ok());
         let tags = result.unwrap();
         assert_eq!(tags.len(), 1);
@@ -947,7 +953,9 @@ mod tests {
         let result = client.get_workspace_tags("ws-notfound").await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 404);
                 assert!(message.contains("ws-notfound"));
             }
@@ -1018,7 +1026,9 @@ mod tests {
         let result = client.add_workspace_tags("ws-notfound", &tag_names).await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 404);
                 assert!(message.contains("ws-notfound"));
             }
@@ -1041,7 +1051,9 @@ mod tests {
         let result = client.add_workspace_tags("ws-abc123", &tag_names).await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 500);
                 assert!(message.contains("ws-abc123"));
             }
@@ -1114,7 +1126,9 @@ mod tests {
             .await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 404);
                 assert!(message.contains("ws-notfound"));
             }
@@ -1137,7 +1151,9 @@ mod tests {
         let result = client.remove_workspace_tags("ws-abc123", &tag_names).await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 500);
                 assert!(message.contains("ws-abc123"));
             }
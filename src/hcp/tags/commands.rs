@@ -4,8 +4,9 @@ use log::debug;
 
 use crate::cli::{
     classify_tags, parse_tags, Cli, Command, DeleteResource, DeleteTagResource, GetResource,
-    GetTagResource, SetResource, SetTagResource,
+    GetTagArgs, GetTagResource, SetResource, SetTagResource,
 };
+use crate::hcp::explain::{call, print_explain, project_lookup, workspace_lookup, PlannedCall};
 use crate::hcp::projects::resolve_project;
 use crate::hcp::tags::{TagTarget, TagTargetKind};
 use crate::hcp::traits::TfeResource;
@@ -13,7 +14,7 @@ use crate::hcp::workspaces::resolve_workspace;
 use crate::hcp::workspaces::WorkspaceQuery;
 use crate::hcp::TfeClient;
 use crate::output::{
-    output_org_tags, output_org_tags_with_workspaces, output_tag_bindings,
+    apply_limit, output_org_tags, output_org_tags_with_workspaces, output_tag_bindings,
     output_workspace_all_tags,
 };
 use crate::ui::{confirm_action, create_spinner, finish_spinner};
@@ -37,11 +38,36 @@ pub async fn run_set_tag_command(
             // Classify tags into flat string tags and key=value bindings
             let classified = classify_tags(&args.tags)?;
 
-            // Resolve workspace
             let effective_org = client.effective_org(args.org.as_ref());
-            let resolved =
-                resolve_workspace(client, &args.workspace, effective_org.as_deref(), cli.batch)
-                    .await?;
+            if cli.explain {
+                let mut calls = workspace_lookup(&args.workspace, effective_org.as_ref());
+                if !classified.flat_tags.is_empty() {
+                    calls.push(call(
+                        "POST",
+                        "/workspaces/:ws_id/relationships/tags",
+                        "key-only tags",
+                    ));
+                }
+                if !classified.bindings.is_empty() {
+                    calls.push(call(
+                        "PATCH",
+                        "/workspaces/:ws_id/tag-bindings",
+                        "key=value tags",
+                    ));
+                }
+                print_explain(&calls);
+                return Ok(());
+            }
+
+            // Resolve workspace
+            let resolved = resolve_workspace(
+                client,
+                &args.workspace,
+                effective_org.as_deref(),
+                cli.batch,
+                cli.strict,
+            )
+            .await?;
 
             let ws_name = resolved.workspace.name().to_string();
             let ws_id = resolved.workspace.id.clone();
@@ -63,7 +89,7 @@ pub async fn run_set_tag_command(
                 ws_id
             );
 
-            if !confirm_action(&prompt, args.yes || cli.batch)? {
+            if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
                 println!("Cancelled");
                 return Ok(());
             }
@@ -131,6 +157,12 @@ pub async fn run_set_tag_command(
             let org = effective_org
                 .as_deref()
                 .ok_or("Organization (--org) is required for project tag operations")?;
+            if cli.explain {
+                let mut calls = project_lookup(&args.project, org);
+                calls.push(call("PATCH", "/projects/:prj_id/tag-bindings", "set tags"));
+                print_explain(&calls);
+                return Ok(());
+            }
 
             // Resolve project
             let resolved = resolve_project(client, &args.project, org, cli.batch).await?;
@@ -149,7 +181,7 @@ pub async fn run_set_tag_command(
                 prj_id
             );
 
-            if !confirm_action(&prompt, args.yes || cli.batch)? {
+            if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
                 println!("Cancelled");
                 return Ok(());
             }
@@ -192,15 +224,24 @@ pub async fn run_get_tag_command(
     };
 
     let effective_org = client.effective_org(tag_args.org.as_ref());
+    if cli.explain {
+        print_explain(&plan_get_tag(tag_args, effective_org.as_deref())?);
+        return Ok(());
+    }
 
     match &tag_args.resource {
         Some(GetTagResource::Ws(args)) => {
             debug!("Getting tags for workspace '{}'", args.workspace);
 
             // Resolve workspace
-            let resolved =
-                resolve_workspace(client, &args.workspace, effective_org.as_deref(), cli.batch)
-                    .await?;
+            let resolved = resolve_workspace(
+                client,
+                &args.workspace,
+                effective_org.as_deref(),
+                cli.batch,
+                cli.strict,
+            )
+            .await?;
 
             let ws_name = resolved.workspace.name().to_string();
             let ws_id = resolved.workspace.id.clone();
@@ -253,12 +294,13 @@ pub async fn run_get_tag_command(
                 &format!("Fetching tags for project '{}'...", prj_name),
                 cli.batch,
             );
-            let tags = client.get_tag_bindings(&target).await?;
+            let mut tags = client.get_tag_bindings(&target).await?;
             finish_spinner(spinner);
 
             if tags.is_empty() {
                 println!("No tags found on project '{}'", prj_name);
             } else {
+                apply_limit(&mut tags, cli);
                 output_tag_bindings(&tags, &tag_args.output, cli.no_header);
             }
         }
@@ -277,7 +319,10 @@ pub async fn run_get_tag_command(
                 &format!("Fetching tags for organization '{}'...", org),
                 cli.batch,
             );
-            let tags = client.get_org_tags(org, search).await?;
+            let mut tags = client
+                .with_progress(spinner.as_ref())
+                .get_org_tags(org, search, cli.page_request())
+                .await?;
             finish_spinner(spinner);
 
             if tags.is_empty() {
@@ -294,7 +339,10 @@ pub async fn run_get_tag_command(
                     search_tags: Some(tag_name),
                     ..Default::default()
                 };
-                let workspaces = client.get_workspaces(org, query).await?;
+                let workspaces = client
+                    .with_progress(ws_spinner.as_ref())
+                    .get_workspaces(org, query)
+                    .await?;
                 finish_spinner(ws_spinner);
 
                 output_org_tags_with_workspaces(
@@ -304,6 +352,7 @@ pub async fn run_get_tag_command(
                     cli.no_header,
                 );
             } else {
+                apply_limit(&mut tags, cli);
                 output_org_tags(&tags, &tag_args.output, cli.no_header);
             }
         }
@@ -331,11 +380,28 @@ pub async fn run_delete_tag_command(
                 args.keys, args.workspace
             );
 
-            // Resolve workspace
             let effective_org = client.effective_org(args.org.as_ref());
-            let resolved =
-                resolve_workspace(client, &args.workspace, effective_org.as_deref(), cli.batch)
-                    .await?;
+            if cli.explain {
+                let mut calls = workspace_lookup(&args.workspace, effective_org.as_ref());
+                calls.extend(plan_remove_tags("workspaces", "ws_id"));
+                calls.push(call(
+                    "DELETE",
+                    "/workspaces/:ws_id/relationships/tags",
+                    "key-only tags",
+                ));
+                print_explain(&calls);
+                return Ok(());
+            }
+
+            // Resolve workspace
+            let resolved = resolve_workspace(
+                client,
+                &args.workspace,
+                effective_org.as_deref(),
+                cli.batch,
+                cli.strict,
+            )
+            .await?;
 
             let ws_name = resolved.workspace.name().to_string();
             let ws_id = resolved.workspace.id.clone();
@@ -348,7 +414,7 @@ pub async fn run_delete_tag_command(
                 ws_id
             );
 
-            if !confirm_action(&prompt, args.yes || cli.batch)? {
+            if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
                 println!("Cancelled");
                 return Ok(());
             }
@@ -410,6 +476,12 @@ pub async fn run_delete_tag_command(
             let org = effective_org
                 .as_deref()
                 .ok_or("Organization (--org) is required for project tag operations")?;
+            if cli.explain {
+                let mut calls = project_lookup(&args.project, org);
+                calls.extend(plan_remove_tags("projects", "prj_id"));
+                print_explain(&calls);
+                return Ok(());
+            }
 
             // Resolve project
             let resolved = resolve_project(client, &args.project, org, cli.batch).await?;
@@ -425,7 +497,7 @@ pub async fn run_delete_tag_command(
                 prj_id
             );
 
-            if !confirm_action(&prompt, args.yes || cli.batch)? {
+            if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
                 println!("Cancelled");
                 return Ok(());
             }
@@ -454,3 +526,69 @@ pub async fn run_delete_tag_command(
 
     Ok(())
 }
+
+/// API calls made by `get tag` for the effective organization
+fn plan_get_tag(
+    args: &GetTagArgs,
+    org: Option<&str>,
+) -> Result<Vec<PlannedCall>, Box<dyn std::error::Error>> {
+    Ok(match &args.resource {
+        Some(GetTagResource::Ws(ws)) => {
+            let mut calls = workspace_lookup(&ws.workspace, org.map(str::to_string).as_ref());
+            calls.push(call(
+                "GET",
+                "/workspaces/:ws_id/tag-bindings",
+                "key=value tags",
+            ));
+            calls.push(call(
+                "GET",
+                "/workspaces/:ws_id/relationships/tags",
+                "key-only tags",
+            ));
+            calls
+        }
+        Some(GetTagResource::Prj(prj)) => {
+            let org = org.ok_or("Organization (--org) is required for project tag operations")?;
+            let mut calls = project_lookup(&prj.project, org);
+            calls.push(call("GET", "/projects/:prj_id/tag-bindings", "tags"));
+            calls
+        }
+        None => {
+            let org = org.ok_or("Organization (--org) is required to list organization tags")?;
+            let search = args.name.as_deref().or(args.filter.as_deref());
+            let path = match search {
+                Some(q) => format!("/organizations/{}/tags?q={}", org, urlencoding::encode(q)),
+                None => format!("/organizations/{}/tags", org),
+            };
+            let mut calls = vec![call("GET", path, "paginated")];
+            if let Some(name) = &args.name {
+                calls.push(call(
+                    "GET",
+                    format!(
+                        "/organizations/{}/workspaces?search[tags]={}",
+                        org,
+                        urlencoding::encode(name)
+                    ),
+                    "workspaces with the tag",
+                ));
+            }
+            calls
+        }
+    })
+}
+
+/// Calls removing key=value tag bindings: read them, then replace the rest
+fn plan_remove_tags(collection: &str, id: &str) -> Vec<PlannedCall> {
+    vec![
+        call(
+            "GET",
+            format!("/{}/:{}/tag-bindings", collection, id),
+            "current key=value tags",
+        ),
+        call(
+            "PATCH",
+            format!("/{}/:{}", collection, id),
+            "replace remaining key=value tags",
+        ),
+    ]
+}
@@ -4,7 +4,7 @@ use log::debug;
 
 use crate::config::api;
 use crate::error::Result;
-use crate::hcp::TfeClient;
+use crate::hcp::{PageRequest, TfeClient};
 
 use super::models::TeamProjectAccess;
 use crate::hcp::traits::ApiListResponse;
@@ -14,15 +14,17 @@ impl TfeClient {
     pub async fn get_team_project_access(
         &self,
         project_id: &str,
+        page: Option<PageRequest>,
     ) -> Result<Vec<TeamProjectAccess>> {
         let path = format!("/{}?filter[project][id]={}", api::TEAM_PROJECTS, project_id);
         let error_context = format!("team-project access for project '{}'", project_id);
 
         debug!("Fetching team-project access: {}", path);
 
-        self.fetch_all_pages::<TeamProjectAccess, ApiListResponse<TeamProjectAccess>>(
+        self.fetch_pages::<TeamProjectAccess, ApiListResponse<TeamProjectAccess>>(
             &path,
             &error_context,
+            page,
         )
         .await
     }
@@ -95,7 +97,10 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let bindings = client.get_team_project_access("prj-abc").await.unwrap();
+        let bindings = client
+            .get_team_project_access("prj-abc", None)
+            .await
+            .unwrap();
 
         assert_eq!(bindings.len(), 2);
         assert_eq!(bindings[0].id, "tprj-1");
@@ -131,7 +136,10 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let bindings = client.get_team_project_access("prj-empty").await.unwrap();
+        let bindings = client
+            .get_team_project_access("prj-empty", None)
+            .await
+            .unwrap();
 
         assert!(bindings.is_empty());
     }
@@ -274,7 +282,10 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let bindings = client.get_team_project_access("prj-big").await.unwrap();
+        let bindings = client
+            .get_team_project_access("prj-big", None)
+            .await
+            .unwrap();
 
         assert_eq!(bindings.len(), 2);
         assert_eq!(bindings[0].id, "tprj-p1");
@@ -294,7 +305,7 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let result = client.get_team_project_access("prj-err").await;
+        let result = client.get_team_project_access("prj-err", None).await;
 
         assert!(result.is_err());
     }
@@ -5,13 +5,13 @@ use std::collections::HashMap;
 use futures::stream::{self, StreamExt};
 use log::debug;
 
-use crate::cli::{OutputFormat, TeamAccessSortField};
-use crate::config::api;
+use crate::cli::{OutputFormat, TeamAccessArgs, TeamAccessSortField};
 use crate::error::Result as TfeResult;
+use crate::hcp::explain::{call, print_explain, project_lookup, PlannedCall};
 use crate::hcp::projects::{resolve_project, Project};
 use crate::hcp::teams::Team;
 use crate::hcp::TfeClient;
-use crate::output::{output_raw, output_team_access};
+use crate::output::{apply_limit, output_raw, output_team_access};
 use crate::ui::{create_spinner, finish_spinner};
 use crate::{Cli, Command, GetResource};
 
@@ -32,6 +32,10 @@ pub async fn run_team_access_command(
     // Direct lookup by tprj- ID — no org required
     if let Some(name) = &args.name {
         if name.starts_with("tprj-") {
+            if cli.explain {
+                print_explain(&plan_get_single_team_access(name));
+                return Ok(());
+            }
             return get_single_team_access(client, cli, name).await;
         }
     }
@@ -41,6 +45,10 @@ pub async fn run_team_access_command(
     let org = effective_org
         .as_ref()
         .ok_or("Organization is required (--org)")?;
+    if cli.explain {
+        print_explain(&plan_get_team_access(args, org));
+        return Ok(());
+    }
 
     let team_name = args.name.as_deref();
     let prj_input = args.prj.as_deref();
@@ -69,7 +77,8 @@ pub async fn run_team_access_command(
             ))?;
 
             let all_bindings = client
-                .get_team_project_access(&resolved_prj.project.id)
+                .with_progress(spinner.as_ref())
+                .get_team_project_access(&resolved_prj.project.id, cli.page_request())
                 .await?;
 
             let filtered: Vec<TeamProjectAccess> = all_bindings
@@ -78,7 +87,10 @@ pub async fn run_team_access_command(
                 .collect();
 
             // Fetch teams for enrichment
-            let teams = client.get_teams(org).await?;
+            let teams = client
+                .with_progress(spinner.as_ref())
+                .get_teams(org, None)
+                .await?;
             let projects = vec![resolved_prj.project];
 
             finish_spinner(spinner);
@@ -100,8 +112,11 @@ pub async fn run_team_access_command(
                 team, org
             ))?;
 
-            let (teams, projects) =
-                tokio::join!(client.get_teams(org), client.get_projects(org, None));
+            let progress_client = &client.with_progress(spinner.as_ref());
+            let (teams, projects) = tokio::join!(
+                progress_client.get_teams(org, None),
+                progress_client.get_projects(org, None, None)
+            );
             let teams = teams?;
             let projects = projects?;
 
@@ -124,9 +139,11 @@ pub async fn run_team_access_command(
 
             let resolved_prj = resolve_project(client, prj, org, cli.batch).await?;
 
+            let progress_client = &client.with_progress(spinner.as_ref());
             let (teams, bindings) = tokio::join!(
-                client.get_teams(org),
-                client.get_team_project_access(&resolved_prj.project.id)
+                progress_client.get_teams(org, None),
+                progress_client
+                    .get_team_project_access(&resolved_prj.project.id, cli.page_request())
             );
             let teams = teams?;
             let bindings = bindings?;
@@ -145,8 +162,11 @@ pub async fn run_team_access_command(
                 cli.batch,
             );
 
-            let (teams, projects) =
-                tokio::join!(client.get_teams(org), client.get_projects(org, None));
+            let progress_client = &client.with_progress(spinner.as_ref());
+            let (teams, projects) = tokio::join!(
+                progress_client.get_teams(org, None),
+                progress_client.get_projects(org, None, None)
+            );
             let teams = teams?;
             let projects = projects?;
 
@@ -175,6 +195,7 @@ pub async fn run_team_access_command(
 
     // Sort
     sort_team_access(&mut bindings, &args.sort, args.reverse);
+    apply_limit(&mut bindings, cli);
 
     output_team_access(&bindings, &args.output, cli.no_header);
     Ok(())
@@ -202,7 +223,10 @@ async fn get_single_team_access(
         Ok(Some((binding, raw))) => {
             finish_spinner(spinner);
             match args.output {
-                OutputFormat::Json | OutputFormat::Yaml => {
+                OutputFormat::Json
+                | OutputFormat::Yaml
+                | OutputFormat::Ndjson
+                | OutputFormat::Template => {
                     output_raw(&raw, &args.output);
                 }
                 _ => {
@@ -233,9 +257,9 @@ async fn fan_out_per_project(
     let results: Vec<TfeResult<Vec<TeamProjectAccess>>> = stream::iter(
         project_ids
             .into_iter()
-            .map(|prj_id| async move { client.get_team_project_access(&prj_id).await }),
+            .map(|prj_id| async move { client.get_team_project_access(&prj_id, None).await }),
     )
-    .buffer_unordered(api::MAX_CONCURRENT_PAGE_REQUESTS)
+    .buffer_unordered(client.concurrency())
     .collect()
     .await;
 
@@ -356,6 +380,62 @@ fn sort_team_access(
     });
 }
 
+/// API calls made by `get team-access` for a `tprj-` ID
+fn plan_get_single_team_access(tprj_id: &str) -> Vec<PlannedCall> {
+    vec![
+        call(
+            "GET",
+            format!("/team-projects/{}", tprj_id),
+            "team-project access",
+        ),
+        call("GET", "/teams/:team_id", "team name (concurrent)"),
+        call("GET", "/projects/:prj_id", "project name (concurrent)"),
+    ]
+}
+
+/// API calls made by `get team-access` in `org`
+fn plan_get_team_access(args: &TeamAccessArgs, org: &str) -> Vec<PlannedCall> {
+    let mut calls = Vec::new();
+    if let Some(prj) = &args.prj {
+        calls.extend(project_lookup(prj, org));
+    }
+    if let Some(team) = &args.name {
+        calls.push(call(
+            "GET",
+            format!(
+                "/organizations/{}/teams?filter[names]={}",
+                org,
+                urlencoding::encode(team)
+            ),
+            "resolve team",
+        ));
+    }
+    calls.push(call(
+        "GET",
+        format!("/organizations/{}/teams", org),
+        "paginated, team names",
+    ));
+    if args.prj.is_some() {
+        calls.push(call(
+            "GET",
+            "/team-projects?filter[project][id]=:prj_id",
+            "paginated",
+        ));
+    } else {
+        calls.push(call(
+            "GET",
+            format!("/organizations/{}/projects", org),
+            "paginated",
+        ));
+        calls.push(call(
+            "GET",
+            "/team-projects?filter[project][id]=:prj_id",
+            "per project (concurrent)",
+        ));
+    }
+    calls
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -801,4 +881,19 @@ mod tests {
         assert_eq!(enriched.team_id, "team-gone");
         assert_eq!(enriched.project_id, "prj-gone");
     }
+
+    #[test]
+    fn test_plan_team_access_fans_out_without_project() {
+        let cli = <Cli as clap::Parser>::parse_from(["hcp", "get", "team-access"]);
+        let Command::Get {
+            resource: GetResource::TeamAccess(args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        let calls = plan_get_team_access(args, "acme");
+        assert_eq!(calls[0].path, "/organizations/acme/teams");
+        assert_eq!(calls[1].path, "/organizations/acme/projects");
+        assert_eq!(calls[2].note, "per project (concurrent)");
+    }
 }
@@ -1,21 +1,24 @@
 //! Team API operations
 
+use std::collections::HashMap;
+
+use futures::stream::{self, StreamExt};
 use log::debug;
 
 use crate::config::api;
 use crate::error::{Result, TfeError};
-use crate::hcp::TfeClient;
+use crate::hcp::{PageRequest, TfeClient};
 
-use super::models::Team;
+use super::models::{Team, TeamMember};
 use crate::hcp::traits::ApiListResponse;
 
 impl TfeClient {
     /// Get all teams for an organization (with pagination)
-    pub async fn get_teams(&self, org: &str) -> Result<Vec<Team>> {
+    pub async fn get_teams(&self, org: &str, page: Option<PageRequest>) -> Result<Vec<Team>> {
         let path = format!("/{}/{}/{}", api::ORGANIZATIONS, org, api::TEAMS);
         let error_context = format!("teams for organization '{}'", org);
 
-        self.fetch_all_pages::<Team, ApiListResponse<Team>>(&path, &error_context)
+        self.fetch_pages::<Team, ApiListResponse<Team>>(&path, &error_context, page)
             .await
     }
 
@@ -26,6 +29,47 @@ impl TfeClient {
             .await
     }
 
+    /// Get the members (users) of a team, with emails where visible
+    pub async fn get_team_members(&self, team_id: &str) -> Result<Option<Vec<TeamMember>>> {
+        let path = format!(
+            "/{}/{}?include=users,organization-memberships",
+            api::TEAMS,
+            team_id
+        );
+        let result = self
+            .fetch_resource_by_path::<Team>(&path, &format!("members of team '{}'", team_id))
+            .await?;
+        Ok(result.map(|(_, raw)| TeamMember::from_included(&raw)))
+    }
+
+    /// Fetch members for several teams concurrently, keyed by team ID
+    ///
+    /// Uses `buffer_unordered` bounded by `--concurrency`.
+    /// Teams that fail to load are skipped with a debug warning.
+    pub async fn fetch_team_members(&self, teams: &[Team]) -> HashMap<String, Vec<TeamMember>> {
+        stream::iter(teams)
+            .map(|team| async move {
+                match self.get_team_members(&team.id).await {
+                    Ok(Some(members)) => Some((team.id.clone(), members)),
+                    Ok(None) => {
+                        debug!("Team '{}' not found (404), skipping", team.id);
+                        None
+                    }
+                    Err(e) => {
+                        debug!(
+                            "Error fetching members of team '{}': {}, skipping",
+                            team.id, e
+                        );
+                        None
+                    }
+                }
+            })
+            .buffer_unordered(self.concurrency())
+            .filter_map(|entry| async move { entry })
+            .collect()
+            .await
+    }
+
     /// Get a team by name within an organization
     pub async fn get_team_by_name(
         &self,
@@ -43,7 +87,7 @@ impl TfeClient {
         );
         debug!("Fetching team by name: {}", url);
 
-        let response = self.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -52,6 +96,7 @@ impl TfeClient {
                     .map_err(|e| TfeError::Api {
                         status: 200,
                         message: format!("Failed to parse teams: {}", e),
+                        details: Vec::new(),
                     })?;
 
                 // Find exact match (API might return partial matches)
@@ -72,10 +117,12 @@ impl TfeClient {
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Organization '{}' not found", org),
+                details: Vec::new(),
             }),
             status => Err(TfeError::Api {
                 status,
                 message: format!("Failed to fetch team '{}' in organization '{}'", name, org),
+                details: Vec::new(),
             }),
         }
     }
@@ -144,7 +191,7 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let teams = client.get_teams("my-org").await.unwrap();
+        let teams = client.get_teams("my-org", None).await.unwrap();
 
         assert_eq!(teams.len(), 2);
         assert_eq!(teams[0].id, "team-abc");
@@ -165,7 +212,7 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let result = client.get_teams("unknown-org").await;
+        let result = client.get_teams("unknown-org", None).await;
 
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -237,7 +284,7 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let teams = client.get_teams("my-org").await.unwrap();
+        let teams = client.get_teams("my-org", None).await.unwrap();
 
         assert_eq!(teams.len(), 2);
         assert_eq!(teams[0].id, "team-1");
@@ -291,6 +338,79 @@ mod tests {
         assert!(result.is_none());
     }
 
+    fn team_with_members_body(team_id: &str, username: &str) -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "id": team_id,
+                "type": "teams",
+                "attributes": {"name": team_id},
+                "relationships": {
+                    "users": {"data": [{"id": "user-1", "type": "users"}]}
+                }
+            },
+            "included": [
+                {"id": "user-1", "type": "users", "attributes": {"username": username}},
+                {
+                    "id": "ou-1",
+                    "type": "organization-memberships",
+                    "attributes": {"email": "alice@example.com"},
+                    "relationships": {"user": {"data": {"id": "user-1", "type": "users"}}}
+                }
+            ]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_team_members() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/teams/team-abc"))
+            .and(query_param("include", "users,organization-memberships"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(team_with_members_body("team-abc", "alice")),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let members = client.get_team_members("team-abc").await.unwrap().unwrap();
+
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].username, "alice");
+        assert_eq!(members[0].email.as_deref(), Some("alice@example.com"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_team_members_skips_failures() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/teams/team-ok"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(team_with_members_body("team-ok", "bob")),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/teams/team-gone"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let teams: Vec<Team> = serde_json::from_value(serde_json::json!([
+            {"id": "team-ok", "type": "teams"},
+            {"id": "team-gone", "type": "teams"}
+        ]))
+        .unwrap();
+        let client = TfeClient::test_client(&mock_server.uri());
+        let members = client.fetch_team_members(&teams).await;
+
+        assert_eq!(members.len(), 1);
+        assert_eq!(members["team-ok"][0].username, "bob");
+    }
+
     #[tokio::test]
     async fn test_get_team_by_name() {
         let mock_server = MockServer::start().await;
@@ -2,9 +2,10 @@
 
 use log::debug;
 
-use crate::cli::OutputFormat;
+use crate::cli::{OutputFormat, TeamArgs};
+use crate::hcp::explain::{call, print_explain, PlannedCall};
 use crate::hcp::TfeClient;
-use crate::output::{output_raw, output_teams};
+use crate::output::{apply_limit, output_raw, output_team_members, output_teams};
 use crate::ui::{create_spinner, finish_spinner};
 use crate::{Cli, Command, GetResource};
 
@@ -25,6 +26,10 @@ pub async fn run_team_command(
     let org = effective_org
         .as_ref()
         .ok_or("Organization is required (--org)")?;
+    if cli.explain {
+        print_explain(&plan_get_team(args, org));
+        return Ok(());
+    }
 
     debug!("Fetching teams for organization: {}", org);
 
@@ -40,12 +45,28 @@ pub async fn run_team_command(
         };
 
         match result {
+            Some((team, _)) if args.members => {
+                let members = client.get_team_members(&team.id).await?.unwrap_or_default();
+                finish_spinner(spinner);
+                if members.is_empty() {
+                    eprintln!("Team '{}' has no members", team.name());
+                    return Ok(());
+                }
+                output_team_members(&members, cli);
+                return Ok(());
+            }
             Some((team, raw)) => {
                 finish_spinner(spinner);
-                if matches!(args.output, OutputFormat::Json | OutputFormat::Yaml) {
+                if matches!(
+                    args.output,
+                    OutputFormat::Json
+                        | OutputFormat::Yaml
+                        | OutputFormat::Ndjson
+                        | OutputFormat::Template
+                ) {
                     output_raw(&raw, &args.output);
                 } else {
-                    output_teams(&[team], cli);
+                    output_teams(&[team], None, cli);
                 }
                 return Ok(());
             }
@@ -57,7 +78,7 @@ pub async fn run_team_command(
     }
 
     // List all teams
-    let mut teams = client.get_teams(org).await?;
+    let mut teams = client.get_teams(org, cli.page_request()).await?;
 
     finish_spinner(spinner);
 
@@ -77,6 +98,53 @@ pub async fn run_team_command(
         return Ok(());
     }
 
-    output_teams(&teams, cli);
+    apply_limit(&mut teams, cli);
+
+    let members = if args.with_members {
+        let spinner = create_spinner(
+            &format!("Fetching members for {} teams...", teams.len()),
+            cli.batch,
+        );
+        let members = client.fetch_team_members(&teams).await;
+        finish_spinner(spinner);
+        Some(members)
+    } else {
+        None
+    };
+
+    output_teams(&teams, members.as_ref(), cli);
     Ok(())
 }
+
+/// API calls made by `get team` in `org`
+fn plan_get_team(args: &TeamArgs, org: &str) -> Vec<PlannedCall> {
+    const MEMBERS: &str = "/teams/:team_id?include=users,organization-memberships";
+    let Some(name) = &args.name else {
+        let mut calls = vec![call(
+            "GET",
+            format!("/organizations/{}/teams", org),
+            "paginated",
+        )];
+        if args.with_members {
+            calls.push(call("GET", MEMBERS, "per team (concurrent)"));
+        }
+        return calls;
+    };
+    let mut calls = vec![if name.starts_with("team-") {
+        call("GET", format!("/teams/{}", name), "team")
+    } else {
+        call(
+            "GET",
+            format!(
+                "/organizations/{}/teams?filter[names]={}",
+                org,
+                urlencoding::encode(name)
+            ),
+            "look up team by name",
+        )
+    }];
+    if args.members {
+        calls.push(call("GET", MEMBERS, "members"));
+    }
+    calls
+}
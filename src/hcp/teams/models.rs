@@ -1,5 +1,7 @@
 //! Team data models
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::hcp::traits::TfeResource;
@@ -149,6 +151,63 @@ impl TfeResource for Team {
     }
 }
 
+/// Team member resolved from a `?include=users,organization-memberships` response
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TeamMember {
+    pub user_id: String,
+    pub username: String,
+    /// Only present when the organization membership is visible to the token
+    pub email: Option<String>,
+}
+
+impl TeamMember {
+    /// Build the member list from a team response with included users and memberships
+    ///
+    /// Users keep the order of the team's `users` relationship; emails come
+    /// from the included organization memberships, matched by user ID.
+    pub fn from_included(raw: &serde_json::Value) -> Vec<TeamMember> {
+        let included = raw["included"].as_array().map(Vec::as_slice).unwrap_or(&[]);
+        let of_type = |kind: &'static str| {
+            included
+                .iter()
+                .filter(move |item| item["type"].as_str() == Some(kind))
+        };
+
+        let emails: HashMap<&str, &str> = of_type("organization-memberships")
+            .filter_map(|m| {
+                let user_id = m["relationships"]["user"]["data"]["id"].as_str()?;
+                Some((user_id, m["attributes"]["email"].as_str()?))
+            })
+            .collect();
+        let usernames: HashMap<&str, &str> = of_type("users")
+            .filter_map(|u| Some((u["id"].as_str()?, u["attributes"]["username"].as_str()?)))
+            .collect();
+
+        raw["data"]["relationships"]["users"]["data"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+            .iter()
+            .filter_map(|user| user["id"].as_str())
+            .map(|id| TeamMember {
+                user_id: id.to_string(),
+                username: usernames.get(id).copied().unwrap_or_default().to_string(),
+                email: emails.get(id).map(|e| e.to_string()),
+            })
+            .collect()
+    }
+}
+
+impl TfeResource for TeamMember {
+    fn id(&self) -> &str {
+        &self.user_id
+    }
+
+    fn name(&self) -> &str {
+        &self.username
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,4 +329,44 @@ mod tests {
         assert!(team.matches("test-team"));
         assert!(!team.matches("other"));
     }
+
+    #[test]
+    fn test_team_members_from_included() {
+        let raw = serde_json::json!({
+            "data": {
+                "id": "team-abc",
+                "type": "teams",
+                "relationships": {
+                    "users": {"data": [
+                        {"id": "user-2", "type": "users"},
+                        {"id": "user-1", "type": "users"}
+                    ]}
+                }
+            },
+            "included": [
+                {"id": "user-1", "type": "users", "attributes": {"username": "alice"}},
+                {"id": "user-2", "type": "users", "attributes": {"username": "bob"}},
+                {
+                    "id": "ou-1",
+                    "type": "organization-memberships",
+                    "attributes": {"email": "alice@example.com"},
+                    "relationships": {"user": {"data": {"id": "user-1", "type": "users"}}}
+                }
+            ]
+        });
+
+        let members = TeamMember::from_included(&raw);
+        assert_eq!(members.len(), 2);
+        assert_eq!(members[0].username, "bob");
+        assert_eq!(members[0].email, None);
+        assert_eq!(members[1].user_id, "user-1");
+        assert_eq!(members[1].username, "alice");
+        assert_eq!(members[1].email.as_deref(), Some("alice@example.com"));
+    }
+
+    #[test]
+    fn test_team_members_without_included() {
+        let raw = serde_json::json!({"data": {"id": "team-abc", "type": "teams"}});
+        assert!(TeamMember::from_included(&raw).is_empty());
+    }
 }
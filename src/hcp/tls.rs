@@ -0,0 +1,169 @@
+//! TLS settings for private TFE installations
+//!
+//! Adds a custom CA bundle to the trusted roots (for self-signed or internal
+//! PKI) and optionally presents a client certificate for mutual TLS.
+
+use reqwest::{Certificate, ClientBuilder, Identity};
+use std::path::{Path, PathBuf};
+
+use crate::error::{Result, TfeError};
+
+/// Optional TLS material for the HTTP client
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TlsConfig {
+    /// PEM bundle with additional trusted CA certificates
+    pub ca_cert: Option<PathBuf>,
+    /// PEM client certificate (requires `client_key`)
+    pub client_cert: Option<PathBuf>,
+    /// PEM private key for `client_cert`
+    pub client_key: Option<PathBuf>,
+}
+
+impl TlsConfig {
+    /// True when no custom TLS material is configured
+    pub fn is_empty(&self) -> bool {
+        self.ca_cert.is_none() && self.client_cert.is_none() && self.client_key.is_none()
+    }
+
+    /// Read and parse the configured files and apply them to `builder`
+    ///
+    /// Fails fast with the offending path in the message, so a typo does not
+    /// surface later as an opaque handshake error.
+    pub fn apply(&self, mut builder: ClientBuilder) -> Result<ClientBuilder> {
+        if let Some(path) = &self.ca_cert {
+            builder = builder.tls_certs_merge(load_ca_certs(path)?);
+        }
+        match (&self.client_cert, &self.client_key) {
+            (Some(cert), Some(key)) => builder = builder.identity(load_identity(cert, key)?),
+            (None, None) => {}
+            _ => {
+                return Err(TfeError::Tls(
+                    "--client-cert and --client-key must be given together".to_string(),
+                ))
+            }
+        }
+        Ok(builder)
+    }
+}
+
+fn read_pem(kind: &str, path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| {
+        TfeError::Tls(format!(
+            "Failed to read {} '{}': {}",
+            kind,
+            path.display(),
+            e
+        ))
+    })
+}
+
+fn load_ca_certs(path: &Path) -> Result<Vec<Certificate>> {
+    let pem = read_pem("CA certificate", path)?;
+    let certs = Certificate::from_pem_bundle(&pem).map_err(|e| {
+        TfeError::Tls(format!(
+            "Failed to parse CA certificate '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+    if certs.is_empty() {
+        return Err(TfeError::Tls(format!(
+            "No PEM certificates found in CA certificate '{}'",
+            path.display()
+        )));
+    }
+    Ok(certs)
+}
+
+fn load_identity(cert: &Path, key: &Path) -> Result<Identity> {
+    let mut pem = read_pem("client certificate", cert)?;
+    pem.push(b'\n');
+    pem.extend(read_pem("client key", key)?);
+    Identity::from_pem(&pem).map_err(|e| {
+        TfeError::Tls(format!(
+            "Failed to load client certificate '{}' with key '{}': {}",
+            cert.display(),
+            key.display(),
+            e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    fn temp_file(content: &str) -> NamedTempFile {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    fn apply_err(tls: TlsConfig) -> String {
+        tls.apply(reqwest::Client::builder())
+            .expect_err("expected TLS error")
+            .to_string()
+    }
+
+    #[test]
+    fn test_empty_config_is_noop() {
+        let tls = TlsConfig::default();
+        assert!(tls.is_empty());
+        assert!(tls.apply(reqwest::Client::builder()).is_ok());
+    }
+
+    #[test]
+    fn test_missing_ca_cert_names_path() {
+        let err = apply_err(TlsConfig {
+            ca_cert: Some(PathBuf::from("/nonexistent/ca.pem")),
+            ..Default::default()
+        });
+        assert!(err.contains("Failed to read CA certificate"), "{}", err);
+        assert!(err.contains("/nonexistent/ca.pem"), "{}", err);
+    }
+
+    #[test]
+    fn test_ca_cert_without_pem_blocks() {
+        let file = temp_file("not a certificate\n");
+        let err = apply_err(TlsConfig {
+            ca_cert: Some(file.path().to_path_buf()),
+            ..Default::default()
+        });
+        assert!(err.contains("No PEM certificates found"), "{}", err);
+    }
+
+    #[test]
+    fn test_client_cert_requires_key() {
+        let file = temp_file("");
+        let err = apply_err(TlsConfig {
+            client_cert: Some(file.path().to_path_buf()),
+            ..Default::default()
+        });
+        assert!(err.contains("must be given together"), "{}", err);
+    }
+
+    #[test]
+    fn test_invalid_client_identity() {
+        let cert = temp_file("garbage\n");
+        let key = temp_file("garbage\n");
+        let err = apply_err(TlsConfig {
+            client_cert: Some(cert.path().to_path_buf()),
+            client_key: Some(key.path().to_path_buf()),
+            ..Default::default()
+        });
+        assert!(err.contains("Failed to load client certificate"), "{}", err);
+    }
+
+    #[test]
+    fn test_missing_client_key_names_path() {
+        let cert = temp_file("");
+        let err = apply_err(TlsConfig {
+            client_cert: Some(cert.path().to_path_buf()),
+            client_key: Some(PathBuf::from("/nonexistent/key.pem")),
+            ..Default::default()
+        });
+        assert!(err.contains("Failed to read client key"), "{}", err);
+    }
+}
@@ -0,0 +1,147 @@
+//! Variable set API operations
+
+use crate::config::api;
+use crate::error::Result;
+use crate::hcp::traits::ApiListResponse;
+use crate::hcp::{PageRequest, TfeClient};
+
+use super::models::VariableSet;
+
+impl TfeClient {
+    /// Get all variable sets of an organization (with pagination)
+    pub async fn get_variable_sets(
+        &self,
+        org: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<VariableSet>> {
+        let path = format!("/{}/{}/{}", api::ORGANIZATIONS, org, api::VARSETS);
+        let error_context = format!("variable sets for organization '{}'", org);
+
+        self.fetch_pages::<VariableSet, ApiListResponse<VariableSet>>(&path, &error_context, page)
+            .await
+    }
+
+    /// Get variable sets applied to a project (with pagination)
+    pub async fn get_project_variable_sets(
+        &self,
+        project_id: &str,
+        page: Option<PageRequest>,
+    ) -> Result<Vec<VariableSet>> {
+        let path = format!("/{}/{}/{}", api::PROJECTS, project_id, api::VARSETS);
+        let error_context = format!("variable sets for project '{}'", project_id);
+
+        self.fetch_pages::<VariableSet, ApiListResponse<VariableSet>>(&path, &error_context, page)
+            .await
+    }
+
+    /// Get a variable set by ID, including its workspace and project relationships
+    pub async fn get_variable_set(
+        &self,
+        varset_id: &str,
+    ) -> Result<Option<(VariableSet, serde_json::Value)>> {
+        let path = format!("/{}/{}", api::VARSETS, varset_id);
+        self.fetch_resource_by_path::<VariableSet>(&path, &format!("variable set '{}'", varset_id))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn varset_json(id: &str, name: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "type": "varsets",
+            "attributes": {
+                "name": name,
+                "global": false,
+                "workspace-count": 1,
+                "project-count": 0
+            }
+        })
+    }
+
+    fn single_page(data: Vec<serde_json::Value>) -> serde_json::Value {
+        let count = data.len();
+        serde_json::json!({
+            "data": data,
+            "meta": {
+                "pagination": {
+                    "current-page": 1,
+                    "page-size": 100,
+                    "prev-page": null,
+                    "next-page": null,
+                    "total-pages": 1,
+                    "total-count": count
+                }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_variable_sets() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/varsets"))
+            .and(query_param("page[number]", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(single_page(vec![
+                varset_json("varset-1", "aws-creds"),
+                varset_json("varset-2", "global-tags"),
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let varsets = client.get_variable_sets("my-org", None).await.unwrap();
+
+        assert_eq!(varsets.len(), 2);
+        assert_eq!(varsets[0].name(), "aws-creds");
+        assert_eq!(varsets[1].id, "varset-2");
+    }
+
+    #[tokio::test]
+    async fn test_get_project_variable_sets() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/projects/prj-abc/varsets"))
+            .and(query_param("page[number]", "1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(single_page(vec![varset_json("varset-1", "aws-creds")])),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let varsets = client
+            .get_project_variable_sets("prj-abc", None)
+            .await
+            .unwrap();
+
+        assert_eq!(varsets.len(), 1);
+        assert_eq!(varsets[0].name(), "aws-creds");
+    }
+
+    #[tokio::test]
+    async fn test_get_variable_set_not_found() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/varsets/varset-missing"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        assert!(client
+            .get_variable_set("varset-missing")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
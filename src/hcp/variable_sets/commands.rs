@@ -0,0 +1,174 @@
+//! Variable set command handlers
+
+use log::debug;
+
+use crate::cli::VarsetArgs;
+use crate::hcp::explain::{call, print_explain, project_lookup, PlannedCall};
+use crate::hcp::projects::resolve_project;
+use crate::hcp::traits::TfeResource;
+use crate::hcp::TfeClient;
+use crate::output::{apply_limit, output_variable_sets};
+use crate::ui::{create_spinner, finish_spinner};
+use crate::{Cli, Command, GetResource};
+
+use super::models::VariableSet;
+
+/// Run the variable set list/get command
+pub async fn run_varset_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::Varset(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let effective_org = client.effective_org(args.org.as_ref());
+    let org = effective_org
+        .as_ref()
+        .ok_or("Organization is required (--org)")?;
+    if cli.explain {
+        print_explain(&plan_get_varset(args, org));
+        return Ok(());
+    }
+
+    if let Some(name) = &args.name {
+        let varset = get_single_varset(client, cli, org, name).await?;
+        output_variable_sets(&[varset], cli);
+        return Ok(());
+    }
+
+    let mut varsets = match &args.prj {
+        Some(prj) => {
+            let resolved = resolve_project(client, prj, org, cli.batch).await?;
+            let spinner = create_spinner(
+                &format!(
+                    "Fetching variable sets for project '{}'...",
+                    resolved.project.name()
+                ),
+                cli.batch,
+            );
+            let result = client
+                .with_progress(spinner.as_ref())
+                .get_project_variable_sets(&resolved.project.id, cli.page_request())
+                .await;
+            finish_spinner(spinner);
+            result?
+        }
+        None => {
+            let spinner = create_spinner(
+                &format!("Fetching variable sets for '{}'...", org),
+                cli.batch,
+            );
+            let result = client
+                .with_progress(spinner.as_ref())
+                .get_variable_sets(org, cli.page_request())
+                .await;
+            finish_spinner(spinner);
+            result?
+        }
+    };
+
+    if let Some(filter) = &args.filter {
+        let filter_lower = filter.to_lowercase();
+        varsets.retain(|vs| vs.name().to_lowercase().contains(&filter_lower));
+        debug!(
+            "Filtered to {} variable sets matching '{}'",
+            varsets.len(),
+            filter
+        );
+    }
+
+    if varsets.is_empty() {
+        if args.filter.is_some() {
+            eprintln!("No variable sets found matching filter");
+        } else {
+            eprintln!("No variable sets found");
+        }
+        return Ok(());
+    }
+
+    varsets.sort_by(|a, b| a.name().cmp(b.name()));
+    apply_limit(&mut varsets, cli);
+    output_variable_sets(&varsets, cli);
+    Ok(())
+}
+
+/// Fetch one variable set by ID (varset-xxx) or name, with its relationships
+async fn get_single_varset(
+    client: &TfeClient,
+    cli: &Cli,
+    org: &str,
+    target: &str,
+) -> Result<VariableSet, Box<dyn std::error::Error>> {
+    let spinner = create_spinner(&format!("Fetching variable set '{}'...", target), cli.batch);
+
+    let varset_id = if target.starts_with("varset-") {
+        target.to_string()
+    } else {
+        let varsets = client
+            .with_progress(spinner.as_ref())
+            .get_variable_sets(org, None)
+            .await;
+        let found = varsets.map(|list| {
+            list.into_iter()
+                .find(|vs| vs.name().eq_ignore_ascii_case(target))
+                .map(|vs| vs.id)
+        });
+        match found {
+            Ok(Some(id)) => id,
+            Ok(None) => {
+                finish_spinner(spinner);
+                return Err(format!(
+                    "Variable set '{}' not found in organization '{}'",
+                    target, org
+                )
+                .into());
+            }
+            Err(e) => {
+                finish_spinner(spinner);
+                return Err(e.into());
+            }
+        }
+    };
+
+    let result = client.get_variable_set(&varset_id).await;
+    finish_spinner(spinner);
+    match result? {
+        Some((varset, _raw)) => Ok(varset),
+        None => Err(format!("Variable set '{}' not found", target).into()),
+    }
+}
+
+/// API calls made by `get varset` in `org`
+fn plan_get_varset(args: &VarsetArgs, org: &str) -> Vec<PlannedCall> {
+    match (&args.name, &args.prj) {
+        (Some(id), _) if id.starts_with("varset-") => {
+            vec![call("GET", format!("/varsets/{}", id), "variable set")]
+        }
+        (Some(_), _) => vec![
+            call(
+                "GET",
+                format!("/organizations/{}/varsets", org),
+                "find variable set by name",
+            ),
+            call(
+                "GET",
+                "/varsets/:varset_id",
+                "variable set with attachments",
+            ),
+        ],
+        (None, Some(prj)) => {
+            let mut calls = project_lookup(prj, org);
+            calls.push(call("GET", "/projects/:prj_id/varsets", "paginated"));
+            calls
+        }
+        (None, None) => vec![call(
+            "GET",
+            format!("/organizations/{}/varsets", org),
+            "paginated",
+        )],
+    }
+}
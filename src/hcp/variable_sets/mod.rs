@@ -0,0 +1,8 @@
+//! Variable sets module - list variable sets at org and project scope
+
+mod api;
+mod commands;
+mod models;
+
+pub use commands::run_varset_command;
+pub use models::{VariableSet, VariableSetAttributes};
@@ -0,0 +1,168 @@
+//! Variable set data models
+
+use serde::{Deserialize, Serialize};
+
+use crate::hcp::traits::TfeResource;
+
+/// Variable set from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VariableSet {
+    pub id: String,
+    pub attributes: VariableSetAttributes,
+    pub relationships: Option<VariableSetRelationships>,
+}
+
+/// Variable set attributes from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VariableSetAttributes {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub global: bool,
+    #[serde(default)]
+    pub priority: bool,
+    #[serde(rename = "workspace-count")]
+    pub workspace_count: Option<u32>,
+    #[serde(rename = "project-count")]
+    pub project_count: Option<u32>,
+    #[serde(rename = "var-count")]
+    pub var_count: Option<u32>,
+}
+
+/// Variable set relationships (attached workspaces and projects)
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct VariableSetRelationships {
+    pub workspaces: Option<VariableSetRelationship>,
+    pub projects: Option<VariableSetRelationship>,
+}
+
+/// To-many relationship of a variable set
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct VariableSetRelationship {
+    #[serde(default)]
+    pub data: Vec<VariableSetRef>,
+}
+
+/// Reference to a workspace or project attached to a variable set
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VariableSetRef {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub ref_type: Option<String>,
+}
+
+impl VariableSet {
+    /// Get variable set name
+    pub fn name(&self) -> &str {
+        &self.attributes.name
+    }
+
+    /// Number of workspaces the set is attached to
+    pub fn workspace_count(&self) -> u32 {
+        self.attributes
+            .workspace_count
+            .unwrap_or_else(|| self.workspace_ids().len() as u32)
+    }
+
+    /// Number of projects the set is attached to
+    pub fn project_count(&self) -> u32 {
+        self.attributes
+            .project_count
+            .unwrap_or_else(|| self.project_ids().len() as u32)
+    }
+
+    /// IDs of attached workspaces (from relationships)
+    pub fn workspace_ids(&self) -> Vec<&str> {
+        Self::ref_ids(
+            self.relationships
+                .as_ref()
+                .and_then(|r| r.workspaces.as_ref()),
+        )
+    }
+
+    /// IDs of attached projects (from relationships)
+    pub fn project_ids(&self) -> Vec<&str> {
+        Self::ref_ids(
+            self.relationships
+                .as_ref()
+                .and_then(|r| r.projects.as_ref()),
+        )
+    }
+
+    fn ref_ids(rel: Option<&VariableSetRelationship>) -> Vec<&str> {
+        rel.map(|r| r.data.iter().map(|d| d.id.as_str()).collect())
+            .unwrap_or_default()
+    }
+}
+
+impl TfeResource for VariableSet {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        self.name()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_variable_set() {
+        let json = r#"{
+            "id": "varset-kjkN545LH2Sfercv",
+            "type": "varsets",
+            "attributes": {
+                "name": "aws-creds",
+                "description": "Shared AWS credentials",
+                "global": false,
+                "priority": false,
+                "workspace-count": 2,
+                "project-count": 1,
+                "var-count": 3
+            },
+            "relationships": {
+                "workspaces": {
+                    "data": [
+                        {"id": "ws-1", "type": "workspaces"},
+                        {"id": "ws-2", "type": "workspaces"}
+                    ]
+                },
+                "projects": {
+                    "data": [{"id": "prj-1", "type": "projects"}]
+                },
+                "vars": {
+                    "data": []
+                }
+            }
+        }"#;
+
+        let varset: VariableSet = serde_json::from_str(json).unwrap();
+        assert_eq!(varset.id, "varset-kjkN545LH2Sfercv");
+        assert_eq!(varset.name(), "aws-creds");
+        assert!(!varset.attributes.global);
+        assert_eq!(varset.workspace_count(), 2);
+        assert_eq!(varset.project_count(), 1);
+        assert_eq!(varset.workspace_ids(), vec!["ws-1", "ws-2"]);
+        assert_eq!(varset.project_ids(), vec!["prj-1"]);
+    }
+
+    #[test]
+    fn test_counts_fall_back_to_relationships() {
+        let json = r#"{
+            "id": "varset-abc",
+            "attributes": {"name": "global-tags", "global": true},
+            "relationships": {
+                "workspaces": {"data": [{"id": "ws-1", "type": "workspaces"}]}
+            }
+        }"#;
+
+        let varset: VariableSet = serde_json::from_str(json).unwrap();
+        assert!(varset.attributes.global);
+        assert_eq!(varset.workspace_count(), 1);
+        assert_eq!(varset.project_count(), 0);
+        assert!(varset.project_ids().is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+//! Workspace variable API operations
+
+use log::debug;
+
+use crate::config::api;
+use crate::error::{Result, TfeError};
+use crate::hcp::traits::ApiListResponse;
+use crate::hcp::TfeClient;
+
+use super::models::Variable;
+
+impl TfeClient {
+    /// Get all variables of a workspace (the endpoint is not paginated)
+    pub async fn get_workspace_vars(&self, workspace_id: &str) -> Result<Vec<Variable>> {
+        let url = format!(
+            "{}/{}/{}/{}",
+            self.base_url(),
+            api::WORKSPACES,
+            workspace_id,
+            api::VARS
+        );
+        debug!("Fetching workspace variables from: {}", url);
+
+        let response = self.send_get(&url).await?;
+
+        match response.status().as_u16() {
+            200 => {
+                let resp: ApiListResponse<Variable> = response.json().await?;
+                Ok(resp.data)
+            }
+            404 => Err(TfeError::Api {
+                status: 404,
+                message: format!("Workspace '{}' not found", workspace_id),
+                details: Vec::new(),
+            }),
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                Err(TfeError::api_response(
+                    status,
+                    format!("Failed to fetch variables for workspace '{}'", workspace_id),
+                    &body,
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_workspace_vars() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-abc123/vars"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {
+                        "id": "var-1",
+                        "type": "vars",
+                        "attributes": {
+                            "key": "region",
+                            "value": "eu-west-1",
+                            "category": "terraform",
+                            "hcl": false,
+                            "sensitive": false
+                        }
+                    },
+                    {
+                        "id": "var-2",
+                        "type": "vars",
+                        "attributes": {
+                            "key": "TF_LOG",
+                            "value": null,
+                            "category": "env",
+                            "sensitive": true
+                        }
+                    }
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let vars = client.get_workspace_vars("ws-abc123").await.unwrap();
+
+        assert_eq!(vars.len(), 2);
+        assert_eq!(vars[0].key(), "region");
+        assert_eq!(vars[1].category(), "env");
+        assert!(vars[1].is_sensitive());
+    }
+
+    #[tokio::test]
+    async fn test_get_workspace_vars_not_found() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-missing/vars"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let err = client.get_workspace_vars("ws-missing").await.unwrap_err();
+        assert!(err.to_string().contains("Workspace 'ws-missing' not found"));
+    }
+}
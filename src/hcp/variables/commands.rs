@@ -0,0 +1,79 @@
+//! Workspace variable command handlers
+
+use log::debug;
+
+use crate::hcp::explain::{call, print_explain, workspace_lookup};
+use crate::hcp::traits::TfeResource;
+use crate::hcp::workspaces::resolve_workspace;
+use crate::hcp::TfeClient;
+use crate::output::{apply_limit, output_variables, VariableRow};
+use crate::ui::{create_spinner, finish_spinner};
+use crate::{Cli, Command, GetResource};
+
+/// Run the workspace variable list command
+pub async fn run_var_command(
+    client: &TfeClient,
+    cli: &Cli,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Command::Get {
+        resource: GetResource::Var(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        let mut calls = workspace_lookup(&args.workspace, effective_org.as_ref());
+        calls.push(call("GET", "/workspaces/:ws_id/vars", "variables"));
+        print_explain(&calls);
+        return Ok(());
+    }
+    let resolved = resolve_workspace(
+        client,
+        &args.workspace,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
+    let ws_name = resolved.workspace.name().to_string();
+
+    let spinner = create_spinner(
+        &format!("Fetching variables for workspace '{}'...", ws_name),
+        cli.batch,
+    );
+    let vars = client.get_workspace_vars(&resolved.workspace.id).await;
+    finish_spinner(spinner);
+    let vars = vars?;
+
+    let category = args.category.map(|c| c.to_string());
+    let mut rows: Vec<VariableRow> = vars
+        .iter()
+        .filter(|v| category.as_deref().is_none_or(|c| v.category() == c))
+        .filter(|v| args.name.as_deref().is_none_or(|key| v.key() == key))
+        .map(VariableRow::from)
+        .collect();
+    debug!(
+        "Showing {} of {} variables for workspace '{}'",
+        rows.len(),
+        vars.len(),
+        ws_name
+    );
+
+    if rows.is_empty() {
+        return match &args.name {
+            Some(key) => {
+                Err(format!("Variable '{}' not found in workspace '{}'", key, ws_name).into())
+            }
+            None => {
+                eprintln!("No variables found in workspace '{}'", ws_name);
+                Ok(())
+            }
+        };
+    }
+
+    apply_limit(&mut rows, cli);
+    output_variables(&rows, cli);
+    Ok(())
+}
@@ -0,0 +1,8 @@
+//! Variables module - list workspace variables
+
+mod api;
+mod commands;
+mod models;
+
+pub use commands::run_var_command;
+pub use models::{Variable, VariableAttributes};
@@ -0,0 +1,106 @@
+//! Workspace variable data models
+
+use serde::{Deserialize, Serialize};
+
+use crate::hcp::traits::TfeResource;
+
+/// Workspace variable from TFE API
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Variable {
+    pub id: String,
+    pub attributes: VariableAttributes,
+}
+
+/// Workspace variable attributes from TFE API
+///
+/// `value` is always null for sensitive variables.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VariableAttributes {
+    pub key: String,
+    pub value: Option<String>,
+    pub description: Option<String>,
+    pub category: String,
+    #[serde(default)]
+    pub hcl: bool,
+    #[serde(default)]
+    pub sensitive: bool,
+}
+
+impl Variable {
+    /// Get variable key
+    pub fn key(&self) -> &str {
+        &self.attributes.key
+    }
+
+    /// Get variable category ("terraform" or "env")
+    pub fn category(&self) -> &str {
+        &self.attributes.category
+    }
+
+    /// Check if the variable is sensitive
+    pub fn is_sensitive(&self) -> bool {
+        self.attributes.sensitive
+    }
+}
+
+impl TfeResource for Variable {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        self.key()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_variable() {
+        let json = r#"{
+            "id": "var-EavQ1LztoRTQHSNT",
+            "type": "vars",
+            "attributes": {
+                "key": "image_id",
+                "value": "ami-0c55b159cbfafe1f0",
+                "description": "AMI for web servers",
+                "sensitive": false,
+                "category": "terraform",
+                "hcl": false,
+                "version-id": "1aa07d63ea8ff4df941c94ca9ac9c5c5"
+            }
+        }"#;
+
+        let var: Variable = serde_json::from_str(json).unwrap();
+        assert_eq!(var.id, "var-EavQ1LztoRTQHSNT");
+        assert_eq!(var.key(), "image_id");
+        assert_eq!(var.category(), "terraform");
+        assert_eq!(
+            var.attributes.value.as_deref(),
+            Some("ami-0c55b159cbfafe1f0")
+        );
+        assert!(!var.is_sensitive());
+    }
+
+    #[test]
+    fn test_deserialize_sensitive_variable() {
+        let json = r#"{
+            "id": "var-secret",
+            "attributes": {
+                "key": "AWS_SECRET_ACCESS_KEY",
+                "value": null,
+                "sensitive": true,
+                "category": "env"
+            }
+        }"#;
+
+        let var: Variable = serde_json::from_str(json).unwrap();
+        assert!(var.is_sensitive());
+        assert!(var.attributes.value.is_none());
+        assert!(var.attributes.description.is_none());
+        assert!(!var.attributes.hcl);
+        assert_eq!(TfeResource::name(&var), "AWS_SECRET_ACCESS_KEY");
+    }
+}
@@ -10,6 +10,7 @@ use log::debug;
 use tokio::time::sleep;
 
 use crate::cli::WatchWsArgs;
+use crate::hcp::explain::{call, print_explain, workspace_lookup, PlannedCall};
 use crate::hcp::runs::{print_log_with_prefix, Run};
 use crate::hcp::traits::TfeResource;
 use crate::hcp::workspaces::{extract_current_run_id, resolve_workspace};
@@ -24,10 +25,21 @@ pub async fn run_watch_ws_command(
     cli: &Cli,
     args: &WatchWsArgs,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // Resolve workspace using shared resolver
     let effective_org = client.effective_org(args.org.as_ref());
-    let resolved =
-        resolve_workspace(client, &args.target, effective_org.as_deref(), cli.batch).await?;
+    if cli.explain {
+        print_explain(&plan_watch_ws(args, effective_org.as_ref()));
+        return Ok(());
+    }
+
+    // Resolve workspace using shared resolver
+    let resolved = resolve_workspace(
+        client,
+        &args.target,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
 
     println!(
         "Watching workspace '{}' in organization '{}' ({})",
@@ -159,6 +171,25 @@ async fn watch_run_logs(
 
 // Log parsing functions moved to runs/log_utils.rs for reuse
 
+/// API calls made by `watch ws` for the effective organization
+fn plan_watch_ws(args: &WatchWsArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    let mut calls = workspace_lookup(&args.target, org);
+    calls.push(call(
+        "GET",
+        "/workspaces/:ws_id",
+        "polled every interval for a new current run",
+    ));
+    calls.push(call("GET", "/runs/:current_run_id", "per new current run"));
+    let phase = if args.apply { "apply" } else { "plan" };
+    calls.push(call(
+        "GET",
+        format!("/runs/:current_run_id/{}", phase),
+        "polled until finished",
+    ));
+    calls.push(call("GET", ":log-read-url", "logs of each new run"));
+    calls
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,8 +7,8 @@ use crate::config::api;
 use crate::error::{Result, TfeError};
 use crate::hcp::{PaginationInfo, TfeClient};
 
-use super::models::{Workspace, WorkspaceQuery};
-use crate::hcp::traits::ApiListResponse;
+use super::models::{Workspace, WorkspaceFilter, WorkspaceQuery};
+use crate::hcp::traits::{ApiListResponse, TfeResource};
 
 /// Build the API path for workspaces with optional query params
 fn build_workspaces_path(org: &str, query: &WorkspaceQuery<'_>) -> String {
@@ -51,18 +51,46 @@ impl TfeClient {
             org, query.search, query.project_id
         );
 
-        self.fetch_all_pages::<Workspace, ApiListResponse<Workspace>>(&path, &error_context)
+        self.fetch_pages::<Workspace, ApiListResponse<Workspace>>(&path, &error_context, query.page)
             .await
     }
 
+    /// List workspaces matching `filter`
+    ///
+    /// Without an organization in the filter, every accessible organization is
+    /// queried (in parallel). Results keep the API order within each
+    /// organization.
+    pub async fn list_workspaces(&self, filter: &WorkspaceFilter) -> Result<Vec<Workspace>> {
+        let orgs = match filter.org_name() {
+            Some(org) => vec![org.to_string()],
+            None => self.get_organizations().await?,
+        };
+
+        let results: Vec<Result<Vec<Workspace>>> = stream::iter(orgs.iter())
+            .map(|org| self.get_workspaces(org, filter.query()))
+            .buffered(self.concurrency())
+            .collect()
+            .await;
+
+        let mut workspaces = Vec::new();
+        for result in results {
+            workspaces.extend(result?.into_iter().filter(|ws| filter.matches(ws)));
+        }
+        Ok(workspaces)
+    }
+
     /// Prefetch pagination info for workspaces without fetching all data
     ///
     /// Use this to check the scale of an operation before committing to full fetch.
+    /// Returns `None` when `query` asks for a single page.
     pub async fn prefetch_workspaces_pagination_info(
         &self,
         org: &str,
         query: WorkspaceQuery<'_>,
     ) -> Result<Option<PaginationInfo>> {
+        if query.page.is_some() {
+            return Ok(None);
+        }
         let path = build_workspaces_path(org, &query);
         let error_context = format!("workspaces pagination info for organization '{}'", org);
 
@@ -73,6 +101,31 @@ impl TfeClient {
         .await
     }
 
+    /// Workspace names matching `partial` (server-side search, first page only)
+    pub async fn search_workspace_names(
+        &self,
+        org: &str,
+        partial: &str,
+        limit: usize,
+    ) -> Result<Vec<String>> {
+        let mut url = format!(
+            "{}/{}/{}/{}?page[size]={}",
+            self.base_url(),
+            api::ORGANIZATIONS,
+            org,
+            api::WORKSPACES,
+            limit
+        );
+        if !partial.is_empty() {
+            url.push_str(&format!("&search[name]={}", urlencoding::encode(partial)));
+        }
+        let response = self.send(self.get(&url)).await?;
+        let list: ApiListResponse<Workspace> = self
+            .parse_api_response(response, &format!("workspaces in '{}'", org))
+            .await?;
+        Ok(list.data.iter().map(|ws| ws.name().to_string()).collect())
+    }
+
     /// Get a single workspace by ID (direct API call, no org needed)
     /// Returns both the typed model and raw JSON for flexible output
     pub async fn get_workspace_by_id(
@@ -108,7 +161,7 @@ impl TfeClient {
         let full_url = format!("https://{}{}", self.host(), url);
         debug!("Fetching subresource: {}", full_url);
 
-        let response = self.get(&full_url).send().await?;
+        let response = self.send_get(&full_url).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -118,17 +171,19 @@ impl TfeClient {
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Subresource not found at '{}'", url),
+                details: Vec::new(),
             }),
             status => Err(TfeError::Api {
                 status,
                 message: format!("Failed to fetch subresource from '{}'", url),
+                details: Vec::new(),
             }),
         }
     }
 
     /// Fetch multiple workspaces by their IDs concurrently
     ///
-    /// Uses `buffer_unordered` with `MAX_CONCURRENT_PAGE_REQUESTS` for concurrency.
+    /// Uses `buffer_unordered` bounded by `--concurrency`.
     /// Skips 404s and errors (deleted workspaces) with debug warnings.
     /// Returns (Workspace, org_name) tuples.
     pub async fn fetch_workspaces_by_ids(
@@ -156,7 +211,7 @@ impl TfeClient {
                     }
                 }
             })
-            .buffer_unordered(api::MAX_CONCURRENT_PAGE_REQUESTS)
+            .buffer_unordered(self.concurrency())
             .collect()
             .await;
 
@@ -193,7 +248,7 @@ impl TfeClient {
                     }
                 }
             })
-            .buffer_unordered(api::MAX_CONCURRENT_PAGE_REQUESTS)
+            .buffer_unordered(self.concurrency())
             .collect()
             .await;
 
@@ -201,7 +256,9 @@ impl TfeClient {
     }
 
     /// Lock a workspace to prevent concurrent modifications
-    pub async fn lock_workspace(&self, workspace_id: &str) -> Result<()> {
+    ///
+    /// `reason` is shown to other users in the UI while the lock is held.
+    pub async fn lock_workspace(&self, workspace_id: &str, reason: Option<&str>) -> Result<()> {
         let url = format!(
             "{}/{}/{}/actions/lock",
             self.base_url(),
@@ -209,15 +266,20 @@ impl TfeClient {
             workspace_id
         );
 
-        debug!("Locking workspace: {}", workspace_id);
+        debug!("Locking workspace: {} (reason={:?})", workspace_id, reason);
 
-        let response = self.post(&url).send().await?;
+        let mut request = self.post(&url);
+        if let Some(reason) = reason {
+            request = request.json(&serde_json::json!({ "reason": reason }));
+        }
+        let response = self.send(request).await?;
 
         match response.status().as_u16() {
             200 => Ok(()),
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Workspace '{}' not found", workspace_id),
+                details: Vec::new(),
             }),
             409 => Err(TfeError::Api {
                 status: 409,
@@ -225,13 +287,15 @@ impl TfeClient {
                     "Workspace '{}' is already locked or has an active run",
                     workspace_id
                 ),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!("Failed to lock workspace '{}': {}", workspace_id, body),
-                })
+                    format!("Failed to lock workspace '{}'", workspace_id),
+                    &body,
+                ))
             }
         }
     }
@@ -247,13 +311,14 @@ impl TfeClient {
 
         debug!("Unlocking workspace: {}", workspace_id);
 
-        let response = self.post(&url).send().await?;
+        let response = self.send(self.post(&url)).await?;
 
         match response.status().as_u16() {
             200 => Ok(()),
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Workspace '{}' not found", workspace_id),
+                details: Vec::new(),
             }),
             409 => Err(TfeError::Api {
                 status: 409,
@@ -261,13 +326,15 @@ impl TfeClient {
                     "Workspace '{}' is not locked or locked by another user/run",
                     workspace_id
                 ),
+                details: Vec::new(),
             }),
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!("Failed to unlock workspace '{}': {}", workspace_id, body),
-                })
+                    format!("Failed to unlock workspace '{}'", workspace_id),
+                    &body,
+                ))
             }
         }
     }
@@ -277,7 +344,7 @@ impl TfeClient {
 mod tests {
     use super::*;
     use crate::hcp::traits::TfeResource;
-    use wiremock::matchers::{method, path, query_param};
+    use wiremock::matchers::{body_json, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn workspace_json(id: &str, name: &str) -> serde_json::Value {
@@ -322,6 +389,79 @@ mod tests {
         assert_eq!(workspaces[1].name(), "workspace-2");
     }
 
+    #[tokio::test]
+    async fn test_list_workspaces_applies_filter() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        let mut locked = workspace_json("ws-2", "prod-locked");
+        locked["attributes"]["locked"] = serde_json::json!(true);
+        let mut small = workspace_json("ws-3", "prod-small");
+        small["attributes"]["resource-count"] = serde_json::json!(2);
+        let response_body = serde_json::json!({
+            "data": [
+                workspace_json("ws-1", "prod-app"),
+                locked,
+                small,
+                // fuzzy server-side search can return non-substring matches
+                workspace_json("ws-4", "pr-od")
+            ]
+        });
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/workspaces"))
+            .and(query_param("search[name]", "prod"))
+            .and(query_param("filter[project][id]", "prj-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let filter = WorkspaceFilter::new()
+            .org("my-org")
+            .project("prj-1")
+            .name_contains("prod")
+            .locked(false)
+            .min_resources(5);
+        let workspaces = client.list_workspaces(&filter).await.unwrap();
+        let ids: Vec<_> = workspaces.iter().map(|w| w.id.as_str()).collect();
+        assert_eq!(ids, vec!["ws-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_workspaces_all_orgs() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"id": "org-a", "attributes": {"name": "org-a"}},
+                    {"id": "org-b", "attributes": {"name": "org-b"}}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+        for (org, ws) in [("org-a", "ws-a"), ("org-b", "ws-b")] {
+            Mock::given(method("GET"))
+                .and(path(format!("/organizations/{}/workspaces", org)))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(serde_json::json!({"data": [workspace_json(ws, ws)]})),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+
+        let workspaces = client
+            .list_workspaces(&WorkspaceFilter::new())
+            .await
+            .unwrap();
+        let ids: Vec<_> = workspaces.iter().map(|w| w.id.as_str()).collect();
+        assert_eq!(ids, vec!["ws-a", "ws-b"]);
+    }
+
     #[tokio::test]
     async fn test_get_workspaces_with_search() {
         let mock_server = MockServer::start().await;
@@ -427,7 +567,9 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         match err {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 403);
                 assert!(message.contains("my-org"));
             }
@@ -548,7 +690,25 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let result = client.lock_workspace("ws-123").await;
+        let result = client.lock_workspace("ws-123", None).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_lock_workspace_sends_reason() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/workspaces/ws-123/actions/lock"))
+            .and(body_json(serde_json::json!({"reason": "change freeze"})))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let result = client.lock_workspace("ws-123", Some("change freeze")).await;
 
         assert!(result.is_ok());
     }
@@ -564,7 +724,7 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let result = client.lock_workspace("ws-123").await;
+        let result = client.lock_workspace("ws-123", None).await;
 
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -582,7 +742,7 @@ mod tests {
             .await;
 
         let client = TfeClient::test_client(&mock_server.uri());
-        let result = client.lock_workspace("ws-notfound").await;
+        let result = client.lock_workspace("ws-notfound", None).await;
 
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -857,4 +1017,29 @@ mod tests {
         assert!(names.contains(&"good-ws"));
         assert!(names.contains(&"another-ws"));
     }
+
+    #[tokio::test]
+    async fn test_search_workspace_names() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/workspaces"))
+            .and(query_param("search[name]", "app"))
+            .and(query_param("page[size]", "50"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"id": "ws-1", "attributes": {"name": "app-web"}},
+                    {"id": "ws-2", "attributes": {"name": "my-app"}}
+                ]
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = TfeClient::test_client(&mock_server.uri());
+        let names = client
+            .search_workspace_names("my-org", "app", 50)
+            .await
+            .unwrap();
+        assert_eq!(names, vec!["app-web", "my-app"]);
+    }
 }
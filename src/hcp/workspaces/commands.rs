@@ -4,18 +4,27 @@ use std::collections::{BTreeMap, HashMap};
 
 use log::debug;
 
-use crate::cli::{OutputFormat, WsSortField, WsSubresource};
+use crate::cli::{OutputFormat, WsArgs, WsSortField, WsSubresource};
+use crate::hcp::explain::{
+    call, org_path, org_scope, print_explain, workspace_lookup, PlannedCall,
+};
 use crate::hcp::helpers::{
     aggregate_pagination_info, collect_org_results, fetch_from_organizations, log_completion,
+    OrgErrors,
 };
 use crate::hcp::organizations::resolve_organizations;
 use crate::hcp::projects::resolve_project;
 use crate::hcp::runs::{count_runs_by_workspace, RunQuery};
-use crate::hcp::workspaces::WorkspaceQuery;
+use crate::hcp::traits::TfeResource;
+use crate::hcp::workspaces::{
+    find_workspace_candidates, report_search_errors, select_workspace_candidate, ResolvedWorkspace,
+    WorkspaceQuery,
+};
 use crate::hcp::TfeClient;
 use crate::output::{
-    output_raw, output_results_sorted, output_workspace_resource_summary, InstanceResourceSummary,
-    OrgResourceSummaryRow, WorkspaceResourceSummary,
+    output_raw_flattened, output_results_diff, output_results_sorted,
+    output_workspace_resource_summary, parse_ws_columns, print_text, InstanceResourceSummary,
+    OrgResourceSummaryRow, WorkspaceResourceSummary, WorkspaceRow,
 };
 use crate::ui::{
     confirm_large_pagination, create_spinner, finish_spinner, finish_spinner_with_status,
@@ -70,11 +79,23 @@ pub async fn run_ws_command(
     }
 
     // Validate: --sort pending-runs requires --has-pending-runs
-    if args.sort == WsSortField::PendingRuns && !args.has_pending_runs {
+    if args.sort.contains(&WsSortField::PendingRuns) && !args.has_pending_runs {
         return Err("--sort pending-runs requires --has-pending-runs".into());
     }
 
+    // Validate: --columns tokens (fail before any API calls)
+    if let Some(spec) = &args.columns {
+        parse_ws_columns(spec)?;
+    }
+
+    // Load the --diff baseline before any API calls so a bad file fails fast
+    let baseline = args.diff.as_deref().map(load_baseline).transpose()?;
+
     let effective_org = client.effective_org(args.org.as_ref());
+    if cli.explain {
+        print_explain(&plan_get_ws(args, effective_org.as_ref()));
+        return Ok(());
+    }
 
     // If NAME is specified, get single workspace
     if let Some(name) = &args.name {
@@ -120,20 +141,25 @@ pub async fn run_ws_command(
         cli.batch,
     );
 
-    let pagination_results = fetch_from_organizations(organizations.clone(), |org| async move {
-        let query = WorkspaceQuery {
-            search: filter,
-            project_id: project_id_ref,
-            ..Default::default()
-        };
-        match client
-            .prefetch_workspaces_pagination_info(&org, query)
-            .await
-        {
-            Ok(info) => Ok(info),
-            Err(e) => Err((org, e)),
-        }
-    })
+    let pagination_results = fetch_from_organizations(
+        organizations.clone(),
+        client.concurrency(),
+        |org| async move {
+            let query = WorkspaceQuery {
+                search: filter,
+                project_id: project_id_ref,
+                page: cli.page_request(),
+                ..Default::default()
+            };
+            match client
+                .prefetch_workspaces_pagination_info(&org, query)
+                .await
+            {
+                Ok(info) => Ok(info),
+                Err(e) => Err((org, e)),
+            }
+        },
+    )
     .await;
 
     // Collect pagination info (ignoring errors - they'll be caught in main fetch)
@@ -150,7 +176,9 @@ pub async fn run_ws_command(
     if aggregated.total_count > 0 {
         let info = LargePaginationInfo::from_aggregated(&aggregated, "workspaces");
 
-        if info.exceeds_threshold() && !confirm_large_pagination(&info, cli.batch) {
+        if info.exceeds_threshold()
+            && !confirm_large_pagination(&info, cli.batch, cli.prompt_timeout).await
+        {
             return Err(Box::new(TfeError::UserCancelled));
         }
     }
@@ -164,13 +192,15 @@ pub async fn run_ws_command(
         cli.batch,
     );
 
-    let results = fetch_from_organizations(organizations, |org| async move {
+    let progress_client = &client.with_progress(spinner.as_ref());
+    let results = fetch_from_organizations(organizations, client.concurrency(), |org| async move {
         let query = WorkspaceQuery {
             search: filter,
             project_id: project_id_ref,
+            page: cli.page_request(),
             ..Default::default()
         };
-        let workspaces = client.get_workspaces(&org, query).await;
+        let workspaces = progress_client.get_workspaces(&org, query).await;
 
         match workspaces {
             Ok(ws) => {
@@ -185,12 +215,18 @@ pub async fn run_ws_command(
     })
     .await;
 
-    let (all_workspaces, had_errors): (Vec<(String, Vec<Workspace>)>, bool) =
-        collect_org_results(results, &spinner, "workspaces");
+    let (all_workspaces, org_errors): (Vec<(String, Vec<Workspace>)>, OrgErrors) =
+        collect_org_results(results, "workspaces");
 
-    finish_spinner_with_status(spinner, &all_workspaces, had_errors);
+    finish_spinner_with_status(spinner, &all_workspaces, org_errors.has_errors());
+    org_errors.check_strict(cli.strict, "workspaces")?;
 
-    if args.resources_summary {
+    let all_workspaces = filter_by_name(all_workspaces, filter);
+    let all_workspaces = filter_by_state(all_workspaces, args.state.as_deref());
+
+    if let Some(baseline) = &baseline {
+        output_results_diff(all_workspaces, baseline, cli);
+    } else if args.resources_summary {
         let summary = build_resource_summary(&all_workspaces);
         output_workspace_resource_summary(&summary, &args.output, cli.no_header);
     } else if !all_workspaces.is_empty() {
@@ -212,13 +248,141 @@ pub async fn run_ws_command(
         } else {
             None
         };
-        output_results_sorted(all_workspaces, cli, None, billable_counts.as_ref());
+        let project_names = if args.with_prj {
+            let orgs: Vec<String> = all_workspaces.iter().map(|(org, _)| org.clone()).collect();
+            Some(fetch_project_names(client, orgs, cli.batch).await)
+        } else {
+            None
+        };
+        output_results_sorted(
+            all_workspaces,
+            cli,
+            None,
+            billable_counts.as_ref(),
+            project_names.as_ref(),
+        );
     }
 
-    log_completion(had_errors);
+    org_errors.print_report("workspaces");
+    log_completion(org_errors.has_errors());
     Ok(())
 }
 
+/// Read a `--diff` baseline: a JSON export from `get ws -o json`
+fn load_baseline(path: &std::path::Path) -> Result<Vec<WorkspaceRow>, Box<dyn std::error::Error>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read baseline '{}': {}", path.display(), e))?;
+    WorkspaceRow::from_snapshot(&content).map_err(|e| {
+        format!(
+            "Baseline '{}' is not a `get ws -o json` export: {}",
+            path.display(),
+            e
+        )
+        .into()
+    })
+}
+
+/// Build a project ID → name map for the given organizations (one paginated
+/// project listing per org, in parallel). Orgs that fail are skipped with a
+/// warning; their workspaces get an empty project name.
+async fn fetch_project_names(
+    client: &TfeClient,
+    organizations: Vec<String>,
+    batch: bool,
+) -> HashMap<String, String> {
+    let spinner = create_spinner(
+        &format!(
+            "Resolving project names in {} organization(s)...",
+            organizations.len()
+        ),
+        batch,
+    );
+    let progress_client = &client.with_progress(spinner.as_ref());
+    let results = fetch_from_organizations(organizations, client.concurrency(), |org| async move {
+        match progress_client.get_projects(&org, None, None).await {
+            Ok(projects) => Ok(projects),
+            Err(e) => Err((org, e)),
+        }
+    })
+    .await;
+    finish_spinner(spinner);
+
+    let mut names = HashMap::new();
+    for result in results {
+        match result {
+            Ok(projects) => {
+                names.extend(projects.into_iter().map(|p| {
+                    let name = p.name().to_string();
+                    (p.id, name)
+                }));
+            }
+            Err((org, e)) => {
+                eprintln!(
+                    "Warning: could not resolve project names for '{}': {}",
+                    org, e
+                );
+            }
+        }
+    }
+    names
+}
+
+/// Second pass for `--filter` after the server-side `search[name]`.
+///
+/// The API search is fuzzy, so its matches are re-checked with the same
+/// substring match used everywhere else; it only narrows what gets paged in.
+fn filter_by_name(
+    workspaces: Vec<(String, Vec<Workspace>)>,
+    filter: Option<&str>,
+) -> Vec<(String, Vec<Workspace>)> {
+    let Some(filter) = filter else {
+        return workspaces;
+    };
+    workspaces
+        .into_iter()
+        .map(|(org, wss)| {
+            let wss = wss.into_iter().filter(|ws| ws.matches_filter(filter));
+            (org, wss.collect())
+        })
+        .collect()
+}
+
+/// Keep only workspaces in the requested lifecycle state.
+///
+/// Older TFE versions do not report a workspace `state` attribute; when none of
+/// the fetched workspaces carry it, the filter is ignored with a warning rather
+/// than hiding everything.
+fn filter_by_state(
+    workspaces: Vec<(String, Vec<Workspace>)>,
+    state: Option<&str>,
+) -> Vec<(String, Vec<Workspace>)> {
+    let Some(state) = state else {
+        return workspaces;
+    };
+
+    let reported = workspaces
+        .iter()
+        .any(|(_, wss)| wss.iter().any(|ws| ws.lifecycle_state().is_some()));
+    if !reported {
+        eprintln!(
+            "Warning: --state ignored, this TFE version does not report workspace lifecycle state"
+        );
+        return workspaces;
+    }
+
+    workspaces
+        .into_iter()
+        .map(|(org, wss)| {
+            let wss: Vec<Workspace> = wss
+                .into_iter()
+                .filter(|ws| ws.matches_state(state))
+                .collect();
+            (org, wss)
+        })
+        .filter(|(_, wss)| !wss.is_empty())
+        .collect()
+}
+
 /// Aggregate workspace data into a resource summary grouped by organization
 fn build_resource_summary(
     org_workspaces: &[(String, Vec<crate::hcp::Workspace>)],
@@ -298,45 +462,37 @@ async fn run_ws_pending_optimized(
         cli.batch,
     );
 
-    let pending_results = fetch_from_organizations(organizations, |org| async move {
-        match client
-            .get_runs_for_organization(&org, RunQuery::pending(), None)
-            .await
-        {
-            Ok(runs) => Ok(runs),
-            Err(e) => Err((org, e)),
-        }
-    })
-    .await;
+    let pending_results =
+        fetch_from_organizations(organizations, client.concurrency(), |org| async move {
+            match client
+                .get_runs_for_organization(&org, RunQuery::pending(), None)
+                .await
+            {
+                Ok(runs) => Ok(runs),
+                Err(e) => Err((org, e)),
+            }
+        })
+        .await;
 
     // Build counts map
-    let mut had_errors = false;
+    let (pending_runs, org_errors) = collect_org_results(pending_results, "pending runs");
     let mut counts: HashMap<String, usize> = HashMap::new();
-    for result in pending_results {
-        match result {
-            Ok(runs) => {
-                for (ws_id, count) in count_runs_by_workspace(&runs) {
-                    *counts.entry(ws_id).or_insert(0) += count;
-                }
-            }
-            Err((org, e)) => {
-                had_errors = true;
-                eprintln!(
-                    "Warning: failed to fetch pending runs for org '{}': {}",
-                    org, e
-                );
-            }
+    for runs in pending_runs {
+        for (ws_id, count) in count_runs_by_workspace(&runs) {
+            *counts.entry(ws_id).or_insert(0) += count;
         }
     }
 
     finish_spinner(pending_spinner);
+    org_errors.check_strict(cli.strict, "pending runs")?;
 
     // Step 2: Extract unique workspace IDs
     let workspace_ids: Vec<String> = counts.keys().cloned().collect();
 
     if workspace_ids.is_empty() {
-        println!("No workspaces with pending runs found.");
-        log_completion(had_errors);
+        print_text("No workspaces with pending runs found.\n");
+        org_errors.print_report("pending runs");
+        log_completion(org_errors.has_errors());
         return Ok(());
     }
 
@@ -381,8 +537,9 @@ async fn run_ws_pending_optimized(
         .collect();
 
     if filtered.is_empty() {
-        println!("No workspaces with pending runs found.");
-        log_completion(had_errors);
+        print_text("No workspaces with pending runs found.\n");
+        org_errors.print_report("pending runs");
+        log_completion(org_errors.has_errors());
         return Ok(());
     }
 
@@ -392,10 +549,19 @@ async fn run_ws_pending_optimized(
         grouped.entry(org_name).or_default().push(ws);
     }
     let grouped: Vec<(String, Vec<Workspace>)> = grouped.into_iter().collect();
+    let grouped = filter_by_state(grouped, args.state.as_deref());
+
+    let project_names = if args.with_prj {
+        let orgs: Vec<String> = grouped.iter().map(|(org, _)| org.clone()).collect();
+        Some(fetch_project_names(client, orgs, cli.batch).await)
+    } else {
+        None
+    };
 
-    output_results_sorted(grouped, cli, Some(&counts), None);
+    output_results_sorted(grouped, cli, Some(&counts), None, project_names.as_ref());
 
-    log_completion(had_errors);
+    org_errors.print_report("pending runs");
+    log_completion(org_errors.has_errors());
     Ok(())
 }
 
@@ -414,10 +580,15 @@ async fn get_single_workspace(
     };
 
     // Validate subresource usage
-    if args.subresource.is_some() && !matches!(args.output, OutputFormat::Json | OutputFormat::Yaml)
+    if args.subresource.is_some()
+        && !matches!(
+            args.output,
+            OutputFormat::Json | OutputFormat::Yaml | OutputFormat::Ndjson | OutputFormat::Template
+        )
     {
         return Err(
-            "--subresource requires JSON or YAML output format (-o json or -o yaml)".into(),
+            "--subresource requires a structured output format (-o json, yaml, ndjson or template)"
+                .into(),
         );
     }
 
@@ -445,8 +616,14 @@ async fn get_single_workspace(
                 }
 
                 // For JSON/YAML, return raw API response
-                if matches!(args.output, OutputFormat::Json | OutputFormat::Yaml) {
-                    output_raw(&raw, &args.output);
+                if matches!(
+                    args.output,
+                    OutputFormat::Json
+                        | OutputFormat::Yaml
+                        | OutputFormat::Ndjson
+                        | OutputFormat::Template
+                ) {
+                    output_raw_flattened(&raw, &args.output, args.flatten);
                     return Ok(());
                 }
 
@@ -477,12 +654,19 @@ async fn get_single_workspace(
                     None
                 };
 
+                let project_names = if args.with_prj {
+                    Some(fetch_project_names(client, vec![org_name.clone()], cli.batch).await)
+                } else {
+                    None
+                };
+
                 let all_workspaces = vec![(org_name, vec![workspace])];
                 output_results_sorted(
                     all_workspaces,
                     cli,
                     pending_counts.as_ref(),
                     billable_counts.as_ref(),
+                    project_names.as_ref(),
                 );
                 return Ok(());
             }
@@ -509,30 +693,26 @@ async fn get_single_workspace(
         cli.batch,
     );
 
-    // Search in all organizations IN PARALLEL with early termination
-    let name_owned = name.to_string();
-    let found = crate::hcp::helpers::search_first_in_orgs(&organizations, |org| {
-        let ws_name = name_owned.clone();
-        async move {
-            match client.get_workspace_by_name(&org, &ws_name).await {
-                Ok(Some(result)) => (org, Some(result)),
-                _ => (org, None),
-            }
-        }
-    })
-    .await;
-
-    if let Some((org_name, (_workspace, raw))) = found {
-        finish_spinner(spinner);
+    // Search in all organizations IN PARALLEL, prompting if the name is ambiguous
+    let (candidates, org_errors) = find_workspace_candidates(client, name, &organizations).await;
+    finish_spinner(spinner);
+    report_search_errors(&org_errors, cli.strict)?;
 
+    if let Some(ResolvedWorkspace {
+        raw, org: org_name, ..
+    }) = select_workspace_candidate(client, name, candidates, cli.batch).await?
+    {
         // Handle subresource if requested
         if let Some(subresource) = &args.subresource {
             return fetch_and_output_subresource(client, cli, &raw, subresource).await;
         }
 
         // For JSON/YAML, return raw API response
-        if matches!(args.output, OutputFormat::Json | OutputFormat::Yaml) {
-            output_raw(&raw, &args.output);
+        if matches!(
+            args.output,
+            OutputFormat::Json | OutputFormat::Yaml | OutputFormat::Ndjson | OutputFormat::Template
+        ) {
+            output_raw_flattened(&raw, &args.output, args.flatten);
             return Ok(());
         }
 
@@ -555,17 +735,23 @@ async fn get_single_workspace(
             None
         };
 
+        let project_names = if args.with_prj {
+            Some(fetch_project_names(client, vec![org_name.clone()], cli.batch).await)
+        } else {
+            None
+        };
+
         let all_workspaces = vec![(org_name, vec![workspace])];
         output_results_sorted(
             all_workspaces,
             cli,
             pending_counts.as_ref(),
             billable_counts.as_ref(),
+            project_names.as_ref(),
         );
         return Ok(());
     }
 
-    finish_spinner(spinner);
     Err(crate::hcp::helpers::not_found_in_orgs_error("Workspace", name, &organizations).into())
 }
 
@@ -588,20 +774,12 @@ async fn get_workspace_runs(
         name.to_string()
     } else {
         let organizations = resolve_organizations(client, org).await?;
-        let name_owned = name.to_string();
-        let found = crate::hcp::helpers::search_first_in_orgs(&organizations, |org| {
-            let ws_name = name_owned.clone();
-            async move {
-                match client.get_workspace_by_name(&org, &ws_name).await {
-                    Ok(Some(result)) => (org, Some(result)),
-                    _ => (org, None),
-                }
-            }
-        })
-        .await;
+        let (candidates, org_errors) =
+            find_workspace_candidates(client, name, &organizations).await;
+        report_search_errors(&org_errors, cli.strict)?;
 
-        match found {
-            Some((_org_name, (workspace, _raw))) => workspace.id,
+        match select_workspace_candidate(client, name, candidates, cli.batch).await? {
+            Some(resolved) => resolved.workspace.id,
             None => {
                 return Err(crate::hcp::helpers::not_found_in_orgs_error(
                     "Workspace",
@@ -621,6 +799,7 @@ async fn get_workspace_runs(
     let query = RunQuery {
         page_size: if args.all_runs { None } else { Some(24) },
         include_all_operations: true,
+        requested_page: cli.page_request(),
         ..Default::default()
     };
 
@@ -633,7 +812,7 @@ async fn get_workspace_runs(
     finish_spinner(spinner);
 
     if runs.is_empty() {
-        println!("\nNo runs found for workspace '{}'", name);
+        print_text(&format!("\nNo runs found for workspace '{}'\n", name));
         return Ok(());
     }
 
@@ -681,20 +860,12 @@ async fn get_workspace_states(
     } else {
         // Search orgs to find workspace
         let organizations = resolve_organizations(client, None).await?;
-        let name_owned = name.to_string();
-        let found = crate::hcp::helpers::search_first_in_orgs(&organizations, |org| {
-            let ws_name = name_owned.clone();
-            async move {
-                match client.get_workspace_by_name(&org, &ws_name).await {
-                    Ok(Some(result)) => (org, Some(result)),
-                    _ => (org, None),
-                }
-            }
-        })
-        .await;
+        let (candidates, org_errors) =
+            find_workspace_candidates(client, name, &organizations).await;
+        report_search_errors(&org_errors, cli.strict)?;
 
-        match found {
-            Some((org_name, (_ws, _raw))) => (org_name, name.to_string()),
+        match select_workspace_candidate(client, name, candidates, cli.batch).await? {
+            Some(resolved) => (resolved.org, name.to_string()),
             None => {
                 return Err(crate::hcp::helpers::not_found_in_orgs_error(
                     "Workspace",
@@ -715,13 +886,23 @@ async fn get_workspace_states(
     );
 
     let mut states = client
-        .get_state_versions_for_workspace(&effective_org, &effective_ws_name, 24, args.all_states)
+        .with_progress(spinner.as_ref())
+        .get_state_versions_for_workspace(
+            &effective_org,
+            &effective_ws_name,
+            24,
+            args.all_states,
+            cli.page_request(),
+        )
         .await?;
 
     finish_spinner(spinner);
 
     if states.is_empty() {
-        println!("\nNo state versions found for workspace '{}'", name);
+        print_text(&format!(
+            "\nNo state versions found for workspace '{}'\n",
+            name
+        ));
         return Ok(());
     }
 
@@ -729,21 +910,7 @@ async fn get_workspace_states(
     states.sort_by_key(|s| s.attributes.serial);
 
     // Compute resource count deltas between consecutive state versions
-    let deltas: Vec<Option<i64>> = states
-        .iter()
-        .enumerate()
-        .map(|(i, state)| {
-            if i == 0 {
-                return None;
-            }
-            let current = state.resource_count();
-            let prev = states[i - 1].resource_count();
-            match (current, prev) {
-                (Some(c), Some(p)) => Some(c as i64 - p as i64),
-                _ => None,
-            }
-        })
-        .collect();
+    let deltas = crate::hcp::state::resource_deltas(&states);
 
     crate::output::output_state_versions(&states, &deltas, &args.output, cli.no_header);
     Ok(())
@@ -766,7 +933,10 @@ async fn fetch_pending_counts_for_workspace(
         .await?;
     let counts = count_runs_by_workspace(&runs);
     if !counts.contains_key(ws_id) {
-        println!("\nNo pending runs found for workspace '{}'", ws_name);
+        print_text(&format!(
+            "\nNo pending runs found for workspace '{}'\n",
+            ws_name
+        ));
         return Ok(None);
     }
     Ok(Some(counts))
@@ -809,7 +979,7 @@ async fn fetch_and_output_subresource(
     match client.get_subresource(url).await {
         Ok(raw) => {
             finish_spinner(spinner);
-            output_raw(&raw, &args.output);
+            output_raw_flattened(&raw, &args.output, args.flatten);
             Ok(())
         }
         Err(e) => {
@@ -819,26 +989,266 @@ async fn fetch_and_output_subresource(
     }
 }
 
+/// API calls made by `get ws` for the effective organization
+fn plan_get_ws(args: &WsArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    if let Some(name) = &args.name {
+        let mut calls = workspace_lookup(name, org);
+        if args.runs {
+            calls.push(call("GET", "/workspaces/:ws_id/runs", "run history"));
+        }
+        if args.states {
+            calls.push(call(
+                "GET",
+                "/state-versions?filter[organization][name]=:org&filter[workspace][name]=:ws",
+                "state version history",
+            ));
+        }
+        if let Some(sub) = &args.subresource {
+            let rel = match sub {
+                WsSubresource::Run => "current-run",
+                WsSubresource::State => "current-state-version",
+                WsSubresource::Config => "current-configuration-version",
+                WsSubresource::Assessment => "current-assessment-result",
+            };
+            calls.push(call(
+                "GET",
+                format!("/workspaces/:ws_id/{}", rel),
+                "subresource (relationship link)",
+            ));
+        }
+        if args.has_pending_runs {
+            calls.push(call(
+                "GET",
+                "/workspaces/:ws_id/runs?filter[status]=pending",
+                "pending runs",
+            ));
+        }
+        return calls;
+    }
+
+    let mut calls = org_scope(org);
+    if args.prj.is_some() {
+        calls.push(call(
+            "GET",
+            format!("/organizations/{}/projects", org_path(org)),
+            "resolve project filter",
+        ));
+    }
+    if args.has_pending_runs {
+        calls.push(call(
+            "GET",
+            format!(
+                "/organizations/{}/runs?filter[status]=pending",
+                org_path(org)
+            ),
+            "pending runs per organization",
+        ));
+        calls.push(call(
+            "GET",
+            "/workspaces/:ws_id",
+            "per workspace with pending runs",
+        ));
+        return calls;
+    }
+    let list_path = match &args.filter {
+        Some(f) => format!(
+            "/organizations/{}/workspaces?search[name]={}",
+            org_path(org),
+            urlencoding::encode(f)
+        ),
+        None => format!("/organizations/{}/workspaces", org_path(org)),
+    };
+    let sep = if args.filter.is_some() { '&' } else { '?' };
+    calls.push(call(
+        "GET",
+        format!("{}{}page[number]=1", list_path, sep),
+        "first page to check result size",
+    ));
+    calls.push(call("GET", list_path, "remaining pages"));
+    if args.billable {
+        calls.push(call(
+            "GET",
+            "/workspaces/:ws_id/current-state-version",
+            "per workspace (billable count)",
+        ));
+    }
+    if args.with_prj {
+        calls.push(call(
+            "GET",
+            format!("/organizations/{}/projects", org_path(org)),
+            "paginated, project names for --with-prj",
+        ));
+    }
+    calls
+}
+
 #[cfg(test)]
 mod tests {
-    use super::build_resource_summary;
+    use super::{
+        build_resource_summary, filter_by_name, filter_by_state, load_baseline, plan_get_ws,
+        run_ws_command,
+    };
     use crate::hcp::workspaces::{Workspace, WorkspaceAttributes};
+    use crate::hcp::PlannedCall;
+    use crate::hcp::TfeClient;
+    use crate::{Cli, Command, GetResource};
+    use clap::Parser;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Two orgs where `broken-org` fails listing workspaces
+    async fn partial_failure_server() -> MockServer {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"id": "good-org", "attributes": {"name": "good-org"}},
+                    {"id": "broken-org", "attributes": {"name": "broken-org"}}
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/good-org/workspaces"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"id": "ws-1", "type": "workspaces", "attributes": {"name": "app"}}],
+                "meta": {"pagination": {"current-page": 1, "total-pages": 1, "total-count": 1}}
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/broken-org/workspaces"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+        server
+    }
+
+    #[tokio::test]
+    async fn test_ws_list_partial_org_failure_does_not_abort() {
+        let server = partial_failure_server().await;
+        let mut client = TfeClient::test_client(&server.uri());
+        client.set_concurrency(1);
+        let cli = Cli::parse_from(["hcp", "--batch", "get", "ws", "-o", "json"]);
+
+        // The failure is reported on stderr after the partial results
+        super::run_ws_command(&client, &cli).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ws_list_partial_org_failure_strict() {
+        let server = partial_failure_server().await;
+        let client = TfeClient::test_client(&server.uri());
+        let cli = Cli::parse_from(["hcp", "--batch", "--strict", "get", "ws"]);
+
+        let err = super::run_ws_command(&client, &cli)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("1 of 2 organization(s) failed while fetching workspaces"),
+            "{}",
+            err
+        );
+        assert!(err.contains("broken-org"), "{}", err);
+    }
 
     fn ws(resource_count: Option<u32>) -> Workspace {
         Workspace {
             id: "ws-test".to_string(),
             attributes: WorkspaceAttributes {
                 name: "test-ws".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: None,
                 resource_count,
                 locked: None,
                 terraform_version: None,
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         }
     }
 
+    fn ws_with_state(name: &str, state: Option<&str>) -> Workspace {
+        let mut w = ws(None);
+        w.attributes.name = name.to_string();
+        w.attributes.state = state.map(String::from);
+        w
+    }
+
+    #[test]
+    fn test_filter_by_state_keeps_matching_case_insensitive() {
+        let data = vec![
+            (
+                "org-a".to_string(),
+                vec![
+                    ws_with_state("a", Some("active")),
+                    ws_with_state("b", Some("archived")),
+                ],
+            ),
+            (
+                "org-b".to_string(),
+                vec![ws_with_state("c", Some("archived"))],
+            ),
+        ];
+        let filtered = filter_by_state(data, Some("ACTIVE"));
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].0, "org-a");
+        assert_eq!(filtered[0].1.len(), 1);
+        assert_eq!(filtered[0].1[0].attributes.name, "a");
+    }
+
+    #[test]
+    fn test_filter_by_name_rechecks_fuzzy_search_results() {
+        // search[name]=app-prod may also return e.g. "app-production-old"
+        // or "prod-app"; only true substring matches survive
+        let data = vec![(
+            "org-a".to_string(),
+            vec![
+                ws_with_state("app-prod", None),
+                ws_with_state("app-prod-eu", None),
+                ws_with_state("prod-app", None),
+            ],
+        )];
+        let filtered = filter_by_name(data, Some("app-prod"));
+        let names: Vec<_> = filtered[0]
+            .1
+            .iter()
+            .map(|w| w.attributes.name.as_str())
+            .collect();
+        assert_eq!(names, ["app-prod", "app-prod-eu"]);
+    }
+
+    #[test]
+    fn test_filter_by_name_none_is_noop() {
+        let data = vec![("org-a".to_string(), vec![ws_with_state("a", None)])];
+        assert_eq!(filter_by_name(data, None)[0].1.len(), 1);
+    }
+
+    #[test]
+    fn test_filter_by_state_ignored_when_attribute_absent() {
+        let data = vec![(
+            "org-a".to_string(),
+            vec![ws_with_state("a", None), ws_with_state("b", None)],
+        )];
+        let filtered = filter_by_state(data, Some("archived"));
+        assert_eq!(filtered[0].1.len(), 2);
+    }
+
+    #[test]
+    fn test_filter_by_state_none_is_noop() {
+        let data = vec![(
+            "org-a".to_string(),
+            vec![ws_with_state("a", Some("archived"))],
+        )];
+        assert_eq!(filter_by_state(data, None)[0].1.len(), 1);
+    }
+
     #[test]
     fn test_build_resource_summary_empty_input() {
         let summary = build_resource_summary(&[]);
@@ -890,4 +1300,114 @@ mod tests {
         assert_eq!(summary.organizations[0].workspace_count, 2);
         assert_eq!(summary.organizations[0].resource_count, 30);
     }
+
+    #[test]
+    fn test_load_baseline_reads_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        std::fs::write(
+            &path,
+            r#"[{"org":"acme","project_id":"prj-1","workspace_name":"net","workspace_id":"ws-1",
+                "resources":3,"execution_mode":"remote","locked":false,
+                "terraform_version":"1.5.0","updated_at":"2024-01-01T00:00:00Z"}]"#,
+        )
+        .unwrap();
+
+        let rows = load_baseline(&path).unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].id, "ws-1");
+        assert_eq!(rows[0].resources, 3);
+    }
+
+    #[test]
+    fn test_load_baseline_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = load_baseline(&dir.path().join("missing.json")).unwrap_err();
+        assert!(missing.to_string().contains("Failed to read baseline"));
+
+        let path = dir.path().join("runs.json");
+        std::fs::write(&path, r#"[{"id":"run-1"}]"#).unwrap();
+        let invalid = load_baseline(&path).unwrap_err();
+        assert!(invalid
+            .to_string()
+            .contains("is not a `get ws -o json` export"));
+    }
+
+    fn plan(argv: &[&str], org: Option<&str>) -> Vec<PlannedCall> {
+        let cli = Cli::parse_from(argv);
+        let Command::Get {
+            resource: GetResource::Ws(args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        plan_get_ws(args, org.map(str::to_string).as_ref())
+    }
+
+    #[test]
+    fn test_plan_get_ws_list_with_org() {
+        let calls = plan(&["hcp", "get", "ws", "--billable"], Some("acme"));
+        assert!(calls.iter().all(|c| c.method == "GET"));
+        assert!(calls
+            .iter()
+            .any(|c| c.path == "/organizations/acme/workspaces"));
+        assert!(calls
+            .iter()
+            .any(|c| c.path == "/workspaces/:ws_id/current-state-version"));
+        assert!(!calls.iter().any(|c| c.path == "/organizations"));
+    }
+
+    #[test]
+    fn test_plan_get_ws_without_org_lists_orgs_first() {
+        let calls = plan(&["hcp", "get", "ws"], None);
+        assert_eq!(calls[0].path, "/organizations");
+        assert!(calls
+            .iter()
+            .any(|c| c.path == "/organizations/:org/workspaces"));
+    }
+
+    #[test]
+    fn test_plan_get_ws_filter_searches_server_side() {
+        let calls = plan(&["hcp", "get", "ws", "-f", "app prod"], Some("acme"));
+        assert_eq!(
+            calls[0].path,
+            "/organizations/acme/workspaces?search[name]=app%20prod&page[number]=1"
+        );
+        assert_eq!(
+            calls[1].path,
+            "/organizations/acme/workspaces?search[name]=app%20prod"
+        );
+    }
+
+    #[test]
+    fn test_plan_get_ws_single_by_id() {
+        let calls = plan(
+            &["hcp", "get", "ws", "ws-abc", "--subresource", "run"],
+            None,
+        );
+        assert_eq!(calls[0].path, "/workspaces/ws-abc");
+        assert_eq!(calls[1].path, "/workspaces/:ws_id/current-run");
+    }
+
+    #[tokio::test]
+    async fn test_explain_uses_context_org_without_api_calls() {
+        let mock_server = MockServer::start().await;
+        let mut client = TfeClient::test_client(&mock_server.uri());
+        client.set_context_org(Some("ctx-org".to_string()));
+        let cli = Cli::parse_from(["hcp", "--explain", "get", "ws"]);
+        let Command::Get {
+            resource: GetResource::Ws(args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+
+        let org = client.effective_org(args.org.as_ref());
+        assert_eq!(
+            plan_get_ws(args, org.as_ref())[0].path,
+            "/organizations/ctx-org/workspaces?page[number]=1"
+        );
+        run_ws_command(&client, &cli).await.unwrap();
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
 }
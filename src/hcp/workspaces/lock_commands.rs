@@ -0,0 +1,239 @@
+//! Lock/unlock workspace command handlers
+
+use log::debug;
+
+use crate::cli::{Cli, LockArgs, UnlockArgs};
+use crate::error::TfeError;
+use crate::hcp::explain::{call, print_explain, workspace_lookup, PlannedCall};
+use crate::hcp::traits::TfeResource;
+use crate::hcp::workspaces::resolve_workspace;
+use crate::hcp::TfeClient;
+use crate::ui::{create_spinner, finish_spinner};
+
+/// Run the lock command
+pub async fn run_lock_command(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &LockArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    set_locked(
+        client,
+        cli,
+        &args.workspace,
+        args.org.as_ref(),
+        true,
+        args.reason.as_deref(),
+    )
+    .await
+}
+
+/// Run the unlock command
+pub async fn run_unlock_command(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &UnlockArgs,
+) -> Result<(), Box<dyn std::error::Error>> {
+    set_locked(client, cli, &args.workspace, args.org.as_ref(), false, None).await
+}
+
+fn state_word(locked: bool) -> &'static str {
+    if locked {
+        "locked"
+    } else {
+        "unlocked"
+    }
+}
+
+async fn set_locked(
+    client: &TfeClient,
+    cli: &Cli,
+    workspace: &str,
+    org: Option<&String>,
+    lock: bool,
+    reason: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let effective_org = client.effective_org(org);
+    if cli.explain {
+        print_explain(&plan_set_locked(workspace, effective_org.as_ref(), lock));
+        return Ok(());
+    }
+
+    let resolved = resolve_workspace(
+        client,
+        workspace,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
+    let ws_id = &resolved.workspace.id;
+    let ws_name = resolved.workspace.name();
+    let word = state_word(lock);
+
+    if resolved.workspace.is_locked() == lock {
+        println!("Workspace '{}' ({}) is already {}", ws_name, ws_id, word);
+        return Ok(());
+    }
+
+    debug!("Setting workspace {} locked={}", ws_id, lock);
+    let action = if lock { "Locking" } else { "Unlocking" };
+    let spinner = create_spinner(&format!("{} workspace {}...", action, ws_id), cli.batch);
+    let result = if lock {
+        client.lock_workspace(ws_id, reason).await
+    } else {
+        client.unlock_workspace(ws_id).await
+    };
+    finish_spinner(spinner);
+
+    match result {
+        Ok(()) => {}
+        // 409 also covers a lock taken (or released) since we resolved the
+        // workspace; only the remaining cases are real conflicts
+        Err(TfeError::Api { status: 409, .. })
+            if matches!(
+                client.get_workspace_by_id(ws_id).await,
+                Ok(Some((ref ws, _))) if ws.is_locked() == lock
+            ) =>
+        {
+            println!("Workspace '{}' ({}) is already {}", ws_name, ws_id, word);
+            return Ok(());
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    match reason {
+        Some(reason) => println!(
+            "Workspace '{}' ({}) is now {} (reason: {})",
+            ws_name, ws_id, word, reason
+        ),
+        None => println!("Workspace '{}' ({}) is now {}", ws_name, ws_id, word),
+    }
+    Ok(())
+}
+
+/// API calls made by `lock`/`unlock` for the effective organization
+fn plan_set_locked(workspace: &str, org: Option<&String>, lock: bool) -> Vec<PlannedCall> {
+    let action = if lock { "lock" } else { "unlock" };
+    let mut calls = workspace_lookup(workspace, org);
+    calls.push(call(
+        "POST",
+        format!("/workspaces/:ws_id/actions/{}", action),
+        action,
+    ));
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn workspace_body(locked: bool) -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "id": "ws-123",
+                "type": "workspaces",
+                "attributes": {"name": "app", "locked": locked},
+                "relationships": {
+                    "organization": {"data": {"id": "my-org", "type": "organizations"}}
+                }
+            }
+        })
+    }
+
+    async fn mount_workspace(server: &MockServer, locked: bool) {
+        Mock::given(method("GET"))
+            .and(path("/workspaces/ws-123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(workspace_body(locked)))
+            .mount(server)
+            .await;
+    }
+
+    fn lock_cli(extra: &[&str]) -> Cli {
+        let mut argv = vec!["hcp", "--batch", "lock", "ws-123"];
+        argv.extend_from_slice(extra);
+        Cli::parse_from(argv)
+    }
+
+    fn lock_args(cli: &Cli) -> &LockArgs {
+        match &cli.command {
+            crate::cli::Command::Lock(args) => args,
+            _ => unreachable!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lock_posts_action() {
+        let server = MockServer::start().await;
+        mount_workspace(&server, false).await;
+        Mock::given(method("POST"))
+            .and(path("/workspaces/ws-123/actions/lock"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = TfeClient::test_client(&server.uri());
+        let cli = lock_cli(&["--reason", "freeze"]);
+        run_lock_command(&client, &cli, lock_args(&cli))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_lock_already_locked_skips_post() {
+        let server = MockServer::start().await;
+        mount_workspace(&server, true).await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let client = TfeClient::test_client(&server.uri());
+        let cli = lock_cli(&[]);
+        run_lock_command(&client, &cli, lock_args(&cli))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unlock_conflict_by_other_holder_is_error() {
+        let server = MockServer::start().await;
+        mount_workspace(&server, true).await;
+        Mock::given(method("POST"))
+            .and(path("/workspaces/ws-123/actions/unlock"))
+            .respond_with(ResponseTemplate::new(409))
+            .mount(&server)
+            .await;
+
+        let client = TfeClient::test_client(&server.uri());
+        let cli = Cli::parse_from(["hcp", "--batch", "unlock", "ws-123"]);
+        let crate::cli::Command::Unlock(args) = &cli.command else {
+            unreachable!()
+        };
+        let err = run_unlock_command(&client, &cli, args)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("locked by another user/run"), "{}", err);
+    }
+
+    #[test]
+    fn test_plan_lock_resolves_then_posts() {
+        let calls = plan_set_locked("ws-abc", None, true);
+        let last = calls.last().unwrap();
+        assert_eq!(last.method, "POST");
+        assert_eq!(last.path, "/workspaces/:ws_id/actions/lock");
+
+        let org = "ctx".to_string();
+        let calls = plan_set_locked("app", Some(&org), false);
+        assert_eq!(calls[0].path, "/organizations/ctx/workspaces/app");
+        assert_eq!(
+            calls.last().unwrap().path,
+            "/workspaces/:ws_id/actions/unlock"
+        );
+    }
+}
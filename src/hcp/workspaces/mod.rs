@@ -2,18 +2,21 @@
 
 mod api;
 mod commands;
+mod lock_commands;
 mod models;
 pub mod resolver;
 mod set_api;
 mod set_commands;
 
 pub use commands::run_ws_command;
+pub use lock_commands::{run_lock_command, run_unlock_command};
 pub use models::{
-    RelationshipData, RelationshipId, Workspace, WorkspaceAttributes, WorkspaceQuery,
-    WorkspaceRelationships,
+    RelationshipData, RelationshipId, VcsRepo, Workspace, WorkspaceAttributes, WorkspaceFilter,
+    WorkspaceQuery, WorkspaceRelationships, WorkspaceSettings,
 };
 pub use resolver::{
-    extract_current_run_id, parse_workspace_target, resolve_workspace, ResolvedWorkspace,
+    extract_current_run_id, find_workspace_candidates, parse_workspace_target,
+    report_search_errors, resolve_workspace, select_workspace_candidate, ResolvedWorkspace,
     WorkspaceTarget,
 };
 pub use set_commands::run_set_ws_command;
@@ -1,11 +1,12 @@
 //! Workspace data models
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::hcp::traits::TfeResource;
+use crate::hcp::PageRequest;
 
 /// Query options for listing workspaces
-#[derive(Default)]
+#[derive(Default, Clone, Copy)]
 pub struct WorkspaceQuery<'a> {
     /// Filter by workspace name (fuzzy server-side search)
     pub search: Option<&'a str>,
@@ -13,6 +14,91 @@ pub struct WorkspaceQuery<'a> {
     pub project_id: Option<&'a str>,
     /// Filter by flat string tag name(s) (comma-separated for multiple)
     pub search_tags: Option<&'a str>,
+    /// Fetch only this page and report it on stderr (`--page`/`--page-size`)
+    pub page: Option<PageRequest>,
+}
+
+/// Filter for [`TfeClient::list_workspaces`](crate::hcp::TfeClient::list_workspaces)
+///
+/// Organization, project and name are sent to the API; name (again, as a
+/// substring), lock state and resource count are checked on the results.
+///
+/// ```
+/// use hcpctl::WorkspaceFilter;
+///
+/// let filter = WorkspaceFilter::new()
+///     .org("my-org")
+///     .name_contains("prod")
+///     .locked(false)
+///     .min_resources(10);
+/// assert_eq!(filter.org_name(), Some("my-org"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorkspaceFilter {
+    org: Option<String>,
+    project_id: Option<String>,
+    name: Option<String>,
+    locked: Option<bool>,
+    min_resources: Option<u32>,
+}
+
+impl WorkspaceFilter {
+    /// Filter matching every workspace in every accessible organization
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only list workspaces in this organization
+    pub fn org(mut self, org: impl Into<String>) -> Self {
+        self.org = Some(org.into());
+        self
+    }
+
+    /// Only list workspaces in this project (project ID, e.g. `prj-abc123`)
+    pub fn project(mut self, project_id: impl Into<String>) -> Self {
+        self.project_id = Some(project_id.into());
+        self
+    }
+
+    /// Only list workspaces whose name contains this substring
+    pub fn name_contains(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Only list locked (`true`) or unlocked (`false`) workspaces
+    pub fn locked(mut self, locked: bool) -> Self {
+        self.locked = Some(locked);
+        self
+    }
+
+    /// Only list workspaces managing at least this many resources
+    pub fn min_resources(mut self, count: u32) -> Self {
+        self.min_resources = Some(count);
+        self
+    }
+
+    /// Organization to query, or `None` for all accessible organizations
+    pub fn org_name(&self) -> Option<&str> {
+        self.org.as_deref()
+    }
+
+    /// Server-side part of the filter
+    pub fn query(&self) -> WorkspaceQuery<'_> {
+        WorkspaceQuery {
+            search: self.name.as_deref(),
+            project_id: self.project_id.as_deref(),
+            search_tags: None,
+            page: None,
+        }
+    }
+
+    /// Check the client-side conditions against a fetched workspace
+    pub fn matches(&self, ws: &Workspace) -> bool {
+        self.name.as_deref().is_none_or(|n| ws.matches_filter(n))
+            && self.locked.is_none_or(|l| ws.is_locked() == l)
+            && self.min_resources.is_none_or(|m| ws.resource_count() >= m)
+    }
 }
 
 /// Workspace data from TFE API
@@ -73,6 +159,18 @@ impl Workspace {
             .unwrap_or("unknown")
     }
 
+    /// Get lifecycle state, if the API reports one
+    pub fn lifecycle_state(&self) -> Option<&str> {
+        self.attributes.state.as_deref()
+    }
+
+    /// Check if workspace is in the given lifecycle state (case-insensitive).
+    /// Workspaces without a reported state never match.
+    pub fn matches_state(&self, state: &str) -> bool {
+        self.lifecycle_state()
+            .is_some_and(|s| s.eq_ignore_ascii_case(state))
+    }
+
     /// Check if workspace is locked
     pub fn is_locked(&self) -> bool {
         self.attributes.locked.unwrap_or(false)
@@ -86,6 +184,20 @@ impl Workspace {
             .unwrap_or("unknown")
     }
 
+    /// VCS repository identifier (e.g. org/repo), empty if not VCS-backed
+    pub fn vcs_repo_identifier(&self) -> &str {
+        self.attributes
+            .vcs_repo
+            .as_ref()
+            .and_then(|r| r.identifier.as_deref())
+            .unwrap_or("")
+    }
+
+    /// Working directory within the repository, empty if not set
+    pub fn working_directory(&self) -> &str {
+        self.attributes.working_directory.as_deref().unwrap_or("")
+    }
+
     /// Get updated_at timestamp, defaulting to empty string if not available
     pub fn updated_at(&self) -> &str {
         self.attributes.updated_at.as_deref().unwrap_or("")
@@ -128,6 +240,95 @@ pub struct WorkspaceAttributes {
 
     #[serde(rename = "updated-at")]
     pub updated_at: Option<String>,
+
+    /// Lifecycle state (e.g. active, archived). Only reported by newer
+    /// HCP Terraform / TFE releases; absent on older versions.
+    pub state: Option<String>,
+
+    /// VCS connection, absent for CLI/API-driven workspaces
+    #[serde(rename = "vcs-repo")]
+    pub vcs_repo: Option<VcsRepo>,
+
+    #[serde(rename = "working-directory")]
+    pub working_directory: Option<String>,
+
+    /// Whether health assessments (drift detection) run for this workspace
+    #[serde(rename = "assessments-enabled")]
+    pub assessments_enabled: Option<bool>,
+
+    /// Boolean workspace settings (auto-apply, speculative, etc.)
+    #[serde(flatten)]
+    pub settings: WorkspaceSettings,
+}
+
+/// VCS repository a workspace is connected to
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct VcsRepo {
+    /// Repository identifier, e.g. "my-org/infra"
+    pub identifier: Option<String>,
+    pub branch: Option<String>,
+}
+
+/// Boolean workspace settings frequently audited for compliance
+///
+/// Deserialized from the kebab-case API attributes, serialized in snake_case
+/// like the rest of the structured output.
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceSettings {
+    #[serde(rename(deserialize = "auto-apply"))]
+    pub auto_apply: Option<bool>,
+
+    #[serde(rename(deserialize = "queue-all-runs"))]
+    pub queue_all_runs: Option<bool>,
+
+    #[serde(rename(deserialize = "speculative-enabled"))]
+    pub speculative_enabled: Option<bool>,
+
+    #[serde(rename(deserialize = "global-remote-state"))]
+    pub global_remote_state: Option<bool>,
+
+    #[serde(rename(deserialize = "allow-destroy-plan"))]
+    pub allow_destroy_plan: Option<bool>,
+}
+
+impl WorkspaceSettings {
+    /// All settings as (API attribute name, value) pairs in display order
+    pub fn entries(&self) -> [(&'static str, Option<bool>); 5] {
+        [
+            ("auto-apply", self.auto_apply),
+            ("queue-all-runs", self.queue_all_runs),
+            ("speculative-enabled", self.speculative_enabled),
+            ("global-remote-state", self.global_remote_state),
+            ("allow-destroy-plan", self.allow_destroy_plan),
+        ]
+    }
+
+    /// Only the settings that are set, as (API attribute name, value) pairs
+    pub fn api_attributes(&self) -> Vec<(&'static str, bool)> {
+        self.entries()
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|v| (key, v)))
+            .collect()
+    }
+
+    /// Check if no setting is set
+    pub fn is_empty(&self) -> bool {
+        self.api_attributes().is_empty()
+    }
+
+    /// Keep only requested settings that differ from the current values
+    pub fn changed_from(&self, current: &WorkspaceSettings) -> WorkspaceSettings {
+        let pick = |requested: Option<bool>, current: Option<bool>| {
+            requested.filter(|r| current != Some(*r))
+        };
+        WorkspaceSettings {
+            auto_apply: pick(self.auto_apply, current.auto_apply),
+            queue_all_runs: pick(self.queue_all_runs, current.queue_all_runs),
+            speculative_enabled: pick(self.speculative_enabled, current.speculative_enabled),
+            global_remote_state: pick(self.global_remote_state, current.global_remote_state),
+            allow_destroy_plan: pick(self.allow_destroy_plan, current.allow_destroy_plan),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -139,16 +340,34 @@ mod tests {
             id: format!("ws-{}", name),
             attributes: WorkspaceAttributes {
                 name: name.to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: Some("remote".to_string()),
                 resource_count: Some(42),
                 locked: Some(locked),
                 terraform_version: Some("1.5.0".to_string()),
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         }
     }
 
+    #[test]
+    fn test_workspace_state_deserialization_and_match() {
+        let json = r#"{"id":"ws-1","attributes":{"name":"a","state":"Archived"}}"#;
+        let ws: Workspace = serde_json::from_str(json).unwrap();
+        assert_eq!(ws.lifecycle_state(), Some("Archived"));
+        assert!(ws.matches_state("archived"));
+        assert!(!ws.matches_state("active"));
+
+        let legacy = create_test_workspace("b", false);
+        assert_eq!(legacy.lifecycle_state(), None);
+        assert!(!legacy.matches_state("active"));
+    }
+
     #[test]
     fn test_workspace_name() {
         let ws = create_test_workspace("my-workspace", false);
@@ -169,11 +388,16 @@ mod tests {
             id: "ws-123".to_string(),
             attributes: WorkspaceAttributes {
                 name: "test".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: None,
                 resource_count: None,
                 locked: None,
                 terraform_version: None,
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         };
@@ -195,11 +419,16 @@ mod tests {
             id: "ws-123".to_string(),
             attributes: WorkspaceAttributes {
                 name: "test".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: None,
                 resource_count: None,
                 locked: None,
                 terraform_version: None,
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: Some(WorkspaceRelationships {
                 project: Some(RelationshipData {
@@ -265,6 +494,104 @@ mod tests {
         assert_eq!(ws.project_id(), Some("prj-xyz"));
     }
 
+    #[test]
+    fn test_workspace_settings_deserialization() {
+        let json = r#"{
+            "id": "ws-abc123",
+            "attributes": {
+                "name": "my-workspace",
+                "auto-apply": true,
+                "queue-all-runs": false,
+                "speculative-enabled": true,
+                "global-remote-state": false,
+                "allow-destroy-plan": true
+            }
+        }"#;
+
+        let ws: Workspace = serde_json::from_str(json).unwrap();
+        let settings = &ws.attributes.settings;
+        assert_eq!(settings.auto_apply, Some(true));
+        assert_eq!(settings.queue_all_runs, Some(false));
+        assert_eq!(settings.speculative_enabled, Some(true));
+        assert_eq!(settings.global_remote_state, Some(false));
+        assert_eq!(settings.allow_destroy_plan, Some(true));
+    }
+
+    #[test]
+    fn test_workspace_vcs_repo_and_working_directory() {
+        let json = r#"{
+            "id": "ws-abc123",
+            "attributes": {
+                "name": "my-workspace",
+                "vcs-repo": {"identifier": "acme/infra", "branch": "main"},
+                "working-directory": "envs/prod"
+            }
+        }"#;
+        let ws: Workspace = serde_json::from_str(json).unwrap();
+        assert_eq!(ws.vcs_repo_identifier(), "acme/infra");
+        assert_eq!(ws.working_directory(), "envs/prod");
+
+        let json = r#"{"id": "ws-1", "attributes": {"name": "ws", "vcs-repo": null}}"#;
+        let ws: Workspace = serde_json::from_str(json).unwrap();
+        assert_eq!(ws.vcs_repo_identifier(), "");
+        assert_eq!(ws.working_directory(), "");
+    }
+
+    #[test]
+    fn test_workspace_settings_missing_default_none() {
+        let json = r#"{"id": "ws-1", "attributes": {"name": "ws"}}"#;
+        let ws: Workspace = serde_json::from_str(json).unwrap();
+        assert_eq!(ws.attributes.settings, WorkspaceSettings::default());
+    }
+
+    #[test]
+    fn test_workspace_settings_serialize_snake_case() {
+        let settings = WorkspaceSettings {
+            auto_apply: Some(true),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&settings).unwrap();
+        assert_eq!(json["auto_apply"], true);
+        assert!(json.get("auto-apply").is_none());
+    }
+
+    #[test]
+    fn test_workspace_settings_api_attributes_only_set_fields() {
+        let settings = WorkspaceSettings {
+            auto_apply: Some(true),
+            allow_destroy_plan: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            settings.api_attributes(),
+            vec![("auto-apply", true), ("allow-destroy-plan", false)]
+        );
+        assert!(!settings.is_empty());
+        assert!(WorkspaceSettings::default().is_empty());
+    }
+
+    #[test]
+    fn test_workspace_settings_changed_from() {
+        let current = WorkspaceSettings {
+            auto_apply: Some(true),
+            queue_all_runs: Some(false),
+            speculative_enabled: None,
+            ..Default::default()
+        };
+        let requested = WorkspaceSettings {
+            auto_apply: Some(true),
+            queue_all_runs: Some(true),
+            speculative_enabled: Some(false),
+            ..Default::default()
+        };
+
+        let changed = requested.changed_from(&current);
+        assert_eq!(changed.auto_apply, None);
+        assert_eq!(changed.queue_all_runs, Some(true));
+        assert_eq!(changed.speculative_enabled, Some(false));
+        assert_eq!(changed.allow_destroy_plan, None);
+    }
+
     // ===== WorkspaceQuery tests =====
 
     #[test]
@@ -330,11 +657,16 @@ mod tests {
             id: "ws-123".to_string(),
             attributes: WorkspaceAttributes {
                 name: "test".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: None,
                 resource_count: None,
                 locked: None,
                 terraform_version: None,
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         };
@@ -353,11 +685,16 @@ mod tests {
             id: "ws-123".to_string(),
             attributes: WorkspaceAttributes {
                 name: "test".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: None,
                 resource_count: None,
                 locked: None,
                 terraform_version: None,
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         };
@@ -370,11 +707,16 @@ mod tests {
             id: "ws-123".to_string(),
             attributes: WorkspaceAttributes {
                 name: "test".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: None,
                 resource_count: None,
                 locked: None,
                 terraform_version: None,
                 updated_at: Some("2025-01-01T00:00:00Z".to_string()),
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         };
@@ -393,11 +735,16 @@ mod tests {
             id: "ws-123".to_string(),
             attributes: WorkspaceAttributes {
                 name: "test".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: None,
                 resource_count: None,
                 locked: None,
                 terraform_version: None,
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: Some(WorkspaceRelationships {
                 project: None,
@@ -424,11 +771,16 @@ mod tests {
             id: "ws-123".to_string(),
             attributes: WorkspaceAttributes {
                 name: "test".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: None,
                 resource_count: None,
                 locked: None,
                 terraform_version: None,
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         };
@@ -3,13 +3,15 @@
 //! Provides shared functionality for resolving workspaces by ID or name,
 //! with optional auto-discovery across organizations.
 
-use futures::stream::{FuturesUnordered, StreamExt};
+use futures::future::join_all;
 use log::debug;
 
 use super::Workspace;
+use crate::hcp::helpers::{collect_org_results, fetch_from_organizations, OrgErrors};
 use crate::hcp::organizations::resolve_organizations;
+use crate::hcp::traits::TfeResource;
 use crate::hcp::TfeClient;
-use crate::ui::{create_spinner, finish_spinner};
+use crate::ui::{create_spinner, finish_spinner, select_with_timeout};
 
 /// Resolved workspace information
 #[derive(Debug)]
@@ -47,6 +49,8 @@ pub fn parse_workspace_target(target: &str) -> WorkspaceTarget {
 /// * `target` - Workspace ID (ws-xxx) or name
 /// * `org` - Optional organization name (required for name lookup without auto-discovery)
 /// * `batch` - If true, no spinners
+/// * `strict` - Fail (`--strict`) instead of warning when a name search could
+///   not cover every organization
 ///
 /// # Returns
 /// Resolved workspace info including workspace model, raw JSON, and organization name
@@ -55,6 +59,7 @@ pub async fn resolve_workspace(
     target: &str,
     org: Option<&str>,
     batch: bool,
+    strict: bool,
 ) -> Result<ResolvedWorkspace, Box<dyn std::error::Error>> {
     match parse_workspace_target(target) {
         WorkspaceTarget::Id(ws_id) => resolve_by_id(client, &ws_id, batch).await,
@@ -62,7 +67,7 @@ pub async fn resolve_workspace(
             if let Some(org_name) = org {
                 resolve_by_name(client, org_name, &name, batch).await
             } else {
-                resolve_across_orgs(client, &name, batch).await
+                resolve_across_orgs(client, &name, batch, strict).await
             }
         }
     }
@@ -123,6 +128,7 @@ async fn resolve_across_orgs(
     client: &TfeClient,
     name: &str,
     batch: bool,
+    strict: bool,
 ) -> Result<ResolvedWorkspace, Box<dyn std::error::Error>> {
     let organizations = resolve_organizations(client, None).await?;
 
@@ -141,41 +147,164 @@ async fn resolve_across_orgs(
         batch,
     );
 
-    // Search in all organizations in parallel with early termination
-    let name_owned = name.to_string();
-    let mut futures: FuturesUnordered<_> = organizations
-        .iter()
-        .map(|org_name| {
-            let org = org_name.clone();
-            let ws_name = name_owned.clone();
-            async move {
-                let result = client.get_workspace_by_name(&org, &ws_name).await;
-                (org, result)
-            }
+    let (candidates, org_errors) = find_workspace_candidates(client, name, &organizations).await;
+    finish_spinner(spinner);
+    report_search_errors(&org_errors, strict)?;
+
+    select_workspace_candidate(client, name, candidates, batch)
+        .await?
+        .ok_or_else(|| {
+            crate::hcp::helpers::not_found_in_orgs_error("Workspace", name, &organizations).into()
         })
-        .collect();
+}
 
-    // Process results as they complete, stop on first match
-    while let Some((org_name, result)) = futures.next().await {
-        if let Ok(Some((ws, raw))) = result {
-            finish_spinner(spinner);
-            return Ok(ResolvedWorkspace {
-                workspace: ws,
-                raw,
-                org: org_name,
-            });
-        }
+/// Find all workspaces with the given name across organizations
+///
+/// Queries all organizations in parallel and returns every match sorted by org,
+/// so callers can detect names that are ambiguous across organizations.
+/// Organizations that could not be searched are returned as [`OrgErrors`].
+pub async fn find_workspace_candidates(
+    client: &TfeClient,
+    name: &str,
+    organizations: &[String],
+) -> (Vec<ResolvedWorkspace>, OrgErrors) {
+    let results = fetch_from_organizations(
+        organizations.to_vec(),
+        client.concurrency(),
+        |org| async move {
+            match client.get_workspace_by_name(&org, name).await {
+                Ok(found) => Ok(found.map(|(workspace, raw)| ResolvedWorkspace {
+                    workspace,
+                    raw,
+                    org,
+                })),
+                Err(e) => Err((org, e)),
+            }
+        },
+    )
+    .await;
+
+    let (found, org_errors) = collect_org_results(results, "workspaces");
+    let mut candidates: Vec<ResolvedWorkspace> = found.into_iter().flatten().collect();
+    candidates.sort_by(|a, b| a.org.cmp(&b.org));
+
+    debug!(
+        "Found {} workspace(s) named '{}' across {} organization(s)",
+        candidates.len(),
+        name,
+        organizations.len()
+    );
+    (candidates, org_errors)
+}
+
+/// Report organizations a workspace name search could not cover
+///
+/// Fails under `--strict`; otherwise warns on stderr, since the workspace may
+/// exist in one of the failed organizations.
+pub fn report_search_errors(org_errors: &OrgErrors, strict: bool) -> Result<(), String> {
+    org_errors.check_strict(strict, "workspaces")?;
+    if let Some(report) = org_errors.report("workspaces") {
+        eprintln!("Warning: {}", report);
     }
+    Ok(())
+}
 
-    finish_spinner(spinner);
+/// Pick one workspace out of the candidates found by name
+///
+/// A single match is returned directly. When the name exists in several
+/// organizations the user picks one interactively (mirroring host selection);
+/// in batch mode, or when the pick is not made within the client's prompt
+/// timeout, an error listing the candidates is returned instead.
+pub async fn select_workspace_candidate(
+    client: &TfeClient,
+    name: &str,
+    mut candidates: Vec<ResolvedWorkspace>,
+    batch: bool,
+) -> Result<Option<ResolvedWorkspace>, Box<dyn std::error::Error>> {
+    if candidates.len() <= 1 {
+        return Ok(candidates.pop());
+    }
+    let labels = candidate_labels(client, &candidates).await;
+    if batch {
+        return Err(ambiguous_workspace_message(name, &labels).into());
+    }
+    interactive_workspace_selection(name, candidates, &labels, client.prompt_timeout())
+        .await
+        .map(Some)
+}
 
-    let searched = if organizations.len() == 1 {
-        format!("organization '{}'", organizations[0])
-    } else {
-        format!("{} organizations", organizations.len())
-    };
+/// Prompt user to select one of several workspaces sharing the same name
+///
+/// Uses the same menu as `HostResolver`'s host selection, but never guesses:
+/// an unanswered prompt errors with the candidate list, as `--batch` does.
+async fn interactive_workspace_selection(
+    name: &str,
+    mut candidates: Vec<ResolvedWorkspace>,
+    labels: &[String],
+    prompt_timeout: Option<u64>,
+) -> Result<ResolvedWorkspace, Box<dyn std::error::Error>> {
+    eprintln!(
+        "\nWorkspace '{}' exists in {} organizations:",
+        name,
+        candidates.len()
+    );
+
+    let selection = select_with_timeout("Select a workspace", labels, 0, prompt_timeout)
+        .await
+        .map_err(|e| format!("Failed to select workspace: {}", e))?
+        .ok_or_else(|| ambiguous_workspace_message(name, labels))?;
+
+    let selected = candidates.swap_remove(selection);
+    debug!(
+        "User selected workspace {} in org '{}'",
+        selected.workspace.id, selected.org
+    );
+    Ok(selected)
+}
+
+/// Labels for ambiguous workspace candidates, with their project names
+///
+/// Projects are looked up by ID in parallel; one that cannot be fetched is
+/// shown by its ID instead.
+async fn candidate_labels(client: &TfeClient, candidates: &[ResolvedWorkspace]) -> Vec<String> {
+    join_all(candidates.iter().map(|candidate| async move {
+        let project = match candidate.workspace.project_id() {
+            Some(project_id) => match client.get_project_by_id(project_id).await {
+                Ok(Some((project, _))) => project.name().to_string(),
+                Ok(None) => project_id.to_string(),
+                Err(e) => {
+                    debug!("Failed to fetch project '{}': {}", project_id, e);
+                    project_id.to_string()
+                }
+            },
+            None => "-".to_string(),
+        };
+        candidate_label(candidate, &project)
+    }))
+    .await
+}
 
-    Err(format!("Workspace '{}' not found in {}", name, searched).into())
+/// Human-readable label for an ambiguous workspace candidate
+fn candidate_label(candidate: &ResolvedWorkspace, project: &str) -> String {
+    format!(
+        "{} ({})  org: {}, project: {}",
+        candidate.workspace.name(),
+        candidate.workspace.id,
+        candidate.org,
+        project
+    )
+}
+
+/// Error message listing all candidates when a name is ambiguous in batch mode
+fn ambiguous_workspace_message(name: &str, labels: &[String]) -> String {
+    let lines: Vec<String> = labels.iter().map(|l| format!("  - {}", l)).collect();
+    format!(
+        "Workspace '{}' is ambiguous, found in {} organizations:\n{}\n\
+         Use --org to select the organization or pass the workspace ID (ws-xxx)",
+        name,
+        labels.len(),
+        lines.join("\n")
+    )
 }
 
 /// Extract current-run ID from workspace raw JSON
@@ -385,11 +514,233 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = resolve_workspace(&client, "ws-abc123", None, true).await;
+        let result = resolve_workspace(&client, "ws-abc123", None, true, false).await;
 
         assert!(result.is_ok());
     }
 
+    fn resolved(ws_id: &str, org: &str) -> ResolvedWorkspace {
+        let raw = workspace_response(ws_id, "shared", org);
+        ResolvedWorkspace {
+            workspace: serde_json::from_value(raw["data"].clone()).unwrap(),
+            raw,
+            org: org.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_candidate_label_includes_context() {
+        let label = candidate_label(&resolved("ws-1", "org-a"), "platform");
+        assert!(label.contains("shared"));
+        assert!(label.contains("ws-1"));
+        assert!(label.contains("org: org-a"));
+        assert!(label.contains("project: platform"));
+    }
+
+    #[tokio::test]
+    async fn test_candidate_labels_show_project_names() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/projects/prj-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {"id": "prj-1", "type": "projects", "attributes": {"name": "platform"}}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/projects/prj-2"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let with_project = |ws_id: &str, org: &str, prj_id: &str| {
+            let mut candidate = resolved(ws_id, org);
+            candidate.workspace = serde_json::from_value(serde_json::json!({
+                "id": ws_id,
+                "type": "workspaces",
+                "attributes": {"name": "shared"},
+                "relationships": {"project": {"data": {"id": prj_id, "type": "projects"}}}
+            }))
+            .unwrap();
+            candidate
+        };
+        let candidates = vec![
+            with_project("ws-1", "org-a", "prj-1"),
+            with_project("ws-2", "org-b", "prj-2"),
+            resolved("ws-3", "org-c"),
+        ];
+
+        let labels = candidate_labels(&client, &candidates).await;
+        assert!(labels[0].ends_with("project: platform"), "{}", labels[0]);
+        // A project that cannot be fetched falls back to its ID
+        assert!(labels[1].ends_with("project: prj-2"), "{}", labels[1]);
+        assert!(labels[2].ends_with("project: -"), "{}", labels[2]);
+    }
+
+    #[test]
+    fn test_ambiguous_workspace_message_lists_candidates() {
+        let msg = ambiguous_workspace_message(
+            "shared",
+            &[
+                candidate_label(&resolved("ws-1", "org-a"), "-"),
+                candidate_label(&resolved("ws-2", "org-b"), "-"),
+            ],
+        );
+        assert!(msg.contains("ambiguous"));
+        assert!(msg.contains("2 organizations"));
+        assert!(msg.contains("ws-1"));
+        assert!(msg.contains("ws-2"));
+        assert!(msg.contains("--org"));
+    }
+
+    #[tokio::test]
+    async fn test_find_workspace_candidates_single_match() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/org-a/workspaces/shared"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(workspace_response("ws-1", "shared", "org-a")),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/org-b/workspaces/shared"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let orgs = vec!["org-a".to_string(), "org-b".to_string()];
+        let (candidates, org_errors) = find_workspace_candidates(&client, "shared", &orgs).await;
+        assert!(!org_errors.has_errors());
+        let found = select_workspace_candidate(&client, "shared", candidates, true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.workspace.id, "ws-1");
+        assert_eq!(found.org, "org-a");
+    }
+
+    #[tokio::test]
+    async fn test_find_workspace_candidates_reports_failed_orgs() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/organizations/org-a/workspaces/shared"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(workspace_response("ws-1", "shared", "org-a")),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/org-b/workspaces/shared"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&mock_server)
+            .await;
+
+        let orgs = vec!["org-a".to_string(), "org-b".to_string()];
+        let (candidates, org_errors) = find_workspace_candidates(&client, "shared", &orgs).await;
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(org_errors.failures().len(), 1);
+        assert_eq!(org_errors.failures()[0].0, "org-b");
+
+        assert!(report_search_errors(&org_errors, false).is_ok());
+        let err = report_search_errors(&org_errors, true).unwrap_err();
+        assert!(err.contains("org-b"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_workspace_across_orgs_honors_strict() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .and(path("/organizations"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [
+                    {"id": "org-a", "attributes": {"name": "org-a"}},
+                    {"id": "org-b", "attributes": {"name": "org-b"}}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/org-a/workspaces/shared"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(workspace_response("ws-1", "shared", "org-a")),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/organizations/org-b/workspaces/shared"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&mock_server)
+            .await;
+
+        let resolved = resolve_workspace(&client, "shared", None, true, false)
+            .await
+            .unwrap();
+        assert_eq!(resolved.workspace.id, "ws-1");
+
+        let err = resolve_workspace(&client, "shared", None, true, true)
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("org-b"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_find_workspace_candidates_none() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+
+        let orgs = vec!["org-a".to_string()];
+        let (candidates, _) = find_workspace_candidates(&client, "missing", &orgs).await;
+        let found = select_workspace_candidate(&client, "missing", candidates, true)
+            .await
+            .unwrap();
+        assert!(found.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_workspace_candidates_ambiguous_batch_errors() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        for (org, ws_id) in [("org-a", "ws-1"), ("org-b", "ws-2")] {
+            Mock::given(method("GET"))
+                .and(path(format!("/organizations/{}/workspaces/shared", org)))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(workspace_response(ws_id, "shared", org)),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+
+        let orgs = vec!["org-a".to_string(), "org-b".to_string()];
+        let (candidates, _) = find_workspace_candidates(&client, "shared", &orgs).await;
+        assert_eq!(candidates.len(), 2);
+        let result = select_workspace_candidate(&client, "shared", candidates, true).await;
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("ambiguous"));
+        assert!(err.contains("org-a"));
+        assert!(err.contains("org-b"));
+    }
+
     #[tokio::test]
     async fn test_resolve_workspace_with_name_and_org() {
         let mock_server = MockServer::start().await;
@@ -405,7 +756,7 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = resolve_workspace(&client, "my-workspace", Some("my-org"), true).await;
+        let result = resolve_workspace(&client, "my-workspace", Some("my-org"), true, false).await;
 
         assert!(result.is_ok());
         let resolved = result.unwrap();
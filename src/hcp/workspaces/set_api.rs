@@ -3,10 +3,10 @@
 use log::debug;
 
 use crate::config::api;
-use crate::error::{Result, TfeError};
+use crate::error::{ApiErrorDetail, Result, TfeError};
 use crate::hcp::TfeClient;
 
-use super::models::Workspace;
+use super::models::{Workspace, WorkspaceSettings};
 
 impl TfeClient {
     /// Assign a workspace to a project
@@ -38,7 +38,7 @@ impl TfeClient {
             }
         });
 
-        let response = self.patch(&url).json(&body).send().await?;
+        let response = self.send(self.patch(&url).json(&body)).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -47,6 +47,7 @@ impl TfeClient {
                     serde_json::from_value(raw["data"].clone()).map_err(|e| TfeError::Api {
                         status: 200,
                         message: format!("Failed to parse workspace response: {}", e),
+                        details: Vec::new(),
                     })?;
                 Ok(workspace)
             }
@@ -56,32 +57,36 @@ impl TfeClient {
                     "Workspace '{}' or project '{}' not found",
                     workspace_id, project_id
                 ),
+                details: Vec::new(),
             }),
             422 => {
                 let body = response.text().await.unwrap_or_default();
+                let details = ApiErrorDetail::parse_all(&body);
                 Err(TfeError::Api {
                     status: 422,
                     message: format!(
                         "Cannot assign workspace '{}' to project '{}': {}. \
                          Hint: you need admin permissions on both the source and destination project",
-                        workspace_id, project_id, body
+                        workspace_id, project_id, ApiErrorDetail::render(&details, &body)
                     ),
+                    details,
                 })
             }
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!(
-                        "Failed to assign workspace '{}' to project '{}': {}",
-                        workspace_id, project_id, body
+                    format!(
+                        "Failed to assign workspace '{}' to project '{}'",
+                        workspace_id, project_id
                     ),
-                })
+                    &body,
+                ))
             }
         }
     }
 
-    /// Update workspace settings (terraform version, project assignment, etc.)
+    /// Update workspace settings (terraform version, project assignment, toggles, etc.)
     ///
     /// Uses PATCH /workspaces/:workspace_id with JSON:API body
     /// Only includes fields that are Some — callers pass None for unchanged settings
@@ -90,22 +95,28 @@ impl TfeClient {
         workspace_id: &str,
         terraform_version: Option<&str>,
         project_id: Option<&str>,
+        settings: &WorkspaceSettings,
     ) -> Result<Workspace> {
         let url = format!("{}/{}/{}", self.base_url(), api::WORKSPACES, workspace_id);
 
         debug!(
-            "Updating workspace {} (terraform_version={:?}, project_id={:?})",
-            workspace_id, terraform_version, project_id
+            "Updating workspace {} (terraform_version={:?}, project_id={:?}, settings={:?})",
+            workspace_id, terraform_version, project_id, settings
         );
 
         let mut data = serde_json::json!({
             "type": "workspaces"
         });
 
+        let mut attributes = serde_json::Map::new();
         if let Some(tf_version) = terraform_version {
-            data["attributes"] = serde_json::json!({
-                "terraform-version": tf_version
-            });
+            attributes.insert("terraform-version".to_string(), tf_version.into());
+        }
+        for (key, value) in settings.api_attributes() {
+            attributes.insert(key.to_string(), value.into());
+        }
+        if !attributes.is_empty() {
+            data["attributes"] = serde_json::Value::Object(attributes);
         }
 
         if let Some(prj_id) = project_id {
@@ -121,7 +132,7 @@ impl TfeClient {
 
         let body = serde_json::json!({ "data": data });
 
-        let response = self.patch(&url).json(&body).send().await?;
+        let response = self.send(self.patch(&url).json(&body)).await?;
 
         match response.status().as_u16() {
             200 => {
@@ -130,40 +141,44 @@ impl TfeClient {
                     serde_json::from_value(raw["data"].clone()).map_err(|e| TfeError::Api {
                         status: 200,
                         message: format!("Failed to parse workspace response: {}", e),
+                        details: Vec::new(),
                     })?;
                 Ok(workspace)
             }
             404 => Err(TfeError::Api {
                 status: 404,
                 message: format!("Workspace '{}' not found", workspace_id),
+                details: Vec::new(),
             }),
             403 => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
-                    status: 403,
-                    message: format!(
-                        "Forbidden: cannot update workspace '{}': {}",
-                        workspace_id, body
-                    ),
-                })
+                Err(TfeError::api_response(
+                    403,
+                    format!("Forbidden: cannot update workspace '{}'", workspace_id),
+                    &body,
+                ))
             }
             422 => {
                 let body = response.text().await.unwrap_or_default();
+                let details = ApiErrorDetail::parse_all(&body);
                 Err(TfeError::Api {
                     status: 422,
                     message: format!(
                         "Invalid update for workspace '{}': {}. \
                          Hint: check that the terraform version and project ID are valid",
-                        workspace_id, body
+                        workspace_id,
+                        ApiErrorDetail::render(&details, &body)
                     ),
+                    details,
                 })
             }
             status => {
                 let body = response.text().await.unwrap_or_default();
-                Err(TfeError::Api {
+                Err(TfeError::api_response(
                     status,
-                    message: format!("Failed to update workspace '{}': {}", workspace_id, body),
-                })
+                    format!("Failed to update workspace '{}'", workspace_id),
+                    &body,
+                ))
             }
         }
     }
@@ -261,7 +276,9 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         match err {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 404);
                 assert!(message.contains("ws-notfound"));
             }
@@ -287,7 +304,9 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         match err {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 403);
                 assert!(message.contains("ws-abc123"));
                 assert!(message.contains("prj-xyz789"));
@@ -342,7 +361,9 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         match err {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 422);
                 assert!(message.contains("ws-abc123"));
                 assert!(message.contains("prj-xyz789"));
@@ -412,7 +433,12 @@ mod tests {
             .await;
 
         let result = client
-            .update_workspace("ws-abc123", Some("1.7.0"), None)
+            .update_workspace(
+                "ws-abc123",
+                Some("1.7.0"),
+                None,
+                &WorkspaceSettings::default(),
+            )
             .await;
 
         assert!(result.is_ok());
@@ -456,7 +482,12 @@ mod tests {
             .await;
 
         let result = client
-            .update_workspace("ws-abc123", None, Some("prj-new789"))
+            .update_workspace(
+                "ws-abc123",
+                None,
+                Some("prj-new789"),
+                &WorkspaceSettings::default(),
+            )
             .await;
 
         assert!(result.is_ok());
@@ -502,7 +533,12 @@ mod tests {
             .await;
 
         let result = client
-            .update_workspace("ws-abc123", Some("1.8.0"), Some("prj-new789"))
+            .update_workspace(
+                "ws-abc123",
+                Some("1.8.0"),
+                Some("prj-new789"),
+                &WorkspaceSettings::default(),
+            )
             .await;
 
         assert!(result.is_ok());
@@ -537,7 +573,9 @@ mod tests {
             .mount(&mock_server)
             .await;
 
-        let result = client.update_workspace("ws-abc123", None, None).await;
+        let result = client
+            .update_workspace("ws-abc123", None, None, &WorkspaceSettings::default())
+            .await;
 
         assert!(result.is_ok());
         let workspace = result.unwrap();
@@ -545,6 +583,91 @@ mod tests {
         assert_eq!(workspace.name(), "my-workspace");
     }
 
+    #[tokio::test]
+    async fn test_update_workspace_settings_only_toggled_attributes() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        let expected_body = serde_json::json!({
+            "data": {
+                "type": "workspaces",
+                "attributes": {
+                    "auto-apply": true,
+                    "speculative-enabled": false
+                }
+            }
+        });
+
+        Mock::given(method("PATCH"))
+            .and(path("/workspaces/ws-abc123"))
+            .and(body_json(expected_body))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(update_workspace_response(
+                    "ws-abc123",
+                    "my-workspace",
+                    "1.5.0",
+                    "prj-xyz789",
+                )),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let settings = WorkspaceSettings {
+            auto_apply: Some(true),
+            speculative_enabled: Some(false),
+            ..Default::default()
+        };
+        let result = client
+            .update_workspace("ws-abc123", None, None, &settings)
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_workspace_settings_with_terraform_version() {
+        let mock_server = MockServer::start().await;
+        let client = TfeClient::test_client(&mock_server.uri());
+
+        let expected_body = serde_json::json!({
+            "data": {
+                "type": "workspaces",
+                "attributes": {
+                    "terraform-version": "1.8.0",
+                    "queue-all-runs": false,
+                    "allow-destroy-plan": true
+                }
+            }
+        });
+
+        Mock::given(method("PATCH"))
+            .and(path("/workspaces/ws-abc123"))
+            .and(body_json(expected_body))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(update_workspace_response(
+                    "ws-abc123",
+                    "my-workspace",
+                    "1.8.0",
+                    "prj-xyz789",
+                )),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let settings = WorkspaceSettings {
+            queue_all_runs: Some(false),
+            allow_destroy_plan: Some(true),
+            ..Default::default()
+        };
+        let result = client
+            .update_workspace("ws-abc123", Some("1.8.0"), None, &settings)
+            .await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_update_workspace_not_found() {
         let mock_server = MockServer::start().await;
@@ -557,13 +680,20 @@ mod tests {
             .await;
 
         let result = client
-            .update_workspace("ws-notfound", Some("1.7.0"), None)
+            .update_workspace(
+                "ws-notfound",
+                Some("1.7.0"),
+                None,
+                &WorkspaceSettings::default(),
+            )
             .await;
 
         assert!(result.is_err());
         let err = result.unwrap_err();
         match err {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 404);
                 assert!(message.contains("ws-notfound"));
             }
@@ -583,13 +713,20 @@ mod tests {
             .await;
 
         let result = client
-            .update_workspace("ws-abc123", Some("1.7.0"), None)
+            .update_workspace(
+                "ws-abc123",
+                Some("1.7.0"),
+                None,
+                &WorkspaceSettings::default(),
+            )
             .await;
 
         assert!(result.is_err());
         let err = result.unwrap_err();
         match err {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 403);
                 assert!(message.contains("ws-abc123"));
             }
@@ -609,13 +746,20 @@ mod tests {
             .await;
 
         let result = client
-            .update_workspace("ws-abc123", Some("invalid"), None)
+            .update_workspace(
+                "ws-abc123",
+                Some("invalid"),
+                None,
+                &WorkspaceSettings::default(),
+            )
             .await;
 
         assert!(result.is_err());
         let err = result.unwrap_err();
         match err {
-            TfeError::Api { status, message } => {
+            TfeError::Api {
+                status, message, ..
+            } => {
                 assert_eq!(status, 422);
                 assert!(message.contains("ws-abc123"));
                 assert!(message.contains("Hint"));
@@ -636,7 +780,12 @@ mod tests {
             .await;
 
         let result = client
-            .update_workspace("ws-abc123", Some("1.7.0"), None)
+            .update_workspace(
+                "ws-abc123",
+                Some("1.7.0"),
+                None,
+                &WorkspaceSettings::default(),
+            )
             .await;
 
         assert!(result.is_err());
@@ -2,10 +2,11 @@
 
 use log::debug;
 
-use crate::cli::{Cli, Command, SetResource};
+use crate::cli::{Cli, Command, OutputFormat, SetResource, SetWsArgs};
+use crate::hcp::explain::{call, org_path, print_explain, workspace_lookup, PlannedCall};
 use crate::hcp::projects::resolve_project;
 use crate::hcp::traits::TfeResource;
-use crate::hcp::workspaces::resolve_workspace;
+use crate::hcp::workspaces::{resolve_workspace, Workspace, WorkspaceFilter, WorkspaceSettings};
 use crate::hcp::TfeClient;
 use crate::ui::{confirm_action, create_spinner, finish_spinner};
 
@@ -21,11 +22,6 @@ pub async fn run_set_ws_command(
         unreachable!()
     };
 
-    debug!(
-        "Set workspace '{}' (project={:?}, terraform_version={:?})",
-        args.workspace, args.project, args.terraform_version
-    );
-
     // Validate terraform_version is not empty/whitespace if provided
     if let Some(ref tf_ver) = args.terraform_version {
         if tf_ver.trim().is_empty() {
@@ -33,11 +29,37 @@ pub async fn run_set_ws_command(
         }
     }
 
+    if cli.explain {
+        let effective_org = client.effective_org(args.org.as_ref());
+        print_explain(&plan_set_ws(args, effective_org.as_ref()));
+        return Ok(());
+    }
+
+    if let Some(filter) = &args.filter {
+        return run_bulk_set_tf_version(client, cli, args, filter).await;
+    }
+    let Some(workspace) = &args.workspace else {
+        unreachable!("clap requires a workspace or --filter")
+    };
+
+    let requested_settings = args.requested_settings();
+
+    debug!(
+        "Set workspace '{}' (project={:?}, terraform_version={:?}, settings={:?})",
+        workspace, args.project, args.terraform_version, requested_settings
+    );
+
     let effective_org = client.effective_org(args.org.as_ref());
 
     // 1. Resolve workspace
-    let resolved_ws =
-        resolve_workspace(client, &args.workspace, effective_org.as_deref(), cli.batch).await?;
+    let resolved_ws = resolve_workspace(
+        client,
+        workspace,
+        effective_org.as_deref(),
+        cli.batch,
+        cli.strict,
+    )
+    .await?;
 
     let ws_id = &resolved_ws.workspace.id;
     let ws_name = resolved_ws.workspace.name().to_string();
@@ -87,12 +109,24 @@ pub async fn run_set_ws_command(
         None
     };
 
-    // 4. If everything is already current, return early
-    if tf_version_to_set.is_none() && project_to_set.is_none() {
+    // 4. Check "already current" for boolean settings
+    let current_settings = &resolved_ws.workspace.attributes.settings;
+    let settings_to_set = requested_settings.changed_from(current_settings);
+    for (key, value) in requested_settings.api_attributes() {
+        if !settings_to_set.api_attributes().contains(&(key, value)) {
+            println!(
+                "Workspace '{}' ({}) already has {} = {}",
+                ws_name, ws_id, key, value
+            );
+        }
+    }
+
+    // 5. If everything is already current, return early
+    if tf_version_to_set.is_none() && project_to_set.is_none() && settings_to_set.is_empty() {
         return Ok(());
     }
 
-    // 5. Build combined confirmation prompt
+    // 6. Build combined confirmation prompt
     let mut changes = Vec::new();
     if let Some(ref tf_ver) = tf_version_to_set {
         changes.push(format!(
@@ -107,6 +141,22 @@ pub async fn run_set_ws_command(
         ));
     }
 
+    for ((key, current), (_, new)) in current_settings
+        .entries()
+        .into_iter()
+        .zip(settings_to_set.entries())
+    {
+        if let Some(new) = new {
+            let current = current.map(|c| c.to_string());
+            changes.push(format!(
+                "{}: {} → {}",
+                key,
+                current.as_deref().unwrap_or("unknown"),
+                new
+            ));
+        }
+    }
+
     let prompt = format!(
         "Update workspace '{}' ({}):\n  {}\nContinue?",
         ws_name,
@@ -114,23 +164,24 @@ pub async fn run_set_ws_command(
         changes.join("\n  ")
     );
 
-    if !confirm_action(&prompt, args.yes || cli.batch)? {
+    if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
         println!("Cancelled");
         return Ok(());
     }
 
-    // 6. Execute single PATCH via update_workspace
+    // 7. Execute single PATCH via update_workspace
     let spinner = create_spinner(&format!("Updating workspace '{}'...", ws_name), cli.batch);
     client
         .update_workspace(
             ws_id,
             tf_version_to_set.as_deref(),
             project_to_set.as_ref().map(|(id, _, _)| id.as_str()),
+            &settings_to_set,
         )
         .await?;
     finish_spinner(spinner);
 
-    // 7. Print success messages
+    // 8. Print success messages
     if let Some(ref tf_ver) = tf_version_to_set {
         println!(
             "✓ Workspace '{}' ({}) terraform version set to '{}' ({})",
@@ -143,6 +194,335 @@ pub async fn run_set_ws_command(
             ws_name, ws_id, prj_name, prj_id, org
         );
     }
+    for (key, value) in settings_to_set.api_attributes() {
+        println!(
+            "✓ Workspace '{}' ({}) {} set to {} ({})",
+            ws_name, ws_id, key, value, org
+        );
+    }
 
     Ok(())
 }
+
+/// Set the Terraform version on every workspace matching `--filter`
+async fn run_bulk_set_tf_version(
+    client: &TfeClient,
+    cli: &Cli,
+    args: &SetWsArgs,
+    filter: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(tf_version) = args.terraform_version.as_deref() else {
+        unreachable!("clap requires --terraform-version with --filter")
+    };
+    let org = client
+        .effective_org(args.org.as_ref())
+        .ok_or("Organization is required (--org)")?;
+
+    let spinner = create_spinner(
+        &format!("Fetching workspaces matching '{}' in '{}'...", filter, org),
+        cli.batch,
+    );
+    let result = client
+        .with_progress(spinner.as_ref())
+        .list_workspaces(&WorkspaceFilter::new().org(&org).name_contains(filter))
+        .await;
+    finish_spinner(spinner);
+    let matched = result?;
+
+    debug!(
+        "Filter '{}' matched {} workspace(s) in '{}'",
+        filter,
+        matched.len(),
+        org
+    );
+    if matched.is_empty() {
+        return Err(format!(
+            "No workspaces match filter '{}' in organization '{}'",
+            filter, org
+        )
+        .into());
+    }
+
+    let (current, workspaces): (Vec<_>, Vec<_>) = matched
+        .into_iter()
+        .partition(|ws| ws.terraform_version() == tf_version);
+    for ws in &current {
+        println!(
+            "Workspace '{}' ({}) already has terraform version '{}'",
+            ws.name(),
+            ws.id,
+            tf_version
+        );
+    }
+    if workspaces.is_empty() {
+        return Ok(());
+    }
+
+    let dry_run_prefix = if args.dry_run { "[DRY-RUN] " } else { "" };
+    println!();
+    println!(
+        "{}The following workspaces will be updated in '{}':",
+        dry_run_prefix, org
+    );
+    println!();
+    output_tf_version_changes_table(&workspaces, tf_version);
+    println!();
+
+    let prompt = format!(
+        "{}Set terraform version '{}' on {} workspace(s)?",
+        dry_run_prefix,
+        tf_version,
+        workspaces.len()
+    );
+    if !confirm_action(&prompt, args.yes || cli.batch, cli.prompt_timeout).await? {
+        println!("\nAborted.");
+        return Ok(());
+    }
+
+    println!();
+    let mut success_count = 0;
+    let mut error_count = 0;
+    for ws in &workspaces {
+        if args.dry_run {
+            println!(
+                "[DRY-RUN] Would set terraform version on workspace: {} ({})",
+                ws.name(),
+                ws.id
+            );
+            success_count += 1;
+            continue;
+        }
+        let spinner = create_spinner(&format!("Updating workspace '{}'...", ws.name()), cli.batch);
+        let result = client
+            .update_workspace(
+                &ws.id,
+                Some(tf_version),
+                None,
+                &WorkspaceSettings::default(),
+            )
+            .await;
+        finish_spinner(spinner);
+        match result {
+            Ok(_) => {
+                println!(
+                    "✓ Workspace '{}' ({}) terraform version set to '{}'",
+                    ws.name(),
+                    ws.id,
+                    tf_version
+                );
+                success_count += 1;
+            }
+            Err(e) => {
+                eprintln!(
+                    "✗ Failed to update workspace '{}' ({}): {}",
+                    ws.name(),
+                    ws.id,
+                    e
+                );
+                error_count += 1;
+                // Stop on first error, like purge run
+                break;
+            }
+        }
+    }
+
+    println!();
+    if args.dry_run {
+        println!("Dry-run complete. No changes were made.");
+    } else if error_count > 0 {
+        println!(
+            "Processed {} of {} workspaces. {} succeeded, {} failed.",
+            success_count + error_count,
+            workspaces.len(),
+            success_count,
+            error_count
+        );
+    } else {
+        println!("All {} workspaces updated successfully.", success_count);
+    }
+    Ok(())
+}
+
+/// Display the workspaces about to get a new Terraform version
+fn output_tf_version_changes_table(workspaces: &[Workspace], tf_version: &str) {
+    use comfy_table::{presets::UTF8_FULL_CONDENSED, Table};
+
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_header(vec![
+        "Workspace",
+        "Workspace ID",
+        "Current Version",
+        "New Version",
+    ]);
+
+    for ws in workspaces {
+        table.add_row(vec![
+            ws.name(),
+            ws.id.as_str(),
+            ws.terraform_version(),
+            tf_version,
+        ]);
+    }
+
+    crate::output::print_table(&table, &OutputFormat::Table);
+}
+
+/// API calls made by `set ws` for the effective organization
+fn plan_set_ws(args: &SetWsArgs, org: Option<&String>) -> Vec<PlannedCall> {
+    if let Some(filter) = &args.filter {
+        return vec![
+            call(
+                "GET",
+                format!(
+                    "/organizations/{}/workspaces?search[name]={}",
+                    org_path(org),
+                    urlencoding::encode(filter)
+                ),
+                "matching workspaces",
+            ),
+            call("PATCH", "/workspaces/:ws_id", "per workspace"),
+        ];
+    }
+    let workspace = args.workspace.as_deref().unwrap_or_default();
+    let mut calls = workspace_lookup(workspace, org);
+    if args.project.is_some() {
+        calls.push(call(
+            "GET",
+            "/organizations/:org/projects",
+            "resolve project in the workspace's organization",
+        ));
+    }
+    calls.push(call("PATCH", "/workspaces/:ws_id", "update workspace"));
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn ws_json(id: &str, name: &str, tf_version: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "type": "workspaces",
+            "attributes": {"name": name, "terraform-version": tf_version}
+        })
+    }
+
+    async fn mount_workspaces(server: &MockServer, workspaces: Vec<serde_json::Value>) {
+        Mock::given(method("GET"))
+            .and(path("/organizations/my-org/workspaces"))
+            .and(query_param("search[name]", "prod"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": workspaces,
+                "meta": {"pagination": {
+                    "current-page": 1, "total-pages": 1, "total-count": 2, "page-size": 100
+                }}
+            })))
+            .mount(server)
+            .await;
+    }
+
+    async fn mount_update(server: &MockServer, ws_id: &str, status: u16, expected: u64) {
+        Mock::given(method("PATCH"))
+            .and(path(format!("/workspaces/{}", ws_id)))
+            .respond_with(
+                ResponseTemplate::new(status)
+                    .set_body_json(serde_json::json!({"data": ws_json(ws_id, "x", "1.9.5")})),
+            )
+            .expect(expected)
+            .mount(server)
+            .await;
+    }
+
+    async fn set_ws(server: &MockServer, extra: &[&str]) -> Result<(), String> {
+        let client = TfeClient::test_client(&server.uri());
+        let mut argv = vec!["hcp", "set", "ws", "--org", "my-org", "-f", "prod"];
+        argv.extend_from_slice(&["--tf-version", "1.9.5", "-y"]);
+        argv.extend_from_slice(extra);
+        let cli = Cli::parse_from(argv);
+        run_set_ws_command(&client, &cli)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_bulk_set_tf_version_skips_current_workspaces() {
+        let server = MockServer::start().await;
+        mount_workspaces(
+            &server,
+            vec![
+                ws_json("ws-1", "app-prod", "1.5.0"),
+                ws_json("ws-2", "db-prod", "1.9.5"),
+            ],
+        )
+        .await;
+        mount_update(&server, "ws-1", 200, 1).await;
+        mount_update(&server, "ws-2", 200, 0).await;
+
+        set_ws(&server, &[]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_bulk_set_tf_version_dry_run_does_not_patch() {
+        let server = MockServer::start().await;
+        mount_workspaces(&server, vec![ws_json("ws-1", "app-prod", "1.5.0")]).await;
+        mount_update(&server, "ws-1", 200, 0).await;
+
+        set_ws(&server, &["--dry-run"]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_bulk_set_tf_version_stops_on_first_error() {
+        let server = MockServer::start().await;
+        mount_workspaces(
+            &server,
+            vec![
+                ws_json("ws-1", "app-prod", "1.5.0"),
+                ws_json("ws-2", "db-prod", "1.5.0"),
+            ],
+        )
+        .await;
+        mount_update(&server, "ws-1", 422, 1).await;
+        mount_update(&server, "ws-2", 200, 0).await;
+
+        set_ws(&server, &[]).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_bulk_set_tf_version_without_matches() {
+        let server = MockServer::start().await;
+        mount_workspaces(&server, vec![]).await;
+
+        let err = set_ws(&server, &[]).await.unwrap_err();
+        assert!(
+            err.contains("No workspaces match filter 'prod' in organization 'my-org'"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_plan_set_ws_bulk_terraform_version() {
+        let cli = Cli::parse_from(["hcp", "set", "ws", "-f", "prod", "--tf-version", "1"]);
+        let Command::Set {
+            resource: SetResource::Ws(args),
+        } = &cli.command
+        else {
+            unreachable!()
+        };
+        let calls = plan_set_ws(args, Some(&"o".to_string()));
+        let paths: Vec<_> = calls.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/organizations/o/workspaces?search[name]=prod",
+                "/workspaces/:ws_id"
+            ]
+        );
+        assert_eq!(calls[1].method, "PATCH");
+    }
+}
@@ -39,31 +39,46 @@ pub mod ui;
 pub mod update;
 
 pub use cli::{
-    Cli, Command, ConfigAction, DeleteContextArgs, DeleteOrgMemberArgs, DeleteResource,
+    arg_given, completion_script, ApplyRunArgs, AssessmentArgs, CacheAction, Cli, ColorChoice,
+    Command, CompleteArgs, CompleteResource, CompletionArgs, ConfigAction, CreateOcArgs,
+    CreateResource, DeleteContextArgs, DeleteOcArgs, DeleteOrgMemberArgs, DeleteResource,
     DeleteTagPrjArgs, DeleteTagResource, DeleteTagWsArgs, DownloadConfigArgs, DownloadResource,
-    GetResource, GetTagArgs, GetTagPrjArgs, GetTagResource, GetTagWsArgs, InviteArgs, LogsArgs,
-    OcArgs, OrgArgs, OrgMemberArgs, OutputFormat, PrjArgs, PrjSortField, PurgeResource,
-    PurgeRunArgs, PurgeStateArgs, RunArgs, RunSortField, RunSubresource, SetContextArgs,
-    SetResource, SetTagPrjArgs, SetTagResource, SetTagWsArgs, SetWsArgs, TeamAccessArgs,
-    TeamAccessSortField, TeamArgs, UseContextArgs, WatchResource, WatchWsArgs, WsArgs, WsSortField,
-    WsSubresource,
+    ExportContextsArgs, GetResource, GetTagArgs, GetTagPrjArgs, GetTagResource, GetTagWsArgs,
+    GraphArgs, GraphFormat, ImportContextsArgs, InviteArgs, LockArgs, LogsArgs,
+    NotificationConfigArgs, OcArgs, OrgArgs, OrgMemberArgs, OutputArgs, OutputFormat,
+    OverrideRunArgs, PolicySetArgs, PrjArgs, PrjSortField, PurgeResource, PurgeRunArgs,
+    PurgeStateArgs, RegistryModuleArgs, RenameContextArgs, RunAction, RunArgs, RunCountField,
+    RunSortField, RunSubresource, RunTriggerArgs, SetContextArgs, SetResource, SetTagPrjArgs,
+    SetTagResource, SetTagWsArgs, SetWsArgs, StateArgs, TeamAccessArgs, TeamAccessSortField,
+    TeamArgs, UnlockArgs, UseContextArgs, VarArgs, VarCategory, VarsetArgs, WatchResource,
+    WatchWsArgs, WhoamiArgs, WsArgs, WsCountField, WsSortField, WsSubresource,
 };
 pub use context::{
-    resolve_active_context, run_context_command, Context, ContextConfig, ContextStore,
+    apply_context_defaults, resolve_active_context, resolve_profile, run_context_command, Context,
+    ContextConfig, ContextExport, ContextStore,
 };
-pub use error::{Result, TfeError};
+pub use error::{ApiErrorDetail, Result, TfeError};
 pub use hcp::{
+    print_explain, run_apply_run_command, run_assessment_command, run_cache_command,
+    run_complete_command, run_create_oc_command, run_delete_oc_command,
     run_delete_org_member_command, run_delete_tag_command, run_download_config_command,
-    run_get_tag_command, run_invite_command, run_logs_command, run_oc_command, run_org_command,
-    run_org_member_command, run_prj_command, run_purge_run_command, run_purge_state_command,
-    run_runs_command, run_set_tag_command, run_set_ws_command, run_team_access_command,
-    run_team_command, run_watch_ws_command, run_ws_command, HostResolver, OAuthClient,
-    Organization, Project, Run, Team, TfeClient, TfeResource, TokenResolver, Workspace,
+    run_get_output_command, run_get_state_command, run_get_tag_command, run_graph_command,
+    run_invite_command, run_lock_command, run_logs_command, run_notification_config_command,
+    run_oc_command, run_org_command, run_org_member_command, run_override_run_command,
+    run_policy_set_command, run_prj_command, run_purge_run_command, run_purge_state_command,
+    run_registry_module_command, run_run_trigger_command, run_runs_command, run_set_tag_command,
+    run_set_ws_command, run_team_access_command, run_team_command, run_unlock_command,
+    run_var_command, run_varset_command, run_watch_ws_command, run_whoami_command, run_ws_command,
+    HostResolver, NotificationConfig, OAuthClient, Organization, PageRequest, PolicySet, Project,
+    ResolvedToken, Run, RunTrigger, Team, TfeClient, TfeResource, TlsConfig, TokenResolver,
+    TokenSource, Variable, Workspace, WorkspaceFilter,
 };
 pub use output::{
-    output_oauth_clients, output_org_tags, output_org_tags_with_workspaces, output_organizations,
-    output_projects, output_results_sorted, output_runs, output_state_versions,
-    output_tag_bindings, output_team_access, output_workspace_all_tags, WorkspaceRow,
+    output_assessments, output_graph, output_notification_configs, output_oauth_clients,
+    output_org_tags, output_org_tags_with_workspaces, output_organizations, output_policy_sets,
+    output_projects, output_registry_modules, output_results_sorted, output_run_triggers,
+    output_runs, output_state_version_outputs, output_state_versions, output_tag_bindings,
+    output_team_access, output_whoami, output_workspace_all_tags, WorkspaceRow,
 };
 pub use ui::{confirm_large_pagination, LargePaginationInfo};
 pub use update::{run_update, UpdateChecker, UpdateHandle};
@@ -1,18 +1,26 @@
 //! HCPCTL - Main entry point
 
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches};
 use log::info;
+use std::io::Write;
 use std::process::ExitCode;
 
+use hcpctl::output::{stderr_color_enabled, RenderOptions};
 use hcpctl::{
-    resolve_active_context, run_context_command, run_delete_org_member_command,
-    run_delete_tag_command, run_download_config_command, run_get_tag_command, run_invite_command,
-    run_logs_command, run_oc_command, run_org_command, run_org_member_command, run_prj_command,
-    run_purge_run_command, run_purge_state_command, run_runs_command, run_set_tag_command,
-    run_set_ws_command, run_team_access_command, run_team_command, run_update,
-    run_watch_ws_command, run_ws_command, Cli, Command, DeleteResource, DownloadResource,
-    GetResource, HostResolver, PurgeResource, SetResource, TfeClient, TokenResolver, UpdateChecker,
-    WatchResource,
+    apply_context_defaults, arg_given, completion_script, resolve_active_context, resolve_profile,
+    run_apply_run_command, run_assessment_command, run_cache_command, run_complete_command,
+    run_context_command, run_create_oc_command, run_delete_oc_command,
+    run_delete_org_member_command, run_delete_tag_command, run_download_config_command,
+    run_get_output_command, run_get_state_command, run_get_tag_command, run_graph_command,
+    run_invite_command, run_lock_command, run_logs_command, run_notification_config_command,
+    run_oc_command, run_org_command, run_org_member_command, run_override_run_command,
+    run_policy_set_command, run_prj_command, run_purge_run_command, run_purge_state_command,
+    run_registry_module_command, run_run_trigger_command, run_runs_command, run_set_tag_command,
+    run_set_ws_command, run_team_access_command, run_team_command, run_unlock_command, run_update,
+    run_var_command, run_varset_command, run_watch_ws_command, run_whoami_command, run_ws_command,
+    Cli, Command, CreateResource, DeleteResource, DownloadResource, GetResource, HostResolver,
+    OutputFormat, PurgeResource, ResolvedToken, RunAction, SetResource, TfeClient, TlsConfig,
+    TokenResolver, UpdateChecker, WatchResource,
 };
 
 #[tokio::main]
@@ -31,7 +39,8 @@ async fn main() -> ExitCode {
 }
 
 async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
     // Initialize logging
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(&cli.log_level))
@@ -39,40 +48,141 @@ async fn run() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("Starting HCP CLI v{}", env!("CARGO_PKG_VERSION"));
 
+    // Resolve active context (--profile must name an existing one); its output
+    // format and color apply unless the matching flags were given explicitly
+    let active_context = match cli.profile.as_deref() {
+        Some(name) => Some(resolve_profile(name)?),
+        None => resolve_active_context(cli.context.as_deref()),
+    };
+    if let Some(ctx) = &active_context {
+        apply_context_defaults(&mut cli, &matches, ctx);
+    }
+
+    if cli.command.output_format() == Some(&OutputFormat::Template) && cli.template.is_none() {
+        return Err("-o template requires --template, e.g. --template '{{.name}}'".into());
+    }
+    if cli.all_orgs && arg_given(&matches, "org") {
+        return Err("--all-orgs cannot be combined with --org".into());
+    }
+    hcpctl::output::set_render_options(RenderOptions::from_cli(&cli)?);
+    // The commands handled before the credential lookup print straight to stdout
+    if cli.output_file.is_some()
+        && matches!(
+            cli.command,
+            Command::Completion(_)
+                | Command::Complete(_)
+                | Command::Update
+                | Command::Config { .. }
+                | Command::Cache { .. }
+        )
+    {
+        return Err(
+            "--output-file is not supported by completion, update, config and cache".into(),
+        );
+    }
+    if cli.output_file.is_some() && cli.command.streams_to_terminal() {
+        return Err(
+            "--output-file is not supported by watch, purge, logs --follow and --tail-log".into(),
+        );
+    }
+    if let Some(path) = &cli.output_file {
+        hcpctl::output::set_output_file(Some(path))
+            .map_err(|e| format!("Cannot create output file '{}': {}", path.display(), e))?;
+    }
+
+    // Handle completion command early (doesn't require TFE credentials)
+    if let Command::Completion(args) = &cli.command {
+        std::io::stdout().write_all(completion_script(args.shell).as_bytes())?;
+        return Ok(());
+    }
+
+    // Dynamic completion resolves credentials itself and never reports errors
+    if let Command::Complete(args) = &cli.command {
+        run_complete_command(&cli, args).await;
+        return Ok(());
+    }
+
     // Handle update command early (doesn't require TFE credentials)
     if matches!(cli.command, Command::Update) {
-        return run_update().await;
+        return run_update(cli.proxy.as_deref()).await;
     }
 
     // Handle config command early (doesn't require TFE credentials)
     if let Command::Config { action } = &cli.command {
-        return run_context_command(action);
+        let cli_context = cli.profile.as_deref().or(cli.context.as_deref());
+        return run_context_command(action, cli_context);
+    }
+
+    // Handle cache command early (doesn't require TFE credentials)
+    if let Command::Cache { action } = &cli.command {
+        return run_cache_command(action);
     }
 
-    // Start background update check (non-blocking, only in interactive mode)
-    let update_handle = if !cli.batch {
-        UpdateChecker::new().check_async()
+    // Start background update check (non-blocking, skipped in batch, quiet and explain modes)
+    let update_handle = if !cli.quiet && !cli.explain {
+        UpdateChecker::new()
+            .with_proxy(cli.proxy.clone())
+            .with_check_disabled(cli.batch || cli.no_update_check)
+            .check_async()
     } else {
         None
     };
 
-    // Resolve active context
-    let active_context = resolve_active_context(cli.context.as_deref());
-
     // Resolve host with fallback logic (CLI -> env var -> context -> credentials file)
     // In batch mode, error on multiple hosts instead of interactive selection
+    // --explain only prints the handlers' call plans (relative paths), so it
+    // needs no credentials
     let context_host = active_context.as_ref().map(|c| c.host.as_str());
-    let host = HostResolver::resolve(cli.host.as_deref(), context_host, cli.batch)?;
+    let (host, token) = if cli.explain {
+        let host = cli.host.as_deref().or(context_host).unwrap_or_default();
+        (host.to_string(), None)
+    } else {
+        let host = HostResolver::resolve(
+            cli.host.as_deref(),
+            context_host,
+            cli.batch,
+            cli.prompt_timeout,
+        )
+        .await?;
 
-    // Resolve token with fallback logic
-    let context_token = active_context.as_ref().and_then(|c| c.token.as_deref());
-    let token_resolver = TokenResolver::new(&host);
-    let token = token_resolver.resolve(cli.token.as_deref(), context_token)?;
+        // Resolve token with fallback logic
+        let context_token = active_context.as_ref().and_then(|c| c.token.as_deref());
+        let token_resolver = TokenResolver::new(&host);
+        let token = token_resolver.resolve(cli.token.as_deref(), context_token)?;
+        (host, Some(token))
+    };
+    let token_source = token.as_ref().map(|t| t.source.clone());
 
     // Create TFE client with batch mode setting and context org
-    let context_org = active_context.as_ref().and_then(|c| c.org.clone());
-    let mut client = TfeClient::new(token, host);
+    // (org precedence: --org > --all-orgs > context org > every org)
+    let context_org = active_context
+        .as_ref()
+        .and_then(|c| c.org.clone())
+        .filter(|_| !cli.all_orgs);
+    let tls = TlsConfig {
+        ca_cert: cli.ca_cert.clone(),
+        client_cert: cli.client_cert.clone(),
+        client_key: cli.client_key.clone(),
+    };
+    let mut client = TfeClient::new(
+        token.map(ResolvedToken::into_value).unwrap_or_default(),
+        host,
+        Some(&tls),
+        cli.proxy.as_deref(),
+    )?;
     client.set_batch_mode(cli.batch);
+    client.set_quiet(cli.quiet);
+    client.set_prompt_timeout(cli.prompt_timeout);
+    client.set_conditional_requests(cli.conditional_requests);
+    client.set_debug_http(cli.debug_http);
+    client.set_retry_on_503(cli.retry_on_503);
+    client.set_retry_idempotent_only(cli.retry_idempotent_only);
+    client.set_max_retries(cli.max_retries);
+    client.set_concurrency(cli.concurrency);
+    client.set_api_version(&cli.api_version);
+    if let Some(ttl) = cli.disk_cache_ttl() {
+        client.enable_disk_cache(ttl);
+    }
     client.set_context_org(context_org);
 
     let result = match &cli.command {
@@ -86,11 +196,26 @@ async fn run() -> Result<(), Box<dyn std::error::Error>> {
             GetResource::TeamAccess(_) => run_team_access_command(&client, &cli).await,
             GetResource::OrgMember(_) => run_org_member_command(&client, &cli).await,
             GetResource::Tag(_) => run_get_tag_command(&client, &cli).await,
+            GetResource::Var(_) => run_var_command(&client, &cli).await,
+            GetResource::Varset(_) => run_varset_command(&client, &cli).await,
+            GetResource::PolicySet(_) => run_policy_set_command(&client, &cli).await,
+            GetResource::NotificationConfig(_) => {
+                run_notification_config_command(&client, &cli).await
+            }
+            GetResource::RunTrigger(_) => run_run_trigger_command(&client, &cli).await,
+            GetResource::Assessment(_) => run_assessment_command(&client, &cli).await,
+            GetResource::RegistryModule(_) => run_registry_module_command(&client, &cli).await,
+            GetResource::State(_) => run_get_state_command(&client, &cli).await,
+            GetResource::Output(_) => run_get_output_command(&client, &cli).await,
+        },
+        Command::Create { resource } => match resource {
+            CreateResource::Oc(_) => run_create_oc_command(&client, &cli).await,
         },
         Command::Delete { resource } => match resource {
             DeleteResource::OrgMember(args) => {
                 run_delete_org_member_command(&client, &cli, args).await
             }
+            DeleteResource::Oc(_) => run_delete_oc_command(&client, &cli).await,
             DeleteResource::Tag { .. } => run_delete_tag_command(&client, &cli).await,
         },
         Command::Purge { resource } => match resource {
@@ -98,25 +223,59 @@ async fn run() -> Result<(), Box<dyn std::error::Error>> {
             PurgeResource::Run(_) => run_purge_run_command(&client, &cli).await,
         },
         Command::Logs(args) => run_logs_command(&client, &cli, args).await,
+        Command::Run { action } => match action {
+            RunAction::Apply(_) => run_apply_run_command(&client, &cli).await,
+            RunAction::Override(_) => run_override_run_command(&client, &cli).await,
+        },
         Command::Watch { resource } => match resource {
             WatchResource::Ws(args) => run_watch_ws_command(&client, &cli, args).await,
         },
         Command::Download { resource } => match resource {
             DownloadResource::Config(_) => run_download_config_command(&client, &cli).await,
         },
+        Command::Graph(args) => run_graph_command(&client, &cli, args).await,
         Command::Invite(args) => run_invite_command(&client, &cli, args).await,
+        Command::Lock(args) => run_lock_command(&client, &cli, args).await,
+        Command::Unlock(args) => run_unlock_command(&client, &cli, args).await,
+        Command::Whoami(_) => run_whoami_command(&client, &cli, token_source.as_ref()).await,
         Command::Set { resource } => match resource {
             SetResource::Ws(_) => run_set_ws_command(&client, &cli).await,
             SetResource::Tag { .. } => run_set_tag_command(&client, &cli).await,
         },
         Command::Update => unreachable!(),        // Handled above
+        Command::Completion(_) => unreachable!(), // Handled above
+        Command::Complete(_) => unreachable!(),   // Handled above
         Command::Config { .. } => unreachable!(), // Handled above
+        Command::Cache { .. } => unreachable!(),  // Handled above
+    };
+    let result = match result {
+        Ok(()) if !cli.explain => hcpctl::output::check_jsonpath_matched().map_err(Into::into),
+        result => result,
+    };
+    // A failed command keeps any existing file unless it already wrote output
+    let result = match result {
+        Ok(()) => hcpctl::output::finish_output_file().map_err(|e| {
+            let path = cli.output_file.clone().unwrap_or_default();
+            format!("Cannot write output file '{}': {}", path.display(), e).into()
+        }),
+        Err(e) => {
+            let _ = hcpctl::output::set_output_file(None);
+            Err(e)
+        }
     };
+    if let Ok(Some((path, bytes))) = &result {
+        if !cli.quiet {
+            eprintln!("Wrote {} bytes to {}", bytes, path.display());
+        }
+    }
+    let result = result.map(|_| ());
 
     // Show update notification if available (non-blocking check completed)
     if let Some(handle) = update_handle {
         if let Some(msg) = handle.get() {
-            eprintln!("{}", msg);
+            if !cli.no_update_notice {
+                UpdateChecker::new().show_notice(&msg, stderr_color_enabled(&cli));
+            }
         }
     }
 
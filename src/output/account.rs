@@ -0,0 +1,177 @@
+//! Whoami output formatter
+
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, header_record, write_record, Formatter, RenderOptions};
+use crate::cli::{Cli, Command, OutputFormat};
+use crate::hcp::Whoami;
+
+/// Output the authenticated identity in the format selected with `-o`
+pub fn output_whoami(whoami: &Whoami, cli: &Cli) {
+    let Command::Whoami(args) = &cli.command else {
+        unreachable!()
+    };
+
+    if cli.id_only {
+        super::common::print_ids(std::slice::from_ref(whoami));
+        return;
+    }
+
+    WhoamiFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+    }
+    .print(std::slice::from_ref(whoami));
+}
+
+/// Whoami formatter; tables list one field per line
+pub struct WhoamiFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+}
+
+impl Formatter for WhoamiFormatter {
+    type Row = Whoami;
+
+    fn format_to<W: Write>(
+        &self,
+        rows: &[Whoami],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => rows
+                .iter()
+                .try_for_each(|w| output_table(writer, w, self.no_header, &self.format, options)),
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(rows, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            // A single identity is an object, not a one-element list
+            _ => rows.iter().try_for_each(|w| {
+                super::common::write_raw(
+                    writer,
+                    &serde_json::json!({ "data": w }),
+                    &self.format,
+                    options,
+                )
+            }),
+        }
+    }
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    whoami: &Whoami,
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
+    if !no_header {
+        table.set_header(vec!["Field", "Value"]);
+    }
+    let username = if whoami.service_account {
+        format!("{} (service account)", whoami.username)
+    } else {
+        whoami.username.clone()
+    };
+    table.add_row(vec!["Username".to_string(), username]);
+    table.add_row(vec![
+        "Email".to_string(),
+        whoami.email.clone().unwrap_or_else(|| "-".to_string()),
+    ]);
+    table.add_row(vec!["User ID".to_string(), whoami.user_id.clone()]);
+    table.add_row(vec!["Host".to_string(), whoami.host.clone()]);
+    table.add_row(vec![
+        "Token source".to_string(),
+        whoami.token_source.clone(),
+    ]);
+    if let Some(org) = &whoami.org {
+        table.add_row(vec!["Default org".to_string(), org.clone()]);
+    }
+
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)
+}
+
+fn csv_records(rows: &[Whoami], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(rows.len() + 1);
+    if !no_header {
+        records.push(header_record(&[
+            "user_id",
+            "username",
+            "email",
+            "service_account",
+            "host",
+            "token_source",
+            "org",
+        ]));
+    }
+    for w in rows {
+        records.push(vec![
+            w.user_id.clone(),
+            w.username.clone(),
+            w.email.clone().unwrap_or_default(),
+            w.service_account.to_string(),
+            w.host.clone(),
+            w.token_source.clone(),
+            w.org.clone().unwrap_or_default(),
+        ]);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn whoami() -> Whoami {
+        Whoami {
+            user_id: "user-abc".to_string(),
+            username: "alice".to_string(),
+            email: Some("alice@example.com".to_string()),
+            service_account: false,
+            host: "app.terraform.io".to_string(),
+            token_source: "HCP_TOKEN environment variable".to_string(),
+            org: Some("acme".to_string()),
+        }
+    }
+
+    fn render(format: OutputFormat) -> String {
+        let mut buf = Vec::new();
+        WhoamiFormatter {
+            format,
+            no_header: false,
+        }
+        .format_to(&[whoami()], &mut buf, &RenderOptions::default())
+        .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_table_output() {
+        let out = render(OutputFormat::Table);
+        assert!(out.contains("alice@example.com"));
+        assert!(out.contains("HCP_TOKEN environment variable"));
+        assert!(out.contains("Default org"));
+        assert!(!out.contains("Total:"));
+    }
+
+    #[test]
+    fn test_json_output_is_object() {
+        let json: serde_json::Value = serde_json::from_str(&render(OutputFormat::Json)).unwrap();
+        assert_eq!(json["username"], "alice");
+        assert_eq!(json["host"], "app.terraform.io");
+        assert_eq!(json["token_source"], "HCP_TOKEN environment variable");
+    }
+
+    #[test]
+    fn test_csv_output() {
+        assert_eq!(
+            render(OutputFormat::Csv),
+            "user_id,username,email,service_account,host,token_source,org\n\
+             user-abc,alice,alice@example.com,false,app.terraform.io,\
+             HCP_TOKEN environment variable,acme\n"
+        );
+    }
+}
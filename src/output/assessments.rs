@@ -0,0 +1,311 @@
+//! Assessment output formatter
+
+use std::io::{self, Write};
+
+use super::common::{
+    csv_delimiter, header_record, table_time, write_record, Formatter, RenderOptions,
+};
+use crate::cli::{Cli, Command, GetResource, OutputFormat};
+use crate::hcp::{AssessmentStatus, WorkspaceAssessment};
+use serde::Serialize;
+
+/// Serializable workspace assessment for structured output (JSON/YAML)
+#[derive(Serialize)]
+struct SerializableAssessment {
+    workspace_id: String,
+    workspace_name: String,
+    status: &'static str,
+    drifted: Option<bool>,
+    succeeded: Option<bool>,
+    error_msg: Option<String>,
+    assessed_at: Option<String>,
+    result_id: Option<String>,
+}
+
+impl From<&WorkspaceAssessment> for SerializableAssessment {
+    fn from(a: &WorkspaceAssessment) -> Self {
+        let attrs = a.result.as_ref().map(|r| &r.attributes);
+        Self {
+            workspace_id: a.workspace_id.clone(),
+            workspace_name: a.workspace_name.clone(),
+            status: a.status().as_str(),
+            drifted: attrs.and_then(|at| at.drifted),
+            succeeded: attrs.and_then(|at| at.succeeded),
+            error_msg: a.error_msg().map(str::to_string),
+            assessed_at: attrs.and_then(|at| at.created_at.clone()),
+            result_id: a.result.as_ref().map(|r| r.id.clone()),
+        }
+    }
+}
+
+/// Output workspace assessments in the specified format
+pub fn output_assessments(assessments: &[WorkspaceAssessment], cli: &Cli) {
+    let Command::Get {
+        resource: GetResource::Assessment(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if cli.id_only {
+        super::common::print_ids(assessments);
+        return;
+    }
+
+    AssessmentFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+    }
+    .print(assessments);
+}
+
+/// Workspace assessment formatter
+pub struct AssessmentFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+}
+
+impl Formatter for AssessmentFormatter {
+    type Row = WorkspaceAssessment;
+
+    fn format_to<W: Write>(
+        &self,
+        assessments: &[WorkspaceAssessment],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_table(writer, assessments, self.no_header, &self.format, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(assessments, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            OutputFormat::Json => {
+                super::common::write_json(writer, &serializable(assessments), options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable(assessments)),
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                assessments.iter().map(SerializableAssessment::from),
+                &self.format,
+                options,
+            ),
+        }
+    }
+}
+
+fn serializable(assessments: &[WorkspaceAssessment]) -> Vec<SerializableAssessment> {
+    assessments
+        .iter()
+        .map(SerializableAssessment::from)
+        .collect()
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    assessments: &[WorkspaceAssessment],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
+    if !no_header {
+        table.set_header(vec![
+            "Workspace",
+            "Workspace ID",
+            "Status",
+            "Last Run",
+            "Error",
+        ]);
+    }
+
+    for a in assessments {
+        let result = a.result.as_ref();
+        table.add_row(vec![
+            super::common::table_name(&a.workspace_name, options),
+            a.workspace_id.clone(),
+            a.status().as_str().to_string(),
+            table_time(result.map_or("", |r| r.created_at()), options),
+            a.error_msg().unwrap_or_default().to_string(),
+        ]);
+    }
+
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) {
+        let count = |status| assessments.iter().filter(|a| a.status() == status).count();
+        let unavailable = match count(AssessmentStatus::Unavailable) {
+            0 => String::new(),
+            n => format!(", {} unavailable", n),
+        };
+        writeln!(
+            writer,
+            "\nTotal: {} workspaces ({} drifted, {} errored, {} ok, {} not yet assessed{})",
+            assessments.len(),
+            count(AssessmentStatus::Drifted),
+            count(AssessmentStatus::Errored),
+            count(AssessmentStatus::Ok),
+            count(AssessmentStatus::None),
+            unavailable
+        )?;
+    }
+    Ok(())
+}
+
+fn csv_records(assessments: &[WorkspaceAssessment], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(assessments.len() + 1);
+    if !no_header {
+        records.push(header_record(&[
+            "workspace_id",
+            "workspace_name",
+            "status",
+            "assessed_at",
+            "error_msg",
+        ]));
+    }
+    for a in assessments {
+        let result = a.result.as_ref();
+        records.push(vec![
+            a.workspace_id.clone(),
+            a.workspace_name.clone(),
+            a.status().as_str().to_string(),
+            result.map_or("", |r| r.created_at()).to_string(),
+            a.error_msg().unwrap_or_default().to_string(),
+        ]);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assessment(name: &str, attributes: Option<serde_json::Value>) -> WorkspaceAssessment {
+        WorkspaceAssessment {
+            workspace_id: format!("ws-{}", name),
+            workspace_name: name.to_string(),
+            result: attributes.map(|attributes| {
+                serde_json::from_value(serde_json::json!({
+                    "id": format!("asmtres-{}", name),
+                    "attributes": attributes
+                }))
+                .unwrap()
+            }),
+            fetch_error: None,
+        }
+    }
+
+    fn assessments() -> Vec<WorkspaceAssessment> {
+        vec![
+            assessment(
+                "network",
+                Some(serde_json::json!({
+                    "drifted": true,
+                    "succeeded": true,
+                    "created-at": "2024-05-01T12:00:00Z"
+                })),
+            ),
+            assessment(
+                "app",
+                Some(serde_json::json!({
+                    "drifted": false,
+                    "succeeded": false,
+                    "error-msg": "plan failed, x",
+                    "created-at": "2024-05-02T12:00:00Z"
+                })),
+            ),
+            assessment("new", None),
+        ]
+    }
+
+    fn render(format: OutputFormat, no_header: bool) -> String {
+        let mut buf = Vec::new();
+        AssessmentFormatter { format, no_header }
+            .format_to(&assessments(), &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_table_output() {
+        let out = render(OutputFormat::Table, false);
+        assert!(out.contains("Last Run"));
+        assert!(out.contains("drifted"));
+        assert!(out.contains("plan failed"));
+        assert!(out
+            .ends_with("\nTotal: 3 workspaces (1 drifted, 1 errored, 0 ok, 1 not yet assessed)\n"));
+        assert!(!render(OutputFormat::Table, true).contains("Total:"));
+    }
+
+    #[test]
+    fn test_csv_output() {
+        assert_eq!(
+            render(OutputFormat::Csv, false),
+            "workspace_id,workspace_name,status,assessed_at,error_msg\n\
+             ws-network,network,drifted,2024-05-01T12:00:00Z,\n\
+             ws-app,app,errored,2024-05-02T12:00:00Z,\"plan failed, x\"\n\
+             ws-new,new,none,,\n"
+        );
+        assert_eq!(render(OutputFormat::Csv, true).lines().count(), 3);
+    }
+
+    #[test]
+    fn test_tsv_output() {
+        let tsv = render(OutputFormat::Tsv, true);
+        assert_eq!(
+            tsv.lines().nth(1).unwrap(),
+            "ws-app\tapp\terrored\t2024-05-02T12:00:00Z\tplan failed, x"
+        );
+    }
+
+    #[test]
+    fn test_unavailable_output() {
+        let mut rows = assessments();
+        rows.push(WorkspaceAssessment {
+            fetch_error: Some("HTTP 500".to_string()),
+            ..assessment("broken", None)
+        });
+        let mut buf = Vec::new();
+        AssessmentFormatter {
+            format: OutputFormat::Table,
+            no_header: false,
+        }
+        .format_to(&rows, &mut buf, &RenderOptions::default())
+        .unwrap();
+        let table = String::from_utf8(buf).unwrap();
+        assert!(table.contains("unavailable"));
+        assert!(table.contains("HTTP 500"));
+        assert!(
+            table.ends_with("1 not yet assessed, 1 unavailable)\n"),
+            "{}",
+            table
+        );
+
+        let json = serde_json::to_value(SerializableAssessment::from(&rows[3])).unwrap();
+        assert_eq!(json["status"], "unavailable");
+        assert_eq!(json["error_msg"], "HTTP 500");
+        assert_eq!(json["drifted"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_json_output() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(OutputFormat::Json, false)).unwrap();
+        assert_eq!(
+            json[0],
+            serde_json::json!({
+                "workspace_id": "ws-network",
+                "workspace_name": "network",
+                "status": "drifted",
+                "drifted": true,
+                "succeeded": true,
+                "error_msg": null,
+                "assessed_at": "2024-05-01T12:00:00Z",
+                "result_id": "asmtres-network"
+            })
+        );
+        assert_eq!(json[2]["status"], "none");
+        assert_eq!(json[2]["result_id"], serde_json::Value::Null);
+    }
+}
@@ -0,0 +1,59 @@
+//! Color policy shared by table formatters and the update notice
+//!
+//! `--color auto` (default) colors only when the stream is a terminal, not in
+//! `--batch` mode and without `NO_COLOR` set; `always` and `never` override
+//! all of these. CSV/JSON/YAML output is never colored.
+
+use std::io::IsTerminal;
+
+use crate::cli::{Cli, ColorChoice};
+
+/// Resolve a color choice for a stream
+pub fn resolve_color(choice: ColorChoice, batch: bool, is_tty: bool, no_color_env: bool) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => is_tty && !batch && !no_color_env,
+    }
+}
+
+/// Whether a stream may be colored under `--color`/`--no-color` and `--batch`
+pub fn color_for(cli: &Cli, is_tty: bool) -> bool {
+    let choice = if cli.no_color {
+        ColorChoice::Never
+    } else {
+        cli.color
+    };
+    resolve_color(
+        choice,
+        cli.batch,
+        is_tty,
+        std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()),
+    )
+}
+
+/// Whether messages written to stderr (update notice) may be colored
+pub fn stderr_color_enabled(cli: &Cli) -> bool {
+    color_for(cli, std::io::stderr().is_terminal())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_always_and_never_ignore_environment() {
+        for (tty, batch, no_color) in [(false, true, true), (true, false, false)] {
+            assert!(resolve_color(ColorChoice::Always, batch, tty, no_color));
+            assert!(!resolve_color(ColorChoice::Never, batch, tty, no_color));
+        }
+    }
+
+    #[test]
+    fn test_auto_requires_tty_without_batch_or_no_color() {
+        assert!(resolve_color(ColorChoice::Auto, false, true, false));
+        assert!(!resolve_color(ColorChoice::Auto, false, false, false));
+        assert!(!resolve_color(ColorChoice::Auto, true, true, false));
+        assert!(!resolve_color(ColorChoice::Auto, false, true, true));
+    }
+}
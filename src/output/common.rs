@@ -1,50 +1,1214 @@
 //! Common utilities for output formatters
 
-use crate::cli::OutputFormat;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::IsTerminal;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use chrono::{DateTime, Utc};
+use comfy_table::{presets::NOTHING, ContentArrangement, Table};
+
+use crate::cli::{Cli, OutputFormat};
+use crate::hcp::TfeResource;
+
+use super::color::color_for;
+use super::jsonpath::JsonPath;
+use super::template::Template;
+
+/// Process-wide `--output-file` sink; formatter output goes to stdout when unset
+static OUTPUT_FILE: Mutex<Option<OutputFile>> = Mutex::new(None);
+
+/// File receiving formatter output, with the number of bytes written so far
+///
+/// Missing parent directories are created up front, but the file itself is
+/// only created (or truncated) on the first write, so a command that fails
+/// before printing anything leaves an existing file untouched.
+/// The first write error is kept and reported by [`finish_output_file`], so
+/// formatters can keep writing with `print!`-like convenience.
+struct OutputFile {
+    path: PathBuf,
+    writer: Option<BufWriter<File>>,
+    bytes: u64,
+    error: Option<io::Error>,
+}
+
+impl OutputFile {
+    /// Prepare writing to `path`, creating missing parent directories
+    fn create(path: &Path) -> io::Result<Self> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: None,
+            bytes: 0,
+            error: None,
+        })
+    }
+
+    /// Create (or truncate) the file on first use
+    fn open(&mut self) -> io::Result<&mut BufWriter<File>> {
+        if self.writer.is_none() {
+            self.writer = Some(BufWriter::new(File::create(&self.path)?));
+        }
+        Ok(self.writer.as_mut().unwrap())
+    }
+
+    /// Flush buffered output, returning the bytes written or the first error
+    ///
+    /// A command that succeeded without output still leaves an empty file.
+    fn finish(mut self) -> io::Result<(PathBuf, u64)> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        self.open()?.flush()?;
+        Ok((self.path, self.bytes))
+    }
+}
+
+impl Write for OutputFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.error.is_some() {
+            return Ok(buf.len());
+        }
+        match self.open().and_then(|writer| writer.write(buf)) {
+            Ok(n) => {
+                self.bytes += n as u64;
+                Ok(n)
+            }
+            Err(e) => {
+                self.error = Some(e);
+                Ok(buf.len())
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // Flushed once at the end; per-record flushes only matter on stdout
+        Ok(())
+    }
+}
+
+/// Send formatter output to `path` (`--output-file`) instead of stdout
+///
+/// The file is created on the first write or by [`finish_output_file`].
+pub fn set_output_file(path: Option<&Path>) -> io::Result<()> {
+    *OUTPUT_FILE.lock().unwrap() = path.map(OutputFile::create).transpose()?;
+    Ok(())
+}
+
+/// Flush and close the `--output-file` sink
+///
+/// Returns the file path and the number of bytes written, or `None` when
+/// output went to stdout.
+pub fn finish_output_file() -> io::Result<Option<(PathBuf, u64)>> {
+    OUTPUT_FILE
+        .lock()
+        .unwrap()
+        .take()
+        .map(OutputFile::finish)
+        .transpose()
+}
+
+/// Run `f` with the formatter output sink (`--output-file` or stdout)
+fn with_output<R>(f: impl FnOnce(&mut dyn Write) -> R) -> R {
+    let mut sink = OUTPUT_FILE.lock().unwrap();
+    match sink.as_mut() {
+        Some(file) => f(file),
+        None => f(&mut io::stdout().lock()),
+    }
+}
+
+/// Run a writer-based formatter against the output sink
+///
+/// For views that are not a plain row list (e.g. a tag with its workspaces).
+/// Errors on stdout (e.g. a closed pipe) are ignored; file errors surface
+/// from [`finish_output_file`].
+pub(super) fn print_with(f: impl FnOnce(&mut dyn Write) -> io::Result<()>) {
+    with_output(|out| {
+        let _ = f(out);
+    });
+}
+
+/// Print preformatted text (messages, logs, `--explain` plans) to the output sink
+///
+/// Like formatter output, it lands in the `--output-file` when one is set.
+pub fn print_text(text: &str) {
+    print_with(|out| {
+        out.write_all(text.as_bytes())?;
+        out.flush()
+    });
+}
+
+/// Renders a list of rows into any [`Write`] sink
+///
+/// Implementations only write to the given writer, so output can be captured
+/// in a buffer; [`Formatter::print`] sends it to the process output sink.
+pub trait Formatter {
+    type Row;
+
+    /// Write `rows` to `writer` in the formatter's output format
+    fn format_to<W: Write>(
+        &self,
+        rows: &[Self::Row],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()>;
+
+    /// Write `rows` to the output sink (stdout, or the `--output-file`)
+    /// with the process [`render_options`]
+    ///
+    /// Errors on stdout (e.g. a closed pipe) are ignored, like [`write_output`].
+    fn print(&self, rows: &[Self::Row]) {
+        with_output(|mut out| {
+            let _ = self.format_to(rows, &mut out, render_options());
+        });
+    }
+}
+
+/// Rendering settings taken from the global output flags
+///
+/// Formatters receive these through [`Formatter::format_to`] and the `write_*`
+/// helpers, so output can be rendered with any settings (e.g. in tests).
+/// [`Formatter::print`] and the `print_*`/`output_*` helpers use the options
+/// installed for the process by [`set_render_options`].
+#[derive(Debug, Default)]
+pub struct RenderOptions {
+    /// `--sort-keys`: order object keys alphabetically in JSON output
+    pub sort_keys: bool,
+    /// `--quiet`: no footers, limit notes or informational stderr
+    pub quiet: bool,
+    /// `--jsonpath` query (source text and parsed form) applied to pretty JSON
+    pub jsonpath: Option<(String, JsonPath)>,
+    /// `--template` rendered per row by `-o template`
+    pub template: Option<Template>,
+    /// `--max-name-width` for name cells in tables (`None` = unlimited)
+    pub max_name_width: Option<usize>,
+    /// `--wrap`: fit table columns to the terminal width
+    pub wrap: bool,
+    /// `--absolute-time`: tables show raw timestamps instead of ages
+    pub absolute_time: bool,
+    /// Whether tables may be colored (`--color` resolved for the output sink)
+    pub color: bool,
+    /// Set when `jsonpath` matched nothing in some JSON output
+    pub(super) jsonpath_unmatched: AtomicBool,
+}
+
+impl RenderOptions {
+    /// Options from the parsed command line
+    ///
+    /// Fails when `--jsonpath` or `--template` does not parse. `auto` color
+    /// applies only when output goes to a terminal rather than `--output-file`.
+    pub fn from_cli(cli: &Cli) -> Result<Self, String> {
+        let jsonpath = cli
+            .jsonpath
+            .as_deref()
+            .map(|e| JsonPath::parse(e).map(|path| (e.to_string(), path)))
+            .transpose()?;
+        let template = cli.template.as_deref().map(Template::parse).transpose()?;
+        let is_tty = cli.output_file.is_none() && io::stdout().is_terminal();
+        Ok(Self {
+            sort_keys: cli.sort_keys,
+            quiet: cli.quiet,
+            jsonpath,
+            template,
+            max_name_width: cli.max_name_width,
+            wrap: cli.wrap,
+            absolute_time: cli.absolute_time,
+            color: color_for(cli, is_tty),
+            jsonpath_unmatched: AtomicBool::new(false),
+        })
+    }
+
+    /// Fail when the `--jsonpath` query matched nothing in the rendered output
+    pub fn check_jsonpath_matched(&self) -> Result<(), String> {
+        match &self.jsonpath {
+            Some((expr, _)) if self.jsonpath_unmatched.load(Ordering::Relaxed) => {
+                Err(format!("JSONPath '{}' matched nothing", expr))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Render options of this process, installed once at startup
+static RENDER_OPTIONS: OnceLock<RenderOptions> = OnceLock::new();
+
+/// Install the render options used by `print`-style output
+///
+/// Only the first call takes effect; until then defaults apply.
+pub fn set_render_options(options: RenderOptions) {
+    let _ = RENDER_OPTIONS.set(options);
+}
+
+/// Render options of this process (defaults until [`set_render_options`])
+pub fn render_options() -> &'static RenderOptions {
+    static DEFAULT: OnceLock<RenderOptions> = OnceLock::new();
+    RENDER_OPTIONS
+        .get()
+        .unwrap_or_else(|| DEFAULT.get_or_init(RenderOptions::default))
+}
+
+/// Whether `--quiet` is in effect
+pub fn is_quiet() -> bool {
+    render_options().quiet
+}
+
+/// Whether table output ends with a "Total: N" footer
+///
+/// Footers are dropped with `--no-header` (plain rows) and with `--quiet`.
+pub(super) fn show_footer(no_header: bool, options: &RenderOptions) -> bool {
+    !no_header && !options.quiet
+}
+
+/// Fail when the `--jsonpath` query matched nothing in the printed output
+pub fn check_jsonpath_matched() -> Result<(), String> {
+    render_options().check_jsonpath_matched()
+}
+
+/// Print a table built by a `-o table` formatter, as markdown under `-o markdown`
+pub fn print_table(table: &Table, format: &OutputFormat) {
+    with_output(|out| {
+        let _ = write_table(out, table, format);
+    });
+}
+
+/// Write a table built by a `-o table` formatter, as markdown under `-o markdown`
+pub fn write_table<W: Write + ?Sized>(
+    writer: &mut W,
+    table: &Table,
+    format: &OutputFormat,
+) -> io::Result<()> {
+    if *format == OutputFormat::Markdown {
+        write!(writer, "{}", markdown_table(table))
+    } else {
+        writeln!(writer, "{table}")
+    }
+}
+
+/// Render a table as a GitHub-flavored markdown table
+///
+/// A table without header (`--no-header`) is rendered without the header and
+/// separator rows. `|` in cell values is escaped and line breaks become `<br>`.
+pub fn markdown_table(table: &Table) -> String {
+    fn line(cells: &[String]) -> String {
+        format!("| {} |\n", cells.join(" | "))
+    }
+    let cells = |row: &comfy_table::Row| -> Vec<String> {
+        row.cell_iter()
+            .map(|cell| escape_markdown(&cell.content()))
+            .collect()
+    };
+
+    let mut out = String::new();
+    if let Some(header) = table.header() {
+        let header = cells(header);
+        out.push_str(&line(&header));
+        out.push_str(&line(&vec!["---".to_string(); header.len()]));
+    }
+    for row in table.row_iter() {
+        out.push_str(&line(&cells(row)));
+    }
+    out
+}
+
+/// Escape a value for a markdown table cell
+fn escape_markdown(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// Create a borderless table honoring `--wrap`
+///
+/// Without `--wrap` cells are never wrapped and long rows overflow the terminal;
+/// with it comfy_table fits columns to the terminal width.
+pub fn new_table(options: &RenderOptions) -> Table {
+    let mut table = Table::new();
+    table.load_preset(NOTHING);
+    if options.wrap {
+        table.set_content_arrangement(ContentArrangement::Dynamic);
+    }
+    table
+}
+
+/// Resource name for a table cell, truncated to `--max-name-width`
+///
+/// CSV/JSON/YAML output always keeps the full name.
+pub fn table_name(name: &str, options: &RenderOptions) -> String {
+    truncate_with_ellipsis(name, options.max_name_width.unwrap_or(0))
+}
+
+fn truncate_with_ellipsis(value: &str, max_width: usize) -> String {
+    if max_width == 0 || value.chars().count() <= max_width {
+        return value.to_string();
+    }
+    let kept: String = value.chars().take(max_width.saturating_sub(1)).collect();
+    format!("{}…", kept)
+}
+
+/// Serialize to pretty JSON, honoring `--sort-keys`
+///
+/// All JSON output paths go through here so exports diff cleanly between runs.
+/// With `--jsonpath`, only the matched part of the document is rendered.
+pub fn to_json_pretty<T: serde::Serialize + ?Sized>(value: &T, options: &RenderOptions) -> String {
+    match &options.jsonpath {
+        Some((_, path)) => match path.apply(&serde_json::to_value(value).unwrap()) {
+            Some(selected) => render_json(&selected, options.sort_keys),
+            None => {
+                options.jsonpath_unmatched.store(true, Ordering::Relaxed);
+                String::new()
+            }
+        },
+        None => render_json(value, options.sort_keys),
+    }
+}
+
+/// Serialize to single-line JSON, honoring `--sort-keys` (one NDJSON record)
+pub fn to_json_line<T: serde::Serialize + ?Sized>(value: &T, options: &RenderOptions) -> String {
+    if options.sort_keys {
+        let value = serde_json::to_value(value).unwrap();
+        serde_json::to_string(&sort_json_keys(value)).unwrap()
+    } else {
+        serde_json::to_string(value).unwrap()
+    }
+}
+
+fn render_json<T: serde::Serialize + ?Sized>(value: &T, sort_keys: bool) -> String {
+    if sort_keys {
+        let value = serde_json::to_value(value).unwrap();
+        serde_json::to_string_pretty(&sort_json_keys(value)).unwrap()
+    } else {
+        serde_json::to_string_pretty(value).unwrap()
+    }
+}
+
+/// Recursively order object keys alphabetically
+fn sort_json_keys(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, sort_json_keys(v)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(sort_json_keys).collect())
+        }
+        other => other,
+    }
+}
 
 /// Escape a value for CSV output
 /// Handles commas, quotes, and newlines according to RFC 4180
 pub fn escape_csv(value: &str) -> String {
-    if value.contains(',') || value.contains('"') || value.contains('\n') {
+    escape_field(value, ',')
+}
+
+/// Escape a value for a delimiter-separated line
+///
+/// `,` quotes the value per RFC 4180 when needed. `\t` (TSV) never quotes;
+/// embedded backslashes, tabs and line breaks become `\\`, `\t`, `\n`, `\r`.
+pub fn escape_field(value: &str, delimiter: char) -> String {
+    if delimiter == '\t' {
+        return value
+            .replace('\\', "\\\\")
+            .replace('\t', "\\t")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r");
+    }
+    if value.contains(delimiter) || value.contains('"') || value.contains('\n') {
         format!("\"{}\"", value.replace('"', "\"\""))
     } else {
         value.to_string()
     }
 }
 
+/// Field delimiter of a delimiter-separated format: `\t` for `-o tsv`, else `,`
+pub fn csv_delimiter(format: &OutputFormat) -> char {
+    match format {
+        OutputFormat::Tsv => '\t',
+        _ => ',',
+    }
+}
+
+/// Print one delimiter-separated record (header or row)
+pub fn print_record<S: AsRef<str>>(fields: &[S], delimiter: char) {
+    with_output(|out| {
+        let _ = write_record(out, fields, delimiter);
+    });
+}
+
+/// Write one delimiter-separated record, escaping each field for `delimiter`
+pub fn write_record<W: Write + ?Sized, S: AsRef<str>>(
+    writer: &mut W,
+    fields: &[S],
+    delimiter: char,
+) -> io::Result<()> {
+    let line = fields
+        .iter()
+        .map(|f| escape_field(f.as_ref(), delimiter))
+        .collect::<Vec<_>>()
+        .join(&delimiter.to_string());
+    writeln!(writer, "{}", line)
+}
+
+/// Header record from column names
+pub fn header_record(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
 /// Output raw JSON/YAML for a single object from API response
 /// Extracts just the "data" part, removing the wrapper
 pub fn output_raw(raw: &serde_json::Value, format: &OutputFormat) {
+    with_output(|out| {
+        let _ = write_raw(out, raw, format, render_options());
+    });
+}
+
+/// Write the "data" part of a raw API response as JSON/YAML/NDJSON
+pub fn write_raw<W: Write + ?Sized>(
+    writer: &mut W,
+    raw: &serde_json::Value,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
     let data = &raw["data"];
     match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(data).unwrap());
+        OutputFormat::Json => writeln!(writer, "{}", to_json_pretty(data, options)),
+        OutputFormat::Yaml => writeln!(writer, "{}", serde_yml::to_string(data).unwrap()),
+        OutputFormat::Ndjson | OutputFormat::Template => {
+            write_raw_lines(writer, raw, format, options)
         }
-        OutputFormat::Yaml => {
-            println!("{}", serde_yml::to_string(data).unwrap());
+        _ => unreachable!("output_raw should only be called for structured formats"),
+    }
+}
+
+/// Format age from ISO timestamp (e.g. "3d 4h", "2h 5m", "7m")
+pub fn format_age(timestamp: Option<&str>) -> String {
+    let Some(ts) = timestamp else {
+        return "unknown".to_string();
+    };
+
+    let Ok(dt) = ts.parse::<DateTime<Utc>>() else {
+        return "unknown".to_string();
+    };
+
+    let now = Utc::now();
+    let duration = now.signed_duration_since(dt);
+
+    if duration.num_days() > 0 {
+        format!("{}d {}h", duration.num_days(), duration.num_hours() % 24)
+    } else if duration.num_hours() > 0 {
+        format!("{}h {}m", duration.num_hours(), duration.num_minutes() % 60)
+    } else if duration.num_minutes() > 0 {
+        format!("{}m", duration.num_minutes())
+    } else {
+        format!("{}s", duration.num_seconds())
+    }
+}
+
+/// Table cell for a timestamp: relative age, or the raw value with
+/// `--absolute-time` (unparseable values are also shown as is)
+pub fn table_time(timestamp: &str, options: &RenderOptions) -> String {
+    if options.absolute_time || timestamp.parse::<DateTime<Utc>>().is_err() {
+        timestamp.to_string()
+    } else {
+        format_age(Some(timestamp))
+    }
+}
+
+/// Format an integer with thousands separators (e.g. 12345 -> "12,345")
+pub fn format_thousands(n: u64) -> String {
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Flatten nested JSON objects into a single object with dotted keys
+///
+/// `{"attributes": {"name": "x"}}` becomes `{"attributes.name": "x"}`.
+/// Arrays and scalars are kept as values; non-object input is returned as is.
+pub fn flatten_json(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(_) => {
+            let mut flat = serde_json::Map::new();
+            flatten_into("", value, &mut flat);
+            serde_json::Value::Object(flat)
         }
-        _ => unreachable!("output_raw should only be called for JSON/YAML formats"),
+        other => other.clone(),
     }
 }
 
-/// Print items as pretty-printed JSON
+fn flatten_into(
+    prefix: &str,
+    value: &serde_json::Value,
+    flat: &mut serde_json::Map<String, serde_json::Value>,
+) {
+    match value {
+        serde_json::Value::Object(map) if !map.is_empty() => {
+            for (key, nested) in map {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_into(&key, nested, flat);
+            }
+        }
+        other => {
+            flat.insert(prefix.to_string(), other.clone());
+        }
+    }
+}
+
+/// Output the "data" part of a raw API response, flattened when requested
+///
+/// Flattening (`--flatten`) only applies to JSON and NDJSON output.
+pub fn output_raw_flattened(raw: &serde_json::Value, format: &OutputFormat, flatten: bool) {
+    let options = render_options();
+    with_output(|out| {
+        let _ = if flatten && *format == OutputFormat::Json {
+            writeln!(
+                out,
+                "{}",
+                to_json_pretty(&flatten_json(&raw["data"]), options)
+            )
+        } else if flatten && *format == OutputFormat::Ndjson {
+            write_lines(
+                out,
+                std::iter::once(flatten_json(&raw["data"])),
+                format,
+                options,
+            )
+        } else {
+            write_raw(out, raw, format, options)
+        };
+    });
+}
+
+/// Write items as pretty-printed JSON
 ///
 /// Generic helper that replaces per-resource `output_json` boilerplate.
-pub fn print_json<T: serde::Serialize>(items: &[T]) {
-    println!("{}", serde_json::to_string_pretty(items).unwrap());
+pub fn write_json<W, T>(writer: &mut W, items: &[T], options: &RenderOptions) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    T: serde::Serialize,
+{
+    writeln!(writer, "{}", to_json_pretty(items, options))
 }
 
-/// Print items as YAML
+/// Write items as YAML
 ///
 /// Generic helper that replaces per-resource `output_yaml` boilerplate.
-pub fn print_yaml<T: serde::Serialize>(items: &[T]) {
-    println!("{}", serde_yml::to_string(&items).unwrap());
+pub fn write_yaml<W, T>(writer: &mut W, items: &[T]) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    T: serde::Serialize,
+{
+    writeln!(writer, "{}", serde_yml::to_string(&items).unwrap())
+}
+
+/// Print one record per item: a compact JSON line (`ndjson`) or the rendered `--template`
+///
+/// Items are serialized lazily and each record is flushed as soon as it is
+/// written, so consumers can process records without waiting for the whole
+/// result set. Stops quietly when the reader goes away (e.g. `| head`).
+pub fn print_lines<T, I>(items: I, format: &OutputFormat)
+where
+    T: serde::Serialize,
+    I: IntoIterator<Item = T>,
+{
+    with_output(|out| {
+        // A closed reader just ends the stream
+        let _ = write_lines(out, items, format, render_options());
+    });
+}
+
+/// Write one record per item: a compact JSON line (`ndjson`) or the rendered `--template`
+///
+/// Each record is flushed as soon as it is written; the first write error
+/// stops the stream and is returned. `-o template` without a template in
+/// `options` is an [`io::ErrorKind::InvalidInput`] error.
+pub fn write_lines<W, T, I>(
+    writer: &mut W,
+    items: I,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    T: serde::Serialize,
+    I: IntoIterator<Item = T>,
+{
+    let template = match (format, &options.template) {
+        (OutputFormat::Template, None) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "-o template requires --template",
+            ))
+        }
+        (OutputFormat::Template, Some(template)) => Some(template),
+        _ => None,
+    };
+    for item in items {
+        let line = match template {
+            Some(template) => {
+                let rendered = template.render(&serde_json::to_value(&item).unwrap());
+                // Each row ends up on its own line whether or not the template ends with "\n"
+                rendered.strip_suffix('\n').unwrap_or(&rendered).to_string()
+            }
+            None => to_json_line(&item, options),
+        };
+        writeln!(writer, "{}", line)?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+/// Print a single object as one `ndjson`/`template` record
+pub fn print_line<T: serde::Serialize + ?Sized>(value: &T, format: &OutputFormat) {
+    print_lines(std::iter::once(value), format);
+}
+
+/// Write the "data" part of a raw API response as `ndjson`/`template` records
+///
+/// A list response yields one record per element, a single object one record.
+pub fn write_raw_lines<W: Write + ?Sized>(
+    writer: &mut W,
+    raw: &serde_json::Value,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    match &raw["data"] {
+        serde_json::Value::Array(items) => write_lines(writer, items, format, options),
+        data => write_lines(writer, std::iter::once(data), format, options),
+    }
+}
+
+/// Print only resource IDs, one per line
+///
+/// Generic helper backing `--id-only` for any resource implementing `TfeResource`.
+pub fn print_ids<'a, T, I>(items: I)
+where
+    T: TfeResource + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    with_output(|out| {
+        let _ = write_ids(out, items);
+    });
+}
+
+/// Write only resource IDs, one per line
+pub fn write_ids<'a, W, T, I>(writer: &mut W, items: I) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    T: TfeResource + 'a,
+    I: IntoIterator<Item = &'a T>,
+{
+    for item in items {
+        writeln!(writer, "{}", item.id())?;
+    }
+    Ok(())
+}
+
+/// Truncate a sorted listing to `--limit` items
+///
+/// Prints "Showing N of M" on stderr when rows were dropped, unless
+/// `--no-header` or `--quiet` is set; stdout stays machine-readable either way.
+pub fn apply_limit<T>(items: &mut Vec<T>, cli: &Cli) {
+    let total = items.len();
+    if let Some(limit) = cli.limit.filter(|limit| *limit < total) {
+        items.truncate(limit);
+        print_limit_note(limit, total, cli);
+    }
+}
+
+/// `apply_limit` for listings grouped per organization, counting items
+/// across groups; groups left empty are dropped
+pub fn apply_limit_grouped<T>(groups: &mut Vec<(String, Vec<T>)>, cli: &Cli) {
+    let total: usize = groups.iter().map(|(_, items)| items.len()).sum();
+    let Some(limit) = cli.limit.filter(|limit| *limit < total) else {
+        return;
+    };
+    let mut remaining = limit;
+    for (_, items) in groups.iter_mut() {
+        items.truncate(remaining);
+        remaining -= items.len();
+    }
+    groups.retain(|(_, items)| !items.is_empty());
+    print_limit_note(limit, total, cli);
+}
+
+fn print_limit_note(shown: usize, total: usize, cli: &Cli) {
+    if !cli.no_header && !cli.quiet {
+        eprintln!("Showing {} of {}", shown, total);
+    }
+}
+
+/// Count items per distinct key (shared by the `--count-by` options)
+pub fn count_by<T, F>(items: &[T], key: F) -> BTreeMap<String, usize>
+where
+    F: Fn(&T) -> String,
+{
+    let mut counts = BTreeMap::new();
+    for item in items {
+        *counts.entry(key(item)).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Output grouped counts (most common value first)
+///
+/// `field` labels the value column, `resource` the counted items (e.g. "workspaces").
+/// JSON/YAML emit an object of value → count.
+pub fn output_counts(
+    counts: &BTreeMap<String, usize>,
+    field: &str,
+    resource: &str,
+    format: &OutputFormat,
+    no_header: bool,
+) {
+    with_output(|out| {
+        let _ = write_counts(
+            out,
+            counts,
+            field,
+            resource,
+            format,
+            no_header,
+            render_options(),
+        );
+    });
+}
+
+/// Write a value → count breakdown; see [`output_counts`]
+pub fn write_counts<W: Write + ?Sized>(
+    writer: &mut W,
+    counts: &BTreeMap<String, usize>,
+    field: &str,
+    resource: &str,
+    format: &OutputFormat,
+    no_header: bool,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut sorted: Vec<(&String, &usize)> = counts.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    let total: usize = counts.values().sum();
+
+    match format {
+        OutputFormat::Table | OutputFormat::Markdown => {
+            let mut table = new_table(options);
+            if !no_header {
+                table.set_header(vec![field.to_uppercase(), resource.to_uppercase()]);
+            }
+            for (value, count) in &sorted {
+                let value = if value.is_empty() {
+                    "-"
+                } else {
+                    value.as_str()
+                };
+                table.add_row(vec![value.to_string(), count.to_string()]);
+            }
+            writeln!(writer)?;
+            write_table(writer, &table, format)?;
+            if show_footer(no_header, options) {
+                writeln!(
+                    writer,
+                    "\nTotal: {} {}, {} distinct values",
+                    total,
+                    resource,
+                    counts.len()
+                )?;
+            }
+            Ok(())
+        }
+        OutputFormat::Csv | OutputFormat::Tsv => {
+            let delimiter = csv_delimiter(format);
+            if !no_header {
+                write_record(
+                    writer,
+                    &[field.replace('-', "_").as_str(), "count"],
+                    delimiter,
+                )?;
+            }
+            for (value, count) in &sorted {
+                write_record(writer, &[value.to_string(), count.to_string()], delimiter)?;
+            }
+            Ok(())
+        }
+        OutputFormat::Json => writeln!(writer, "{}", to_json_pretty(counts, options)),
+        OutputFormat::Yaml => writeln!(writer, "{}", serde_yml::to_string(counts).unwrap()),
+        OutputFormat::Ndjson | OutputFormat::Template => write_lines(
+            writer,
+            sorted
+                .iter()
+                .map(|(value, count)| serde_json::json!({ field: value, "count": count })),
+            format,
+            options,
+        ),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_output_file_creates_parents_and_counts_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("reports/today/ws.json");
+
+        let mut file = OutputFile::create(&path).unwrap();
+        writeln!(file, "[{{\"name\": \"app\"}}]").unwrap();
+        write!(file, "é").unwrap();
+        let (written_path, bytes) = file.finish().unwrap();
+
+        assert_eq!(written_path, path);
+        assert_eq!(bytes, 20);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "[{\"name\": \"app\"}]\né"
+        );
+    }
+
+    #[test]
+    fn test_output_file_truncates_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.csv");
+        std::fs::write(&path, "old content that is longer").unwrap();
+
+        let mut file = OutputFile::create(&path).unwrap();
+        write!(file, "new").unwrap();
+        assert_eq!(file.finish().unwrap().1, 3);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+    }
+
+    #[test]
+    fn test_output_file_in_missing_dir_under_a_file_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let blocker = dir.path().join("not-a-dir");
+        std::fs::write(&blocker, "").unwrap();
+        assert!(OutputFile::create(&blocker.join("out.json")).is_err());
+    }
+
+    #[test]
+    fn test_output_file_untouched_until_first_write() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.json");
+        std::fs::write(&path, "previous export").unwrap();
+
+        // A command failing before any output drops the sink unopened
+        drop(OutputFile::create(&path).unwrap());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "previous export");
+
+        // A command succeeding without output still leaves an empty file
+        assert_eq!(OutputFile::create(&path).unwrap().finish().unwrap().1, 0);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+    }
+
+    #[test]
+    fn test_render_options_reject_invalid_jsonpath() {
+        let cli = <Cli as clap::Parser>::parse_from(["hcp", "get", "org", "--jsonpath", "data.id"]);
+        let err = RenderOptions::from_cli(&cli).unwrap_err();
+        assert!(err.contains("must start with '$'"));
+    }
+
+    #[test]
+    fn test_to_json_line_is_single_line() {
+        let value = serde_json::json!({"name": "ws", "tags": ["a", "b"]});
+        let line = to_json_line(&value, &RenderOptions::default());
+        assert!(!line.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&line).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn test_count_by_groups_keys() {
+        let items = ["a", "b", "a", "c", "a"];
+        let counts = count_by(&items, |s| s.to_string());
+        assert_eq!(counts.get("a"), Some(&3));
+        assert_eq!(counts.get("b"), Some(&1));
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    fn test_output_counts_all_formats() {
+        let counts = count_by(&["x", "y", "x"], |s| s.to_string());
+        // Should not panic
+        for format in [
+            OutputFormat::Table,
+            OutputFormat::Csv,
+            OutputFormat::Json,
+            OutputFormat::Yaml,
+            OutputFormat::Ndjson,
+        ] {
+            output_counts(&counts, "status", "runs", &format, false);
+        }
+    }
+
+    #[test]
+    fn test_markdown_table_with_header() {
+        let mut table = new_table(&RenderOptions::default());
+        table.set_header(vec!["NAME", "ID"]);
+        table.add_row(vec!["app", "ws-1"]);
+        table.add_row(vec!["web", "ws-2"]);
+        assert_eq!(
+            markdown_table(&table),
+            "| NAME | ID |\n| --- | --- |\n| app | ws-1 |\n| web | ws-2 |\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_table_without_header() {
+        let mut table = new_table(&RenderOptions::default());
+        table.add_row(vec!["app", "ws-1"]);
+        assert_eq!(markdown_table(&table), "| app | ws-1 |\n");
+    }
+
+    #[test]
+    fn test_markdown_table_escapes_cells() {
+        let mut table = new_table(&RenderOptions::default());
+        table.set_header(vec!["NAME", "DESCRIPTION"]);
+        table.add_row(vec!["a|b", "line1\nline2 C:\\dir"]);
+        assert_eq!(
+            markdown_table(&table).lines().nth(2).unwrap(),
+            r"| a\|b | line1<br>line2 C:\\dir |"
+        );
+    }
+
+    #[test]
+    fn test_write_table_follows_format() {
+        let mut table = new_table(&RenderOptions::default());
+        table.set_header(vec!["NAME"]);
+        table.add_row(vec!["app"]);
+        let render = |format: &OutputFormat| {
+            let mut out = Vec::new();
+            write_table(&mut out, &table, format).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert_eq!(
+            render(&OutputFormat::Markdown),
+            "| NAME |\n| --- |\n| app |\n"
+        );
+        assert!(!render(&OutputFormat::Table).contains('|'));
+    }
+
+    #[test]
+    fn test_truncate_with_ellipsis() {
+        assert_eq!(truncate_with_ellipsis("short", 10), "short");
+        assert_eq!(truncate_with_ellipsis("exactly-10", 10), "exactly-10");
+        assert_eq!(
+            truncate_with_ellipsis("a-very-long-workspace", 10),
+            "a-very-lo…"
+        );
+        assert_eq!(
+            truncate_with_ellipsis("a-very-long-workspace", 0),
+            "a-very-long-workspace"
+        );
+        assert_eq!(truncate_with_ellipsis("źdźbło-łąka", 4), "źdź…");
+    }
+
+    #[test]
+    fn test_render_json_sort_keys() {
+        #[derive(serde::Serialize)]
+        struct Row {
+            zeta: u32,
+            alpha: u32,
+            nested: serde_json::Value,
+        }
+        let row = Row {
+            zeta: 1,
+            alpha: 2,
+            nested: serde_json::json!({"b": 1, "a": [{"d": 1, "c": 2}]}),
+        };
+
+        let unsorted = render_json(&row, false);
+        assert!(unsorted.find("zeta").unwrap() < unsorted.find("alpha").unwrap());
+
+        let sorted = render_json(&row, true);
+        assert!(sorted.find("alpha").unwrap() < sorted.find("nested").unwrap());
+        assert!(sorted.find("nested").unwrap() < sorted.find("zeta").unwrap());
+        assert!(sorted.find("\"c\"").unwrap() < sorted.find("\"d\"").unwrap());
+    }
+
+    #[test]
+    fn test_format_thousands() {
+        assert_eq!(format_thousands(0), "0");
+        assert_eq!(format_thousands(999), "999");
+        assert_eq!(format_thousands(1000), "1,000");
+        assert_eq!(format_thousands(12345), "12,345");
+        assert_eq!(format_thousands(1234567), "1,234,567");
+        assert_eq!(format_thousands(100000), "100,000");
+    }
+
+    #[test]
+    fn test_flatten_json_nested() {
+        let value = serde_json::json!({
+            "id": "ws-1",
+            "attributes": {"resource-count": 5, "vcs-repo": {"branch": "main"}},
+            "relationships": {"project": {"data": {"id": "prj-1"}}}
+        });
+        let flat = flatten_json(&value);
+
+        assert_eq!(flat["id"], "ws-1");
+        assert_eq!(flat["attributes.resource-count"], 5);
+        assert_eq!(flat["attributes.vcs-repo.branch"], "main");
+        assert_eq!(flat["relationships.project.data.id"], "prj-1");
+        assert!(flat.get("attributes").is_none());
+    }
+
+    #[test]
+    fn test_flatten_json_keeps_arrays_and_empty_objects() {
+        let value = serde_json::json!({
+            "tags": ["a", "b"],
+            "meta": {},
+            "nothing": null
+        });
+        let flat = flatten_json(&value);
+
+        assert_eq!(flat["tags"], serde_json::json!(["a", "b"]));
+        assert_eq!(flat["meta"], serde_json::json!({}));
+        assert!(flat["nothing"].is_null());
+    }
+
+    #[test]
+    fn test_flatten_json_non_object() {
+        let value = serde_json::json!([1, 2]);
+        assert_eq!(flatten_json(&value), value);
+    }
+
+    #[test]
+    fn test_show_footer() {
+        assert!(show_footer(false, &RenderOptions::default()));
+        assert!(!show_footer(true, &RenderOptions::default()));
+    }
+
+    #[test]
+    fn test_apply_limit() {
+        use clap::Parser;
+
+        let cli = Cli::parse_from(["hcp", "get", "ws", "-n", "2"]);
+        let mut items = vec![1, 2, 3];
+        apply_limit(&mut items, &cli);
+        assert_eq!(items, [1, 2]);
+
+        let mut items = vec![1];
+        apply_limit(&mut items, &cli);
+        assert_eq!(items, [1]);
+
+        let cli = Cli::parse_from(["hcp", "get", "ws"]);
+        let mut items = vec![1, 2, 3];
+        apply_limit(&mut items, &cli);
+        assert_eq!(items.len(), 3);
+    }
+
+    #[test]
+    fn test_apply_limit_grouped_counts_across_groups() {
+        use clap::Parser;
+
+        let cli = Cli::parse_from(["hcp", "get", "oc", "--limit", "3"]);
+        let mut groups = vec![
+            ("org-a".to_string(), vec![1, 2]),
+            ("org-b".to_string(), vec![3, 4]),
+            ("org-c".to_string(), vec![5]),
+        ];
+        apply_limit_grouped(&mut groups, &cli);
+        assert_eq!(
+            groups,
+            [
+                ("org-a".to_string(), vec![1, 2]),
+                ("org-b".to_string(), vec![3])
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_age_minutes() {
+        let ts = (Utc::now() - chrono::Duration::minutes(5)).to_rfc3339();
+        let age = format_age(Some(&ts));
+        assert!(age.contains('m') || age.contains('s'));
+    }
+
+    #[test]
+    fn test_format_age_hours() {
+        let ts = (Utc::now() - chrono::Duration::hours(2)).to_rfc3339();
+        assert!(format_age(Some(&ts)).contains('h'));
+    }
+
+    #[test]
+    fn test_format_age_days() {
+        let ts = (Utc::now() - chrono::Duration::days(3) - chrono::Duration::hours(4)).to_rfc3339();
+        assert_eq!(format_age(Some(&ts)), "3d 4h");
+    }
+
+    #[test]
+    fn test_format_age_none() {
+        assert_eq!(format_age(None), "unknown");
+    }
+
+    #[test]
+    fn test_format_age_invalid() {
+        assert_eq!(format_age(Some("not-a-date")), "unknown");
+    }
+
+    #[test]
+    fn test_table_time() {
+        let ts = (Utc::now() - chrono::Duration::days(2)).to_rfc3339();
+        assert!(table_time(&ts, &RenderOptions::default()).starts_with("2d "));
+        assert_eq!(table_time("", &RenderOptions::default()), "");
+        assert_eq!(
+            table_time("not-a-date", &RenderOptions::default()),
+            "not-a-date"
+        );
+    }
+
+    #[test]
+    fn test_escape_field_tsv() {
+        assert_eq!(escape_field("a,b", '\t'), "a,b");
+        assert_eq!(escape_field("a\tb\nc", '\t'), "a\\tb\\nc");
+        assert_eq!(escape_field("C:\\dir", '\t'), "C:\\\\dir");
+        assert_eq!(escape_field("say \"hi\"", '\t'), "say \"hi\"");
+    }
+
+    #[test]
+    fn test_write_record() {
+        let render = |fields: &[&str], delimiter| {
+            let mut buf = Vec::new();
+            write_record(&mut buf, fields, delimiter).unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+        assert_eq!(render(&["id", "name", "count"], ','), "id,name,count\n");
+        assert_eq!(render(&["ws-1", "a, b", "3"], ','), "ws-1,\"a, b\",3\n");
+        assert_eq!(render(&["ws-1", "a, b", "3"], '\t'), "ws-1\ta, b\t3\n");
+        assert_eq!(render(&["say \"hi\"", "x\ty"], '\t'), "say \"hi\"\tx\\ty\n");
+        assert_eq!(render(&["line1\nline2"], '\t'), "line1\\nline2\n");
+        assert_eq!(render(&["a", "", ""], '\t'), "a\t\t\n");
+    }
+
+    #[test]
+    fn test_csv_delimiter() {
+        assert_eq!(csv_delimiter(&OutputFormat::Csv), ',');
+        assert_eq!(csv_delimiter(&OutputFormat::Tsv), '\t');
+    }
+
     #[test]
     fn test_escape_csv_simple() {
         assert_eq!(escape_csv("simple"), "simple");
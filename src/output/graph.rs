@@ -0,0 +1,124 @@
+//! Workspace dependency graph output (Graphviz DOT / Mermaid)
+
+use std::collections::BTreeMap;
+
+use crate::cli::GraphFormat;
+use crate::hcp::RunTrigger;
+
+/// Print the run trigger graph in the requested syntax
+pub fn output_graph(triggers: &[RunTrigger], format: GraphFormat) {
+    let rendered = match format {
+        GraphFormat::Dot => render_dot(triggers),
+        GraphFormat::Mermaid => render_mermaid(triggers),
+    };
+    super::common::print_with(|out| write!(out, "{}", rendered));
+}
+
+/// Workspaces (ID → name) and source → target edges, both sorted for stable output
+fn nodes_and_edges(triggers: &[RunTrigger]) -> (BTreeMap<&str, &str>, Vec<(&str, &str)>) {
+    let mut nodes = BTreeMap::new();
+    let mut edges = Vec::with_capacity(triggers.len());
+    for rt in triggers {
+        nodes.insert(rt.sourceable_id(), rt.sourceable_name());
+        nodes.insert(rt.workspace_id(), rt.attributes.workspace_name.as_str());
+        edges.push((rt.sourceable_id(), rt.workspace_id()));
+    }
+    edges.sort_unstable();
+    edges.dedup();
+    (nodes, edges)
+}
+
+fn render_dot(triggers: &[RunTrigger]) -> String {
+    let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+    let (nodes, edges) = nodes_and_edges(triggers);
+
+    let mut out = String::from("digraph workspaces {\n  rankdir=LR;\n");
+    for (id, name) in &nodes {
+        out.push_str(&format!("  {} [label={}];\n", quote(id), quote(name)));
+    }
+    for (from, to) in &edges {
+        out.push_str(&format!("  {} -> {};\n", quote(from), quote(to)));
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn render_mermaid(triggers: &[RunTrigger]) -> String {
+    // Mermaid node IDs must be plain identifiers; labels carry the real names
+    let node_id = |id: &str| id.replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    let label = |name: &str| name.replace('"', "#quot;");
+    let (nodes, edges) = nodes_and_edges(triggers);
+
+    let mut out = String::from("graph LR\n");
+    for (id, name) in &nodes {
+        out.push_str(&format!("  {}[\"{}\"]\n", node_id(id), label(name)));
+    }
+    for (from, to) in &edges {
+        out.push_str(&format!("  {} --> {}\n", node_id(from), node_id(to)));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trigger(source: (&str, &str), target: (&str, &str)) -> RunTrigger {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("rt-{}-{}", source.0, target.0),
+            "attributes": {"workspace-name": target.1, "sourceable-name": source.1},
+            "relationships": {
+                "workspace": {"data": {"id": target.0}},
+                "sourceable": {"data": {"id": source.0}}
+            }
+        }))
+        .unwrap()
+    }
+
+    fn triggers() -> Vec<RunTrigger> {
+        vec![
+            trigger(("ws-net", "network"), ("ws-app", "app")),
+            trigger(("ws-net", "network"), ("ws-db", "db \"main\"")),
+            trigger(("ws-db", "db \"main\""), ("ws-app", "app")),
+        ]
+    }
+
+    #[test]
+    fn test_render_dot() {
+        assert_eq!(
+            render_dot(&triggers()),
+            "digraph workspaces {\n  rankdir=LR;\n\
+             \x20 \"ws-app\" [label=\"app\"];\n\
+             \x20 \"ws-db\" [label=\"db \\\"main\\\"\"];\n\
+             \x20 \"ws-net\" [label=\"network\"];\n\
+             \x20 \"ws-db\" -> \"ws-app\";\n\
+             \x20 \"ws-net\" -> \"ws-app\";\n\
+             \x20 \"ws-net\" -> \"ws-db\";\n\
+             }\n"
+        );
+    }
+
+    #[test]
+    fn test_render_mermaid() {
+        assert_eq!(
+            render_mermaid(&triggers()),
+            "graph LR\n\
+             \x20 ws_app[\"app\"]\n\
+             \x20 ws_db[\"db #quot;main#quot;\"]\n\
+             \x20 ws_net[\"network\"]\n\
+             \x20 ws_db --> ws_app\n\
+             \x20 ws_net --> ws_app\n\
+             \x20 ws_net --> ws_db\n"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_edges_collapse() {
+        let mut list = triggers();
+        list.push(trigger(("ws-net", "network"), ("ws-app", "app")));
+        assert_eq!(
+            render_mermaid(&list).matches("ws_net --> ws_app").count(),
+            1
+        );
+    }
+}
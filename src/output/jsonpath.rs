@@ -0,0 +1,534 @@
+//! Minimal JSONPath evaluator backing the global `--jsonpath` option
+//!
+//! Supported syntax (enough for scripting without `jq`):
+//! - `$` root, `.name` / `['name']` child, `.*` / `[*]` wildcard
+//! - `..name` / `..*` recursive descent
+//! - `[0]`, `[-1]` index, `[1:3]` slice, `[0,2]` / `['a','b']` union
+//! - `[?(@.attr)]` existence filter, `[?(@.attr == 'x')]` comparison filter
+//!   (`==`, `!=`, `<`, `<=`, `>`, `>=` against strings, numbers, booleans, null)
+
+use serde_json::Value;
+
+/// Parsed JSONPath expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Child(Selector),
+    Descendant(Selector),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Selector {
+    Name(String),
+    Wildcard,
+    Index(i64),
+    Slice(Option<i64>, Option<i64>),
+    Union(Vec<Selector>),
+    Filter(Filter),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Filter {
+    path: Vec<Segment>,
+    comparison: Option<(CmpOp, Value)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl JsonPath {
+    /// Parse an expression such as `$.data[*].attributes.name`
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            chars: expr.trim().chars().collect(),
+            pos: 0,
+        };
+        if !parser.eat('$') {
+            return Err(format!(
+                "Invalid JSONPath '{}': expression must start with '$'",
+                expr
+            ));
+        }
+        let segments = parser
+            .segments(false)
+            .map_err(|e| format!("Invalid JSONPath '{}': {}", expr, e))?;
+        if parser.pos < parser.chars.len() {
+            return Err(format!(
+                "Invalid JSONPath '{}': unexpected '{}' at position {}",
+                expr, parser.chars[parser.pos], parser.pos
+            ));
+        }
+        Ok(Self { segments })
+    }
+
+    /// Whether the expression can match at most one node (no wildcards, slices,
+    /// unions, filters or recursive descent)
+    pub fn is_definite(&self) -> bool {
+        self.segments.iter().all(|s| {
+            matches!(
+                s,
+                Segment::Child(Selector::Name(_)) | Segment::Child(Selector::Index(_))
+            )
+        })
+    }
+
+    /// All nodes matched by the expression, in document order
+    pub fn select<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        select_segments(&self.segments, root)
+    }
+
+    /// Apply the expression to a document
+    ///
+    /// A definite path yields the matched value itself, any other path an array
+    /// of matches. `None` when nothing matched.
+    pub fn apply(&self, root: &Value) -> Option<Value> {
+        let matches = self.select(root);
+        if matches.is_empty() {
+            None
+        } else if self.is_definite() {
+            Some(matches[0].clone())
+        } else {
+            Some(Value::Array(matches.into_iter().cloned().collect()))
+        }
+    }
+}
+
+fn select_segments<'a>(segments: &[Segment], root: &'a Value) -> Vec<&'a Value> {
+    let mut nodes = vec![root];
+    for segment in segments {
+        let mut next = Vec::new();
+        for node in nodes {
+            match segment {
+                Segment::Child(selector) => apply_selector(selector, node, &mut next),
+                Segment::Descendant(selector) => {
+                    let mut stack = vec![node];
+                    while let Some(current) = stack.pop() {
+                        apply_selector(selector, current, &mut next);
+                        // Push children in reverse so they are visited in document order
+                        match current {
+                            Value::Object(map) => stack.extend(map.values().rev()),
+                            Value::Array(items) => stack.extend(items.iter().rev()),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+        nodes = next;
+    }
+    nodes
+}
+
+fn apply_selector<'a>(selector: &Selector, node: &'a Value, out: &mut Vec<&'a Value>) {
+    match selector {
+        Selector::Name(name) => {
+            if let Some(value) = node.get(name.as_str()) {
+                out.push(value);
+            }
+        }
+        Selector::Wildcard => children(node, out),
+        Selector::Index(index) => {
+            if let Value::Array(items) = node {
+                if let Some(i) = normalize_index(*index, items.len()) {
+                    out.push(&items[i]);
+                }
+            }
+        }
+        Selector::Slice(start, end) => {
+            if let Value::Array(items) = node {
+                let len = items.len() as i64;
+                let clamp = |v: i64| if v < 0 { (len + v).max(0) } else { v.min(len) };
+                let from = clamp(start.unwrap_or(0));
+                let to = clamp(end.unwrap_or(len));
+                if from < to {
+                    out.extend(&items[from as usize..to as usize]);
+                }
+            }
+        }
+        Selector::Union(selectors) => {
+            for s in selectors {
+                apply_selector(s, node, out);
+            }
+        }
+        Selector::Filter(filter) => {
+            let mut candidates = Vec::new();
+            children(node, &mut candidates);
+            out.extend(candidates.into_iter().filter(|c| filter.matches(c)));
+        }
+    }
+}
+
+fn children<'a>(node: &'a Value, out: &mut Vec<&'a Value>) {
+    match node {
+        Value::Object(map) => out.extend(map.values()),
+        Value::Array(items) => out.extend(items),
+        _ => {}
+    }
+}
+
+fn normalize_index(index: i64, len: usize) -> Option<usize> {
+    let i = if index < 0 { len as i64 + index } else { index };
+    (0..len as i64).contains(&i).then_some(i as usize)
+}
+
+impl Filter {
+    fn matches(&self, node: &Value) -> bool {
+        let Some(value) = select_segments(&self.path, node).into_iter().next() else {
+            return false;
+        };
+        match &self.comparison {
+            None => true,
+            Some((op, literal)) => compare(value, *op, literal),
+        }
+    }
+}
+
+fn compare(value: &Value, op: CmpOp, literal: &Value) -> bool {
+    let ordering = match (value, literal) {
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .zip(b.as_f64())
+            .and_then(|(a, b)| a.partial_cmp(&b)),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    };
+    match op {
+        CmpOp::Eq => ordering.map_or(value == literal, |o| o.is_eq()),
+        CmpOp::Ne => ordering.map_or(value != literal, |o| o.is_ne()),
+        CmpOp::Lt => ordering.is_some_and(|o| o.is_lt()),
+        CmpOp::Le => ordering.is_some_and(|o| o.is_le()),
+        CmpOp::Gt => ordering.is_some_and(|o| o.is_gt()),
+        CmpOp::Ge => ordering.is_some_and(|o| o.is_ge()),
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        let end = self.pos + s.chars().count();
+        if end <= self.chars.len() && self.chars[self.pos..end].iter().copied().eq(s.chars()) {
+            self.pos = end;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Parse `.x`, `..x` and `[...]` segments; inside a filter stop at an operator or `)`
+    fn segments(&mut self, in_filter: bool) -> Result<Vec<Segment>, String> {
+        let mut segments = Vec::new();
+        loop {
+            if self.eat_str("..") {
+                let selector = if self.eat('[') {
+                    self.bracket()?
+                } else {
+                    self.dot_selector()?
+                };
+                segments.push(Segment::Descendant(selector));
+            } else if self.eat('.') {
+                segments.push(Segment::Child(self.dot_selector()?));
+            } else if self.eat('[') {
+                segments.push(Segment::Child(self.bracket()?));
+            } else if self.peek().is_none() || in_filter {
+                return Ok(segments);
+            } else {
+                return Err(format!(
+                    "unexpected '{}' at position {}",
+                    self.chars[self.pos], self.pos
+                ));
+            }
+        }
+    }
+
+    fn dot_selector(&mut self) -> Result<Selector, String> {
+        if self.eat('*') {
+            return Ok(Selector::Wildcard);
+        }
+        let name = self.identifier();
+        if name.is_empty() {
+            return Err(format!("expected a field name at position {}", self.pos));
+        }
+        Ok(Selector::Name(name))
+    }
+
+    fn identifier(&mut self) -> String {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Parse the inside of `[...]`; the opening bracket is already consumed
+    fn bracket(&mut self) -> Result<Selector, String> {
+        self.skip_ws();
+        let selector = if self.eat('*') {
+            Selector::Wildcard
+        } else if self.eat('?') {
+            Selector::Filter(self.filter()?)
+        } else {
+            let mut selectors = vec![self.bracket_item()?];
+            self.skip_ws();
+            while self.eat(',') {
+                self.skip_ws();
+                selectors.push(self.bracket_item()?);
+                self.skip_ws();
+            }
+            if selectors.len() == 1 {
+                selectors.remove(0)
+            } else {
+                Selector::Union(selectors)
+            }
+        };
+        self.skip_ws();
+        if !self.eat(']') {
+            return Err(format!("expected ']' at position {}", self.pos));
+        }
+        Ok(selector)
+    }
+
+    /// A quoted name, an index or a slice
+    fn bracket_item(&mut self) -> Result<Selector, String> {
+        if matches!(self.peek(), Some('\'') | Some('"')) {
+            return Ok(Selector::Name(self.quoted()?));
+        }
+        let start = self.integer()?;
+        self.skip_ws();
+        if self.eat(':') {
+            self.skip_ws();
+            let end = self.integer()?;
+            return Ok(Selector::Slice(start, end));
+        }
+        start
+            .map(Selector::Index)
+            .ok_or_else(|| format!("expected an index or quoted name at position {}", self.pos))
+    }
+
+    fn integer(&mut self) -> Result<Option<i64>, String> {
+        let start = self.pos;
+        self.eat('-');
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        if text.is_empty() {
+            return Ok(None);
+        }
+        text.parse()
+            .map(Some)
+            .map_err(|_| format!("invalid index '{}'", text))
+    }
+
+    fn quoted(&mut self) -> Result<String, String> {
+        let quote = self.peek().unwrap_or('\'');
+        self.pos += 1;
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c != quote) {
+            self.pos += 1;
+        }
+        if !self.eat(quote) {
+            return Err("unterminated string".to_string());
+        }
+        Ok(self.chars[start..self.pos - 1].iter().collect())
+    }
+
+    /// Parse `(@.path [op literal])`; the leading `?` is already consumed
+    fn filter(&mut self) -> Result<Filter, String> {
+        if !self.eat('(') {
+            return Err(format!("expected '(' after '?' at position {}", self.pos));
+        }
+        self.skip_ws();
+        if !self.eat('@') {
+            return Err(format!(
+                "filter must start with '@' at position {}",
+                self.pos
+            ));
+        }
+        let path = self.segments(true)?;
+        self.skip_ws();
+        let comparison = match self.operator() {
+            Some(op) => {
+                self.skip_ws();
+                Some((op, self.literal()?))
+            }
+            None => None,
+        };
+        self.skip_ws();
+        if !self.eat(')') {
+            return Err(format!("expected ')' at position {}", self.pos));
+        }
+        Ok(Filter { path, comparison })
+    }
+
+    fn operator(&mut self) -> Option<CmpOp> {
+        [
+            ("==", CmpOp::Eq),
+            ("!=", CmpOp::Ne),
+            ("<=", CmpOp::Le),
+            (">=", CmpOp::Ge),
+            ("<", CmpOp::Lt),
+            (">", CmpOp::Gt),
+        ]
+        .into_iter()
+        .find(|(token, _)| self.eat_str(token))
+        .map(|(_, op)| op)
+    }
+
+    fn literal(&mut self) -> Result<Value, String> {
+        if matches!(self.peek(), Some('\'') | Some('"')) {
+            return Ok(Value::String(self.quoted()?));
+        }
+        for (token, value) in [
+            ("true", Value::Bool(true)),
+            ("false", Value::Bool(false)),
+            ("null", Value::Null),
+        ] {
+            if self.eat_str(token) {
+                return Ok(value);
+            }
+        }
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || matches!(c, '-' | '.' | 'e' | 'E' | '+'))
+        {
+            self.pos += 1;
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        serde_json::from_str::<serde_json::Number>(&text)
+            .map(Value::Number)
+            .map_err(|_| format!("expected a literal at position {}", start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn doc() -> Value {
+        json!([
+            {"name": "alpha", "org": "acme", "resources": 10, "locked": false,
+             "attributes": {"tf-version": "1.5.0"}},
+            {"name": "beta", "org": "acme", "resources": 3, "locked": true,
+             "attributes": {"tf-version": "1.9.2"}},
+            {"name": "gamma", "org": "other", "resources": 0, "locked": false,
+             "attributes": {"tf-version": "1.9.2"}}
+        ])
+    }
+
+    fn eval(expr: &str) -> Option<Value> {
+        JsonPath::parse(expr).unwrap().apply(&doc())
+    }
+
+    #[test]
+    fn test_root_and_index() {
+        assert_eq!(eval("$"), Some(doc()));
+        assert_eq!(eval("$[0].name"), Some(json!("alpha")));
+        assert_eq!(eval("$[-1]['name']"), Some(json!("gamma")));
+        assert_eq!(eval("$[5].name"), None);
+    }
+
+    #[test]
+    fn test_wildcard_returns_array() {
+        assert_eq!(eval("$[*].name"), Some(json!(["alpha", "beta", "gamma"])));
+        assert_eq!(eval("$.*.org"), Some(json!(["acme", "acme", "other"])));
+    }
+
+    #[test]
+    fn test_hyphenated_keys() {
+        assert_eq!(eval("$[1].attributes.tf-version"), Some(json!("1.9.2")));
+    }
+
+    #[test]
+    fn test_slice_and_union() {
+        assert_eq!(eval("$[1:].name"), Some(json!(["beta", "gamma"])));
+        assert_eq!(eval("$[:1].name"), Some(json!(["alpha"])));
+        assert_eq!(eval("$[0,2].name"), Some(json!(["alpha", "gamma"])));
+        assert_eq!(eval("$[0]['name','org']"), Some(json!(["alpha", "acme"])));
+    }
+
+    #[test]
+    fn test_recursive_descent() {
+        assert_eq!(
+            eval("$..tf-version"),
+            Some(json!(["1.5.0", "1.9.2", "1.9.2"]))
+        );
+    }
+
+    #[test]
+    fn test_filters() {
+        assert_eq!(
+            eval("$[?(@.org == 'acme')].name"),
+            Some(json!(["alpha", "beta"]))
+        );
+        assert_eq!(
+            eval("$[?(@.resources > 2)].name"),
+            Some(json!(["alpha", "beta"]))
+        );
+        assert_eq!(eval("$[?(@.locked == true)].name"), Some(json!(["beta"])));
+        assert_eq!(
+            eval("$[?(@.attributes.tf-version != \"1.9.2\")].name"),
+            Some(json!(["alpha"]))
+        );
+        assert_eq!(eval("$[?(@.missing)].name"), None);
+    }
+
+    #[test]
+    fn test_no_match_is_none_not_empty_array() {
+        assert_eq!(eval("$[*].nope"), None);
+        assert_eq!(eval("$[?(@.org == 'nobody')]"), None);
+    }
+
+    #[test]
+    fn test_definite() {
+        assert!(JsonPath::parse("$.data[0].id").unwrap().is_definite());
+        assert!(!JsonPath::parse("$.data[*].id").unwrap().is_definite());
+        assert!(!JsonPath::parse("$..id").unwrap().is_definite());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in ["data.id", "$.", "$[", "$[0", "$['x]", "$[?(@.a ==)]", "$ x"] {
+            let err = JsonPath::parse(bad).unwrap_err();
+            assert!(err.starts_with("Invalid JSONPath"), "{}: {}", bad, err);
+        }
+    }
+}
@@ -1,39 +1,74 @@
 //! Output formatting module for all resources (organizations, projects, workspaces, oauth clients, runs, teams)
 
+mod account;
+mod assessments;
+mod color;
 mod common;
+mod graph;
+mod jsonpath;
+mod notification_configs;
 mod oauth_clients;
 pub mod org_memberships;
 mod organizations;
+mod policy_sets;
 mod projects;
+mod registry_modules;
+mod run_triggers;
 mod runs;
 mod state_versions;
 mod tags;
 mod team_access;
 mod teams;
+mod template;
+mod variable_sets;
+mod variables;
+mod workspace_diff;
 mod workspaces;
 
-pub use common::{escape_csv, output_raw};
+pub use account::output_whoami;
+pub use assessments::output_assessments;
+pub use color::{color_for, resolve_color, stderr_color_enabled};
+pub use common::{
+    apply_limit, apply_limit_grouped, check_jsonpath_matched, count_by, csv_delimiter, escape_csv,
+    finish_output_file, flatten_json, format_age, is_quiet, output_counts, output_raw,
+    output_raw_flattened, print_ids, print_line, print_lines, print_record, print_table,
+    print_text, render_options, set_output_file, set_render_options, to_json_line, to_json_pretty,
+    write_record, write_table, Formatter, RenderOptions,
+};
+pub use graph::output_graph;
+pub use notification_configs::output_notification_configs;
 pub use oauth_clients::output_oauth_clients;
 pub use organizations::output_organizations;
+pub use policy_sets::output_policy_sets;
 pub use projects::output_projects;
-pub use runs::{output_apply, output_plan, output_run_events, output_run_history, output_runs};
-pub use state_versions::output_state_versions;
+pub use registry_modules::output_registry_modules;
+pub use run_triggers::output_run_triggers;
+pub use runs::{
+    output_apply, output_configuration_version, output_cost_estimate, output_current_run,
+    output_plan, output_policy_checks, output_run_events, output_run_history, output_runs,
+};
+pub use state_versions::{output_state_version_outputs, output_state_versions};
 pub use tags::{
     output_org_tags, output_org_tags_with_workspaces, output_tag_bindings,
     output_workspace_all_tags,
 };
 pub use team_access::output_team_access;
-pub use teams::output_teams;
+pub use teams::{output_team_members, output_teams};
+pub use variable_sets::output_variable_sets;
+pub use variables::{output_variables, VariableRow};
+pub use workspace_diff::{
+    diff_workspaces, output_workspace_diff, FieldChange, WorkspaceChangeKind, WorkspaceDiff,
+};
 pub use workspaces::{
     output_workspace_resource_summary, InstanceResourceSummary, OrgResourceSummaryRow,
-    WorkspaceResourceSummary, WorkspaceRow,
+    WorkspaceResourceSummary, WorkspaceRow, WsColumn,
 };
 
-use workspaces::output_workspaces;
+use workspaces::{output_workspace_columns, output_workspaces};
 
 use std::collections::HashMap;
 
-use crate::cli::{Cli, Command, GetResource, WsSortField};
+use crate::cli::{Cli, Command, GetResource, OutputFormat, WsArgs, WsSortField};
 use crate::hcp::Workspace;
 
 /// Main entry point for sorted workspace output - converts raw data to WorkspaceRow and outputs
@@ -42,6 +77,7 @@ pub fn output_results_sorted(
     cli: &Cli,
     pending_counts: Option<&HashMap<String, usize>>,
     billable_counts: Option<&HashMap<String, u64>>,
+    project_names: Option<&HashMap<String, String>>,
 ) {
     let Command::Get {
         resource: GetResource::Ws(args),
@@ -50,21 +86,25 @@ pub fn output_results_sorted(
         unreachable!()
     };
 
-    // Convert to WorkspaceRow using the constructor
-    let mut rows: Vec<WorkspaceRow> = org_workspaces
-        .into_iter()
-        .flat_map(|(org, workspaces)| {
-            workspaces
-                .iter()
-                .map(move |ws| {
-                    let mut row = WorkspaceRow::new(&org, ws);
-                    row.pending_runs = pending_counts.and_then(|m| m.get(&ws.id).copied());
-                    row.billable = billable_counts.and_then(|m| m.get(&ws.id).copied());
-                    row
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect();
+    let mut rows = build_rows(
+        org_workspaces,
+        args,
+        pending_counts,
+        billable_counts,
+        project_names,
+    );
+
+    if let Some(field) = args.count_by {
+        let counts = workspaces::count_rows_by(&rows, field);
+        output_counts(
+            &counts,
+            &field.to_string(),
+            "workspaces",
+            &args.output,
+            cli.no_header,
+        );
+        return;
+    }
 
     // Sort
     rows.sort_by(|a, b| {
@@ -86,9 +126,133 @@ pub fn output_results_sorted(
             }
         }
 
-        // Then sort by selected field
-        match args.sort {
+        // Then sort by selected fields
+        compare_rows(a, b, &args.sort)
+    });
+
+    if args.reverse {
+        rows.reverse();
+    }
+
+    apply_limit(&mut rows, cli);
+
+    if cli.id_only {
+        print_ids(&rows);
+        return;
+    }
+
+    // --columns was validated before fetching, so a parse error can't happen here
+    let columns = args
+        .columns
+        .as_deref()
+        .and_then(|spec| parse_ws_columns(spec).ok());
+    match columns {
+        Some(columns)
+            if !matches!(
+                args.output,
+                OutputFormat::Json
+                    | OutputFormat::Yaml
+                    | OutputFormat::Ndjson
+                    | OutputFormat::Template
+            ) =>
+        {
+            output_workspace_columns(&rows, &columns, &args.output, cli.no_header, args.human)
+        }
+        _ => output_workspaces(&rows, &args.output, cli.no_header, args.flatten, args.human),
+    }
+}
+
+/// Parse a `--columns` spec (e.g. "name,id,resources,updated-at") into workspace columns
+///
+/// Tokens are case-insensitive and accept `_` for `-`; a few natural aliases are
+/// understood (workspace-name, workspace-id, prj-id, terraform-version, ...).
+pub fn parse_ws_columns(spec: &str) -> Result<Vec<WsColumn>, String> {
+    let columns: Vec<WsColumn> = spec
+        .split(',')
+        .map(|token| {
+            let normalized = token.trim().to_lowercase().replace('_', "-");
+            let canonical = match normalized.as_str() {
+                "workspace-name" | "ws-name" => "name",
+                "workspace-id" | "ws-id" => "id",
+                "prj-id" | "project" | "prj" => "project-id",
+                "prj-name" => "project-name",
+                "terraform-version" | "version" => "tf-version",
+                "mode" => "execution-mode",
+                "repo" | "vcs" => "vcs-repo",
+                "working-dir" | "workdir" => "working-directory",
+                other => other,
+            };
+            WsColumn::ALL
+                .iter()
+                .find(|(_, name)| *name == canonical)
+                .map(|(column, _)| *column)
+                .ok_or_else(|| {
+                    let valid: Vec<&str> = WsColumn::ALL.iter().map(|(_, name)| *name).collect();
+                    format!(
+                        "Unknown column '{}' in --columns. Valid columns: {}",
+                        token.trim(),
+                        valid.join(", ")
+                    )
+                })
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(columns)
+}
+
+/// Diff the workspace listing against a `--diff` baseline snapshot and output the changes
+pub fn output_results_diff(
+    org_workspaces: Vec<(String, Vec<Workspace>)>,
+    baseline: &[WorkspaceRow],
+    cli: &Cli,
+) {
+    let Command::Get {
+        resource: GetResource::Ws(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    let rows = build_rows(org_workspaces, args, None, None, None);
+    let diffs = diff_workspaces(baseline, &rows);
+    output_workspace_diff(&diffs, &args.output, cli.no_header);
+}
+
+/// Convert raw workspaces to WorkspaceRow, attaching the optional per-workspace extras
+fn build_rows(
+    org_workspaces: Vec<(String, Vec<Workspace>)>,
+    args: &WsArgs,
+    pending_counts: Option<&HashMap<String, usize>>,
+    billable_counts: Option<&HashMap<String, u64>>,
+    project_names: Option<&HashMap<String, String>>,
+) -> Vec<WorkspaceRow> {
+    org_workspaces
+        .into_iter()
+        .flat_map(|(org, workspaces)| {
+            workspaces
+                .iter()
+                .map(move |ws| {
+                    let mut row = WorkspaceRow::new(&org, ws);
+                    row.pending_runs = pending_counts.and_then(|m| m.get(&ws.id).copied());
+                    row.billable = billable_counts.and_then(|m| m.get(&ws.id).copied());
+                    row.project_name =
+                        project_names.map(|m| m.get(&row.project_id).cloned().unwrap_or_default());
+                    if args.wide {
+                        row.settings = Some(ws.attributes.settings.clone());
+                    }
+                    row
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Compare workspace rows by each sort field in turn, falling through on ties
+fn compare_rows(a: &WorkspaceRow, b: &WorkspaceRow, fields: &[WsSortField]) -> std::cmp::Ordering {
+    fields
+        .iter()
+        .map(|field| match field {
             WsSortField::Name => a.name.cmp(&b.name),
+            WsSortField::Org => a.org.cmp(&b.org),
             WsSortField::Resources => a.resources.cmp(&b.resources),
             WsSortField::UpdatedAt => a.updated_at.cmp(&b.updated_at),
             WsSortField::TfVersion => compare_versions(&a.terraform_version, &b.terraform_version),
@@ -96,17 +260,14 @@ pub fn output_results_sorted(
                 .pending_runs
                 .unwrap_or(0)
                 .cmp(&b.pending_runs.unwrap_or(0)),
-        }
-    });
-
-    if args.reverse {
-        rows.reverse();
-    }
-
-    output_workspaces(&rows, &args.output, cli.no_header);
+        })
+        .find(|ord| ord.is_ne())
+        .unwrap_or(std::cmp::Ordering::Equal)
 }
 
-/// Compare semantic versions (handles "unknown" and partial versions)
+/// Compare semantic versions (handles "unknown", partial and pre-release versions)
+///
+/// A pre-release (`1.6.0-beta1`) sorts before its release (`1.6.0`).
 fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
     use std::cmp::Ordering;
 
@@ -121,12 +282,21 @@ fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
         return Ordering::Less;
     }
 
-    // Parse version parts
-    let parse_parts =
-        |v: &str| -> Vec<u32> { v.split('.').filter_map(|p| p.parse::<u32>().ok()).collect() };
+    // Split off the pre-release suffix, then parse version parts
+    let split = |v: &str| -> (Vec<u32>, Option<String>) {
+        let (core, pre) = match v.split_once('-') {
+            Some((core, pre)) => (core, Some(pre.to_string())),
+            None => (v, None),
+        };
+        let parts = core
+            .split('.')
+            .filter_map(|p| p.parse::<u32>().ok())
+            .collect();
+        (parts, pre)
+    };
 
-    let a_parts = parse_parts(a);
-    let b_parts = parse_parts(b);
+    let (a_parts, a_pre) = split(a);
+    let (b_parts, b_pre) = split(b);
 
     // Compare part by part
     for (ap, bp) in a_parts.iter().zip(b_parts.iter()) {
@@ -137,7 +307,49 @@ fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
     }
 
     // If all compared parts are equal, longer version is greater
-    a_parts.len().cmp(&b_parts.len())
+    match a_parts.len().cmp(&b_parts.len()) {
+        Ordering::Equal => {}
+        other => return other,
+    }
+
+    // Same release: a pre-release sorts before it
+    match (a_pre, b_pre) {
+        (None, None) => Ordering::Equal,
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(a_pre), Some(b_pre)) => compare_prerelease(&a_pre, &b_pre),
+    }
+}
+
+/// Compare pre-release suffixes naturally: `alpha` < `beta` < `rc`, and
+/// numbers by value (`beta2` < `beta10`)
+fn compare_prerelease(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    // Runs of digits or non-digits, ignoring `.` separators
+    let chunks = |s: &str| -> Vec<String> {
+        let mut chunks: Vec<String> = Vec::new();
+        for c in s.chars().filter(|c| *c != '.') {
+            let digit = c.is_ascii_digit();
+            match chunks.last_mut() {
+                Some(last) if last.ends_with(|l: char| l.is_ascii_digit()) == digit => last.push(c),
+                _ => chunks.push(c.to_string()),
+            }
+        }
+        chunks
+    };
+
+    let (a, b) = (chunks(a), chunks(b));
+    for (ac, bc) in a.iter().zip(&b) {
+        let ord = match (ac.parse::<u64>(), bc.parse::<u64>()) {
+            (Ok(an), Ok(bn)) => an.cmp(&bn),
+            _ => ac.cmp(bc),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    a.len().cmp(&b.len())
 }
 
 #[cfg(test)]
@@ -145,6 +357,31 @@ mod tests {
     use super::*;
     use std::cmp::Ordering;
 
+    #[test]
+    fn test_parse_ws_columns() {
+        assert_eq!(
+            parse_ws_columns("name,id,resources,updated-at").unwrap(),
+            vec![
+                WsColumn::Name,
+                WsColumn::Id,
+                WsColumn::Resources,
+                WsColumn::UpdatedAt
+            ]
+        );
+        assert_eq!(
+            parse_ws_columns(" Workspace_Name , TF_VERSION,prj-id").unwrap(),
+            vec![WsColumn::Name, WsColumn::TfVersion, WsColumn::ProjectId]
+        );
+    }
+
+    #[test]
+    fn test_parse_ws_columns_unknown_token() {
+        let err = parse_ws_columns("name,colour").unwrap_err();
+        assert!(err.contains("Unknown column 'colour'"));
+        assert!(err.contains("updated-at"));
+        assert!(parse_ws_columns("name,").is_err());
+    }
+
     #[test]
     fn test_compare_versions_equal() {
         assert_eq!(compare_versions("1.5.0", "1.5.0"), Ordering::Equal);
@@ -175,4 +412,91 @@ mod tests {
     fn test_compare_versions_both_unknown() {
         assert_eq!(compare_versions("unknown", "unknown"), Ordering::Equal);
     }
+
+    #[test]
+    fn test_compare_versions_pre_release_before_release() {
+        assert_eq!(compare_versions("1.6.0-beta1", "1.6.0"), Ordering::Less);
+        assert_eq!(compare_versions("1.6.0", "1.6.0-rc1"), Ordering::Greater);
+        assert_eq!(compare_versions("1.6.0-rc1", "1.5.7"), Ordering::Greater);
+        assert_eq!(compare_versions("1.6.0-rc1", "unknown"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_compare_versions_alpha_beta_rc_ordering() {
+        let mut versions = vec![
+            "1.6.0",
+            "1.6.0-rc1",
+            "unknown",
+            "1.6.0-beta10",
+            "1.6.0-alpha20230719",
+            "1.6.0-beta2",
+            "1.5.7",
+            "1.6.0-rc.2",
+        ];
+        versions.sort_by(|a, b| compare_versions(a, b));
+        assert_eq!(
+            versions,
+            [
+                "1.5.7",
+                "1.6.0-alpha20230719",
+                "1.6.0-beta2",
+                "1.6.0-beta10",
+                "1.6.0-rc1",
+                "1.6.0-rc.2",
+                "1.6.0",
+                "unknown",
+            ]
+        );
+        assert_eq!(
+            compare_versions("1.6.0-beta1", "1.6.0-beta1"),
+            Ordering::Equal
+        );
+    }
+
+    fn row(org: &str, name: &str, resources: u32) -> WorkspaceRow {
+        WorkspaceRow {
+            org: org.to_string(),
+            vcs_repo: String::new(),
+            working_directory: String::new(),
+            project_id: String::new(),
+            project_name: None,
+            name: name.to_string(),
+            id: format!("ws-{}", name),
+            resources,
+            billable: None,
+            execution_mode: "remote".to_string(),
+            locked: false,
+            terraform_version: "1.5.0".to_string(),
+            updated_at: String::new(),
+            pending_runs: None,
+            settings: None,
+        }
+    }
+
+    #[test]
+    fn test_compare_rows_two_keys() {
+        let mut rows = [
+            row("beta", "a", 5),
+            row("alpha", "b", 10),
+            row("beta", "c", 1),
+            row("alpha", "d", 2),
+        ];
+        rows.sort_by(|a, b| compare_rows(a, b, &[WsSortField::Org, WsSortField::Resources]));
+        let names: Vec<_> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["d", "b", "c", "a"]);
+    }
+
+    #[test]
+    fn test_compare_rows_single_key_tie_is_equal() {
+        let a = row("x", "a", 3);
+        let b = row("y", "b", 3);
+        assert_eq!(
+            compare_rows(&a, &b, &[WsSortField::Resources]),
+            Ordering::Equal
+        );
+        assert_eq!(
+            compare_rows(&a, &b, &[WsSortField::Resources, WsSortField::Name]),
+            Ordering::Less
+        );
+    }
 }
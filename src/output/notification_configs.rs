@@ -0,0 +1,295 @@
+//! Notification configuration output formatter
+
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, header_record, write_record, Formatter, RenderOptions};
+use crate::cli::{Cli, Command, GetResource, OutputFormat};
+use crate::hcp::NotificationConfig;
+use serde::Serialize;
+
+/// Number of triggers listed in table rows before summarizing the rest
+const LISTED_TRIGGERS: usize = 2;
+
+/// Serializable notification configuration for structured output (JSON/YAML)
+///
+/// The redacted URL and email addresses are only included for a single
+/// configuration.
+#[derive(Serialize)]
+struct SerializableNotificationConfig {
+    id: String,
+    name: String,
+    destination_type: String,
+    enabled: bool,
+    triggers: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email_addresses: Option<Vec<String>>,
+}
+
+impl SerializableNotificationConfig {
+    fn new(config: &NotificationConfig, detail: bool) -> Self {
+        let attrs = &config.attributes;
+        Self {
+            id: config.id.clone(),
+            name: config.name().to_string(),
+            destination_type: config.destination_type().to_string(),
+            enabled: attrs.enabled,
+            triggers: attrs.triggers.clone(),
+            url: detail.then(|| config.redacted_url()).flatten(),
+            email_addresses: (detail && !attrs.email_addresses.is_empty())
+                .then(|| attrs.email_addresses.clone()),
+        }
+    }
+}
+
+/// Output notification configurations in the specified format
+///
+/// A single configuration (looked up by ID) shows every trigger and the
+/// redacted destination URL; lists summarize long trigger lists.
+pub fn output_notification_configs(configs: &[NotificationConfig], cli: &Cli) {
+    let Command::Get {
+        resource: GetResource::NotificationConfig(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if cli.id_only {
+        super::common::print_ids(configs);
+        return;
+    }
+
+    NotificationConfigFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+        detail: args.id.is_some(),
+    }
+    .print(configs);
+}
+
+/// Notification configuration formatter; `detail` adds the redacted destination
+pub struct NotificationConfigFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub detail: bool,
+}
+
+impl Formatter for NotificationConfigFormatter {
+    type Row = NotificationConfig;
+
+    fn format_to<W: Write>(
+        &self,
+        configs: &[NotificationConfig],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        let detail = self.detail;
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => output_table(
+                writer,
+                configs,
+                self.no_header,
+                detail,
+                &self.format,
+                options,
+            ),
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(configs, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            OutputFormat::Json => {
+                super::common::write_json(writer, &serializable(configs, detail), options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable(configs, detail)),
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                configs
+                    .iter()
+                    .map(|nc| SerializableNotificationConfig::new(nc, detail)),
+                &self.format,
+                options,
+            ),
+        }
+    }
+}
+
+fn serializable(
+    configs: &[NotificationConfig],
+    detail: bool,
+) -> Vec<SerializableNotificationConfig> {
+    configs
+        .iter()
+        .map(|nc| SerializableNotificationConfig::new(nc, detail))
+        .collect()
+}
+
+/// Triggers for a table cell, e.g. "run:errored, run:needs_attention (+3 more)"
+fn summarize_triggers(triggers: &[String]) -> String {
+    if triggers.len() <= LISTED_TRIGGERS + 1 {
+        return triggers.join(", ");
+    }
+    format!(
+        "{} (+{} more)",
+        triggers[..LISTED_TRIGGERS].join(", "),
+        triggers.len() - LISTED_TRIGGERS
+    )
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    configs: &[NotificationConfig],
+    no_header: bool,
+    detail: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
+    if !no_header {
+        let mut header = vec!["ID", "Name", "Destination", "Enabled", "Triggers"];
+        if detail {
+            header.push("URL");
+        }
+        table.set_header(header);
+    }
+
+    for config in configs {
+        let attrs = &config.attributes;
+        let mut row = vec![
+            config.id.clone(),
+            super::common::table_name(config.name(), options),
+            config.destination_type().to_string(),
+            if attrs.enabled { "Yes" } else { "No" }.to_string(),
+            if detail {
+                attrs.triggers.join("\n")
+            } else {
+                summarize_triggers(&attrs.triggers)
+            },
+        ];
+        if detail {
+            row.push(match config.redacted_url() {
+                Some(url) => url,
+                None => attrs.email_addresses.join(", "),
+            });
+        }
+        table.add_row(row);
+    }
+
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) && !detail {
+        writeln!(
+            writer,
+            "\nTotal: {} notification configurations",
+            configs.len()
+        )?;
+    }
+    Ok(())
+}
+
+fn csv_records(configs: &[NotificationConfig], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(configs.len() + 1);
+    if !no_header {
+        records.push(header_record(&[
+            "id",
+            "name",
+            "destination_type",
+            "enabled",
+            "triggers",
+        ]));
+    }
+    for config in configs {
+        records.push(vec![
+            config.id.clone(),
+            config.name().to_string(),
+            config.destination_type().to_string(),
+            config.attributes.enabled.to_string(),
+            config.attributes.triggers.join(";"),
+        ]);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(triggers: &[&str]) -> NotificationConfig {
+        serde_json::from_value(serde_json::json!({
+            "id": "nc-abc",
+            "attributes": {
+                "name": "alerts, prod",
+                "destination-type": "slack",
+                "enabled": true,
+                "triggers": triggers,
+                "url": "https://hooks.slack.com/services/T0/B0/secret"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn render(configs: &[NotificationConfig], format: OutputFormat, detail: bool) -> String {
+        let formatter = NotificationConfigFormatter {
+            format,
+            no_header: false,
+            detail,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(configs, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_csv_output() {
+        let configs = [config(&["run:errored", "run:completed"])];
+        assert_eq!(
+            render(&configs, OutputFormat::Csv, false),
+            "id,name,destination_type,enabled,triggers\n\
+             nc-abc,\"alerts, prod\",slack,true,run:errored;run:completed\n"
+        );
+    }
+
+    #[test]
+    fn test_table_output_redacts_url_in_detail() {
+        let configs = [config(&["run:errored"])];
+        let list = render(&configs, OutputFormat::Table, false);
+        assert!(!list.contains("hooks.slack.com"));
+        assert!(list.ends_with("\nTotal: 1 notification configurations\n"));
+
+        let detail = render(&configs, OutputFormat::Table, true);
+        assert!(detail.contains("https://hooks.slack.com/***"));
+        assert!(!detail.contains("secret"));
+    }
+
+    #[test]
+    fn test_summarize_triggers() {
+        let triggers: Vec<String> = ["run:created", "run:planning", "run:errored"]
+            .map(String::from)
+            .to_vec();
+        assert_eq!(
+            summarize_triggers(&triggers),
+            "run:created, run:planning, run:errored"
+        );
+
+        let mut more = triggers.clone();
+        more.push("run:completed".to_string());
+        assert_eq!(
+            summarize_triggers(&more),
+            "run:created, run:planning (+2 more)"
+        );
+        assert_eq!(summarize_triggers(&[]), "");
+    }
+
+    #[test]
+    fn test_serializable_detail_includes_redacted_url() {
+        let nc = config(&["run:errored"]);
+        let json = serde_json::to_value(SerializableNotificationConfig::new(&nc, true)).unwrap();
+        assert_eq!(json["url"], "https://hooks.slack.com/***");
+        assert_eq!(json["triggers"], serde_json::json!(["run:errored"]));
+        assert!(json.get("email_addresses").is_none());
+
+        let json = serde_json::to_value(SerializableNotificationConfig::new(&nc, false)).unwrap();
+        assert!(json.get("url").is_none());
+    }
+}
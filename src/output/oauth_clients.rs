@@ -1,14 +1,37 @@
 //! OAuth Client output formatter
 
-use super::common::escape_csv;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, write_record, Formatter, RenderOptions};
 use crate::cli::{Cli, Command, GetResource, OutputFormat};
-use crate::hcp::{OAuthClient, TfeResource};
-use comfy_table::{presets::NOTHING, Table};
+use crate::hcp::{OAuthClient, OAuthToken, TfeResource};
 use serde::Serialize;
 
 /// OAuth Client row type alias
 pub type OAuthClientRow = (String, Vec<OAuthClient>);
 
+/// OAuth tokens keyed by OAuth client ID (`--with-tokens`)
+pub type OAuthClientTokens = HashMap<String, Vec<OAuthToken>>;
+
+/// Serializable OAuth token metadata (`--with-tokens`)
+#[derive(Serialize)]
+struct SerializableOAuthToken {
+    id: String,
+    service_provider_user: String,
+    created_at: String,
+}
+
+impl From<&OAuthToken> for SerializableOAuthToken {
+    fn from(token: &OAuthToken) -> Self {
+        Self {
+            id: token.id.clone(),
+            service_provider_user: token.service_provider_user().to_string(),
+            created_at: token.created_at().to_string(),
+        }
+    }
+}
+
 /// Serializable OAuth Client for structured output (JSON/YAML)
 #[derive(Serialize)]
 struct SerializableOAuthClient {
@@ -21,10 +44,12 @@ struct SerializableOAuthClient {
     created_at: String,
     organization_scoped: bool,
     oauth_token_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    oauth_tokens: Option<Vec<SerializableOAuthToken>>,
 }
 
 impl SerializableOAuthClient {
-    fn from_client(org: &str, client: &OAuthClient) -> Self {
+    fn from_client(org: &str, client: &OAuthClient, tokens: Option<&OAuthClientTokens>) -> Self {
         Self {
             org: org.to_string(),
             id: client.id.clone(),
@@ -39,12 +64,30 @@ impl SerializableOAuthClient {
                 .iter()
                 .map(|s| s.to_string())
                 .collect(),
+            oauth_tokens: tokens.map(|t| {
+                client_tokens(t, client)
+                    .iter()
+                    .map(SerializableOAuthToken::from)
+                    .collect()
+            }),
         }
     }
 }
 
+/// Fetched tokens of one client; empty when its tokens could not be loaded
+fn client_tokens<'a>(tokens: &'a OAuthClientTokens, client: &OAuthClient) -> &'a [OAuthToken] {
+    tokens.get(&client.id).map(Vec::as_slice).unwrap_or(&[])
+}
+
 /// Output OAuth clients in the specified format
-pub fn output_oauth_clients(clients: &[OAuthClientRow], cli: &Cli) {
+///
+/// With `tokens` (`--with-tokens`), the token column shows each token's
+/// service-provider user and creation time next to its ID.
+pub fn output_oauth_clients(
+    clients: &[OAuthClientRow],
+    tokens: Option<&OAuthClientTokens>,
+    cli: &Cli,
+) {
     let Command::Get {
         resource: GetResource::Oc(args),
     } = &cli.command
@@ -52,17 +95,78 @@ pub fn output_oauth_clients(clients: &[OAuthClientRow], cli: &Cli) {
         unreachable!()
     };
 
-    match args.output {
-        OutputFormat::Table => output_table(clients, cli.no_header),
-        OutputFormat::Csv => output_csv(clients, cli.no_header),
-        OutputFormat::Json => output_json(clients),
-        OutputFormat::Yaml => output_yaml(clients),
+    if cli.id_only {
+        super::common::print_ids(clients.iter().flat_map(|(_, org_clients)| org_clients));
+        return;
+    }
+
+    OAuthClientFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+        tokens,
+    }
+    .print(clients);
+}
+
+/// OAuth client formatter; rows are grouped by organization
+pub struct OAuthClientFormatter<'a> {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub tokens: Option<&'a OAuthClientTokens>,
+}
+
+impl Formatter for OAuthClientFormatter<'_> {
+    type Row = OAuthClientRow;
+
+    fn format_to<W: Write>(
+        &self,
+        clients: &[OAuthClientRow],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => output_table(
+                writer,
+                clients,
+                self.tokens,
+                self.no_header,
+                &self.format,
+                options,
+            ),
+            OutputFormat::Csv | OutputFormat::Tsv => output_csv(
+                writer,
+                clients,
+                self.tokens,
+                self.no_header,
+                csv_delimiter(&self.format),
+            ),
+            OutputFormat::Json => super::common::write_json(
+                writer,
+                &build_serializable_clients(clients, self.tokens),
+                options,
+            ),
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                build_serializable_clients(clients, self.tokens),
+                &self.format,
+                options,
+            ),
+            OutputFormat::Yaml => {
+                super::common::write_yaml(writer, &build_serializable_clients(clients, self.tokens))
+            }
+        }
     }
 }
 
-fn output_table(clients: &[OAuthClientRow], no_header: bool) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+fn output_table<W: Write>(
+    writer: &mut W,
+    clients: &[OAuthClientRow],
+    tokens: Option<&OAuthClientTokens>,
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
     if !no_header {
         table.set_header(vec![
             "Org",
@@ -84,7 +188,21 @@ fn output_table(clients: &[OAuthClientRow], no_header: bool) {
             } else {
                 "No"
             };
-            let token_ids = client.oauth_token_ids().join(", ");
+            let token_ids = match tokens {
+                Some(tokens) => client_tokens(tokens, client)
+                    .iter()
+                    .map(|t| {
+                        format!(
+                            "{} ({}, {})",
+                            t.id,
+                            non_empty(t.service_provider_user()),
+                            non_empty(t.created_at())
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                None => client.oauth_token_ids().join(", "),
+            };
             table.add_row(vec![
                 org_name,
                 &client.id,
@@ -99,58 +217,95 @@ fn output_table(clients: &[OAuthClientRow], no_header: bool) {
         }
     }
 
-    println!();
-    println!("{table}");
-    if !no_header {
-        println!("\nTotal: {} OAuth clients", total);
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) {
+        writeln!(writer, "\nTotal: {} OAuth clients", total)?;
+    }
+    Ok(())
+}
+
+fn non_empty(value: &str) -> &str {
+    if value.is_empty() {
+        "-"
+    } else {
+        value
     }
 }
 
-fn output_csv(clients: &[OAuthClientRow], no_header: bool) {
+fn output_csv<W: Write>(
+    writer: &mut W,
+    clients: &[OAuthClientRow],
+    tokens: Option<&OAuthClientTokens>,
+    no_header: bool,
+    delimiter: char,
+) -> io::Result<()> {
     if !no_header {
-        println!("org,id,name,service_provider,service_provider_display_name,http_url,created_at,organization_scoped,oauth_token_ids");
+        let mut header = vec![
+            "org",
+            "id",
+            "name",
+            "service_provider",
+            "service_provider_display_name",
+            "http_url",
+            "created_at",
+            "organization_scoped",
+            "oauth_token_ids",
+        ];
+        if tokens.is_some() {
+            header.extend(["oauth_token_users", "oauth_token_created_at"]);
+        }
+        write_record(writer, &header, delimiter)?;
     }
 
     for (org_name, org_clients) in clients {
         for client in org_clients {
-            let token_ids = client.oauth_token_ids().join(";");
-            println!(
-                "{},{},{},{},{},{},{},{},{}",
-                escape_csv(org_name),
-                escape_csv(&client.id),
-                escape_csv(client.name()),
-                escape_csv(client.service_provider()),
-                escape_csv(client.service_provider_display_name()),
-                escape_csv(client.http_url()),
-                escape_csv(client.created_at()),
-                client.is_organization_scoped(),
-                escape_csv(&token_ids)
-            );
+            let mut record = vec![
+                org_name.clone(),
+                client.id.clone(),
+                client.name().to_string(),
+                client.service_provider().to_string(),
+                client.service_provider_display_name().to_string(),
+                client.http_url().to_string(),
+                client.created_at().to_string(),
+                client.is_organization_scoped().to_string(),
+            ];
+            match tokens {
+                // IDs come from the fetched tokens so the `;`-joined columns line up
+                Some(tokens) => {
+                    let fetched = client_tokens(tokens, client);
+                    let columns: [fn(&OAuthToken) -> &str; 3] = [
+                        |t| t.id.as_str(),
+                        OAuthToken::service_provider_user,
+                        OAuthToken::created_at,
+                    ];
+                    for column in columns {
+                        let values: Vec<&str> = fetched.iter().map(column).collect();
+                        record.push(values.join(";"));
+                    }
+                }
+                None => record.push(client.oauth_token_ids().join(";")),
+            }
+            write_record(writer, &record, delimiter)?;
         }
     }
+    Ok(())
 }
 
-fn build_serializable_clients(clients: &[OAuthClientRow]) -> Vec<SerializableOAuthClient> {
+fn build_serializable_clients(
+    clients: &[OAuthClientRow],
+    tokens: Option<&OAuthClientTokens>,
+) -> Vec<SerializableOAuthClient> {
     clients
         .iter()
         .flat_map(|(org_name, org_clients)| {
             org_clients
                 .iter()
-                .map(|c| SerializableOAuthClient::from_client(org_name, c))
+                .map(move |c| SerializableOAuthClient::from_client(org_name, c, tokens))
         })
         .collect()
 }
 
-fn output_json(clients: &[OAuthClientRow]) {
-    let data = build_serializable_clients(clients);
-    super::common::print_json(&data);
-}
-
-fn output_yaml(clients: &[OAuthClientRow]) {
-    let data = build_serializable_clients(clients);
-    super::common::print_yaml(&data);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,39 +329,113 @@ mod tests {
         }
     }
 
+    fn render(clients: &[OAuthClientRow], format: OutputFormat, no_header: bool) -> String {
+        let mut buf = Vec::new();
+        OAuthClientFormatter {
+            format,
+            no_header,
+            tokens: None,
+        }
+        .format_to(clients, &mut buf, &RenderOptions::default())
+        .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
     fn test_output_table_empty() {
-        output_table(&[], false);
+        let out = render(&[], OutputFormat::Table, false);
+        assert!(out.contains("Provider"));
+        assert!(out.ends_with("\nTotal: 0 OAuth clients\n"));
     }
 
     #[test]
     fn test_output_table() {
         let clients = vec![("test-org".to_string(), vec![create_test_oauth_client()])];
-        output_table(&clients, false);
+        let out = render(&clients, OutputFormat::Table, false);
+        assert!(out.contains("test-org"));
+        assert!(out.contains("oc-123"));
+        assert!(out.contains("My GitHub"));
+        assert!(out.ends_with("\nTotal: 1 OAuth clients\n"));
     }
 
     #[test]
     fn test_output_csv() {
         let clients = vec![("test-org".to_string(), vec![create_test_oauth_client()])];
-        output_csv(&clients, false);
+        let out = render(&clients, OutputFormat::Csv, false);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("org,id,name,service_provider,"));
+        assert_eq!(
+            lines[1],
+            "test-org,oc-123,My GitHub,github,GitHub,https://github.com,2025-01-01T00:00:00Z,true,"
+        );
     }
 
     #[test]
     fn test_output_json() {
         let clients = vec![("test-org".to_string(), vec![create_test_oauth_client()])];
-        output_json(&clients);
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&clients, OutputFormat::Json, false)).unwrap();
+        assert_eq!(json[0]["org"], "test-org");
+        assert_eq!(json[0]["organization_scoped"], true);
     }
 
     #[test]
     fn test_output_yaml() {
         let clients = vec![("test-org".to_string(), vec![create_test_oauth_client()])];
-        output_yaml(&clients);
+        let yaml = render(&clients, OutputFormat::Yaml, false);
+        assert!(yaml.contains("id: oc-123"));
+        assert!(yaml.contains("service_provider: github"));
+    }
+
+    fn with_tokens(format: OutputFormat) -> String {
+        let clients = vec![("test-org".to_string(), vec![create_test_oauth_client()])];
+        let token: OAuthToken = serde_json::from_value(serde_json::json!({
+            "id": "ot-1",
+            "type": "oauth-tokens",
+            "attributes": {
+                "created-at": "2025-02-01T00:00:00Z",
+                "service-provider-user": "octocat"
+            }
+        }))
+        .unwrap();
+        let tokens = OAuthClientTokens::from([("oc-123".to_string(), vec![token])]);
+        let mut buf = Vec::new();
+        OAuthClientFormatter {
+            format,
+            no_header: false,
+            tokens: Some(&tokens),
+        }
+        .format_to(&clients, &mut buf, &RenderOptions::default())
+        .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_output_with_tokens() {
+        assert!(with_tokens(OutputFormat::Table).contains("ot-1 (octocat, 2025-02-01T00:00:00Z)"));
+
+        let csv = with_tokens(OutputFormat::Csv);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].ends_with(",oauth_token_ids,oauth_token_users,oauth_token_created_at"));
+        assert!(lines[1].ends_with(",true,ot-1,octocat,2025-02-01T00:00:00Z"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&with_tokens(OutputFormat::Json)).unwrap();
+        assert_eq!(
+            json[0]["oauth_tokens"][0]["service_provider_user"],
+            "octocat"
+        );
+        let clients = vec![("test-org".to_string(), vec![create_test_oauth_client()])];
+        assert!(!render(&clients, OutputFormat::Json, false).contains("oauth_tokens"));
     }
 
     #[test]
     fn test_output_no_header() {
         let clients = vec![("test-org".to_string(), vec![create_test_oauth_client()])];
-        output_table(&clients, true);
-        output_csv(&clients, true);
+        let table = render(&clients, OutputFormat::Table, true);
+        assert!(!table.contains("Provider"));
+        assert!(!table.contains("Total:"));
+        assert_eq!(render(&clients, OutputFormat::Csv, true).lines().count(), 1);
     }
 }
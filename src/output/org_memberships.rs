@@ -1,7 +1,10 @@
 //! Output formatting for organization memberships
 
+use std::io::{self, Write};
+
 use comfy_table::{presets::UTF8_FULL_CONDENSED, Cell, CellAlignment, ContentArrangement, Table};
 
+use super::common::{csv_delimiter, write_record, Formatter, RenderOptions};
 use crate::hcp::OrganizationMembership;
 use crate::{OrgMemberArgs, OutputFormat};
 
@@ -11,82 +14,118 @@ pub fn output_org_memberships(
     args: &OrgMemberArgs,
     no_header: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    match args.output {
-        OutputFormat::Json => output_json(memberships),
-        OutputFormat::Yaml => output_yaml(memberships),
-        OutputFormat::Csv => output_csv(memberships, no_header),
-        OutputFormat::Table => output_table(memberships, no_header),
+    OrgMembershipFormatter {
+        format: args.output,
+        no_header,
     }
+    .print(memberships);
+    Ok(())
 }
 
-fn output_json(
-    memberships: &[(String, OrganizationMembership)],
-) -> Result<(), Box<dyn std::error::Error>> {
-    let output: Vec<_> = memberships
-        .iter()
-        .map(|(org, m)| {
-            serde_json::json!({
-                "id": m.id,
-                "organization": org,
-                "email": m.email(),
-                "status": m.status(),
-                "created_at": m.created_at(),
-                "teams": m.team_ids()
-            })
-        })
-        .collect();
-    super::common::print_json(&output);
-    Ok(())
+/// Organization membership formatter; rows pair the organization name with the membership
+pub struct OrgMembershipFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
 }
 
-fn output_yaml(
-    memberships: &[(String, OrganizationMembership)],
-) -> Result<(), Box<dyn std::error::Error>> {
-    let output: Vec<_> = memberships
+impl Formatter for OrgMembershipFormatter {
+    type Row = (String, OrganizationMembership);
+
+    fn format_to<W: Write>(
+        &self,
+        memberships: &[(String, OrganizationMembership)],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Json => super::common::write_json(writer, &records(memberships), options),
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                super::common::write_lines(writer, records(memberships), &self.format, options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &records(memberships)),
+            OutputFormat::Csv | OutputFormat::Tsv => output_csv(
+                writer,
+                memberships,
+                self.no_header,
+                csv_delimiter(&self.format),
+            ),
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_table(writer, memberships, self.no_header, &self.format)
+            }
+        }
+    }
+}
+
+/// Structured (JSON/YAML/NDJSON) record for a membership
+///
+/// `attributes` carries the raw API attributes for access-review tooling.
+pub fn membership_json(org: &str, m: &OrganizationMembership) -> serde_json::Value {
+    serde_json::json!({
+        "id": m.id,
+        "organization": org,
+        "email": m.email(),
+        "status": m.status(),
+        "created_at": m.created_at(),
+        "teams": m.team_ids(),
+        "team_count": m.team_count(),
+        "attributes": m.attributes
+    })
+}
+
+fn records(memberships: &[(String, OrganizationMembership)]) -> Vec<serde_json::Value> {
+    memberships
         .iter()
-        .map(|(org, m)| {
-            serde_json::json!({
-                "id": m.id,
-                "organization": org,
-                "email": m.email(),
-                "status": m.status(),
-                "created_at": m.created_at(),
-                "teams": m.team_ids()
-            })
-        })
-        .collect();
-    super::common::print_yaml(&output);
-    Ok(())
+        .map(|(org, m)| membership_json(org, m))
+        .collect()
 }
 
-fn output_csv(
+fn output_csv<W: Write>(
+    writer: &mut W,
     memberships: &[(String, OrganizationMembership)],
     no_header: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
+    delimiter: char,
+) -> io::Result<()> {
     if !no_header {
-        println!("id,organization,email,status,created_at,teams");
+        write_record(
+            writer,
+            &[
+                "id",
+                "organization",
+                "email",
+                "status",
+                "created_at",
+                "teams",
+                "team_count",
+            ],
+            delimiter,
+        )?;
     }
     for (org, m) in memberships {
-        println!(
-            "{},{},{},{},{},\"{}\"",
-            m.id,
-            org,
-            m.email(),
-            m.status(),
-            m.created_at(),
-            m.team_ids().join(",")
-        );
+        write_record(
+            writer,
+            &[
+                m.id.clone(),
+                org.clone(),
+                m.email().to_string(),
+                m.status().to_string(),
+                m.created_at().to_string(),
+                m.team_ids().join(","),
+                m.team_count().to_string(),
+            ],
+            delimiter,
+        )?;
     }
     Ok(())
 }
 
-fn output_table(
+fn output_table<W: Write>(
+    writer: &mut W,
     memberships: &[(String, OrganizationMembership)],
     no_header: bool,
-) -> Result<(), Box<dyn std::error::Error>> {
+    format: &OutputFormat,
+) -> io::Result<()> {
     if memberships.is_empty() {
-        println!("No organization members found");
-        return Ok(());
+        return writeln!(writer, "No organization members found");
     }
 
     let mut table = Table::new();
@@ -110,10 +149,81 @@ fn output_table(
             Cell::new(org),
             Cell::new(m.email()),
             Cell::new(m.status()),
-            Cell::new(m.team_ids().join(", ")).set_alignment(CellAlignment::Right),
+            Cell::new(m.team_count()).set_alignment(CellAlignment::Right),
         ]);
     }
 
-    println!("{table}");
-    Ok(())
+    super::common::write_table(writer, &table, format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_membership_json_includes_team_count_and_raw_attributes() {
+        let m: OrganizationMembership = serde_json::from_value(serde_json::json!({
+            "id": "ou-1",
+            "attributes": {
+                "email": "a@example.com",
+                "status": "invited",
+                "created-at": "2024-01-15T10:30:00Z"
+            },
+            "relationships": {
+                "teams": { "data": [{ "id": "team-1", "type": "teams" }] }
+            }
+        }))
+        .unwrap();
+
+        let json = membership_json("my-org", &m);
+
+        assert_eq!(json["organization"], "my-org");
+        assert_eq!(json["status"], "invited");
+        assert_eq!(json["team_count"], 1);
+        assert_eq!(json["attributes"]["created-at"], "2024-01-15T10:30:00Z");
+    }
+
+    fn membership() -> (String, OrganizationMembership) {
+        let m = serde_json::from_value(serde_json::json!({
+            "id": "ou-1",
+            "attributes": {"email": "a@example.com", "status": "active"},
+            "relationships": {
+                "teams": {"data": [{"id": "team-1", "type": "teams"}, {"id": "team-2", "type": "teams"}]}
+            }
+        }))
+        .unwrap();
+        ("my-org".to_string(), m)
+    }
+
+    fn render(memberships: &[(String, OrganizationMembership)], format: OutputFormat) -> String {
+        let formatter = OrgMembershipFormatter {
+            format,
+            no_header: false,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(memberships, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_csv_output_quotes_team_list() {
+        assert_eq!(
+            render(&[membership()], OutputFormat::Csv),
+            "id,organization,email,status,created_at,teams,team_count\n\
+             ou-1,my-org,a@example.com,active,,\"team-1,team-2\",2\n"
+        );
+    }
+
+    #[test]
+    fn test_table_output() {
+        let out = render(&[membership()], OutputFormat::Table);
+        assert!(out.contains("ORGANIZATION"));
+        assert!(out.contains("a@example.com"));
+        assert_eq!(
+            render(&[], OutputFormat::Table),
+            "No organization members found\n"
+        );
+    }
 }
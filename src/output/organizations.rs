@@ -1,9 +1,10 @@
 //! Organization output formatter
 
-use super::common::escape_csv;
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, write_record, Formatter, RenderOptions};
 use crate::cli::{Cli, Command, GetResource, OutputFormat};
 use crate::hcp::{OrganizationWithTokens, TfeResource};
-use comfy_table::{presets::NOTHING, Table};
 use serde::Serialize;
 
 /// Serializable organization for structured output (JSON/YAML)
@@ -49,17 +50,66 @@ pub fn output_organizations(orgs: &[OrganizationWithTokens], cli: &Cli) {
         unreachable!()
     };
 
-    match args.output {
-        OutputFormat::Table => output_table(orgs, cli.no_header),
-        OutputFormat::Csv => output_csv(orgs, cli.no_header),
-        OutputFormat::Json => output_json(orgs),
-        OutputFormat::Yaml => output_yaml(orgs),
+    if cli.id_only {
+        super::common::print_ids(orgs.iter().map(|owt| &owt.organization));
+        return;
+    }
+
+    OrganizationFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+        detail: args.name.is_some(),
     }
+    .print(orgs);
+}
+
+/// Organization formatter; `detail` adds org-wide defaults and policies to the table
+pub struct OrganizationFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub detail: bool,
 }
 
-fn output_table(orgs: &[OrganizationWithTokens], no_header: bool) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+impl Formatter for OrganizationFormatter {
+    type Row = OrganizationWithTokens;
+
+    fn format_to<W: Write>(
+        &self,
+        orgs: &[OrganizationWithTokens],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown if self.detail => {
+                output_detail_table(writer, orgs, self.no_header, &self.format, options)
+            }
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_table(writer, orgs, self.no_header, &self.format, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                output_csv(writer, orgs, self.no_header, csv_delimiter(&self.format))
+            }
+            OutputFormat::Json => super::common::write_json(writer, &serializable(orgs), options),
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                super::common::write_lines(writer, serializable(orgs), &self.format, options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable(orgs)),
+        }
+    }
+}
+
+fn serializable(orgs: &[OrganizationWithTokens]) -> Vec<SerializableOrganization> {
+    orgs.iter().map(|o| o.into()).collect()
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    orgs: &[OrganizationWithTokens],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
     if !no_header {
         table.set_header(vec![
             "Name",
@@ -85,43 +135,106 @@ fn output_table(orgs: &[OrganizationWithTokens], no_header: bool) {
         ]);
     }
 
-    println!();
-    println!("{table}");
-    if !no_header {
-        println!("\nTotal: {} organizations", orgs.len());
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) {
+        writeln!(writer, "\nTotal: {} organizations", orgs.len())?;
     }
+    Ok(())
 }
 
-fn output_csv(orgs: &[OrganizationWithTokens], no_header: bool) {
+/// Single-org detail: the list columns plus org-wide defaults and policies
+fn output_detail_table<W: Write>(
+    writer: &mut W,
+    orgs: &[OrganizationWithTokens],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
     if !no_header {
-        println!(
-            "name,external_id,email,created_at,saml_enabled,default_project_id,oauth_token_ids"
-        );
+        table.set_header(vec![
+            "Name",
+            "External ID",
+            "Email",
+            "Created At",
+            "SAML",
+            "Default Execution Mode",
+            "Cost Estimation",
+            "Collaborator Auth Policy",
+            "2FA Conformant",
+            "OAuth Tokens",
+        ]);
     }
+
+    let yes_no = |v: Option<bool>| match v {
+        Some(true) => "Yes",
+        Some(false) => "No",
+        None => "-",
+    };
+
     for owt in orgs {
         let org = &owt.organization;
-        let token_ids = owt.oauth_token_ids().join(";");
-        println!(
-            "{},{},{},{},{},{},{}",
-            escape_csv(org.name()),
-            escape_csv(org.external_id()),
-            escape_csv(org.email()),
-            escape_csv(org.created_at()),
-            org.saml_enabled(),
-            escape_csv(org.default_project_id().unwrap_or("")),
-            escape_csv(&token_ids)
-        );
+        let settings = org.settings();
+        let saml = if org.saml_enabled() { "Yes" } else { "No" };
+        let token_ids = owt.oauth_token_ids().join(", ");
+        table.add_row(vec![
+            org.name(),
+            org.external_id(),
+            org.email(),
+            org.created_at(),
+            saml,
+            settings.default_execution_mode.as_deref().unwrap_or("-"),
+            yes_no(settings.cost_estimation_enabled),
+            settings.collaborator_auth_policy.as_deref().unwrap_or("-"),
+            yes_no(settings.two_factor_conformant),
+            &token_ids,
+        ]);
     }
-}
 
-fn output_json(orgs: &[OrganizationWithTokens]) {
-    let data: Vec<SerializableOrganization> = orgs.iter().map(|o| o.into()).collect();
-    super::common::print_json(&data);
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)
 }
 
-fn output_yaml(orgs: &[OrganizationWithTokens]) {
-    let data: Vec<SerializableOrganization> = orgs.iter().map(|o| o.into()).collect();
-    super::common::print_yaml(&data);
+fn output_csv<W: Write>(
+    writer: &mut W,
+    orgs: &[OrganizationWithTokens],
+    no_header: bool,
+    delimiter: char,
+) -> io::Result<()> {
+    if !no_header {
+        write_record(
+            writer,
+            &[
+                "name",
+                "external_id",
+                "email",
+                "created_at",
+                "saml_enabled",
+                "default_project_id",
+                "oauth_token_ids",
+            ],
+            delimiter,
+        )?;
+    }
+    for owt in orgs {
+        let org = &owt.organization;
+        let token_ids = owt.oauth_token_ids().join(";");
+        write_record(
+            writer,
+            &[
+                org.name().to_string(),
+                org.external_id().to_string(),
+                org.email().to_string(),
+                org.created_at().to_string(),
+                org.saml_enabled().to_string(),
+                org.default_project_id().unwrap_or("").to_string(),
+                token_ids.to_string(),
+            ],
+            delimiter,
+        )?;
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -140,6 +253,7 @@ mod tests {
                     external_id: Some("org-123".to_string()),
                     created_at: Some("2025-01-01T00:00:00Z".to_string()),
                     saml_enabled: Some(false),
+                    settings: Default::default(),
                 }),
                 relationships: None,
             },
@@ -158,45 +272,90 @@ mod tests {
         }
     }
 
+    fn render(orgs: &[OrganizationWithTokens], format: OutputFormat, no_header: bool) -> String {
+        render_with(orgs, format, no_header, false)
+    }
+
+    fn render_with(
+        orgs: &[OrganizationWithTokens],
+        format: OutputFormat,
+        no_header: bool,
+        detail: bool,
+    ) -> String {
+        let formatter = OrganizationFormatter {
+            format,
+            no_header,
+            detail,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(orgs, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
     fn test_output_table_empty() {
-        // Should not panic with empty input
-        output_table(&[], false);
+        let out = render(&[], OutputFormat::Table, false);
+        assert!(out.ends_with("\nTotal: 0 organizations\n"));
+    }
+
+    #[test]
+    fn test_output_detail_table() {
+        let mut org = create_test_org();
+        if let Some(attrs) = org.organization.attributes.as_mut() {
+            attrs.settings.default_execution_mode = Some("agent".to_string());
+            attrs.settings.two_factor_conformant = Some(true);
+        }
+        let out = render_with(&[org], OutputFormat::Table, false, true);
+        assert!(out.contains("Default Execution Mode"));
+        assert!(out.contains("agent"));
+        assert!(!out.contains("Total:"));
+
+        // Unset settings render as "-"
+        let out = render_with(&[create_test_org()], OutputFormat::Table, true, true);
+        assert!(!out.contains("Default Execution Mode"));
+        assert!(out.contains(" - "));
     }
 
     #[test]
     fn test_output_table() {
-        let orgs = vec![create_test_org()];
-        // Should not panic
-        output_table(&orgs, false);
+        let out = render(&[create_test_org()], OutputFormat::Table, false);
+        assert!(out.contains("test@example.com"));
+        assert!(out.contains("ot-abc123, ot-def456"));
+        assert!(out.ends_with("\nTotal: 1 organizations\n"));
     }
 
     #[test]
     fn test_output_csv() {
-        let orgs = vec![create_test_org()];
-        // Should not panic
-        output_csv(&orgs, false);
+        assert_eq!(
+            render(&[create_test_org()], OutputFormat::Csv, false),
+            "name,external_id,email,created_at,saml_enabled,default_project_id,oauth_token_ids\n\
+             test-org,org-123,test@example.com,2025-01-01T00:00:00Z,false,,ot-abc123;ot-def456\n"
+        );
     }
 
     #[test]
     fn test_output_json() {
-        let orgs = vec![create_test_org()];
-        // Should not panic
-        output_json(&orgs);
+        let out = render(&[create_test_org()], OutputFormat::Json, false);
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(json[0]["external_id"], "org-123");
+        assert!(json[0].get("default_project_id").is_none());
     }
 
     #[test]
     fn test_output_yaml() {
-        let orgs = vec![create_test_org()];
-        // Should not panic
-        output_yaml(&orgs);
+        let out = render(&[create_test_org()], OutputFormat::Yaml, false);
+        assert!(out.contains("name: test-org"));
+        assert!(out.contains("- ot-abc123"));
     }
 
     #[test]
     fn test_output_no_header() {
         let orgs = vec![create_test_org()];
-        // Should not panic
-        output_table(&orgs, true);
-        output_csv(&orgs, true);
+        let table = render(&orgs, OutputFormat::Table, true);
+        assert!(!table.contains("External ID"));
+        assert!(!table.contains("Total:"));
+        assert_eq!(render(&orgs, OutputFormat::Csv, true).lines().count(), 1);
     }
 }
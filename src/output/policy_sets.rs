@@ -0,0 +1,263 @@
+//! Policy set output formatter
+
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, header_record, write_record, Formatter, RenderOptions};
+use crate::cli::{Cli, Command, GetResource, OutputFormat};
+use crate::hcp::PolicySet;
+use serde::Serialize;
+
+/// Serializable policy set for structured output (JSON/YAML)
+///
+/// Attached workspace/project IDs are only included for a single policy set,
+/// since list responses don't carry the relationships reliably.
+#[derive(Serialize)]
+struct SerializablePolicySet {
+    id: String,
+    name: String,
+    description: String,
+    kind: String,
+    global: bool,
+    workspace_count: u32,
+    project_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    policy_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overridable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspaces: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    projects: Option<Vec<String>>,
+}
+
+impl SerializablePolicySet {
+    fn new(set: &PolicySet, detail: bool) -> Self {
+        let ids = |ids: Vec<&str>| detail.then(|| ids.into_iter().map(String::from).collect());
+        Self {
+            id: set.id.clone(),
+            name: set.name().to_string(),
+            description: set.attributes.description.clone().unwrap_or_default(),
+            kind: set.kind().to_string(),
+            global: set.attributes.global,
+            workspace_count: set.workspace_count(),
+            project_count: set.project_count(),
+            policy_count: set.attributes.policy_count,
+            overridable: set.attributes.overridable,
+            workspaces: ids(set.workspace_ids()),
+            projects: ids(set.project_ids()),
+        }
+    }
+}
+
+/// Output policy sets in the specified format
+///
+/// A single named policy set also lists its attached workspaces and projects.
+pub fn output_policy_sets(sets: &[PolicySet], cli: &Cli) {
+    let Command::Get {
+        resource: GetResource::PolicySet(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if cli.id_only {
+        super::common::print_ids(sets);
+        return;
+    }
+
+    PolicySetFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+        detail: args.name.is_some(),
+    }
+    .print(sets);
+}
+
+/// Policy set formatter; `detail` adds the attached workspace and project IDs
+pub struct PolicySetFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub detail: bool,
+}
+
+impl Formatter for PolicySetFormatter {
+    type Row = PolicySet;
+
+    fn format_to<W: Write>(
+        &self,
+        sets: &[PolicySet],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        let detail = self.detail;
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_table(writer, sets, self.no_header, detail, &self.format, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(sets, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            OutputFormat::Json => {
+                super::common::write_json(writer, &serializable(sets, detail), options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable(sets, detail)),
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                sets.iter().map(|ps| SerializablePolicySet::new(ps, detail)),
+                &self.format,
+                options,
+            ),
+        }
+    }
+}
+
+fn serializable(sets: &[PolicySet], detail: bool) -> Vec<SerializablePolicySet> {
+    sets.iter()
+        .map(|ps| SerializablePolicySet::new(ps, detail))
+        .collect()
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    sets: &[PolicySet],
+    no_header: bool,
+    detail: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
+    if !no_header {
+        let mut header = vec!["ID", "Name", "Kind", "Global", "Workspaces", "Projects"];
+        if detail {
+            header.extend_from_slice(&["Workspace IDs", "Project IDs"]);
+        }
+        table.set_header(header);
+    }
+
+    for set in sets {
+        let mut row = vec![
+            set.id.clone(),
+            super::common::table_name(set.name(), options),
+            set.kind().to_string(),
+            if set.attributes.global { "Yes" } else { "No" }.to_string(),
+            set.workspace_count().to_string(),
+            set.project_count().to_string(),
+        ];
+        if detail {
+            row.push(set.workspace_ids().join(", "));
+            row.push(set.project_ids().join(", "));
+        }
+        table.add_row(row);
+    }
+
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) && !detail {
+        writeln!(writer, "\nTotal: {} policy sets", sets.len())?;
+    }
+    Ok(())
+}
+
+fn csv_records(sets: &[PolicySet], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(sets.len() + 1);
+    if !no_header {
+        records.push(header_record(&[
+            "id",
+            "name",
+            "kind",
+            "global",
+            "workspace_count",
+            "project_count",
+        ]));
+    }
+    for set in sets {
+        records.push(vec![
+            set.id.clone(),
+            set.name().to_string(),
+            set.kind().to_string(),
+            set.attributes.global.to_string(),
+            set.workspace_count().to_string(),
+            set.project_count().to_string(),
+        ]);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_set() -> PolicySet {
+        serde_json::from_value(serde_json::json!({
+            "id": "polset-abc",
+            "attributes": {
+                "name": "cis, baseline",
+                "kind": "opa",
+                "global": false,
+                "workspace-count": 2,
+                "project-count": 1,
+                "policy-count": 5
+            },
+            "relationships": {
+                "workspaces": {"data": [{"id": "ws-1"}, {"id": "ws-2"}]},
+                "projects": {"data": [{"id": "prj-1"}]}
+            }
+        }))
+        .unwrap()
+    }
+
+    fn render(format: OutputFormat, no_header: bool, detail: bool) -> String {
+        let formatter = PolicySetFormatter {
+            format,
+            no_header,
+            detail,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(&[policy_set()], &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_csv_output() {
+        assert_eq!(
+            render(OutputFormat::Csv, false, false),
+            "id,name,kind,global,workspace_count,project_count\n\
+             polset-abc,\"cis, baseline\",opa,false,2,1\n"
+        );
+        assert_eq!(
+            render(OutputFormat::Csv, true, false),
+            "polset-abc,\"cis, baseline\",opa,false,2,1\n"
+        );
+    }
+
+    #[test]
+    fn test_table_output() {
+        let out = render(OutputFormat::Table, false, false);
+        assert!(out.contains("polset-abc"));
+        assert!(out.contains("cis, baseline"));
+        assert!(!out.contains("Workspace IDs"));
+        assert!(out.ends_with("\nTotal: 1 policy sets\n"));
+
+        let detail = render(OutputFormat::Table, false, true);
+        assert!(detail.contains("Workspace IDs"));
+        assert!(detail.contains("ws-1, ws-2"));
+        assert!(!detail.contains("Total:"));
+    }
+
+    #[test]
+    fn test_serializable_detail_includes_attachments() {
+        let json = serde_json::to_value(SerializablePolicySet::new(&policy_set(), true)).unwrap();
+        assert_eq!(json["workspaces"], serde_json::json!(["ws-1", "ws-2"]));
+        assert_eq!(json["projects"], serde_json::json!(["prj-1"]));
+        assert_eq!(json["policy_count"], 5);
+    }
+
+    #[test]
+    fn test_serializable_list_omits_attachments() {
+        let json = serde_json::to_value(SerializablePolicySet::new(&policy_set(), false)).unwrap();
+        assert!(json.get("workspaces").is_none());
+        assert_eq!(json["kind"], "opa");
+    }
+}
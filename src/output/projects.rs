@@ -1,15 +1,18 @@
 //! Project output formatter
 
-use super::common::escape_csv;
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, write_record, Formatter, RenderOptions};
 use crate::cli::{Cli, Command, GetResource, OutputFormat};
 use crate::hcp::{Project, ProjectWorkspaces, TfeResource, Workspace};
-use comfy_table::{presets::NOTHING, Table};
 use serde::Serialize;
 
 /// Project row type alias
 pub type ProjectRow = (String, Project, ProjectWorkspaces);
 
 /// Serializable workspace for structured output (JSON/YAML) - subset of fields
+///
+/// `--with-ws-details` fills all fields; the other `--with-ws*` flags only id and name.
 #[derive(Serialize)]
 struct SerializableWorkspace {
     id: String,
@@ -18,8 +21,24 @@ struct SerializableWorkspace {
     terraform_version: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     execution_mode: Option<String>,
-    resource_count: u32,
-    locked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resource_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locked: Option<bool>,
+}
+
+impl SerializableWorkspace {
+    /// Workspace reference with id and name only
+    fn brief(ws: &Workspace) -> Self {
+        SerializableWorkspace {
+            id: ws.id.clone(),
+            name: ws.attributes.name.clone(),
+            terraform_version: None,
+            execution_mode: None,
+            resource_count: None,
+            locked: None,
+        }
+    }
 }
 
 impl From<&Workspace> for SerializableWorkspace {
@@ -29,8 +48,8 @@ impl From<&Workspace> for SerializableWorkspace {
             name: ws.attributes.name.clone(),
             terraform_version: ws.attributes.terraform_version.clone(),
             execution_mode: ws.attributes.execution_mode.clone(),
-            resource_count: ws.attributes.resource_count.unwrap_or(0),
-            locked: ws.attributes.locked.unwrap_or(false),
+            resource_count: Some(ws.attributes.resource_count.unwrap_or(0)),
+            locked: Some(ws.attributes.locked.unwrap_or(false)),
         }
     }
 }
@@ -41,10 +60,11 @@ struct SerializableProject {
     org: String,
     name: String,
     id: String,
+    /// Omitted without `--with-ws`; `null` when the workspaces could not be fetched
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspace_count: Option<usize>,
+    workspace_count: Option<Option<usize>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    workspaces: Option<Vec<SerializableWorkspace>>,
+    workspaces: Option<Option<Vec<SerializableWorkspace>>>,
     description: String,
 }
 
@@ -57,158 +77,221 @@ pub fn output_projects(projects: &[ProjectRow], cli: &Cli) {
         unreachable!()
     };
 
-    let show_ws = args.with_ws || args.with_ws_names || args.with_ws_ids || args.with_ws_details;
-    let show_names = args.with_ws_names;
-    let show_ids = args.with_ws_ids;
-    let show_details = args.with_ws_details;
+    if cli.id_only {
+        super::common::print_ids(projects.iter().map(|(_, prj, _)| prj));
+        return;
+    }
 
-    match args.output {
-        OutputFormat::Table => output_table(
-            projects,
-            cli.no_header,
-            show_ws,
-            show_names,
-            show_ids,
-            show_details,
-        ),
-        OutputFormat::Csv => output_csv(
-            projects,
-            cli.no_header,
-            show_ws,
-            show_names,
-            show_ids,
-            show_details,
-        ),
-        OutputFormat::Json => output_json(projects, show_ws, show_details),
-        OutputFormat::Yaml => output_yaml(projects, show_ws, show_details),
+    ProjectFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+        show_ws: args.with_ws || args.with_ws_names || args.with_ws_ids || args.with_ws_details,
+        show_names: args.with_ws_names,
+        show_ids: args.with_ws_ids,
+        show_details: args.with_ws_details,
     }
+    .print(projects);
 }
 
-fn output_table(
-    projects: &[ProjectRow],
-    no_header: bool,
-    show_ws: bool,
-    show_names: bool,
-    show_ids: bool,
-    show_details: bool,
-) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
-
-    // Build header dynamically
-    let mut headers = vec!["Org", "Name", "ID"];
-    if show_ws {
-        headers.push("Workspaces");
-    }
-    if show_names {
-        headers.push("WS Names");
-    }
-    if show_ids {
-        headers.push("WS IDs");
-    }
-    if show_details {
-        headers.push("WS Details");
-    }
-    headers.push("Description");
+/// Project formatter; the `show_*` flags mirror the `--with-ws*` options
+pub struct ProjectFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub show_ws: bool,
+    pub show_names: bool,
+    pub show_ids: bool,
+    pub show_details: bool,
+}
 
-    if !no_header {
-        table.set_header(headers);
+impl Formatter for ProjectFormatter {
+    type Row = ProjectRow;
+
+    fn format_to<W: Write>(
+        &self,
+        projects: &[ProjectRow],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                self.output_table(writer, projects, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => self.output_csv(writer, projects),
+            OutputFormat::Json => super::common::write_json(
+                writer,
+                &build_serializable_projects(projects, self.show_ws, self.show_details),
+                options,
+            ),
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                build_serializable_projects(projects, self.show_ws, self.show_details),
+                &self.format,
+                options,
+            ),
+            OutputFormat::Yaml => super::common::write_yaml(
+                writer,
+                &build_serializable_projects(projects, self.show_ws, self.show_details),
+            ),
+        }
     }
+}
 
-    for (org_name, prj, ws_info) in projects {
-        let mut row: Vec<String> = vec![org_name.clone(), prj.name().to_string(), prj.id.clone()];
+impl ProjectFormatter {
+    fn output_table<W: Write>(
+        &self,
+        writer: &mut W,
+        projects: &[ProjectRow],
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        let ProjectFormatter {
+            no_header,
+            show_ws,
+            show_names,
+            show_ids,
+            show_details,
+            ..
+        } = *self;
+        let mut table = super::common::new_table(options);
 
+        // Build header dynamically
+        let mut headers = vec!["Org", "Name", "ID"];
         if show_ws {
-            let ws_str = if ws_info.is_empty() && !show_names && !show_ids && !show_details {
-                "-".to_string()
-            } else {
-                ws_info.count().to_string()
-            };
-            row.push(ws_str);
+            headers.push("Workspaces");
         }
-
         if show_names {
-            row.push(ws_info.names().join(", "));
+            headers.push("WS Names");
         }
-
         if show_ids {
-            row.push(ws_info.ids().join(", "));
+            headers.push("WS IDs");
         }
-
         if show_details {
-            row.push(ws_info.name_id_pairs().join(", "));
+            headers.push("WS Details");
         }
+        headers.push("Description");
 
-        row.push(prj.description().to_string());
-
-        table.add_row(row);
-    }
-
-    println!();
-    println!("{table}");
-    if !no_header {
-        println!("\nTotal: {} projects", projects.len());
-    }
-}
+        if !no_header {
+            table.set_header(headers);
+        }
 
-fn output_csv(
-    projects: &[ProjectRow],
-    no_header: bool,
-    show_ws: bool,
-    show_names: bool,
-    show_ids: bool,
-    show_details: bool,
-) {
-    // Build header
-    let mut headers = vec!["org", "name", "id"];
-    if show_ws {
-        headers.push("workspaces");
-    }
-    if show_names {
-        headers.push("ws_names");
-    }
-    if show_ids {
-        headers.push("ws_ids");
-    }
-    if show_details {
-        headers.push("ws_details");
-    }
-    headers.push("description");
+        for (org_name, prj, ws_info) in projects {
+            let mut row: Vec<String> = vec![
+                org_name.clone(),
+                super::common::table_name(prj.name(), options),
+                prj.id.clone(),
+            ];
+
+            // Shown for every workspace column when the workspaces could not be fetched
+            let unavailable = || "-".to_string();
+
+            if show_ws {
+                let ws_str = if ws_info.failed
+                    || (ws_info.is_empty() && !show_names && !show_ids && !show_details)
+                {
+                    unavailable()
+                } else {
+                    ws_info.count().to_string()
+                };
+                row.push(ws_str);
+            }
+
+            if show_names {
+                row.push(if ws_info.failed {
+                    unavailable()
+                } else {
+                    ws_info.names().join(", ")
+                });
+            }
+
+            if show_ids {
+                row.push(if ws_info.failed {
+                    unavailable()
+                } else {
+                    ws_info.ids().join(", ")
+                });
+            }
+
+            if show_details {
+                row.push(if ws_info.failed {
+                    unavailable()
+                } else {
+                    ws_info.name_id_pairs().join(", ")
+                });
+            }
+
+            row.push(prj.description().to_string());
+
+            table.add_row(row);
+        }
 
-    if !no_header {
-        println!("{}", headers.join(","));
+        writeln!(writer)?;
+        super::common::write_table(writer, &table, &self.format)?;
+        if super::common::show_footer(no_header, options) {
+            writeln!(writer, "\nTotal: {} projects", projects.len())?;
+        }
+        Ok(())
     }
 
-    for (org_name, prj, ws_info) in projects {
-        let mut fields: Vec<String> = vec![
-            escape_csv(org_name),
-            escape_csv(prj.name()),
-            escape_csv(&prj.id),
-        ];
-
+    fn output_csv<W: Write>(&self, writer: &mut W, projects: &[ProjectRow]) -> io::Result<()> {
+        let ProjectFormatter {
+            format,
+            no_header,
+            show_ws,
+            show_names,
+            show_ids,
+            show_details,
+        } = *self;
+        let delimiter = csv_delimiter(&format);
+        // Build header
+        let mut headers = vec!["org", "name", "id"];
         if show_ws {
-            fields.push(ws_info.count().to_string());
+            headers.push("workspaces");
         }
-
         if show_names {
-            // Encapsulate list as semicolon-separated within quotes
-            let names_str = ws_info.names().join(";");
-            fields.push(escape_csv(&names_str));
+            headers.push("ws_names");
         }
-
         if show_ids {
-            let ids_str = ws_info.ids().join(";");
-            fields.push(escape_csv(&ids_str));
+            headers.push("ws_ids");
         }
-
         if show_details {
-            let details_str = ws_info.name_id_pairs().join(";");
-            fields.push(escape_csv(&details_str));
+            headers.push("ws_details");
+        }
+        headers.push("description");
+
+        if !no_header {
+            write_record(writer, &headers, delimiter)?;
         }
 
-        fields.push(escape_csv(prj.description()));
+        for (org_name, prj, ws_info) in projects {
+            let mut fields: Vec<String> =
+                vec![org_name.clone(), prj.name().to_string(), prj.id.clone()];
+
+            // Workspace columns stay empty when the workspaces could not be fetched
+            if show_ws {
+                fields.push(if ws_info.failed {
+                    String::new()
+                } else {
+                    ws_info.count().to_string()
+                });
+            }
+
+            // Lists are semicolon-separated within a single field
+            if show_names {
+                fields.push(ws_info.names().join(";"));
+            }
+
+            if show_ids {
+                fields.push(ws_info.ids().join(";"));
+            }
+
+            if show_details {
+                fields.push(ws_info.name_id_pairs().join(";"));
+            }
 
-        println!("{}", fields.join(","));
+            fields.push(prj.description().to_string());
+
+            write_record(writer, &fields, delimiter)?;
+        }
+        Ok(())
     }
 }
 
@@ -224,15 +307,25 @@ fn build_serializable_projects(
             org: org_name.clone(),
             name: p.name().to_string(),
             id: p.id.clone(),
-            workspace_count: if show_ws { Some(ws_info.count()) } else { None },
-            workspaces: if show_details {
-                Some(
+            workspace_count: show_ws.then(|| (!ws_info.failed).then_some(ws_info.count())),
+            workspaces: if (show_ws || show_details) && ws_info.failed {
+                Some(None)
+            } else if show_details {
+                Some(Some(
                     ws_info
                         .workspaces
                         .iter()
                         .map(SerializableWorkspace::from)
                         .collect(),
-                )
+                ))
+            } else if show_ws {
+                Some(Some(
+                    ws_info
+                        .workspaces
+                        .iter()
+                        .map(SerializableWorkspace::brief)
+                        .collect(),
+                ))
             } else {
                 None
             },
@@ -241,16 +334,6 @@ fn build_serializable_projects(
         .collect()
 }
 
-fn output_json(projects: &[ProjectRow], show_ws: bool, show_details: bool) {
-    let data = build_serializable_projects(projects, show_ws, show_details);
-    super::common::print_json(&data);
-}
-
-fn output_yaml(projects: &[ProjectRow], show_ws: bool, show_details: bool) {
-    let data = build_serializable_projects(projects, show_ws, show_details);
-    super::common::print_yaml(&data);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -272,11 +355,16 @@ mod tests {
             id: id.to_string(),
             attributes: WorkspaceAttributes {
                 name: name.to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: Some("remote".to_string()),
                 resource_count: Some(5),
                 locked: Some(false),
                 terraform_version: Some("1.5.0".to_string()),
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         }
@@ -289,10 +377,32 @@ mod tests {
         ])
     }
 
+    /// Render with `--with-ws`, `--with-ws-names`, `--with-ws-ids`, `--with-ws-details`
+    fn render(
+        projects: &[ProjectRow],
+        format: OutputFormat,
+        no_header: bool,
+        [show_ws, show_names, show_ids, show_details]: [bool; 4],
+    ) -> String {
+        let formatter = ProjectFormatter {
+            format,
+            no_header,
+            show_ws,
+            show_names,
+            show_ids,
+            show_details,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(projects, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
     fn test_output_table_empty() {
-        // Should not panic with empty input
-        output_table(&[], false, false, false, false, false);
+        let out = render(&[], OutputFormat::Table, false, [false; 4]);
+        assert!(out.ends_with("\nTotal: 0 projects\n"));
     }
 
     #[test]
@@ -302,8 +412,17 @@ mod tests {
             create_test_project(),
             create_test_ws_info(),
         )];
-        // Should not panic
-        output_table(&projects, false, true, false, false, false);
+        let out = render(
+            &projects,
+            OutputFormat::Table,
+            false,
+            [true, false, false, false],
+        );
+        assert!(out.contains("Workspaces"));
+        assert!(!out.contains("WS Names"));
+        assert!(out.contains("prj-123"));
+        assert!(out.contains("A test project"));
+        assert!(out.ends_with("\nTotal: 1 projects\n"));
     }
 
     #[test]
@@ -313,8 +432,9 @@ mod tests {
             create_test_project(),
             ProjectWorkspaces::new(),
         )];
-        // Should not panic
-        output_table(&projects, false, false, false, false, false);
+        let out = render(&projects, OutputFormat::Table, false, [false; 4]);
+        assert!(!out.contains("Workspaces"));
+        assert!(out.contains("test-project"));
     }
 
     #[test]
@@ -324,8 +444,14 @@ mod tests {
             create_test_project(),
             create_test_ws_info(),
         )];
-        // Should not panic
-        output_table(&projects, false, true, true, false, false);
+        let out = render(
+            &projects,
+            OutputFormat::Table,
+            false,
+            [true, true, false, false],
+        );
+        assert!(out.contains("WS Names"));
+        assert!(out.contains("ws-one, ws-two"));
     }
 
     #[test]
@@ -335,8 +461,47 @@ mod tests {
             create_test_project(),
             create_test_ws_info(),
         )];
-        // Should not panic
-        output_csv(&projects, false, true, true, true, true);
+        let out = render(&projects, OutputFormat::Csv, false, [true; 4]);
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            lines[0],
+            "org,name,id,workspaces,ws_names,ws_ids,ws_details,description"
+        );
+        assert!(lines[1].starts_with("test-org,test-project,prj-123,2,ws-one;ws-two,"));
+        assert!(lines[1].ends_with(",A test project"));
+        assert_eq!(lines.len(), 2);
+    }
+
+    #[test]
+    fn test_output_unavailable_workspaces() {
+        let projects = vec![(
+            "test-org".to_string(),
+            create_test_project(),
+            ProjectWorkspaces::unavailable(),
+        )];
+
+        let table = render(
+            &projects,
+            OutputFormat::Table,
+            false,
+            [true, true, false, false],
+        );
+        let row = table.lines().find(|l| l.contains("prj-123")).unwrap();
+        assert_eq!(row.matches(" - ").count(), 2, "{}", row);
+
+        let csv = render(&projects, OutputFormat::Csv, true, [true; 4]);
+        assert_eq!(csv, "test-org,test-project,prj-123,,,,,A test project\n");
+
+        let json: serde_json::Value = serde_json::from_str(&render(
+            &projects,
+            OutputFormat::Json,
+            false,
+            [true, false, false, false],
+        ))
+        .unwrap();
+        assert_eq!(json[0]["workspace_count"], serde_json::Value::Null);
+        assert_eq!(json[0]["workspaces"], serde_json::Value::Null);
+        assert!(json[0].as_object().unwrap().contains_key("workspace_count"));
     }
 
     #[test]
@@ -346,8 +511,62 @@ mod tests {
             create_test_project(),
             create_test_ws_info(),
         )];
-        // Should not panic
-        output_json(&projects, true, true);
+        let out = render(
+            &projects,
+            OutputFormat::Json,
+            false,
+            [true, false, false, true],
+        );
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(json[0]["workspace_count"], 2);
+        assert_eq!(json[0]["workspaces"][0]["terraform_version"], "1.5.0");
+    }
+
+    #[test]
+    fn test_serializable_projects_with_ws_nests_workspace_refs() {
+        let projects = vec![(
+            "test-org".to_string(),
+            create_test_project(),
+            create_test_ws_info(),
+        )];
+        let data = build_serializable_projects(&projects, true, false);
+        let json = serde_json::to_value(&data).unwrap();
+
+        let workspaces = json[0]["workspaces"].as_array().unwrap();
+        assert_eq!(workspaces.len(), 2);
+        assert_eq!(
+            workspaces[0],
+            serde_json::json!({"id": "ws-id-1", "name": "ws-one"})
+        );
+        assert_eq!(json[0]["workspace_count"], 2);
+    }
+
+    #[test]
+    fn test_serializable_projects_with_ws_details_full_objects() {
+        let projects = vec![(
+            "test-org".to_string(),
+            create_test_project(),
+            create_test_ws_info(),
+        )];
+        let data = build_serializable_projects(&projects, true, true);
+        let json = serde_json::to_value(&data).unwrap();
+
+        let ws = &json[0]["workspaces"][1];
+        assert_eq!(ws["name"], "ws-two");
+        assert_eq!(ws["resource_count"], 5);
+        assert_eq!(ws["locked"], false);
+    }
+
+    #[test]
+    fn test_serializable_projects_without_ws_omits_workspaces() {
+        let projects = vec![(
+            "test-org".to_string(),
+            create_test_project(),
+            create_test_ws_info(),
+        )];
+        let json =
+            serde_json::to_value(build_serializable_projects(&projects, false, false)).unwrap();
+        assert!(json[0].get("workspaces").is_none());
     }
 
     #[test]
@@ -357,8 +576,14 @@ mod tests {
             create_test_project(),
             create_test_ws_info(),
         )];
-        // Should not panic
-        output_yaml(&projects, true, true);
+        let out = render(
+            &projects,
+            OutputFormat::Yaml,
+            false,
+            [true, false, false, true],
+        );
+        assert!(out.contains("workspace_count: 2"));
+        assert!(out.contains("name: ws-two"));
     }
 
     #[test]
@@ -368,8 +593,13 @@ mod tests {
             create_test_project(),
             create_test_ws_info(),
         )];
-        // Should not panic
-        output_table(&projects, true, true, false, false, false);
-        output_csv(&projects, true, true, false, false, false);
+        let flags = [true, false, false, false];
+        let table = render(&projects, OutputFormat::Table, true, flags);
+        assert!(!table.contains("Description"));
+        assert!(!table.contains("Total:"));
+        assert_eq!(
+            render(&projects, OutputFormat::Csv, true, flags),
+            "test-org,test-project,prj-123,2,A test project\n"
+        );
     }
 }
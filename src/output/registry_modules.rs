@@ -0,0 +1,284 @@
+//! Registry module output formatter
+
+use std::io::{self, Write};
+
+use super::common::{
+    csv_delimiter, header_record, table_time, write_record, Formatter, RenderOptions,
+};
+use crate::cli::{Cli, Command, GetResource, OutputFormat};
+use crate::hcp::RegistryModule;
+use serde::Serialize;
+
+/// Published version of a module in structured output
+#[derive(Serialize)]
+struct SerializableVersion {
+    version: String,
+    status: Option<String>,
+}
+
+/// Serializable registry module for structured output (JSON/YAML)
+///
+/// All published versions are only included for a single module.
+#[derive(Serialize)]
+struct SerializableRegistryModule {
+    id: String,
+    name: String,
+    namespace: String,
+    provider: String,
+    registry_name: String,
+    latest_version: Option<String>,
+    status: Option<String>,
+    updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    versions: Option<Vec<SerializableVersion>>,
+}
+
+impl SerializableRegistryModule {
+    fn new(module: &RegistryModule, detail: bool) -> Self {
+        Self {
+            id: module.id.clone(),
+            name: module.name().to_string(),
+            namespace: module.namespace().to_string(),
+            provider: module.provider().to_string(),
+            registry_name: module.registry_name().to_string(),
+            latest_version: module.latest_version().map(String::from),
+            status: module.attributes.status.clone(),
+            updated_at: module.attributes.updated_at.clone(),
+            versions: detail.then(|| {
+                module
+                    .versions()
+                    .into_iter()
+                    .map(|v| SerializableVersion {
+                        version: v.version.clone(),
+                        status: v.status.clone(),
+                    })
+                    .collect()
+            }),
+        }
+    }
+}
+
+/// Output registry modules in the specified format
+///
+/// A single named module also lists its published versions.
+pub fn output_registry_modules(modules: &[RegistryModule], cli: &Cli) {
+    let Command::Get {
+        resource: GetResource::RegistryModule(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if cli.id_only {
+        super::common::print_ids(modules);
+        return;
+    }
+
+    RegistryModuleFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+        detail: args.name.is_some(),
+    }
+    .print(modules);
+}
+
+/// Registry module formatter; `detail` adds every published version
+pub struct RegistryModuleFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub detail: bool,
+}
+
+impl Formatter for RegistryModuleFormatter {
+    type Row = RegistryModule;
+
+    fn format_to<W: Write>(
+        &self,
+        modules: &[RegistryModule],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        let detail = self.detail;
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => output_table(
+                writer,
+                modules,
+                self.no_header,
+                detail,
+                &self.format,
+                options,
+            ),
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(modules, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            OutputFormat::Json => {
+                super::common::write_json(writer, &serializable(modules, detail), options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable(modules, detail)),
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                modules
+                    .iter()
+                    .map(|m| SerializableRegistryModule::new(m, detail)),
+                &self.format,
+                options,
+            ),
+        }
+    }
+}
+
+fn serializable(modules: &[RegistryModule], detail: bool) -> Vec<SerializableRegistryModule> {
+    modules
+        .iter()
+        .map(|m| SerializableRegistryModule::new(m, detail))
+        .collect()
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    modules: &[RegistryModule],
+    no_header: bool,
+    detail: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
+    if !no_header {
+        let mut header = vec!["Name", "Provider", "Namespace", "Latest Version", "Updated"];
+        if detail {
+            header.push("Versions");
+        }
+        table.set_header(header);
+    }
+
+    for module in modules {
+        let mut row = vec![
+            super::common::table_name(module.name(), options),
+            module.provider().to_string(),
+            module.namespace().to_string(),
+            module.latest_version().unwrap_or("-").to_string(),
+            table_time(
+                module.attributes.updated_at.as_deref().unwrap_or(""),
+                options,
+            ),
+        ];
+        if detail {
+            row.push(
+                module
+                    .versions()
+                    .iter()
+                    .map(|v| match v.status.as_deref() {
+                        Some(status) if status != "ok" => format!("{} ({})", v.version, status),
+                        _ => v.version.clone(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            );
+        }
+        table.add_row(row);
+    }
+
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) && !detail {
+        writeln!(writer, "\nTotal: {} registry modules", modules.len())?;
+    }
+    Ok(())
+}
+
+fn csv_records(modules: &[RegistryModule], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(modules.len() + 1);
+    if !no_header {
+        records.push(header_record(&[
+            "id",
+            "name",
+            "provider",
+            "namespace",
+            "latest_version",
+            "updated_at",
+        ]));
+    }
+    for module in modules {
+        records.push(vec![
+            module.id.clone(),
+            module.name().to_string(),
+            module.provider().to_string(),
+            module.namespace().to_string(),
+            module.latest_version().unwrap_or("").to_string(),
+            module.attributes.updated_at.clone().unwrap_or_default(),
+        ]);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module() -> RegistryModule {
+        serde_json::from_value(serde_json::json!({
+            "id": "mod-abc",
+            "attributes": {
+                "name": "vpc",
+                "namespace": "my-org",
+                "provider": "aws",
+                "registry-name": "private",
+                "status": "setup_complete",
+                "version-statuses": [
+                    {"version": "1.1.0", "status": "ok"},
+                    {"version": "1.2.0", "status": "reg_ingress_failed"}
+                ],
+                "updated-at": "2024-03-01T10:00:00Z"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn render(format: OutputFormat, no_header: bool, detail: bool) -> String {
+        let formatter = RegistryModuleFormatter {
+            format,
+            no_header,
+            detail,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(&[module()], &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_csv_output() {
+        assert_eq!(
+            render(OutputFormat::Csv, false, false),
+            "id,name,provider,namespace,latest_version,updated_at\n\
+             mod-abc,vpc,aws,my-org,1.1.0,2024-03-01T10:00:00Z\n"
+        );
+        assert_eq!(render(OutputFormat::Csv, true, false).lines().count(), 1);
+    }
+
+    #[test]
+    fn test_table_output() {
+        let out = render(OutputFormat::Table, false, false);
+        assert!(out.contains("Latest Version"));
+        assert!(out.contains("1.1.0"));
+        assert!(!out.contains("Versions"));
+        assert!(out.ends_with("\nTotal: 1 registry modules\n"));
+
+        let detail = render(OutputFormat::Table, false, true);
+        assert!(detail.contains("Versions"));
+        assert!(detail.contains("1.2.0 (reg_ingress_failed)"));
+        assert!(!detail.contains("Total:"));
+    }
+
+    #[test]
+    fn test_serializable_detail_includes_versions() {
+        let json = serde_json::to_value(SerializableRegistryModule::new(&module(), true)).unwrap();
+        assert_eq!(json["latest_version"], "1.1.0");
+        assert_eq!(json["versions"][0]["version"], "1.2.0");
+        assert_eq!(json["versions"][1]["status"], "ok");
+
+        let list = serde_json::to_value(SerializableRegistryModule::new(&module(), false)).unwrap();
+        assert!(list.get("versions").is_none());
+    }
+}
@@ -0,0 +1,222 @@
+//! Run trigger output formatter
+
+use std::io::{self, Write};
+
+use super::common::{
+    csv_delimiter, header_record, table_time, write_record, Formatter, RenderOptions,
+};
+use crate::cli::{Cli, Command, GetResource, OutputFormat};
+use crate::hcp::RunTrigger;
+use serde::Serialize;
+
+/// Workspace at one end of a run trigger edge
+#[derive(Serialize)]
+struct WorkspaceNode {
+    id: String,
+    name: String,
+}
+
+/// Run trigger as a directed edge (source workspace → triggered workspace)
+///
+/// JSON/YAML emit one object per edge, so the output can be fed to graph
+/// tooling as an edge list without reshaping.
+#[derive(Serialize)]
+struct RunTriggerEdge {
+    id: String,
+    source: WorkspaceNode,
+    target: WorkspaceNode,
+    created_at: Option<String>,
+}
+
+impl From<&RunTrigger> for RunTriggerEdge {
+    fn from(rt: &RunTrigger) -> Self {
+        Self {
+            id: rt.id.clone(),
+            source: WorkspaceNode {
+                id: rt.sourceable_id().to_string(),
+                name: rt.sourceable_name().to_string(),
+            },
+            target: WorkspaceNode {
+                id: rt.workspace_id().to_string(),
+                name: rt.attributes.workspace_name.clone(),
+            },
+            created_at: rt.attributes.created_at.clone(),
+        }
+    }
+}
+
+/// Output run triggers in the specified format
+pub fn output_run_triggers(triggers: &[RunTrigger], cli: &Cli) {
+    let Command::Get {
+        resource: GetResource::RunTrigger(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if cli.id_only {
+        super::common::print_ids(triggers);
+        return;
+    }
+
+    RunTriggerFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+    }
+    .print(triggers);
+}
+
+/// Run trigger formatter; structured formats emit an edge list
+pub struct RunTriggerFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+}
+
+impl Formatter for RunTriggerFormatter {
+    type Row = RunTrigger;
+
+    fn format_to<W: Write>(
+        &self,
+        triggers: &[RunTrigger],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_table(writer, triggers, self.no_header, &self.format, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(triggers, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            OutputFormat::Json => super::common::write_json(writer, &edges(triggers), options),
+            OutputFormat::Yaml => super::common::write_yaml(writer, &edges(triggers)),
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                triggers.iter().map(RunTriggerEdge::from),
+                &self.format,
+                options,
+            ),
+        }
+    }
+}
+
+fn edges(triggers: &[RunTrigger]) -> Vec<RunTriggerEdge> {
+    triggers.iter().map(RunTriggerEdge::from).collect()
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    triggers: &[RunTrigger],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
+    if !no_header {
+        table.set_header(vec!["ID", "Source Workspace", "Source ID", "Created"]);
+    }
+
+    for rt in triggers {
+        table.add_row(vec![
+            rt.id.clone(),
+            super::common::table_name(rt.sourceable_name(), options),
+            rt.sourceable_id().to_string(),
+            table_time(rt.attributes.created_at.as_deref().unwrap_or(""), options),
+        ]);
+    }
+
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) {
+        writeln!(writer, "\nTotal: {} run triggers", triggers.len())?;
+    }
+    Ok(())
+}
+
+fn csv_records(triggers: &[RunTrigger], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(triggers.len() + 1);
+    if !no_header {
+        records.push(header_record(&[
+            "id",
+            "source_id",
+            "source_name",
+            "target_id",
+            "target_name",
+            "created_at",
+        ]));
+    }
+    for rt in triggers {
+        records.push(vec![
+            rt.id.clone(),
+            rt.sourceable_id().to_string(),
+            rt.sourceable_name().to_string(),
+            rt.workspace_id().to_string(),
+            rt.attributes.workspace_name.clone(),
+            rt.attributes.created_at.clone().unwrap_or_default(),
+        ]);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trigger() -> RunTrigger {
+        serde_json::from_value(serde_json::json!({
+            "id": "rt-1",
+            "attributes": {
+                "workspace-name": "app",
+                "sourceable-name": "network",
+                "created-at": "2024-01-01T00:00:00Z"
+            },
+            "relationships": {
+                "workspace": {"data": {"id": "ws-app"}},
+                "sourceable": {"data": {"id": "ws-net"}}
+            }
+        }))
+        .unwrap()
+    }
+
+    fn render(format: OutputFormat, no_header: bool) -> String {
+        let mut buf = Vec::new();
+        RunTriggerFormatter { format, no_header }
+            .format_to(&[trigger()], &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_csv_output() {
+        assert_eq!(
+            render(OutputFormat::Csv, false),
+            "id,source_id,source_name,target_id,target_name,created_at\n\
+             rt-1,ws-net,network,ws-app,app,2024-01-01T00:00:00Z\n"
+        );
+        assert_eq!(render(OutputFormat::Csv, true).lines().count(), 1);
+    }
+
+    #[test]
+    fn test_table_output() {
+        let out = render(OutputFormat::Table, false);
+        assert!(out.contains("Source Workspace"));
+        assert!(out.contains("network"));
+        assert!(out.contains("ws-net"));
+        assert!(out.ends_with("\nTotal: 1 run triggers\n"));
+        assert!(!render(OutputFormat::Table, true).contains("Total:"));
+    }
+
+    #[test]
+    fn test_json_is_edge_list() {
+        let json = serde_json::to_value(edges(&[trigger()])).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "id": "rt-1",
+                "source": {"id": "ws-net", "name": "network"},
+                "target": {"id": "ws-app", "name": "app"},
+                "created_at": "2024-01-01T00:00:00Z"
+            }])
+        );
+    }
+}
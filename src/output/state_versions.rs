@@ -1,9 +1,10 @@
 //! State version output formatter
 
-use super::common::escape_csv;
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, write_record, Formatter, RenderOptions};
 use crate::cli::OutputFormat;
-use crate::hcp::state::StateVersionListItem;
-use comfy_table::{presets::NOTHING, Table};
+use crate::hcp::state::{StateVersionListItem, StateVersionOutput};
 use serde::Serialize;
 
 /// Serializable state version for structured output (JSON/YAML)
@@ -21,6 +22,17 @@ struct SerializableStateVersion {
     vcs_commit_sha: String,
 }
 
+/// Serializable state version output; sensitive values are omitted unless shown
+#[derive(Serialize)]
+struct SerializableOutput {
+    name: String,
+    #[serde(rename = "type")]
+    output_type: String,
+    sensitive: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<serde_json::Value>,
+}
+
 /// Output state versions in the specified format
 pub fn output_state_versions(
     states: &[StateVersionListItem],
@@ -28,17 +40,70 @@ pub fn output_state_versions(
     format: &OutputFormat,
     no_header: bool,
 ) {
-    match format {
-        OutputFormat::Table => output_table(states, deltas, no_header),
-        OutputFormat::Csv => output_csv(states, deltas, no_header),
-        OutputFormat::Json => output_json(states, deltas),
-        OutputFormat::Yaml => output_yaml(states, deltas),
+    StateVersionFormatter {
+        format: *format,
+        no_header,
+        deltas,
     }
+    .print(states);
 }
 
-fn output_table(states: &[StateVersionListItem], deltas: &[Option<i64>], no_header: bool) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+/// State version formatter; `deltas[i]` is the resource count change of `states[i]`
+pub struct StateVersionFormatter<'a> {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub deltas: &'a [Option<i64>],
+}
+
+impl Formatter for StateVersionFormatter<'_> {
+    type Row = StateVersionListItem;
+
+    fn format_to<W: Write>(
+        &self,
+        states: &[StateVersionListItem],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        let deltas = self.deltas;
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => output_table(
+                writer,
+                states,
+                deltas,
+                self.no_header,
+                &self.format,
+                options,
+            ),
+            OutputFormat::Csv | OutputFormat::Tsv => output_csv(
+                writer,
+                states,
+                deltas,
+                self.no_header,
+                csv_delimiter(&self.format),
+            ),
+            OutputFormat::Json => {
+                super::common::write_json(writer, &serializable(states, deltas), options)
+            }
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                serializable(states, deltas),
+                &self.format,
+                options,
+            ),
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable(states, deltas)),
+        }
+    }
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    states: &[StateVersionListItem],
+    deltas: &[Option<i64>],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
     if !no_header {
         table.set_header(vec![
             "ID",
@@ -83,16 +148,38 @@ fn output_table(states: &[StateVersionListItem], deltas: &[Option<i64>], no_head
         ]);
     }
 
-    println!();
-    println!("{table}");
-    if !no_header {
-        println!("\nTotal: {} state versions", states.len());
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) {
+        writeln!(writer, "\nTotal: {} state versions", states.len())?;
     }
+    Ok(())
 }
 
-fn output_csv(states: &[StateVersionListItem], deltas: &[Option<i64>], no_header: bool) {
+fn output_csv<W: Write>(
+    writer: &mut W,
+    states: &[StateVersionListItem],
+    deltas: &[Option<i64>],
+    no_header: bool,
+    delimiter: char,
+) -> io::Result<()> {
     if !no_header {
-        println!("id,serial,status,created_at,size,resources,delta_resources,terraform_version,run_id,vcs_commit_sha");
+        write_record(
+            writer,
+            &[
+                "id",
+                "serial",
+                "status",
+                "created_at",
+                "size",
+                "resources",
+                "delta_resources",
+                "terraform_version",
+                "run_id",
+                "vcs_commit_sha",
+            ],
+            delimiter,
+        )?;
     }
 
     for (i, state) in states.iter().enumerate() {
@@ -121,38 +208,35 @@ fn output_csv(states: &[StateVersionListItem], deltas: &[Option<i64>], no_header
         let tf_ver = state.attributes.terraform_version.as_deref().unwrap_or("");
         let vcs = state.attributes.vcs_commit_sha.as_deref().unwrap_or("");
 
-        println!(
-            "{},{},{},{},{},{},{},{},{},{}",
-            escape_csv(&state.id),
-            escape_csv(&serial),
-            escape_csv(status),
-            escape_csv(created),
-            escape_csv(&size),
-            escape_csv(&resources),
-            escape_csv(&delta),
-            escape_csv(tf_ver),
-            escape_csv(state.run_id()),
-            escape_csv(vcs),
-        );
+        write_record(
+            writer,
+            &[
+                state.id.clone(),
+                serial,
+                status.to_string(),
+                created.to_string(),
+                size,
+                resources,
+                delta,
+                tf_ver.to_string(),
+                state.run_id().to_string(),
+                vcs.to_string(),
+            ],
+            delimiter,
+        )?;
     }
+    Ok(())
 }
 
-fn output_json(states: &[StateVersionListItem], deltas: &[Option<i64>]) {
-    let data: Vec<SerializableStateVersion> = states
-        .iter()
-        .enumerate()
-        .map(|(i, s)| to_serializable(s, deltas.get(i).copied().flatten()))
-        .collect();
-    super::common::print_json(&data);
-}
-
-fn output_yaml(states: &[StateVersionListItem], deltas: &[Option<i64>]) {
-    let data: Vec<SerializableStateVersion> = states
+fn serializable(
+    states: &[StateVersionListItem],
+    deltas: &[Option<i64>],
+) -> Vec<SerializableStateVersion> {
+    states
         .iter()
         .enumerate()
         .map(|(i, s)| to_serializable(s, deltas.get(i).copied().flatten()))
-        .collect();
-    super::common::print_yaml(&data);
+        .collect()
 }
 
 fn to_serializable(state: &StateVersionListItem, delta: Option<i64>) -> SerializableStateVersion {
@@ -174,6 +258,114 @@ fn to_serializable(state: &StateVersionListItem, delta: Option<i64>) -> Serializ
     }
 }
 
+/// Output the Terraform outputs recorded in a state version
+///
+/// Sensitive values are always masked in table/CSV; structured formats include
+/// them only when `show_sensitive` is set.
+pub fn output_state_version_outputs(
+    outputs: &[StateVersionOutput],
+    format: &OutputFormat,
+    no_header: bool,
+    show_sensitive: bool,
+) {
+    StateOutputFormatter {
+        format: *format,
+        no_header,
+        show_sensitive,
+    }
+    .print(outputs);
+}
+
+/// State version output formatter; see [`output_state_version_outputs`]
+pub struct StateOutputFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub show_sensitive: bool,
+}
+
+impl Formatter for StateOutputFormatter {
+    type Row = StateVersionOutput;
+
+    fn format_to<W: Write>(
+        &self,
+        outputs: &[StateVersionOutput],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        let no_header = self.no_header;
+        let data: Vec<SerializableOutput> = outputs
+            .iter()
+            .map(|o| output_to_serializable(o, self.show_sensitive))
+            .collect();
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                let mut table = super::common::new_table(options);
+                if !no_header {
+                    table.set_header(vec!["NAME", "TYPE", "SENSITIVE", "VALUE"]);
+                }
+                for output in &data {
+                    table.add_row(vec![
+                        output.name.clone(),
+                        output.output_type.clone(),
+                        output.sensitive.to_string(),
+                        display_value(output),
+                    ]);
+                }
+                writeln!(writer)?;
+                super::common::write_table(writer, &table, &self.format)?;
+                if super::common::show_footer(no_header, options) {
+                    writeln!(writer, "\nTotal: {} outputs", data.len())?;
+                }
+                Ok(())
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                let delimiter = csv_delimiter(&self.format);
+                if !no_header {
+                    write_record(writer, &["name", "type", "sensitive", "value"], delimiter)?;
+                }
+                for output in &data {
+                    write_record(
+                        writer,
+                        &[
+                            output.name.clone(),
+                            output.output_type.to_string(),
+                            output.sensitive.to_string(),
+                            display_value(output),
+                        ],
+                        delimiter,
+                    )?;
+                }
+                Ok(())
+            }
+            OutputFormat::Json => super::common::write_json(writer, &data, options),
+            OutputFormat::Yaml => super::common::write_yaml(writer, &data),
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                super::common::write_lines(writer, &data, &self.format, options)
+            }
+        }
+    }
+}
+
+fn output_to_serializable(output: &StateVersionOutput, show_sensitive: bool) -> SerializableOutput {
+    let attrs = &output.attributes;
+    SerializableOutput {
+        name: attrs.name.clone(),
+        output_type: attrs.output_type.clone().unwrap_or_default(),
+        sensitive: attrs.sensitive,
+        value: (show_sensitive || !attrs.sensitive).then(|| attrs.value.clone()),
+    }
+}
+
+/// Value for table/CSV cells: strings as-is, other types as compact JSON
+fn display_value(output: &SerializableOutput) -> String {
+    match &output.value {
+        _ if output.sensitive => "***".to_string(),
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(serde_json::Value::Null) | None => String::new(),
+        Some(other) => other.to_string(),
+    }
+}
+
 fn format_delta(delta: Option<i64>) -> String {
     match delta {
         None => "-".to_string(),
@@ -187,6 +379,121 @@ fn format_delta(delta: Option<i64>) -> String {
 mod tests {
     use super::*;
 
+    fn output(name: &str, sensitive: bool, value: serde_json::Value) -> StateVersionOutput {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("wsout-{}", name),
+            "attributes": {"name": name, "sensitive": sensitive, "type": "string", "value": value}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sensitive_output_value_hidden() {
+        let secret = output_to_serializable(&output("db_password", true, "hunter2".into()), false);
+        assert!(secret.value.is_none());
+        assert_eq!(display_value(&secret), "***");
+        let json = serde_json::to_value(&secret).unwrap();
+        assert!(json.get("value").is_none());
+        assert_eq!(json["type"], "string");
+    }
+
+    #[test]
+    fn test_show_sensitive_includes_value_but_table_stays_masked() {
+        let secret = output_to_serializable(&output("db_password", true, "hunter2".into()), true);
+        assert_eq!(secret.value, Some(serde_json::json!("hunter2")));
+        assert_eq!(display_value(&secret), "***");
+    }
+
+    #[test]
+    fn test_output_display_value() {
+        let plain = output_to_serializable(&output("vpc_id", false, "vpc-123".into()), false);
+        assert_eq!(display_value(&plain), "vpc-123");
+        let list = output_to_serializable(
+            &output("subnets", false, serde_json::json!(["a", "b"])),
+            false,
+        );
+        assert_eq!(display_value(&list), r#"["a","b"]"#);
+        let null = output_to_serializable(&output("empty", false, serde_json::Value::Null), false);
+        assert_eq!(display_value(&null), "");
+    }
+
+    fn state(id: &str, serial: u64) -> StateVersionListItem {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "attributes": {
+                "serial": serial,
+                "status": "finalized",
+                "created-at": "2024-01-01T00:00:00Z",
+                "size": 2048,
+                "terraform-version": "1.6.0"
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_state_version_csv_includes_deltas() {
+        let formatter = StateVersionFormatter {
+            format: OutputFormat::Csv,
+            no_header: true,
+            deltas: &[Some(-2), None],
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(
+                &[state("sv-2", 2), state("sv-1", 1)],
+                &mut buf,
+                &RenderOptions::default(),
+            )
+            .unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("sv-2,2,finalized,2024-01-01T00:00:00Z,2048,"));
+        assert!(lines[0].contains(",-2,1.6.0,"));
+        assert!(lines[1].contains(",,1.6.0,"));
+    }
+
+    #[test]
+    fn test_state_version_table_shows_signed_delta() {
+        let formatter = StateVersionFormatter {
+            format: OutputFormat::Table,
+            no_header: false,
+            deltas: &[Some(3)],
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(&[state("sv-1", 7)], &mut buf, &RenderOptions::default())
+            .unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert!(out.contains("Δ RES"));
+        assert!(out.contains("+3"));
+        assert!(out.ends_with("\nTotal: 1 state versions\n"));
+    }
+
+    #[test]
+    fn test_state_outputs_csv_masks_sensitive() {
+        let outputs = [
+            output("vpc_id", false, "vpc-123".into()),
+            output("db_password", true, "hunter2".into()),
+        ];
+        let formatter = StateOutputFormatter {
+            format: OutputFormat::Csv,
+            no_header: false,
+            show_sensitive: true,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(&outputs, &mut buf, &RenderOptions::default())
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "name,type,sensitive,value\n\
+             vpc_id,string,false,vpc-123\n\
+             db_password,string,true,***\n"
+        );
+    }
+
     #[test]
     fn test_format_delta() {
         assert_eq!(format_delta(None), "-");
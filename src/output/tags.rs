@@ -1,11 +1,12 @@
 //! Tag binding and organization tag output formatter
 
-use super::common::escape_csv;
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, write_record, Formatter, RenderOptions};
 use crate::cli::OutputFormat;
 use crate::hcp::tags::{OrgTag, TagBinding};
 use crate::hcp::traits::TfeResource;
 use crate::hcp::Workspace;
-use comfy_table::{presets::NOTHING, Table};
 use serde::Serialize;
 
 /// Serializable tag binding for structured output (JSON/YAML)
@@ -29,17 +30,56 @@ impl From<&TagBinding> for SerializableTagBinding {
 
 /// Output tag bindings in the specified format
 pub fn output_tag_bindings(tags: &[TagBinding], format: &OutputFormat, no_header: bool) {
-    match format {
-        OutputFormat::Table => output_table(tags, no_header),
-        OutputFormat::Csv => output_csv(tags, no_header),
-        OutputFormat::Json => output_json(tags),
-        OutputFormat::Yaml => output_yaml(tags),
+    TagBindingFormatter {
+        format: *format,
+        no_header,
     }
+    .print(tags);
+}
+
+/// Key-value tag binding formatter
+pub struct TagBindingFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+}
+
+impl Formatter for TagBindingFormatter {
+    type Row = TagBinding;
+
+    fn format_to<W: Write>(
+        &self,
+        tags: &[TagBinding],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_table(writer, tags, self.no_header, &self.format, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                output_csv(writer, tags, self.no_header, csv_delimiter(&self.format))
+            }
+            OutputFormat::Json => super::common::write_json(writer, &serializable(tags), options),
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                super::common::write_lines(writer, serializable(tags), &self.format, options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable(tags)),
+        }
+    }
+}
+
+fn serializable(tags: &[TagBinding]) -> Vec<SerializableTagBinding> {
+    tags.iter().map(SerializableTagBinding::from).collect()
 }
 
-fn output_table(tags: &[TagBinding], no_header: bool) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+fn output_table<W: Write>(
+    writer: &mut W,
+    tags: &[TagBinding],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
     if !no_header {
         table.set_header(vec!["Key", "Value", "Created At"]);
     }
@@ -52,36 +92,36 @@ fn output_table(tags: &[TagBinding], no_header: bool) {
         ]);
     }
 
-    println!();
-    println!("{table}");
-    if !no_header {
-        println!("\nTotal: {} tag(s)", tags.len());
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) {
+        writeln!(writer, "\nTotal: {} tag(s)", tags.len())?;
     }
+    Ok(())
 }
 
-fn output_csv(tags: &[TagBinding], no_header: bool) {
+fn output_csv<W: Write>(
+    writer: &mut W,
+    tags: &[TagBinding],
+    no_header: bool,
+    delimiter: char,
+) -> io::Result<()> {
     if !no_header {
-        println!("key,value,created_at");
+        write_record(writer, &["key", "value", "created_at"], delimiter)?;
     }
 
     for tag in tags {
-        println!(
-            "{},{},{}",
-            escape_csv(&tag.attributes.key),
-            escape_csv(&tag.attributes.value),
-            escape_csv(tag.attributes.created_at.as_deref().unwrap_or(""))
-        );
-    }
-}
-
-fn output_json(tags: &[TagBinding]) {
-    let data: Vec<SerializableTagBinding> = tags.iter().map(SerializableTagBinding::from).collect();
-    super::common::print_json(&data);
-}
-
-fn output_yaml(tags: &[TagBinding]) {
-    let data: Vec<SerializableTagBinding> = tags.iter().map(SerializableTagBinding::from).collect();
-    super::common::print_yaml(&data);
+        write_record(
+            writer,
+            &[
+                tag.attributes.key.clone(),
+                tag.attributes.value.to_string(),
+                tag.attributes.created_at.clone().unwrap_or_default(),
+            ],
+            delimiter,
+        )?;
+    }
+    Ok(())
 }
 
 // === Organization-level tag output ===
@@ -107,17 +147,58 @@ impl From<&OrgTag> for SerializableOrgTag {
 
 /// Output organization tags in the specified format
 pub fn output_org_tags(tags: &[OrgTag], format: &OutputFormat, no_header: bool) {
-    match format {
-        OutputFormat::Table => output_org_table(tags, no_header),
-        OutputFormat::Csv => output_org_csv(tags, no_header),
-        OutputFormat::Json => output_org_json(tags),
-        OutputFormat::Yaml => output_org_yaml(tags),
+    OrgTagFormatter {
+        format: *format,
+        no_header,
     }
+    .print(tags);
+}
+
+/// Organization tag formatter
+pub struct OrgTagFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
 }
 
-fn output_org_table(tags: &[OrgTag], no_header: bool) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+impl Formatter for OrgTagFormatter {
+    type Row = OrgTag;
+
+    fn format_to<W: Write>(
+        &self,
+        tags: &[OrgTag],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_org_table(writer, tags, self.no_header, &self.format, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                output_org_csv(writer, tags, self.no_header, csv_delimiter(&self.format))
+            }
+            OutputFormat::Json => {
+                super::common::write_json(writer, &serializable_org(tags), options)
+            }
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                super::common::write_lines(writer, serializable_org(tags), &self.format, options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable_org(tags)),
+        }
+    }
+}
+
+fn serializable_org(tags: &[OrgTag]) -> Vec<SerializableOrgTag> {
+    tags.iter().map(SerializableOrgTag::from).collect()
+}
+
+fn output_org_table<W: Write>(
+    writer: &mut W,
+    tags: &[OrgTag],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
     if !no_header {
         table.set_header(vec!["Name", "Instance Count", "Created At"]);
     }
@@ -130,36 +211,36 @@ fn output_org_table(tags: &[OrgTag], no_header: bool) {
         ]);
     }
 
-    println!();
-    println!("{table}");
-    if !no_header {
-        println!("\nTotal: {} tag(s)", tags.len());
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) {
+        writeln!(writer, "\nTotal: {} tag(s)", tags.len())?;
     }
+    Ok(())
 }
 
-fn output_org_csv(tags: &[OrgTag], no_header: bool) {
+fn output_org_csv<W: Write>(
+    writer: &mut W,
+    tags: &[OrgTag],
+    no_header: bool,
+    delimiter: char,
+) -> io::Result<()> {
     if !no_header {
-        println!("name,instance_count,created_at");
+        write_record(writer, &["name", "instance_count", "created_at"], delimiter)?;
     }
 
     for tag in tags {
-        println!(
-            "{},{},{}",
-            escape_csv(&tag.attributes.name),
-            tag.attributes.instance_count,
-            escape_csv(tag.attributes.created_at.as_deref().unwrap_or(""))
-        );
-    }
-}
-
-fn output_org_json(tags: &[OrgTag]) {
-    let data: Vec<SerializableOrgTag> = tags.iter().map(SerializableOrgTag::from).collect();
-    super::common::print_json(&data);
-}
-
-fn output_org_yaml(tags: &[OrgTag]) {
-    let data: Vec<SerializableOrgTag> = tags.iter().map(SerializableOrgTag::from).collect();
-    super::common::print_yaml(&data);
+        write_record(
+            writer,
+            &[
+                tag.attributes.name.clone(),
+                tag.attributes.instance_count.to_string(),
+                tag.attributes.created_at.clone().unwrap_or_default(),
+            ],
+            delimiter,
+        )?;
+    }
+    Ok(())
 }
 
 // === Organization-level tag detail output (with associated workspaces) ===
@@ -192,57 +273,81 @@ pub fn output_org_tags_with_workspaces(
     format: &OutputFormat,
     no_header: bool,
 ) {
+    let options = super::common::render_options();
+    super::common::print_with(|mut out| {
+        write_org_tags_with_workspaces(&mut out, tags, workspaces, format, no_header, options)
+    });
+}
+
+fn write_org_tags_with_workspaces<W: Write>(
+    writer: &mut W,
+    tags: &[OrgTag],
+    workspaces: &[Workspace],
+    format: &OutputFormat,
+    no_header: bool,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let details = || {
+        tags.iter()
+            .map(|t| SerializableOrgTagDetail::from_tag_and_workspaces(t, workspaces))
+    };
     match format {
-        OutputFormat::Table => {
-            output_org_table(tags, no_header);
-            output_associated_workspaces_table(workspaces);
+        OutputFormat::Table | OutputFormat::Markdown => {
+            output_org_table(writer, tags, no_header, format, options)?;
+            output_associated_workspaces_table(writer, workspaces, format, options)
         }
-        OutputFormat::Csv => {
-            output_org_csv(tags, no_header);
+        OutputFormat::Csv | OutputFormat::Tsv => {
+            let delimiter = csv_delimiter(format);
+            output_org_csv(writer, tags, no_header, delimiter)?;
             // Workspaces in a separate CSV section
             if !workspaces.is_empty() {
-                println!();
+                writeln!(writer)?;
                 if !no_header {
-                    println!("workspace_name,workspace_id");
+                    write_record(writer, &["workspace_name", "workspace_id"], delimiter)?;
                 }
                 for ws in workspaces {
-                    println!("{},{}", escape_csv(ws.name()), escape_csv(ws.id()));
+                    write_record(
+                        writer,
+                        &[ws.name().to_string(), ws.id().to_string()],
+                        delimiter,
+                    )?;
                 }
             }
+            Ok(())
         }
         OutputFormat::Json => {
-            let data: Vec<SerializableOrgTagDetail> = tags
-                .iter()
-                .map(|t| SerializableOrgTagDetail::from_tag_and_workspaces(t, workspaces))
-                .collect();
-            super::common::print_json(&data);
+            super::common::write_json(writer, &details().collect::<Vec<_>>(), options)
         }
-        OutputFormat::Yaml => {
-            let data: Vec<SerializableOrgTagDetail> = tags
-                .iter()
-                .map(|t| SerializableOrgTagDetail::from_tag_and_workspaces(t, workspaces))
-                .collect();
-            super::common::print_yaml(&data);
+        OutputFormat::Yaml => super::common::write_yaml(writer, &details().collect::<Vec<_>>()),
+        OutputFormat::Ndjson | OutputFormat::Template => {
+            super::common::write_lines(writer, details(), format, options)
         }
     }
 }
 
-fn output_associated_workspaces_table(workspaces: &[Workspace]) {
+fn output_associated_workspaces_table<W: Write>(
+    writer: &mut W,
+    workspaces: &[Workspace],
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
     if workspaces.is_empty() {
-        return;
+        return Ok(());
     }
 
-    println!("\nAssociated workspaces:");
+    writeln!(writer, "\nAssociated workspaces:")?;
 
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+    let mut table = super::common::new_table(options);
     table.set_header(vec!["Workspace", "ID"]);
 
     for ws in workspaces {
-        table.add_row(vec![ws.name(), ws.id()]);
+        table.add_row(vec![
+            super::common::table_name(ws.name(), options),
+            ws.id().to_string(),
+        ]);
     }
 
-    println!("{table}");
+    super::common::write_table(writer, &table, format)
 }
 
 // === Workspace combined tags output (flat string tags + key-value tag bindings) ===
@@ -263,21 +368,47 @@ pub fn output_workspace_all_tags(
     format: &OutputFormat,
     no_header: bool,
 ) {
+    let options = super::common::render_options();
+    super::common::print_with(|mut out| {
+        write_workspace_all_tags(
+            &mut out,
+            workspace_tags,
+            tag_bindings,
+            format,
+            no_header,
+            options,
+        )
+    });
+}
+
+fn write_workspace_all_tags<W: Write>(
+    writer: &mut W,
+    workspace_tags: &[OrgTag],
+    tag_bindings: &[TagBinding],
+    format: &OutputFormat,
+    no_header: bool,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let data = || SerializableWorkspaceAllTags {
+        tags: workspace_tags
+            .iter()
+            .map(|t| t.attributes.name.clone())
+            .collect(),
+        tag_bindings: serializable(tag_bindings),
+    };
     match format {
-        OutputFormat::Table => {
+        OutputFormat::Table | OutputFormat::Markdown => {
             if !workspace_tags.is_empty() {
-                println!("\nTags:");
-                let mut table = Table::new();
-                table.load_preset(NOTHING);
+                writeln!(writer, "\nTags:")?;
+                let mut table = super::common::new_table(options);
                 for tag in workspace_tags {
                     table.add_row(vec![&tag.attributes.name]);
                 }
-                println!("{table}");
+                super::common::write_table(writer, &table, format)?;
             }
             if !tag_bindings.is_empty() {
-                println!("\nTag bindings:");
-                let mut table = Table::new();
-                table.load_preset(NOTHING);
+                writeln!(writer, "\nTag bindings:")?;
+                let mut table = super::common::new_table(options);
                 if !no_header {
                     table.set_header(vec!["Key", "Value", "Created At"]);
                 }
@@ -288,57 +419,44 @@ pub fn output_workspace_all_tags(
                         tag.attributes.created_at.as_deref().unwrap_or(""),
                     ]);
                 }
-                println!("{table}");
+                super::common::write_table(writer, &table, format)?;
             }
-            if !no_header {
-                println!(
+            if super::common::show_footer(no_header, options) {
+                writeln!(
+                    writer,
                     "\nTotal: {} tag(s), {} tag binding(s)",
                     workspace_tags.len(),
                     tag_bindings.len()
-                );
+                )?;
             }
+            Ok(())
         }
-        OutputFormat::Csv => {
+        OutputFormat::Csv | OutputFormat::Tsv => {
+            let delimiter = csv_delimiter(format);
             if !workspace_tags.is_empty() {
                 if !no_header {
-                    println!("tag_name");
+                    write_record(writer, &["tag_name"], delimiter)?;
                 }
                 for tag in workspace_tags {
-                    println!("{}", escape_csv(&tag.attributes.name));
+                    write_record(writer, &[&tag.attributes.name], delimiter)?;
                 }
             }
             if !tag_bindings.is_empty() {
                 if !workspace_tags.is_empty() {
-                    println!();
+                    writeln!(writer)?;
                 }
-                output_csv(tag_bindings, no_header);
+                output_csv(writer, tag_bindings, no_header, csv_delimiter(format))?;
             }
+            Ok(())
         }
-        OutputFormat::Json => {
-            let data = SerializableWorkspaceAllTags {
-                tags: workspace_tags
-                    .iter()
-                    .map(|t| t.attributes.name.clone())
-                    .collect(),
-                tag_bindings: tag_bindings
-                    .iter()
-                    .map(SerializableTagBinding::from)
-                    .collect(),
-            };
-            println!("{}", serde_json::to_string_pretty(&data).unwrap());
-        }
-        OutputFormat::Yaml => {
-            let data = SerializableWorkspaceAllTags {
-                tags: workspace_tags
-                    .iter()
-                    .map(|t| t.attributes.name.clone())
-                    .collect(),
-                tag_bindings: tag_bindings
-                    .iter()
-                    .map(SerializableTagBinding::from)
-                    .collect(),
-            };
-            println!("{}", serde_yml::to_string(&data).unwrap());
+        OutputFormat::Json => writeln!(
+            writer,
+            "{}",
+            super::common::to_json_pretty(&data(), options)
+        ),
+        OutputFormat::Yaml => writeln!(writer, "{}", serde_yml::to_string(&data()).unwrap()),
+        OutputFormat::Ndjson | OutputFormat::Template => {
+            super::common::write_lines(writer, std::iter::once(data()), format, options)
         }
     }
 }
@@ -371,43 +489,68 @@ mod tests {
         ]
     }
 
+    fn render_bindings(tags: &[TagBinding], format: OutputFormat, no_header: bool) -> String {
+        let mut buf = Vec::new();
+        TagBindingFormatter { format, no_header }
+            .format_to(tags, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
     fn test_output_tag_bindings_empty() {
-        output_tag_bindings(&[], &OutputFormat::Table, false);
-        output_tag_bindings(&[], &OutputFormat::Csv, false);
-        output_tag_bindings(&[], &OutputFormat::Json, false);
-        output_tag_bindings(&[], &OutputFormat::Yaml, false);
+        assert!(render_bindings(&[], OutputFormat::Table, false).ends_with("Total: 0 tag(s)\n"));
+        assert_eq!(
+            render_bindings(&[], OutputFormat::Csv, false),
+            "key,value,created_at\n"
+        );
+        assert_eq!(render_bindings(&[], OutputFormat::Json, false), "[]\n");
+        assert_eq!(render_bindings(&[], OutputFormat::Yaml, false), "[]\n");
     }
 
     #[test]
     fn test_output_tag_bindings_table() {
-        let tags = create_test_tags();
-        output_tag_bindings(&tags, &OutputFormat::Table, false);
+        let out = render_bindings(&create_test_tags(), OutputFormat::Table, false);
+        assert!(out.contains("Created At"));
+        assert!(out.contains("backend"));
+        assert!(out.ends_with("\nTotal: 2 tag(s)\n"));
     }
 
     #[test]
     fn test_output_tag_bindings_csv() {
-        let tags = create_test_tags();
-        output_tag_bindings(&tags, &OutputFormat::Csv, false);
+        assert_eq!(
+            render_bindings(&create_test_tags(), OutputFormat::Csv, false),
+            "key,value,created_at\nenv,prod,2024-01-01T00:00:00Z\nteam,backend,\n"
+        );
     }
 
     #[test]
     fn test_output_tag_bindings_json() {
-        let tags = create_test_tags();
-        output_tag_bindings(&tags, &OutputFormat::Json, false);
+        let out = render_bindings(&create_test_tags(), OutputFormat::Json, false);
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(json[0]["key"], "env");
+        assert!(json[1].get("created_at").is_none());
     }
 
     #[test]
     fn test_output_tag_bindings_yaml() {
-        let tags = create_test_tags();
-        output_tag_bindings(&tags, &OutputFormat::Yaml, false);
+        let out = render_bindings(&create_test_tags(), OutputFormat::Yaml, false);
+        assert!(out.contains("key: team"));
+        assert!(out.contains("value: backend"));
     }
 
     #[test]
     fn test_output_tag_bindings_no_header() {
         let tags = create_test_tags();
-        output_tag_bindings(&tags, &OutputFormat::Table, true);
-        output_tag_bindings(&tags, &OutputFormat::Csv, true);
+        let table = render_bindings(&tags, OutputFormat::Table, true);
+        assert!(!table.contains("Created At"));
+        assert!(!table.contains("Total:"));
+        assert_eq!(
+            render_bindings(&tags, OutputFormat::Csv, true)
+                .lines()
+                .count(),
+            2
+        );
     }
 
     #[test]
@@ -452,40 +595,59 @@ mod tests {
         ]
     }
 
+    fn render_org_tags(tags: &[OrgTag], format: OutputFormat, no_header: bool) -> String {
+        let mut buf = Vec::new();
+        OrgTagFormatter { format, no_header }
+            .format_to(tags, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
     fn test_output_org_tags_table() {
-        let tags = create_test_org_tags();
-        output_org_tags(&tags, &OutputFormat::Table, false);
+        let out = render_org_tags(&create_test_org_tags(), OutputFormat::Table, false);
+        assert!(out.contains("Instance Count"));
+        assert!(out.contains("team"));
+        assert!(out.ends_with("\nTotal: 2 tag(s)\n"));
     }
 
     #[test]
     fn test_output_org_tags_csv() {
-        let tags = create_test_org_tags();
-        output_org_tags(&tags, &OutputFormat::Csv, false);
+        assert_eq!(
+            render_org_tags(&create_test_org_tags(), OutputFormat::Csv, false),
+            "name,instance_count,created_at\nenv,5,2024-01-01T00:00:00Z\nteam,3,\n"
+        );
     }
 
     #[test]
     fn test_output_org_tags_json() {
-        let tags = create_test_org_tags();
-        output_org_tags(&tags, &OutputFormat::Json, false);
+        let out = render_org_tags(&create_test_org_tags(), OutputFormat::Json, false);
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(json[0]["instance_count"], 5);
+        assert_eq!(json[1]["name"], "team");
     }
 
     #[test]
     fn test_output_org_tags_yaml() {
-        let tags = create_test_org_tags();
-        output_org_tags(&tags, &OutputFormat::Yaml, false);
+        let out = render_org_tags(&create_test_org_tags(), OutputFormat::Yaml, false);
+        assert!(out.contains("name: env"));
+        assert!(out.contains("instance_count: 3"));
     }
 
     #[test]
     fn test_output_org_tags_empty() {
-        output_org_tags(&[], &OutputFormat::Table, false);
+        let out = render_org_tags(&[], OutputFormat::Table, false);
+        assert!(out.ends_with("\nTotal: 0 tag(s)\n"));
     }
 
     #[test]
     fn test_output_org_tags_no_header() {
         let tags = create_test_org_tags();
-        output_org_tags(&tags, &OutputFormat::Table, true);
-        output_org_tags(&tags, &OutputFormat::Csv, true);
+        assert!(!render_org_tags(&tags, OutputFormat::Table, true).contains("Instance Count"));
+        assert_eq!(
+            render_org_tags(&tags, OutputFormat::Csv, true),
+            "env,5,2024-01-01T00:00:00Z\nteam,3,\n"
+        );
     }
 
     #[test]
@@ -514,11 +676,16 @@ mod tests {
                 id: "ws-abc".to_string(),
                 attributes: WorkspaceAttributes {
                     name: "alpha-ws".to_string(),
+                    vcs_repo: None,
+                    working_directory: None,
+                    assessments_enabled: None,
                     execution_mode: None,
                     resource_count: None,
                     locked: None,
                     terraform_version: None,
                     updated_at: None,
+                    settings: Default::default(),
+                    state: None,
                 },
                 relationships: None,
             },
@@ -526,49 +693,82 @@ mod tests {
                 id: "ws-def".to_string(),
                 attributes: WorkspaceAttributes {
                     name: "beta-ws".to_string(),
+                    vcs_repo: None,
+                    working_directory: None,
+                    assessments_enabled: None,
                     execution_mode: None,
                     resource_count: None,
                     locked: None,
                     terraform_version: None,
                     updated_at: None,
+                    settings: Default::default(),
+                    state: None,
                 },
                 relationships: None,
             },
         ]
     }
 
+    fn render_with_workspaces(
+        tags: &[OrgTag],
+        workspaces: &[Workspace],
+        format: OutputFormat,
+        options: &RenderOptions,
+    ) -> String {
+        let mut buf = Vec::new();
+        write_org_tags_with_workspaces(&mut buf, tags, workspaces, &format, false, options)
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
     fn test_output_org_tags_with_workspaces_table() {
+        let options = &RenderOptions::default();
         let tags = create_test_org_tags();
         let workspaces = create_test_workspaces();
-        output_org_tags_with_workspaces(&tags, &workspaces, &OutputFormat::Table, false);
+        let out = render_with_workspaces(&tags, &workspaces, OutputFormat::Table, options);
+        assert!(out.contains("Associated workspaces:"));
+        assert!(out.contains("alpha-ws"));
+        assert!(out.contains("ws-def"));
     }
 
     #[test]
     fn test_output_org_tags_with_workspaces_json() {
+        let options = &RenderOptions::default();
         let tags = create_test_org_tags();
         let workspaces = create_test_workspaces();
-        output_org_tags_with_workspaces(&tags, &workspaces, &OutputFormat::Json, false);
+        let out = render_with_workspaces(&tags, &workspaces, OutputFormat::Json, options);
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(
+            json[0]["workspaces"],
+            serde_json::json!(["alpha-ws", "beta-ws"])
+        );
     }
 
     #[test]
     fn test_output_org_tags_with_workspaces_yaml() {
+        let options = &RenderOptions::default();
         let tags = create_test_org_tags();
         let workspaces = create_test_workspaces();
-        output_org_tags_with_workspaces(&tags, &workspaces, &OutputFormat::Yaml, false);
+        let out = render_with_workspaces(&tags, &workspaces, OutputFormat::Yaml, options);
+        assert!(out.contains("- beta-ws"));
     }
 
     #[test]
     fn test_output_org_tags_with_workspaces_csv() {
+        let options = &RenderOptions::default();
         let tags = create_test_org_tags();
         let workspaces = create_test_workspaces();
-        output_org_tags_with_workspaces(&tags, &workspaces, &OutputFormat::Csv, false);
+        let out = render_with_workspaces(&tags, &workspaces, OutputFormat::Csv, options);
+        assert!(out.ends_with("\n\nworkspace_name,workspace_id\nalpha-ws,ws-abc\nbeta-ws,ws-def\n"));
     }
 
     #[test]
     fn test_output_org_tags_with_empty_workspaces() {
+        let options = &RenderOptions::default();
         let tags = create_test_org_tags();
-        output_org_tags_with_workspaces(&tags, &[], &OutputFormat::Table, false);
+        let out = render_with_workspaces(&tags, &[], OutputFormat::Table, options);
+        assert!(!out.contains("Associated workspaces:"));
     }
 
     #[test]
@@ -591,47 +791,95 @@ mod tests {
 
     // === Workspace combined tags output tests ===
 
+    fn render_all_tags(
+        workspace_tags: &[OrgTag],
+        bindings: &[TagBinding],
+        format: OutputFormat,
+        options: &RenderOptions,
+    ) -> String {
+        let mut buf = Vec::new();
+        write_workspace_all_tags(&mut buf, workspace_tags, bindings, &format, false, options)
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
     fn test_output_workspace_all_tags_table() {
-        let ws_tags = create_test_org_tags();
-        let bindings = create_test_tags();
-        output_workspace_all_tags(&ws_tags, &bindings, &OutputFormat::Table, false);
+        let options = &RenderOptions::default();
+        let out = render_all_tags(
+            &create_test_org_tags(),
+            &create_test_tags(),
+            OutputFormat::Table,
+            options,
+        );
+        assert!(out.contains("Tags:"));
+        assert!(out.contains("Tag bindings:"));
+        assert!(out.ends_with("\nTotal: 2 tag(s), 2 tag binding(s)\n"));
     }
 
     #[test]
     fn test_output_workspace_all_tags_json() {
-        let ws_tags = create_test_org_tags();
-        let bindings = create_test_tags();
-        output_workspace_all_tags(&ws_tags, &bindings, &OutputFormat::Json, false);
+        let options = &RenderOptions::default();
+        let out = render_all_tags(
+            &create_test_org_tags(),
+            &create_test_tags(),
+            OutputFormat::Json,
+            options,
+        );
+        let json: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(json["tags"], serde_json::json!(["env", "team"]));
+        assert_eq!(json["tag_bindings"][0]["value"], "prod");
     }
 
     #[test]
     fn test_output_workspace_all_tags_yaml() {
-        let ws_tags = create_test_org_tags();
-        let bindings = create_test_tags();
-        output_workspace_all_tags(&ws_tags, &bindings, &OutputFormat::Yaml, false);
+        let options = &RenderOptions::default();
+        let out = render_all_tags(
+            &create_test_org_tags(),
+            &create_test_tags(),
+            OutputFormat::Yaml,
+            options,
+        );
+        assert!(out.contains("tag_bindings:"));
+        assert!(out.contains("- env"));
     }
 
     #[test]
     fn test_output_workspace_all_tags_csv() {
-        let ws_tags = create_test_org_tags();
-        let bindings = create_test_tags();
-        output_workspace_all_tags(&ws_tags, &bindings, &OutputFormat::Csv, false);
+        let options = &RenderOptions::default();
+        let out = render_all_tags(
+            &create_test_org_tags(),
+            &create_test_tags(),
+            OutputFormat::Csv,
+            options,
+        );
+        assert_eq!(
+            out,
+            "tag_name\nenv\nteam\n\n\
+             key,value,created_at\nenv,prod,2024-01-01T00:00:00Z\nteam,backend,\n"
+        );
     }
 
     #[test]
     fn test_output_workspace_all_tags_only_flat_tags() {
+        let options = &RenderOptions::default();
         let ws_tags = create_test_org_tags();
-        output_workspace_all_tags(&ws_tags, &[], &OutputFormat::Table, false);
-        output_workspace_all_tags(&ws_tags, &[], &OutputFormat::Json, false);
-        output_workspace_all_tags(&ws_tags, &[], &OutputFormat::Yaml, false);
+        let table = render_all_tags(&ws_tags, &[], OutputFormat::Table, options);
+        assert!(!table.contains("Tag bindings:"));
+        let json = render_all_tags(&ws_tags, &[], OutputFormat::Json, options);
+        assert!(!json.contains("tag_bindings"));
+        let yaml = render_all_tags(&ws_tags, &[], OutputFormat::Yaml, options);
+        assert!(yaml.starts_with("tags:"));
     }
 
     #[test]
     fn test_output_workspace_all_tags_only_bindings() {
+        let options = &RenderOptions::default();
         let bindings = create_test_tags();
-        output_workspace_all_tags(&[], &bindings, &OutputFormat::Table, false);
-        output_workspace_all_tags(&[], &bindings, &OutputFormat::Json, false);
+        let table = render_all_tags(&[], &bindings, OutputFormat::Table, options);
+        assert!(!table.contains("Tags:"));
+        let json = render_all_tags(&[], &bindings, OutputFormat::Json, options);
+        assert!(!json.contains("\"tags\""));
     }
 
     #[test]
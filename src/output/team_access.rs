@@ -1,9 +1,10 @@
 //! Team access output formatter
 
-use super::common::escape_csv;
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, write_record, Formatter, RenderOptions};
 use crate::cli::OutputFormat;
 use crate::hcp::team_projects::EnrichedTeamProjectAccess;
-use comfy_table::{presets::NOTHING, Table};
 use serde::Serialize;
 
 /// Serializable team access for structured output (JSON/YAML)
@@ -36,17 +37,61 @@ pub fn output_team_access(
     format: &OutputFormat,
     no_header: bool,
 ) {
-    match format {
-        OutputFormat::Table => output_table(bindings, no_header),
-        OutputFormat::Csv => output_csv(bindings, no_header),
-        OutputFormat::Json => output_json(bindings),
-        OutputFormat::Yaml => output_yaml(bindings),
+    TeamAccessFormatter {
+        format: *format,
+        no_header,
+    }
+    .print(bindings);
+}
+
+/// Team project access formatter
+pub struct TeamAccessFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+}
+
+impl Formatter for TeamAccessFormatter {
+    type Row = EnrichedTeamProjectAccess;
+
+    fn format_to<W: Write>(
+        &self,
+        bindings: &[EnrichedTeamProjectAccess],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_table(writer, bindings, self.no_header, &self.format, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => output_csv(
+                writer,
+                bindings,
+                self.no_header,
+                csv_delimiter(&self.format),
+            ),
+            OutputFormat::Json => {
+                super::common::write_json(writer, &serializable(bindings), options)
+            }
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                super::common::write_lines(writer, serializable(bindings), &self.format, options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable(bindings)),
+        }
     }
 }
 
-fn output_table(bindings: &[EnrichedTeamProjectAccess], no_header: bool) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+fn serializable(bindings: &[EnrichedTeamProjectAccess]) -> Vec<SerializableTeamAccess> {
+    bindings.iter().map(SerializableTeamAccess::from).collect()
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    bindings: &[EnrichedTeamProjectAccess],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
     if !no_header {
         table.set_header(vec!["ID", "TEAM", "PROJECT", "ACCESS"]);
     }
@@ -60,34 +105,31 @@ fn output_table(bindings: &[EnrichedTeamProjectAccess], no_header: bool) {
         ]);
     }
 
-    println!("{table}");
+    super::common::write_table(writer, &table, format)
 }
 
-fn output_csv(bindings: &[EnrichedTeamProjectAccess], no_header: bool) {
+fn output_csv<W: Write>(
+    writer: &mut W,
+    bindings: &[EnrichedTeamProjectAccess],
+    no_header: bool,
+    delimiter: char,
+) -> io::Result<()> {
     if !no_header {
-        println!("ID,TEAM,PROJECT,ACCESS");
+        write_record(writer, &["ID", "TEAM", "PROJECT", "ACCESS"], delimiter)?;
     }
     for binding in bindings {
-        println!(
-            "{},{},{},{}",
-            escape_csv(&binding.id),
-            escape_csv(&binding.team_name),
-            escape_csv(&binding.project_name),
-            escape_csv(&binding.access),
-        );
+        write_record(
+            writer,
+            &[
+                binding.id.clone(),
+                binding.team_name.to_string(),
+                binding.project_name.to_string(),
+                binding.access.to_string(),
+            ],
+            delimiter,
+        )?;
     }
-}
-
-fn output_json(bindings: &[EnrichedTeamProjectAccess]) {
-    let serializable: Vec<SerializableTeamAccess> =
-        bindings.iter().map(SerializableTeamAccess::from).collect();
-    super::common::print_json(&serializable);
-}
-
-fn output_yaml(bindings: &[EnrichedTeamProjectAccess]) {
-    let serializable: Vec<SerializableTeamAccess> =
-        bindings.iter().map(SerializableTeamAccess::from).collect();
-    super::common::print_yaml(&serializable);
+    Ok(())
 }
 
 #[cfg(test)]
@@ -181,14 +223,41 @@ mod tests {
         assert!(json.contains("\"team_name\": \"devs\""));
     }
 
+    fn render(bindings: &[EnrichedTeamProjectAccess], format: OutputFormat) -> String {
+        let formatter = TeamAccessFormatter {
+            format,
+            no_header: false,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(bindings, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
     fn test_output_csv_escaping() {
         let binding = create_test_binding("tprj-1", "team,with,commas", "project\"quoted", "admin");
-        let serializable = SerializableTeamAccess::from(&binding);
 
-        // Verify the field values that would need escaping in CSV
-        assert_eq!(serializable.team_name, "team,with,commas");
-        assert_eq!(serializable.project_name, "project\"quoted");
+        assert_eq!(
+            render(&[binding], OutputFormat::Csv),
+            "ID,TEAM,PROJECT,ACCESS\n\
+             tprj-1,\"team,with,commas\",\"project\"\"quoted\",admin\n"
+        );
+    }
+
+    #[test]
+    fn test_output_table() {
+        let bindings = [
+            create_test_binding("tprj-1", "owners", "infra", "admin"),
+            create_test_binding("tprj-2", "devs", "app", "read"),
+        ];
+        let out = render(&bindings, OutputFormat::Table);
+
+        assert!(out.contains("TEAM"));
+        assert!(out.contains("owners"));
+        assert!(out.contains("tprj-2"));
+        assert_eq!(out.lines().count(), 3);
     }
 
     #[test]
@@ -1,11 +1,16 @@
 //! Team output formatter
 
-use super::common::escape_csv;
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, write_record, Formatter, RenderOptions};
 use crate::cli::{Cli, Command, GetResource, OutputFormat};
-use crate::hcp::teams::Team;
-use comfy_table::{presets::NOTHING, Table};
+use crate::hcp::teams::{Team, TeamMember};
 use serde::Serialize;
 
+/// Team members keyed by team ID (`--with-members`)
+pub type TeamMembers = HashMap<String, Vec<TeamMember>>;
+
 /// Serializable team for structured output (JSON/YAML)
 #[derive(Serialize)]
 struct SerializableTeam {
@@ -13,6 +18,8 @@ struct SerializableTeam {
     name: String,
     users_count: u32,
     visibility: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    members: Option<Vec<String>>,
 }
 
 impl From<&Team> for SerializableTeam {
@@ -22,12 +29,25 @@ impl From<&Team> for SerializableTeam {
             name: team.name().to_string(),
             users_count: team.users_count(),
             visibility: team.visibility().to_string(),
+            members: None,
         }
     }
 }
 
+/// Usernames of a team's members, if members were fetched
+fn member_names(members: Option<&TeamMembers>, team: &Team) -> Option<Vec<String>> {
+    members.map(|m| {
+        m.get(&team.id)
+            .map(|list| list.iter().map(|u| u.username.clone()).collect())
+            .unwrap_or_default()
+    })
+}
+
 /// Output teams in the specified format
-pub fn output_teams(teams: &[Team], cli: &Cli) {
+///
+/// With `members` (`--with-members`), table/CSV gain a MEMBERS column and
+/// JSON/YAML a `members` list of usernames.
+pub fn output_teams(teams: &[Team], members: Option<&TeamMembers>, cli: &Cli) {
     let Command::Get {
         resource: GetResource::Team(args),
     } = &cli.command
@@ -35,56 +55,219 @@ pub fn output_teams(teams: &[Team], cli: &Cli) {
         unreachable!()
     };
 
-    match args.output {
-        OutputFormat::Table => output_table(teams, cli.no_header),
-        OutputFormat::Csv => output_csv(teams, cli.no_header),
-        OutputFormat::Json => output_json(teams),
-        OutputFormat::Yaml => output_yaml(teams),
+    if cli.id_only {
+        super::common::print_ids(teams);
+        return;
     }
+
+    TeamFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+        members,
+    }
+    .print(teams);
+}
+
+/// Team formatter; `members` adds the `--with-members` column
+pub struct TeamFormatter<'a> {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub members: Option<&'a TeamMembers>,
+}
+
+impl Formatter for TeamFormatter<'_> {
+    type Row = Team;
+
+    fn format_to<W: Write>(
+        &self,
+        teams: &[Team],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => output_table(
+                writer,
+                teams,
+                self.members,
+                self.no_header,
+                &self.format,
+                options,
+            ),
+            OutputFormat::Csv | OutputFormat::Tsv => output_csv(
+                writer,
+                teams,
+                self.members,
+                self.no_header,
+                csv_delimiter(&self.format),
+            ),
+            OutputFormat::Json => {
+                super::common::write_json(writer, &serializable(teams, self.members), options)
+            }
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                serializable(teams, self.members),
+                &self.format,
+                options,
+            ),
+            OutputFormat::Yaml => {
+                super::common::write_yaml(writer, &serializable(teams, self.members))
+            }
+        }
+    }
+}
+
+fn serializable(teams: &[Team], members: Option<&TeamMembers>) -> Vec<SerializableTeam> {
+    teams
+        .iter()
+        .map(|team| SerializableTeam {
+            members: member_names(members, team),
+            ..SerializableTeam::from(team)
+        })
+        .collect()
 }
 
-fn output_table(teams: &[Team], no_header: bool) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+fn output_table<W: Write>(
+    writer: &mut W,
+    teams: &[Team],
+    members: Option<&TeamMembers>,
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
     if !no_header {
-        table.set_header(vec!["ID", "NAME", "USERS", "VISIBILITY"]);
+        let mut header = vec!["ID", "NAME", "USERS", "VISIBILITY"];
+        if members.is_some() {
+            header.push("MEMBERS");
+        }
+        table.set_header(header);
     }
 
     for team in teams {
-        table.add_row(vec![
-            team.id.as_str(),
-            team.name(),
-            &team.users_count().to_string(),
-            team.visibility(),
-        ]);
+        let mut row = vec![
+            team.id.clone(),
+            team.name().to_string(),
+            team.users_count().to_string(),
+            team.visibility().to_string(),
+        ];
+        if let Some(names) = member_names(members, team) {
+            row.push(names.join(", "));
+        }
+        table.add_row(row);
     }
 
-    println!("{table}");
+    super::common::write_table(writer, &table, format)
 }
 
-fn output_csv(teams: &[Team], no_header: bool) {
+fn output_csv<W: Write>(
+    writer: &mut W,
+    teams: &[Team],
+    members: Option<&TeamMembers>,
+    no_header: bool,
+    delimiter: char,
+) -> io::Result<()> {
     if !no_header {
-        println!("ID,NAME,USERS,VISIBILITY");
+        let mut header = vec!["ID", "NAME", "USERS", "VISIBILITY"];
+        if members.is_some() {
+            header.push("MEMBERS");
+        }
+        write_record(writer, &header, delimiter)?;
     }
     for team in teams {
-        println!(
-            "{},{},{},{}",
-            escape_csv(&team.id),
-            escape_csv(team.name()),
-            team.users_count(),
-            escape_csv(team.visibility())
-        );
+        let mut record = vec![
+            team.id.clone(),
+            team.name().to_string(),
+            team.users_count().to_string(),
+            team.visibility().to_string(),
+        ];
+        if let Some(names) = member_names(members, team) {
+            record.push(names.join(";"));
+        }
+        write_record(writer, &record, delimiter)?;
     }
+    Ok(())
 }
 
-fn output_json(teams: &[Team]) {
-    let serializable: Vec<SerializableTeam> = teams.iter().map(SerializableTeam::from).collect();
-    super::common::print_json(&serializable);
+/// Output the members of a single team (`get team NAME --members`)
+pub fn output_team_members(members: &[TeamMember], cli: &Cli) {
+    let Command::Get {
+        resource: GetResource::Team(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if cli.id_only {
+        super::common::print_ids(members);
+        return;
+    }
+
+    TeamMemberFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+    }
+    .print(members);
 }
 
-fn output_yaml(teams: &[Team]) {
-    let serializable: Vec<SerializableTeam> = teams.iter().map(SerializableTeam::from).collect();
-    super::common::print_yaml(&serializable);
+/// Team member formatter
+pub struct TeamMemberFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+}
+
+impl Formatter for TeamMemberFormatter {
+    type Row = TeamMember;
+
+    fn format_to<W: Write>(
+        &self,
+        members: &[TeamMember],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                let mut table = super::common::new_table(options);
+                if !self.no_header {
+                    table.set_header(vec!["USERNAME", "EMAIL", "USER ID"]);
+                }
+                for member in members {
+                    table.add_row(vec![
+                        member.username.as_str(),
+                        member.email.as_deref().unwrap_or("-"),
+                        member.user_id.as_str(),
+                    ]);
+                }
+                writeln!(writer)?;
+                super::common::write_table(writer, &table, &self.format)?;
+                if super::common::show_footer(self.no_header, options) {
+                    writeln!(writer, "\nTotal: {} members", members.len())?;
+                }
+                Ok(())
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                let delimiter = csv_delimiter(&self.format);
+                if !self.no_header {
+                    write_record(writer, &["username", "email", "user_id"], delimiter)?;
+                }
+                members.iter().try_for_each(|member| {
+                    write_record(
+                        writer,
+                        &[
+                            member.username.to_string(),
+                            member.email.clone().unwrap_or_default(),
+                            member.user_id.to_string(),
+                        ],
+                        delimiter,
+                    )
+                })
+            }
+            OutputFormat::Json => super::common::write_json(writer, members, options),
+            OutputFormat::Yaml => super::common::write_yaml(writer, members),
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                super::common::write_lines(writer, members, &self.format, options)
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -115,6 +298,106 @@ mod tests {
         assert_eq!(serializable.visibility, "organization");
     }
 
+    fn render(teams: &[Team], format: OutputFormat, no_header: bool) -> String {
+        let mut buf = Vec::new();
+        TeamFormatter {
+            format,
+            no_header,
+            members: None,
+        }
+        .format_to(teams, &mut buf, &RenderOptions::default())
+        .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn member(user_id: &str, username: &str, email: Option<&str>) -> TeamMember {
+        TeamMember {
+            user_id: user_id.to_string(),
+            username: username.to_string(),
+            email: email.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_output_with_members_column() {
+        let teams = [
+            create_test_team("team-1", "devs", 2, "secret"),
+            create_test_team("team-2", "ops", 0, "secret"),
+        ];
+        let members = TeamMembers::from([(
+            "team-1".to_string(),
+            vec![
+                member("user-1", "alice", None),
+                member("user-2", "bob", None),
+            ],
+        )]);
+        let render_with = |format| {
+            let mut buf = Vec::new();
+            TeamFormatter {
+                format,
+                no_header: false,
+                members: Some(&members),
+            }
+            .format_to(&teams, &mut buf, &RenderOptions::default())
+            .unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+
+        assert_eq!(
+            render_with(OutputFormat::Csv),
+            "ID,NAME,USERS,VISIBILITY,MEMBERS\nteam-1,devs,2,secret,alice;bob\nteam-2,ops,0,secret,\n"
+        );
+        assert!(render_with(OutputFormat::Table).contains("alice, bob"));
+        assert!(render_with(OutputFormat::Json).contains("\"members\": []"));
+        assert!(!render(&teams, OutputFormat::Json, false).contains("members"));
+    }
+
+    #[test]
+    fn test_output_team_members_csv_and_table() {
+        let members = [
+            member("user-1", "alice", Some("alice@example.com")),
+            member("user-2", "bob", None),
+        ];
+        let render_members = |format, no_header| {
+            let mut buf = Vec::new();
+            TeamMemberFormatter { format, no_header }
+                .format_to(&members, &mut buf, &RenderOptions::default())
+                .unwrap();
+            String::from_utf8(buf).unwrap()
+        };
+
+        assert_eq!(
+            render_members(OutputFormat::Csv, false),
+            "username,email,user_id\nalice,alice@example.com,user-1\nbob,,user-2\n"
+        );
+        let table = render_members(OutputFormat::Table, false);
+        assert!(table.contains("USERNAME"));
+        assert!(table.contains("alice@example.com"));
+        assert!(table.ends_with("\nTotal: 2 members\n"));
+        assert!(!render_members(OutputFormat::Table, true).contains("Total"));
+    }
+
+    #[test]
+    fn test_output_table() {
+        let teams = [create_test_team("team-1", "devs", 3, "secret")];
+        let out = render(&teams, OutputFormat::Table, false);
+
+        assert!(out.contains("VISIBILITY"));
+        assert!(out.contains("team-1"));
+        assert!(out.contains("devs"));
+        assert!(!render(&teams, OutputFormat::Table, true).contains("VISIBILITY"));
+    }
+
+    #[test]
+    fn test_output_csv() {
+        let teams = [create_test_team("team-1", "devs, ops", 3, "secret")];
+
+        assert_eq!(
+            render(&teams, OutputFormat::Csv, false),
+            "ID,NAME,USERS,VISIBILITY\nteam-1,\"devs, ops\",3,secret\n"
+        );
+    }
+
     #[test]
     fn test_output_json_format() {
         let teams = [create_test_team("team-1", "devs", 3, "secret")];
@@ -0,0 +1,369 @@
+//! Minimal Go-template renderer backing `-o template --template '...'`
+//!
+//! Supported subset (modelled on kubectl's `-o go-template`):
+//! - `{{.field}}`, `{{.nested.field}}`, `{{.}}` (current value)
+//! - `{{"literal"}}` with `\n`, `\t`, `\"` and `\\` escapes
+//! - `{{range .list}}...{{else}}...{{end}}` (`.` is the element inside)
+//! - `{{if .field}}...{{else}}...{{end}}` (false, 0, null, "" and empty
+//!   lists/objects are falsy)
+//! - `{{-` / `-}}` trim surrounding whitespace
+//!
+//! Missing fields and nulls render as an empty string; lists and objects as
+//! compact JSON.
+
+use serde_json::Value;
+
+/// Parsed template
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    nodes: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    Field(Vec<String>),
+    Literal(String),
+    Range {
+        path: Vec<String>,
+        body: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+    If {
+        path: Vec<String>,
+        then: Vec<Node>,
+        otherwise: Vec<Node>,
+    },
+}
+
+/// A lexed `{{ ... }}` action or the text between actions
+#[derive(Debug)]
+enum Token {
+    Text(String),
+    Action(String),
+}
+
+impl Template {
+    /// Parse template source such as `{{.name}}\t{{.id}}`
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let tokens = lex(source).map_err(|e| format!("Invalid template: {}", e))?;
+        let mut tokens = tokens.into_iter();
+        let (nodes, terminator) =
+            parse_nodes(&mut tokens).map_err(|e| format!("Invalid template: {}", e))?;
+        match terminator {
+            None => Ok(Self { nodes }),
+            Some(word) => Err(format!("Invalid template: unexpected {{{{{}}}}}", word)),
+        }
+    }
+
+    /// Render the template with `value` as `.`
+    pub fn render(&self, value: &Value) -> String {
+        let mut out = String::new();
+        render_nodes(&self.nodes, value, &mut out);
+        out
+    }
+}
+
+fn lex(source: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("{{") {
+        let mut text = rest[..start].to_string();
+        let after_open = &rest[start + 2..];
+        let end = after_open
+            .find("}}")
+            .ok_or_else(|| "unclosed action, missing '}}'".to_string())?;
+        let mut action = &after_open[..end];
+        if let Some(trimmed) = action.strip_prefix('-') {
+            text.truncate(text.trim_end().len());
+            action = trimmed;
+        }
+        let trim_after = action.ends_with('-');
+        if trim_after {
+            action = &action[..action.len() - 1];
+        }
+        if !text.is_empty() {
+            tokens.push(Token::Text(text));
+        }
+        tokens.push(Token::Action(action.trim().to_string()));
+        rest = &after_open[end + 2..];
+        if trim_after {
+            rest = rest.trim_start();
+        }
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest.to_string()));
+    }
+    Ok(tokens)
+}
+
+/// Parse nodes until `else`/`end` (returned as the terminator) or end of input
+fn parse_nodes(
+    tokens: &mut std::vec::IntoIter<Token>,
+) -> Result<(Vec<Node>, Option<&'static str>), String> {
+    let mut nodes = Vec::new();
+    while let Some(token) = tokens.next() {
+        let action = match token {
+            Token::Text(text) => {
+                nodes.push(Node::Text(text));
+                continue;
+            }
+            Token::Action(action) => action,
+        };
+        if action == "end" {
+            return Ok((nodes, Some("end")));
+        }
+        if action == "else" {
+            return Ok((nodes, Some("else")));
+        }
+        if let Some(arg) = keyword_arg(&action, "range") {
+            let path = parse_path(arg)?;
+            let (body, otherwise) = parse_block(tokens, "range")?;
+            nodes.push(Node::Range {
+                path,
+                body,
+                otherwise,
+            });
+        } else if let Some(arg) = keyword_arg(&action, "if") {
+            let path = parse_path(arg)?;
+            let (then, otherwise) = parse_block(tokens, "if")?;
+            nodes.push(Node::If {
+                path,
+                then,
+                otherwise,
+            });
+        } else if action.starts_with('"') {
+            nodes.push(Node::Literal(parse_literal(&action)?));
+        } else {
+            nodes.push(Node::Field(parse_path(&action)?));
+        }
+    }
+    Ok((nodes, None))
+}
+
+/// Parse a `range`/`if` body with an optional `else` branch up to `end`
+fn parse_block(
+    tokens: &mut std::vec::IntoIter<Token>,
+    keyword: &str,
+) -> Result<(Vec<Node>, Vec<Node>), String> {
+    let (body, terminator) = parse_nodes(tokens)?;
+    match terminator {
+        Some("end") => Ok((body, Vec::new())),
+        Some("else") => match parse_nodes(tokens)? {
+            (otherwise, Some("end")) => Ok((body, otherwise)),
+            _ => Err(format!("missing {{{{end}}}} for {{{{{}}}}}", keyword)),
+        },
+        _ => Err(format!("missing {{{{end}}}} for {{{{{}}}}}", keyword)),
+    }
+}
+
+fn keyword_arg<'a>(action: &'a str, keyword: &str) -> Option<&'a str> {
+    action
+        .strip_prefix(keyword)
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .map(str::trim)
+}
+
+/// Parse `.`, `.a` or `.a.b` into its field names
+fn parse_path(expr: &str) -> Result<Vec<String>, String> {
+    if expr == "." {
+        return Ok(Vec::new());
+    }
+    let Some(rest) = expr.strip_prefix('.') else {
+        return Err(format!(
+            "unsupported action '{{{{{}}}}}' (expected .field, \"text\", range, if, else or end)",
+            expr
+        ));
+    };
+    rest.split('.')
+        .map(|name| {
+            if name.is_empty() || name.chars().any(char::is_whitespace) {
+                Err(format!("invalid field reference '{}'", expr))
+            } else {
+                Ok(name.to_string())
+            }
+        })
+        .collect()
+}
+
+fn parse_literal(action: &str) -> Result<String, String> {
+    let inner = action
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .ok_or_else(|| format!("unterminated string {}", action))?;
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            other => {
+                return Err(format!(
+                    "unsupported escape '\\{}' in {}",
+                    other.map(String::from).unwrap_or_default(),
+                    action
+                ))
+            }
+        }
+    }
+    Ok(out)
+}
+
+fn lookup<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+    path.iter()
+        .try_fold(value, |current, name| current.get(name))
+}
+
+fn is_truthy(value: Option<&Value>) -> bool {
+    match value {
+        None | Some(Value::Null) => false,
+        Some(Value::Bool(b)) => *b,
+        Some(Value::Number(n)) => n.as_f64().is_some_and(|f| f != 0.0),
+        Some(Value::String(s)) => !s.is_empty(),
+        Some(Value::Array(items)) => !items.is_empty(),
+        Some(Value::Object(map)) => !map.is_empty(),
+    }
+}
+
+fn render_nodes(nodes: &[Node], value: &Value, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) | Node::Literal(text) => out.push_str(text),
+            Node::Field(path) => match lookup(value, path) {
+                None | Some(Value::Null) => {}
+                Some(Value::String(s)) => out.push_str(s),
+                Some(other) => out.push_str(&other.to_string()),
+            },
+            Node::Range {
+                path,
+                body,
+                otherwise,
+            } => {
+                let items: Vec<&Value> = match lookup(value, path) {
+                    Some(Value::Array(items)) => items.iter().collect(),
+                    // Go ranges over map values in key order
+                    Some(Value::Object(map)) => {
+                        let mut entries: Vec<_> = map.iter().collect();
+                        entries.sort_by(|a, b| a.0.cmp(b.0));
+                        entries.into_iter().map(|(_, v)| v).collect()
+                    }
+                    _ => Vec::new(),
+                };
+                if items.is_empty() {
+                    render_nodes(otherwise, value, out);
+                }
+                for item in items {
+                    render_nodes(body, item, out);
+                }
+            }
+            Node::If {
+                path,
+                then,
+                otherwise,
+            } => {
+                if is_truthy(lookup(value, path)) {
+                    render_nodes(then, value, out);
+                } else {
+                    render_nodes(otherwise, value, out);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render(source: &str, value: Value) -> String {
+        Template::parse(source).unwrap().render(&value)
+    }
+
+    #[test]
+    fn test_fields_and_text() {
+        let ws = json!({"name": "app", "resources": 12, "locked": false});
+        assert_eq!(
+            render("{{.name}}: {{.resources}} {{.locked}}", ws),
+            "app: 12 false"
+        );
+    }
+
+    #[test]
+    fn test_nested_field_and_dot() {
+        assert_eq!(render("{{.a.b}}", json!({"a": {"b": "x"}})), "x");
+        assert_eq!(render("[{{.}}]", json!("v")), "[v]");
+    }
+
+    #[test]
+    fn test_missing_and_null_render_empty() {
+        assert_eq!(render("<{{.nope}}><{{.n}}>", json!({"n": null})), "<><>");
+    }
+
+    #[test]
+    fn test_literals_with_escapes() {
+        assert_eq!(
+            render(r#"{{.a}}{{"\t"}}{{.b}}{{"\n"}}"#, json!({"a": 1, "b": 2})),
+            "1\t2\n"
+        );
+    }
+
+    #[test]
+    fn test_range_over_list_with_else() {
+        let run = json!({"id": "run-1", "teams": ["t1", "t2"], "none": []});
+        assert_eq!(
+            render("{{.id}}:{{range .teams}} {{.}}{{end}}", run.clone()),
+            "run-1: t1 t2"
+        );
+        assert_eq!(render("{{range .none}}x{{else}}empty{{end}}", run), "empty");
+    }
+
+    #[test]
+    fn test_range_over_objects_in_list() {
+        let runs = json!({"items": [{"id": "run-1", "status": "planning"}, {"id": "run-2", "status": "applying"}]});
+        assert_eq!(
+            render(
+                "{{range .items}}{{.id}}={{.status}}{{\"\\n\"}}{{end}}",
+                runs
+            ),
+            "run-1=planning\nrun-2=applying\n"
+        );
+    }
+
+    #[test]
+    fn test_if_else() {
+        let tpl = "{{if .locked}}LOCKED{{else}}open{{end}}";
+        assert_eq!(render(tpl, json!({"locked": true})), "LOCKED");
+        assert_eq!(render(tpl, json!({"locked": false})), "open");
+        assert_eq!(render(tpl, json!({})), "open");
+        assert_eq!(render("{{if .n}}y{{end}}", json!({"n": 0})), "");
+    }
+
+    #[test]
+    fn test_trim_markers() {
+        let tpl = "{{range .l -}}\n  {{.}}\n{{- end}}";
+        assert_eq!(render(tpl, json!({"l": [1, 2]})), "12");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in [
+            "{{.name",
+            "{{range .l}}x",
+            "{{end}}",
+            "{{if .a}}x{{else}}y",
+            "{{name}}",
+            "{{.a..b}}",
+            "{{\"open}}",
+        ] {
+            let err = Template::parse(bad).unwrap_err();
+            assert!(err.starts_with("Invalid template"), "{}: {}", bad, err);
+        }
+    }
+}
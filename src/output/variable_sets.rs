@@ -0,0 +1,264 @@
+//! Variable set output formatter
+
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, header_record, write_record, Formatter, RenderOptions};
+use crate::cli::{Cli, Command, GetResource, OutputFormat};
+use crate::hcp::VariableSet;
+use serde::Serialize;
+
+/// Serializable variable set for structured output (JSON/YAML)
+///
+/// Attached workspace/project IDs are only included for a single variable set,
+/// since list responses don't carry the relationships reliably.
+#[derive(Serialize)]
+struct SerializableVariableSet {
+    id: String,
+    name: String,
+    description: String,
+    global: bool,
+    priority: bool,
+    workspace_count: u32,
+    project_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    var_count: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    workspaces: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    projects: Option<Vec<String>>,
+}
+
+impl SerializableVariableSet {
+    fn new(varset: &VariableSet, detail: bool) -> Self {
+        let ids = |ids: Vec<&str>| detail.then(|| ids.into_iter().map(String::from).collect());
+        Self {
+            id: varset.id.clone(),
+            name: varset.name().to_string(),
+            description: varset.attributes.description.clone().unwrap_or_default(),
+            global: varset.attributes.global,
+            priority: varset.attributes.priority,
+            workspace_count: varset.workspace_count(),
+            project_count: varset.project_count(),
+            var_count: varset.attributes.var_count,
+            workspaces: ids(varset.workspace_ids()),
+            projects: ids(varset.project_ids()),
+        }
+    }
+}
+
+/// Output variable sets in the specified format
+///
+/// A single named variable set also lists its attached workspaces and projects.
+pub fn output_variable_sets(varsets: &[VariableSet], cli: &Cli) {
+    let Command::Get {
+        resource: GetResource::Varset(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if cli.id_only {
+        super::common::print_ids(varsets);
+        return;
+    }
+
+    VariableSetFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+        detail: args.name.is_some(),
+    }
+    .print(varsets);
+}
+
+/// Variable set formatter; `detail` adds the attached workspace and project IDs
+pub struct VariableSetFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub detail: bool,
+}
+
+impl Formatter for VariableSetFormatter {
+    type Row = VariableSet;
+
+    fn format_to<W: Write>(
+        &self,
+        varsets: &[VariableSet],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        let detail = self.detail;
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => output_table(
+                writer,
+                varsets,
+                self.no_header,
+                detail,
+                &self.format,
+                options,
+            ),
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(varsets, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            OutputFormat::Json => {
+                super::common::write_json(writer, &serializable(varsets, detail), options)
+            }
+            OutputFormat::Yaml => super::common::write_yaml(writer, &serializable(varsets, detail)),
+            OutputFormat::Ndjson | OutputFormat::Template => super::common::write_lines(
+                writer,
+                varsets
+                    .iter()
+                    .map(|vs| SerializableVariableSet::new(vs, detail)),
+                &self.format,
+                options,
+            ),
+        }
+    }
+}
+
+fn serializable(varsets: &[VariableSet], detail: bool) -> Vec<SerializableVariableSet> {
+    varsets
+        .iter()
+        .map(|vs| SerializableVariableSet::new(vs, detail))
+        .collect()
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    varsets: &[VariableSet],
+    no_header: bool,
+    detail: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
+    if !no_header {
+        let mut header = vec!["ID", "Name", "Global", "Workspaces", "Projects"];
+        if detail {
+            header.extend_from_slice(&["Workspace IDs", "Project IDs"]);
+        }
+        table.set_header(header);
+    }
+
+    for varset in varsets {
+        let mut row = vec![
+            varset.id.clone(),
+            super::common::table_name(varset.name(), options),
+            if varset.attributes.global {
+                "Yes"
+            } else {
+                "No"
+            }
+            .to_string(),
+            varset.workspace_count().to_string(),
+            varset.project_count().to_string(),
+        ];
+        if detail {
+            row.push(varset.workspace_ids().join(", "));
+            row.push(varset.project_ids().join(", "));
+        }
+        table.add_row(row);
+    }
+
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) && !detail {
+        writeln!(writer, "\nTotal: {} variable sets", varsets.len())?;
+    }
+    Ok(())
+}
+
+fn csv_records(varsets: &[VariableSet], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(varsets.len() + 1);
+    if !no_header {
+        records.push(header_record(&[
+            "id",
+            "name",
+            "global",
+            "workspace_count",
+            "project_count",
+        ]));
+    }
+    for varset in varsets {
+        records.push(vec![
+            varset.id.clone(),
+            varset.name().to_string(),
+            varset.attributes.global.to_string(),
+            varset.workspace_count().to_string(),
+            varset.project_count().to_string(),
+        ]);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn varset() -> VariableSet {
+        serde_json::from_value(serde_json::json!({
+            "id": "varset-abc",
+            "attributes": {
+                "name": "aws, shared",
+                "global": true,
+                "workspace-count": 2,
+                "project-count": 0,
+                "var-count": 4
+            },
+            "relationships": {
+                "workspaces": {"data": [{"id": "ws-1"}, {"id": "ws-2"}]},
+                "projects": {"data": []}
+            }
+        }))
+        .unwrap()
+    }
+
+    fn render(format: OutputFormat, detail: bool) -> String {
+        let formatter = VariableSetFormatter {
+            format,
+            no_header: false,
+            detail,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(&[varset()], &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_csv_output() {
+        assert_eq!(
+            render(OutputFormat::Csv, false),
+            "id,name,global,workspace_count,project_count\n\
+             varset-abc,\"aws, shared\",true,2,0\n"
+        );
+    }
+
+    #[test]
+    fn test_table_output() {
+        let out = render(OutputFormat::Table, false);
+        assert!(out.contains("varset-abc"));
+        assert!(out.contains("aws, shared"));
+        assert!(out.ends_with("\nTotal: 1 variable sets\n"));
+
+        let detail = render(OutputFormat::Table, true);
+        assert!(detail.contains("ws-1, ws-2"));
+        assert!(!detail.contains("Total:"));
+    }
+
+    #[test]
+    fn test_serializable_detail_includes_attachments() {
+        let json = serde_json::to_value(SerializableVariableSet::new(&varset(), true)).unwrap();
+        assert_eq!(json["workspaces"], serde_json::json!(["ws-1", "ws-2"]));
+        assert_eq!(json["projects"], serde_json::json!([]));
+        assert_eq!(json["var_count"], 4);
+    }
+
+    #[test]
+    fn test_serializable_list_omits_attachments() {
+        let json = serde_json::to_value(SerializableVariableSet::new(&varset(), false)).unwrap();
+        assert!(json.get("workspaces").is_none());
+        assert!(json.get("projects").is_none());
+        assert_eq!(json["global"], true);
+    }
+}
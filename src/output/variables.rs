@@ -0,0 +1,253 @@
+//! Workspace variable output formatter
+
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, header_record, write_record, Formatter, RenderOptions};
+use crate::cli::{Cli, Command, GetResource, OutputFormat};
+use crate::hcp::{TfeResource, Variable};
+use serde::Serialize;
+
+/// Placeholder shown instead of sensitive values in table/CSV output
+const MASKED_VALUE: &str = "***";
+
+/// Flattened workspace variable data for output
+#[derive(Debug, Clone, Serialize)]
+pub struct VariableRow {
+    pub id: String,
+    pub key: String,
+    /// Value; None for sensitive variables (omitted from JSON/YAML)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    pub category: String,
+    pub hcl: bool,
+    pub sensitive: bool,
+    pub description: String,
+}
+
+impl From<&Variable> for VariableRow {
+    fn from(var: &Variable) -> Self {
+        let attrs = &var.attributes;
+        Self {
+            id: var.id.clone(),
+            key: attrs.key.clone(),
+            value: if attrs.sensitive {
+                None
+            } else {
+                Some(attrs.value.clone().unwrap_or_default())
+            },
+            category: attrs.category.clone(),
+            hcl: attrs.hcl,
+            sensitive: attrs.sensitive,
+            description: attrs.description.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl VariableRow {
+    /// Value for table/CSV output, masked when sensitive
+    fn display_value(&self) -> &str {
+        if self.sensitive {
+            MASKED_VALUE
+        } else {
+            self.value.as_deref().unwrap_or("")
+        }
+    }
+}
+
+impl TfeResource for VariableRow {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.key
+    }
+}
+
+/// Output workspace variables in the specified format
+pub fn output_variables(rows: &[VariableRow], cli: &Cli) {
+    let Command::Get {
+        resource: GetResource::Var(args),
+    } = &cli.command
+    else {
+        unreachable!()
+    };
+
+    if cli.id_only {
+        super::common::print_ids(rows);
+        return;
+    }
+
+    VariableFormatter {
+        format: args.output,
+        no_header: cli.no_header,
+    }
+    .print(rows);
+}
+
+/// Workspace variable formatter; sensitive values are masked or omitted
+pub struct VariableFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+}
+
+impl Formatter for VariableFormatter {
+    type Row = VariableRow;
+
+    fn format_to<W: Write>(
+        &self,
+        rows: &[VariableRow],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_table(writer, rows, self.no_header, &self.format, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(rows, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            OutputFormat::Json => super::common::write_json(writer, rows, options),
+            OutputFormat::Yaml => super::common::write_yaml(writer, rows),
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                super::common::write_lines(writer, rows, &self.format, options)
+            }
+        }
+    }
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    rows: &[VariableRow],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
+    if !no_header {
+        table.set_header(vec![
+            "Key",
+            "Value",
+            "Category",
+            "HCL",
+            "Sensitive",
+            "Description",
+        ]);
+    }
+
+    let yes_no = |b: bool| if b { "Yes" } else { "No" };
+    for row in rows {
+        table.add_row(vec![
+            row.key.as_str(),
+            row.display_value(),
+            row.category.as_str(),
+            yes_no(row.hcl),
+            yes_no(row.sensitive),
+            row.description.as_str(),
+        ]);
+    }
+
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) {
+        writeln!(writer, "\nTotal: {} variables", rows.len())?;
+    }
+    Ok(())
+}
+
+fn csv_records(rows: &[VariableRow], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(rows.len() + 1);
+    if !no_header {
+        records.push(header_record(&[
+            "key",
+            "value",
+            "category",
+            "hcl",
+            "sensitive",
+            "description",
+        ]));
+    }
+    for row in rows {
+        records.push(vec![
+            row.key.clone(),
+            row.display_value().to_string(),
+            row.category.to_string(),
+            row.hcl.to_string(),
+            row.sensitive.to_string(),
+            row.description.clone(),
+        ]);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(key: &str, value: Option<&str>, sensitive: bool) -> Variable {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("var-{}", key),
+            "attributes": {
+                "key": key,
+                "value": value,
+                "category": "env",
+                "hcl": false,
+                "sensitive": sensitive,
+                "description": "desc, with comma"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn render(rows: &[VariableRow], format: OutputFormat, no_header: bool) -> String {
+        let mut buf = Vec::new();
+        VariableFormatter { format, no_header }
+            .format_to(rows, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_csv_masks_sensitive_values() {
+        let rows = vec![
+            VariableRow::from(&var("REGION", Some("eu-west-1"), false)),
+            VariableRow::from(&var("SECRET", None, true)),
+        ];
+        assert_eq!(
+            render(&rows, OutputFormat::Csv, false),
+            "key,value,category,hcl,sensitive,description\n\
+             REGION,eu-west-1,env,false,false,\"desc, with comma\"\n\
+             SECRET,***,env,false,true,\"desc, with comma\"\n"
+        );
+        assert_eq!(render(&rows, OutputFormat::Csv, true).lines().count(), 2);
+    }
+
+    #[test]
+    fn test_table_masks_sensitive_values() {
+        let rows = vec![VariableRow::from(&var("SECRET", Some("leaked"), true))];
+        let out = render(&rows, OutputFormat::Table, false);
+        assert!(out.contains("SECRET"));
+        assert!(out.contains("***"));
+        assert!(!out.contains("leaked"));
+        assert!(out.ends_with("\nTotal: 1 variables\n"));
+    }
+
+    #[test]
+    fn test_json_omits_sensitive_values() {
+        let rows = vec![
+            VariableRow::from(&var("REGION", Some("eu-west-1"), false)),
+            VariableRow::from(&var("SECRET", Some("leaked"), true)),
+        ];
+        let json = serde_json::to_value(&rows).unwrap();
+        assert_eq!(json[0]["value"], "eu-west-1");
+        assert!(json[1].get("value").is_none());
+        assert_eq!(json[1]["sensitive"], true);
+    }
+
+    #[test]
+    fn test_empty_value_kept_for_non_sensitive() {
+        let row = VariableRow::from(&var("EMPTY", None, false));
+        assert_eq!(row.value.as_deref(), Some(""));
+        assert_eq!(row.display_value(), "");
+    }
+}
@@ -0,0 +1,374 @@
+//! Workspace snapshot diff (`get ws --diff <baseline.json>`)
+//!
+//! Compares the current workspace listing against a previous `get ws -o json`
+//! export, matching workspaces by ID.
+
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use super::common::{csv_delimiter, header_record, write_record, Formatter, RenderOptions};
+use super::workspaces::WorkspaceRow;
+use crate::cli::OutputFormat;
+
+/// Kind of difference between the baseline and the current listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+impl std::fmt::Display for WorkspaceChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Added => write!(f, "added"),
+            Self::Removed => write!(f, "removed"),
+            Self::Changed => write!(f, "changed"),
+        }
+    }
+}
+
+/// One changed field of a workspace present in both listings
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// A workspace that was added, removed or changed since the baseline
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkspaceDiff {
+    pub change: WorkspaceChangeKind,
+    pub org: String,
+    pub workspace_name: String,
+    pub workspace_id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldChange>,
+}
+
+impl WorkspaceDiff {
+    fn new(change: WorkspaceChangeKind, row: &WorkspaceRow, fields: Vec<FieldChange>) -> Self {
+        Self {
+            change,
+            org: row.org.clone(),
+            workspace_name: row.name.clone(),
+            workspace_id: row.id.clone(),
+            fields,
+        }
+    }
+}
+
+/// Compared fields, named like the JSON export keys
+fn field_changes(before: &WorkspaceRow, after: &WorkspaceRow) -> Vec<FieldChange> {
+    let pairs = [
+        (
+            "resources",
+            before.resources.to_string(),
+            after.resources.to_string(),
+        ),
+        (
+            "terraform_version",
+            before.terraform_version.clone(),
+            after.terraform_version.clone(),
+        ),
+        (
+            "locked",
+            before.locked.to_string(),
+            after.locked.to_string(),
+        ),
+        (
+            "updated_at",
+            before.updated_at.clone(),
+            after.updated_at.clone(),
+        ),
+    ];
+    pairs
+        .into_iter()
+        .filter(|(_, b, a)| b != a)
+        .map(|(field, before, after)| FieldChange {
+            field,
+            before,
+            after,
+        })
+        .collect()
+}
+
+/// Diff two workspace listings by workspace ID, sorted by org, name and change kind
+pub fn diff_workspaces(baseline: &[WorkspaceRow], current: &[WorkspaceRow]) -> Vec<WorkspaceDiff> {
+    let before: HashMap<&str, &WorkspaceRow> =
+        baseline.iter().map(|r| (r.id.as_str(), r)).collect();
+    let after: HashMap<&str, &WorkspaceRow> = current.iter().map(|r| (r.id.as_str(), r)).collect();
+
+    let mut diffs: Vec<WorkspaceDiff> = current
+        .iter()
+        .filter_map(|row| match before.get(row.id.as_str()) {
+            None => Some(WorkspaceDiff::new(WorkspaceChangeKind::Added, row, vec![])),
+            Some(old) => {
+                let fields = field_changes(old, row);
+                (!fields.is_empty())
+                    .then(|| WorkspaceDiff::new(WorkspaceChangeKind::Changed, row, fields))
+            }
+        })
+        .collect();
+    diffs.extend(
+        baseline
+            .iter()
+            .filter(|row| !after.contains_key(row.id.as_str()))
+            .map(|row| WorkspaceDiff::new(WorkspaceChangeKind::Removed, row, vec![])),
+    );
+
+    diffs.sort_by(|a, b| {
+        a.org
+            .cmp(&b.org)
+            .then_with(|| a.workspace_name.cmp(&b.workspace_name))
+            .then_with(|| a.change.cmp(&b.change))
+    });
+    diffs
+}
+
+/// Output a workspace diff in the specified format
+pub fn output_workspace_diff(diffs: &[WorkspaceDiff], format: &OutputFormat, no_header: bool) {
+    WorkspaceDiffFormatter {
+        format: *format,
+        no_header,
+    }
+    .print(diffs);
+}
+
+/// Workspace diff formatter; table/CSV emit one row per changed field
+pub struct WorkspaceDiffFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+}
+
+impl Formatter for WorkspaceDiffFormatter {
+    type Row = WorkspaceDiff;
+
+    fn format_to<W: Write>(
+        &self,
+        diffs: &[WorkspaceDiff],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                output_table(writer, diffs, self.no_header, &self.format, options)
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(diffs, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            OutputFormat::Json => super::common::write_json(writer, diffs, options),
+            OutputFormat::Yaml => super::common::write_yaml(writer, diffs),
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                super::common::write_lines(writer, diffs, &self.format, options)
+            }
+        }
+    }
+}
+
+/// One output row per changed field (added/removed workspaces get a single row)
+fn flat_rows(diff: &WorkspaceDiff) -> Vec<[String; 3]> {
+    if diff.fields.is_empty() {
+        return vec![[String::new(), String::new(), String::new()]];
+    }
+    diff.fields
+        .iter()
+        .map(|f| [f.field.to_string(), f.before.clone(), f.after.clone()])
+        .collect()
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    diffs: &[WorkspaceDiff],
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
+    if !no_header {
+        table.set_header(vec![
+            "Change",
+            "Org",
+            "Workspace Name",
+            "Workspace ID",
+            "Field",
+            "Before",
+            "After",
+        ]);
+    }
+
+    for diff in diffs {
+        for [field, before, after] in flat_rows(diff) {
+            let or_dash = |v: String| if v.is_empty() { "-".to_string() } else { v };
+            table.add_row(vec![
+                diff.change.to_string(),
+                diff.org.clone(),
+                super::common::table_name(&diff.workspace_name, options),
+                diff.workspace_id.clone(),
+                or_dash(field),
+                or_dash(before),
+                or_dash(after),
+            ]);
+        }
+    }
+
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if !no_header {
+        let count = |kind| diffs.iter().filter(|d| d.change == kind).count();
+        writeln!(
+            writer,
+            "\nAdded: {}, Removed: {}, Changed: {}",
+            count(WorkspaceChangeKind::Added),
+            count(WorkspaceChangeKind::Removed),
+            count(WorkspaceChangeKind::Changed)
+        )?;
+    }
+    Ok(())
+}
+
+fn csv_records(diffs: &[WorkspaceDiff], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    if !no_header {
+        records.push(header_record(&[
+            "change",
+            "org",
+            "workspace_name",
+            "workspace_id",
+            "field",
+            "before",
+            "after",
+        ]));
+    }
+    for diff in diffs {
+        for [field, before, after] in flat_rows(diff) {
+            records.push(vec![
+                diff.change.to_string(),
+                diff.org.clone(),
+                diff.workspace_name.clone(),
+                diff.workspace_id.clone(),
+                field.to_string(),
+                before,
+                after,
+            ]);
+        }
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(id: &str, name: &str, resources: u32) -> WorkspaceRow {
+        WorkspaceRow {
+            org: "org".to_string(),
+            vcs_repo: String::new(),
+            working_directory: String::new(),
+            project_id: "prj-1".to_string(),
+            project_name: None,
+            name: name.to_string(),
+            id: id.to_string(),
+            resources,
+            billable: None,
+            execution_mode: "remote".to_string(),
+            locked: false,
+            terraform_version: "1.5.0".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            pending_runs: None,
+            settings: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_added_removed_changed() {
+        let baseline = vec![row("ws-1", "alpha", 10), row("ws-2", "beta", 5)];
+        let mut changed = row("ws-1", "alpha", 12);
+        changed.locked = true;
+        let current = vec![changed, row("ws-3", "gamma", 0)];
+
+        let diffs = diff_workspaces(&baseline, &current);
+        let summary: Vec<_> = diffs
+            .iter()
+            .map(|d| (d.change, d.workspace_id.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (WorkspaceChangeKind::Changed, "ws-1"),
+                (WorkspaceChangeKind::Removed, "ws-2"),
+                (WorkspaceChangeKind::Added, "ws-3"),
+            ]
+        );
+        assert_eq!(
+            diffs[0].fields,
+            vec![
+                FieldChange {
+                    field: "resources",
+                    before: "10".to_string(),
+                    after: "12".to_string(),
+                },
+                FieldChange {
+                    field: "locked",
+                    before: "false".to_string(),
+                    after: "true".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_matches_by_id_not_name() {
+        let baseline = vec![row("ws-1", "old-name", 1)];
+        let current = vec![row("ws-1", "new-name", 1)];
+        assert!(diff_workspaces(&baseline, &current).is_empty());
+    }
+
+    fn render(diffs: &[WorkspaceDiff], format: OutputFormat) -> String {
+        let formatter = WorkspaceDiffFormatter {
+            format,
+            no_header: false,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(diffs, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn test_diff_csv_output() {
+        let mut changed = row("ws-1", "alpha", 1);
+        changed.terraform_version = "1.6.0".to_string();
+        let diffs = diff_workspaces(&[row("ws-1", "alpha", 1)], &[changed, row("ws-2", "b", 0)]);
+
+        assert_eq!(
+            render(&diffs, OutputFormat::Csv),
+            "change,org,workspace_name,workspace_id,field,before,after\n\
+             changed,org,alpha,ws-1,terraform_version,1.5.0,1.6.0\n\
+             added,org,b,ws-2,,,\n"
+        );
+    }
+
+    #[test]
+    fn test_diff_table_output() {
+        let diffs = diff_workspaces(&[row("ws-1", "alpha", 1)], &[row("ws-2", "b", 0)]);
+        let out = render(&diffs, OutputFormat::Table);
+
+        assert!(out.contains("removed"));
+        assert!(out.contains("ws-2"));
+        assert!(out.ends_with("\nAdded: 1, Removed: 1, Changed: 0\n"));
+    }
+
+    #[test]
+    fn test_diff_json_omits_empty_fields() {
+        let diffs = diff_workspaces(&[], &[row("ws-1", "alpha", 1)]);
+        let json = serde_json::to_value(&diffs).unwrap();
+        assert_eq!(json[0]["change"], "added");
+        assert!(json[0].get("fields").is_none());
+    }
+}
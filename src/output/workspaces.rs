@@ -1,16 +1,19 @@
 //! Workspace output formatter
 
-use super::common::escape_csv;
-use crate::cli::OutputFormat;
-use crate::hcp::{TfeResource, Workspace};
-use comfy_table::{presets::NOTHING, Table};
-use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use super::common::{csv_delimiter, header_record, write_record, Formatter, RenderOptions};
+use crate::cli::{OutputFormat, WsCountField};
+use crate::hcp::{TfeResource, Workspace, WorkspaceSettings};
+use serde::{Deserialize, Serialize};
 
 /// Flattened workspace data for output
 #[derive(Debug, Clone)]
 pub struct WorkspaceRow {
     pub org: String,
     pub project_id: String,
+    pub project_name: Option<String>,
     pub name: String,
     pub id: String,
     pub resources: u32,
@@ -19,7 +22,10 @@ pub struct WorkspaceRow {
     pub locked: bool,
     pub terraform_version: String,
     pub updated_at: String,
+    pub vcs_repo: String,
+    pub working_directory: String,
     pub pending_runs: Option<usize>,
+    pub settings: Option<WorkspaceSettings>,
 }
 
 impl WorkspaceRow {
@@ -28,6 +34,7 @@ impl WorkspaceRow {
         Self {
             org: org.to_string(),
             project_id: workspace.project_id().unwrap_or("").to_string(),
+            project_name: None,
             name: workspace.name().to_string(),
             id: workspace.id.clone(),
             resources: workspace.resource_count(),
@@ -36,16 +43,37 @@ impl WorkspaceRow {
             locked: workspace.is_locked(),
             terraform_version: workspace.terraform_version().to_string(),
             updated_at: workspace.updated_at().to_string(),
+            vcs_repo: workspace.vcs_repo_identifier().to_string(),
+            working_directory: workspace.working_directory().to_string(),
             pending_runs: None,
+            settings: None,
         }
     }
+
+    /// Parse rows from a saved `get ws -o json` export (used as a `--diff` baseline)
+    pub fn from_snapshot(json: &str) -> serde_json::Result<Vec<Self>> {
+        let data: Vec<SerializableWorkspace> = serde_json::from_str(json)?;
+        Ok(data.into_iter().map(Self::from).collect())
+    }
+}
+
+impl TfeResource for WorkspaceRow {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
 }
 
 /// Serializable workspace for structured output (JSON/YAML)
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct SerializableWorkspace {
     org: String,
     project_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project_name: Option<String>,
     workspace_name: String,
     workspace_id: String,
     resources: u32,
@@ -55,8 +83,15 @@ struct SerializableWorkspace {
     locked: bool,
     terraform_version: String,
     updated_at: String,
+    // Empty when not VCS-backed; defaulted so older --diff baselines still load
+    #[serde(default)]
+    vcs_repo: String,
+    #[serde(default)]
+    working_directory: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pending_runs: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    settings: Option<WorkspaceSettings>,
 }
 
 impl From<&WorkspaceRow> for SerializableWorkspace {
@@ -64,6 +99,7 @@ impl From<&WorkspaceRow> for SerializableWorkspace {
         Self {
             org: row.org.clone(),
             project_id: row.project_id.clone(),
+            project_name: row.project_name.clone(),
             workspace_name: row.name.clone(),
             workspace_id: row.id.clone(),
             resources: row.resources,
@@ -72,38 +108,377 @@ impl From<&WorkspaceRow> for SerializableWorkspace {
             locked: row.locked,
             terraform_version: row.terraform_version.clone(),
             updated_at: row.updated_at.clone(),
+            vcs_repo: row.vcs_repo.clone(),
+            working_directory: row.working_directory.clone(),
             pending_runs: row.pending_runs,
+            settings: row.settings.clone(),
+        }
+    }
+}
+
+impl From<SerializableWorkspace> for WorkspaceRow {
+    fn from(ws: SerializableWorkspace) -> Self {
+        Self {
+            org: ws.org,
+            project_id: ws.project_id,
+            project_name: ws.project_name,
+            name: ws.workspace_name,
+            id: ws.workspace_id,
+            resources: ws.resources,
+            billable: ws.billable,
+            execution_mode: ws.execution_mode,
+            locked: ws.locked,
+            terraform_version: ws.terraform_version,
+            updated_at: ws.updated_at,
+            vcs_repo: ws.vcs_repo,
+            working_directory: ws.working_directory,
+            pending_runs: ws.pending_runs,
+            settings: ws.settings,
         }
     }
 }
 
 /// Output workspaces in the specified format
-pub fn output_workspaces(rows: &[WorkspaceRow], format: &OutputFormat, no_header: bool) {
-    match format {
-        OutputFormat::Table => output_table(rows, no_header),
-        OutputFormat::Csv => output_csv(rows, no_header),
-        OutputFormat::Json => output_json(rows),
-        OutputFormat::Yaml => output_yaml(rows),
+///
+/// With `flatten`, JSON/NDJSON output emits one flat object per workspace (dotted keys).
+/// With `human`, table output shows counts with thousands separators.
+pub fn output_workspaces(
+    rows: &[WorkspaceRow],
+    format: &OutputFormat,
+    no_header: bool,
+    flatten: bool,
+    human: bool,
+) {
+    WorkspaceFormatter {
+        format: *format,
+        no_header,
+        flatten,
+        human,
+    }
+    .print(rows);
+}
+
+/// Workspace formatter; `flatten`/`human` as in [`output_workspaces`]
+pub struct WorkspaceFormatter {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub flatten: bool,
+    pub human: bool,
+}
+
+impl Formatter for WorkspaceFormatter {
+    type Row = WorkspaceRow;
+
+    fn format_to<W: Write>(
+        &self,
+        rows: &[WorkspaceRow],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => output_table(
+                writer,
+                rows,
+                self.no_header,
+                self.human,
+                &self.format,
+                options,
+            ),
+            OutputFormat::Csv | OutputFormat::Tsv => csv_records(rows, self.no_header)
+                .iter()
+                .try_for_each(|record| write_record(writer, record, csv_delimiter(&self.format))),
+            OutputFormat::Json => output_json(writer, rows, self.flatten, options),
+            OutputFormat::Yaml => output_yaml(writer, rows),
+            OutputFormat::Ndjson | OutputFormat::Template => {
+                output_lines(writer, rows, self.flatten, &self.format, options)
+            }
+        }
+    }
+}
+
+/// Workspace column selectable with `get ws --columns`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsColumn {
+    Org,
+    ProjectId,
+    ProjectName,
+    Name,
+    Id,
+    Resources,
+    Billable,
+    ExecutionMode,
+    Locked,
+    TfVersion,
+    UpdatedAt,
+    VcsRepo,
+    WorkingDirectory,
+    PendingRuns,
+}
+
+impl WsColumn {
+    /// All columns with their canonical `--columns` token, in default display order
+    pub const ALL: [(WsColumn, &'static str); 14] = [
+        (WsColumn::Org, "org"),
+        (WsColumn::ProjectId, "project-id"),
+        (WsColumn::ProjectName, "project-name"),
+        (WsColumn::Name, "name"),
+        (WsColumn::Id, "id"),
+        (WsColumn::Resources, "resources"),
+        (WsColumn::Billable, "billable"),
+        (WsColumn::ExecutionMode, "execution-mode"),
+        (WsColumn::Locked, "locked"),
+        (WsColumn::TfVersion, "tf-version"),
+        (WsColumn::UpdatedAt, "updated-at"),
+        (WsColumn::VcsRepo, "vcs-repo"),
+        (WsColumn::WorkingDirectory, "working-directory"),
+        (WsColumn::PendingRuns, "pending-runs"),
+    ];
+
+    /// Table header, matching the default workspace table
+    fn header(&self) -> &'static str {
+        match self {
+            WsColumn::Org => "Org",
+            WsColumn::ProjectId => "Project ID",
+            WsColumn::ProjectName => "Project Name",
+            WsColumn::Name => "Workspace Name",
+            WsColumn::Id => "Workspace ID",
+            WsColumn::Resources => "Resources",
+            WsColumn::Billable => "Billable",
+            WsColumn::ExecutionMode => "Execution Mode",
+            WsColumn::Locked => "Locked",
+            WsColumn::TfVersion => "TF Version",
+            WsColumn::UpdatedAt => "Updated At",
+            WsColumn::VcsRepo => "VCS Repo",
+            WsColumn::WorkingDirectory => "Working Directory",
+            WsColumn::PendingRuns => "Pending Runs",
+        }
+    }
+
+    /// CSV header, matching the default workspace CSV
+    fn csv_header(&self) -> &'static str {
+        match self {
+            WsColumn::Org => "org",
+            WsColumn::ProjectId => "project_id",
+            WsColumn::ProjectName => "project_name",
+            WsColumn::Name => "workspace_name",
+            WsColumn::Id => "workspace_id",
+            WsColumn::Resources => "resources",
+            WsColumn::Billable => "billable",
+            WsColumn::ExecutionMode => "execution_mode",
+            WsColumn::Locked => "locked",
+            WsColumn::TfVersion => "terraform_version",
+            WsColumn::UpdatedAt => "updated_at",
+            WsColumn::VcsRepo => "vcs_repo",
+            WsColumn::WorkingDirectory => "working_directory",
+            WsColumn::PendingRuns => "pending_runs",
+        }
+    }
+
+    /// Raw cell value (CSV form); missing optional values are empty
+    fn value(&self, row: &WorkspaceRow) -> String {
+        match self {
+            WsColumn::Org => row.org.clone(),
+            WsColumn::ProjectId => row.project_id.clone(),
+            WsColumn::ProjectName => row.project_name.clone().unwrap_or_default(),
+            WsColumn::Name => row.name.clone(),
+            WsColumn::Id => row.id.clone(),
+            WsColumn::Resources => row.resources.to_string(),
+            WsColumn::Billable => row.billable.map(|b| b.to_string()).unwrap_or_default(),
+            WsColumn::ExecutionMode => row.execution_mode.clone(),
+            WsColumn::Locked => row.locked.to_string(),
+            WsColumn::TfVersion => row.terraform_version.clone(),
+            WsColumn::UpdatedAt => row.updated_at.clone(),
+            WsColumn::VcsRepo => row.vcs_repo.clone(),
+            WsColumn::WorkingDirectory => row.working_directory.clone(),
+            WsColumn::PendingRuns => row.pending_runs.map(|n| n.to_string()).unwrap_or_default(),
+        }
+    }
+
+    /// Table cell value, formatted like the default workspace table
+    fn table_value(&self, row: &WorkspaceRow, human: bool, options: &RenderOptions) -> String {
+        let count = |n: u64| {
+            if human {
+                super::common::format_thousands(n)
+            } else {
+                n.to_string()
+            }
+        };
+        match self {
+            WsColumn::ProjectName => {
+                super::common::table_name(row.project_name.as_deref().unwrap_or("-"), options)
+            }
+            WsColumn::Name => super::common::table_name(&row.name, options),
+            WsColumn::Resources => count(u64::from(row.resources)),
+            WsColumn::Billable => row.billable.map(count).unwrap_or_else(|| "-".to_string()),
+            WsColumn::Locked => if row.locked { "Yes" } else { "No" }.to_string(),
+            WsColumn::PendingRuns => row.pending_runs.unwrap_or(0).to_string(),
+            WsColumn::UpdatedAt => super::common::table_time(&row.updated_at, options),
+            _ => self.value(row),
+        }
+    }
+}
+
+/// Output workspaces as table/CSV with exactly the chosen columns, in order
+///
+/// JSON/YAML/NDJSON ignore the column selection and use the regular output.
+pub fn output_workspace_columns(
+    rows: &[WorkspaceRow],
+    columns: &[WsColumn],
+    format: &OutputFormat,
+    no_header: bool,
+    human: bool,
+) {
+    WorkspaceColumnsFormatter {
+        format: *format,
+        no_header,
+        human,
+        columns,
     }
+    .print(rows);
+}
+
+/// Workspace formatter limited to the `--columns` selection
+pub struct WorkspaceColumnsFormatter<'a> {
+    pub format: OutputFormat,
+    pub no_header: bool,
+    pub human: bool,
+    pub columns: &'a [WsColumn],
 }
 
-fn output_table(rows: &[WorkspaceRow], no_header: bool) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+impl Formatter for WorkspaceColumnsFormatter<'_> {
+    type Row = WorkspaceRow;
+
+    fn format_to<W: Write>(
+        &self,
+        rows: &[WorkspaceRow],
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> io::Result<()> {
+        match self.format {
+            OutputFormat::Table | OutputFormat::Markdown => {
+                let mut table = super::common::new_table(options);
+                if !self.no_header {
+                    table.set_header(
+                        self.columns
+                            .iter()
+                            .map(WsColumn::header)
+                            .collect::<Vec<_>>(),
+                    );
+                }
+                for ws in rows {
+                    table.add_row(
+                        self.columns
+                            .iter()
+                            .map(|c| c.table_value(ws, self.human, options))
+                            .collect::<Vec<_>>(),
+                    );
+                }
+                writeln!(writer)?;
+                super::common::write_table(writer, &table, &self.format)?;
+                if super::common::show_footer(self.no_header, options) {
+                    writeln!(writer, "\nTotal: {} workspaces", rows.len())?;
+                }
+                Ok(())
+            }
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                column_csv_records(rows, self.columns, self.no_header)
+                    .iter()
+                    .try_for_each(|record| {
+                        write_record(writer, record, csv_delimiter(&self.format))
+                    })
+            }
+            OutputFormat::Json
+            | OutputFormat::Yaml
+            | OutputFormat::Ndjson
+            | OutputFormat::Template => WorkspaceFormatter {
+                format: self.format,
+                no_header: self.no_header,
+                flatten: false,
+                human: self.human,
+            }
+            .format_to(rows, writer, options),
+        }
+    }
+}
+
+/// CSV records for the chosen columns (header first unless `no_header`)
+fn column_csv_records(
+    rows: &[WorkspaceRow],
+    columns: &[WsColumn],
+    no_header: bool,
+) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(rows.len() + 1);
+    if !no_header {
+        records.push(columns.iter().map(|c| c.csv_header().to_string()).collect());
+    }
+    for ws in rows {
+        records.push(columns.iter().map(|c| c.value(ws)).collect());
+    }
+    records
+}
+
+/// Column headers for the workspace settings shown with --wide
+const SETTINGS_HEADERS: [&str; 5] = [
+    "Auto Apply",
+    "Queue All Runs",
+    "Speculative",
+    "Global Remote State",
+    "Allow Destroy Plan",
+];
+
+/// CSV column names for the workspace settings shown with --wide
+const SETTINGS_CSV_HEADER: [&str; 5] = [
+    "auto_apply",
+    "queue_all_runs",
+    "speculative_enabled",
+    "global_remote_state",
+    "allow_destroy_plan",
+];
+
+/// Settings values in column order (None when the API did not report a value)
+fn settings_values(settings: &WorkspaceSettings) -> [Option<bool>; 5] {
+    [
+        settings.auto_apply,
+        settings.queue_all_runs,
+        settings.speculative_enabled,
+        settings.global_remote_state,
+        settings.allow_destroy_plan,
+    ]
+}
+
+fn output_table<W: Write>(
+    writer: &mut W,
+    rows: &[WorkspaceRow],
+    no_header: bool,
+    human: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let count = |n: u64| {
+        if human {
+            super::common::format_thousands(n)
+        } else {
+            n.to_string()
+        }
+    };
+    let mut table = super::common::new_table(options);
     let show_pending = rows.iter().any(|r| r.pending_runs.is_some());
     let show_billable = rows.iter().any(|r| r.billable.is_some());
+    let show_settings = rows.iter().any(|r| r.settings.is_some());
+    let show_prj_name = rows.iter().any(|r| r.project_name.is_some());
     if !no_header {
-        let mut header = vec![
-            "Org",
-            "Project ID",
-            "Workspace Name",
-            "Workspace ID",
-            "Resources",
-        ];
+        let mut header = vec!["Org", "Project ID"];
+        if show_prj_name {
+            header.push("Project Name");
+        }
+        header.extend_from_slice(&["Workspace Name", "Workspace ID", "Resources"]);
         if show_billable {
             header.push("Billable");
         }
         header.extend_from_slice(&["Execution Mode", "Locked", "TF Version", "Updated At"]);
+        if show_settings {
+            header.extend_from_slice(&SETTINGS_HEADERS);
+        }
         if show_pending {
             header.push("Pending Runs");
         }
@@ -112,91 +487,151 @@ fn output_table(rows: &[WorkspaceRow], no_header: bool) {
 
     for ws in rows {
         let locked = if ws.locked { "Yes" } else { "No" };
-        let mut row = vec![
-            ws.org.clone(),
-            ws.project_id.clone(),
-            ws.name.clone(),
+        let mut row = vec![ws.org.clone(), ws.project_id.clone()];
+        if show_prj_name {
+            row.push(super::common::table_name(
+                ws.project_name.as_deref().unwrap_or("-"),
+                options,
+            ));
+        }
+        row.extend_from_slice(&[
+            super::common::table_name(&ws.name, options),
             ws.id.clone(),
-            ws.resources.to_string(),
-        ];
+            count(u64::from(ws.resources)),
+        ]);
         if show_billable {
-            row.push(
-                ws.billable
-                    .map(|b| b.to_string())
-                    .unwrap_or_else(|| "-".to_string()),
-            );
+            row.push(ws.billable.map(count).unwrap_or_else(|| "-".to_string()));
         }
         row.extend_from_slice(&[
             ws.execution_mode.clone(),
             locked.to_string(),
             ws.terraform_version.clone(),
-            ws.updated_at.clone(),
+            super::common::table_time(&ws.updated_at, options),
         ]);
+        if show_settings {
+            let settings = ws.settings.clone().unwrap_or_default();
+            row.extend(settings_values(&settings).iter().map(|v| match v {
+                Some(true) => "Yes".to_string(),
+                Some(false) => "No".to_string(),
+                None => "-".to_string(),
+            }));
+        }
         if show_pending {
             row.push(ws.pending_runs.unwrap_or(0).to_string());
         }
         table.add_row(row);
     }
 
-    println!();
-    println!("{table}");
-    if !no_header {
-        println!("\nTotal: {} workspaces", rows.len());
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    if super::common::show_footer(no_header, options) {
+        writeln!(writer, "\nTotal: {} workspaces", rows.len())?;
     }
+    Ok(())
 }
 
-fn output_csv(rows: &[WorkspaceRow], no_header: bool) {
+/// Build CSV records (header first unless `no_header`); optional columns appear
+/// only when at least one row carries the value
+fn csv_records(rows: &[WorkspaceRow], no_header: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::with_capacity(rows.len() + 1);
     let show_pending = rows.iter().any(|r| r.pending_runs.is_some());
     let show_billable = rows.iter().any(|r| r.billable.is_some());
+    let show_settings = rows.iter().any(|r| r.settings.is_some());
+    let show_prj_name = rows.iter().any(|r| r.project_name.is_some());
     if !no_header {
-        let mut header = "org,project_id,workspace_name,workspace_id,resources".to_string();
+        let mut header = vec!["org", "project_id"];
+        if show_prj_name {
+            header.push("project_name");
+        }
+        header.extend(["workspace_name", "workspace_id", "resources"]);
         if show_billable {
-            header.push_str(",billable");
+            header.push("billable");
+        }
+        header.extend([
+            "execution_mode",
+            "locked",
+            "terraform_version",
+            "updated_at",
+        ]);
+        if show_settings {
+            header.extend(SETTINGS_CSV_HEADER);
         }
-        header.push_str(",execution_mode,locked,terraform_version,updated_at");
         if show_pending {
-            header.push_str(",pending_runs");
+            header.push("pending_runs");
         }
-        println!("{}", header);
+        records.push(header_record(&header));
     }
 
     for ws in rows {
-        let mut line = format!(
-            "{},{},{},{},{}",
-            escape_csv(&ws.org),
-            escape_csv(&ws.project_id),
-            escape_csv(&ws.name),
-            escape_csv(&ws.id),
-            ws.resources,
-        );
+        let mut record = vec![ws.org.clone(), ws.project_id.clone()];
+        if show_prj_name {
+            record.push(ws.project_name.clone().unwrap_or_default());
+        }
+        record.extend([ws.name.clone(), ws.id.clone(), ws.resources.to_string()]);
         if show_billable {
-            line.push_str(&format!(
-                ",{}",
-                ws.billable.map(|b| b.to_string()).unwrap_or_default()
-            ));
+            record.push(ws.billable.map(|b| b.to_string()).unwrap_or_default());
+        }
+        record.extend([
+            ws.execution_mode.clone(),
+            ws.locked.to_string(),
+            ws.terraform_version.clone(),
+            ws.updated_at.clone(),
+        ]);
+        if show_settings {
+            let settings = ws.settings.clone().unwrap_or_default();
+            for value in settings_values(&settings) {
+                record.push(value.map(|b| b.to_string()).unwrap_or_default());
+            }
         }
-        line.push_str(&format!(
-            ",{},{},{},{}",
-            escape_csv(&ws.execution_mode),
-            ws.locked,
-            escape_csv(&ws.terraform_version),
-            escape_csv(&ws.updated_at)
-        ));
         if show_pending {
-            line.push_str(&format!(",{}", ws.pending_runs.unwrap_or(0)));
+            record.push(ws.pending_runs.unwrap_or(0).to_string());
         }
-        println!("{}", line);
+        records.push(record);
     }
+    records
 }
 
-fn output_json(rows: &[WorkspaceRow]) {
+fn output_json<W: Write>(
+    writer: &mut W,
+    rows: &[WorkspaceRow],
+    flatten: bool,
+    options: &RenderOptions,
+) -> io::Result<()> {
     let data: Vec<SerializableWorkspace> = rows.iter().map(SerializableWorkspace::from).collect();
-    super::common::print_json(&data);
+    if flatten {
+        let flat: Vec<serde_json::Value> = data
+            .iter()
+            .map(|ws| super::common::flatten_json(&serde_json::to_value(ws).unwrap()))
+            .collect();
+        super::common::write_json(writer, &flat, options)
+    } else {
+        super::common::write_json(writer, &data, options)
+    }
 }
 
-fn output_yaml(rows: &[WorkspaceRow]) {
+fn output_yaml<W: Write>(writer: &mut W, rows: &[WorkspaceRow]) -> io::Result<()> {
     let data: Vec<SerializableWorkspace> = rows.iter().map(SerializableWorkspace::from).collect();
-    super::common::print_yaml(&data);
+    super::common::write_yaml(writer, &data)
+}
+
+fn output_lines<W: Write>(
+    writer: &mut W,
+    rows: &[WorkspaceRow],
+    flatten: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let data = rows.iter().map(SerializableWorkspace::from);
+    if flatten {
+        super::common::write_lines(
+            writer,
+            data.map(|ws| super::common::flatten_json(&serde_json::to_value(ws).unwrap())),
+            format,
+            options,
+        )
+    } else {
+        super::common::write_lines(writer, data, format, options)
+    }
 }
 
 /// Per-organization row in the resource summary
@@ -227,17 +662,46 @@ pub fn output_workspace_resource_summary(
     format: &OutputFormat,
     no_header: bool,
 ) {
+    let options = super::common::render_options();
+    super::common::print_with(|mut out| {
+        write_workspace_resource_summary(&mut out, summary, format, no_header, options)
+    });
+}
+
+fn write_workspace_resource_summary<W: Write>(
+    writer: &mut W,
+    summary: &WorkspaceResourceSummary,
+    format: &OutputFormat,
+    no_header: bool,
+    options: &RenderOptions,
+) -> io::Result<()> {
     match format {
-        OutputFormat::Table => output_resource_summary_table(summary, no_header),
-        OutputFormat::Csv => output_resource_summary_csv(summary, no_header),
-        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(summary).unwrap()),
-        OutputFormat::Yaml => println!("{}", serde_yml::to_string(summary).unwrap()),
+        OutputFormat::Table | OutputFormat::Markdown => {
+            output_resource_summary_table(writer, summary, no_header, format, options)
+        }
+        OutputFormat::Csv | OutputFormat::Tsv => {
+            output_resource_summary_csv(writer, summary, no_header, csv_delimiter(format))
+        }
+        OutputFormat::Json => writeln!(
+            writer,
+            "{}",
+            super::common::to_json_pretty(summary, options)
+        ),
+        OutputFormat::Yaml => writeln!(writer, "{}", serde_yml::to_string(summary).unwrap()),
+        OutputFormat::Ndjson | OutputFormat::Template => {
+            super::common::write_lines(writer, std::iter::once(summary), format, options)
+        }
     }
 }
 
-fn output_resource_summary_table(summary: &WorkspaceResourceSummary, no_header: bool) {
-    let mut table = Table::new();
-    table.load_preset(NOTHING);
+fn output_resource_summary_table<W: Write>(
+    writer: &mut W,
+    summary: &WorkspaceResourceSummary,
+    no_header: bool,
+    format: &OutputFormat,
+    options: &RenderOptions,
+) -> io::Result<()> {
+    let mut table = super::common::new_table(options);
 
     if !no_header {
         table.set_header(vec!["ORG", "WORKSPACES", "RESOURCES"]);
@@ -263,50 +727,121 @@ fn output_resource_summary_table(summary: &WorkspaceResourceSummary, no_header:
         summary.instance_total.resource_count.to_string(),
     ]);
 
-    println!();
-    println!("{table}");
+    writeln!(writer)?;
+    super::common::write_table(writer, &table, format)?;
+    Ok(())
 }
 
-fn output_resource_summary_csv(summary: &WorkspaceResourceSummary, no_header: bool) {
+fn output_resource_summary_csv<W: Write>(
+    writer: &mut W,
+    summary: &WorkspaceResourceSummary,
+    no_header: bool,
+    delimiter: char,
+) -> io::Result<()> {
     if !no_header {
-        println!("org,workspace_count,resource_count");
+        write_record(
+            writer,
+            &["org", "workspace_count", "resource_count"],
+            delimiter,
+        )?;
     }
 
     for row in &summary.organizations {
-        println!(
-            "{},{},{}",
-            escape_csv(&row.org),
-            row.workspace_count,
-            row.resource_count
-        );
+        write_record(
+            writer,
+            &[
+                row.org.clone(),
+                row.workspace_count.to_string(),
+                row.resource_count.to_string(),
+            ],
+            delimiter,
+        )?;
     }
 
-    println!(
-        "TOTAL,{},{}",
-        summary.instance_total.workspace_count, summary.instance_total.resource_count
-    );
+    write_record(
+        writer,
+        &[
+            "TOTAL".to_string(),
+            summary.instance_total.workspace_count.to_string(),
+            summary.instance_total.resource_count.to_string(),
+        ],
+        delimiter,
+    )?;
+    Ok(())
+}
+
+/// Count workspace rows per distinct value of `field`
+pub fn count_rows_by(rows: &[WorkspaceRow], field: WsCountField) -> BTreeMap<String, usize> {
+    super::common::count_by(rows, |row| match field {
+        WsCountField::ExecutionMode => row.execution_mode.clone(),
+        WsCountField::TfVersion => row.terraform_version.clone(),
+        WsCountField::Locked => row.locked.to_string(),
+        WsCountField::Project => row
+            .project_name
+            .clone()
+            .filter(|n| !n.is_empty())
+            .unwrap_or_else(|| row.project_id.clone()),
+        WsCountField::Org => row.org.clone(),
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::hcp::WorkspaceAttributes;
+    use crate::output::jsonpath::JsonPath;
+    use crate::output::template::Template;
 
     fn create_test_workspace() -> Workspace {
         Workspace {
             id: "ws-123".to_string(),
             attributes: WorkspaceAttributes {
                 name: "test-workspace".to_string(),
+                vcs_repo: None,
+                working_directory: None,
+                assessments_enabled: None,
                 execution_mode: Some("remote".to_string()),
                 resource_count: Some(42),
                 locked: Some(false),
                 terraform_version: Some("1.5.0".to_string()),
                 updated_at: None,
+                settings: Default::default(),
+                state: None,
             },
             relationships: None,
         }
     }
 
+    fn render(rows: &[WorkspaceRow], format: OutputFormat, no_header: bool, human: bool) -> String {
+        let formatter = WorkspaceFormatter {
+            format,
+            no_header,
+            flatten: false,
+            human,
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(rows, &mut buf, &RenderOptions::default())
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn render_with(
+        rows: &[WorkspaceRow],
+        format: OutputFormat,
+        options: &RenderOptions,
+    ) -> io::Result<String> {
+        let formatter = WorkspaceFormatter {
+            format,
+            no_header: true,
+            flatten: false,
+            human: false,
+        };
+        let mut buf = Vec::new();
+        formatter.format_to(rows, &mut buf, options)?;
+        Ok(String::from_utf8(buf).unwrap())
+    }
+
     #[test]
     fn test_workspace_row_creation() {
         let ws = create_test_workspace();
@@ -322,11 +857,24 @@ mod tests {
         assert_eq!(row.terraform_version, "1.5.0");
     }
 
+    #[test]
+    fn test_workspace_row_tfe_resource() {
+        let ws = create_test_workspace();
+        let row = WorkspaceRow::new("my-org", &ws);
+
+        assert_eq!(TfeResource::id(&row), "ws-123");
+        assert_eq!(TfeResource::name(&row), "test-workspace");
+        assert!(row.matches("ws-123"));
+    }
+
     #[test]
     fn test_serializable_from_row() {
         let row = WorkspaceRow {
             org: "test-org".to_string(),
+            vcs_repo: String::new(),
+            working_directory: String::new(),
             project_id: "prj-123".to_string(),
+            project_name: None,
             name: "test-ws".to_string(),
             id: "ws-123".to_string(),
             resources: 10,
@@ -336,6 +884,7 @@ mod tests {
             terraform_version: "1.5.0".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
             pending_runs: None,
+            settings: None,
         };
 
         let serialized_ws = SerializableWorkspace::from(&row);
@@ -350,7 +899,10 @@ mod tests {
     fn test_serializable_with_pending_runs() {
         let row = WorkspaceRow {
             org: "test-org".to_string(),
+            vcs_repo: String::new(),
+            working_directory: String::new(),
             project_id: "prj-123".to_string(),
+            project_name: None,
             name: "test-ws".to_string(),
             id: "ws-123".to_string(),
             resources: 10,
@@ -360,12 +912,135 @@ mod tests {
             terraform_version: "1.5.0".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
             pending_runs: Some(5),
+            settings: None,
         };
 
         let serialized_ws = SerializableWorkspace::from(&row);
         assert_eq!(serialized_ws.pending_runs, Some(5));
     }
 
+    #[test]
+    fn test_serializable_with_settings() {
+        let mut row = WorkspaceRow::new("org", &create_test_workspace());
+        row.settings = Some(WorkspaceSettings {
+            auto_apply: Some(true),
+            allow_destroy_plan: Some(false),
+            ..Default::default()
+        });
+
+        let json = serde_json::to_value(SerializableWorkspace::from(&row)).unwrap();
+        assert_eq!(json["settings"]["auto_apply"], true);
+        assert_eq!(json["settings"]["allow_destroy_plan"], false);
+        assert!(json["settings"]["queue_all_runs"].is_null());
+    }
+
+    #[test]
+    fn test_serializable_settings_skipped_when_none() {
+        let row = WorkspaceRow::new("org", &create_test_workspace());
+        let json = serde_json::to_string(&SerializableWorkspace::from(&row)).unwrap();
+        assert!(!json.contains("settings"));
+    }
+
+    #[test]
+    fn test_from_snapshot_round_trip() {
+        let mut row = WorkspaceRow::new("org", &create_test_workspace());
+        row.project_name = Some("platform".to_string());
+        let json = serde_json::to_string(&[SerializableWorkspace::from(&row)]).unwrap();
+
+        let parsed = WorkspaceRow::from_snapshot(&json).unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].id, "ws-123");
+        assert_eq!(parsed[0].name, "test-workspace");
+        assert_eq!(parsed[0].resources, 42);
+        assert_eq!(parsed[0].project_name.as_deref(), Some("platform"));
+        assert!(parsed[0].pending_runs.is_none());
+    }
+
+    #[test]
+    fn test_serializable_always_has_vcs_fields() {
+        let options = &RenderOptions::default();
+        let row = WorkspaceRow::new("org", &create_test_workspace());
+        let json = serde_json::to_value(SerializableWorkspace::from(&row)).unwrap();
+        assert_eq!(json["vcs_repo"], "");
+        assert_eq!(json["working_directory"], "");
+
+        let mut row = row;
+        row.vcs_repo = "acme/infra".to_string();
+        row.working_directory = "envs/prod".to_string();
+        assert_eq!(WsColumn::VcsRepo.value(&row), "acme/infra");
+        assert_eq!(
+            WsColumn::WorkingDirectory.table_value(&row, false, options),
+            "envs/prod"
+        );
+
+        // Baselines exported before these fields existed still load
+        let mut old = serde_json::to_value(SerializableWorkspace::from(&row)).unwrap();
+        old.as_object_mut().unwrap().remove("vcs_repo");
+        old.as_object_mut().unwrap().remove("working_directory");
+        let parsed = WorkspaceRow::from_snapshot(&format!("[{}]", old)).unwrap();
+        assert_eq!(parsed[0].vcs_repo, "");
+    }
+
+    #[test]
+    fn test_from_snapshot_rejects_other_json() {
+        assert!(WorkspaceRow::from_snapshot(r#"{"org": "x"}"#).is_err());
+        assert!(WorkspaceRow::from_snapshot(r#"[{"workspace_id": "ws-1"}]"#).is_err());
+    }
+
+    #[test]
+    fn test_column_csv_records_honor_order() {
+        let mut row = WorkspaceRow::new("my-org", &create_test_workspace());
+        row.updated_at = "2024-01-01T00:00:00Z".to_string();
+        let columns = [
+            WsColumn::Name,
+            WsColumn::Id,
+            WsColumn::Resources,
+            WsColumn::UpdatedAt,
+        ];
+
+        assert_eq!(
+            column_csv_records(&[row.clone()], &columns, false),
+            vec![
+                vec!["workspace_name", "workspace_id", "resources", "updated_at"],
+                vec!["test-workspace", "ws-123", "42", "2024-01-01T00:00:00Z"],
+            ]
+        );
+        assert_eq!(
+            column_csv_records(&[row], &[WsColumn::Billable, WsColumn::Org], true),
+            vec![vec!["", "my-org"]]
+        );
+    }
+
+    #[test]
+    fn test_column_table_values() {
+        let options = &RenderOptions::default();
+        let mut row = WorkspaceRow::new("my-org", &create_test_workspace());
+        row.resources = 12345;
+        row.locked = true;
+        assert_eq!(
+            WsColumn::Resources.table_value(&row, true, options),
+            "12,345"
+        );
+        assert_eq!(WsColumn::Locked.table_value(&row, false, options), "Yes");
+        assert_eq!(WsColumn::Billable.table_value(&row, false, options), "-");
+        assert_eq!(WsColumn::ProjectName.table_value(&row, false, options), "-");
+    }
+
+    #[test]
+    fn test_settings_values_order() {
+        let settings = WorkspaceSettings {
+            auto_apply: Some(true),
+            queue_all_runs: Some(false),
+            speculative_enabled: None,
+            global_remote_state: Some(true),
+            allow_destroy_plan: Some(false),
+        };
+        assert_eq!(
+            settings_values(&settings),
+            [Some(true), Some(false), None, Some(true), Some(false)]
+        );
+    }
+
     #[test]
     fn test_workspace_row_pending_runs_default_none() {
         let ws = create_test_workspace();
@@ -380,7 +1055,10 @@ mod tests {
     fn test_serializable_pending_runs_skipped_when_none() {
         let row = WorkspaceRow {
             org: "org".to_string(),
+            vcs_repo: String::new(),
+            working_directory: String::new(),
             project_id: "prj-1".to_string(),
+            project_name: None,
             name: "ws".to_string(),
             id: "ws-1".to_string(),
             resources: 0,
@@ -390,6 +1068,7 @@ mod tests {
             terraform_version: "1.5.0".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
             pending_runs: None,
+            settings: None,
         };
 
         let json = serde_json::to_string(&SerializableWorkspace::from(&row)).unwrap();
@@ -403,7 +1082,10 @@ mod tests {
     fn test_serializable_pending_runs_included_when_some() {
         let row = WorkspaceRow {
             org: "org".to_string(),
+            vcs_repo: String::new(),
+            working_directory: String::new(),
             project_id: "prj-1".to_string(),
+            project_name: None,
             name: "ws".to_string(),
             id: "ws-1".to_string(),
             resources: 0,
@@ -413,6 +1095,7 @@ mod tests {
             terraform_version: "1.5.0".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
             pending_runs: Some(3),
+            settings: None,
         };
 
         let json = serde_json::to_string(&SerializableWorkspace::from(&row)).unwrap();
@@ -427,7 +1110,10 @@ mod tests {
     fn test_output_workspaces_with_pending_runs_column() {
         let rows = vec![WorkspaceRow {
             org: "org".to_string(),
+            vcs_repo: String::new(),
+            working_directory: String::new(),
             project_id: "prj-1".to_string(),
+            project_name: None,
             name: "ws-a".to_string(),
             id: "ws-aaa".to_string(),
             resources: 5,
@@ -437,26 +1123,205 @@ mod tests {
             terraform_version: "1.5.0".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
             pending_runs: Some(2),
+            settings: None,
         }];
-        // Should not panic — table includes Pending Runs column
-        output_workspaces(&rows, &OutputFormat::Table, false);
-        output_workspaces(&rows, &OutputFormat::Csv, false);
+        let table = render(&rows, OutputFormat::Table, false, false);
+        assert!(table.contains("Pending Runs"));
+        assert!(table.ends_with("\nTotal: 1 workspaces\n"));
+
+        let csv = render(&rows, OutputFormat::Csv, false, false);
+        assert!(csv.lines().next().unwrap().ends_with(",pending_runs"));
+        assert!(csv.lines().nth(1).unwrap().ends_with(",2"));
+    }
+
+    #[test]
+    fn test_output_workspaces_human_table() {
+        let rows = vec![WorkspaceRow {
+            org: "org".to_string(),
+            vcs_repo: String::new(),
+            working_directory: String::new(),
+            project_id: "prj-1".to_string(),
+            project_name: None,
+            name: "ws-big".to_string(),
+            id: "ws-big".to_string(),
+            resources: 12345,
+            billable: Some(1_000_000),
+            execution_mode: "remote".to_string(),
+            locked: false,
+            terraform_version: "1.5.0".to_string(),
+            updated_at: "2024-01-01T00:00:00Z".to_string(),
+            pending_runs: None,
+            settings: None,
+        }];
+        // Only table output applies separators
+        let table = render(&rows, OutputFormat::Table, false, true);
+        assert!(table.contains("12,345"));
+        assert!(table.contains("1,000,000"));
+
+        let csv = render(&rows, OutputFormat::Csv, false, true);
+        assert!(csv.contains(",12345,"));
+        assert!(!csv.contains("12,345"));
+    }
+
+    #[test]
+    fn test_csv_records_with_project_name() {
+        let mut row = WorkspaceRow::new("my-org", &create_test_workspace());
+        row.project_id = "prj-1".to_string();
+        row.project_name = Some("Platform, Core".to_string());
+        let records = csv_records(&[row], false);
+        assert_eq!(
+            records[0][..4],
+            ["org", "project_id", "project_name", "workspace_name"]
+        );
+        assert_eq!(
+            records[1][..4],
+            ["my-org", "prj-1", "Platform, Core", "test-workspace"]
+        );
+    }
+
+    #[test]
+    fn test_csv_records_without_project_name() {
+        let row = WorkspaceRow::new("my-org", &create_test_workspace());
+        let records = csv_records(&[row], true);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0][..3], ["my-org", "", "test-workspace"]);
+    }
+
+    #[test]
+    fn test_count_rows_by_execution_mode() {
+        let ws = create_test_workspace();
+        let mut local = WorkspaceRow::new("org", &ws);
+        local.execution_mode = "local".to_string();
+        let rows = vec![
+            WorkspaceRow::new("org", &ws),
+            local,
+            WorkspaceRow::new("org", &ws),
+        ];
+        let counts = count_rows_by(&rows, WsCountField::ExecutionMode);
+        assert_eq!(counts.get("remote"), Some(&2));
+        assert_eq!(counts.get("local"), Some(&1));
+    }
+
+    #[test]
+    fn test_count_rows_by_project_prefers_name() {
+        let ws = create_test_workspace();
+        let mut named = WorkspaceRow::new("org", &ws);
+        named.project_id = "prj-1".to_string();
+        named.project_name = Some("Core".to_string());
+        let mut unnamed = WorkspaceRow::new("org", &ws);
+        unnamed.project_id = "prj-2".to_string();
+        let counts = count_rows_by(&[named, unnamed], WsCountField::Project);
+        assert_eq!(counts.get("Core"), Some(&1));
+        assert_eq!(counts.get("prj-2"), Some(&1));
+    }
+
+    #[test]
+    fn test_count_rows_by_locked_json_shape() {
+        let rows = vec![WorkspaceRow::new("org", &create_test_workspace())];
+        let counts = count_rows_by(&rows, WsCountField::Locked);
+        assert_eq!(
+            serde_json::to_value(&counts).unwrap(),
+            serde_json::json!({"false": 1})
+        );
+        // Should not panic
     }
 
     #[test]
     fn test_output_workspaces_empty() {
-        // Should not panic with empty input
-        output_workspaces(&[], &OutputFormat::Table, false);
-        output_workspaces(&[], &OutputFormat::Csv, false);
-        output_workspaces(&[], &OutputFormat::Json, false);
-        output_workspaces(&[], &OutputFormat::Yaml, false);
+        assert!(render(&[], OutputFormat::Table, false, false).ends_with("Total: 0 workspaces\n"));
+        assert_eq!(render(&[], OutputFormat::Json, false, false), "[]\n");
+        assert_eq!(render(&[], OutputFormat::Yaml, false, false), "[]\n");
+        assert!(render(&[], OutputFormat::Csv, false, false).starts_with("org,"));
     }
 
     #[test]
     fn test_output_workspaces_no_header() {
-        // Should not panic
-        output_workspaces(&[], &OutputFormat::Table, true);
-        output_workspaces(&[], &OutputFormat::Csv, true);
+        assert!(!render(&[], OutputFormat::Table, true, false).contains("Total"));
+        assert_eq!(render(&[], OutputFormat::Csv, true, false), "");
+    }
+
+    #[test]
+    fn test_output_workspaces_template() {
+        let rows = [WorkspaceRow::new("my-org", &create_test_workspace())];
+        let options = RenderOptions {
+            template: Some(Template::parse("{{.org}}/{{.workspace_name}}").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            render_with(&rows, OutputFormat::Template, &options).unwrap(),
+            "my-org/test-workspace\n"
+        );
+    }
+
+    #[test]
+    fn test_output_workspaces_template_missing_is_error() {
+        let rows = [WorkspaceRow::new("my-org", &create_test_workspace())];
+        let err =
+            render_with(&rows, OutputFormat::Template, &RenderOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_output_workspaces_jsonpath() {
+        let rows = [WorkspaceRow::new("my-org", &create_test_workspace())];
+        let jsonpath = |expr: &str| RenderOptions {
+            jsonpath: Some((expr.to_string(), JsonPath::parse(expr).unwrap())),
+            ..Default::default()
+        };
+
+        let options = jsonpath("$[0].workspace_name");
+        assert_eq!(
+            render_with(&rows, OutputFormat::Json, &options).unwrap(),
+            "\"test-workspace\"\n"
+        );
+        assert!(options.check_jsonpath_matched().is_ok());
+
+        let options = jsonpath("$[0].missing");
+        render_with(&rows, OutputFormat::Json, &options).unwrap();
+        assert!(options.check_jsonpath_matched().is_err());
+    }
+
+    #[test]
+    fn test_output_workspaces_sort_keys() {
+        let rows = [WorkspaceRow::new("my-org", &create_test_workspace())];
+        let options = RenderOptions {
+            sort_keys: true,
+            ..Default::default()
+        };
+        let json = render_with(&rows, OutputFormat::Json, &options).unwrap();
+        assert!(json.find("\"locked\"").unwrap() < json.find("\"org\"").unwrap());
+        assert!(json.find("\"org\"").unwrap() < json.find("\"workspace_name\"").unwrap());
+    }
+
+    #[test]
+    fn test_output_workspaces_max_name_width() {
+        let rows = [WorkspaceRow::new("my-org", &create_test_workspace())];
+        let options = RenderOptions {
+            max_name_width: Some(5),
+            ..Default::default()
+        };
+        let table = render_with(&rows, OutputFormat::Table, &options).unwrap();
+        assert!(table.contains("test…"), "{}", table);
+        assert!(!table.contains("test-workspace"), "{}", table);
+    }
+
+    #[test]
+    fn test_columns_formatter_csv_output() {
+        let row = WorkspaceRow::new("my-org", &create_test_workspace());
+        let formatter = WorkspaceColumnsFormatter {
+            format: OutputFormat::Csv,
+            no_header: false,
+            human: false,
+            columns: &[WsColumn::Name, WsColumn::Org],
+        };
+        let mut buf = Vec::new();
+        formatter
+            .format_to(&[row], &mut buf, &RenderOptions::default())
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "workspace_name,org\ntest-workspace,my-org\n"
+        );
     }
 
     // -------------------------------------------------------------------------
@@ -491,13 +1356,34 @@ mod tests {
         assert_eq!(summary.instance_total.resource_count, 0);
     }
 
+    fn render_summary(
+        summary: &WorkspaceResourceSummary,
+        format: OutputFormat,
+        no_header: bool,
+        options: &RenderOptions,
+    ) -> String {
+        let mut buf = Vec::new();
+        write_workspace_resource_summary(&mut buf, summary, &format, no_header, options).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
-    fn test_resource_summary_empty_no_panic_all_formats() {
+    fn test_resource_summary_empty_all_formats() {
+        let options = &RenderOptions::default();
         let summary = make_summary(vec![]);
-        output_workspace_resource_summary(&summary, &OutputFormat::Table, false);
-        output_workspace_resource_summary(&summary, &OutputFormat::Csv, false);
-        output_workspace_resource_summary(&summary, &OutputFormat::Json, false);
-        output_workspace_resource_summary(&summary, &OutputFormat::Yaml, false);
+        assert!(render_summary(&summary, OutputFormat::Table, false, options).contains("TOTAL"));
+        assert_eq!(
+            render_summary(&summary, OutputFormat::Csv, false, options),
+            "org,workspace_count,resource_count\nTOTAL,0,0\n"
+        );
+        assert!(render_summary(&summary, OutputFormat::Json, false, options)
+            .contains("\"instance_total\""));
+        assert!(render_summary(&summary, OutputFormat::Yaml, false, options)
+            .contains("instance_total:"));
+        assert_eq!(
+            render_summary(&summary, OutputFormat::Ndjson, false, options),
+            "{\"organizations\":[],\"instance_total\":{\"workspace_count\":0,\"resource_count\":0}}\n"
+        );
     }
 
     #[test]
@@ -539,17 +1425,29 @@ mod tests {
     }
 
     #[test]
-    fn test_resource_summary_csv_no_panic_with_data() {
+    fn test_resource_summary_csv_with_data() {
+        let options = &RenderOptions::default();
         let summary = make_summary(vec![("org-a", 2, 20), ("org-b", 1, 5)]);
-        // Should not panic for any format
-        output_workspace_resource_summary(&summary, &OutputFormat::Csv, false);
-        output_workspace_resource_summary(&summary, &OutputFormat::Csv, true);
+        assert_eq!(
+            render_summary(&summary, OutputFormat::Csv, false, options),
+            "org,workspace_count,resource_count\norg-a,2,20\norg-b,1,5\nTOTAL,3,25\n"
+        );
+        assert_eq!(
+            render_summary(&summary, OutputFormat::Csv, true, options),
+            "org-a,2,20\norg-b,1,5\nTOTAL,3,25\n"
+        );
     }
 
     #[test]
-    fn test_resource_summary_table_no_panic_with_data() {
+    fn test_resource_summary_table_with_data() {
+        let options = &RenderOptions::default();
         let summary = make_summary(vec![("org-a", 2, 20)]);
-        output_workspace_resource_summary(&summary, &OutputFormat::Table, false);
-        output_workspace_resource_summary(&summary, &OutputFormat::Table, true);
+        let table = render_summary(&summary, OutputFormat::Table, false, options);
+        assert!(table.contains("WORKSPACES"));
+        assert!(table.contains("org-a"));
+        assert!(table.contains("TOTAL"));
+        assert!(
+            !render_summary(&summary, OutputFormat::Table, true, options).contains("WORKSPACES")
+        );
     }
 }
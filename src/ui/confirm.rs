@@ -1,6 +1,13 @@
 //! User confirmation prompts for potentially dangerous operations
 
 use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use dialoguer::{theme::ColorfulTheme, Confirm, Select};
+use log::debug;
+use tokio::sync::{mpsc, Mutex};
 
 use crate::config::api;
 use crate::hcp::AggregatedPaginationInfo;
@@ -58,6 +65,216 @@ impl LargePaginationInfo {
     }
 }
 
+/// Shared stdin reader thread and the lines it has read
+struct StdinReader {
+    /// Asks the thread to read one more line
+    requests: std::sync::mpsc::Sender<()>,
+    /// Set while a requested line has not been received yet (e.g. its prompt
+    /// timed out), meaning the thread is still reading stdin
+    pending: AtomicBool,
+    lines: Mutex<mpsc::UnboundedReceiver<io::Result<String>>>,
+}
+
+static STDIN_READER: OnceLock<StdinReader> = OnceLock::new();
+
+/// Shared stdin reader, started on first use
+///
+/// A single thread owns line input for the life of the process, so no reader is
+/// ever abandoned mid-read. It only reads when a prompt asks for a line, so
+/// between prompts stdin is left alone for the dialoguer menus. A line that
+/// arrives after a prompt timed out is discarded by [`discard_pending_input`]
+/// before the next prompt is shown, so it cannot answer a question the user
+/// has not seen.
+fn stdin_reader() -> &'static StdinReader {
+    STDIN_READER.get_or_init(|| {
+        let (requests, requested) = std::sync::mpsc::channel::<()>();
+        let (tx, rx) = mpsc::unbounded_channel();
+        std::thread::spawn(move || {
+            for () in requested {
+                let mut line = String::new();
+                let result = io::stdin().read_line(&mut line).map(|_| line);
+                let done = !matches!(&result, Ok(line) if !line.is_empty());
+                if tx.send(result).is_err() || done {
+                    break;
+                }
+            }
+        });
+        StdinReader {
+            requests,
+            pending: AtomicBool::new(false),
+            lines: Mutex::new(rx),
+        }
+    })
+}
+
+/// Whether the shared reader is still waiting on a line for a timed-out prompt
+///
+/// A dialoguer menu shown now would compete with it for input, so prompts fall
+/// back to reading a line instead.
+fn read_pending() -> bool {
+    STDIN_READER
+        .get()
+        .is_some_and(|reader| reader.pending.load(Ordering::SeqCst))
+}
+
+/// Drop lines typed before the next prompt is shown (e.g. a late answer to a
+/// prompt that already timed out)
+///
+/// End of input is kept: once the reader thread has stopped, the channel is
+/// closed and the next read still sees it.
+pub fn discard_pending_input() {
+    if let Some(reader) = STDIN_READER.get() {
+        discard_queued(reader);
+    }
+}
+
+fn discard_queued(reader: &StdinReader) {
+    let Ok(mut rx) = reader.lines.try_lock() else {
+        return;
+    };
+    while let Ok(received) = rx.try_recv() {
+        reader.pending.store(false, Ordering::SeqCst);
+        match received {
+            Ok(line) if !line.is_empty() => debug!(
+                "Discarding input typed before the prompt: {:?}",
+                line.trim_end()
+            ),
+            _ => break,
+        }
+    }
+}
+
+/// Wait for the next line from `reader`, giving up after `timeout_secs`
+///
+/// Returns `None` on timeout; the line stays requested and is dropped by the
+/// next [`discard_queued`]. End of input reads as an empty line.
+async fn next_line(reader: &StdinReader, timeout_secs: Option<u64>) -> io::Result<Option<String>> {
+    let mut rx = reader.lines.lock().await;
+    if !reader.pending.swap(true, Ordering::SeqCst) {
+        // Only fails once the thread stopped at end of input, which the closed
+        // channel reports below
+        let _ = reader.requests.send(());
+    }
+    let received = match timeout_secs {
+        Some(secs) => match tokio::time::timeout(Duration::from_secs(secs), rx.recv()).await {
+            Ok(received) => received,
+            Err(_) => return Ok(None),
+        },
+        None => rx.recv().await,
+    };
+    reader.pending.store(false, Ordering::SeqCst);
+    received.unwrap_or_else(|| Ok(String::new())).map(Some)
+}
+
+/// Read one line from stdin, returning `None` if it is not answered in time
+///
+/// Without a timeout (`timeout_secs=None`) this waits indefinitely. Call
+/// [`discard_pending_input`] before showing the prompt this line answers.
+pub async fn read_line_with_timeout(timeout_secs: Option<u64>) -> io::Result<Option<String>> {
+    let line = next_line(stdin_reader(), timeout_secs).await?;
+    if line.is_none() {
+        report_timeout(timeout_secs.unwrap_or_default(), ", using default");
+    }
+    Ok(line)
+}
+
+/// Tell the user a prompt was not answered in time
+fn report_timeout(secs: u64, outcome: &str) {
+    debug!("Prompt not answered within {}s", secs);
+    // Restore the cursor in case a spinner left it hidden
+    let _ = console::Term::stderr().show_cursor();
+    eprintln!("\nNo answer within {}s{}", secs, outcome);
+}
+
+/// Ask the user to pick one of `items`, returning `None` if not answered in time
+///
+/// Without a timeout this is an interactive arrow-key menu with `default`
+/// highlighted. A timed prompt lists the items numbered and reads the choice
+/// as a line through the shared stdin reader (a bare Enter picks `default`),
+/// so nothing is left reading the terminal once it times out.
+pub async fn select_with_timeout(
+    prompt: &str,
+    items: &[String],
+    default: usize,
+    timeout_secs: Option<u64>,
+) -> io::Result<Option<usize>> {
+    discard_pending_input();
+    if timeout_secs.is_none() && !read_pending() {
+        return Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(prompt)
+            .items(items)
+            .default(default)
+            .interact()
+            .map(Some)
+            .map_err(io::Error::other);
+    }
+
+    for (i, item) in items.iter().enumerate() {
+        eprintln!("  {}) {}", i + 1, item);
+    }
+    eprint!("{} [1-{}, default {}]: ", prompt, items.len(), default + 1);
+    io::stderr().flush()?;
+
+    match next_line(stdin_reader(), timeout_secs).await? {
+        Some(line) => parse_selection(&line, items.len(), default).map(Some),
+        None => {
+            report_timeout(timeout_secs.unwrap_or_default(), "");
+            Ok(None)
+        }
+    }
+}
+
+/// Parse a 1-based menu choice; empty input picks `default`
+fn parse_selection(input: &str, len: usize, default: usize) -> io::Result<usize> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(default);
+    }
+    match input.parse::<usize>() {
+        Ok(n) if (1..=len).contains(&n) => Ok(n - 1),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid choice '{}', expected 1-{}", input, len),
+        )),
+    }
+}
+
+/// Ask a yes/no question, applying `default` if not answered in time
+///
+/// Without a timeout this is an interactive dialoguer prompt. A timed prompt
+/// reads a `y`/`n` line through the shared stdin reader instead.
+pub async fn confirm_with_timeout(
+    prompt: &str,
+    default: bool,
+    timeout_secs: Option<u64>,
+) -> io::Result<bool> {
+    discard_pending_input();
+    if timeout_secs.is_none() && !read_pending() {
+        return Confirm::new()
+            .with_prompt(prompt)
+            .default(default)
+            .interact()
+            .map_err(io::Error::other);
+    }
+
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    eprint!("{} {} ", prompt, hint);
+    io::stderr().flush()?;
+
+    Ok(read_line_with_timeout(timeout_secs)
+        .await?
+        .map_or(default, |line| parse_yes_no(&line, default)))
+}
+
+/// Parse a y/n answer; anything else keeps `default`
+fn parse_yes_no(input: &str, default: bool) -> bool {
+    match input.trim().to_lowercase().as_str() {
+        "y" | "yes" => true,
+        "n" | "no" => false,
+        _ => default,
+    }
+}
+
 /// Prompt user to confirm an action with [y/N]
 ///
 /// Returns `true` if user confirms, `false` if user declines.
@@ -66,28 +283,78 @@ impl LargePaginationInfo {
 /// # Arguments
 /// * `message` - The prompt message (will be followed by " [y/N] ")
 /// * `skip_prompt` - If true, skip the prompt and return true (for --yes/--batch)
-pub fn confirm_action(
+/// * `timeout_secs` - Declines (the default answer) if not answered in time
+pub async fn confirm_action(
     message: &str,
     skip_prompt: bool,
+    timeout_secs: Option<u64>,
 ) -> Result<bool, Box<dyn std::error::Error>> {
     if skip_prompt {
         return Ok(true);
     }
 
+    discard_pending_input();
     print!("{} [y/N] ", message);
     io::stdout().flush()?;
 
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
+    let input = read_line_with_timeout(timeout_secs)
+        .await?
+        .unwrap_or_default();
 
     Ok(input.trim().eq_ignore_ascii_case("y"))
 }
 
+/// Prompt user to confirm a destructive action by typing `expected` (e.g. a workspace name)
+///
+/// Returns `true` only if the typed text matches exactly (surrounding whitespace
+/// ignored). With `skip_prompt` (--yes) the prompt is skipped and `true` returned.
+/// In batch mode without `skip_prompt` this returns an error instead of prompting.
+/// An unanswered prompt declines once `timeout_secs` elapses.
+pub async fn confirm_typed(
+    expected: &str,
+    skip_prompt: bool,
+    batch_mode: bool,
+    timeout_secs: Option<u64>,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    if skip_prompt {
+        return Ok(true);
+    }
+    if batch_mode {
+        return Err(format!(
+            "Confirmation required: refusing to prompt for '{}' in batch mode (use --yes to confirm)",
+            expected
+        )
+        .into());
+    }
+
+    discard_pending_input();
+    print!("Type '{}' to confirm: ", expected);
+    io::stdout().flush()?;
+
+    let input = read_line_with_timeout(timeout_secs)
+        .await?
+        .unwrap_or_default();
+    let input = input.trim();
+    if input != expected {
+        println!(
+            "\nAborted. Input '{}' does not match '{}'.",
+            input, expected
+        );
+        return Ok(false);
+    }
+    Ok(true)
+}
+
 /// Prompt user to confirm a large pagination operation
 ///
 /// Returns `true` if user confirms, `false` if user declines.
-/// In batch mode, always returns `false` (fails safe).
-pub fn confirm_large_pagination(info: &LargePaginationInfo, batch_mode: bool) -> bool {
+/// In batch mode, always returns `false` (fails safe), as does an unanswered
+/// prompt once `timeout_secs` elapses.
+pub async fn confirm_large_pagination(
+    info: &LargePaginationInfo,
+    batch_mode: bool,
+    timeout_secs: Option<u64>,
+) -> bool {
     if batch_mode {
         eprintln!(
             "\nWARNING: LARGE RESULT SET DETECTED - Operation aborted in batch mode\n\
@@ -107,6 +374,7 @@ pub fn confirm_large_pagination(info: &LargePaginationInfo, batch_mode: bool) ->
     }
 
     // Interactive mode - show warning and prompt
+    discard_pending_input();
     eprintln!(
         "\n\x1b[1;33mWARNING: LARGE RESULT SET DETECTED\x1b[0m\n\
          \n\
@@ -139,10 +407,9 @@ pub fn confirm_large_pagination(info: &LargePaginationInfo, batch_mode: bool) ->
     eprint!("\n\x1b[1;33mProceed with this operation? [y/N]:\x1b[0m ");
     let _ = io::stderr().flush();
 
-    let mut input = String::new();
-    if io::stdin().read_line(&mut input).is_err() {
+    let Ok(Some(input)) = read_line_with_timeout(timeout_secs).await else {
         return false;
-    }
+    };
 
     let answer = input.trim().to_lowercase();
     matches!(answer.as_str(), "y" | "yes")
@@ -231,17 +498,127 @@ mod tests {
         assert!(truncated.ends_with("..."));
     }
 
-    #[test]
-    fn test_confirm_large_pagination_batch_mode() {
+    #[tokio::test]
+    async fn test_confirm_large_pagination_batch_mode() {
         let info = LargePaginationInfo::new(5000, 50, "test");
         // In batch mode, should always return false
-        assert!(!confirm_large_pagination(&info, true));
+        assert!(!confirm_large_pagination(&info, true, None).await);
     }
 
-    #[test]
-    fn test_confirm_action_skip_prompt() {
+    #[tokio::test]
+    async fn test_confirm_action_skip_prompt() {
         // When skip_prompt is true, should auto-confirm without reading stdin
-        let result = confirm_action("Delete everything?", true).unwrap();
+        let result = confirm_action("Delete everything?", true, Some(1))
+            .await
+            .unwrap();
         assert!(result);
     }
+
+    #[tokio::test]
+    async fn test_confirm_typed_skip_prompt() {
+        assert!(confirm_typed("my-ws", true, true, Some(1)).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_confirm_typed_batch_mode_aborts() {
+        let err = confirm_typed("my-ws", false, true, None).await.unwrap_err();
+        assert!(err.to_string().contains("my-ws"));
+        assert!(err.to_string().contains("--yes"));
+    }
+
+    fn lines_with(sent: &[&str]) -> (mpsc::UnboundedSender<io::Result<String>>, StdinReader) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        for line in sent {
+            tx.send(Ok(line.to_string())).unwrap();
+        }
+        let reader = StdinReader {
+            requests: std::sync::mpsc::channel().0,
+            pending: AtomicBool::new(false),
+            lines: Mutex::new(rx),
+        };
+        (tx, reader)
+    }
+
+    #[tokio::test]
+    async fn test_next_line_answered_in_time() {
+        let (_tx, lines) = lines_with(&["yes\n"]);
+        let line = next_line(&lines, Some(5)).await.unwrap();
+        assert_eq!(line.as_deref(), Some("yes\n"));
+    }
+
+    #[tokio::test]
+    async fn test_next_line_times_out() {
+        let (_tx, lines) = lines_with(&[]);
+        assert!(next_line(&lines, Some(1)).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_late_answer_discarded_before_next_prompt() {
+        let (tx, lines) = lines_with(&[]);
+        assert!(next_line(&lines, Some(1)).await.unwrap().is_none());
+
+        tx.send(Ok("y\n".to_string())).unwrap();
+        discard_queued(&lines);
+        assert!(next_line(&lines, Some(1)).await.unwrap().is_none());
+
+        tx.send(Ok("n\n".to_string())).unwrap();
+        let line = next_line(&lines, Some(1)).await.unwrap();
+        assert_eq!(line.as_deref(), Some("n\n"));
+    }
+
+    #[tokio::test]
+    async fn test_discard_keeps_end_of_input() {
+        let (tx, lines) = lines_with(&["stale\n", ""]);
+        drop(tx);
+        discard_queued(&lines);
+        assert_eq!(
+            next_line(&lines, Some(1)).await.unwrap().as_deref(),
+            Some("")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_line_end_of_input_is_empty() {
+        let (tx, lines) = lines_with(&[]);
+        drop(tx);
+        assert_eq!(
+            next_line(&lines, Some(1)).await.unwrap().as_deref(),
+            Some("")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_next_line_propagates_error() {
+        let (tx, lines) = lines_with(&[]);
+        tx.send(Err(io::Error::other("boom"))).unwrap();
+        assert!(next_line(&lines, Some(1)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_line_stays_pending_until_discarded() {
+        let (tx, lines) = lines_with(&[]);
+        assert!(next_line(&lines, Some(1)).await.unwrap().is_none());
+        assert!(lines.pending.load(Ordering::SeqCst));
+
+        tx.send(Ok("late\n".to_string())).unwrap();
+        discard_queued(&lines);
+        assert!(!lines.pending.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!(parse_selection("2\n", 3, 0).unwrap(), 1);
+        assert_eq!(parse_selection("\n", 3, 0).unwrap(), 0);
+        assert!(parse_selection("4", 3, 0).is_err());
+        assert!(parse_selection("abc", 3, 0).is_err());
+    }
+
+    #[test]
+    fn test_parse_yes_no() {
+        assert!(parse_yes_no("y\n", false));
+        assert!(parse_yes_no("YES", false));
+        assert!(!parse_yes_no("n", true));
+        assert!(parse_yes_no("\n", true));
+        assert!(!parse_yes_no("maybe", false));
+    }
 }
@@ -6,7 +6,11 @@
 mod confirm;
 mod spinner;
 
-pub use confirm::{confirm_action, confirm_large_pagination, LargePaginationInfo};
+pub use confirm::{
+    confirm_action, confirm_large_pagination, confirm_typed, confirm_with_timeout,
+    discard_pending_input, read_line_with_timeout, select_with_timeout, LargePaginationInfo,
+};
 pub use spinner::{
-    create_spinner, finish_spinner, finish_spinner_with_message, finish_spinner_with_status,
+    add_pages, create_spinner, finish_spinner, finish_spinner_with_message,
+    finish_spinner_with_status,
 };
@@ -5,9 +5,10 @@ use std::time::Duration;
 
 /// Create a spinner with the given message
 ///
-/// Returns `None` if quiet mode is enabled.
+/// Returns `None` if quiet mode is enabled, either via `quiet` (batch mode)
+/// or the global `--quiet` flag.
 pub fn create_spinner(message: &str, quiet: bool) -> Option<ProgressBar> {
-    if quiet {
+    if quiet || crate::output::is_quiet() {
         return None;
     }
     let spinner = ProgressBar::new_spinner();
@@ -22,6 +23,25 @@ pub fn create_spinner(message: &str, quiet: bool) -> Option<ProgressBar> {
     Some(spinner)
 }
 
+/// Announce `pages` more pages of a paginated fetch to a spinner
+///
+/// Once more than one page is known the spinner becomes a progress bar,
+/// advanced by one for each fetched page.
+pub fn add_pages(bar: &ProgressBar, pages: u64) {
+    let known = bar.length().unwrap_or(0);
+    let total = known + pages;
+    bar.set_length(total);
+    if known <= 1 && total > 1 {
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .tick_strings(&["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
+                .template("{spinner:.blue} {msg} [{bar:30.blue}] {pos}/{len} pages")
+                .unwrap()
+                .progress_chars("=> "),
+        );
+    }
+}
+
 /// Finish spinner - clears it completely without leaving a message
 ///
 /// The spinner disappears without leaving any trace in the terminal.
@@ -62,6 +82,19 @@ mod tests {
         assert!(create_spinner("test", true).is_none());
     }
 
+    #[test]
+    fn test_add_pages_switches_to_progress_bar() {
+        let bar = ProgressBar::hidden();
+        add_pages(&bar, 1);
+        assert_eq!(bar.length(), Some(1));
+
+        // A second fetch (e.g. another organization) adds to the total
+        add_pages(&bar, 4);
+        assert_eq!(bar.length(), Some(5));
+        bar.inc(2);
+        assert_eq!(bar.position(), 2);
+    }
+
     #[test]
     fn test_finish_spinner_none() {
         // Should not panic
@@ -5,21 +5,83 @@
 
 use log::debug;
 use reqwest::Client;
+use semver::{Prerelease, Version};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 use tokio::sync::oneshot;
 
 use crate::config::update as config;
+use crate::hcp::apply_proxy;
 
 /// Cache file for update check results
 #[derive(Debug, Serialize, Deserialize)]
 struct UpdateCache {
     last_check: u64, // Unix timestamp
     latest_version: String,
+    /// When the update notice was last shown (Unix timestamp)
+    #[serde(default)]
+    notice_shown_at: Option<u64>,
+    /// Version the notice was shown for; a newer release ends the snooze
+    #[serde(default)]
+    notice_version: Option<String>,
+}
+
+impl UpdateCache {
+    /// Whether the notice for the cached latest version is still snoozed
+    fn is_snoozed(&self, now: u64, snooze: Duration) -> bool {
+        match (self.notice_shown_at, &self.notice_version) {
+            (Some(shown_at), Some(version)) => {
+                version == &self.latest_version && now.saturating_sub(shown_at) < snooze.as_secs()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Current Unix timestamp in seconds
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Notice snooze period, overridable via environment variable (in hours)
+fn notice_snooze() -> Duration {
+    std::env::var(config::NOTICE_SNOOZE_ENV_VAR)
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(|hours| Duration::from_secs(hours * 60 * 60))
+        .unwrap_or(config::NOTICE_SNOOZE)
+}
+
+/// Whether `HCPCTL_NO_UPDATE_CHECK` opts out of update checks
+///
+/// Any non-empty value except `0` or `false` disables the check.
+fn check_disabled_by_env(value: Option<&str>) -> bool {
+    value
+        .map(str::trim)
+        .is_some_and(|v| !v.is_empty() && v != "0" && !v.eq_ignore_ascii_case("false"))
+}
+
+/// Read update cache from disk
+fn read_cache_from(path: &Path) -> Option<UpdateCache> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Write update cache to disk (best-effort)
+fn write_cache_to(path: &Path, cache: &UpdateCache) {
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(content) = serde_json::to_string(cache) {
+        let _ = fs::write(path, content);
+    }
 }
 
 /// Handle for receiving update check result
@@ -43,6 +105,10 @@ impl UpdateHandle {
 pub struct UpdateChecker {
     current_version: &'static str,
     cache_path: PathBuf,
+    /// Proxy URL from `--proxy` (environment variables apply otherwise)
+    proxy: Option<String>,
+    /// Skip the version check entirely (no cache read, no network call)
+    disabled: bool,
 }
 
 impl UpdateChecker {
@@ -55,9 +121,26 @@ impl UpdateChecker {
         Self {
             current_version: env!("CARGO_PKG_VERSION"),
             cache_path: cache_dir.join(".hcpctl").join("update-check.json"),
+            proxy: None,
+            disabled: check_disabled_by_env(
+                std::env::var(config::NO_CHECK_ENV_VAR).ok().as_deref(),
+            ),
         }
     }
 
+    /// Disable the version check when `disabled` is set (`--no-update-check`,
+    /// `--batch`); `HCPCTL_NO_UPDATE_CHECK` disables it regardless
+    pub fn with_check_disabled(mut self, disabled: bool) -> Self {
+        self.disabled |= disabled;
+        self
+    }
+
+    /// Route version checks through `proxy` (see `--proxy`)
+    pub fn with_proxy(mut self, proxy: Option<String>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
     /// Check if we should perform a version check (based on cache age)
     fn should_check(&self) -> bool {
         let cache = match self.read_cache() {
@@ -65,28 +148,45 @@ impl UpdateChecker {
             None => return true, // No cache, should check
         };
 
-        let now = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or(0);
-
-        let elapsed = now.saturating_sub(cache.last_check);
+        let elapsed = unix_now().saturating_sub(cache.last_check);
         elapsed >= config::CHECK_INTERVAL.as_secs()
     }
 
     /// Read cache from disk
     fn read_cache(&self) -> Option<UpdateCache> {
-        let content = fs::read_to_string(&self.cache_path).ok()?;
-        serde_json::from_str(&content).ok()
+        read_cache_from(&self.cache_path)
+    }
+
+    /// Print the update notice to stderr (colored when `color` is set) and
+    /// start its snooze period
+    pub fn show_notice(&self, msg: &str, color: bool) {
+        eprintln!("{}", style_notice(msg, color));
+        self.record_notice_shown(unix_now());
+    }
+
+    /// Remember that the notice for the cached latest version was shown
+    fn record_notice_shown(&self, now: u64) {
+        if let Some(mut cache) = self.read_cache() {
+            cache.notice_shown_at = Some(now);
+            cache.notice_version = Some(cache.latest_version.clone());
+            write_cache_to(&self.cache_path, &cache);
+        }
     }
 
     /// Spawn background version check (non-blocking)
-    /// Returns a handle that can be used to get the result later
+    /// Returns a handle that can be used to get the result later,
+    /// or `None` straight away when the check is disabled
     pub fn check_async(&self) -> Option<UpdateHandle> {
+        if self.disabled {
+            debug!("Update check disabled");
+            return None;
+        }
         if !self.should_check() {
             // Check cache for existing update notification
             if let Some(cache) = self.read_cache() {
-                if is_newer(&cache.latest_version, self.current_version) {
+                if is_newer(&cache.latest_version, self.current_version)
+                    && !cache.is_snoozed(unix_now(), notice_snooze())
+                {
                     let msg = format_update_message(self.current_version, &cache.latest_version);
                     let (tx, rx) = oneshot::channel();
                     let _ = tx.send(Some(msg));
@@ -98,10 +198,11 @@ impl UpdateChecker {
 
         let current = self.current_version.to_string();
         let cache_path = self.cache_path.clone();
+        let proxy = self.proxy.clone();
         let (tx, rx) = oneshot::channel();
 
         tokio::spawn(async move {
-            let result = check_version(&current, &cache_path).await;
+            let result = check_version(&current, &cache_path, proxy.as_deref()).await;
             let _ = tx.send(result);
         });
 
@@ -116,11 +217,15 @@ impl Default for UpdateChecker {
 }
 
 /// Async version check
-async fn check_version(current_version: &str, cache_path: &PathBuf) -> Option<String> {
+async fn check_version(
+    current_version: &str,
+    cache_path: &Path,
+    proxy: Option<&str>,
+) -> Option<String> {
     debug!("Checking for updates...");
 
     // Fetch latest version via redirect (no API rate limits)
-    let latest = match fetch_latest_version().await {
+    let latest = match fetch_latest_version(proxy).await {
         Ok(v) => v,
         Err(e) => {
             debug!("Failed to check for updates: {}", e);
@@ -130,24 +235,19 @@ async fn check_version(current_version: &str, cache_path: &PathBuf) -> Option<St
 
     debug!("Current: {}, Latest: {}", current_version, latest);
 
-    // Update cache
+    // Update cache, keeping the notice snooze state from the previous check
+    let previous = read_cache_from(cache_path);
+    let now = unix_now();
     let cache = UpdateCache {
-        last_check: SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .map(|d| d.as_secs())
-            .unwrap_or(0),
+        last_check: now,
         latest_version: latest.clone(),
+        notice_shown_at: previous.as_ref().and_then(|c| c.notice_shown_at),
+        notice_version: previous.and_then(|c| c.notice_version),
     };
-
-    if let Some(parent) = cache_path.parent() {
-        let _ = fs::create_dir_all(parent);
-    }
-    if let Ok(content) = serde_json::to_string(&cache) {
-        let _ = fs::write(cache_path, content);
-    }
+    write_cache_to(cache_path, &cache);
 
     // Check if update available
-    if is_newer(&latest, current_version) {
+    if is_newer(&latest, current_version) && !cache.is_snoozed(now, notice_snooze()) {
         Some(format_update_message(current_version, &latest))
     } else {
         None
@@ -159,19 +259,22 @@ async fn check_version(current_version: &str, cache_path: &PathBuf) -> Option<St
 /// Uses `https://github.com/{repo}/releases/latest` which returns a 302 redirect
 /// to `.../tag/v0.15.0`. We extract the version from the redirect URL.
 /// This does NOT hit the GitHub API and is not subject to rate limiting.
-async fn fetch_latest_version() -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+async fn fetch_latest_version(
+    proxy: Option<&str>,
+) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
     let url = format!("https://github.com/{}/releases/latest", config::GITHUB_REPO);
-    fetch_latest_version_from(&url).await
+    fetch_latest_version_from(&url, proxy).await
 }
 
 /// Testable implementation that accepts a full URL
 async fn fetch_latest_version_from(
     url: &str,
+    proxy: Option<&str>,
 ) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
-    let client = Client::builder()
+    let builder = Client::builder()
         .timeout(config::REQUEST_TIMEOUT)
-        .redirect(reqwest::redirect::Policy::none())
-        .build()?;
+        .redirect(reqwest::redirect::Policy::none());
+    let client = apply_proxy(builder, proxy)?.build()?;
 
     let response = client
         .get(url)
@@ -202,21 +305,19 @@ async fn fetch_latest_version_from(
 }
 
 /// Fetch release details from GitHub API (best-effort, for release notes only)
-async fn fetch_release_body(tag: &str) -> Option<String> {
+async fn fetch_release_body(tag: &str, proxy: Option<&str>) -> Option<String> {
     let url = format!(
         "https://api.github.com/repos/{}/releases/tags/v{}",
         config::GITHUB_REPO,
         tag
     );
-    fetch_release_body_from(&url).await
+    fetch_release_body_from(&url, proxy).await
 }
 
 /// Testable implementation that accepts a full URL
-async fn fetch_release_body_from(url: &str) -> Option<String> {
-    let client = Client::builder()
-        .timeout(config::REQUEST_TIMEOUT)
-        .build()
-        .ok()?;
+async fn fetch_release_body_from(url: &str, proxy: Option<&str>) -> Option<String> {
+    let builder = Client::builder().timeout(config::REQUEST_TIMEOUT);
+    let client = apply_proxy(builder, proxy).ok()?.build().ok()?;
 
     let response = client
         .get(url)
@@ -240,22 +341,36 @@ struct GitHubRelease {
     body: Option<String>,
 }
 
-/// Compare versions (simple semver comparison)
+/// Compare versions by semver precedence
+///
+/// Pre-releases sort below their release (`1.2.0-rc.1 < 1.2.0`) and build
+/// metadata is ignored. A fourth numeric part (`1.2.3.1`) ranks after the
+/// patch. Tags that do not parse are never considered newer.
 fn is_newer(latest: &str, current: &str) -> bool {
-    let parse = |v: &str| -> (u32, u32, u32) {
-        let parts: Vec<u32> = v
-            .trim_start_matches('v')
-            .split('.')
-            .filter_map(|p| p.parse().ok())
-            .collect();
-        (
-            parts.first().copied().unwrap_or(0),
-            parts.get(1).copied().unwrap_or(0),
-            parts.get(2).copied().unwrap_or(0),
-        )
-    };
+    match (version_key(latest), version_key(current)) {
+        (Some(latest), Some(current)) => latest > current,
+        _ => false,
+    }
+}
 
-    parse(latest) > parse(current)
+/// Precedence key: (major, minor, patch, fourth part, pre-release)
+fn version_key(version: &str) -> Option<(u64, u64, u64, u64, Prerelease)> {
+    let version = version.trim().trim_start_matches('v');
+    let (parsed, fourth) = match Version::parse(version) {
+        Ok(parsed) => (parsed, 0),
+        Err(_) => {
+            // 1.2.3.4[-pre][+build]: split the fourth part off the numeric core
+            let core_end = version.find(['-', '+']).unwrap_or(version.len());
+            let (core, suffix) = version.split_at(core_end);
+            let (three, fourth) = core.rsplit_once('.')?;
+            let fourth = fourth.parse().ok()?;
+            (
+                Version::parse(&format!("{}{}", three, suffix)).ok()?,
+                fourth,
+            )
+        }
+    };
+    Some((parsed.major, parsed.minor, parsed.patch, fourth, parsed.pre))
 }
 
 /// Format the update notification message using comfy_table for proper borders
@@ -276,6 +391,25 @@ fn format_update_message(current: &str, latest: &str) -> String {
     format!("\n{}", table)
 }
 
+/// Highlight the update notice (bold yellow) when writing to a color terminal
+///
+/// Each line is wrapped separately so table borders keep their alignment.
+fn style_notice(msg: &str, color: bool) -> String {
+    if !color {
+        return msg.to_string();
+    }
+    msg.lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("\x1b[1;33m{}\x1b[0m", line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Format release notes for display after update.
 /// Returns None if body is empty or missing.
 fn format_changelog(body: Option<&str>) -> Option<String> {
@@ -288,13 +422,13 @@ fn format_changelog(body: Option<&str>) -> Option<String> {
 }
 
 /// Run the update command - checks for updates and installs if available
-pub async fn run_update() -> Result<(), Box<dyn std::error::Error>> {
+pub async fn run_update(proxy: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
     let current_version = env!("CARGO_PKG_VERSION");
 
     println!("Checking for updates...");
 
     // Fetch latest version via redirect (no API rate limits)
-    let latest = match fetch_latest_version().await {
+    let latest = match fetch_latest_version(proxy).await {
         Ok(v) => v,
         Err(e) => {
             return Err(format!("Failed to check for updates: {}", e).into());
@@ -310,7 +444,7 @@ pub async fn run_update() -> Result<(), Box<dyn std::error::Error>> {
 
     // Fetch the install script using reqwest (no curl dependency)
     let script_url = get_install_script_url();
-    let script = fetch_install_script(script_url).await?;
+    let script = fetch_install_script(script_url, proxy).await?;
 
     // Execute the script
     #[cfg(not(target_os = "windows"))]
@@ -348,7 +482,7 @@ pub async fn run_update() -> Result<(), Box<dyn std::error::Error>> {
     println!("✓ Successfully updated to v{}", latest);
 
     // Show release notes if available (best-effort, may fail due to API rate limits)
-    if let Some(body) = fetch_release_body(&latest).await {
+    if let Some(body) = fetch_release_body(&latest, proxy).await {
         if let Some(notes) = format_changelog(Some(&body)) {
             println!("\n{}", notes);
         }
@@ -371,10 +505,12 @@ fn get_install_script_url() -> &'static str {
 }
 
 /// Fetch the install script content
-async fn fetch_install_script(url: &str) -> Result<String, Box<dyn std::error::Error>> {
-    let client = Client::builder()
-        .timeout(std::time::Duration::from_secs(30))
-        .build()?;
+async fn fetch_install_script(
+    url: &str,
+    proxy: Option<&str>,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let builder = Client::builder().timeout(std::time::Duration::from_secs(30));
+    let client = apply_proxy(builder, proxy)?.build()?;
 
     let response = client
         .get(url)
@@ -409,6 +545,38 @@ mod tests {
         assert!(!is_newer("0.2.9", "0.3.1"));
     }
 
+    #[test]
+    fn test_is_newer_pre_release() {
+        assert!(is_newer("1.2.0", "1.2.0-rc.1"));
+        assert!(!is_newer("1.2.0-rc.1", "1.2.0"));
+        assert!(is_newer("1.2.0-rc.2", "1.2.0-rc.1"));
+        assert!(is_newer("1.2.0-rc.1", "1.1.9"));
+    }
+
+    #[test]
+    fn test_is_newer_ignores_build_metadata() {
+        assert!(!is_newer("1.2.0+build.5", "1.2.0+build.4"));
+        assert!(!is_newer("1.2.0+abc", "1.2.0"));
+        assert!(!is_newer("1.2.0", "1.2.0+abc"));
+    }
+
+    #[test]
+    fn test_is_newer_four_part_versions() {
+        assert!(is_newer("1.2.3.1", "1.2.3"));
+        assert!(is_newer("1.2.4", "1.2.3.1"));
+        assert!(!is_newer("1.2.3.1", "1.2.3.1"));
+        assert!(!is_newer("1.2.3.1-rc.1", "1.2.3.1"));
+    }
+
+    #[test]
+    fn test_is_newer_malformed_is_not_newer() {
+        assert!(!is_newer("latest", "0.3.1"));
+        assert!(!is_newer("", "0.3.1"));
+        assert!(!is_newer("1.x.0", "0.3.1"));
+        assert!(!is_newer("1.2.3.4.5", "0.3.1"));
+        assert!(!is_newer("1.0.0", "garbage"));
+    }
+
     // --- format helpers ---
 
     #[test]
@@ -427,6 +595,142 @@ mod tests {
         assert!(msg.contains("hcpctl"));
     }
 
+    #[test]
+    fn test_style_notice_plain() {
+        let msg = format_update_message("0.3.1", "0.4.0");
+        assert_eq!(style_notice(&msg, false), msg);
+    }
+
+    #[test]
+    fn test_style_notice_color_wraps_lines() {
+        let styled = style_notice("\nline one\nline two", true);
+        assert_eq!(
+            styled,
+            "\n\x1b[1;33mline one\x1b[0m\n\x1b[1;33mline two\x1b[0m"
+        );
+    }
+
+    // --- notice snooze ---
+
+    fn cache(latest: &str, shown_at: Option<u64>, shown_for: Option<&str>) -> UpdateCache {
+        UpdateCache {
+            last_check: 0,
+            latest_version: latest.to_string(),
+            notice_shown_at: shown_at,
+            notice_version: shown_for.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_is_snoozed_within_period() {
+        let c = cache("1.0.0", Some(1_000), Some("1.0.0"));
+        assert!(c.is_snoozed(1_000 + 3_599, Duration::from_secs(3_600)));
+        assert!(!c.is_snoozed(1_000 + 3_600, Duration::from_secs(3_600)));
+    }
+
+    #[test]
+    fn test_is_snoozed_never_shown() {
+        let c = cache("1.0.0", None, None);
+        assert!(!c.is_snoozed(1_000, Duration::from_secs(3_600)));
+    }
+
+    #[test]
+    fn test_is_snoozed_newer_release_ends_snooze() {
+        let c = cache("1.1.0", Some(1_000), Some("1.0.0"));
+        assert!(!c.is_snoozed(1_001, Duration::from_secs(3_600)));
+    }
+
+    #[test]
+    fn test_is_snoozed_zero_period_disables() {
+        let c = cache("1.0.0", Some(1_000), Some("1.0.0"));
+        assert!(!c.is_snoozed(1_000, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_update_cache_without_notice_fields() {
+        let c: UpdateCache =
+            serde_json::from_str(r#"{"last_check": 5, "latest_version": "1.0.0"}"#).unwrap();
+        assert!(c.notice_shown_at.is_none());
+        assert!(c.notice_version.is_none());
+    }
+
+    #[test]
+    fn test_record_notice_shown_snoozes_cached_notice() {
+        let dir = tempfile::tempdir().unwrap();
+        let checker = UpdateChecker {
+            current_version: "0.1.0",
+            cache_path: dir.path().join("update-check.json"),
+            proxy: None,
+            disabled: false,
+        };
+        write_cache_to(&checker.cache_path, &cache("9.9.9", None, None));
+
+        checker.record_notice_shown(unix_now());
+
+        let c = checker.read_cache().unwrap();
+        assert_eq!(c.notice_version.as_deref(), Some("9.9.9"));
+        assert!(c.is_snoozed(unix_now(), config::NOTICE_SNOOZE));
+    }
+
+    #[test]
+    fn test_check_disabled_by_env() {
+        assert!(check_disabled_by_env(Some("1")));
+        assert!(check_disabled_by_env(Some("true")));
+        assert!(check_disabled_by_env(Some("yes")));
+        assert!(!check_disabled_by_env(None));
+        assert!(!check_disabled_by_env(Some("")));
+        assert!(!check_disabled_by_env(Some("0")));
+        assert!(!check_disabled_by_env(Some("FALSE")));
+    }
+
+    #[test]
+    fn test_check_async_disabled_returns_none_without_network() {
+        // No cache and no tokio runtime: an enabled checker would try to spawn
+        // the network check, so returning here proves it short-circuits
+        let dir = tempfile::tempdir().unwrap();
+        let checker = UpdateChecker {
+            current_version: "0.1.0",
+            cache_path: dir.path().join("update-check.json"),
+            proxy: None,
+            disabled: false,
+        }
+        .with_check_disabled(true);
+        assert!(checker.check_async().is_none());
+    }
+
+    #[test]
+    fn test_check_async_disabled_ignores_cached_update() {
+        let dir = tempfile::tempdir().unwrap();
+        let checker = UpdateChecker {
+            current_version: "0.1.0",
+            cache_path: dir.path().join("update-check.json"),
+            proxy: None,
+            disabled: true,
+        };
+        let mut fresh = cache("9.9.9", None, None);
+        fresh.last_check = unix_now();
+        write_cache_to(&checker.cache_path, &fresh);
+        assert!(checker.check_async().is_none());
+
+        let enabled = UpdateChecker {
+            disabled: false,
+            ..checker
+        };
+        assert!(enabled.check_async().is_some());
+    }
+
+    #[test]
+    fn test_with_check_disabled_keeps_env_opt_out() {
+        let checker = UpdateChecker {
+            current_version: "0.1.0",
+            cache_path: PathBuf::from("unused"),
+            proxy: None,
+            disabled: true,
+        }
+        .with_check_disabled(false);
+        assert!(checker.disabled);
+    }
+
     #[test]
     fn test_format_changelog_with_content() {
         let result = format_changelog(Some("## Bug Fixes\n- Fixed crash on startup"));
@@ -494,7 +798,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/latest", mock_server.uri());
-        let version = fetch_latest_version_from(&url).await.unwrap();
+        let version = fetch_latest_version_from(&url, None).await.unwrap();
         assert_eq!(version, "1.2.3");
     }
 
@@ -512,7 +816,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/latest", mock_server.uri());
-        let version = fetch_latest_version_from(&url).await.unwrap();
+        let version = fetch_latest_version_from(&url, None).await.unwrap();
         assert_eq!(version, "1.0.0");
     }
 
@@ -527,7 +831,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/latest", mock_server.uri());
-        let err = fetch_latest_version_from(&url).await.unwrap_err();
+        let err = fetch_latest_version_from(&url, None).await.unwrap_err();
         let msg = err.to_string();
         assert!(msg.contains("404"), "expected 404 in error: {}", msg);
         assert!(msg.contains("Not Found"), "expected body in error: {}", msg);
@@ -544,7 +848,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/latest", mock_server.uri());
-        let err = fetch_latest_version_from(&url).await.unwrap_err();
+        let err = fetch_latest_version_from(&url, None).await.unwrap_err();
         let msg = err.to_string();
         assert!(msg.contains("500"), "expected 500 in error: {}", msg);
     }
@@ -561,7 +865,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/latest", mock_server.uri());
-        let err = fetch_latest_version_from(&url).await.unwrap_err();
+        let err = fetch_latest_version_from(&url, None).await.unwrap_err();
         let msg = err.to_string();
         assert!(
             msg.contains("Location"),
@@ -582,7 +886,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/latest", mock_server.uri());
-        let err = fetch_latest_version_from(&url).await.unwrap_err();
+        let err = fetch_latest_version_from(&url, None).await.unwrap_err();
         let msg = err.to_string();
         assert!(msg.contains("200"), "expected 200 in error: {}", msg);
     }
@@ -603,7 +907,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/tags/v1.2.3", mock_server.uri());
-        let body = fetch_release_body_from(&url).await;
+        let body = fetch_release_body_from(&url, None).await;
         assert_eq!(body.as_deref(), Some("## What's Changed\n- Bug fix"));
     }
 
@@ -620,7 +924,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/tags/v1.2.3", mock_server.uri());
-        let body = fetch_release_body_from(&url).await;
+        let body = fetch_release_body_from(&url, None).await;
         assert!(body.is_none());
     }
 
@@ -635,7 +939,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/tags/v99.99.99", mock_server.uri());
-        let body = fetch_release_body_from(&url).await;
+        let body = fetch_release_body_from(&url, None).await;
         assert!(body.is_none());
     }
 
@@ -653,7 +957,7 @@ mod tests {
             .await;
 
         let url = format!("{}/releases/tags/v1.0.0", mock_server.uri());
-        let body = fetch_release_body_from(&url).await;
+        let body = fetch_release_body_from(&url, None).await;
         assert!(body.is_none());
     }
 }
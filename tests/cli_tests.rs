@@ -44,6 +44,28 @@ fn test_get_help_flag() {
     assert!(stdout.contains("ws"), "Should document ws resource");
 }
 
+/// Test that 'get var' help documents the workspace and category options
+#[test]
+fn test_var_help_flag() {
+    let output = Command::new(hcpctl_bin())
+        .args(["get", "var", "--help"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("--ws"), "Should document --ws option");
+    assert!(
+        stdout.contains("--category"),
+        "Should document --category option"
+    );
+    assert!(
+        stdout.contains("terraform"),
+        "Should list terraform category"
+    );
+    assert!(stdout.contains("env"), "Should list env category");
+}
+
 /// Test that 'get ws' subcommand help shows expected options
 #[test]
 fn test_ws_help_flag() {
@@ -257,6 +279,20 @@ fn test_oauth_client_alias() {
     );
 }
 
+/// Test that 'get oc --help' documents --with-tokens
+#[test]
+fn test_oauth_client_with_tokens_flag() {
+    let output = Command::new(hcpctl_bin())
+        .args(["get", "oc", "--help"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("--with-tokens"),
+        "Should document --with-tokens"
+    );
+}
+
 /// Test that output formats are documented
 #[test]
 fn test_output_formats_documented() {
@@ -288,6 +324,17 @@ fn test_batch_flag_documented() {
     );
 }
 
+/// Test that --quiet flag is documented
+#[test]
+fn test_quiet_flag_documented() {
+    let output = Command::new(hcpctl_bin()).arg("--help").output().unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(stdout.contains("--quiet"), "Should document --quiet option");
+}
+
 /// Test that --no-header flag is documented
 #[test]
 fn test_no_header_flag_documented() {
@@ -302,6 +349,133 @@ fn test_no_header_flag_documented() {
     );
 }
 
+/// Test that --wide flag is documented for ws
+#[test]
+fn test_ws_wide_flag_documented() {
+    let output = Command::new(hcpctl_bin())
+        .args(["get", "ws", "--help"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(stdout.contains("--wide"), "Should document --wide option");
+    assert!(
+        stdout.contains("auto-apply"),
+        "Should describe settings columns"
+    );
+}
+
+/// Test that --flatten flag is documented for ws
+#[test]
+fn test_ws_flatten_flag_documented() {
+    let output = Command::new(hcpctl_bin())
+        .args(["get", "ws", "--help"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("--flatten"),
+        "Should document --flatten option"
+    );
+}
+
+/// Test that --id-only flag is documented
+#[test]
+fn test_id_only_flag_documented() {
+    let output = Command::new(hcpctl_bin()).arg("--help").output().unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("--id-only"),
+        "Should document --id-only option"
+    );
+}
+
+/// Test that --output-file flag is documented
+#[test]
+fn test_output_file_flag_documented() {
+    let output = Command::new(hcpctl_bin()).arg("--help").output().unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("--output-file <PATH>"),
+        "Should document --output-file option"
+    );
+}
+
+/// Test that an output file whose directory cannot be created fails before any API call
+#[test]
+fn test_output_file_unwritable_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let blocker = dir.path().join("not-a-dir");
+    std::fs::write(&blocker, "").unwrap();
+
+    let output = Command::new(hcpctl_bin())
+        .args([
+            "--host",
+            "nonexistent.example.com",
+            "get",
+            "org",
+            "--output-file",
+        ])
+        .arg(blocker.join("orgs.json"))
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Cannot create output file"),
+        "Should explain the failure: {}",
+        stderr
+    );
+}
+
+/// Test that --output-file is rejected for commands that print straight to stdout
+#[test]
+fn test_output_file_rejected_for_config() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("contexts.txt");
+
+    let output = Command::new(hcpctl_bin())
+        .args(["config", "get-contexts", "--output-file"])
+        .arg(&path)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--output-file is not supported"),
+        "Should reject --output-file: {}",
+        stderr
+    );
+    assert!(!path.exists(), "Should not create the file");
+}
+
+/// Test that --prompt-timeout flag is documented
+#[test]
+fn test_prompt_timeout_flag_documented() {
+    let output = Command::new(hcpctl_bin()).arg("--help").output().unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("--prompt-timeout <SECONDS>"),
+        "Should document --prompt-timeout option"
+    );
+}
+
 /// Test that sort options are documented for ws
 #[test]
 fn test_ws_sort_options_documented() {
@@ -883,6 +1057,10 @@ fn test_download_config_help_flag() {
         stdout.contains("--output"),
         "Should document --output option"
     );
+    assert!(
+        stdout.contains("--output-dir"),
+        "Should document --output-dir option"
+    );
     assert!(stdout.contains("-o"), "Should have short -o for org");
 }
 
@@ -952,6 +1130,94 @@ fn test_download_config_target_types_documented() {
     );
 }
 
+/// Test that the hidden completion command prints a script for every shell
+#[test]
+fn test_completion_scripts_non_empty() {
+    for shell in ["bash", "zsh", "fish", "powershell"] {
+        let output = Command::new(hcpctl_bin())
+            .args(["completion", shell])
+            .output()
+            .unwrap();
+
+        assert!(output.status.success(), "completion {} failed", shell);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            stdout.contains("hcpctl"),
+            "completion {} should mention hcpctl",
+            shell
+        );
+    }
+}
+
+/// Test that dynamic completion prints nothing and succeeds without credentials
+#[test]
+fn test_dynamic_completion_silent_on_auth_failure() {
+    let home = tempfile::tempdir().unwrap();
+    let output = Command::new(hcpctl_bin())
+        .args([
+            "--host",
+            "nonexistent.example.com",
+            "__complete",
+            "ws",
+            "--org",
+            "o",
+            "ap",
+        ])
+        .env_remove("HCP_TOKEN")
+        .env_remove("TFC_TOKEN")
+        .env_remove("TFE_TOKEN")
+        .env("HOME", home.path())
+        .env("HCPCTL_CONTEXT", "__nonexistent_test_context__")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(output.stderr.is_empty());
+}
+
+/// Test that --profile fails fast when the named context does not exist
+#[test]
+fn test_unknown_profile_errors() {
+    let home = tempfile::tempdir().unwrap();
+    let output = Command::new(hcpctl_bin())
+        .args(["--profile", "missing", "get", "org"])
+        .env("HOME", home.path())
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Profile 'missing' not found"), "{}", stderr);
+}
+
+/// Test that --all-orgs is rejected together with an explicit --org
+#[test]
+fn test_all_orgs_conflicts_with_org() {
+    let home = tempfile::tempdir().unwrap();
+    let output = Command::new(hcpctl_bin())
+        .args(["get", "ws", "--org", "acme", "--all-orgs"])
+        .env("HOME", home.path())
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--all-orgs cannot be combined with --org"),
+        "{}",
+        stderr
+    );
+}
+
+/// Test that the completion command is hidden from the main help
+#[test]
+fn test_completion_hidden_from_help() {
+    let output = Command::new(hcpctl_bin()).arg("--help").output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("completion"));
+}
+
 // ===== Set command tests =====
 
 /// Test set help flag
@@ -1037,6 +1303,40 @@ fn test_set_ws_requires_at_least_one_setting() {
     );
 }
 
+/// Test set ws settings toggles are documented and satisfy the settings requirement
+#[test]
+fn test_set_ws_settings_toggles() {
+    let output = Command::new(hcpctl_bin())
+        .args(["set", "ws", "--help"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for flag in [
+        "--auto-apply",
+        "--queue-all-runs",
+        "--speculative-enabled",
+        "--allow-destroy-plan",
+    ] {
+        assert!(stdout.contains(flag), "Should document {}", flag);
+    }
+
+    let output = Command::new(hcpctl_bin())
+        .args(["set", "ws", "ws-abc123", "--auto-apply", "false"])
+        .env("TFE_TOKEN", "fake-token")
+        .env("TFE_HOSTNAME", "fake.host.com")
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("unexpected argument") && !stderr.contains("required arguments"),
+        "--auto-apply alone should satisfy the settings group: {}",
+        stderr
+    );
+}
+
 /// Test set ws workspace alias works
 #[test]
 fn test_set_ws_alias() {
@@ -1941,3 +2241,45 @@ fn test_ws_all_states_requires_states() {
         stderr
     );
 }
+
+/// Test that '-o template' without --template fails before contacting TFE
+#[test]
+fn test_template_output_requires_template() {
+    let output = Command::new(hcpctl_bin())
+        .args(["get", "ws", "--org", "my-org", "-o", "template"])
+        .env_remove("HCP_TOKEN")
+        .env_remove("TFC_TOKEN")
+        .env_remove("TFE_TOKEN")
+        .env("HCPCTL_CONTEXT", "__nonexistent_test_context__")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("-o template requires --template"),
+        "Error should explain the missing --template, got: {}",
+        stderr
+    );
+}
+
+/// Test that an invalid --template is rejected with a parse error
+#[test]
+fn test_template_invalid_is_rejected() {
+    let output = Command::new(hcpctl_bin())
+        .args(["get", "ws", "-o", "template", "--template", "{{range .x}}"])
+        .env_remove("HCP_TOKEN")
+        .env_remove("TFC_TOKEN")
+        .env_remove("TFE_TOKEN")
+        .env("HCPCTL_CONTEXT", "__nonexistent_test_context__")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid template"),
+        "Error should report the template problem, got: {}",
+        stderr
+    );
+}